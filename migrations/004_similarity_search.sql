@@ -0,0 +1,9 @@
+-- Trigram similarity support for fuzzy address matching
+-- Enables ranked lookups such as find_similar_addresses without per-row queries
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS idx_states_name_trgm ON states USING gin (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_lgas_name_trgm ON lgas USING gin (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_wards_name_trgm ON wards USING gin (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_postal_codes_code_trgm ON postal_codes USING gin (postal_code gin_trgm_ops);
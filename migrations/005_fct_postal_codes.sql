@@ -0,0 +1,14 @@
+-- Move FCT postal codes into the NIPOST Abuja range
+-- generate_postal_codes.py looked the FCT up as 'Federal Capital Territory' while the
+-- states table names it 'Abuja', so its wards fell back to the unassigned 999xxx range.
+-- Those codes were numbered sequentially from 999001, so keep the last three digits.
+
+UPDATE postal_codes p
+SET postal_code = '900' || right(p.postal_code, 3),
+    updated_at = NOW()
+FROM wards w
+JOIN lgas l ON l.id = w.lga_id
+JOIN states s ON s.id = l.state_id
+WHERE p.ward_id = w.id
+  AND s.code = 'NG-FC'
+  AND p.postal_code LIKE '999%';
@@ -0,0 +1,103 @@
+-- Hierarchical administrative codes
+--
+--   State  NG-XX          ISO 3166-2:NG code, unchanged         e.g. NG-LA
+--   LGA    NG-XX-YYY      state code + 3-character abbreviation e.g. NG-LA-IKJ
+--   Ward   NG-XX-YYY-WNN  LGA code + W + ward number in the LGA e.g. NG-LA-IKJ-W03
+--
+-- Every parent code is a prefix of its children's codes, so the state and LGA can
+-- be derived from any ward code. This rewrites the legacy LGA-0001 / WARD-00001 codes.
+--
+-- LGA abbreviations:
+--   one word    first letter, then the remaining consonants, then vowels (Ikeja -> IKJ)
+--   two words   first two letters of the one-word form + initial of the second word
+--               (Lagos Island -> LGI, Lagos Mainland -> LGM)
+--   3+ words    initials of the first three words (Ibadan North-East -> INE)
+-- If two LGAs in a state still collide, later ones (by name) keep the first two
+-- characters and take the first suffix, 2-9 then A-Z, that gives an unused code,
+-- e.g. KD2. The migration fails if an LGA runs out of suffixes.
+
+CREATE TEMPORARY TABLE lga_abbreviations AS
+WITH words AS (
+    SELECT l.id, l.name, s.code AS state_code,
+           regexp_split_to_array(trim(upper(regexp_replace(l.name, '[^A-Za-z]+', ' ', 'g'))), ' ') AS w
+    FROM lgas l
+    JOIN states s ON s.id = l.state_id
+    WHERE l.code NOT LIKE 'NG-%'
+), first_word AS (
+    SELECT *,
+           rpad(left(left(w[1], 1)
+                     || regexp_replace(substr(w[1], 2), '[AEIOU]', '', 'g')
+                     || regexp_replace(substr(w[1], 2), '[^AEIOU]', '', 'g'), 3), 3, 'X') AS head
+    FROM words
+)
+SELECT id, name, state_code,
+       CASE array_length(w, 1)
+           WHEN 1 THEN head
+           WHEN 2 THEN left(head, 2) || left(w[2], 1)
+           ELSE left(w[1], 1) || left(w[2], 1) || left(w[3], 1)
+       END AS abbreviation
+FROM first_word;
+
+DO $$
+DECLARE
+    lga RECORD;
+    suffix TEXT;
+    candidate TEXT;
+BEGIN
+    -- Abbreviations first, so that no LGA's suffixed code takes another's
+    FOR lga IN
+        SELECT DISTINCT ON (state_code, abbreviation) id, state_code, abbreviation
+        FROM lga_abbreviations
+        ORDER BY state_code, abbreviation, name
+    LOOP
+        candidate := lga.state_code || '-' || lga.abbreviation;
+        IF NOT EXISTS (SELECT 1 FROM lgas WHERE code = candidate) THEN
+            UPDATE lgas SET code = candidate, updated_at = NOW() WHERE id = lga.id;
+        END IF;
+    END LOOP;
+
+    FOR lga IN
+        SELECT a.id, a.name, a.state_code, a.abbreviation
+        FROM lga_abbreviations a
+        JOIN lgas l ON l.id = a.id
+        WHERE l.code NOT LIKE 'NG-%'
+        ORDER BY a.state_code, a.abbreviation, a.name
+    LOOP
+        candidate := NULL;
+        FOREACH suffix IN ARRAY string_to_array('23456789ABCDEFGHIJKLMNOPQRSTUVWXYZ', NULL) LOOP
+            IF NOT EXISTS (
+                SELECT 1 FROM lgas WHERE code = lga.state_code || '-' || left(lga.abbreviation, 2) || suffix
+            ) THEN
+                candidate := lga.state_code || '-' || left(lga.abbreviation, 2) || suffix;
+                EXIT;
+            END IF;
+        END LOOP;
+
+        IF candidate IS NULL THEN
+            RAISE EXCEPTION 'No unused code left for LGA % (%) in %',
+                lga.name, lga.abbreviation, lga.state_code;
+        END IF;
+        UPDATE lgas SET code = candidate, updated_at = NOW() WHERE id = lga.id;
+    END LOOP;
+END $$;
+
+DROP TABLE lga_abbreviations;
+
+-- Number wards within each LGA in their legacy code order, with at least two
+-- digits; an LGA with 1000 wards or more fails the format check below
+WITH numbered AS (
+    SELECT w.id, l.code AS lga_code,
+           row_number() OVER (PARTITION BY w.lga_id ORDER BY w.code) AS n
+    FROM wards w
+    JOIN lgas l ON l.id = w.lga_id
+    WHERE w.code NOT LIKE 'NG-%'
+)
+UPDATE wards w
+SET code = nb.lga_code || '-W' || CASE WHEN nb.n < 10 THEN '0' ELSE '' END || nb.n,
+    updated_at = NOW()
+FROM numbered nb
+WHERE w.id = nb.id;
+
+ALTER TABLE states ADD CONSTRAINT states_code_format CHECK (code ~ '^NG-[A-Z]{2}$');
+ALTER TABLE lgas ADD CONSTRAINT lgas_code_format CHECK (code ~ '^NG-[A-Z]{2}-[A-Z]{2}[A-Z0-9]$');
+ALTER TABLE wards ADD CONSTRAINT wards_code_format CHECK (code ~ '^NG-[A-Z]{2}-[A-Z]{2}[A-Z0-9]-W[0-9]{2,3}$');
@@ -0,0 +1,72 @@
+-- Alternate, abbreviated, historical and local-language names for states, LGAs and wards
+-- Lookups and searches resolve an alias to the entity it names
+
+CREATE TABLE IF NOT EXISTS aliases (
+    id UUID PRIMARY KEY,
+    entity_type VARCHAR(10) NOT NULL CHECK (entity_type IN ('state', 'lga', 'ward')),
+    entity_id UUID NOT NULL,
+    name TEXT NOT NULL,
+    alias_type VARCHAR(20) NOT NULL CHECK (alias_type IN ('alternate', 'abbreviation', 'historical', 'local')),
+    -- ISO 639 language code, e.g. en, yo, ha, ig
+    language VARCHAR(3) CHECK (language ~ '^[a-z]{2,3}$'),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS aliases_entity_name_key ON aliases (entity_type, entity_id, lower(name));
+CREATE INDEX IF NOT EXISTS idx_aliases_entity ON aliases (entity_type, entity_id);
+CREATE INDEX IF NOT EXISTS idx_aliases_lower_name ON aliases (lower(name));
+CREATE INDEX IF NOT EXISTS idx_aliases_name_trgm ON aliases USING gin (name gin_trgm_ops);
+
+DROP TRIGGER IF EXISTS update_aliases_updated_at ON aliases;
+CREATE TRIGGER update_aliases_updated_at BEFORE UPDATE ON aliases
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- entity_id points at one of three tables, so remove aliases with their entity
+CREATE OR REPLACE FUNCTION delete_entity_aliases()
+RETURNS TRIGGER AS $$
+BEGIN
+    DELETE FROM aliases WHERE entity_type = TG_ARGV[0] AND entity_id = OLD.id;
+    RETURN OLD;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS delete_state_aliases ON states;
+CREATE TRIGGER delete_state_aliases AFTER DELETE ON states
+    FOR EACH ROW EXECUTE FUNCTION delete_entity_aliases('state');
+
+DROP TRIGGER IF EXISTS delete_lga_aliases ON lgas;
+CREATE TRIGGER delete_lga_aliases AFTER DELETE ON lgas
+    FOR EACH ROW EXECUTE FUNCTION delete_entity_aliases('lga');
+
+DROP TRIGGER IF EXISTS delete_ward_aliases ON wards;
+CREATE TRIGGER delete_ward_aliases AFTER DELETE ON wards
+    FOR EACH ROW EXECUTE FUNCTION delete_entity_aliases('ward');
+
+-- Well-known aliases; entities missing from this database are skipped
+INSERT INTO aliases (id, entity_type, entity_id, name, alias_type, language)
+SELECT md5('alias:' || a.code || ':' || a.name)::uuid, a.entity_type, e.id, a.name, a.alias_type, a.language
+FROM (VALUES
+    ('state', 'NG-LA', 'Èkó', 'local', 'yo'),
+    ('state', 'NG-LA', 'Eko', 'local', 'yo'),
+    ('state', 'NG-OY', 'Ọ̀yọ́', 'local', 'yo'),
+    ('state', 'NG-FC', 'Abuja', 'alternate', 'en'),
+    ('state', 'NG-ED', 'Bendel', 'historical', 'en'),
+    ('lga', 'NG-RI-PRH', 'PH', 'abbreviation', 'en'),
+    ('lga', 'NG-RI-PRH', 'Pitakwa', 'local', NULL),
+    ('lga', 'NG-RI-OBA', 'Obio-Akpor', 'alternate', 'en'),
+    ('lga', 'NG-FC-ABM', 'AMAC', 'abbreviation', 'en'),
+    ('lga', 'NG-FC-ABM', 'Abuja Municipal Area Council', 'alternate', 'en'),
+    ('lga', 'NG-OS-IFC', 'Ile-Ife', 'alternate', 'en'),
+    ('lga', 'NG-OS-IFC', 'Ifẹ̀', 'local', 'yo'),
+    ('lga', 'NG-LA-ETO', 'Eti-Osa', 'alternate', 'en'),
+    ('lga', 'NG-LA-OSI', 'Oshodi', 'alternate', 'en'),
+    ('lga', 'NG-OY-OGN', 'Ogbomoso North', 'alternate', 'en'),
+    ('lga', 'NG-OY-OGS', 'Ogbomoso South', 'alternate', 'en')
+) AS a (entity_type, code, name, alias_type, language)
+JOIN (
+    SELECT 'state' AS entity_type, id, code FROM states
+    UNION ALL
+    SELECT 'lga', id, code FROM lgas
+) e ON e.entity_type = a.entity_type AND e.code = a.code
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,18 @@
+-- Normalized names for diacritic-, punctuation- and stopword-insensitive matching
+-- Values are computed by the application (src/domain/normalization.rs) on write
+-- and backfilled at startup for rows that are missing or out of date
+
+ALTER TABLE states ADD COLUMN IF NOT EXISTS normalized_name TEXT;
+ALTER TABLE lgas ADD COLUMN IF NOT EXISTS normalized_name TEXT;
+ALTER TABLE wards ADD COLUMN IF NOT EXISTS normalized_name TEXT;
+ALTER TABLE aliases ADD COLUMN IF NOT EXISTS normalized_name TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_states_normalized_name ON states (normalized_name);
+CREATE INDEX IF NOT EXISTS idx_lgas_normalized_name ON lgas (normalized_name);
+CREATE INDEX IF NOT EXISTS idx_wards_normalized_name ON wards (normalized_name);
+CREATE INDEX IF NOT EXISTS idx_aliases_normalized_name ON aliases (normalized_name);
+
+CREATE INDEX IF NOT EXISTS idx_states_normalized_name_trgm ON states USING gin (normalized_name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_lgas_normalized_name_trgm ON lgas USING gin (normalized_name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_wards_normalized_name_trgm ON wards USING gin (normalized_name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_aliases_normalized_name_trgm ON aliases USING gin (normalized_name gin_trgm_ops);
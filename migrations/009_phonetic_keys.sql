@@ -0,0 +1,18 @@
+-- Phonetic keys for matching names transcribed by ear
+-- Values are computed by the application (src/domain/phonetic.rs) on write
+-- and backfilled at startup for rows that are missing or out of date
+
+ALTER TABLE states ADD COLUMN IF NOT EXISTS phonetic_key TEXT;
+ALTER TABLE lgas ADD COLUMN IF NOT EXISTS phonetic_key TEXT;
+ALTER TABLE wards ADD COLUMN IF NOT EXISTS phonetic_key TEXT;
+ALTER TABLE aliases ADD COLUMN IF NOT EXISTS phonetic_key TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_states_phonetic_key ON states (phonetic_key);
+CREATE INDEX IF NOT EXISTS idx_lgas_phonetic_key ON lgas (phonetic_key);
+CREATE INDEX IF NOT EXISTS idx_wards_phonetic_key ON wards (phonetic_key);
+CREATE INDEX IF NOT EXISTS idx_aliases_phonetic_key ON aliases (phonetic_key);
+
+CREATE INDEX IF NOT EXISTS idx_states_phonetic_key_trgm ON states USING gin (phonetic_key gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_lgas_phonetic_key_trgm ON lgas USING gin (phonetic_key gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_wards_phonetic_key_trgm ON wards USING gin (phonetic_key gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_aliases_phonetic_key_trgm ON aliases USING gin (phonetic_key gin_trgm_ops);
@@ -0,0 +1,30 @@
+-- Append-only history of writes to states, LGAs, wards, postal codes and aliases
+-- Rows are written by the repositories in the same transaction as the change,
+-- with the entity as it was before and after and who made the change
+
+CREATE TABLE IF NOT EXISTS change_history (
+    id BIGSERIAL PRIMARY KEY,
+    entity_type VARCHAR(20) NOT NULL CHECK (entity_type IN ('state', 'lga', 'ward', 'postal_code', 'alias')),
+    entity_id UUID NOT NULL,
+    operation VARCHAR(10) NOT NULL CHECK (operation IN ('create', 'update', 'delete')),
+    before JSONB,
+    after JSONB,
+    actor TEXT NOT NULL,
+    changed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CHECK ((before IS NULL) = (operation = 'create')),
+    CHECK ((after IS NULL) = (operation = 'delete'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_change_history_entity ON change_history (entity_type, entity_id, id);
+CREATE INDEX IF NOT EXISTS idx_change_history_changed_at ON change_history (changed_at);
+
+CREATE OR REPLACE FUNCTION reject_change_history_rewrite()
+RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'change_history is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS change_history_append_only ON change_history;
+CREATE TRIGGER change_history_append_only BEFORE UPDATE OR DELETE ON change_history
+    FOR EACH ROW EXECUTE FUNCTION reject_change_history_rewrite();
@@ -0,0 +1,31 @@
+-- Named, immutable releases of the dataset
+-- Releasing copies states, LGAs, wards, postal codes and aliases into a schema
+-- of their own, dataset_v<id>, which pinned reads use in place of the live
+-- tables. history_id is the last change_history row included in the release,
+-- so the changes between two releases are the history rows between theirs.
+
+CREATE TABLE IF NOT EXISTS dataset_versions (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(50) NOT NULL UNIQUE CHECK (name ~ '^[A-Za-z0-9._-]+$'),
+    notes TEXT,
+    released_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    released_by TEXT NOT NULL,
+    history_id BIGINT NOT NULL DEFAULT 0,
+    states BIGINT NOT NULL DEFAULT 0,
+    lgas BIGINT NOT NULL DEFAULT 0,
+    wards BIGINT NOT NULL DEFAULT 0,
+    postal_codes BIGINT NOT NULL DEFAULT 0
+);
+
+CREATE INDEX IF NOT EXISTS idx_dataset_versions_released_at ON dataset_versions (released_at);
+
+CREATE OR REPLACE FUNCTION reject_dataset_version_rewrite()
+RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'dataset releases are immutable';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS dataset_versions_immutable ON dataset_versions;
+CREATE TRIGGER dataset_versions_immutable BEFORE UPDATE OR DELETE ON dataset_versions
+    FOR EACH ROW EXECUTE FUNCTION reject_dataset_version_rewrite();
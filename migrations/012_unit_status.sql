@@ -0,0 +1,71 @@
+-- Lifecycle of states, LGAs, wards and postal codes
+-- Units are no longer deleted: boundary reforms mark them deprecated, or merged
+-- into a successor of the same kind, and lookups follow the successor. Rows
+-- stay in place so their children, aliases and history keep their references.
+
+ALTER TABLE states ADD COLUMN IF NOT EXISTS status VARCHAR(20) NOT NULL DEFAULT 'active';
+ALTER TABLE lgas ADD COLUMN IF NOT EXISTS status VARCHAR(20) NOT NULL DEFAULT 'active';
+ALTER TABLE wards ADD COLUMN IF NOT EXISTS status VARCHAR(20) NOT NULL DEFAULT 'active';
+ALTER TABLE postal_codes ADD COLUMN IF NOT EXISTS status VARCHAR(20) NOT NULL DEFAULT 'active';
+
+ALTER TABLE states ADD COLUMN IF NOT EXISTS successor_id UUID REFERENCES states(id);
+ALTER TABLE lgas ADD COLUMN IF NOT EXISTS successor_id UUID REFERENCES lgas(id);
+ALTER TABLE wards ADD COLUMN IF NOT EXISTS successor_id UUID REFERENCES wards(id);
+ALTER TABLE postal_codes ADD COLUMN IF NOT EXISTS successor_id UUID REFERENCES postal_codes(id);
+
+-- Active units have no successor, merged ones always do, deprecated ones may
+DO $$
+DECLARE
+    t TEXT;
+BEGIN
+    FOREACH t IN ARRAY ARRAY['states', 'lgas', 'wards', 'postal_codes'] LOOP
+        EXECUTE format('ALTER TABLE %I DROP CONSTRAINT IF EXISTS %I', t, t || '_status_check');
+        EXECUTE format(
+            'ALTER TABLE %I ADD CONSTRAINT %I CHECK (
+                status IN (''active'', ''deprecated'', ''merged'')
+                AND successor_id IS DISTINCT FROM id
+                AND CASE status
+                        WHEN ''active'' THEN successor_id IS NULL
+                        WHEN ''merged'' THEN successor_id IS NOT NULL
+                        ELSE TRUE
+                    END
+            )',
+            t, t || '_status_check'
+        );
+    END LOOP;
+END $$;
+
+CREATE INDEX IF NOT EXISTS idx_states_status ON states (status) WHERE status <> 'active';
+CREATE INDEX IF NOT EXISTS idx_lgas_status ON lgas (status) WHERE status <> 'active';
+CREATE INDEX IF NOT EXISTS idx_wards_status ON wards (status) WHERE status <> 'active';
+CREATE INDEX IF NOT EXISTS idx_postal_codes_status ON postal_codes (status) WHERE status <> 'active';
+
+-- A parent can no longer take its children with it
+ALTER TABLE lgas DROP CONSTRAINT IF EXISTS lgas_state_id_fkey;
+ALTER TABLE lgas ADD CONSTRAINT lgas_state_id_fkey
+    FOREIGN KEY (state_id) REFERENCES states(id) ON DELETE RESTRICT;
+ALTER TABLE wards DROP CONSTRAINT IF EXISTS wards_lga_id_fkey;
+ALTER TABLE wards ADD CONSTRAINT wards_lga_id_fkey
+    FOREIGN KEY (lga_id) REFERENCES lgas(id) ON DELETE RESTRICT;
+ALTER TABLE postal_codes DROP CONSTRAINT IF EXISTS postal_codes_ward_id_fkey;
+ALTER TABLE postal_codes ADD CONSTRAINT postal_codes_ward_id_fkey
+    FOREIGN KEY (ward_id) REFERENCES wards(id) ON DELETE RESTRICT;
+
+-- Releases made before this migration predate the lifecycle, so everything in
+-- them was active; give their copies the same columns so pinned reads work
+DO $$
+DECLARE
+    release_id INTEGER;
+    t TEXT;
+BEGIN
+    FOR release_id IN SELECT id FROM dataset_versions LOOP
+        FOREACH t IN ARRAY ARRAY['states', 'lgas', 'wards', 'postal_codes'] LOOP
+            EXECUTE format(
+                'ALTER TABLE %I.%I
+                    ADD COLUMN IF NOT EXISTS status VARCHAR(20) NOT NULL DEFAULT ''active'',
+                    ADD COLUMN IF NOT EXISTS successor_id UUID',
+                'dataset_v' || release_id, t
+            );
+        END LOOP;
+    END LOOP;
+END $$;
@@ -5,6 +5,7 @@ This script creates real LGA names for all Nigerian states
 """
 
 import json
+import re
 import uuid
 from typing import Dict, List, Any
 
@@ -241,6 +242,48 @@ AUTHENTIC_LGAS = {
     ]
 }
 
+# ISO 3166-2:NG codes, keyed by the state names used in the states table
+STATE_CODES = {
+    "Abia": "NG-AB", "Adamawa": "NG-AD", "Akwa Ibom": "NG-AK", "Anambra": "NG-AN",
+    "Bauchi": "NG-BA", "Bayelsa": "NG-BY", "Benue": "NG-BE", "Borno": "NG-BO",
+    "Cross River": "NG-CR", "Delta": "NG-DE", "Ebonyi": "NG-EB", "Edo": "NG-ED",
+    "Ekiti": "NG-EK", "Enugu": "NG-EN", "Abuja": "NG-FC", "Gombe": "NG-GO",
+    "Imo": "NG-IM", "Jigawa": "NG-JI", "Kaduna": "NG-KD", "Kano": "NG-KN",
+    "Katsina": "NG-KT", "Kebbi": "NG-KE", "Kogi": "NG-KO", "Kwara": "NG-KW",
+    "Lagos": "NG-LA", "Nasarawa": "NG-NA", "Niger": "NG-NI", "Ogun": "NG-OG",
+    "Ondo": "NG-ON", "Osun": "NG-OS", "Oyo": "NG-OY", "Plateau": "NG-PL",
+    "Rivers": "NG-RI", "Sokoto": "NG-SO", "Taraba": "NG-TA", "Yobe": "NG-YO",
+    "Zamfara": "NG-ZA",
+}
+
+def lga_abbreviation(lga_name):
+    """Three-character LGA abbreviation, using the same rules as migration 006"""
+    words = re.sub(r"[^A-Za-z]+", " ", lga_name).strip().upper().split(" ")
+    first = words[0]
+    rest = first[1:]
+    head = (first[:1]
+            + "".join(c for c in rest if c not in "AEIOU")
+            + "".join(c for c in rest if c in "AEIOU"))[:3].ljust(3, "X")
+
+    if len(words) == 1:
+        return head
+    if len(words) == 2:
+        return head[:2] + words[1][:1]
+    return words[0][:1] + words[1][:1] + words[2][:1]
+
+def lga_codes_for_state(state_name, lga_names):
+    """Hierarchical LGA codes (NG-XX-YYY) for a state, numbering collisions by name"""
+    state_code = STATE_CODES[state_name]
+    seen = {}
+    codes = {}
+    for lga_name in sorted(lga_names):
+        abbreviation = lga_abbreviation(lga_name)
+        seen[abbreviation] = seen.get(abbreviation, 0) + 1
+        if seen[abbreviation] > 1:
+            abbreviation = f"{abbreviation[:2]}{seen[abbreviation]}"
+        codes[lga_name] = f"{state_code}-{abbreviation}"
+    return codes
+
 def generate_uuid():
     """Generate a UUID string"""
     return str(uuid.uuid4())
@@ -254,9 +297,10 @@ def generate_authentic_lgas_sql():
     lga_counter = 1
     
     for state_name, lgas in AUTHENTIC_LGAS.items():
+        lga_codes = lga_codes_for_state(state_name, lgas)
         for i, lga_name in enumerate(lgas):
             lga_id = generate_uuid()
-            lga_code = f"LGA-{lga_counter:04d}"
+            lga_code = lga_codes[lga_name]
             # Escape single quotes in LGA names
             escaped_lga_name = lga_name.replace("'", "''")
             lga_entries.append(f"    ('{lga_id}', (SELECT id FROM states WHERE name = '{state_name}'), '{escaped_lga_name}', '{lga_code}')")
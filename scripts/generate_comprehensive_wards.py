@@ -113,7 +113,7 @@ def get_all_lgas():
     try:
         with conn.cursor(cursor_factory=RealDictCursor) as cur:
             cur.execute("""
-                SELECT l.id, l.name as lga_name, s.name as state_name
+                SELECT l.id, l.name as lga_name, l.code as lga_code, s.name as state_name
                 FROM lgas l
                 JOIN states s ON l.state_id = s.id
                 ORDER BY s.name, l.name
@@ -342,7 +342,7 @@ def generate_comprehensive_wards():
                     ward_name = f"Ward {ward_index + 1}-{lga_name[:3]}"
                 used_names.add(ward_name)
             
-            ward_code = f"WARD-{ward_counter:05d}"
+            ward_code = f"{lga['lga_code']}-W{ward_index + 1:02d}"
             
             # Escape single quotes in ward names
             escaped_ward_name = ward_name.replace("'", "''")
@@ -39,6 +39,7 @@ POSTAL_CODE_RANGES = {
     
     # Middle Belt (400000-599999)
     'Federal Capital Territory': {'start': 900001, 'base': 900000},
+    'Abuja': {'start': 900001, 'base': 900000},  # FCT row name in the states table
     'Niger': {'start': 920001, 'base': 920000},
     'Kwara': {'start': 240001, 'base': 240000},
     'Kogi': {'start': 260001, 'base': 260000},
@@ -1,776 +1,776 @@
 -- Authentic Nigerian LGAs Data (from Wikipedia source)
 INSERT INTO lgas (id, state_id, name, code) VALUES
-    ('c21d863c-4faa-4406-8b8e-74a8e4f2ab53', (SELECT id FROM states WHERE name = 'Abia'), 'Aba North', 'LGA-0001'),
-    ('6fbf18be-9850-4a2a-8890-250096d6d6e2', (SELECT id FROM states WHERE name = 'Abia'), 'Aba South', 'LGA-0002'),
-    ('9317c3e1-c654-4d4d-bf30-282cfecd8ee7', (SELECT id FROM states WHERE name = 'Abia'), 'Arochukwu', 'LGA-0003'),
-    ('bd9370bc-3b19-4994-ab37-cfd4130a20b0', (SELECT id FROM states WHERE name = 'Abia'), 'Bende', 'LGA-0004'),
-    ('d6f6f999-760c-47de-b045-6434d28981d1', (SELECT id FROM states WHERE name = 'Abia'), 'Ikwuano', 'LGA-0005'),
-    ('249a43bb-de7a-4eb7-80b0-3b0b4b70a98e', (SELECT id FROM states WHERE name = 'Abia'), 'Isiala Ngwa North', 'LGA-0006'),
-    ('c9b8a871-9215-48e4-a907-00a16e208ca7', (SELECT id FROM states WHERE name = 'Abia'), 'Isiala Ngwa South', 'LGA-0007'),
-    ('e710caaa-742b-48af-a1aa-2b902421954f', (SELECT id FROM states WHERE name = 'Abia'), 'Isuikwuato', 'LGA-0008'),
-    ('c85b7052-06d2-4282-83de-805c6e5d117e', (SELECT id FROM states WHERE name = 'Abia'), 'Obi Ngwa', 'LGA-0009'),
-    ('b6f727f3-6d09-4209-a6cd-f6061950890f', (SELECT id FROM states WHERE name = 'Abia'), 'Ohafia', 'LGA-0010'),
-    ('caeb10e6-6146-4b5a-acc6-6a65dbf821f3', (SELECT id FROM states WHERE name = 'Abia'), 'Osisioma Ngwa', 'LGA-0011'),
-    ('f6a26698-214f-42cc-9bbd-90ace3ccff45', (SELECT id FROM states WHERE name = 'Abia'), 'Ugwunagbo', 'LGA-0012'),
-    ('06e760c2-f0f7-41b3-826c-655343d4c16a', (SELECT id FROM states WHERE name = 'Abia'), 'Ukwa East', 'LGA-0013'),
-    ('c1099cb0-be66-403c-bb8a-a1b6acf03c92', (SELECT id FROM states WHERE name = 'Abia'), 'Ukwa West', 'LGA-0014'),
-    ('a38dd84e-33d2-40e5-9ebb-0d6ae38337bf', (SELECT id FROM states WHERE name = 'Abia'), 'Umuahia North', 'LGA-0015'),
-    ('3c304d56-9d31-4942-b4d7-89b9869f1f64', (SELECT id FROM states WHERE name = 'Abia'), 'Umuahia South', 'LGA-0016'),
-    ('60427195-c0f7-4577-91e9-0676e8b5753d', (SELECT id FROM states WHERE name = 'Abia'), 'Umu Nneochi', 'LGA-0017'),
-    ('2ad1afda-4ab3-49ff-994c-f2fff5e2177f', (SELECT id FROM states WHERE name = 'Adamawa'), 'Demsa', 'LGA-0018'),
-    ('393ccbbe-b43a-4910-9a71-0793836d649e', (SELECT id FROM states WHERE name = 'Adamawa'), 'Fufure', 'LGA-0019'),
-    ('83a5d598-4b36-40a2-96bf-afad7b1a1aa5', (SELECT id FROM states WHERE name = 'Adamawa'), 'Ganye', 'LGA-0020'),
-    ('04c71ba3-e770-40f2-aaaa-8231a3643e43', (SELECT id FROM states WHERE name = 'Adamawa'), 'Guyuk', 'LGA-0021'),
-    ('bb049ea4-4501-4862-86b4-4244f9761e94', (SELECT id FROM states WHERE name = 'Adamawa'), 'Girei', 'LGA-0022'),
-    ('536d2dd6-52eb-4030-8ce9-ad62aebbd742', (SELECT id FROM states WHERE name = 'Adamawa'), 'Gombi', 'LGA-0023'),
-    ('edf4f908-7670-4abb-8230-5ab37c5b12a6', (SELECT id FROM states WHERE name = 'Adamawa'), 'Hong', 'LGA-0024'),
-    ('4f113b07-f4ad-4d4e-96e6-2d4130a8d930', (SELECT id FROM states WHERE name = 'Adamawa'), 'Jada', 'LGA-0025'),
-    ('cf2c3ed6-dc72-4b46-aac0-33ca1429cf92', (SELECT id FROM states WHERE name = 'Adamawa'), 'Lamurde', 'LGA-0026'),
-    ('676927b4-865b-4ddd-af3c-377e80912952', (SELECT id FROM states WHERE name = 'Adamawa'), 'Madagali', 'LGA-0027'),
-    ('b50f0e82-ebfa-47bc-87ba-e591b639cee6', (SELECT id FROM states WHERE name = 'Adamawa'), 'Maiha', 'LGA-0028'),
-    ('5908c5d2-6191-4acd-8dc1-0e9061f500d0', (SELECT id FROM states WHERE name = 'Adamawa'), 'Mayo Belwa', 'LGA-0029'),
-    ('2b62548b-cf17-4bc9-8c08-93eaa0d4c8c1', (SELECT id FROM states WHERE name = 'Adamawa'), 'Michika', 'LGA-0030'),
-    ('79be97c6-ace8-4c6d-a00b-5b0bb49a1699', (SELECT id FROM states WHERE name = 'Adamawa'), 'Mubi North', 'LGA-0031'),
-    ('6585cd53-00e9-448f-afbe-5f671fd8e8cc', (SELECT id FROM states WHERE name = 'Adamawa'), 'Mubi South', 'LGA-0032'),
-    ('44724563-7e13-43ab-8929-49df2adb8a55', (SELECT id FROM states WHERE name = 'Adamawa'), 'Numan', 'LGA-0033'),
-    ('91c6849e-e7b0-4027-a093-90d3e65a5105', (SELECT id FROM states WHERE name = 'Adamawa'), 'Shelleng', 'LGA-0034'),
-    ('3d08a354-bcd2-44dd-b1d3-2c3eb487aa1e', (SELECT id FROM states WHERE name = 'Adamawa'), 'Song', 'LGA-0035'),
-    ('b0ca9e38-564b-4f31-a87a-9babd9d857c8', (SELECT id FROM states WHERE name = 'Adamawa'), 'Toungo', 'LGA-0036'),
-    ('32194863-70cd-4e9f-81ab-a8d8cf4400e3', (SELECT id FROM states WHERE name = 'Adamawa'), 'Yola North', 'LGA-0037'),
-    ('bc6ac9ef-c043-491b-a8ec-c38762da6b28', (SELECT id FROM states WHERE name = 'Adamawa'), 'Yola South', 'LGA-0038'),
-    ('9feada09-9d29-4406-afb6-1d9e302f4a31', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Abak', 'LGA-0039'),
-    ('5e3d7360-f8ab-4bae-aa96-ad8e794d615f', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Eastern Obolo', 'LGA-0040'),
-    ('2c72fce0-c047-4534-87e4-5e5edf84419a', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Eket', 'LGA-0041'),
-    ('54a658bf-3d94-48ea-afdb-9e81e5a340e2', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Esit Eket', 'LGA-0042'),
-    ('3b5a9715-26e8-4cb8-8775-6dac3ef1ac60', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Essien Udim', 'LGA-0043'),
-    ('c88620c1-e47b-40bc-bb85-f90d07ef67ff', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Etim Ekpo', 'LGA-0044'),
-    ('b32688b5-ea7a-45bb-ae40-720e47a0a63b', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Etinan', 'LGA-0045'),
-    ('1114670e-ac88-40fa-9261-db59d702319c', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ibeno', 'LGA-0046'),
-    ('474272a4-8aa6-466d-8acc-fb16c60a3d3d', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ibesikpo Asutan', 'LGA-0047'),
-    ('7ab27b83-770e-4baa-8da5-d5c4da3dc050', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ibiono-Ibom', 'LGA-0048'),
-    ('5e5a1910-77a6-4cd2-afc6-a71b80dad232', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ika', 'LGA-0049'),
-    ('f712c841-cbcc-4974-84ac-bf626824fa1e', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ikono', 'LGA-0050'),
-    ('18c0edd4-770e-4213-849e-846ab3508d5c', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ikot Abasi', 'LGA-0051'),
-    ('5f041625-7bbb-4109-bd71-4b7794107618', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ikot Ekpene', 'LGA-0052'),
-    ('22a76a75-c582-4242-9833-7dc2152e4564', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ini', 'LGA-0053'),
-    ('6b79413e-2938-468e-b44a-6f7d0314a1b3', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Itu', 'LGA-0054'),
-    ('bc97c94f-6fb2-4146-812b-6c084301031d', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Mbo', 'LGA-0055'),
-    ('12292d38-3f69-45a7-95d2-03d4a4974bf6', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Mkpat-Enin', 'LGA-0056'),
-    ('ed440aea-b120-4683-a111-9c6e5ed1fc91', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Nsit-Atai', 'LGA-0057'),
-    ('96001547-4715-43be-a67f-b1939635dcba', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Nsit-Ibom', 'LGA-0058'),
-    ('24d54fdf-b75f-43cd-a6aa-cd7f3b4b09ff', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Nsit-Ubium', 'LGA-0059'),
-    ('d0d0b7e0-83e9-444d-97b5-a3676aebfbaf', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Obot Akara', 'LGA-0060'),
-    ('a96e6ab2-b234-4518-bf1f-a284d2bfde87', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Okobo', 'LGA-0061'),
-    ('17dc5432-c35e-4a05-aab2-b5882b9baee4', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Onna', 'LGA-0062'),
-    ('445f7685-fc91-41d2-9659-db15e39a0315', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Oron', 'LGA-0063'),
-    ('088de810-10f4-4a87-b20c-633b74535055', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Oruk Anam', 'LGA-0064'),
-    ('d5c12503-fa6b-4035-bd48-e802c40f378f', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Udung-Uko', 'LGA-0065'),
-    ('e786738c-d5b9-4389-824e-9b6bc10e97e9', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ukanafun', 'LGA-0066'),
-    ('352c9084-2226-4098-bfd6-0fc62fb7f6cc', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Uruan', 'LGA-0067'),
-    ('e7da1c19-51db-4744-bb69-572a3bec58d3', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Urue-Offong/Oruko', 'LGA-0068'),
-    ('a381b2d9-c18d-42f5-8b0a-ae81fe07c810', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Uyo', 'LGA-0069'),
-    ('c6cf8f4d-b7e7-4b32-a545-089b92dd6634', (SELECT id FROM states WHERE name = 'Anambra'), 'Aguata', 'LGA-0070'),
-    ('53cd8d7a-fb1f-495e-866f-0b7a6457da95', (SELECT id FROM states WHERE name = 'Anambra'), 'Anambra East', 'LGA-0071'),
-    ('c3340b5f-2a02-4b2e-bd8c-4be03f1d5f23', (SELECT id FROM states WHERE name = 'Anambra'), 'Anambra West', 'LGA-0072'),
-    ('1ecbb605-0e13-4868-b0a1-d3339a738caf', (SELECT id FROM states WHERE name = 'Anambra'), 'Anaocha', 'LGA-0073'),
-    ('8d738087-2dfd-4d12-ab4b-c728cdecf408', (SELECT id FROM states WHERE name = 'Anambra'), 'Awka North', 'LGA-0074'),
-    ('a3748f85-af67-4c3e-bdd8-73430d86a7cf', (SELECT id FROM states WHERE name = 'Anambra'), 'Awka South', 'LGA-0075'),
-    ('e4611870-fdfe-4250-ba14-941aabbd7a00', (SELECT id FROM states WHERE name = 'Anambra'), 'Ayamelum', 'LGA-0076'),
-    ('298fb4e4-c8bb-4694-bc52-ca31f4885b1f', (SELECT id FROM states WHERE name = 'Anambra'), 'Dunukofia', 'LGA-0077'),
-    ('da2e645c-4b30-4eac-85c0-6d1695cc95c7', (SELECT id FROM states WHERE name = 'Anambra'), 'Ekwusigo', 'LGA-0078'),
-    ('068cb749-6a47-4c6e-a579-3fb78ab34570', (SELECT id FROM states WHERE name = 'Anambra'), 'Idemili North', 'LGA-0079'),
-    ('c18954b7-1bc2-41b0-93e5-37b63149ac43', (SELECT id FROM states WHERE name = 'Anambra'), 'Idemili South', 'LGA-0080'),
-    ('0b9a7ce9-31e5-4c6f-a430-b3864c9a8fb9', (SELECT id FROM states WHERE name = 'Anambra'), 'Ihiala', 'LGA-0081'),
-    ('698ee059-bd9c-49d3-89fb-f4997e4a90eb', (SELECT id FROM states WHERE name = 'Anambra'), 'Njikoka', 'LGA-0082'),
-    ('5564e73d-3f45-483a-9a94-77adb5f1628a', (SELECT id FROM states WHERE name = 'Anambra'), 'Nnewi North', 'LGA-0083'),
-    ('198ab072-722b-4f86-9055-5e83e9081a93', (SELECT id FROM states WHERE name = 'Anambra'), 'Nnewi South', 'LGA-0084'),
-    ('b830c3b3-65fb-4af8-9380-25c6a4f96826', (SELECT id FROM states WHERE name = 'Anambra'), 'Ogbaru', 'LGA-0085'),
-    ('661a623f-595c-4643-a055-16a87b680789', (SELECT id FROM states WHERE name = 'Anambra'), 'Onitsha North', 'LGA-0086'),
-    ('86cd56bf-5997-44af-9834-7d73ced5ceb0', (SELECT id FROM states WHERE name = 'Anambra'), 'Onitsha South', 'LGA-0087'),
-    ('40740340-9183-4ee2-928b-e891e07be1df', (SELECT id FROM states WHERE name = 'Anambra'), 'Orumba North', 'LGA-0088'),
-    ('57fa3bee-e7e0-4ed2-9f9a-047da8295cc1', (SELECT id FROM states WHERE name = 'Anambra'), 'Orumba South', 'LGA-0089'),
-    ('17e5356d-9be0-4407-871d-442793397f03', (SELECT id FROM states WHERE name = 'Anambra'), 'Oyi', 'LGA-0090'),
-    ('9afcc7e1-5614-47ae-b0f9-df11cc94d11f', (SELECT id FROM states WHERE name = 'Bauchi'), 'Alkaleri', 'LGA-0091'),
-    ('e8446ba3-cbdd-4d6b-916d-078d376a5b46', (SELECT id FROM states WHERE name = 'Bauchi'), 'Bauchi', 'LGA-0092'),
-    ('e5135314-9a0a-46e0-bb5e-714121bd8f29', (SELECT id FROM states WHERE name = 'Bauchi'), 'Bogoro', 'LGA-0093'),
-    ('729d11d6-f94e-478c-9c72-f8a887064dd4', (SELECT id FROM states WHERE name = 'Bauchi'), 'Damban', 'LGA-0094'),
-    ('5d9a846c-d26e-4f9c-b3ab-bb6ee09f7a52', (SELECT id FROM states WHERE name = 'Bauchi'), 'Darazo', 'LGA-0095'),
-    ('861a370a-3fa6-4b61-bd3c-dde0ada25a47', (SELECT id FROM states WHERE name = 'Bauchi'), 'Dass', 'LGA-0096'),
-    ('a48b8fa6-cac1-4ea0-9a85-b923a8859aae', (SELECT id FROM states WHERE name = 'Bauchi'), 'Gamawa', 'LGA-0097'),
-    ('4f41aad4-b4c1-4cfc-ab47-30fc60f1c0a2', (SELECT id FROM states WHERE name = 'Bauchi'), 'Ganjuwa', 'LGA-0098'),
-    ('4a78b8ac-3d69-4101-869c-4e9feab55ea5', (SELECT id FROM states WHERE name = 'Bauchi'), 'Giade', 'LGA-0099'),
-    ('39c4c41d-77d1-4927-91c4-a7e2a12b2c33', (SELECT id FROM states WHERE name = 'Bauchi'), 'Itas/Gadau', 'LGA-0100'),
-    ('dd9192aa-a6b9-462a-a61e-4f337d5d215c', (SELECT id FROM states WHERE name = 'Bauchi'), 'Jama''are', 'LGA-0101'),
-    ('148b534b-bba6-4c67-a583-bdda8ecd6ad1', (SELECT id FROM states WHERE name = 'Bauchi'), 'Katagum', 'LGA-0102'),
-    ('09177802-8995-4735-836d-3c375a6fa009', (SELECT id FROM states WHERE name = 'Bauchi'), 'Kirfi', 'LGA-0103'),
-    ('7e80a006-25de-41ca-82ea-d0b0402ba0c3', (SELECT id FROM states WHERE name = 'Bauchi'), 'Misau', 'LGA-0104'),
-    ('dd1e3fae-e97c-49ff-9cc7-42c9a7c82949', (SELECT id FROM states WHERE name = 'Bauchi'), 'Ningi', 'LGA-0105'),
-    ('acc4ae70-b6d2-4771-a5c7-affe0ec754e6', (SELECT id FROM states WHERE name = 'Bauchi'), 'Shira', 'LGA-0106'),
-    ('115b327e-af26-42a6-ab36-bebd246ea581', (SELECT id FROM states WHERE name = 'Bauchi'), 'Tafawa Balewa', 'LGA-0107'),
-    ('6b04cfe8-4667-4a1b-a31e-7249662c5525', (SELECT id FROM states WHERE name = 'Bauchi'), 'Toro', 'LGA-0108'),
-    ('623c1293-0e55-4679-89d6-d2515d90fa94', (SELECT id FROM states WHERE name = 'Bauchi'), 'Warji', 'LGA-0109'),
-    ('a18a9e93-55ff-4737-a806-df6f1aa0afbc', (SELECT id FROM states WHERE name = 'Bauchi'), 'Zaki', 'LGA-0110'),
-    ('f1905d0f-729c-4dc5-bbaa-e599d1029aae', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Brass', 'LGA-0111'),
-    ('60f2972b-b77e-4981-b5fb-c588aa00f454', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Ekeremor', 'LGA-0112'),
-    ('b3882948-9c0a-4557-a7ba-72598e9ea8ed', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Kolokuma/Opokuma', 'LGA-0113'),
-    ('74d05d12-3774-4e3f-a6b5-da32a6b123d1', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Nembe', 'LGA-0114'),
-    ('ed793f7b-0296-4d7e-b328-39a0bf5b2cfc', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Ogbia', 'LGA-0115'),
-    ('69a5188d-f99e-4522-b14d-fbc0a6a5e2ee', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Sagbama', 'LGA-0116'),
-    ('1dfc8dc8-7db4-4666-8b18-e71bd172337e', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Southern Ijaw', 'LGA-0117'),
-    ('ca74b19e-d1a3-405b-a8eb-5127945ff106', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Yenagoa', 'LGA-0118'),
-    ('3cb3408d-ffc6-4dc8-b36e-24710db06290', (SELECT id FROM states WHERE name = 'Benue'), 'Ado', 'LGA-0119'),
-    ('aa54e4dd-5203-488c-8bad-f4a5899f589c', (SELECT id FROM states WHERE name = 'Benue'), 'Agatu', 'LGA-0120'),
-    ('0c556c4f-6f19-4a38-aa52-d6f6054f1d7c', (SELECT id FROM states WHERE name = 'Benue'), 'Apa', 'LGA-0121'),
-    ('665516d6-4a67-4bf1-8320-f2006c80a418', (SELECT id FROM states WHERE name = 'Benue'), 'Buruku', 'LGA-0122'),
-    ('aaa93679-60aa-4d71-93b6-ea46ffe17877', (SELECT id FROM states WHERE name = 'Benue'), 'Gboko', 'LGA-0123'),
-    ('408bea54-4dbe-4c37-bb00-58ecefb78de0', (SELECT id FROM states WHERE name = 'Benue'), 'Guma', 'LGA-0124'),
-    ('e6d5928e-6056-4a43-a66c-de33d616ae24', (SELECT id FROM states WHERE name = 'Benue'), 'Gwer East', 'LGA-0125'),
-    ('e2d674fe-6b7f-4f57-bb06-4febc90367ab', (SELECT id FROM states WHERE name = 'Benue'), 'Gwer West', 'LGA-0126'),
-    ('83cd81b5-6d05-4f02-b9ed-69d27d9fd98a', (SELECT id FROM states WHERE name = 'Benue'), 'Katsina-Ala', 'LGA-0127'),
-    ('d6f6313d-bbf1-4258-90ed-be9e3b6b9c8d', (SELECT id FROM states WHERE name = 'Benue'), 'Konshisha', 'LGA-0128'),
-    ('45292410-1d20-41dc-8642-97bfc35b325f', (SELECT id FROM states WHERE name = 'Benue'), 'Kwande', 'LGA-0129'),
-    ('1a07b86d-c270-4b87-83e0-e2ebf3044284', (SELECT id FROM states WHERE name = 'Benue'), 'Logo', 'LGA-0130'),
-    ('66c58c86-faa8-4fda-957f-d5368b946ba3', (SELECT id FROM states WHERE name = 'Benue'), 'Makurdi', 'LGA-0131'),
-    ('b40e6ca5-e3bd-4276-b54b-252d70bdb8cf', (SELECT id FROM states WHERE name = 'Benue'), 'Obi', 'LGA-0132'),
-    ('6bf33d3e-24c4-4d76-a332-250f69c3507f', (SELECT id FROM states WHERE name = 'Benue'), 'Ogbadibo', 'LGA-0133'),
-    ('d8af164a-6628-421e-ac9d-5b6143189303', (SELECT id FROM states WHERE name = 'Benue'), 'Ohimini', 'LGA-0134'),
-    ('38c0c448-bb4e-405c-8fa9-a6f67e5fe560', (SELECT id FROM states WHERE name = 'Benue'), 'Oju', 'LGA-0135'),
-    ('3d23b8d1-b25a-4126-9001-f17981080c30', (SELECT id FROM states WHERE name = 'Benue'), 'Okpokwu', 'LGA-0136'),
-    ('fd2a2013-ea2c-42a3-84bf-5cbda264abc7', (SELECT id FROM states WHERE name = 'Benue'), 'Oturkpo', 'LGA-0137'),
-    ('48517cab-30d0-4c86-a735-8f2ef69357c4', (SELECT id FROM states WHERE name = 'Benue'), 'Tarka', 'LGA-0138'),
-    ('0e138a65-45e0-4cab-8bed-69234c4e99d6', (SELECT id FROM states WHERE name = 'Benue'), 'Ukum', 'LGA-0139'),
-    ('b767df6d-2052-4f16-9936-60f59fa9f54f', (SELECT id FROM states WHERE name = 'Benue'), 'Ushongo', 'LGA-0140'),
-    ('e6a9b58b-8e60-45e1-8b39-5e24c45e399d', (SELECT id FROM states WHERE name = 'Benue'), 'Vandeikya', 'LGA-0141'),
-    ('cd08ce9f-c88c-4525-8a0f-a3478f699c4d', (SELECT id FROM states WHERE name = 'Borno'), 'Abadam', 'LGA-0142'),
-    ('6a7d6ee8-aa40-46d0-9163-7c8990e42c8d', (SELECT id FROM states WHERE name = 'Borno'), 'Askira/Uba', 'LGA-0143'),
-    ('f8f4b8f6-4f99-4891-a8da-e153283653c8', (SELECT id FROM states WHERE name = 'Borno'), 'Bama', 'LGA-0144'),
-    ('40cc3aa1-4ead-4e1a-bcc0-c45a1b5f8911', (SELECT id FROM states WHERE name = 'Borno'), 'Bayo', 'LGA-0145'),
-    ('240fcc72-cdee-4e3d-8bfb-2e39ed41584f', (SELECT id FROM states WHERE name = 'Borno'), 'Biu', 'LGA-0146'),
-    ('56e6c6a3-6999-4912-acce-eddcc3a1472e', (SELECT id FROM states WHERE name = 'Borno'), 'Chibok', 'LGA-0147'),
-    ('a7110e5e-4270-48f9-9baa-2f700ca716c4', (SELECT id FROM states WHERE name = 'Borno'), 'Damboa', 'LGA-0148'),
-    ('f49493c4-397c-4f6e-8661-09761306238b', (SELECT id FROM states WHERE name = 'Borno'), 'Dikwa', 'LGA-0149'),
-    ('83ab0a8a-b28b-468f-840f-a2c07ede2e13', (SELECT id FROM states WHERE name = 'Borno'), 'Gubio', 'LGA-0150'),
-    ('8e8b14bb-c375-4790-8be5-b8d98d1f0416', (SELECT id FROM states WHERE name = 'Borno'), 'Guzamala', 'LGA-0151'),
-    ('bead100c-7f1a-4d55-b510-c114457cf465', (SELECT id FROM states WHERE name = 'Borno'), 'Gwoza', 'LGA-0152'),
-    ('adc94a94-0d07-4083-950a-e3bf85a4b64c', (SELECT id FROM states WHERE name = 'Borno'), 'Hawul', 'LGA-0153'),
-    ('b0d91f99-d43b-48fa-931c-55b6156d6c47', (SELECT id FROM states WHERE name = 'Borno'), 'Jere', 'LGA-0154'),
-    ('45ea1416-bbf9-4c96-8b8f-63b6423526ba', (SELECT id FROM states WHERE name = 'Borno'), 'Kaga', 'LGA-0155'),
-    ('5506de88-fc00-4a47-aa96-54f15cea08a3', (SELECT id FROM states WHERE name = 'Borno'), 'Kala/Balge', 'LGA-0156'),
-    ('26ba2588-93fb-4d13-82d0-a9c8b4d48de8', (SELECT id FROM states WHERE name = 'Borno'), 'Konduga', 'LGA-0157'),
-    ('81952993-2199-494c-bad0-2ba65ae0172a', (SELECT id FROM states WHERE name = 'Borno'), 'Kukawa', 'LGA-0158'),
-    ('a5edac5e-178a-431a-b660-0b6798735c8e', (SELECT id FROM states WHERE name = 'Borno'), 'Kwaya Kusar', 'LGA-0159'),
-    ('d9c4cc4a-8c73-4b63-b5f5-ac240a269b66', (SELECT id FROM states WHERE name = 'Borno'), 'Mafa', 'LGA-0160'),
-    ('09275dc6-ca78-49f9-b63e-2c7d1fc03d48', (SELECT id FROM states WHERE name = 'Borno'), 'Magumeri', 'LGA-0161'),
-    ('48f4defc-057a-4575-80f6-7a6d08e2d686', (SELECT id FROM states WHERE name = 'Borno'), 'Maiduguri', 'LGA-0162'),
-    ('34c1bcdd-60c7-45fa-baec-c578240f1f06', (SELECT id FROM states WHERE name = 'Borno'), 'Marte', 'LGA-0163'),
-    ('f9db5db0-18e4-4da9-bad2-a62b2fffa41a', (SELECT id FROM states WHERE name = 'Borno'), 'Mobbar', 'LGA-0164'),
-    ('00a6a682-9a60-4adc-83eb-c322ddd1460a', (SELECT id FROM states WHERE name = 'Borno'), 'Monguno', 'LGA-0165'),
-    ('8e6af4c6-f8e9-4156-b915-a56b5d1d9175', (SELECT id FROM states WHERE name = 'Borno'), 'Ngala', 'LGA-0166'),
-    ('1e4c033f-0b86-4ec0-a950-22d5939d5712', (SELECT id FROM states WHERE name = 'Borno'), 'Nganzai', 'LGA-0167'),
-    ('5be95f29-7daf-4928-9c6a-4ada97e16a28', (SELECT id FROM states WHERE name = 'Borno'), 'Shani', 'LGA-0168'),
-    ('bc2eef74-52aa-4921-935c-9e689c563723', (SELECT id FROM states WHERE name = 'Cross River'), 'Abi', 'LGA-0169'),
-    ('1e189263-a4f5-4d78-86e8-8d18254cb191', (SELECT id FROM states WHERE name = 'Cross River'), 'Akamkpa', 'LGA-0170'),
-    ('78c88a80-2f8a-41da-af8b-21d347aa6335', (SELECT id FROM states WHERE name = 'Cross River'), 'Akpabuyo', 'LGA-0171'),
-    ('c4d7520b-c1e3-4cef-bb47-0c063df0c583', (SELECT id FROM states WHERE name = 'Cross River'), 'Bakassi', 'LGA-0172'),
-    ('81cbfc70-6c19-49a1-bb56-e98ea30d8f55', (SELECT id FROM states WHERE name = 'Cross River'), 'Bekwarra', 'LGA-0173'),
-    ('0b5261d4-c9e3-4c51-8a7b-504777de0712', (SELECT id FROM states WHERE name = 'Cross River'), 'Biase', 'LGA-0174'),
-    ('b6a890dd-3c98-4554-b8d4-9878bd4f7f9a', (SELECT id FROM states WHERE name = 'Cross River'), 'Boki', 'LGA-0175'),
-    ('49ee39b7-bd20-493d-a80b-7587c62d22e9', (SELECT id FROM states WHERE name = 'Cross River'), 'Calabar Municipal', 'LGA-0176'),
-    ('8eee3150-110d-4e85-8d24-9ceaec7d15d1', (SELECT id FROM states WHERE name = 'Cross River'), 'Calabar South', 'LGA-0177'),
-    ('7b2befa6-53db-4a0b-a478-da57222e0ca4', (SELECT id FROM states WHERE name = 'Cross River'), 'Etung', 'LGA-0178'),
-    ('a006c4fd-2719-4118-b9f3-5ea91391bba2', (SELECT id FROM states WHERE name = 'Cross River'), 'Ikom', 'LGA-0179'),
-    ('0265de8e-488a-46d5-92ba-7cd2489a7530', (SELECT id FROM states WHERE name = 'Cross River'), 'Obanliku', 'LGA-0180'),
-    ('2a828bcf-0d44-4c95-9165-d5ad9fd3c877', (SELECT id FROM states WHERE name = 'Cross River'), 'Obubra', 'LGA-0181'),
-    ('616e6b09-edbb-400a-a25c-260efd089453', (SELECT id FROM states WHERE name = 'Cross River'), 'Obudu', 'LGA-0182'),
-    ('56a1edaf-fab6-4b25-9b06-5c96fe9a0fd5', (SELECT id FROM states WHERE name = 'Cross River'), 'Odukpani', 'LGA-0183'),
-    ('4c3a04ce-f923-4e83-b8a0-b4850f36c903', (SELECT id FROM states WHERE name = 'Cross River'), 'Ogoja', 'LGA-0184'),
-    ('e9880264-4195-4e9a-962b-f7a61c635db1', (SELECT id FROM states WHERE name = 'Cross River'), 'Yakurr', 'LGA-0185'),
-    ('7be5c63d-a0a9-4921-b7cf-e393569bd7b4', (SELECT id FROM states WHERE name = 'Cross River'), 'Yala', 'LGA-0186'),
-    ('d47e9e89-8ae1-48b2-9d81-4ac3eaf71961', (SELECT id FROM states WHERE name = 'Delta'), 'Aniocha North', 'LGA-0187'),
-    ('f0b140e2-8189-4563-981f-1c79649e4a0b', (SELECT id FROM states WHERE name = 'Delta'), 'Aniocha South', 'LGA-0188'),
-    ('8bfed9d5-ff00-4e34-b54a-6a9ce0f8c205', (SELECT id FROM states WHERE name = 'Delta'), 'Bomadi', 'LGA-0189'),
-    ('53972db4-ae32-4423-a5dc-789e907a1577', (SELECT id FROM states WHERE name = 'Delta'), 'Burutu', 'LGA-0190'),
-    ('88c7300a-6ddb-44c0-a0cf-b57a5eb6245b', (SELECT id FROM states WHERE name = 'Delta'), 'Ethiope East', 'LGA-0191'),
-    ('d954db05-6233-45b5-95d8-1118cbea891a', (SELECT id FROM states WHERE name = 'Delta'), 'Ethiope West', 'LGA-0192'),
-    ('b4149618-6d9c-45a8-aba3-81b84d54eaf1', (SELECT id FROM states WHERE name = 'Delta'), 'Ika North East', 'LGA-0193'),
-    ('6010eea5-3ea6-454a-9e26-2a29167341a3', (SELECT id FROM states WHERE name = 'Delta'), 'Ika South', 'LGA-0194'),
-    ('9ffdb7e3-6a80-4121-a513-299a85db57d1', (SELECT id FROM states WHERE name = 'Delta'), 'Isoko North', 'LGA-0195'),
-    ('a858ff88-7546-4a30-b56e-b28b67d58ac7', (SELECT id FROM states WHERE name = 'Delta'), 'Isoko South', 'LGA-0196'),
-    ('dd4265bb-de3a-4555-a779-2e2c937fbbb9', (SELECT id FROM states WHERE name = 'Delta'), 'Ndokwa East', 'LGA-0197'),
-    ('02a74602-770d-44ca-9927-0bfc1dfc807f', (SELECT id FROM states WHERE name = 'Delta'), 'Ndokwa West', 'LGA-0198'),
-    ('35803871-8225-4881-bd6a-ed43a48e41ce', (SELECT id FROM states WHERE name = 'Delta'), 'Okpe', 'LGA-0199'),
-    ('aa0197e7-e828-4e3b-ab66-2f780fe77e4d', (SELECT id FROM states WHERE name = 'Delta'), 'Oshimili North', 'LGA-0200'),
-    ('5192debb-09ff-4ade-81e4-c3b42e93e842', (SELECT id FROM states WHERE name = 'Delta'), 'Oshimili South', 'LGA-0201'),
-    ('903dfa8d-70c4-4777-a46c-0b0cb4fcfef3', (SELECT id FROM states WHERE name = 'Delta'), 'Patani', 'LGA-0202'),
-    ('14b662ca-8b98-4755-9254-1019127d7585', (SELECT id FROM states WHERE name = 'Delta'), 'Sapele', 'LGA-0203'),
-    ('d6350402-5175-4410-9709-cc043ab26f05', (SELECT id FROM states WHERE name = 'Delta'), 'Udu', 'LGA-0204'),
-    ('39abf91d-7afd-4613-b4af-0fc163acd2a3', (SELECT id FROM states WHERE name = 'Delta'), 'Ughelli North', 'LGA-0205'),
-    ('b940741f-9fa7-4570-bec6-90ad7c3f06a6', (SELECT id FROM states WHERE name = 'Delta'), 'Ughelli South', 'LGA-0206'),
-    ('c6c45a65-8189-431e-8366-182d2bf541f2', (SELECT id FROM states WHERE name = 'Delta'), 'Ukwuani', 'LGA-0207'),
-    ('fc32ab10-79e6-4934-b1bc-8bed43b5fde1', (SELECT id FROM states WHERE name = 'Delta'), 'Uvwie', 'LGA-0208'),
-    ('19752825-19fa-4d02-9542-0e8552a9f97b', (SELECT id FROM states WHERE name = 'Delta'), 'Warri North', 'LGA-0209'),
-    ('c3ae6a17-55fa-416d-8147-b69e924be35e', (SELECT id FROM states WHERE name = 'Delta'), 'Warri South', 'LGA-0210'),
-    ('dc6961e4-d41f-4ff4-ab08-3d7495ce65fb', (SELECT id FROM states WHERE name = 'Delta'), 'Warri South West', 'LGA-0211'),
-    ('e3ae3ef4-0b20-404e-bcda-a9c5e3a0978c', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Abakaliki', 'LGA-0212'),
-    ('09298915-23aa-4981-8e4e-42abe52f3d80', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Afikpo North', 'LGA-0213'),
-    ('9de120a5-472b-4fc3-a0cc-09daed77d6b9', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Afikpo South', 'LGA-0214'),
-    ('6b87eb0c-127c-40cf-9182-8a736d57c599', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ebonyi', 'LGA-0215'),
-    ('97984965-8270-4ed0-a789-e8b8a6824857', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ezza North', 'LGA-0216'),
-    ('cbdbb237-5b88-4b05-a7da-689f103d4c81', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ezza South', 'LGA-0217'),
-    ('5cd18006-dfa5-46a0-8ed0-b54263128fa4', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ikwo', 'LGA-0218'),
-    ('4b0dcc69-a610-4643-83f9-c5ec64653436', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ishielu', 'LGA-0219'),
-    ('04b6bbbf-d396-4151-9518-e369ca70bcb6', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ivo', 'LGA-0220'),
-    ('1f623189-6e58-4e7c-8bf4-05cf4274d20e', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Izzi', 'LGA-0221'),
-    ('ce3bced6-e24c-4e20-8078-983f191b8813', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ohaozara', 'LGA-0222'),
-    ('6c51975f-e588-4be5-9d0c-036cf96ca54c', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ohaukwu', 'LGA-0223'),
-    ('df513e4c-4a22-4eee-9564-48c5e2a11359', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Onicha', 'LGA-0224'),
-    ('58b433b2-30c0-41fc-8fed-5c95b822b942', (SELECT id FROM states WHERE name = 'Edo'), 'Akoko-Edo', 'LGA-0225'),
-    ('5dc9a088-2518-4040-bbce-697cc821dad1', (SELECT id FROM states WHERE name = 'Edo'), 'Egor', 'LGA-0226'),
-    ('dece45dc-849f-41b6-9b3e-6a68d8b82557', (SELECT id FROM states WHERE name = 'Edo'), 'Esan Central', 'LGA-0227'),
-    ('5673a1cf-13c5-46ad-9bfd-fec5a8a33082', (SELECT id FROM states WHERE name = 'Edo'), 'Esan North-East', 'LGA-0228'),
-    ('dbfed3a3-89f8-4c5c-a8c9-addd0009e0c6', (SELECT id FROM states WHERE name = 'Edo'), 'Esan South-East', 'LGA-0229'),
-    ('5364b353-a9d8-4f90-b340-b7575b2c6daa', (SELECT id FROM states WHERE name = 'Edo'), 'Esan West', 'LGA-0230'),
-    ('98eefac1-41e4-4999-b51a-3cabb5f8d260', (SELECT id FROM states WHERE name = 'Edo'), 'Etsako Central', 'LGA-0231'),
-    ('1ffff803-ac42-4b40-8b2f-f90da92aab03', (SELECT id FROM states WHERE name = 'Edo'), 'Etsako East', 'LGA-0232'),
-    ('4ba10ab9-e695-47a2-8d07-a2e9f5de1c46', (SELECT id FROM states WHERE name = 'Edo'), 'Etsako West', 'LGA-0233'),
-    ('655585bc-3b0c-4125-9741-c83406fc9b5b', (SELECT id FROM states WHERE name = 'Edo'), 'Igueben', 'LGA-0234'),
-    ('1dba60d6-5f5e-4738-a0ad-fdfa29bf71d6', (SELECT id FROM states WHERE name = 'Edo'), 'Ikpoba Okha', 'LGA-0235'),
-    ('0c31aea0-6071-47b8-bfad-40002291fe74', (SELECT id FROM states WHERE name = 'Edo'), 'Orhionmwon', 'LGA-0236'),
-    ('111f5882-a51d-4360-9060-12d958726858', (SELECT id FROM states WHERE name = 'Edo'), 'Oredo', 'LGA-0237'),
-    ('94f07173-43ba-4b13-8532-8a2f31f54ec9', (SELECT id FROM states WHERE name = 'Edo'), 'Ovia North-East', 'LGA-0238'),
-    ('a517901c-ed72-4c1e-9556-9ba38409f650', (SELECT id FROM states WHERE name = 'Edo'), 'Ovia South-West', 'LGA-0239'),
-    ('fe8912ee-2997-456d-889f-cff9bdd8f74a', (SELECT id FROM states WHERE name = 'Edo'), 'Owan East', 'LGA-0240'),
-    ('e0979726-dc2b-4bc6-aa98-56dc68c74cc6', (SELECT id FROM states WHERE name = 'Edo'), 'Owan West', 'LGA-0241'),
-    ('329a149c-a3a5-45ec-a068-e99e415d39e3', (SELECT id FROM states WHERE name = 'Edo'), 'Uhunmwonde', 'LGA-0242'),
-    ('46726bbe-8fd6-4d0e-9fb3-62aad82c7be3', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ado Ekiti', 'LGA-0243'),
-    ('decb64ec-33a4-4510-a73a-866414b1e010', (SELECT id FROM states WHERE name = 'Ekiti'), 'Efon', 'LGA-0244'),
-    ('3343d8f8-f08a-4b32-ab18-1209f40cff25', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ekiti East', 'LGA-0245'),
-    ('dac3cc2f-d4b5-4419-9c31-86ec2c51501b', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ekiti South-West', 'LGA-0246'),
-    ('8e0d449b-1937-4b71-84a8-356212b38af2', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ekiti West', 'LGA-0247'),
-    ('d6ec2fe9-c608-4c9e-881f-8560978ed279', (SELECT id FROM states WHERE name = 'Ekiti'), 'Emure', 'LGA-0248'),
-    ('7b968686-9af9-4d2a-b0c7-e4bbcd843648', (SELECT id FROM states WHERE name = 'Ekiti'), 'Gbonyin', 'LGA-0249'),
-    ('f41dc054-ca05-434d-b1a0-8d66160b0e95', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ido Osi', 'LGA-0250'),
-    ('766d611e-1473-41d3-9688-b51a621b382a', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ijero', 'LGA-0251'),
-    ('d4997a8e-e005-42f8-9f24-fc58898a2d54', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ikere', 'LGA-0252'),
-    ('2a80aa10-3cc1-4a99-b319-eb2101e3667a', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ikole', 'LGA-0253'),
-    ('917ac848-e182-4044-bdf0-339340490b18', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ilejemeje', 'LGA-0254'),
-    ('1de8b8a0-9499-4190-9d96-0950334f268f', (SELECT id FROM states WHERE name = 'Ekiti'), 'Irepodun/Ifelodun', 'LGA-0255'),
-    ('f8b338f6-b1ea-404f-8eb3-886d26df5ec0', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ise/Orun', 'LGA-0256'),
-    ('c68467f7-9cc9-40c7-a741-cc573dbb680f', (SELECT id FROM states WHERE name = 'Ekiti'), 'Moba', 'LGA-0257'),
-    ('9bf53706-f2ea-48c0-ae24-94f3a27c2b31', (SELECT id FROM states WHERE name = 'Ekiti'), 'Oye', 'LGA-0258'),
-    ('02777634-5eb6-4efe-9cf1-8672aa4e91bb', (SELECT id FROM states WHERE name = 'Enugu'), 'Aninri', 'LGA-0259'),
-    ('d1e77a65-a5e7-45fd-93c6-aa9ea9280971', (SELECT id FROM states WHERE name = 'Enugu'), 'Awgu', 'LGA-0260'),
-    ('9c704b7c-7e8b-4a8b-a84d-57518bcd6fa3', (SELECT id FROM states WHERE name = 'Enugu'), 'Enugu East', 'LGA-0261'),
-    ('25af6f2e-adc7-4cc6-8359-cc1f39053d54', (SELECT id FROM states WHERE name = 'Enugu'), 'Enugu North', 'LGA-0262'),
-    ('2938bc94-f32e-4a3b-a786-1918db215f71', (SELECT id FROM states WHERE name = 'Enugu'), 'Enugu South', 'LGA-0263'),
-    ('430e883e-1d99-438b-ba36-2ea3b57f87bf', (SELECT id FROM states WHERE name = 'Enugu'), 'Ezeagu', 'LGA-0264'),
-    ('2a98a916-116a-48c5-beb2-e2da1bdd3c0e', (SELECT id FROM states WHERE name = 'Enugu'), 'Igbo Etiti', 'LGA-0265'),
-    ('803aeb3c-2994-4120-938c-9beb237655c5', (SELECT id FROM states WHERE name = 'Enugu'), 'Igbo Eze North', 'LGA-0266'),
-    ('9fef5464-036c-4a59-81e4-f5b83c46cd16', (SELECT id FROM states WHERE name = 'Enugu'), 'Igbo Eze South', 'LGA-0267'),
-    ('64e45298-85f0-4e8d-9ed8-ed91270b8652', (SELECT id FROM states WHERE name = 'Enugu'), 'Isi Uzo', 'LGA-0268'),
-    ('6b4e0696-6166-49dc-8636-b489a15da877', (SELECT id FROM states WHERE name = 'Enugu'), 'Nkanu East', 'LGA-0269'),
-    ('976cd4c6-a2a7-4edb-a690-499c3c0f6635', (SELECT id FROM states WHERE name = 'Enugu'), 'Nkanu West', 'LGA-0270'),
-    ('d3b92a8e-9d51-4439-a690-a11731e29420', (SELECT id FROM states WHERE name = 'Enugu'), 'Nsukka', 'LGA-0271'),
-    ('e32c53db-5d3a-4fed-8a5e-d7413ddc1c15', (SELECT id FROM states WHERE name = 'Enugu'), 'Oji River', 'LGA-0272'),
-    ('4be84b3c-7626-4ece-8b28-7527573865af', (SELECT id FROM states WHERE name = 'Enugu'), 'Udenu', 'LGA-0273'),
-    ('3571b128-bc48-42bb-a9d2-f263cb8e1b31', (SELECT id FROM states WHERE name = 'Enugu'), 'Udi', 'LGA-0274'),
-    ('41930f24-a11f-4fdb-9974-c310ac02ffea', (SELECT id FROM states WHERE name = 'Enugu'), 'Uzo-Uwani', 'LGA-0275'),
-    ('2e6ba1d8-e283-4e7c-b438-89027b1b18b5', (SELECT id FROM states WHERE name = 'Abuja'), 'Abaji', 'LGA-0276'),
-    ('21bac160-7164-440f-962e-cdf8d1543d61', (SELECT id FROM states WHERE name = 'Abuja'), 'Abuja Municipal', 'LGA-0277'),
-    ('aabd4fef-c16f-4e01-96f9-ee0fbe8fe9fc', (SELECT id FROM states WHERE name = 'Abuja'), 'Bwari', 'LGA-0278'),
-    ('5bb38b1d-c655-4ac8-bc46-2fe735be1d69', (SELECT id FROM states WHERE name = 'Abuja'), 'Gwagwalada', 'LGA-0279'),
-    ('db994369-d181-4fb8-a2b8-ffc4fa559dfa', (SELECT id FROM states WHERE name = 'Abuja'), 'Kuje', 'LGA-0280'),
-    ('50d1f936-404d-4ff5-b304-1175ae92dcfb', (SELECT id FROM states WHERE name = 'Abuja'), 'Kwali', 'LGA-0281'),
-    ('16eb634c-2783-44de-93fb-1c0d29c82cd1', (SELECT id FROM states WHERE name = 'Gombe'), 'Akko', 'LGA-0282'),
-    ('a932397b-1826-4226-a9bb-06f55d71226c', (SELECT id FROM states WHERE name = 'Gombe'), 'Balanga', 'LGA-0283'),
-    ('bf406720-8ddb-4b43-9e76-672cb593738e', (SELECT id FROM states WHERE name = 'Gombe'), 'Billiri', 'LGA-0284'),
-    ('4ef7fccb-a3a5-48a9-8811-e97d2b2b3473', (SELECT id FROM states WHERE name = 'Gombe'), 'Dukku', 'LGA-0285'),
-    ('aaa3716b-f8eb-443a-afb9-cac8b885818f', (SELECT id FROM states WHERE name = 'Gombe'), 'Funakaye', 'LGA-0286'),
-    ('b47c2cff-ae88-44b3-bf1e-7e214d5f0bc3', (SELECT id FROM states WHERE name = 'Gombe'), 'Gombe', 'LGA-0287'),
-    ('b88341b6-1f60-41a8-aeef-518aad52773b', (SELECT id FROM states WHERE name = 'Gombe'), 'Kaltungo', 'LGA-0288'),
-    ('0ef7e64e-ee66-4dd8-a271-4c46db139d1d', (SELECT id FROM states WHERE name = 'Gombe'), 'Kwami', 'LGA-0289'),
-    ('40e463d3-15ea-468d-ac88-67da2ee0f198', (SELECT id FROM states WHERE name = 'Gombe'), 'Nafada', 'LGA-0290'),
-    ('c37db810-9769-4461-942e-8afeb037e4be', (SELECT id FROM states WHERE name = 'Gombe'), 'Shongom', 'LGA-0291'),
-    ('94d894d4-6dcc-4a79-80c3-6e9900a108e4', (SELECT id FROM states WHERE name = 'Gombe'), 'Yamaltu/Deba', 'LGA-0292'),
-    ('16810ca4-b65e-42a2-a24f-ee802d9cacf3', (SELECT id FROM states WHERE name = 'Imo'), 'Aboh Mbaise', 'LGA-0293'),
-    ('76aa451b-8df2-4ea6-94f5-a65e12d82473', (SELECT id FROM states WHERE name = 'Imo'), 'Ahiazu Mbaise', 'LGA-0294'),
-    ('7d846a63-35b2-4655-b11b-0d125d0bbfae', (SELECT id FROM states WHERE name = 'Imo'), 'Ehime Mbano', 'LGA-0295'),
-    ('481efa55-702e-4b6c-a329-a4eeba401d4f', (SELECT id FROM states WHERE name = 'Imo'), 'Ezinihitte', 'LGA-0296'),
-    ('ce1e61d3-f24a-486c-9669-9513fde855b8', (SELECT id FROM states WHERE name = 'Imo'), 'Ideato North', 'LGA-0297'),
-    ('3745cdd1-cc58-4327-b96a-b7794f68b7dd', (SELECT id FROM states WHERE name = 'Imo'), 'Ideato South', 'LGA-0298'),
-    ('8166fdd1-d6b5-4f9e-a5fd-c3a852b9352b', (SELECT id FROM states WHERE name = 'Imo'), 'Ihitte/Uboma', 'LGA-0299'),
-    ('f352196d-c9e2-4eec-8c6e-fd1e92b8845e', (SELECT id FROM states WHERE name = 'Imo'), 'Ikeduru', 'LGA-0300'),
-    ('483d88b4-e61b-4dcc-8314-6b116c7f0de0', (SELECT id FROM states WHERE name = 'Imo'), 'Isiala Mbano', 'LGA-0301'),
-    ('2758bed7-f487-437e-a36e-fa76173c6eaa', (SELECT id FROM states WHERE name = 'Imo'), 'Isu', 'LGA-0302'),
-    ('92322c36-3566-4a79-aae0-f1e9a499aee1', (SELECT id FROM states WHERE name = 'Imo'), 'Mbaitoli', 'LGA-0303'),
-    ('a2837319-3719-422a-aab7-90f97c3d14ff', (SELECT id FROM states WHERE name = 'Imo'), 'Ngor Okpala', 'LGA-0304'),
-    ('15065f5a-8b82-4999-9066-2cdc32fb7ae6', (SELECT id FROM states WHERE name = 'Imo'), 'Njaba', 'LGA-0305'),
-    ('582c7d7a-50ad-4092-a610-a94c873528dc', (SELECT id FROM states WHERE name = 'Imo'), 'Nkwerre', 'LGA-0306'),
-    ('c0ee5c6d-280f-4b24-892b-8bfef983e916', (SELECT id FROM states WHERE name = 'Imo'), 'Nwangele', 'LGA-0307'),
-    ('b7d93c51-5dca-4a80-9297-e016e42df5bc', (SELECT id FROM states WHERE name = 'Imo'), 'Obowo', 'LGA-0308'),
-    ('26c187a0-2144-4c17-ba8c-4122b56b3ce2', (SELECT id FROM states WHERE name = 'Imo'), 'Oguta', 'LGA-0309'),
-    ('a5787c46-1055-4a00-8f86-eb6303cd5865', (SELECT id FROM states WHERE name = 'Imo'), 'Ohaji/Egbema', 'LGA-0310'),
-    ('08b8cd62-2283-4747-bf3e-46617ea23247', (SELECT id FROM states WHERE name = 'Imo'), 'Okigwe', 'LGA-0311'),
-    ('058f00fc-3da9-4128-a37a-a435546afe33', (SELECT id FROM states WHERE name = 'Imo'), 'Orlu', 'LGA-0312'),
-    ('d447c54d-ef90-4ebb-99ba-292a18b88409', (SELECT id FROM states WHERE name = 'Imo'), 'Orsu', 'LGA-0313'),
-    ('af9d0455-b160-479e-bf11-a7fb35f28842', (SELECT id FROM states WHERE name = 'Imo'), 'Oru East', 'LGA-0314'),
-    ('9dbf4db5-a7a9-4d17-8126-3537fbf04964', (SELECT id FROM states WHERE name = 'Imo'), 'Oru West', 'LGA-0315'),
-    ('be5ce398-372f-461d-b334-7f42af7d97f1', (SELECT id FROM states WHERE name = 'Imo'), 'Owerri Municipal', 'LGA-0316'),
-    ('d19badef-6c45-4338-8fbc-9d5d5cc4594b', (SELECT id FROM states WHERE name = 'Imo'), 'Owerri North', 'LGA-0317'),
-    ('5b181bc0-9ea5-46e6-ab6a-418f78af8843', (SELECT id FROM states WHERE name = 'Imo'), 'Owerri West', 'LGA-0318'),
-    ('5a894dd0-45e8-444a-856c-2cd8d64c65d6', (SELECT id FROM states WHERE name = 'Imo'), 'Onuimo', 'LGA-0319'),
-    ('e189eab1-016e-4809-9aaa-407d1e55cca1', (SELECT id FROM states WHERE name = 'Jigawa'), 'Auyo', 'LGA-0320'),
-    ('19fcd145-57ea-4944-bc3d-f53241181811', (SELECT id FROM states WHERE name = 'Jigawa'), 'Babura', 'LGA-0321'),
-    ('6f9bf6e5-27af-4ae3-bfc6-12fcff4da284', (SELECT id FROM states WHERE name = 'Jigawa'), 'Birniwa', 'LGA-0322'),
-    ('022e3e4d-33e5-42dd-8b88-197999b93a66', (SELECT id FROM states WHERE name = 'Jigawa'), 'Birnin Kudu', 'LGA-0323'),
-    ('2c696dce-11e5-460b-b1cd-9dda37c7c713', (SELECT id FROM states WHERE name = 'Jigawa'), 'Buji', 'LGA-0324'),
-    ('f1693066-0f63-43c9-a2f7-280084f64767', (SELECT id FROM states WHERE name = 'Jigawa'), 'Dutse', 'LGA-0325'),
-    ('7615cdde-8e2d-4f08-a47f-4c50b7ccf307', (SELECT id FROM states WHERE name = 'Jigawa'), 'Gagarawa', 'LGA-0326'),
-    ('a6734416-81b0-4432-bf34-295d5019f061', (SELECT id FROM states WHERE name = 'Jigawa'), 'Garki', 'LGA-0327'),
-    ('dc6728e4-2ae6-4584-9115-397c88046321', (SELECT id FROM states WHERE name = 'Jigawa'), 'Gumel', 'LGA-0328'),
-    ('78244d5f-7d4b-4b2e-accc-58b7b0eb4087', (SELECT id FROM states WHERE name = 'Jigawa'), 'Guri', 'LGA-0329'),
-    ('7a750bca-3a7b-4bf1-8056-84f76ce9b07c', (SELECT id FROM states WHERE name = 'Jigawa'), 'Gwaram', 'LGA-0330'),
-    ('2de4702a-fddc-4044-89ee-dc6351f00cad', (SELECT id FROM states WHERE name = 'Jigawa'), 'Gwiwa', 'LGA-0331'),
-    ('b8cbe2cf-6fb7-4190-a82e-2558997cdaa0', (SELECT id FROM states WHERE name = 'Jigawa'), 'Hadejia', 'LGA-0332'),
-    ('3a57870e-775d-4886-8066-58b7b4572353', (SELECT id FROM states WHERE name = 'Jigawa'), 'Jahun', 'LGA-0333'),
-    ('fb16c223-d91b-49fa-ac47-2111b56e2a4a', (SELECT id FROM states WHERE name = 'Jigawa'), 'Kafin Hausa', 'LGA-0334'),
-    ('58bd7721-f2b1-4c07-a1ea-2b46eb110c00', (SELECT id FROM states WHERE name = 'Jigawa'), 'Kaugama', 'LGA-0335'),
-    ('c8b54262-4397-438d-9b5e-de48a05249ee', (SELECT id FROM states WHERE name = 'Jigawa'), 'Kazaure', 'LGA-0336'),
-    ('66934f5c-9286-445b-a6ff-c7ccde12c2ef', (SELECT id FROM states WHERE name = 'Jigawa'), 'Kiri Kasama', 'LGA-0337'),
-    ('dae682f9-2bf4-4d2a-9475-2d1263eee1d3', (SELECT id FROM states WHERE name = 'Jigawa'), 'Kiyawa', 'LGA-0338'),
-    ('5f3abf53-88e9-4854-8cf6-73fbb0bdad35', (SELECT id FROM states WHERE name = 'Jigawa'), 'Maigatari', 'LGA-0339'),
-    ('60add559-68f6-4f60-a616-eda625101727', (SELECT id FROM states WHERE name = 'Jigawa'), 'Malam Madori', 'LGA-0340'),
-    ('d8a484a7-ebb0-45e6-a72e-02ed6dc6ea6c', (SELECT id FROM states WHERE name = 'Jigawa'), 'Miga', 'LGA-0341'),
-    ('3fb58302-425a-484f-b923-19c94b0d7180', (SELECT id FROM states WHERE name = 'Jigawa'), 'Ringim', 'LGA-0342'),
-    ('942ddfe8-faed-45f4-9d25-b02ffe88f36a', (SELECT id FROM states WHERE name = 'Jigawa'), 'Roni', 'LGA-0343'),
-    ('07c23a00-dbc4-4cc1-8cd5-09a1ce74f4ec', (SELECT id FROM states WHERE name = 'Jigawa'), 'Sule Tankarkar', 'LGA-0344'),
-    ('582e88ed-c9d1-4040-b91e-cd4f4e374804', (SELECT id FROM states WHERE name = 'Jigawa'), 'Taura', 'LGA-0345'),
-    ('0209649d-41ea-4c4d-9776-33f15273726a', (SELECT id FROM states WHERE name = 'Jigawa'), 'Yankwashi', 'LGA-0346'),
-    ('350017b8-a87d-4fff-88ae-d5138a626f28', (SELECT id FROM states WHERE name = 'Kaduna'), 'Birnin Gwari', 'LGA-0347'),
-    ('23bc6ed7-b35f-43f3-9b73-f4c50f8d6698', (SELECT id FROM states WHERE name = 'Kaduna'), 'Chikun', 'LGA-0348'),
-    ('3161e840-a2fc-4fe1-85e7-37069446b792', (SELECT id FROM states WHERE name = 'Kaduna'), 'Giwa', 'LGA-0349'),
-    ('8efd91d9-e95d-4090-8951-af84cc61f7bf', (SELECT id FROM states WHERE name = 'Kaduna'), 'Igabi', 'LGA-0350'),
-    ('236984ed-1b22-4f7e-a330-6e73b7040837', (SELECT id FROM states WHERE name = 'Kaduna'), 'Ikara', 'LGA-0351'),
-    ('f86f7aa2-d277-4cca-9353-7de389dbeefd', (SELECT id FROM states WHERE name = 'Kaduna'), 'Jaba', 'LGA-0352'),
-    ('24552b8d-6236-4215-9999-671a7c5ebabc', (SELECT id FROM states WHERE name = 'Kaduna'), 'Jema''a', 'LGA-0353'),
-    ('5f6d87f8-c3f1-41af-b956-5a4b92358c26', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kachia', 'LGA-0354'),
-    ('372b6bb8-30b7-451f-b7fd-7c4072dbd57b', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kaduna North', 'LGA-0355'),
-    ('cb409362-9986-48c2-af9f-061e04a8f884', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kaduna South', 'LGA-0356'),
-    ('c200d6cc-419f-4159-9878-1a02f54fa3bd', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kagarko', 'LGA-0357'),
-    ('41226ed2-3d18-48fe-8ace-96d233642dfb', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kajuru', 'LGA-0358'),
-    ('c96c8f32-5fc2-4ffa-b958-620a9e9b566e', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kaura', 'LGA-0359'),
-    ('863432b4-88f2-4eb8-91ed-fbc80d5bca2a', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kauru', 'LGA-0360'),
-    ('7d51da38-2ee2-454d-a6af-2a2c6d83a830', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kubau', 'LGA-0361'),
-    ('6ca3b35d-bc71-4493-831e-dbfa5fec1267', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kudan', 'LGA-0362'),
-    ('d6b79972-efc1-4c5b-9e04-47bcb467a94b', (SELECT id FROM states WHERE name = 'Kaduna'), 'Lere', 'LGA-0363'),
-    ('a4bb9f41-3edb-47ac-8394-ccd8ba2c9ad9', (SELECT id FROM states WHERE name = 'Kaduna'), 'Makarfi', 'LGA-0364'),
-    ('cb6e4bd3-6969-4171-8ba1-bb9764f0d8aa', (SELECT id FROM states WHERE name = 'Kaduna'), 'Sabon Gari', 'LGA-0365'),
-    ('79c88efc-be42-4cd1-9d64-1bb13e5e8c13', (SELECT id FROM states WHERE name = 'Kaduna'), 'Sanga', 'LGA-0366'),
-    ('64454eaa-1ca9-494e-adb2-ecf92f73dce7', (SELECT id FROM states WHERE name = 'Kaduna'), 'Soba', 'LGA-0367'),
-    ('6b8a1b54-f4d8-4c96-85f1-0618419034f4', (SELECT id FROM states WHERE name = 'Kaduna'), 'Zangon Kataf', 'LGA-0368'),
-    ('6180afdc-fac1-418a-b8ee-c1fd571dfa0b', (SELECT id FROM states WHERE name = 'Kaduna'), 'Zaria', 'LGA-0369'),
-    ('e35b3d5a-240e-424d-b927-9be60c053468', (SELECT id FROM states WHERE name = 'Kano'), 'Ajingi', 'LGA-0370'),
-    ('2e34e281-4967-4025-86da-e28b176963f1', (SELECT id FROM states WHERE name = 'Kano'), 'Albasu', 'LGA-0371'),
-    ('9b75ca97-ea00-4371-9ec7-1d8c0a214f3e', (SELECT id FROM states WHERE name = 'Kano'), 'Bagwai', 'LGA-0372'),
-    ('70fcc5a0-ec5b-47a5-9e7d-6e64c8a1abf9', (SELECT id FROM states WHERE name = 'Kano'), 'Bebeji', 'LGA-0373'),
-    ('dfe0c7c9-de0f-4de3-9a80-2cb02b84cd30', (SELECT id FROM states WHERE name = 'Kano'), 'Bichi', 'LGA-0374'),
-    ('6fc22b76-3c4c-416a-b303-60faf6874521', (SELECT id FROM states WHERE name = 'Kano'), 'Bunkure', 'LGA-0375'),
-    ('5a7bd1e2-a0ef-4255-8528-11ce64f81aae', (SELECT id FROM states WHERE name = 'Kano'), 'Dala', 'LGA-0376'),
-    ('9327cf7c-849d-44d4-9f33-433b5716391e', (SELECT id FROM states WHERE name = 'Kano'), 'Dambatta', 'LGA-0377'),
-    ('5a69e063-284a-4412-a83e-64ae3b5fc6ac', (SELECT id FROM states WHERE name = 'Kano'), 'Dawakin Kudu', 'LGA-0378'),
-    ('d493b793-9b81-457c-9527-11972dc6e2d4', (SELECT id FROM states WHERE name = 'Kano'), 'Dawakin Tofa', 'LGA-0379'),
-    ('d4d7844c-c355-4a5f-9344-201b6feccc39', (SELECT id FROM states WHERE name = 'Kano'), 'Doguwa', 'LGA-0380'),
-    ('c4c61712-0d9c-4ff1-b1b3-887741bb30e8', (SELECT id FROM states WHERE name = 'Kano'), 'Fagge', 'LGA-0381'),
-    ('0009d55a-52f1-48a6-84f6-1b00215a9dc1', (SELECT id FROM states WHERE name = 'Kano'), 'Gabasawa', 'LGA-0382'),
-    ('85691289-f320-4158-ba2e-bca552fa93cc', (SELECT id FROM states WHERE name = 'Kano'), 'Garko', 'LGA-0383'),
-    ('831e9691-3be7-4dbc-9499-5e030871054c', (SELECT id FROM states WHERE name = 'Kano'), 'Garun Mallam', 'LGA-0384'),
-    ('3ba204bb-050c-405f-a392-acee7a3c72d7', (SELECT id FROM states WHERE name = 'Kano'), 'Gaya', 'LGA-0385'),
-    ('39356363-7459-4c88-a1c6-d1ba237b8991', (SELECT id FROM states WHERE name = 'Kano'), 'Gezawa', 'LGA-0386'),
-    ('260a0107-af12-4af0-82da-899c0b211a84', (SELECT id FROM states WHERE name = 'Kano'), 'Gwale', 'LGA-0387'),
-    ('805f5d70-f00a-42f7-bb9d-87eddc7de39b', (SELECT id FROM states WHERE name = 'Kano'), 'Gwarzo', 'LGA-0388'),
-    ('264645c7-a24f-4cce-bb62-18028ff965dc', (SELECT id FROM states WHERE name = 'Kano'), 'Kabo', 'LGA-0389'),
-    ('77a273e8-25bd-453f-9ecd-65a390de1c98', (SELECT id FROM states WHERE name = 'Kano'), 'Kano Municipal', 'LGA-0390'),
-    ('d9eec56b-0da8-4990-a091-78616f255e26', (SELECT id FROM states WHERE name = 'Kano'), 'Karaye', 'LGA-0391'),
-    ('8de11e09-89a9-4165-96c3-15bf38da8dc8', (SELECT id FROM states WHERE name = 'Kano'), 'Kibiya', 'LGA-0392'),
-    ('5cd6c0ea-cb85-4ff1-b482-701a199ad47a', (SELECT id FROM states WHERE name = 'Kano'), 'Kiru', 'LGA-0393'),
-    ('0486679a-94ce-4a97-b593-12a86219f83b', (SELECT id FROM states WHERE name = 'Kano'), 'Kumbotso', 'LGA-0394'),
-    ('08553487-d741-47f1-8894-3272827b8fe0', (SELECT id FROM states WHERE name = 'Kano'), 'Kunchi', 'LGA-0395'),
-    ('f2ba4c22-d872-4296-aba1-a5e370020077', (SELECT id FROM states WHERE name = 'Kano'), 'Kura', 'LGA-0396'),
-    ('e9b0830f-d62d-4792-92e8-327f3652bb92', (SELECT id FROM states WHERE name = 'Kano'), 'Madobi', 'LGA-0397'),
-    ('5307db79-66b2-4f9b-97d8-55583867a1f5', (SELECT id FROM states WHERE name = 'Kano'), 'Makoda', 'LGA-0398'),
-    ('afa0784d-7057-4d70-8f01-c8c84c9983a1', (SELECT id FROM states WHERE name = 'Kano'), 'Minjibir', 'LGA-0399'),
-    ('4d032b5a-af4c-427c-a545-162fc408cbde', (SELECT id FROM states WHERE name = 'Kano'), 'Nasarawa', 'LGA-0400'),
-    ('25677370-ce07-4392-a89f-4aaf7fd67c95', (SELECT id FROM states WHERE name = 'Kano'), 'Rano', 'LGA-0401'),
-    ('08224615-35b4-4542-8637-07183a4bc4eb', (SELECT id FROM states WHERE name = 'Kano'), 'Rimin Gado', 'LGA-0402'),
-    ('62e54e24-9cfa-4dac-bced-0b2076055d80', (SELECT id FROM states WHERE name = 'Kano'), 'Rogo', 'LGA-0403'),
-    ('f866a43c-1f81-472e-bf6d-9773402d4bbf', (SELECT id FROM states WHERE name = 'Kano'), 'Shanono', 'LGA-0404'),
-    ('508bb62f-6b81-48bd-8db9-a5a48a0eaddc', (SELECT id FROM states WHERE name = 'Kano'), 'Sumaila', 'LGA-0405'),
-    ('492c7d5d-63a0-436f-ae0a-5df45be1543c', (SELECT id FROM states WHERE name = 'Kano'), 'Takai', 'LGA-0406'),
-    ('6d826a95-4cf2-4f23-a4c1-f0315d8f331b', (SELECT id FROM states WHERE name = 'Kano'), 'Tarauni', 'LGA-0407'),
-    ('a771a9fd-ca13-4ee2-ae0b-02a48a4d9d20', (SELECT id FROM states WHERE name = 'Kano'), 'Tofa', 'LGA-0408'),
-    ('219c907f-3de1-4e75-a3c0-b4334b266045', (SELECT id FROM states WHERE name = 'Kano'), 'Tsanyawa', 'LGA-0409'),
-    ('2f51bd47-9e86-4e1b-b674-0b976d2833c0', (SELECT id FROM states WHERE name = 'Kano'), 'Tudun Wada', 'LGA-0410'),
-    ('0e918b27-421b-4e8b-9f60-f76a7d38979d', (SELECT id FROM states WHERE name = 'Kano'), 'Ungogo', 'LGA-0411'),
-    ('472d7a21-84c8-44fd-b162-bff0cd9def5c', (SELECT id FROM states WHERE name = 'Kano'), 'Warawa', 'LGA-0412'),
-    ('6a90f3ab-29bf-40d0-9f55-21b750c21ee2', (SELECT id FROM states WHERE name = 'Kano'), 'Wudil', 'LGA-0413'),
-    ('0916833e-22c5-4fc4-b4bf-94187b1f36b8', (SELECT id FROM states WHERE name = 'Katsina'), 'Bakori', 'LGA-0414'),
-    ('4011496e-f3f0-4fc0-a358-9304cb725ef3', (SELECT id FROM states WHERE name = 'Katsina'), 'Batagarawa', 'LGA-0415'),
-    ('34435646-1a8a-4ab6-8d61-fbaaf59ef4a2', (SELECT id FROM states WHERE name = 'Katsina'), 'Batsari', 'LGA-0416'),
-    ('8827be2e-9f93-44ff-a1e4-3d72fc378c62', (SELECT id FROM states WHERE name = 'Katsina'), 'Baure', 'LGA-0417'),
-    ('1ff324b7-6720-4f0c-8871-e5c7a3764baf', (SELECT id FROM states WHERE name = 'Katsina'), 'Bindawa', 'LGA-0418'),
-    ('74950c62-2b40-4dab-b249-3de1702d9409', (SELECT id FROM states WHERE name = 'Katsina'), 'Charanchi', 'LGA-0419'),
-    ('8325a45a-cde1-4f32-91b0-7d1a434637ac', (SELECT id FROM states WHERE name = 'Katsina'), 'Dandume', 'LGA-0420'),
-    ('03f8baa0-0f97-4dd4-8d94-c15192d99dc7', (SELECT id FROM states WHERE name = 'Katsina'), 'Danja', 'LGA-0421'),
-    ('e5b8c8c8-fc93-434d-9890-704df1686f71', (SELECT id FROM states WHERE name = 'Katsina'), 'Dan Musa', 'LGA-0422'),
-    ('43359c38-e3f0-4d49-aaa0-25bc61c23862', (SELECT id FROM states WHERE name = 'Katsina'), 'Daura', 'LGA-0423'),
-    ('bcbc1e4a-a639-4791-9778-0485594dedc5', (SELECT id FROM states WHERE name = 'Katsina'), 'Dutsi', 'LGA-0424'),
-    ('e46b6d63-b36e-4e4b-9772-c9ec96c6f497', (SELECT id FROM states WHERE name = 'Katsina'), 'Dutsin Ma', 'LGA-0425'),
-    ('c9d66507-c2d3-4e38-9989-6a682eb5d075', (SELECT id FROM states WHERE name = 'Katsina'), 'Faskari', 'LGA-0426'),
-    ('49c21b54-6502-4801-929a-96460317ac0a', (SELECT id FROM states WHERE name = 'Katsina'), 'Funtua', 'LGA-0427'),
-    ('a5686a25-3f42-44f6-b88b-5dde39b769ab', (SELECT id FROM states WHERE name = 'Katsina'), 'Ingawa', 'LGA-0428'),
-    ('f28f1746-a9ea-48e9-ab68-c891c0e80a0a', (SELECT id FROM states WHERE name = 'Katsina'), 'Jibia', 'LGA-0429'),
-    ('b904e33a-b74b-47ed-9612-71a1e08eb14e', (SELECT id FROM states WHERE name = 'Katsina'), 'Kafur', 'LGA-0430'),
-    ('991f4bab-93bf-49d5-a5d0-fdd9761b5a0e', (SELECT id FROM states WHERE name = 'Katsina'), 'Kaita', 'LGA-0431'),
-    ('cdb5562e-2376-4683-b989-fdf8f8e16ecc', (SELECT id FROM states WHERE name = 'Katsina'), 'Kankara', 'LGA-0432'),
-    ('493c3e11-8cff-4857-a721-4396b30dd913', (SELECT id FROM states WHERE name = 'Katsina'), 'Kankia', 'LGA-0433'),
-    ('e9cec069-b0bb-4046-aa88-d09ef3d6b401', (SELECT id FROM states WHERE name = 'Katsina'), 'Katsina', 'LGA-0434'),
-    ('00d0e4f5-e5fc-45af-81b8-6be2448b4f31', (SELECT id FROM states WHERE name = 'Katsina'), 'Kurfi', 'LGA-0435'),
-    ('04bd20df-147a-49ae-9e88-c39720daad8c', (SELECT id FROM states WHERE name = 'Katsina'), 'Kusada', 'LGA-0436'),
-    ('360ec37b-41fe-4397-a6ae-8dda1c8ef9f5', (SELECT id FROM states WHERE name = 'Katsina'), 'Mai''Adua', 'LGA-0437'),
-    ('ded8727a-5fb0-4a1c-be75-d7aac8d57db3', (SELECT id FROM states WHERE name = 'Katsina'), 'Malumfashi', 'LGA-0438'),
-    ('79b5a26d-1edb-4a7a-9e2b-4512af1cffac', (SELECT id FROM states WHERE name = 'Katsina'), 'Mani', 'LGA-0439'),
-    ('a6424677-39eb-462b-9a35-57b1766da513', (SELECT id FROM states WHERE name = 'Katsina'), 'Mashi', 'LGA-0440'),
-    ('f4ca96fb-3818-4745-ae40-2690f49e8a0e', (SELECT id FROM states WHERE name = 'Katsina'), 'Matazu', 'LGA-0441'),
-    ('001cbc4c-e830-416d-8b4f-b423cae39968', (SELECT id FROM states WHERE name = 'Katsina'), 'Musawa', 'LGA-0442'),
-    ('405a9f33-ffb5-47de-be8d-542af5b5c422', (SELECT id FROM states WHERE name = 'Katsina'), 'Rimi', 'LGA-0443'),
-    ('29ca1204-541f-4f84-943b-b842a99616c7', (SELECT id FROM states WHERE name = 'Katsina'), 'Sabuwa', 'LGA-0444'),
-    ('0fe9bd53-e37c-4055-91e5-c69361cc2b70', (SELECT id FROM states WHERE name = 'Katsina'), 'Safana', 'LGA-0445'),
-    ('4dc77ef9-274e-4949-9d45-bcd434ff4ab0', (SELECT id FROM states WHERE name = 'Katsina'), 'Sandamu', 'LGA-0446'),
-    ('09b7dc1e-e08e-4ed9-9bd1-383d96dea909', (SELECT id FROM states WHERE name = 'Katsina'), 'Zango', 'LGA-0447'),
-    ('4df5897d-5088-41ca-b248-f1f51b5aaf85', (SELECT id FROM states WHERE name = 'Kebbi'), 'Aleiro', 'LGA-0448'),
-    ('3890fbd6-1173-4965-92f3-76f708c7eda7', (SELECT id FROM states WHERE name = 'Kebbi'), 'Arewa', 'LGA-0449'),
-    ('a4315329-b998-4cb3-a4ad-bb8c535d2911', (SELECT id FROM states WHERE name = 'Kebbi'), 'Argungu', 'LGA-0450'),
-    ('513c790b-66e6-44e8-b2d6-6a008eedf633', (SELECT id FROM states WHERE name = 'Kebbi'), 'Augie', 'LGA-0451'),
-    ('d4d06902-e527-49ea-804e-7928b8d6a718', (SELECT id FROM states WHERE name = 'Kebbi'), 'Bagudo', 'LGA-0452'),
-    ('a81f474b-b67b-4055-9cde-42721f66fcde', (SELECT id FROM states WHERE name = 'Kebbi'), 'Birnin Kebbi', 'LGA-0453'),
-    ('e87b2a22-61f7-4ff3-8ceb-ee43a92b1f03', (SELECT id FROM states WHERE name = 'Kebbi'), 'Bunza', 'LGA-0454'),
-    ('0e14dc87-83e1-4017-b73f-3d98fce1081f', (SELECT id FROM states WHERE name = 'Kebbi'), 'Dandi', 'LGA-0455'),
-    ('6945164f-0baf-4a2c-85b8-b3101949f9d8', (SELECT id FROM states WHERE name = 'Kebbi'), 'Fakai', 'LGA-0456'),
-    ('8b9fc2a3-45ef-4f36-968d-04df5ab90902', (SELECT id FROM states WHERE name = 'Kebbi'), 'Gwandu', 'LGA-0457'),
-    ('400036dc-d92e-46b1-a1b5-a77520acc8e4', (SELECT id FROM states WHERE name = 'Kebbi'), 'Jega', 'LGA-0458'),
-    ('0c03d353-16ab-4ec1-a2d7-f401e903497b', (SELECT id FROM states WHERE name = 'Kebbi'), 'Kalgo', 'LGA-0459'),
-    ('5179b12f-325c-4f15-87f5-4c4f51ede703', (SELECT id FROM states WHERE name = 'Kebbi'), 'Koko/Besse', 'LGA-0460'),
-    ('0c276cf9-6481-4045-8e2f-36584928b9ba', (SELECT id FROM states WHERE name = 'Kebbi'), 'Maiyama', 'LGA-0461'),
-    ('61558b52-b701-4268-9b92-7ed0054e5070', (SELECT id FROM states WHERE name = 'Kebbi'), 'Ngaski', 'LGA-0462'),
-    ('30eedc9b-2aa4-4e19-92a2-889fb1235c10', (SELECT id FROM states WHERE name = 'Kebbi'), 'Sakaba', 'LGA-0463'),
-    ('3443fca6-a442-4d2f-8e7b-e49d3c70e29c', (SELECT id FROM states WHERE name = 'Kebbi'), 'Shanga', 'LGA-0464'),
-    ('43f31f0d-6e6b-4636-bd5b-72c5fa1613ba', (SELECT id FROM states WHERE name = 'Kebbi'), 'Suru', 'LGA-0465'),
-    ('349577ea-3756-4c3b-af12-0d71a799033c', (SELECT id FROM states WHERE name = 'Kebbi'), 'Wasagu/Danko', 'LGA-0466'),
-    ('bd3d84c5-03c5-4a2c-b061-1f954dcc1326', (SELECT id FROM states WHERE name = 'Kebbi'), 'Yauri', 'LGA-0467'),
-    ('3cb2fe92-d75a-40b8-85d4-16be33f6ad9b', (SELECT id FROM states WHERE name = 'Kebbi'), 'Zuru', 'LGA-0468'),
-    ('5e568d8c-a061-4954-8acd-857d0def3ea2', (SELECT id FROM states WHERE name = 'Kogi'), 'Adavi', 'LGA-0469'),
-    ('35bce242-2fb4-441e-b92c-9ad3483a5c3e', (SELECT id FROM states WHERE name = 'Kogi'), 'Ajaokuta', 'LGA-0470'),
-    ('6770596a-10e7-40f7-abc2-a08e3de41d2d', (SELECT id FROM states WHERE name = 'Kogi'), 'Ankpa', 'LGA-0471'),
-    ('c1275108-13b7-42b2-827b-edadd3d105d1', (SELECT id FROM states WHERE name = 'Kogi'), 'Bassa', 'LGA-0472'),
-    ('8bea750a-53c4-41e8-b75c-bbfb50f6b22f', (SELECT id FROM states WHERE name = 'Kogi'), 'Dekina', 'LGA-0473'),
-    ('545f3eca-e7fb-4252-8714-604e06483438', (SELECT id FROM states WHERE name = 'Kogi'), 'Ibaji', 'LGA-0474'),
-    ('d32c0723-f2b5-4476-a500-459cf1923a54', (SELECT id FROM states WHERE name = 'Kogi'), 'Idah', 'LGA-0475'),
-    ('e293de4f-7b1b-4ac7-aa7e-cb793498b2b8', (SELECT id FROM states WHERE name = 'Kogi'), 'Igalamela-Odolu', 'LGA-0476'),
-    ('0d537d33-7362-4ac7-82b4-d392cf810774', (SELECT id FROM states WHERE name = 'Kogi'), 'Ijumu', 'LGA-0477'),
-    ('71476656-5ba2-4f2c-8004-811b7f2ef11f', (SELECT id FROM states WHERE name = 'Kogi'), 'Kabba/Bunu', 'LGA-0478'),
-    ('63f80889-9eaf-4126-b7e0-43866bdd4141', (SELECT id FROM states WHERE name = 'Kogi'), 'Kogi', 'LGA-0479'),
-    ('d943bd74-338e-4bc6-8a98-19f373adc2bb', (SELECT id FROM states WHERE name = 'Kogi'), 'Lokoja', 'LGA-0480'),
-    ('7a2bc94b-6ad3-4c01-bfcf-3551a6c98d71', (SELECT id FROM states WHERE name = 'Kogi'), 'Mopa-Muro', 'LGA-0481'),
-    ('e38e4a25-1154-442a-86fa-90a3e443b6fb', (SELECT id FROM states WHERE name = 'Kogi'), 'Ofu', 'LGA-0482'),
-    ('a734e25d-3f32-4f00-aa90-1d33fc871f42', (SELECT id FROM states WHERE name = 'Kogi'), 'Ogori/Magongo', 'LGA-0483'),
-    ('38dbf599-cf91-4b51-af41-16c7b3df93fa', (SELECT id FROM states WHERE name = 'Kogi'), 'Okehi', 'LGA-0484'),
-    ('2cdcd0ec-b2d2-44e2-9fc8-add0968a537e', (SELECT id FROM states WHERE name = 'Kogi'), 'Okene', 'LGA-0485'),
-    ('1c7e807e-bb74-47f6-9019-fbe9f3325bfc', (SELECT id FROM states WHERE name = 'Kogi'), 'Olamaboro', 'LGA-0486'),
-    ('c20eb8c0-24d3-44f8-b04e-c2684814a7ff', (SELECT id FROM states WHERE name = 'Kogi'), 'Omala', 'LGA-0487'),
-    ('99bc8873-5691-49e9-8008-dbd482b7f955', (SELECT id FROM states WHERE name = 'Kogi'), 'Yagba East', 'LGA-0488'),
-    ('583dc4a6-1546-4ee4-afb9-b78270f9af66', (SELECT id FROM states WHERE name = 'Kogi'), 'Yagba West', 'LGA-0489'),
-    ('41eebf8b-c0b2-4cc8-b3ca-7c21b526118c', (SELECT id FROM states WHERE name = 'Kwara'), 'Asa', 'LGA-0490'),
-    ('8c8665e9-8fef-47a0-96dd-bb8acb2113b9', (SELECT id FROM states WHERE name = 'Kwara'), 'Baruten', 'LGA-0491'),
-    ('8bb419e5-3fe9-44fe-aa12-48c79da9a5c7', (SELECT id FROM states WHERE name = 'Kwara'), 'Edu', 'LGA-0492'),
-    ('14d21f82-08ea-483e-a3be-f6b5a1c6d1c6', (SELECT id FROM states WHERE name = 'Kwara'), 'Ekiti', 'LGA-0493'),
-    ('e50d6ff6-0776-4a46-bd9d-ce0a570b1d1e', (SELECT id FROM states WHERE name = 'Kwara'), 'Ifelodun', 'LGA-0494'),
-    ('91c67439-aee0-4a1c-8f10-d315007c594c', (SELECT id FROM states WHERE name = 'Kwara'), 'Ilorin East', 'LGA-0495'),
-    ('ea8692e3-7a30-43e7-8f1d-b32ab54e7bc4', (SELECT id FROM states WHERE name = 'Kwara'), 'Ilorin South', 'LGA-0496'),
-    ('27205670-93f7-4e78-afaf-a43be670b68f', (SELECT id FROM states WHERE name = 'Kwara'), 'Ilorin West', 'LGA-0497'),
-    ('758d5794-653e-4093-99ff-b27310dad5f7', (SELECT id FROM states WHERE name = 'Kwara'), 'Irepodun', 'LGA-0498'),
-    ('8abc8d60-72f5-4bf2-a3e8-3a8486a993bd', (SELECT id FROM states WHERE name = 'Kwara'), 'Isin', 'LGA-0499'),
-    ('6484a472-5c16-4042-bc0a-89c8bf2e56d9', (SELECT id FROM states WHERE name = 'Kwara'), 'Kaiama', 'LGA-0500'),
-    ('a580ec79-75cc-43ab-b5b3-ad359cd8bb0f', (SELECT id FROM states WHERE name = 'Kwara'), 'Moro', 'LGA-0501'),
-    ('a044f4ff-ab0a-413c-8545-088cb5dfa0a1', (SELECT id FROM states WHERE name = 'Kwara'), 'Offa', 'LGA-0502'),
-    ('bc7e904b-87ef-43ba-bd1d-9289072cab58', (SELECT id FROM states WHERE name = 'Kwara'), 'Oke Ero', 'LGA-0503'),
-    ('d1335c02-643b-404a-b934-4f7f4f4995c0', (SELECT id FROM states WHERE name = 'Kwara'), 'Oyun', 'LGA-0504'),
-    ('8b831046-94b6-4324-83cb-3e6b1bfb27ed', (SELECT id FROM states WHERE name = 'Kwara'), 'Pategi', 'LGA-0505'),
-    ('8cdcfadb-4cf5-43e7-bacd-50aca466e372', (SELECT id FROM states WHERE name = 'Lagos'), 'Agege', 'LGA-0506'),
-    ('c79d01a2-e26d-4ea9-afdf-f4710f0d2c95', (SELECT id FROM states WHERE name = 'Lagos'), 'Ajeromi-Ifelodun', 'LGA-0507'),
-    ('a3c26a3e-43ff-4654-b0b1-76bca203fd34', (SELECT id FROM states WHERE name = 'Lagos'), 'Alimosho', 'LGA-0508'),
-    ('17a1647d-0ebc-4d76-88eb-2c06c5d22fe2', (SELECT id FROM states WHERE name = 'Lagos'), 'Amuwo-Odofin', 'LGA-0509'),
-    ('76cc35de-60cd-41b1-a350-80ee1d7e2e80', (SELECT id FROM states WHERE name = 'Lagos'), 'Apapa', 'LGA-0510'),
-    ('e363823e-1f92-4fb6-97ea-c97d53b90b7a', (SELECT id FROM states WHERE name = 'Lagos'), 'Badagry', 'LGA-0511'),
-    ('a338878c-7983-48f6-903d-27cf42fdc4fe', (SELECT id FROM states WHERE name = 'Lagos'), 'Epe', 'LGA-0512'),
-    ('f649a66f-74ff-4725-b591-486d387d04bc', (SELECT id FROM states WHERE name = 'Lagos'), 'Eti Osa', 'LGA-0513'),
-    ('5830f7d0-9504-4786-a7de-27fd327f6f62', (SELECT id FROM states WHERE name = 'Lagos'), 'Ibeju-Lekki', 'LGA-0514'),
-    ('7c39af8a-17fc-4273-b1b2-fa4d7442b8ca', (SELECT id FROM states WHERE name = 'Lagos'), 'Ifako-Ijaiye', 'LGA-0515'),
-    ('d965bd6b-d611-430b-9e72-253532d9caeb', (SELECT id FROM states WHERE name = 'Lagos'), 'Ikeja', 'LGA-0516'),
-    ('27e46beb-ce9e-4ac3-95de-586934ceceb8', (SELECT id FROM states WHERE name = 'Lagos'), 'Ikorodu', 'LGA-0517'),
-    ('169e9a0a-5ede-451a-a3d9-520abe81c272', (SELECT id FROM states WHERE name = 'Lagos'), 'Kosofe', 'LGA-0518'),
-    ('5541b8cc-5336-40d8-a20e-832d2b5eda69', (SELECT id FROM states WHERE name = 'Lagos'), 'Lagos Island', 'LGA-0519'),
-    ('481a4688-0d2d-4a9d-a4ac-f782081caa00', (SELECT id FROM states WHERE name = 'Lagos'), 'Lagos Mainland', 'LGA-0520'),
-    ('edb9dfc8-8556-4422-8639-09a0e38da0b7', (SELECT id FROM states WHERE name = 'Lagos'), 'Mushin', 'LGA-0521'),
-    ('19d383e9-265c-4c03-bd45-3ca53e81ae02', (SELECT id FROM states WHERE name = 'Lagos'), 'Ojo', 'LGA-0522'),
-    ('bdcf44c5-12cf-4f96-8051-b7cf7a4227ab', (SELECT id FROM states WHERE name = 'Lagos'), 'Oshodi-Isolo', 'LGA-0523'),
-    ('1dafb6ed-639e-4fb5-a727-6125be697fc4', (SELECT id FROM states WHERE name = 'Lagos'), 'Shomolu', 'LGA-0524'),
-    ('bbf30cac-3137-4fb1-90d1-259bf22579aa', (SELECT id FROM states WHERE name = 'Lagos'), 'Surulere', 'LGA-0525'),
-    ('3a0dfd7e-9784-40e1-9e35-20a40c7b6c2c', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Akwanga', 'LGA-0526'),
-    ('1462b49f-cd90-4020-a83f-fd1ece8bd3c6', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Awe', 'LGA-0527'),
-    ('cd188a1a-c3d5-4514-b231-95ed21897d9c', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Doma', 'LGA-0528'),
-    ('d5759082-32e8-4fc2-95c2-052ad12883cc', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Karu', 'LGA-0529'),
-    ('8ecc99f1-58c5-43e2-a38c-5303bb98bbbb', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Keana', 'LGA-0530'),
-    ('5ee37e95-4c39-425a-ae5f-645a65d88faa', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Keffi', 'LGA-0531'),
-    ('2684d430-f45c-409e-b402-31c3ddbc3288', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Kokona', 'LGA-0532'),
-    ('a35ca8a4-b542-4ff5-b05d-8040db589ea2', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Lafia', 'LGA-0533'),
-    ('04e73260-d4ff-47a1-ba58-065028bb32c5', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Nasarawa', 'LGA-0534'),
-    ('d231cb65-9615-4051-a892-29b591674290', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Nasarawa Eggon', 'LGA-0535'),
-    ('dca01d17-844c-40db-b1e7-860269bcae59', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Obi', 'LGA-0536'),
-    ('d63472ae-34cc-43d7-a3d3-843096efbb80', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Toto', 'LGA-0537'),
-    ('f9293d77-1032-4d1d-9e2f-49245f3192a7', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Wamba', 'LGA-0538'),
-    ('996a5337-dbc4-4cf9-8e75-1eb3a64df582', (SELECT id FROM states WHERE name = 'Niger'), 'Agaie', 'LGA-0539'),
-    ('dcd2e858-e340-49b0-b1ad-32c0bc1274f3', (SELECT id FROM states WHERE name = 'Niger'), 'Agwara', 'LGA-0540'),
-    ('cfac7f78-6462-4943-a395-17e9b3b27f28', (SELECT id FROM states WHERE name = 'Niger'), 'Bida', 'LGA-0541'),
-    ('bc1c4451-e19c-4b06-8cda-42246004bf92', (SELECT id FROM states WHERE name = 'Niger'), 'Borgu', 'LGA-0542'),
-    ('1c99f5ba-b926-4576-8839-15e415ef88c9', (SELECT id FROM states WHERE name = 'Niger'), 'Bosso', 'LGA-0543'),
-    ('15ced91b-e504-4225-bc06-6517db5e5377', (SELECT id FROM states WHERE name = 'Niger'), 'Chanchaga', 'LGA-0544'),
-    ('b36cf634-7d75-4140-9d79-c56c0a678691', (SELECT id FROM states WHERE name = 'Niger'), 'Edati', 'LGA-0545'),
-    ('a3c5a277-b241-4c1d-8317-a7fdfdd35281', (SELECT id FROM states WHERE name = 'Niger'), 'Gbako', 'LGA-0546'),
-    ('373b8426-0ca0-410b-9a89-a4963ebc4e13', (SELECT id FROM states WHERE name = 'Niger'), 'Gurara', 'LGA-0547'),
-    ('f7183b94-d7a1-4a89-af27-b1a681eaee78', (SELECT id FROM states WHERE name = 'Niger'), 'Katcha', 'LGA-0548'),
-    ('2cff00b1-bb60-4f9b-868c-d4a6672a8524', (SELECT id FROM states WHERE name = 'Niger'), 'Kontagora', 'LGA-0549'),
-    ('7df9f14b-8477-4861-bfeb-4c5645670939', (SELECT id FROM states WHERE name = 'Niger'), 'Lapai', 'LGA-0550'),
-    ('8dc106ce-38af-4beb-bbe2-78d03b4c416b', (SELECT id FROM states WHERE name = 'Niger'), 'Lavun', 'LGA-0551'),
-    ('4d8f1596-0857-4765-b025-543c60f062c0', (SELECT id FROM states WHERE name = 'Niger'), 'Magama', 'LGA-0552'),
-    ('7cd55579-3608-450b-a1a4-721eec3ef4dc', (SELECT id FROM states WHERE name = 'Niger'), 'Mariga', 'LGA-0553'),
-    ('3194fe9c-dfce-47b3-8a6a-bfd05edbc8e8', (SELECT id FROM states WHERE name = 'Niger'), 'Mashegu', 'LGA-0554'),
-    ('1e3bde69-75ca-41d4-b80f-c444375d110d', (SELECT id FROM states WHERE name = 'Niger'), 'Mokwa', 'LGA-0555'),
-    ('53da8b3c-2cbb-453d-b614-e703905ac018', (SELECT id FROM states WHERE name = 'Niger'), 'Munya', 'LGA-0556'),
-    ('2844f0d0-c6be-4fe2-86c5-4423d259be07', (SELECT id FROM states WHERE name = 'Niger'), 'Paikoro', 'LGA-0557'),
-    ('7eacada6-8201-4479-8046-9dff0f5d91ab', (SELECT id FROM states WHERE name = 'Niger'), 'Rafi', 'LGA-0558'),
-    ('dff147b2-6e12-4bcd-bbb2-7eb2d44bd9e1', (SELECT id FROM states WHERE name = 'Niger'), 'Rijau', 'LGA-0559'),
-    ('ae8ec26b-eab1-4a57-8e32-7bc2062a74d9', (SELECT id FROM states WHERE name = 'Niger'), 'Shiroro', 'LGA-0560'),
-    ('0bd50933-ac16-455f-9e7d-11d8e1837744', (SELECT id FROM states WHERE name = 'Niger'), 'Suleja', 'LGA-0561'),
-    ('3775040b-f61c-46c9-90be-0b8c7c84d29d', (SELECT id FROM states WHERE name = 'Niger'), 'Tafa', 'LGA-0562'),
-    ('baf0f299-af33-4172-8785-fc816dd98407', (SELECT id FROM states WHERE name = 'Niger'), 'Wushishi', 'LGA-0563'),
-    ('5f3e2d54-1ce7-4d07-8aba-aefdb28a9638', (SELECT id FROM states WHERE name = 'Ogun'), 'Abeokuta North', 'LGA-0564'),
-    ('81b82c1e-dbbb-4c6a-9886-5908123ae655', (SELECT id FROM states WHERE name = 'Ogun'), 'Abeokuta South', 'LGA-0565'),
-    ('b004dceb-e4c3-4520-9594-22e92e5b39f4', (SELECT id FROM states WHERE name = 'Ogun'), 'Ado-Odo/Ota', 'LGA-0566'),
-    ('d3298489-f00f-449e-a0ed-5828726a82ac', (SELECT id FROM states WHERE name = 'Ogun'), 'Ewekoro', 'LGA-0567'),
-    ('6efe0c66-fead-4519-8e10-e2f741324114', (SELECT id FROM states WHERE name = 'Ogun'), 'Ifo', 'LGA-0568'),
-    ('5b11785a-ed63-46ea-8dac-f2f74ac0d087', (SELECT id FROM states WHERE name = 'Ogun'), 'Ijebu East', 'LGA-0569'),
-    ('f0bed0ec-c395-49ee-834e-a5869bb74913', (SELECT id FROM states WHERE name = 'Ogun'), 'Ijebu North', 'LGA-0570'),
-    ('6313f1df-8845-48b9-b1cf-1e9eb637ffea', (SELECT id FROM states WHERE name = 'Ogun'), 'Ijebu North East', 'LGA-0571'),
-    ('1085e417-a25a-429e-8f94-805462010308', (SELECT id FROM states WHERE name = 'Ogun'), 'Ijebu Ode', 'LGA-0572'),
-    ('9708186c-3f93-4417-8d4e-efaf9e87fd11', (SELECT id FROM states WHERE name = 'Ogun'), 'Ikenne', 'LGA-0573'),
-    ('a169083c-57bb-40ff-b092-58e5068b46e8', (SELECT id FROM states WHERE name = 'Ogun'), 'Imeko Afon', 'LGA-0574'),
-    ('15339474-4221-486d-95f7-bf692bd1bfc5', (SELECT id FROM states WHERE name = 'Ogun'), 'Ipokia', 'LGA-0575'),
-    ('28193633-3c08-4056-b78c-5d691603fee6', (SELECT id FROM states WHERE name = 'Ogun'), 'Obafemi Owode', 'LGA-0576'),
-    ('2885bd01-223d-499f-bb0a-71bf97cece52', (SELECT id FROM states WHERE name = 'Ogun'), 'Odeda', 'LGA-0577'),
-    ('b9a5b040-32f4-4b45-a107-4b6653d1a17a', (SELECT id FROM states WHERE name = 'Ogun'), 'Odogbolu', 'LGA-0578'),
-    ('f9d9108d-ee91-4a82-bf1e-aa53ed076210', (SELECT id FROM states WHERE name = 'Ogun'), 'Ogun Waterside', 'LGA-0579'),
-    ('88be31ec-6586-402c-a52f-cf5a2920a4aa', (SELECT id FROM states WHERE name = 'Ogun'), 'Remo North', 'LGA-0580'),
-    ('bb4fac5d-2491-4ce9-b44a-783188b0621d', (SELECT id FROM states WHERE name = 'Ogun'), 'Shagamu', 'LGA-0581'),
-    ('bc5a9336-6553-4afd-bd3d-a06f084b38c9', (SELECT id FROM states WHERE name = 'Ogun'), 'Yewa North', 'LGA-0582'),
-    ('98b64d0c-db95-4ceb-83a0-51d24fd5b947', (SELECT id FROM states WHERE name = 'Ogun'), 'Yewa South', 'LGA-0583'),
-    ('f715d0e0-336f-4717-8b6e-631b13a33c68', (SELECT id FROM states WHERE name = 'Ondo'), 'Akoko North-East', 'LGA-0584'),
-    ('5ab0fade-50b1-470c-bf17-f1cc33549b56', (SELECT id FROM states WHERE name = 'Ondo'), 'Akoko North-West', 'LGA-0585'),
-    ('bc51a1d2-8237-49db-81de-9c32682999b3', (SELECT id FROM states WHERE name = 'Ondo'), 'Akoko South-East', 'LGA-0586'),
-    ('7cc138d5-b49a-4a34-9979-bf5eeac29ece', (SELECT id FROM states WHERE name = 'Ondo'), 'Akoko South-West', 'LGA-0587'),
-    ('38c23ab1-bec1-4934-8226-4f2eec86225f', (SELECT id FROM states WHERE name = 'Ondo'), 'Akure North', 'LGA-0588'),
-    ('8f611602-3436-446e-aed0-d5b7a6e35d40', (SELECT id FROM states WHERE name = 'Ondo'), 'Akure South', 'LGA-0589'),
-    ('372b47dc-4f01-4da3-9274-ac93c1cc85e2', (SELECT id FROM states WHERE name = 'Ondo'), 'Ese Odo', 'LGA-0590'),
-    ('51629b4a-6d4a-4964-8f44-c90631b6d5a6', (SELECT id FROM states WHERE name = 'Ondo'), 'Idanre', 'LGA-0591'),
-    ('add045e4-1e7b-442e-a318-658587be9d10', (SELECT id FROM states WHERE name = 'Ondo'), 'Ifedore', 'LGA-0592'),
-    ('09aa3dbb-7bd2-47a8-ba43-9d62141b78c0', (SELECT id FROM states WHERE name = 'Ondo'), 'Ilaje', 'LGA-0593'),
-    ('fc29b513-cb82-4b50-bfd1-ad92e27dac59', (SELECT id FROM states WHERE name = 'Ondo'), 'Ile Oluji/Okeigbo', 'LGA-0594'),
-    ('84369971-34da-4fb4-aed2-dccfb676dca5', (SELECT id FROM states WHERE name = 'Ondo'), 'Irele', 'LGA-0595'),
-    ('3ecd7242-7dfc-4da3-8a8d-a7c14fc2bbac', (SELECT id FROM states WHERE name = 'Ondo'), 'Odigbo', 'LGA-0596'),
-    ('19a854e0-a5a3-4f40-a996-6449684b440f', (SELECT id FROM states WHERE name = 'Ondo'), 'Okitipupa', 'LGA-0597'),
-    ('ad227732-fa82-46f8-affc-c33df65a80f2', (SELECT id FROM states WHERE name = 'Ondo'), 'Ondo East', 'LGA-0598'),
-    ('28c21c3f-0078-42bc-af8d-311c41e6090b', (SELECT id FROM states WHERE name = 'Ondo'), 'Ondo West', 'LGA-0599'),
-    ('49905263-f7c9-44c4-ac3b-7d6d46310966', (SELECT id FROM states WHERE name = 'Ondo'), 'Ose', 'LGA-0600'),
-    ('717cfd33-8147-4982-99d9-42b07b1a079c', (SELECT id FROM states WHERE name = 'Ondo'), 'Owo', 'LGA-0601'),
-    ('8bdc6090-a78c-45bb-a0c1-04aae8374387', (SELECT id FROM states WHERE name = 'Osun'), 'Aiyedaade', 'LGA-0602'),
-    ('b2875678-b4b0-4ddb-a3e6-778294439341', (SELECT id FROM states WHERE name = 'Osun'), 'Aiyedire', 'LGA-0603'),
-    ('d35337cc-73cc-4ad3-b82c-395ad439ab7c', (SELECT id FROM states WHERE name = 'Osun'), 'Atakunmosa East', 'LGA-0604'),
-    ('0404a141-c24c-4bbf-a6cf-d753ca96c64c', (SELECT id FROM states WHERE name = 'Osun'), 'Atakunmosa West', 'LGA-0605'),
-    ('4de75e68-f0b3-44f4-87ad-d1e6d4d7137e', (SELECT id FROM states WHERE name = 'Osun'), 'Boluwaduro', 'LGA-0606'),
-    ('9a375438-85fe-43f9-87f8-43eab3d5aa87', (SELECT id FROM states WHERE name = 'Osun'), 'Boripe', 'LGA-0607'),
-    ('ecb724bd-6709-4a10-92ad-1af3300fa991', (SELECT id FROM states WHERE name = 'Osun'), 'Ede North', 'LGA-0608'),
-    ('739a247e-b8d0-4fcf-a5d2-2d1809b1667a', (SELECT id FROM states WHERE name = 'Osun'), 'Ede South', 'LGA-0609'),
-    ('f06c3c61-e298-4623-b158-f29383f0715f', (SELECT id FROM states WHERE name = 'Osun'), 'Egbedore', 'LGA-0610'),
-    ('9268c48d-8184-4e87-b3b1-8a6e7d764a37', (SELECT id FROM states WHERE name = 'Osun'), 'Ejigbo', 'LGA-0611'),
-    ('d4f32733-aba6-426c-8a87-f81068d01b78', (SELECT id FROM states WHERE name = 'Osun'), 'Ife Central', 'LGA-0612'),
-    ('f896b76a-025c-4b9d-a09b-eb460cf6521c', (SELECT id FROM states WHERE name = 'Osun'), 'Ife East', 'LGA-0613'),
-    ('93d317b5-2f95-4aae-b3a3-84aee631adde', (SELECT id FROM states WHERE name = 'Osun'), 'Ife North', 'LGA-0614'),
-    ('76c2bd2c-0bc7-4434-a1b0-ddf6d7b95e27', (SELECT id FROM states WHERE name = 'Osun'), 'Ife South', 'LGA-0615'),
-    ('245f72e3-0c3d-4bb3-b4df-d246a7613089', (SELECT id FROM states WHERE name = 'Osun'), 'Ifedayo', 'LGA-0616'),
-    ('1b59e905-2499-42db-b881-2b7307ead655', (SELECT id FROM states WHERE name = 'Osun'), 'Ifelodun', 'LGA-0617'),
-    ('169d46fa-55f5-410b-aa10-44d2ecb8ec99', (SELECT id FROM states WHERE name = 'Osun'), 'Ila', 'LGA-0618'),
-    ('decddd07-f53c-4cd1-b8e6-f4b66bb3738c', (SELECT id FROM states WHERE name = 'Osun'), 'Ilesa East', 'LGA-0619'),
-    ('0107339d-34c2-482b-a911-5f0a801234e5', (SELECT id FROM states WHERE name = 'Osun'), 'Ilesa West', 'LGA-0620'),
-    ('47dccca8-6476-44f3-b86f-8b3409469f28', (SELECT id FROM states WHERE name = 'Osun'), 'Irepodun', 'LGA-0621'),
-    ('9042f87c-d232-4a37-8525-6a5f0c307965', (SELECT id FROM states WHERE name = 'Osun'), 'Irewole', 'LGA-0622'),
-    ('be41b433-d6f1-4fdd-9f39-d9e56a5de5f9', (SELECT id FROM states WHERE name = 'Osun'), 'Isokan', 'LGA-0623'),
-    ('d04d8963-9c63-424e-9ddd-6409ba68be98', (SELECT id FROM states WHERE name = 'Osun'), 'Iwo', 'LGA-0624'),
-    ('84c69b4b-0c70-468f-be66-a4484f316a53', (SELECT id FROM states WHERE name = 'Osun'), 'Obokun', 'LGA-0625'),
-    ('0405de5c-96e8-4117-986b-1e4db765454f', (SELECT id FROM states WHERE name = 'Osun'), 'Odo Otin', 'LGA-0626'),
-    ('e319360e-d649-4cc2-a50a-4dc498a94c7d', (SELECT id FROM states WHERE name = 'Osun'), 'Ola Oluwa', 'LGA-0627'),
-    ('1b886a5c-72a6-4e83-b09d-22d94c9865b7', (SELECT id FROM states WHERE name = 'Osun'), 'Olorunda', 'LGA-0628'),
-    ('644eab7e-d845-46e2-8b3a-8eb0b1e8db8a', (SELECT id FROM states WHERE name = 'Osun'), 'Oriade', 'LGA-0629'),
-    ('d4f682d2-a08b-465d-a16c-2b96f39220c1', (SELECT id FROM states WHERE name = 'Osun'), 'Orolu', 'LGA-0630'),
-    ('c196b03a-0171-4b28-9a7b-aa95671cf029', (SELECT id FROM states WHERE name = 'Osun'), 'Osogbo', 'LGA-0631'),
-    ('cc9bf6f1-a758-4ad6-a21f-2d3d3dc6b1b8', (SELECT id FROM states WHERE name = 'Oyo'), 'Afijio', 'LGA-0632'),
-    ('6d91a246-c2f1-483d-9235-9a3e33cc71c5', (SELECT id FROM states WHERE name = 'Oyo'), 'Akinyele', 'LGA-0633'),
-    ('e5b1ee0d-9c11-4318-b455-ea368ebbaf6a', (SELECT id FROM states WHERE name = 'Oyo'), 'Atiba', 'LGA-0634'),
-    ('3e3f042f-aca5-4991-8de2-cd261ab41254', (SELECT id FROM states WHERE name = 'Oyo'), 'Atisbo', 'LGA-0635'),
-    ('ba627f44-8695-4fc2-b592-7df98c386d5b', (SELECT id FROM states WHERE name = 'Oyo'), 'Egbeda', 'LGA-0636'),
-    ('71f5f775-1cde-4bce-9cf4-d9a0512ec493', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibadan North', 'LGA-0637'),
-    ('0d1c595f-bdb7-4892-a67c-a3e8dc056cfc', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibadan North-East', 'LGA-0638'),
-    ('eeb763ed-d6b4-4d9d-a1a2-a3fd8f71fa12', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibadan North-West', 'LGA-0639'),
-    ('f0bb039d-89a8-4ef9-8dd5-68fef4c6f35e', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibadan South-East', 'LGA-0640'),
-    ('ed7da07f-f80c-47da-9f42-96821c698662', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibadan South-West', 'LGA-0641'),
-    ('06ce4961-1c73-43fe-8ca3-6823602aa971', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibarapa Central', 'LGA-0642'),
-    ('d89459a4-ba0b-4f4b-9c1b-f10095d3ab5d', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibarapa East', 'LGA-0643'),
-    ('ff1cd786-22d9-40cb-a54b-6b09ecde252a', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibarapa North', 'LGA-0644'),
-    ('a463ddf5-71bc-4dbd-9017-5139d63fe694', (SELECT id FROM states WHERE name = 'Oyo'), 'Ido', 'LGA-0645'),
-    ('885dfcc1-b992-4380-be0e-79f03633fd76', (SELECT id FROM states WHERE name = 'Oyo'), 'Irepo', 'LGA-0646'),
-    ('eb045d58-a29b-41dc-b54b-2dc64acf0adf', (SELECT id FROM states WHERE name = 'Oyo'), 'Iseyin', 'LGA-0647'),
-    ('f0ac77b1-0240-419d-be63-f0a9f7d6c94e', (SELECT id FROM states WHERE name = 'Oyo'), 'Itesiwaju', 'LGA-0648'),
-    ('6829ffdf-e538-4851-9527-f6c39acb4354', (SELECT id FROM states WHERE name = 'Oyo'), 'Iwajowa', 'LGA-0649'),
-    ('9fdb9e97-6cb4-4a1d-b1ed-ec224abf6c14', (SELECT id FROM states WHERE name = 'Oyo'), 'Kajola', 'LGA-0650'),
-    ('3a99c91d-8cda-4f39-817d-5e2d3a8b9660', (SELECT id FROM states WHERE name = 'Oyo'), 'Lagelu', 'LGA-0651'),
-    ('a2a65c71-304e-4a36-ba78-a8b56c920198', (SELECT id FROM states WHERE name = 'Oyo'), 'Ogbomosho North', 'LGA-0652'),
-    ('568024bf-4969-4445-aafa-7ae03b92238f', (SELECT id FROM states WHERE name = 'Oyo'), 'Ogbomosho South', 'LGA-0653'),
-    ('dc9fe157-8b5e-4510-9747-a14bedd71188', (SELECT id FROM states WHERE name = 'Oyo'), 'Ogo Oluwa', 'LGA-0654'),
-    ('b180e8fc-9eb2-451c-8e20-69f42a49bea3', (SELECT id FROM states WHERE name = 'Oyo'), 'Olorunsogo', 'LGA-0655'),
-    ('3de48ed8-0647-41c3-b710-b41ada6deed3', (SELECT id FROM states WHERE name = 'Oyo'), 'Oluyole', 'LGA-0656'),
-    ('2c67d7b4-f5f9-45ca-b0f2-132db5146421', (SELECT id FROM states WHERE name = 'Oyo'), 'Ona Ara', 'LGA-0657'),
-    ('208a4f98-6984-4bcb-aab2-e7a4614b11b4', (SELECT id FROM states WHERE name = 'Oyo'), 'Orelope', 'LGA-0658'),
-    ('36c41a84-2d09-4335-9289-95f5c89f2b25', (SELECT id FROM states WHERE name = 'Oyo'), 'Ori Ire', 'LGA-0659'),
-    ('142b956e-1ad2-4385-9fb6-6b08db58f747', (SELECT id FROM states WHERE name = 'Oyo'), 'Oyo East', 'LGA-0660'),
-    ('dfbb6049-e9c9-456d-9542-be33ed91a72c', (SELECT id FROM states WHERE name = 'Oyo'), 'Oyo West', 'LGA-0661'),
-    ('16ed8b14-d958-4f04-bb6f-29fb07757049', (SELECT id FROM states WHERE name = 'Oyo'), 'Saki East', 'LGA-0662'),
-    ('ac9c14e9-a5bd-469d-be5b-4b9242d7430c', (SELECT id FROM states WHERE name = 'Oyo'), 'Saki West', 'LGA-0663'),
-    ('23d20c9a-341d-4807-8895-909592a16d66', (SELECT id FROM states WHERE name = 'Oyo'), 'Surulere', 'LGA-0664'),
-    ('15556c66-23da-40d4-a2da-dbb63c58cd0a', (SELECT id FROM states WHERE name = 'Plateau'), 'Barkin Ladi', 'LGA-0665'),
-    ('003da7b0-7fc4-4b3e-b110-c624f6744139', (SELECT id FROM states WHERE name = 'Plateau'), 'Bassa', 'LGA-0666'),
-    ('3fa18a49-9329-42e3-8351-cb6a15e2c793', (SELECT id FROM states WHERE name = 'Plateau'), 'Bokkos', 'LGA-0667'),
-    ('df20c675-10ce-4b3f-a615-c5093f612b0a', (SELECT id FROM states WHERE name = 'Plateau'), 'Jos East', 'LGA-0668'),
-    ('0e9deafd-e93a-448c-bb4a-e8ea8bc3c323', (SELECT id FROM states WHERE name = 'Plateau'), 'Jos North', 'LGA-0669'),
-    ('6905c016-8024-4c68-8c80-491a8ae31877', (SELECT id FROM states WHERE name = 'Plateau'), 'Jos South', 'LGA-0670'),
-    ('70fad1e2-c8e1-4346-9032-638e73c1306a', (SELECT id FROM states WHERE name = 'Plateau'), 'Kanam', 'LGA-0671'),
-    ('9817618e-63b7-4fcd-932d-c9e009c61e6d', (SELECT id FROM states WHERE name = 'Plateau'), 'Kanke', 'LGA-0672'),
-    ('8126dbac-c528-4686-b91b-c557ad70bfc5', (SELECT id FROM states WHERE name = 'Plateau'), 'Langtang North', 'LGA-0673'),
-    ('543f8a75-acc5-4fff-95b5-8a261d8649aa', (SELECT id FROM states WHERE name = 'Plateau'), 'Langtang South', 'LGA-0674'),
-    ('c4516357-0413-43d5-9e6c-d17d7da1a1db', (SELECT id FROM states WHERE name = 'Plateau'), 'Mangu', 'LGA-0675'),
-    ('7ea0da20-87be-4f1c-a46b-e9d3cef5a061', (SELECT id FROM states WHERE name = 'Plateau'), 'Mikang', 'LGA-0676'),
-    ('1707bee2-ce5a-43cf-9f72-1d594dc32e7d', (SELECT id FROM states WHERE name = 'Plateau'), 'Pankshin', 'LGA-0677'),
-    ('9e052665-af05-4d1b-9e61-96d2f5f33e26', (SELECT id FROM states WHERE name = 'Plateau'), 'Qua''an Pan', 'LGA-0678'),
-    ('1ad4ecb3-d871-462d-ade9-f9fb42cb7e54', (SELECT id FROM states WHERE name = 'Plateau'), 'Riyom', 'LGA-0679'),
-    ('92e890e1-2027-4557-b131-c43526d101f5', (SELECT id FROM states WHERE name = 'Plateau'), 'Shendam', 'LGA-0680'),
-    ('d033d5ab-73be-4c89-a410-4214741f103b', (SELECT id FROM states WHERE name = 'Plateau'), 'Wase', 'LGA-0681'),
-    ('23f0f938-06f1-4831-9f95-b3b94e3c29d9', (SELECT id FROM states WHERE name = 'Rivers'), 'Abua/Odual', 'LGA-0682'),
-    ('4223559e-45e8-4800-840e-786cebdb7500', (SELECT id FROM states WHERE name = 'Rivers'), 'Ahoada East', 'LGA-0683'),
-    ('e1d1c609-9ccc-4f7a-a472-fd9db5f6e2b8', (SELECT id FROM states WHERE name = 'Rivers'), 'Ahoada West', 'LGA-0684'),
-    ('575453d6-ce17-4c33-943d-aa123c12e1d8', (SELECT id FROM states WHERE name = 'Rivers'), 'Akuku-Toru', 'LGA-0685'),
-    ('610872fe-6b0f-4fb9-99ff-7020d7c88f1f', (SELECT id FROM states WHERE name = 'Rivers'), 'Andoni', 'LGA-0686'),
-    ('18a82154-27f0-4d9d-866e-f8872cf6c6b7', (SELECT id FROM states WHERE name = 'Rivers'), 'Asari-Toru', 'LGA-0687'),
-    ('4dcba0e9-77e4-4976-99d2-ab577e51f2ee', (SELECT id FROM states WHERE name = 'Rivers'), 'Bonny', 'LGA-0688'),
-    ('d1be9ea6-931b-4a9d-9284-dead24a5095f', (SELECT id FROM states WHERE name = 'Rivers'), 'Degema', 'LGA-0689'),
-    ('3dba42a9-e255-4f32-ace5-a633d751ddef', (SELECT id FROM states WHERE name = 'Rivers'), 'Eleme', 'LGA-0690'),
-    ('842e3605-aeab-4c89-b9d5-b43339b8ffe7', (SELECT id FROM states WHERE name = 'Rivers'), 'Emohua', 'LGA-0691'),
-    ('d73da8de-c74e-4934-9b2f-a5f1c369d20c', (SELECT id FROM states WHERE name = 'Rivers'), 'Etche', 'LGA-0692'),
-    ('6402c840-241a-4464-8143-d27245a842ae', (SELECT id FROM states WHERE name = 'Rivers'), 'Gokana', 'LGA-0693'),
-    ('8e7e6605-1101-4402-940b-14a892152461', (SELECT id FROM states WHERE name = 'Rivers'), 'Ikwerre', 'LGA-0694'),
-    ('2d60c731-b582-4b87-8cf2-0c67f5edb128', (SELECT id FROM states WHERE name = 'Rivers'), 'Khana', 'LGA-0695'),
-    ('82e4ce21-637d-4de2-afa0-a9b4cc3b5947', (SELECT id FROM states WHERE name = 'Rivers'), 'Obio/Akpor', 'LGA-0696'),
-    ('2cd2ceb6-8deb-47b1-9585-ec49596b926f', (SELECT id FROM states WHERE name = 'Rivers'), 'Ogba/Egbema/Ndoni', 'LGA-0697'),
-    ('6c105670-e17c-4b6c-9401-00b6ba975b02', (SELECT id FROM states WHERE name = 'Rivers'), 'Ogu/Bolo', 'LGA-0698'),
-    ('96fd9830-1be8-4062-93b8-614314ea8be7', (SELECT id FROM states WHERE name = 'Rivers'), 'Okrika', 'LGA-0699'),
-    ('acabd2e8-2a4f-4f99-8f4d-f6cfb9a35c1b', (SELECT id FROM states WHERE name = 'Rivers'), 'Omuma', 'LGA-0700'),
-    ('7cb94a83-ea1a-414f-8b8b-ef2ac037cfa9', (SELECT id FROM states WHERE name = 'Rivers'), 'Opobo/Nkoro', 'LGA-0701'),
-    ('4c3c2290-6973-44cd-a62a-6db90d8e326c', (SELECT id FROM states WHERE name = 'Rivers'), 'Oyigbo', 'LGA-0702'),
-    ('405ae352-bcb2-4d02-876a-8d85fce9fc66', (SELECT id FROM states WHERE name = 'Rivers'), 'Port Harcourt', 'LGA-0703'),
-    ('df359153-4c57-487d-8e0f-02bbcc1f6dca', (SELECT id FROM states WHERE name = 'Rivers'), 'Tai', 'LGA-0704'),
-    ('6f544b0b-2818-4f4e-984e-f247cf7faa1c', (SELECT id FROM states WHERE name = 'Sokoto'), 'Binji', 'LGA-0705'),
-    ('08f2b338-a45c-42c7-8ae3-d174b6cd7082', (SELECT id FROM states WHERE name = 'Sokoto'), 'Bodinga', 'LGA-0706'),
-    ('e667b037-ae42-438d-b4b7-9b07647b0904', (SELECT id FROM states WHERE name = 'Sokoto'), 'Dange Shuni', 'LGA-0707'),
-    ('1f6ffee3-95d4-4db4-a624-3682de517807', (SELECT id FROM states WHERE name = 'Sokoto'), 'Gada', 'LGA-0708'),
-    ('3f65b7d5-043e-4bcb-b771-f0d7ac0fe071', (SELECT id FROM states WHERE name = 'Sokoto'), 'Goronyo', 'LGA-0709'),
-    ('4703be8f-6f11-4beb-af97-fe4c63ccddc9', (SELECT id FROM states WHERE name = 'Sokoto'), 'Gudu', 'LGA-0710'),
-    ('3bc2c225-3971-4628-955e-700fb23062ee', (SELECT id FROM states WHERE name = 'Sokoto'), 'Gwadabawa', 'LGA-0711'),
-    ('97cde3d4-3582-4b6c-ba5c-1f34ade8f0c5', (SELECT id FROM states WHERE name = 'Sokoto'), 'Illela', 'LGA-0712'),
-    ('c6de90ca-cd44-47da-92c0-d117ba6a22b2', (SELECT id FROM states WHERE name = 'Sokoto'), 'Isa', 'LGA-0713'),
-    ('f6d73fda-04a2-46bb-8358-302d64d6174b', (SELECT id FROM states WHERE name = 'Sokoto'), 'Kebbe', 'LGA-0714'),
-    ('145dbd8a-ba25-468e-968d-9a7a572ef9e0', (SELECT id FROM states WHERE name = 'Sokoto'), 'Kware', 'LGA-0715'),
-    ('32fdad7b-d6b5-4d07-8cb8-5ad6a006f3da', (SELECT id FROM states WHERE name = 'Sokoto'), 'Rabah', 'LGA-0716'),
-    ('9a351abc-1f02-46e0-bb67-6b84ec2dab1a', (SELECT id FROM states WHERE name = 'Sokoto'), 'Sabon Birni', 'LGA-0717'),
-    ('83c5c166-83ea-4f98-afb2-1c6eeb34c473', (SELECT id FROM states WHERE name = 'Sokoto'), 'Shagari', 'LGA-0718'),
-    ('01e75207-cfae-4b46-b5e9-739c8f14ab7f', (SELECT id FROM states WHERE name = 'Sokoto'), 'Silame', 'LGA-0719'),
-    ('0b4a7deb-eda2-4901-828a-553ad6ddb997', (SELECT id FROM states WHERE name = 'Sokoto'), 'Sokoto North', 'LGA-0720'),
-    ('3154605d-bea0-4f2d-881f-46ad7a8e144a', (SELECT id FROM states WHERE name = 'Sokoto'), 'Sokoto South', 'LGA-0721'),
-    ('e7625d2d-796c-4c21-a217-02dcfa2cc841', (SELECT id FROM states WHERE name = 'Sokoto'), 'Tambuwal', 'LGA-0722'),
-    ('e8572ecd-b2c4-4b95-9df4-6af0ee2682c4', (SELECT id FROM states WHERE name = 'Sokoto'), 'Tangaza', 'LGA-0723'),
-    ('f2f0422d-8a8f-47f1-8d93-3a6eda8a1d28', (SELECT id FROM states WHERE name = 'Sokoto'), 'Tureta', 'LGA-0724'),
-    ('ca2ce843-a1b8-4c85-aa0a-9e44d2633f04', (SELECT id FROM states WHERE name = 'Sokoto'), 'Wamakko', 'LGA-0725'),
-    ('937eb9bd-5c64-451a-a617-7365b5cb5ca9', (SELECT id FROM states WHERE name = 'Sokoto'), 'Wurno', 'LGA-0726'),
-    ('bbf020fa-01cc-44e3-903e-e81fdee1a0cc', (SELECT id FROM states WHERE name = 'Sokoto'), 'Yabo', 'LGA-0727'),
-    ('4679a03c-1a5e-4957-9291-381120939145', (SELECT id FROM states WHERE name = 'Taraba'), 'Ardo Kola', 'LGA-0728'),
-    ('124e2fdd-8b4e-4f3c-a1e7-65da150214dc', (SELECT id FROM states WHERE name = 'Taraba'), 'Bali', 'LGA-0729'),
-    ('5c399b43-ff6b-4bde-b39b-96c9df541353', (SELECT id FROM states WHERE name = 'Taraba'), 'Donga', 'LGA-0730'),
-    ('c8356ebd-6835-44b9-8d6d-7a82832e991e', (SELECT id FROM states WHERE name = 'Taraba'), 'Gashaka', 'LGA-0731'),
-    ('c88a23a0-96dc-410c-9fa0-4d4148eea45d', (SELECT id FROM states WHERE name = 'Taraba'), 'Gassol', 'LGA-0732'),
-    ('65a4b8ab-193c-461d-aa7f-36f2147b7645', (SELECT id FROM states WHERE name = 'Taraba'), 'Ibi', 'LGA-0733'),
-    ('db55cc7a-29a0-4538-bd12-f199b4345ea9', (SELECT id FROM states WHERE name = 'Taraba'), 'Jalingo', 'LGA-0734'),
-    ('fd22f60c-971f-4817-a6d2-bf89776f6202', (SELECT id FROM states WHERE name = 'Taraba'), 'Karim Lamido', 'LGA-0735'),
-    ('40635fcc-54c9-4da3-a3d3-70c5adf7f0a6', (SELECT id FROM states WHERE name = 'Taraba'), 'Kurmi', 'LGA-0736'),
-    ('fdb41fcb-b9b4-4a75-bf15-1585596ec2e0', (SELECT id FROM states WHERE name = 'Taraba'), 'Lau', 'LGA-0737'),
-    ('ca6c2a67-9ce3-4342-8e03-af68f24c115c', (SELECT id FROM states WHERE name = 'Taraba'), 'Sardauna', 'LGA-0738'),
-    ('535a6f28-6e00-41e3-859e-53237d971e6c', (SELECT id FROM states WHERE name = 'Taraba'), 'Takum', 'LGA-0739'),
-    ('33e083e8-ce1a-434f-8940-d4c416744488', (SELECT id FROM states WHERE name = 'Taraba'), 'Ussa', 'LGA-0740'),
-    ('de51ccfa-98a3-40fb-8f47-5bc344bc69e7', (SELECT id FROM states WHERE name = 'Taraba'), 'Wukari', 'LGA-0741'),
-    ('da48b8fc-67c3-4819-8770-70fc966538eb', (SELECT id FROM states WHERE name = 'Taraba'), 'Yorro', 'LGA-0742'),
-    ('f1af3629-5818-4289-84ff-1e33599c5b18', (SELECT id FROM states WHERE name = 'Taraba'), 'Zing', 'LGA-0743'),
-    ('621df2ae-48f4-4303-80b8-3e89b75a12ba', (SELECT id FROM states WHERE name = 'Yobe'), 'Bade', 'LGA-0744'),
-    ('4b4d5ec9-b926-458c-b2c9-dedbc61bdfaf', (SELECT id FROM states WHERE name = 'Yobe'), 'Bursari', 'LGA-0745'),
-    ('faa22218-cd3c-42a6-871c-88b25ec7b30b', (SELECT id FROM states WHERE name = 'Yobe'), 'Damaturu', 'LGA-0746'),
-    ('330bed92-5e60-4c02-887c-a949bac07c48', (SELECT id FROM states WHERE name = 'Yobe'), 'Fika', 'LGA-0747'),
-    ('5ae5adf9-6117-4da3-a65f-65a688636fac', (SELECT id FROM states WHERE name = 'Yobe'), 'Fune', 'LGA-0748'),
-    ('bce8ccd0-dffa-4d4b-831d-66b45c049f5f', (SELECT id FROM states WHERE name = 'Yobe'), 'Geidam', 'LGA-0749'),
-    ('0985306b-791b-4b6e-903d-cf19f3270b27', (SELECT id FROM states WHERE name = 'Yobe'), 'Gujba', 'LGA-0750'),
-    ('acffec4f-e648-4f4b-bcff-7dfd3dd29d3f', (SELECT id FROM states WHERE name = 'Yobe'), 'Gulani', 'LGA-0751'),
-    ('ce985a2a-e05b-463a-b83d-a0fd764e6784', (SELECT id FROM states WHERE name = 'Yobe'), 'Jakusko', 'LGA-0752'),
-    ('4bac4079-95e6-4c8e-9603-663b06097e07', (SELECT id FROM states WHERE name = 'Yobe'), 'Karasuwa', 'LGA-0753'),
-    ('c1867d0f-f527-4204-8811-0cfe9a471768', (SELECT id FROM states WHERE name = 'Yobe'), 'Machina', 'LGA-0754'),
-    ('3c371ad2-583e-4e26-a3ac-92180b4f6a79', (SELECT id FROM states WHERE name = 'Yobe'), 'Nangere', 'LGA-0755'),
-    ('e733a78e-322e-42f8-a779-a9264a47b9b5', (SELECT id FROM states WHERE name = 'Yobe'), 'Nguru', 'LGA-0756'),
-    ('425e1a49-9267-4103-936f-66177c2a23ed', (SELECT id FROM states WHERE name = 'Yobe'), 'Potiskum', 'LGA-0757'),
-    ('50fc09b2-802e-4d75-907d-2ab76193aa12', (SELECT id FROM states WHERE name = 'Yobe'), 'Tarmuwa', 'LGA-0758'),
-    ('d89171f5-9988-490d-bf26-b97580737a33', (SELECT id FROM states WHERE name = 'Yobe'), 'Yunusari', 'LGA-0759'),
-    ('395dd78f-7548-4842-95f6-ea203ff20150', (SELECT id FROM states WHERE name = 'Yobe'), 'Yusufari', 'LGA-0760'),
-    ('01a758f0-56ad-4c37-9888-2da9843c1131', (SELECT id FROM states WHERE name = 'Zamfara'), 'Anka', 'LGA-0761'),
-    ('a21ffd9f-9bb0-4783-9cb5-a7f64dc722c2', (SELECT id FROM states WHERE name = 'Zamfara'), 'Bakura', 'LGA-0762'),
-    ('65c45105-3af0-4ab2-90e7-783823c1626d', (SELECT id FROM states WHERE name = 'Zamfara'), 'Birnin Magaji/Kiyaw', 'LGA-0763'),
-    ('574b65be-87a4-45da-82b1-66f51e1115d7', (SELECT id FROM states WHERE name = 'Zamfara'), 'Bukkuyum', 'LGA-0764'),
-    ('ed995267-3dcc-404f-9a7c-fbc434cb10eb', (SELECT id FROM states WHERE name = 'Zamfara'), 'Bungudu', 'LGA-0765'),
-    ('55b1c546-424f-4be4-b0c4-0125665433aa', (SELECT id FROM states WHERE name = 'Zamfara'), 'Gummi', 'LGA-0766'),
-    ('7f4f943c-72f5-48a4-a3df-1e2206bf099f', (SELECT id FROM states WHERE name = 'Zamfara'), 'Gusau', 'LGA-0767'),
-    ('71f09f4d-9097-41b6-81c5-d6427f4df497', (SELECT id FROM states WHERE name = 'Zamfara'), 'Kaura Namoda', 'LGA-0768'),
-    ('17a29954-d37a-4868-8dab-c90e3d8182e9', (SELECT id FROM states WHERE name = 'Zamfara'), 'Maradun', 'LGA-0769'),
-    ('01fb44a7-b54a-45b8-b35d-bc6c67845956', (SELECT id FROM states WHERE name = 'Zamfara'), 'Maru', 'LGA-0770'),
-    ('53e93d09-6f49-4190-a55a-83234b9d06b6', (SELECT id FROM states WHERE name = 'Zamfara'), 'Shinkafi', 'LGA-0771'),
-    ('56674305-2482-46b6-91cb-1bb05271ae93', (SELECT id FROM states WHERE name = 'Zamfara'), 'Talata Mafara', 'LGA-0772'),
-    ('e35becc9-c117-4e83-a452-bd6d934cd204', (SELECT id FROM states WHERE name = 'Zamfara'), 'Chafe', 'LGA-0773'),
-    ('1d9d4201-da4b-4a96-91b2-72db8d1354c0', (SELECT id FROM states WHERE name = 'Zamfara'), 'Zurmi', 'LGA-0774');
\ No newline at end of file
+    ('c21d863c-4faa-4406-8b8e-74a8e4f2ab53', (SELECT id FROM states WHERE name = 'Abia'), 'Aba North', 'NG-AB-ABN'),
+    ('6fbf18be-9850-4a2a-8890-250096d6d6e2', (SELECT id FROM states WHERE name = 'Abia'), 'Aba South', 'NG-AB-ABS'),
+    ('9317c3e1-c654-4d4d-bf30-282cfecd8ee7', (SELECT id FROM states WHERE name = 'Abia'), 'Arochukwu', 'NG-AB-ARC'),
+    ('bd9370bc-3b19-4994-ab37-cfd4130a20b0', (SELECT id FROM states WHERE name = 'Abia'), 'Bende', 'NG-AB-BND'),
+    ('d6f6f999-760c-47de-b045-6434d28981d1', (SELECT id FROM states WHERE name = 'Abia'), 'Ikwuano', 'NG-AB-IKW'),
+    ('249a43bb-de7a-4eb7-80b0-3b0b4b70a98e', (SELECT id FROM states WHERE name = 'Abia'), 'Isiala Ngwa North', 'NG-AB-INN'),
+    ('c9b8a871-9215-48e4-a907-00a16e208ca7', (SELECT id FROM states WHERE name = 'Abia'), 'Isiala Ngwa South', 'NG-AB-INS'),
+    ('e710caaa-742b-48af-a1aa-2b902421954f', (SELECT id FROM states WHERE name = 'Abia'), 'Isuikwuato', 'NG-AB-ISK'),
+    ('c85b7052-06d2-4282-83de-805c6e5d117e', (SELECT id FROM states WHERE name = 'Abia'), 'Obi Ngwa', 'NG-AB-OBN'),
+    ('b6f727f3-6d09-4209-a6cd-f6061950890f', (SELECT id FROM states WHERE name = 'Abia'), 'Ohafia', 'NG-AB-OHF'),
+    ('caeb10e6-6146-4b5a-acc6-6a65dbf821f3', (SELECT id FROM states WHERE name = 'Abia'), 'Osisioma Ngwa', 'NG-AB-OSN'),
+    ('f6a26698-214f-42cc-9bbd-90ace3ccff45', (SELECT id FROM states WHERE name = 'Abia'), 'Ugwunagbo', 'NG-AB-UGW'),
+    ('06e760c2-f0f7-41b3-826c-655343d4c16a', (SELECT id FROM states WHERE name = 'Abia'), 'Ukwa East', 'NG-AB-UKE'),
+    ('c1099cb0-be66-403c-bb8a-a1b6acf03c92', (SELECT id FROM states WHERE name = 'Abia'), 'Ukwa West', 'NG-AB-UKW'),
+    ('a38dd84e-33d2-40e5-9ebb-0d6ae38337bf', (SELECT id FROM states WHERE name = 'Abia'), 'Umuahia North', 'NG-AB-UM2'),
+    ('3c304d56-9d31-4942-b4d7-89b9869f1f64', (SELECT id FROM states WHERE name = 'Abia'), 'Umuahia South', 'NG-AB-UMS'),
+    ('60427195-c0f7-4577-91e9-0676e8b5753d', (SELECT id FROM states WHERE name = 'Abia'), 'Umu Nneochi', 'NG-AB-UMN'),
+    ('2ad1afda-4ab3-49ff-994c-f2fff5e2177f', (SELECT id FROM states WHERE name = 'Adamawa'), 'Demsa', 'NG-AD-DMS'),
+    ('393ccbbe-b43a-4910-9a71-0793836d649e', (SELECT id FROM states WHERE name = 'Adamawa'), 'Fufure', 'NG-AD-FFR'),
+    ('83a5d598-4b36-40a2-96bf-afad7b1a1aa5', (SELECT id FROM states WHERE name = 'Adamawa'), 'Ganye', 'NG-AD-GNY'),
+    ('04c71ba3-e770-40f2-aaaa-8231a3643e43', (SELECT id FROM states WHERE name = 'Adamawa'), 'Guyuk', 'NG-AD-GYK'),
+    ('bb049ea4-4501-4862-86b4-4244f9761e94', (SELECT id FROM states WHERE name = 'Adamawa'), 'Girei', 'NG-AD-GRI'),
+    ('536d2dd6-52eb-4030-8ce9-ad62aebbd742', (SELECT id FROM states WHERE name = 'Adamawa'), 'Gombi', 'NG-AD-GMB'),
+    ('edf4f908-7670-4abb-8230-5ab37c5b12a6', (SELECT id FROM states WHERE name = 'Adamawa'), 'Hong', 'NG-AD-HNG'),
+    ('4f113b07-f4ad-4d4e-96e6-2d4130a8d930', (SELECT id FROM states WHERE name = 'Adamawa'), 'Jada', 'NG-AD-JDA'),
+    ('cf2c3ed6-dc72-4b46-aac0-33ca1429cf92', (SELECT id FROM states WHERE name = 'Adamawa'), 'Lamurde', 'NG-AD-LMR'),
+    ('676927b4-865b-4ddd-af3c-377e80912952', (SELECT id FROM states WHERE name = 'Adamawa'), 'Madagali', 'NG-AD-MDG'),
+    ('b50f0e82-ebfa-47bc-87ba-e591b639cee6', (SELECT id FROM states WHERE name = 'Adamawa'), 'Maiha', 'NG-AD-MHA'),
+    ('5908c5d2-6191-4acd-8dc1-0e9061f500d0', (SELECT id FROM states WHERE name = 'Adamawa'), 'Mayo Belwa', 'NG-AD-MYB'),
+    ('2b62548b-cf17-4bc9-8c08-93eaa0d4c8c1', (SELECT id FROM states WHERE name = 'Adamawa'), 'Michika', 'NG-AD-MCH'),
+    ('79be97c6-ace8-4c6d-a00b-5b0bb49a1699', (SELECT id FROM states WHERE name = 'Adamawa'), 'Mubi North', 'NG-AD-MBN'),
+    ('6585cd53-00e9-448f-afbe-5f671fd8e8cc', (SELECT id FROM states WHERE name = 'Adamawa'), 'Mubi South', 'NG-AD-MBS'),
+    ('44724563-7e13-43ab-8929-49df2adb8a55', (SELECT id FROM states WHERE name = 'Adamawa'), 'Numan', 'NG-AD-NMN'),
+    ('91c6849e-e7b0-4027-a093-90d3e65a5105', (SELECT id FROM states WHERE name = 'Adamawa'), 'Shelleng', 'NG-AD-SHL'),
+    ('3d08a354-bcd2-44dd-b1d3-2c3eb487aa1e', (SELECT id FROM states WHERE name = 'Adamawa'), 'Song', 'NG-AD-SNG'),
+    ('b0ca9e38-564b-4f31-a87a-9babd9d857c8', (SELECT id FROM states WHERE name = 'Adamawa'), 'Toungo', 'NG-AD-TNG'),
+    ('32194863-70cd-4e9f-81ab-a8d8cf4400e3', (SELECT id FROM states WHERE name = 'Adamawa'), 'Yola North', 'NG-AD-YLN'),
+    ('bc6ac9ef-c043-491b-a8ec-c38762da6b28', (SELECT id FROM states WHERE name = 'Adamawa'), 'Yola South', 'NG-AD-YLS'),
+    ('9feada09-9d29-4406-afb6-1d9e302f4a31', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Abak', 'NG-AK-ABK'),
+    ('5e3d7360-f8ab-4bae-aa96-ad8e794d615f', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Eastern Obolo', 'NG-AK-ESO'),
+    ('2c72fce0-c047-4534-87e4-5e5edf84419a', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Eket', 'NG-AK-EKT'),
+    ('54a658bf-3d94-48ea-afdb-9e81e5a340e2', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Esit Eket', 'NG-AK-ESE'),
+    ('3b5a9715-26e8-4cb8-8775-6dac3ef1ac60', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Essien Udim', 'NG-AK-ESU'),
+    ('c88620c1-e47b-40bc-bb85-f90d07ef67ff', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Etim Ekpo', 'NG-AK-ETE'),
+    ('b32688b5-ea7a-45bb-ae40-720e47a0a63b', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Etinan', 'NG-AK-ETN'),
+    ('1114670e-ac88-40fa-9261-db59d702319c', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ibeno', 'NG-AK-IBN'),
+    ('474272a4-8aa6-466d-8acc-fb16c60a3d3d', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ibesikpo Asutan', 'NG-AK-IBA'),
+    ('7ab27b83-770e-4baa-8da5-d5c4da3dc050', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ibiono-Ibom', 'NG-AK-IBI'),
+    ('5e5a1910-77a6-4cd2-afc6-a71b80dad232', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ika', 'NG-AK-IKA'),
+    ('f712c841-cbcc-4974-84ac-bf626824fa1e', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ikono', 'NG-AK-IKN'),
+    ('18c0edd4-770e-4213-849e-846ab3508d5c', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ikot Abasi', 'NG-AK-IK2'),
+    ('5f041625-7bbb-4109-bd71-4b7794107618', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ikot Ekpene', 'NG-AK-IKE'),
+    ('22a76a75-c582-4242-9833-7dc2152e4564', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ini', 'NG-AK-INI'),
+    ('6b79413e-2938-468e-b44a-6f7d0314a1b3', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Itu', 'NG-AK-ITU'),
+    ('bc97c94f-6fb2-4146-812b-6c084301031d', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Mbo', 'NG-AK-MBO'),
+    ('12292d38-3f69-45a7-95d2-03d4a4974bf6', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Mkpat-Enin', 'NG-AK-MKE'),
+    ('ed440aea-b120-4683-a111-9c6e5ed1fc91', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Nsit-Atai', 'NG-AK-NSA'),
+    ('96001547-4715-43be-a67f-b1939635dcba', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Nsit-Ibom', 'NG-AK-NSI'),
+    ('24d54fdf-b75f-43cd-a6aa-cd7f3b4b09ff', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Nsit-Ubium', 'NG-AK-NSU'),
+    ('d0d0b7e0-83e9-444d-97b5-a3676aebfbaf', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Obot Akara', 'NG-AK-OBA'),
+    ('a96e6ab2-b234-4518-bf1f-a284d2bfde87', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Okobo', 'NG-AK-OKB'),
+    ('17dc5432-c35e-4a05-aab2-b5882b9baee4', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Onna', 'NG-AK-ONN'),
+    ('445f7685-fc91-41d2-9659-db15e39a0315', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Oron', 'NG-AK-ORN'),
+    ('088de810-10f4-4a87-b20c-633b74535055', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Oruk Anam', 'NG-AK-ORA'),
+    ('d5c12503-fa6b-4035-bd48-e802c40f378f', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Udung-Uko', 'NG-AK-UDU'),
+    ('e786738c-d5b9-4389-824e-9b6bc10e97e9', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Ukanafun', 'NG-AK-UKN'),
+    ('352c9084-2226-4098-bfd6-0fc62fb7f6cc', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Uruan', 'NG-AK-URN'),
+    ('e7da1c19-51db-4744-bb69-572a3bec58d3', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Urue-Offong/Oruko', 'NG-AK-UOO'),
+    ('a381b2d9-c18d-42f5-8b0a-ae81fe07c810', (SELECT id FROM states WHERE name = 'Akwa Ibom'), 'Uyo', 'NG-AK-UYO'),
+    ('c6cf8f4d-b7e7-4b32-a545-089b92dd6634', (SELECT id FROM states WHERE name = 'Anambra'), 'Aguata', 'NG-AN-AGT'),
+    ('53cd8d7a-fb1f-495e-866f-0b7a6457da95', (SELECT id FROM states WHERE name = 'Anambra'), 'Anambra East', 'NG-AN-ANE'),
+    ('c3340b5f-2a02-4b2e-bd8c-4be03f1d5f23', (SELECT id FROM states WHERE name = 'Anambra'), 'Anambra West', 'NG-AN-ANW'),
+    ('1ecbb605-0e13-4868-b0a1-d3339a738caf', (SELECT id FROM states WHERE name = 'Anambra'), 'Anaocha', 'NG-AN-ANC'),
+    ('8d738087-2dfd-4d12-ab4b-c728cdecf408', (SELECT id FROM states WHERE name = 'Anambra'), 'Awka North', 'NG-AN-AWN'),
+    ('a3748f85-af67-4c3e-bdd8-73430d86a7cf', (SELECT id FROM states WHERE name = 'Anambra'), 'Awka South', 'NG-AN-AWS'),
+    ('e4611870-fdfe-4250-ba14-941aabbd7a00', (SELECT id FROM states WHERE name = 'Anambra'), 'Ayamelum', 'NG-AN-AYM'),
+    ('298fb4e4-c8bb-4694-bc52-ca31f4885b1f', (SELECT id FROM states WHERE name = 'Anambra'), 'Dunukofia', 'NG-AN-DNK'),
+    ('da2e645c-4b30-4eac-85c0-6d1695cc95c7', (SELECT id FROM states WHERE name = 'Anambra'), 'Ekwusigo', 'NG-AN-EKW'),
+    ('068cb749-6a47-4c6e-a579-3fb78ab34570', (SELECT id FROM states WHERE name = 'Anambra'), 'Idemili North', 'NG-AN-IDN'),
+    ('c18954b7-1bc2-41b0-93e5-37b63149ac43', (SELECT id FROM states WHERE name = 'Anambra'), 'Idemili South', 'NG-AN-IDS'),
+    ('0b9a7ce9-31e5-4c6f-a430-b3864c9a8fb9', (SELECT id FROM states WHERE name = 'Anambra'), 'Ihiala', 'NG-AN-IHL'),
+    ('698ee059-bd9c-49d3-89fb-f4997e4a90eb', (SELECT id FROM states WHERE name = 'Anambra'), 'Njikoka', 'NG-AN-NJK'),
+    ('5564e73d-3f45-483a-9a94-77adb5f1628a', (SELECT id FROM states WHERE name = 'Anambra'), 'Nnewi North', 'NG-AN-NNN'),
+    ('198ab072-722b-4f86-9055-5e83e9081a93', (SELECT id FROM states WHERE name = 'Anambra'), 'Nnewi South', 'NG-AN-NNS'),
+    ('b830c3b3-65fb-4af8-9380-25c6a4f96826', (SELECT id FROM states WHERE name = 'Anambra'), 'Ogbaru', 'NG-AN-OGB'),
+    ('661a623f-595c-4643-a055-16a87b680789', (SELECT id FROM states WHERE name = 'Anambra'), 'Onitsha North', 'NG-AN-ONN'),
+    ('86cd56bf-5997-44af-9834-7d73ced5ceb0', (SELECT id FROM states WHERE name = 'Anambra'), 'Onitsha South', 'NG-AN-ONS'),
+    ('40740340-9183-4ee2-928b-e891e07be1df', (SELECT id FROM states WHERE name = 'Anambra'), 'Orumba North', 'NG-AN-ORN'),
+    ('57fa3bee-e7e0-4ed2-9f9a-047da8295cc1', (SELECT id FROM states WHERE name = 'Anambra'), 'Orumba South', 'NG-AN-ORS'),
+    ('17e5356d-9be0-4407-871d-442793397f03', (SELECT id FROM states WHERE name = 'Anambra'), 'Oyi', 'NG-AN-OYI'),
+    ('9afcc7e1-5614-47ae-b0f9-df11cc94d11f', (SELECT id FROM states WHERE name = 'Bauchi'), 'Alkaleri', 'NG-BA-ALK'),
+    ('e8446ba3-cbdd-4d6b-916d-078d376a5b46', (SELECT id FROM states WHERE name = 'Bauchi'), 'Bauchi', 'NG-BA-BCH'),
+    ('e5135314-9a0a-46e0-bb5e-714121bd8f29', (SELECT id FROM states WHERE name = 'Bauchi'), 'Bogoro', 'NG-BA-BGR'),
+    ('729d11d6-f94e-478c-9c72-f8a887064dd4', (SELECT id FROM states WHERE name = 'Bauchi'), 'Damban', 'NG-BA-DMB'),
+    ('5d9a846c-d26e-4f9c-b3ab-bb6ee09f7a52', (SELECT id FROM states WHERE name = 'Bauchi'), 'Darazo', 'NG-BA-DRZ'),
+    ('861a370a-3fa6-4b61-bd3c-dde0ada25a47', (SELECT id FROM states WHERE name = 'Bauchi'), 'Dass', 'NG-BA-DSS'),
+    ('a48b8fa6-cac1-4ea0-9a85-b923a8859aae', (SELECT id FROM states WHERE name = 'Bauchi'), 'Gamawa', 'NG-BA-GMW'),
+    ('4f41aad4-b4c1-4cfc-ab47-30fc60f1c0a2', (SELECT id FROM states WHERE name = 'Bauchi'), 'Ganjuwa', 'NG-BA-GNJ'),
+    ('4a78b8ac-3d69-4101-869c-4e9feab55ea5', (SELECT id FROM states WHERE name = 'Bauchi'), 'Giade', 'NG-BA-GDI'),
+    ('39c4c41d-77d1-4927-91c4-a7e2a12b2c33', (SELECT id FROM states WHERE name = 'Bauchi'), 'Itas/Gadau', 'NG-BA-ITG'),
+    ('dd9192aa-a6b9-462a-a61e-4f337d5d215c', (SELECT id FROM states WHERE name = 'Bauchi'), 'Jama''are', 'NG-BA-JMA'),
+    ('148b534b-bba6-4c67-a583-bdda8ecd6ad1', (SELECT id FROM states WHERE name = 'Bauchi'), 'Katagum', 'NG-BA-KTG'),
+    ('09177802-8995-4735-836d-3c375a6fa009', (SELECT id FROM states WHERE name = 'Bauchi'), 'Kirfi', 'NG-BA-KRF'),
+    ('7e80a006-25de-41ca-82ea-d0b0402ba0c3', (SELECT id FROM states WHERE name = 'Bauchi'), 'Misau', 'NG-BA-MSI'),
+    ('dd1e3fae-e97c-49ff-9cc7-42c9a7c82949', (SELECT id FROM states WHERE name = 'Bauchi'), 'Ningi', 'NG-BA-NNG'),
+    ('acc4ae70-b6d2-4771-a5c7-affe0ec754e6', (SELECT id FROM states WHERE name = 'Bauchi'), 'Shira', 'NG-BA-SHR'),
+    ('115b327e-af26-42a6-ab36-bebd246ea581', (SELECT id FROM states WHERE name = 'Bauchi'), 'Tafawa Balewa', 'NG-BA-TFB'),
+    ('6b04cfe8-4667-4a1b-a31e-7249662c5525', (SELECT id FROM states WHERE name = 'Bauchi'), 'Toro', 'NG-BA-TRO'),
+    ('623c1293-0e55-4679-89d6-d2515d90fa94', (SELECT id FROM states WHERE name = 'Bauchi'), 'Warji', 'NG-BA-WRJ'),
+    ('a18a9e93-55ff-4737-a806-df6f1aa0afbc', (SELECT id FROM states WHERE name = 'Bauchi'), 'Zaki', 'NG-BA-ZKA'),
+    ('f1905d0f-729c-4dc5-bbaa-e599d1029aae', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Brass', 'NG-BY-BRS'),
+    ('60f2972b-b77e-4981-b5fb-c588aa00f454', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Ekeremor', 'NG-BY-EKR'),
+    ('b3882948-9c0a-4557-a7ba-72598e9ea8ed', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Kolokuma/Opokuma', 'NG-BY-KLO'),
+    ('74d05d12-3774-4e3f-a6b5-da32a6b123d1', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Nembe', 'NG-BY-NMB'),
+    ('ed793f7b-0296-4d7e-b328-39a0bf5b2cfc', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Ogbia', 'NG-BY-OGB'),
+    ('69a5188d-f99e-4522-b14d-fbc0a6a5e2ee', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Sagbama', 'NG-BY-SGB'),
+    ('1dfc8dc8-7db4-4666-8b18-e71bd172337e', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Southern Ijaw', 'NG-BY-STI'),
+    ('ca74b19e-d1a3-405b-a8eb-5127945ff106', (SELECT id FROM states WHERE name = 'Bayelsa'), 'Yenagoa', 'NG-BY-YNG'),
+    ('3cb3408d-ffc6-4dc8-b36e-24710db06290', (SELECT id FROM states WHERE name = 'Benue'), 'Ado', 'NG-BE-ADO'),
+    ('aa54e4dd-5203-488c-8bad-f4a5899f589c', (SELECT id FROM states WHERE name = 'Benue'), 'Agatu', 'NG-BE-AGT'),
+    ('0c556c4f-6f19-4a38-aa52-d6f6054f1d7c', (SELECT id FROM states WHERE name = 'Benue'), 'Apa', 'NG-BE-APA'),
+    ('665516d6-4a67-4bf1-8320-f2006c80a418', (SELECT id FROM states WHERE name = 'Benue'), 'Buruku', 'NG-BE-BRK'),
+    ('aaa93679-60aa-4d71-93b6-ea46ffe17877', (SELECT id FROM states WHERE name = 'Benue'), 'Gboko', 'NG-BE-GBK'),
+    ('408bea54-4dbe-4c37-bb00-58ecefb78de0', (SELECT id FROM states WHERE name = 'Benue'), 'Guma', 'NG-BE-GMU'),
+    ('e6d5928e-6056-4a43-a66c-de33d616ae24', (SELECT id FROM states WHERE name = 'Benue'), 'Gwer East', 'NG-BE-GWE'),
+    ('e2d674fe-6b7f-4f57-bb06-4febc90367ab', (SELECT id FROM states WHERE name = 'Benue'), 'Gwer West', 'NG-BE-GWW'),
+    ('83cd81b5-6d05-4f02-b9ed-69d27d9fd98a', (SELECT id FROM states WHERE name = 'Benue'), 'Katsina-Ala', 'NG-BE-KTA'),
+    ('d6f6313d-bbf1-4258-90ed-be9e3b6b9c8d', (SELECT id FROM states WHERE name = 'Benue'), 'Konshisha', 'NG-BE-KNS'),
+    ('45292410-1d20-41dc-8642-97bfc35b325f', (SELECT id FROM states WHERE name = 'Benue'), 'Kwande', 'NG-BE-KWN'),
+    ('1a07b86d-c270-4b87-83e0-e2ebf3044284', (SELECT id FROM states WHERE name = 'Benue'), 'Logo', 'NG-BE-LGO'),
+    ('66c58c86-faa8-4fda-957f-d5368b946ba3', (SELECT id FROM states WHERE name = 'Benue'), 'Makurdi', 'NG-BE-MKR'),
+    ('b40e6ca5-e3bd-4276-b54b-252d70bdb8cf', (SELECT id FROM states WHERE name = 'Benue'), 'Obi', 'NG-BE-OBI'),
+    ('6bf33d3e-24c4-4d76-a332-250f69c3507f', (SELECT id FROM states WHERE name = 'Benue'), 'Ogbadibo', 'NG-BE-OGB'),
+    ('d8af164a-6628-421e-ac9d-5b6143189303', (SELECT id FROM states WHERE name = 'Benue'), 'Ohimini', 'NG-BE-OHM'),
+    ('38c0c448-bb4e-405c-8fa9-a6f67e5fe560', (SELECT id FROM states WHERE name = 'Benue'), 'Oju', 'NG-BE-OJU'),
+    ('3d23b8d1-b25a-4126-9001-f17981080c30', (SELECT id FROM states WHERE name = 'Benue'), 'Okpokwu', 'NG-BE-OKP'),
+    ('fd2a2013-ea2c-42a3-84bf-5cbda264abc7', (SELECT id FROM states WHERE name = 'Benue'), 'Oturkpo', 'NG-BE-OTR'),
+    ('48517cab-30d0-4c86-a735-8f2ef69357c4', (SELECT id FROM states WHERE name = 'Benue'), 'Tarka', 'NG-BE-TRK'),
+    ('0e138a65-45e0-4cab-8bed-69234c4e99d6', (SELECT id FROM states WHERE name = 'Benue'), 'Ukum', 'NG-BE-UKM'),
+    ('b767df6d-2052-4f16-9936-60f59fa9f54f', (SELECT id FROM states WHERE name = 'Benue'), 'Ushongo', 'NG-BE-USH'),
+    ('e6a9b58b-8e60-45e1-8b39-5e24c45e399d', (SELECT id FROM states WHERE name = 'Benue'), 'Vandeikya', 'NG-BE-VND'),
+    ('cd08ce9f-c88c-4525-8a0f-a3478f699c4d', (SELECT id FROM states WHERE name = 'Borno'), 'Abadam', 'NG-BO-ABD'),
+    ('6a7d6ee8-aa40-46d0-9163-7c8990e42c8d', (SELECT id FROM states WHERE name = 'Borno'), 'Askira/Uba', 'NG-BO-ASU'),
+    ('f8f4b8f6-4f99-4891-a8da-e153283653c8', (SELECT id FROM states WHERE name = 'Borno'), 'Bama', 'NG-BO-BMA'),
+    ('40cc3aa1-4ead-4e1a-bcc0-c45a1b5f8911', (SELECT id FROM states WHERE name = 'Borno'), 'Bayo', 'NG-BO-BYA'),
+    ('240fcc72-cdee-4e3d-8bfb-2e39ed41584f', (SELECT id FROM states WHERE name = 'Borno'), 'Biu', 'NG-BO-BIU'),
+    ('56e6c6a3-6999-4912-acce-eddcc3a1472e', (SELECT id FROM states WHERE name = 'Borno'), 'Chibok', 'NG-BO-CHB'),
+    ('a7110e5e-4270-48f9-9baa-2f700ca716c4', (SELECT id FROM states WHERE name = 'Borno'), 'Damboa', 'NG-BO-DMB'),
+    ('f49493c4-397c-4f6e-8661-09761306238b', (SELECT id FROM states WHERE name = 'Borno'), 'Dikwa', 'NG-BO-DKW'),
+    ('83ab0a8a-b28b-468f-840f-a2c07ede2e13', (SELECT id FROM states WHERE name = 'Borno'), 'Gubio', 'NG-BO-GBU'),
+    ('8e8b14bb-c375-4790-8be5-b8d98d1f0416', (SELECT id FROM states WHERE name = 'Borno'), 'Guzamala', 'NG-BO-GZM'),
+    ('bead100c-7f1a-4d55-b510-c114457cf465', (SELECT id FROM states WHERE name = 'Borno'), 'Gwoza', 'NG-BO-GWZ'),
+    ('adc94a94-0d07-4083-950a-e3bf85a4b64c', (SELECT id FROM states WHERE name = 'Borno'), 'Hawul', 'NG-BO-HWL'),
+    ('b0d91f99-d43b-48fa-931c-55b6156d6c47', (SELECT id FROM states WHERE name = 'Borno'), 'Jere', 'NG-BO-JRE'),
+    ('45ea1416-bbf9-4c96-8b8f-63b6423526ba', (SELECT id FROM states WHERE name = 'Borno'), 'Kaga', 'NG-BO-KGA'),
+    ('5506de88-fc00-4a47-aa96-54f15cea08a3', (SELECT id FROM states WHERE name = 'Borno'), 'Kala/Balge', 'NG-BO-KLB'),
+    ('26ba2588-93fb-4d13-82d0-a9c8b4d48de8', (SELECT id FROM states WHERE name = 'Borno'), 'Konduga', 'NG-BO-KND'),
+    ('81952993-2199-494c-bad0-2ba65ae0172a', (SELECT id FROM states WHERE name = 'Borno'), 'Kukawa', 'NG-BO-KKW'),
+    ('a5edac5e-178a-431a-b660-0b6798735c8e', (SELECT id FROM states WHERE name = 'Borno'), 'Kwaya Kusar', 'NG-BO-KWK'),
+    ('d9c4cc4a-8c73-4b63-b5f5-ac240a269b66', (SELECT id FROM states WHERE name = 'Borno'), 'Mafa', 'NG-BO-MFA'),
+    ('09275dc6-ca78-49f9-b63e-2c7d1fc03d48', (SELECT id FROM states WHERE name = 'Borno'), 'Magumeri', 'NG-BO-MGM'),
+    ('48f4defc-057a-4575-80f6-7a6d08e2d686', (SELECT id FROM states WHERE name = 'Borno'), 'Maiduguri', 'NG-BO-MDG'),
+    ('34c1bcdd-60c7-45fa-baec-c578240f1f06', (SELECT id FROM states WHERE name = 'Borno'), 'Marte', 'NG-BO-MRT'),
+    ('f9db5db0-18e4-4da9-bad2-a62b2fffa41a', (SELECT id FROM states WHERE name = 'Borno'), 'Mobbar', 'NG-BO-MBB'),
+    ('00a6a682-9a60-4adc-83eb-c322ddd1460a', (SELECT id FROM states WHERE name = 'Borno'), 'Monguno', 'NG-BO-MNG'),
+    ('8e6af4c6-f8e9-4156-b915-a56b5d1d9175', (SELECT id FROM states WHERE name = 'Borno'), 'Ngala', 'NG-BO-NGL'),
+    ('1e4c033f-0b86-4ec0-a950-22d5939d5712', (SELECT id FROM states WHERE name = 'Borno'), 'Nganzai', 'NG-BO-NGN'),
+    ('5be95f29-7daf-4928-9c6a-4ada97e16a28', (SELECT id FROM states WHERE name = 'Borno'), 'Shani', 'NG-BO-SHN'),
+    ('bc2eef74-52aa-4921-935c-9e689c563723', (SELECT id FROM states WHERE name = 'Cross River'), 'Abi', 'NG-CR-ABI'),
+    ('1e189263-a4f5-4d78-86e8-8d18254cb191', (SELECT id FROM states WHERE name = 'Cross River'), 'Akamkpa', 'NG-CR-AKM'),
+    ('78c88a80-2f8a-41da-af8b-21d347aa6335', (SELECT id FROM states WHERE name = 'Cross River'), 'Akpabuyo', 'NG-CR-AKP'),
+    ('c4d7520b-c1e3-4cef-bb47-0c063df0c583', (SELECT id FROM states WHERE name = 'Cross River'), 'Bakassi', 'NG-CR-BKS'),
+    ('81cbfc70-6c19-49a1-bb56-e98ea30d8f55', (SELECT id FROM states WHERE name = 'Cross River'), 'Bekwarra', 'NG-CR-BKW'),
+    ('0b5261d4-c9e3-4c51-8a7b-504777de0712', (SELECT id FROM states WHERE name = 'Cross River'), 'Biase', 'NG-CR-BSI'),
+    ('b6a890dd-3c98-4554-b8d4-9878bd4f7f9a', (SELECT id FROM states WHERE name = 'Cross River'), 'Boki', 'NG-CR-BKO'),
+    ('49ee39b7-bd20-493d-a80b-7587c62d22e9', (SELECT id FROM states WHERE name = 'Cross River'), 'Calabar Municipal', 'NG-CR-CLM'),
+    ('8eee3150-110d-4e85-8d24-9ceaec7d15d1', (SELECT id FROM states WHERE name = 'Cross River'), 'Calabar South', 'NG-CR-CLS'),
+    ('7b2befa6-53db-4a0b-a478-da57222e0ca4', (SELECT id FROM states WHERE name = 'Cross River'), 'Etung', 'NG-CR-ETN'),
+    ('a006c4fd-2719-4118-b9f3-5ea91391bba2', (SELECT id FROM states WHERE name = 'Cross River'), 'Ikom', 'NG-CR-IKM'),
+    ('0265de8e-488a-46d5-92ba-7cd2489a7530', (SELECT id FROM states WHERE name = 'Cross River'), 'Obanliku', 'NG-CR-OBN'),
+    ('2a828bcf-0d44-4c95-9165-d5ad9fd3c877', (SELECT id FROM states WHERE name = 'Cross River'), 'Obubra', 'NG-CR-OBB'),
+    ('616e6b09-edbb-400a-a25c-260efd089453', (SELECT id FROM states WHERE name = 'Cross River'), 'Obudu', 'NG-CR-OBD'),
+    ('56a1edaf-fab6-4b25-9b06-5c96fe9a0fd5', (SELECT id FROM states WHERE name = 'Cross River'), 'Odukpani', 'NG-CR-ODK'),
+    ('4c3a04ce-f923-4e83-b8a0-b4850f36c903', (SELECT id FROM states WHERE name = 'Cross River'), 'Ogoja', 'NG-CR-OGJ'),
+    ('e9880264-4195-4e9a-962b-f7a61c635db1', (SELECT id FROM states WHERE name = 'Cross River'), 'Yakurr', 'NG-CR-YKR'),
+    ('7be5c63d-a0a9-4921-b7cf-e393569bd7b4', (SELECT id FROM states WHERE name = 'Cross River'), 'Yala', 'NG-CR-YLA'),
+    ('d47e9e89-8ae1-48b2-9d81-4ac3eaf71961', (SELECT id FROM states WHERE name = 'Delta'), 'Aniocha North', 'NG-DE-ANN'),
+    ('f0b140e2-8189-4563-981f-1c79649e4a0b', (SELECT id FROM states WHERE name = 'Delta'), 'Aniocha South', 'NG-DE-ANS'),
+    ('8bfed9d5-ff00-4e34-b54a-6a9ce0f8c205', (SELECT id FROM states WHERE name = 'Delta'), 'Bomadi', 'NG-DE-BMD'),
+    ('53972db4-ae32-4423-a5dc-789e907a1577', (SELECT id FROM states WHERE name = 'Delta'), 'Burutu', 'NG-DE-BRT'),
+    ('88c7300a-6ddb-44c0-a0cf-b57a5eb6245b', (SELECT id FROM states WHERE name = 'Delta'), 'Ethiope East', 'NG-DE-ETE'),
+    ('d954db05-6233-45b5-95d8-1118cbea891a', (SELECT id FROM states WHERE name = 'Delta'), 'Ethiope West', 'NG-DE-ETW'),
+    ('b4149618-6d9c-45a8-aba3-81b84d54eaf1', (SELECT id FROM states WHERE name = 'Delta'), 'Ika North East', 'NG-DE-INE'),
+    ('6010eea5-3ea6-454a-9e26-2a29167341a3', (SELECT id FROM states WHERE name = 'Delta'), 'Ika South', 'NG-DE-IKS'),
+    ('9ffdb7e3-6a80-4121-a513-299a85db57d1', (SELECT id FROM states WHERE name = 'Delta'), 'Isoko North', 'NG-DE-ISN'),
+    ('a858ff88-7546-4a30-b56e-b28b67d58ac7', (SELECT id FROM states WHERE name = 'Delta'), 'Isoko South', 'NG-DE-ISS'),
+    ('dd4265bb-de3a-4555-a779-2e2c937fbbb9', (SELECT id FROM states WHERE name = 'Delta'), 'Ndokwa East', 'NG-DE-NDE'),
+    ('02a74602-770d-44ca-9927-0bfc1dfc807f', (SELECT id FROM states WHERE name = 'Delta'), 'Ndokwa West', 'NG-DE-NDW'),
+    ('35803871-8225-4881-bd6a-ed43a48e41ce', (SELECT id FROM states WHERE name = 'Delta'), 'Okpe', 'NG-DE-OKP'),
+    ('aa0197e7-e828-4e3b-ab66-2f780fe77e4d', (SELECT id FROM states WHERE name = 'Delta'), 'Oshimili North', 'NG-DE-OSN'),
+    ('5192debb-09ff-4ade-81e4-c3b42e93e842', (SELECT id FROM states WHERE name = 'Delta'), 'Oshimili South', 'NG-DE-OSS'),
+    ('903dfa8d-70c4-4777-a46c-0b0cb4fcfef3', (SELECT id FROM states WHERE name = 'Delta'), 'Patani', 'NG-DE-PTN'),
+    ('14b662ca-8b98-4755-9254-1019127d7585', (SELECT id FROM states WHERE name = 'Delta'), 'Sapele', 'NG-DE-SPL'),
+    ('d6350402-5175-4410-9709-cc043ab26f05', (SELECT id FROM states WHERE name = 'Delta'), 'Udu', 'NG-DE-UDU'),
+    ('39abf91d-7afd-4613-b4af-0fc163acd2a3', (SELECT id FROM states WHERE name = 'Delta'), 'Ughelli North', 'NG-DE-UGN'),
+    ('b940741f-9fa7-4570-bec6-90ad7c3f06a6', (SELECT id FROM states WHERE name = 'Delta'), 'Ughelli South', 'NG-DE-UGS'),
+    ('c6c45a65-8189-431e-8366-182d2bf541f2', (SELECT id FROM states WHERE name = 'Delta'), 'Ukwuani', 'NG-DE-UKW'),
+    ('fc32ab10-79e6-4934-b1bc-8bed43b5fde1', (SELECT id FROM states WHERE name = 'Delta'), 'Uvwie', 'NG-DE-UVW'),
+    ('19752825-19fa-4d02-9542-0e8552a9f97b', (SELECT id FROM states WHERE name = 'Delta'), 'Warri North', 'NG-DE-WRN'),
+    ('c3ae6a17-55fa-416d-8147-b69e924be35e', (SELECT id FROM states WHERE name = 'Delta'), 'Warri South', 'NG-DE-WRS'),
+    ('dc6961e4-d41f-4ff4-ab08-3d7495ce65fb', (SELECT id FROM states WHERE name = 'Delta'), 'Warri South West', 'NG-DE-WSW'),
+    ('e3ae3ef4-0b20-404e-bcda-a9c5e3a0978c', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Abakaliki', 'NG-EB-ABK'),
+    ('09298915-23aa-4981-8e4e-42abe52f3d80', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Afikpo North', 'NG-EB-AFN'),
+    ('9de120a5-472b-4fc3-a0cc-09daed77d6b9', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Afikpo South', 'NG-EB-AFS'),
+    ('6b87eb0c-127c-40cf-9182-8a736d57c599', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ebonyi', 'NG-EB-EBN'),
+    ('97984965-8270-4ed0-a789-e8b8a6824857', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ezza North', 'NG-EB-EZN'),
+    ('cbdbb237-5b88-4b05-a7da-689f103d4c81', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ezza South', 'NG-EB-EZS'),
+    ('5cd18006-dfa5-46a0-8ed0-b54263128fa4', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ikwo', 'NG-EB-IKW'),
+    ('4b0dcc69-a610-4643-83f9-c5ec64653436', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ishielu', 'NG-EB-ISH'),
+    ('04b6bbbf-d396-4151-9518-e369ca70bcb6', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ivo', 'NG-EB-IVO'),
+    ('1f623189-6e58-4e7c-8bf4-05cf4274d20e', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Izzi', 'NG-EB-IZZ'),
+    ('ce3bced6-e24c-4e20-8078-983f191b8813', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ohaozara', 'NG-EB-OHZ'),
+    ('6c51975f-e588-4be5-9d0c-036cf96ca54c', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Ohaukwu', 'NG-EB-OHK'),
+    ('df513e4c-4a22-4eee-9564-48c5e2a11359', (SELECT id FROM states WHERE name = 'Ebonyi'), 'Onicha', 'NG-EB-ONC'),
+    ('58b433b2-30c0-41fc-8fed-5c95b822b942', (SELECT id FROM states WHERE name = 'Edo'), 'Akoko-Edo', 'NG-ED-AKE'),
+    ('5dc9a088-2518-4040-bbce-697cc821dad1', (SELECT id FROM states WHERE name = 'Edo'), 'Egor', 'NG-ED-EGR'),
+    ('dece45dc-849f-41b6-9b3e-6a68d8b82557', (SELECT id FROM states WHERE name = 'Edo'), 'Esan Central', 'NG-ED-ESC'),
+    ('5673a1cf-13c5-46ad-9bfd-fec5a8a33082', (SELECT id FROM states WHERE name = 'Edo'), 'Esan North-East', 'NG-ED-ENE'),
+    ('dbfed3a3-89f8-4c5c-a8c9-addd0009e0c6', (SELECT id FROM states WHERE name = 'Edo'), 'Esan South-East', 'NG-ED-ESE'),
+    ('5364b353-a9d8-4f90-b340-b7575b2c6daa', (SELECT id FROM states WHERE name = 'Edo'), 'Esan West', 'NG-ED-ESW'),
+    ('98eefac1-41e4-4999-b51a-3cabb5f8d260', (SELECT id FROM states WHERE name = 'Edo'), 'Etsako Central', 'NG-ED-ETC'),
+    ('1ffff803-ac42-4b40-8b2f-f90da92aab03', (SELECT id FROM states WHERE name = 'Edo'), 'Etsako East', 'NG-ED-ETE'),
+    ('4ba10ab9-e695-47a2-8d07-a2e9f5de1c46', (SELECT id FROM states WHERE name = 'Edo'), 'Etsako West', 'NG-ED-ETW'),
+    ('655585bc-3b0c-4125-9741-c83406fc9b5b', (SELECT id FROM states WHERE name = 'Edo'), 'Igueben', 'NG-ED-IGB'),
+    ('1dba60d6-5f5e-4738-a0ad-fdfa29bf71d6', (SELECT id FROM states WHERE name = 'Edo'), 'Ikpoba Okha', 'NG-ED-IKO'),
+    ('0c31aea0-6071-47b8-bfad-40002291fe74', (SELECT id FROM states WHERE name = 'Edo'), 'Orhionmwon', 'NG-ED-ORH'),
+    ('111f5882-a51d-4360-9060-12d958726858', (SELECT id FROM states WHERE name = 'Edo'), 'Oredo', 'NG-ED-ORD'),
+    ('94f07173-43ba-4b13-8532-8a2f31f54ec9', (SELECT id FROM states WHERE name = 'Edo'), 'Ovia North-East', 'NG-ED-ONE'),
+    ('a517901c-ed72-4c1e-9556-9ba38409f650', (SELECT id FROM states WHERE name = 'Edo'), 'Ovia South-West', 'NG-ED-OSW'),
+    ('fe8912ee-2997-456d-889f-cff9bdd8f74a', (SELECT id FROM states WHERE name = 'Edo'), 'Owan East', 'NG-ED-OWE'),
+    ('e0979726-dc2b-4bc6-aa98-56dc68c74cc6', (SELECT id FROM states WHERE name = 'Edo'), 'Owan West', 'NG-ED-OWW'),
+    ('329a149c-a3a5-45ec-a068-e99e415d39e3', (SELECT id FROM states WHERE name = 'Edo'), 'Uhunmwonde', 'NG-ED-UHN'),
+    ('46726bbe-8fd6-4d0e-9fb3-62aad82c7be3', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ado Ekiti', 'NG-EK-ADE'),
+    ('decb64ec-33a4-4510-a73a-866414b1e010', (SELECT id FROM states WHERE name = 'Ekiti'), 'Efon', 'NG-EK-EFN'),
+    ('3343d8f8-f08a-4b32-ab18-1209f40cff25', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ekiti East', 'NG-EK-EKE'),
+    ('dac3cc2f-d4b5-4419-9c31-86ec2c51501b', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ekiti South-West', 'NG-EK-ESW'),
+    ('8e0d449b-1937-4b71-84a8-356212b38af2', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ekiti West', 'NG-EK-EKW'),
+    ('d6ec2fe9-c608-4c9e-881f-8560978ed279', (SELECT id FROM states WHERE name = 'Ekiti'), 'Emure', 'NG-EK-EMR'),
+    ('7b968686-9af9-4d2a-b0c7-e4bbcd843648', (SELECT id FROM states WHERE name = 'Ekiti'), 'Gbonyin', 'NG-EK-GBN'),
+    ('f41dc054-ca05-434d-b1a0-8d66160b0e95', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ido Osi', 'NG-EK-IDO'),
+    ('766d611e-1473-41d3-9688-b51a621b382a', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ijero', 'NG-EK-IJR'),
+    ('d4997a8e-e005-42f8-9f24-fc58898a2d54', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ikere', 'NG-EK-IKR'),
+    ('2a80aa10-3cc1-4a99-b319-eb2101e3667a', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ikole', 'NG-EK-IKL'),
+    ('917ac848-e182-4044-bdf0-339340490b18', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ilejemeje', 'NG-EK-ILJ'),
+    ('1de8b8a0-9499-4190-9d96-0950334f268f', (SELECT id FROM states WHERE name = 'Ekiti'), 'Irepodun/Ifelodun', 'NG-EK-IRI'),
+    ('f8b338f6-b1ea-404f-8eb3-886d26df5ec0', (SELECT id FROM states WHERE name = 'Ekiti'), 'Ise/Orun', 'NG-EK-ISO'),
+    ('c68467f7-9cc9-40c7-a741-cc573dbb680f', (SELECT id FROM states WHERE name = 'Ekiti'), 'Moba', 'NG-EK-MBO'),
+    ('9bf53706-f2ea-48c0-ae24-94f3a27c2b31', (SELECT id FROM states WHERE name = 'Ekiti'), 'Oye', 'NG-EK-OYE'),
+    ('02777634-5eb6-4efe-9cf1-8672aa4e91bb', (SELECT id FROM states WHERE name = 'Enugu'), 'Aninri', 'NG-EN-ANN'),
+    ('d1e77a65-a5e7-45fd-93c6-aa9ea9280971', (SELECT id FROM states WHERE name = 'Enugu'), 'Awgu', 'NG-EN-AWG'),
+    ('9c704b7c-7e8b-4a8b-a84d-57518bcd6fa3', (SELECT id FROM states WHERE name = 'Enugu'), 'Enugu East', 'NG-EN-ENE'),
+    ('25af6f2e-adc7-4cc6-8359-cc1f39053d54', (SELECT id FROM states WHERE name = 'Enugu'), 'Enugu North', 'NG-EN-ENN'),
+    ('2938bc94-f32e-4a3b-a786-1918db215f71', (SELECT id FROM states WHERE name = 'Enugu'), 'Enugu South', 'NG-EN-ENS'),
+    ('430e883e-1d99-438b-ba36-2ea3b57f87bf', (SELECT id FROM states WHERE name = 'Enugu'), 'Ezeagu', 'NG-EN-EZG'),
+    ('2a98a916-116a-48c5-beb2-e2da1bdd3c0e', (SELECT id FROM states WHERE name = 'Enugu'), 'Igbo Etiti', 'NG-EN-IGE'),
+    ('803aeb3c-2994-4120-938c-9beb237655c5', (SELECT id FROM states WHERE name = 'Enugu'), 'Igbo Eze North', 'NG-EN-IEN'),
+    ('9fef5464-036c-4a59-81e4-f5b83c46cd16', (SELECT id FROM states WHERE name = 'Enugu'), 'Igbo Eze South', 'NG-EN-IES'),
+    ('64e45298-85f0-4e8d-9ed8-ed91270b8652', (SELECT id FROM states WHERE name = 'Enugu'), 'Isi Uzo', 'NG-EN-ISU'),
+    ('6b4e0696-6166-49dc-8636-b489a15da877', (SELECT id FROM states WHERE name = 'Enugu'), 'Nkanu East', 'NG-EN-NKE'),
+    ('976cd4c6-a2a7-4edb-a690-499c3c0f6635', (SELECT id FROM states WHERE name = 'Enugu'), 'Nkanu West', 'NG-EN-NKW'),
+    ('d3b92a8e-9d51-4439-a690-a11731e29420', (SELECT id FROM states WHERE name = 'Enugu'), 'Nsukka', 'NG-EN-NSK'),
+    ('e32c53db-5d3a-4fed-8a5e-d7413ddc1c15', (SELECT id FROM states WHERE name = 'Enugu'), 'Oji River', 'NG-EN-OJR'),
+    ('4be84b3c-7626-4ece-8b28-7527573865af', (SELECT id FROM states WHERE name = 'Enugu'), 'Udenu', 'NG-EN-UDN'),
+    ('3571b128-bc48-42bb-a9d2-f263cb8e1b31', (SELECT id FROM states WHERE name = 'Enugu'), 'Udi', 'NG-EN-UDI'),
+    ('41930f24-a11f-4fdb-9974-c310ac02ffea', (SELECT id FROM states WHERE name = 'Enugu'), 'Uzo-Uwani', 'NG-EN-UZU'),
+    ('2e6ba1d8-e283-4e7c-b438-89027b1b18b5', (SELECT id FROM states WHERE name = 'Abuja'), 'Abaji', 'NG-FC-ABJ'),
+    ('21bac160-7164-440f-962e-cdf8d1543d61', (SELECT id FROM states WHERE name = 'Abuja'), 'Abuja Municipal', 'NG-FC-ABM'),
+    ('aabd4fef-c16f-4e01-96f9-ee0fbe8fe9fc', (SELECT id FROM states WHERE name = 'Abuja'), 'Bwari', 'NG-FC-BWR'),
+    ('5bb38b1d-c655-4ac8-bc46-2fe735be1d69', (SELECT id FROM states WHERE name = 'Abuja'), 'Gwagwalada', 'NG-FC-GWG'),
+    ('db994369-d181-4fb8-a2b8-ffc4fa559dfa', (SELECT id FROM states WHERE name = 'Abuja'), 'Kuje', 'NG-FC-KJU'),
+    ('50d1f936-404d-4ff5-b304-1175ae92dcfb', (SELECT id FROM states WHERE name = 'Abuja'), 'Kwali', 'NG-FC-KWL'),
+    ('16eb634c-2783-44de-93fb-1c0d29c82cd1', (SELECT id FROM states WHERE name = 'Gombe'), 'Akko', 'NG-GO-AKK'),
+    ('a932397b-1826-4226-a9bb-06f55d71226c', (SELECT id FROM states WHERE name = 'Gombe'), 'Balanga', 'NG-GO-BLN'),
+    ('bf406720-8ddb-4b43-9e76-672cb593738e', (SELECT id FROM states WHERE name = 'Gombe'), 'Billiri', 'NG-GO-BLL'),
+    ('4ef7fccb-a3a5-48a9-8811-e97d2b2b3473', (SELECT id FROM states WHERE name = 'Gombe'), 'Dukku', 'NG-GO-DKK'),
+    ('aaa3716b-f8eb-443a-afb9-cac8b885818f', (SELECT id FROM states WHERE name = 'Gombe'), 'Funakaye', 'NG-GO-FNK'),
+    ('b47c2cff-ae88-44b3-bf1e-7e214d5f0bc3', (SELECT id FROM states WHERE name = 'Gombe'), 'Gombe', 'NG-GO-GMB'),
+    ('b88341b6-1f60-41a8-aeef-518aad52773b', (SELECT id FROM states WHERE name = 'Gombe'), 'Kaltungo', 'NG-GO-KLT'),
+    ('0ef7e64e-ee66-4dd8-a271-4c46db139d1d', (SELECT id FROM states WHERE name = 'Gombe'), 'Kwami', 'NG-GO-KWM'),
+    ('40e463d3-15ea-468d-ac88-67da2ee0f198', (SELECT id FROM states WHERE name = 'Gombe'), 'Nafada', 'NG-GO-NFD'),
+    ('c37db810-9769-4461-942e-8afeb037e4be', (SELECT id FROM states WHERE name = 'Gombe'), 'Shongom', 'NG-GO-SHN'),
+    ('94d894d4-6dcc-4a79-80c3-6e9900a108e4', (SELECT id FROM states WHERE name = 'Gombe'), 'Yamaltu/Deba', 'NG-GO-YMD'),
+    ('16810ca4-b65e-42a2-a24f-ee802d9cacf3', (SELECT id FROM states WHERE name = 'Imo'), 'Aboh Mbaise', 'NG-IM-ABM'),
+    ('76aa451b-8df2-4ea6-94f5-a65e12d82473', (SELECT id FROM states WHERE name = 'Imo'), 'Ahiazu Mbaise', 'NG-IM-AHM'),
+    ('7d846a63-35b2-4655-b11b-0d125d0bbfae', (SELECT id FROM states WHERE name = 'Imo'), 'Ehime Mbano', 'NG-IM-EHM'),
+    ('481efa55-702e-4b6c-a329-a4eeba401d4f', (SELECT id FROM states WHERE name = 'Imo'), 'Ezinihitte', 'NG-IM-EZN'),
+    ('ce1e61d3-f24a-486c-9669-9513fde855b8', (SELECT id FROM states WHERE name = 'Imo'), 'Ideato North', 'NG-IM-IDN'),
+    ('3745cdd1-cc58-4327-b96a-b7794f68b7dd', (SELECT id FROM states WHERE name = 'Imo'), 'Ideato South', 'NG-IM-IDS'),
+    ('8166fdd1-d6b5-4f9e-a5fd-c3a852b9352b', (SELECT id FROM states WHERE name = 'Imo'), 'Ihitte/Uboma', 'NG-IM-IHU'),
+    ('f352196d-c9e2-4eec-8c6e-fd1e92b8845e', (SELECT id FROM states WHERE name = 'Imo'), 'Ikeduru', 'NG-IM-IKD'),
+    ('483d88b4-e61b-4dcc-8314-6b116c7f0de0', (SELECT id FROM states WHERE name = 'Imo'), 'Isiala Mbano', 'NG-IM-ISM'),
+    ('2758bed7-f487-437e-a36e-fa76173c6eaa', (SELECT id FROM states WHERE name = 'Imo'), 'Isu', 'NG-IM-ISU'),
+    ('92322c36-3566-4a79-aae0-f1e9a499aee1', (SELECT id FROM states WHERE name = 'Imo'), 'Mbaitoli', 'NG-IM-MBT'),
+    ('a2837319-3719-422a-aab7-90f97c3d14ff', (SELECT id FROM states WHERE name = 'Imo'), 'Ngor Okpala', 'NG-IM-NGO'),
+    ('15065f5a-8b82-4999-9066-2cdc32fb7ae6', (SELECT id FROM states WHERE name = 'Imo'), 'Njaba', 'NG-IM-NJB'),
+    ('582c7d7a-50ad-4092-a610-a94c873528dc', (SELECT id FROM states WHERE name = 'Imo'), 'Nkwerre', 'NG-IM-NKW'),
+    ('c0ee5c6d-280f-4b24-892b-8bfef983e916', (SELECT id FROM states WHERE name = 'Imo'), 'Nwangele', 'NG-IM-NWN'),
+    ('b7d93c51-5dca-4a80-9297-e016e42df5bc', (SELECT id FROM states WHERE name = 'Imo'), 'Obowo', 'NG-IM-OBW'),
+    ('26c187a0-2144-4c17-ba8c-4122b56b3ce2', (SELECT id FROM states WHERE name = 'Imo'), 'Oguta', 'NG-IM-OGT'),
+    ('a5787c46-1055-4a00-8f86-eb6303cd5865', (SELECT id FROM states WHERE name = 'Imo'), 'Ohaji/Egbema', 'NG-IM-OHE'),
+    ('08b8cd62-2283-4747-bf3e-46617ea23247', (SELECT id FROM states WHERE name = 'Imo'), 'Okigwe', 'NG-IM-OKG'),
+    ('058f00fc-3da9-4128-a37a-a435546afe33', (SELECT id FROM states WHERE name = 'Imo'), 'Orlu', 'NG-IM-ORL'),
+    ('d447c54d-ef90-4ebb-99ba-292a18b88409', (SELECT id FROM states WHERE name = 'Imo'), 'Orsu', 'NG-IM-ORS'),
+    ('af9d0455-b160-479e-bf11-a7fb35f28842', (SELECT id FROM states WHERE name = 'Imo'), 'Oru East', 'NG-IM-ORE'),
+    ('9dbf4db5-a7a9-4d17-8126-3537fbf04964', (SELECT id FROM states WHERE name = 'Imo'), 'Oru West', 'NG-IM-ORW'),
+    ('be5ce398-372f-461d-b334-7f42af7d97f1', (SELECT id FROM states WHERE name = 'Imo'), 'Owerri Municipal', 'NG-IM-OWM'),
+    ('d19badef-6c45-4338-8fbc-9d5d5cc4594b', (SELECT id FROM states WHERE name = 'Imo'), 'Owerri North', 'NG-IM-OWN'),
+    ('5b181bc0-9ea5-46e6-ab6a-418f78af8843', (SELECT id FROM states WHERE name = 'Imo'), 'Owerri West', 'NG-IM-OWW'),
+    ('5a894dd0-45e8-444a-856c-2cd8d64c65d6', (SELECT id FROM states WHERE name = 'Imo'), 'Onuimo', 'NG-IM-ONM'),
+    ('e189eab1-016e-4809-9aaa-407d1e55cca1', (SELECT id FROM states WHERE name = 'Jigawa'), 'Auyo', 'NG-JI-AYU'),
+    ('19fcd145-57ea-4944-bc3d-f53241181811', (SELECT id FROM states WHERE name = 'Jigawa'), 'Babura', 'NG-JI-BBR'),
+    ('6f9bf6e5-27af-4ae3-bfc6-12fcff4da284', (SELECT id FROM states WHERE name = 'Jigawa'), 'Birniwa', 'NG-JI-BRN'),
+    ('022e3e4d-33e5-42dd-8b88-197999b93a66', (SELECT id FROM states WHERE name = 'Jigawa'), 'Birnin Kudu', 'NG-JI-BRK'),
+    ('2c696dce-11e5-460b-b1cd-9dda37c7c713', (SELECT id FROM states WHERE name = 'Jigawa'), 'Buji', 'NG-JI-BJU'),
+    ('f1693066-0f63-43c9-a2f7-280084f64767', (SELECT id FROM states WHERE name = 'Jigawa'), 'Dutse', 'NG-JI-DTS'),
+    ('7615cdde-8e2d-4f08-a47f-4c50b7ccf307', (SELECT id FROM states WHERE name = 'Jigawa'), 'Gagarawa', 'NG-JI-GGR'),
+    ('a6734416-81b0-4432-bf34-295d5019f061', (SELECT id FROM states WHERE name = 'Jigawa'), 'Garki', 'NG-JI-GRK'),
+    ('dc6728e4-2ae6-4584-9115-397c88046321', (SELECT id FROM states WHERE name = 'Jigawa'), 'Gumel', 'NG-JI-GML'),
+    ('78244d5f-7d4b-4b2e-accc-58b7b0eb4087', (SELECT id FROM states WHERE name = 'Jigawa'), 'Guri', 'NG-JI-GRU'),
+    ('7a750bca-3a7b-4bf1-8056-84f76ce9b07c', (SELECT id FROM states WHERE name = 'Jigawa'), 'Gwaram', 'NG-JI-GWR'),
+    ('2de4702a-fddc-4044-89ee-dc6351f00cad', (SELECT id FROM states WHERE name = 'Jigawa'), 'Gwiwa', 'NG-JI-GWW'),
+    ('b8cbe2cf-6fb7-4190-a82e-2558997cdaa0', (SELECT id FROM states WHERE name = 'Jigawa'), 'Hadejia', 'NG-JI-HDJ'),
+    ('3a57870e-775d-4886-8066-58b7b4572353', (SELECT id FROM states WHERE name = 'Jigawa'), 'Jahun', 'NG-JI-JHN'),
+    ('fb16c223-d91b-49fa-ac47-2111b56e2a4a', (SELECT id FROM states WHERE name = 'Jigawa'), 'Kafin Hausa', 'NG-JI-KFH'),
+    ('58bd7721-f2b1-4c07-a1ea-2b46eb110c00', (SELECT id FROM states WHERE name = 'Jigawa'), 'Kaugama', 'NG-JI-KGM'),
+    ('c8b54262-4397-438d-9b5e-de48a05249ee', (SELECT id FROM states WHERE name = 'Jigawa'), 'Kazaure', 'NG-JI-KZR'),
+    ('66934f5c-9286-445b-a6ff-c7ccde12c2ef', (SELECT id FROM states WHERE name = 'Jigawa'), 'Kiri Kasama', 'NG-JI-KRK'),
+    ('dae682f9-2bf4-4d2a-9475-2d1263eee1d3', (SELECT id FROM states WHERE name = 'Jigawa'), 'Kiyawa', 'NG-JI-KYW'),
+    ('5f3abf53-88e9-4854-8cf6-73fbb0bdad35', (SELECT id FROM states WHERE name = 'Jigawa'), 'Maigatari', 'NG-JI-MGT'),
+    ('60add559-68f6-4f60-a616-eda625101727', (SELECT id FROM states WHERE name = 'Jigawa'), 'Malam Madori', 'NG-JI-MLM'),
+    ('d8a484a7-ebb0-45e6-a72e-02ed6dc6ea6c', (SELECT id FROM states WHERE name = 'Jigawa'), 'Miga', 'NG-JI-MGI'),
+    ('3fb58302-425a-484f-b923-19c94b0d7180', (SELECT id FROM states WHERE name = 'Jigawa'), 'Ringim', 'NG-JI-RNG'),
+    ('942ddfe8-faed-45f4-9d25-b02ffe88f36a', (SELECT id FROM states WHERE name = 'Jigawa'), 'Roni', 'NG-JI-RNO'),
+    ('07c23a00-dbc4-4cc1-8cd5-09a1ce74f4ec', (SELECT id FROM states WHERE name = 'Jigawa'), 'Sule Tankarkar', 'NG-JI-SLT'),
+    ('582e88ed-c9d1-4040-b91e-cd4f4e374804', (SELECT id FROM states WHERE name = 'Jigawa'), 'Taura', 'NG-JI-TRA'),
+    ('0209649d-41ea-4c4d-9776-33f15273726a', (SELECT id FROM states WHERE name = 'Jigawa'), 'Yankwashi', 'NG-JI-YNK'),
+    ('350017b8-a87d-4fff-88ae-d5138a626f28', (SELECT id FROM states WHERE name = 'Kaduna'), 'Birnin Gwari', 'NG-KD-BRG'),
+    ('23bc6ed7-b35f-43f3-9b73-f4c50f8d6698', (SELECT id FROM states WHERE name = 'Kaduna'), 'Chikun', 'NG-KD-CHK'),
+    ('3161e840-a2fc-4fe1-85e7-37069446b792', (SELECT id FROM states WHERE name = 'Kaduna'), 'Giwa', 'NG-KD-GWI'),
+    ('8efd91d9-e95d-4090-8951-af84cc61f7bf', (SELECT id FROM states WHERE name = 'Kaduna'), 'Igabi', 'NG-KD-IGB'),
+    ('236984ed-1b22-4f7e-a330-6e73b7040837', (SELECT id FROM states WHERE name = 'Kaduna'), 'Ikara', 'NG-KD-IKR'),
+    ('f86f7aa2-d277-4cca-9353-7de389dbeefd', (SELECT id FROM states WHERE name = 'Kaduna'), 'Jaba', 'NG-KD-JBA'),
+    ('24552b8d-6236-4215-9999-671a7c5ebabc', (SELECT id FROM states WHERE name = 'Kaduna'), 'Jema''a', 'NG-KD-JMA'),
+    ('5f6d87f8-c3f1-41af-b956-5a4b92358c26', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kachia', 'NG-KD-KCH'),
+    ('372b6bb8-30b7-451f-b7fd-7c4072dbd57b', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kaduna North', 'NG-KD-KDN'),
+    ('cb409362-9986-48c2-af9f-061e04a8f884', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kaduna South', 'NG-KD-KDS'),
+    ('c200d6cc-419f-4159-9878-1a02f54fa3bd', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kagarko', 'NG-KD-KGR'),
+    ('41226ed2-3d18-48fe-8ace-96d233642dfb', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kajuru', 'NG-KD-KJR'),
+    ('c96c8f32-5fc2-4ffa-b958-620a9e9b566e', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kaura', 'NG-KD-KRA'),
+    ('863432b4-88f2-4eb8-91ed-fbc80d5bca2a', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kauru', 'NG-KD-KR2'),
+    ('7d51da38-2ee2-454d-a6af-2a2c6d83a830', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kubau', 'NG-KD-KBU'),
+    ('6ca3b35d-bc71-4493-831e-dbfa5fec1267', (SELECT id FROM states WHERE name = 'Kaduna'), 'Kudan', 'NG-KD-KD2'),
+    ('d6b79972-efc1-4c5b-9e04-47bcb467a94b', (SELECT id FROM states WHERE name = 'Kaduna'), 'Lere', 'NG-KD-LRE'),
+    ('a4bb9f41-3edb-47ac-8394-ccd8ba2c9ad9', (SELECT id FROM states WHERE name = 'Kaduna'), 'Makarfi', 'NG-KD-MKR'),
+    ('cb6e4bd3-6969-4171-8ba1-bb9764f0d8aa', (SELECT id FROM states WHERE name = 'Kaduna'), 'Sabon Gari', 'NG-KD-SBG'),
+    ('79c88efc-be42-4cd1-9d64-1bb13e5e8c13', (SELECT id FROM states WHERE name = 'Kaduna'), 'Sanga', 'NG-KD-SNG'),
+    ('64454eaa-1ca9-494e-adb2-ecf92f73dce7', (SELECT id FROM states WHERE name = 'Kaduna'), 'Soba', 'NG-KD-SBO'),
+    ('6b8a1b54-f4d8-4c96-85f1-0618419034f4', (SELECT id FROM states WHERE name = 'Kaduna'), 'Zangon Kataf', 'NG-KD-ZNK'),
+    ('6180afdc-fac1-418a-b8ee-c1fd571dfa0b', (SELECT id FROM states WHERE name = 'Kaduna'), 'Zaria', 'NG-KD-ZRA'),
+    ('e35b3d5a-240e-424d-b927-9be60c053468', (SELECT id FROM states WHERE name = 'Kano'), 'Ajingi', 'NG-KN-AJN'),
+    ('2e34e281-4967-4025-86da-e28b176963f1', (SELECT id FROM states WHERE name = 'Kano'), 'Albasu', 'NG-KN-ALB'),
+    ('9b75ca97-ea00-4371-9ec7-1d8c0a214f3e', (SELECT id FROM states WHERE name = 'Kano'), 'Bagwai', 'NG-KN-BGW'),
+    ('70fcc5a0-ec5b-47a5-9e7d-6e64c8a1abf9', (SELECT id FROM states WHERE name = 'Kano'), 'Bebeji', 'NG-KN-BBJ'),
+    ('dfe0c7c9-de0f-4de3-9a80-2cb02b84cd30', (SELECT id FROM states WHERE name = 'Kano'), 'Bichi', 'NG-KN-BCH'),
+    ('6fc22b76-3c4c-416a-b303-60faf6874521', (SELECT id FROM states WHERE name = 'Kano'), 'Bunkure', 'NG-KN-BNK'),
+    ('5a7bd1e2-a0ef-4255-8528-11ce64f81aae', (SELECT id FROM states WHERE name = 'Kano'), 'Dala', 'NG-KN-DLA'),
+    ('9327cf7c-849d-44d4-9f33-433b5716391e', (SELECT id FROM states WHERE name = 'Kano'), 'Dambatta', 'NG-KN-DMB'),
+    ('5a69e063-284a-4412-a83e-64ae3b5fc6ac', (SELECT id FROM states WHERE name = 'Kano'), 'Dawakin Kudu', 'NG-KN-DWK'),
+    ('d493b793-9b81-457c-9527-11972dc6e2d4', (SELECT id FROM states WHERE name = 'Kano'), 'Dawakin Tofa', 'NG-KN-DWT'),
+    ('d4d7844c-c355-4a5f-9344-201b6feccc39', (SELECT id FROM states WHERE name = 'Kano'), 'Doguwa', 'NG-KN-DGW'),
+    ('c4c61712-0d9c-4ff1-b1b3-887741bb30e8', (SELECT id FROM states WHERE name = 'Kano'), 'Fagge', 'NG-KN-FGG'),
+    ('0009d55a-52f1-48a6-84f6-1b00215a9dc1', (SELECT id FROM states WHERE name = 'Kano'), 'Gabasawa', 'NG-KN-GBS'),
+    ('85691289-f320-4158-ba2e-bca552fa93cc', (SELECT id FROM states WHERE name = 'Kano'), 'Garko', 'NG-KN-GRK'),
+    ('831e9691-3be7-4dbc-9499-5e030871054c', (SELECT id FROM states WHERE name = 'Kano'), 'Garun Mallam', 'NG-KN-GRM'),
+    ('3ba204bb-050c-405f-a392-acee7a3c72d7', (SELECT id FROM states WHERE name = 'Kano'), 'Gaya', 'NG-KN-GYA'),
+    ('39356363-7459-4c88-a1c6-d1ba237b8991', (SELECT id FROM states WHERE name = 'Kano'), 'Gezawa', 'NG-KN-GZW'),
+    ('260a0107-af12-4af0-82da-899c0b211a84', (SELECT id FROM states WHERE name = 'Kano'), 'Gwale', 'NG-KN-GWL'),
+    ('805f5d70-f00a-42f7-bb9d-87eddc7de39b', (SELECT id FROM states WHERE name = 'Kano'), 'Gwarzo', 'NG-KN-GWR'),
+    ('264645c7-a24f-4cce-bb62-18028ff965dc', (SELECT id FROM states WHERE name = 'Kano'), 'Kabo', 'NG-KN-KBA'),
+    ('77a273e8-25bd-453f-9ecd-65a390de1c98', (SELECT id FROM states WHERE name = 'Kano'), 'Kano Municipal', 'NG-KN-KNM'),
+    ('d9eec56b-0da8-4990-a091-78616f255e26', (SELECT id FROM states WHERE name = 'Kano'), 'Karaye', 'NG-KN-KRY'),
+    ('8de11e09-89a9-4165-96c3-15bf38da8dc8', (SELECT id FROM states WHERE name = 'Kano'), 'Kibiya', 'NG-KN-KBY'),
+    ('5cd6c0ea-cb85-4ff1-b482-701a199ad47a', (SELECT id FROM states WHERE name = 'Kano'), 'Kiru', 'NG-KN-KRI'),
+    ('0486679a-94ce-4a97-b593-12a86219f83b', (SELECT id FROM states WHERE name = 'Kano'), 'Kumbotso', 'NG-KN-KMB'),
+    ('08553487-d741-47f1-8894-3272827b8fe0', (SELECT id FROM states WHERE name = 'Kano'), 'Kunchi', 'NG-KN-KNC'),
+    ('f2ba4c22-d872-4296-aba1-a5e370020077', (SELECT id FROM states WHERE name = 'Kano'), 'Kura', 'NG-KN-KRU'),
+    ('e9b0830f-d62d-4792-92e8-327f3652bb92', (SELECT id FROM states WHERE name = 'Kano'), 'Madobi', 'NG-KN-MDB'),
+    ('5307db79-66b2-4f9b-97d8-55583867a1f5', (SELECT id FROM states WHERE name = 'Kano'), 'Makoda', 'NG-KN-MKD'),
+    ('afa0784d-7057-4d70-8f01-c8c84c9983a1', (SELECT id FROM states WHERE name = 'Kano'), 'Minjibir', 'NG-KN-MNJ'),
+    ('4d032b5a-af4c-427c-a545-162fc408cbde', (SELECT id FROM states WHERE name = 'Kano'), 'Nasarawa', 'NG-KN-NSR'),
+    ('25677370-ce07-4392-a89f-4aaf7fd67c95', (SELECT id FROM states WHERE name = 'Kano'), 'Rano', 'NG-KN-RNA'),
+    ('08224615-35b4-4542-8637-07183a4bc4eb', (SELECT id FROM states WHERE name = 'Kano'), 'Rimin Gado', 'NG-KN-RMG'),
+    ('62e54e24-9cfa-4dac-bced-0b2076055d80', (SELECT id FROM states WHERE name = 'Kano'), 'Rogo', 'NG-KN-RGO'),
+    ('f866a43c-1f81-472e-bf6d-9773402d4bbf', (SELECT id FROM states WHERE name = 'Kano'), 'Shanono', 'NG-KN-SHN'),
+    ('508bb62f-6b81-48bd-8db9-a5a48a0eaddc', (SELECT id FROM states WHERE name = 'Kano'), 'Sumaila', 'NG-KN-SML'),
+    ('492c7d5d-63a0-436f-ae0a-5df45be1543c', (SELECT id FROM states WHERE name = 'Kano'), 'Takai', 'NG-KN-TKA'),
+    ('6d826a95-4cf2-4f23-a4c1-f0315d8f331b', (SELECT id FROM states WHERE name = 'Kano'), 'Tarauni', 'NG-KN-TRN'),
+    ('a771a9fd-ca13-4ee2-ae0b-02a48a4d9d20', (SELECT id FROM states WHERE name = 'Kano'), 'Tofa', 'NG-KN-TFO'),
+    ('219c907f-3de1-4e75-a3c0-b4334b266045', (SELECT id FROM states WHERE name = 'Kano'), 'Tsanyawa', 'NG-KN-TSN'),
+    ('2f51bd47-9e86-4e1b-b674-0b976d2833c0', (SELECT id FROM states WHERE name = 'Kano'), 'Tudun Wada', 'NG-KN-TDW'),
+    ('0e918b27-421b-4e8b-9f60-f76a7d38979d', (SELECT id FROM states WHERE name = 'Kano'), 'Ungogo', 'NG-KN-UNG'),
+    ('472d7a21-84c8-44fd-b162-bff0cd9def5c', (SELECT id FROM states WHERE name = 'Kano'), 'Warawa', 'NG-KN-WRW'),
+    ('6a90f3ab-29bf-40d0-9f55-21b750c21ee2', (SELECT id FROM states WHERE name = 'Kano'), 'Wudil', 'NG-KN-WDL'),
+    ('0916833e-22c5-4fc4-b4bf-94187b1f36b8', (SELECT id FROM states WHERE name = 'Katsina'), 'Bakori', 'NG-KT-BKR'),
+    ('4011496e-f3f0-4fc0-a358-9304cb725ef3', (SELECT id FROM states WHERE name = 'Katsina'), 'Batagarawa', 'NG-KT-BTG'),
+    ('34435646-1a8a-4ab6-8d61-fbaaf59ef4a2', (SELECT id FROM states WHERE name = 'Katsina'), 'Batsari', 'NG-KT-BTS'),
+    ('8827be2e-9f93-44ff-a1e4-3d72fc378c62', (SELECT id FROM states WHERE name = 'Katsina'), 'Baure', 'NG-KT-BRA'),
+    ('1ff324b7-6720-4f0c-8871-e5c7a3764baf', (SELECT id FROM states WHERE name = 'Katsina'), 'Bindawa', 'NG-KT-BND'),
+    ('74950c62-2b40-4dab-b249-3de1702d9409', (SELECT id FROM states WHERE name = 'Katsina'), 'Charanchi', 'NG-KT-CHR'),
+    ('8325a45a-cde1-4f32-91b0-7d1a434637ac', (SELECT id FROM states WHERE name = 'Katsina'), 'Dandume', 'NG-KT-DND'),
+    ('03f8baa0-0f97-4dd4-8d94-c15192d99dc7', (SELECT id FROM states WHERE name = 'Katsina'), 'Danja', 'NG-KT-DNJ'),
+    ('e5b8c8c8-fc93-434d-9890-704df1686f71', (SELECT id FROM states WHERE name = 'Katsina'), 'Dan Musa', 'NG-KT-DNM'),
+    ('43359c38-e3f0-4d49-aaa0-25bc61c23862', (SELECT id FROM states WHERE name = 'Katsina'), 'Daura', 'NG-KT-DRA'),
+    ('bcbc1e4a-a639-4791-9778-0485594dedc5', (SELECT id FROM states WHERE name = 'Katsina'), 'Dutsi', 'NG-KT-DTS'),
+    ('e46b6d63-b36e-4e4b-9772-c9ec96c6f497', (SELECT id FROM states WHERE name = 'Katsina'), 'Dutsin Ma', 'NG-KT-DTM'),
+    ('c9d66507-c2d3-4e38-9989-6a682eb5d075', (SELECT id FROM states WHERE name = 'Katsina'), 'Faskari', 'NG-KT-FSK'),
+    ('49c21b54-6502-4801-929a-96460317ac0a', (SELECT id FROM states WHERE name = 'Katsina'), 'Funtua', 'NG-KT-FNT'),
+    ('a5686a25-3f42-44f6-b88b-5dde39b769ab', (SELECT id FROM states WHERE name = 'Katsina'), 'Ingawa', 'NG-KT-ING'),
+    ('f28f1746-a9ea-48e9-ab68-c891c0e80a0a', (SELECT id FROM states WHERE name = 'Katsina'), 'Jibia', 'NG-KT-JBI'),
+    ('b904e33a-b74b-47ed-9612-71a1e08eb14e', (SELECT id FROM states WHERE name = 'Katsina'), 'Kafur', 'NG-KT-KFR'),
+    ('991f4bab-93bf-49d5-a5d0-fdd9761b5a0e', (SELECT id FROM states WHERE name = 'Katsina'), 'Kaita', 'NG-KT-KTA'),
+    ('cdb5562e-2376-4683-b989-fdf8f8e16ecc', (SELECT id FROM states WHERE name = 'Katsina'), 'Kankara', 'NG-KT-KNK'),
+    ('493c3e11-8cff-4857-a721-4396b30dd913', (SELECT id FROM states WHERE name = 'Katsina'), 'Kankia', 'NG-KT-KN2'),
+    ('e9cec069-b0bb-4046-aa88-d09ef3d6b401', (SELECT id FROM states WHERE name = 'Katsina'), 'Katsina', 'NG-KT-KTS'),
+    ('00d0e4f5-e5fc-45af-81b8-6be2448b4f31', (SELECT id FROM states WHERE name = 'Katsina'), 'Kurfi', 'NG-KT-KRF'),
+    ('04bd20df-147a-49ae-9e88-c39720daad8c', (SELECT id FROM states WHERE name = 'Katsina'), 'Kusada', 'NG-KT-KSD'),
+    ('360ec37b-41fe-4397-a6ae-8dda1c8ef9f5', (SELECT id FROM states WHERE name = 'Katsina'), 'Mai''Adua', 'NG-KT-MAA'),
+    ('ded8727a-5fb0-4a1c-be75-d7aac8d57db3', (SELECT id FROM states WHERE name = 'Katsina'), 'Malumfashi', 'NG-KT-MLM'),
+    ('79b5a26d-1edb-4a7a-9e2b-4512af1cffac', (SELECT id FROM states WHERE name = 'Katsina'), 'Mani', 'NG-KT-MNA'),
+    ('a6424677-39eb-462b-9a35-57b1766da513', (SELECT id FROM states WHERE name = 'Katsina'), 'Mashi', 'NG-KT-MSH'),
+    ('f4ca96fb-3818-4745-ae40-2690f49e8a0e', (SELECT id FROM states WHERE name = 'Katsina'), 'Matazu', 'NG-KT-MTZ'),
+    ('001cbc4c-e830-416d-8b4f-b423cae39968', (SELECT id FROM states WHERE name = 'Katsina'), 'Musawa', 'NG-KT-MSW'),
+    ('405a9f33-ffb5-47de-be8d-542af5b5c422', (SELECT id FROM states WHERE name = 'Katsina'), 'Rimi', 'NG-KT-RMI'),
+    ('29ca1204-541f-4f84-943b-b842a99616c7', (SELECT id FROM states WHERE name = 'Katsina'), 'Sabuwa', 'NG-KT-SBW'),
+    ('0fe9bd53-e37c-4055-91e5-c69361cc2b70', (SELECT id FROM states WHERE name = 'Katsina'), 'Safana', 'NG-KT-SFN'),
+    ('4dc77ef9-274e-4949-9d45-bcd434ff4ab0', (SELECT id FROM states WHERE name = 'Katsina'), 'Sandamu', 'NG-KT-SND'),
+    ('09b7dc1e-e08e-4ed9-9bd1-383d96dea909', (SELECT id FROM states WHERE name = 'Katsina'), 'Zango', 'NG-KT-ZNG'),
+    ('4df5897d-5088-41ca-b248-f1f51b5aaf85', (SELECT id FROM states WHERE name = 'Kebbi'), 'Aleiro', 'NG-KE-ALR'),
+    ('3890fbd6-1173-4965-92f3-76f708c7eda7', (SELECT id FROM states WHERE name = 'Kebbi'), 'Arewa', 'NG-KE-ARW'),
+    ('a4315329-b998-4cb3-a4ad-bb8c535d2911', (SELECT id FROM states WHERE name = 'Kebbi'), 'Argungu', 'NG-KE-ARG'),
+    ('513c790b-66e6-44e8-b2d6-6a008eedf633', (SELECT id FROM states WHERE name = 'Kebbi'), 'Augie', 'NG-KE-AGU'),
+    ('d4d06902-e527-49ea-804e-7928b8d6a718', (SELECT id FROM states WHERE name = 'Kebbi'), 'Bagudo', 'NG-KE-BGD'),
+    ('a81f474b-b67b-4055-9cde-42721f66fcde', (SELECT id FROM states WHERE name = 'Kebbi'), 'Birnin Kebbi', 'NG-KE-BRK'),
+    ('e87b2a22-61f7-4ff3-8ceb-ee43a92b1f03', (SELECT id FROM states WHERE name = 'Kebbi'), 'Bunza', 'NG-KE-BNZ'),
+    ('0e14dc87-83e1-4017-b73f-3d98fce1081f', (SELECT id FROM states WHERE name = 'Kebbi'), 'Dandi', 'NG-KE-DND'),
+    ('6945164f-0baf-4a2c-85b8-b3101949f9d8', (SELECT id FROM states WHERE name = 'Kebbi'), 'Fakai', 'NG-KE-FKA'),
+    ('8b9fc2a3-45ef-4f36-968d-04df5ab90902', (SELECT id FROM states WHERE name = 'Kebbi'), 'Gwandu', 'NG-KE-GWN'),
+    ('400036dc-d92e-46b1-a1b5-a77520acc8e4', (SELECT id FROM states WHERE name = 'Kebbi'), 'Jega', 'NG-KE-JGE'),
+    ('0c03d353-16ab-4ec1-a2d7-f401e903497b', (SELECT id FROM states WHERE name = 'Kebbi'), 'Kalgo', 'NG-KE-KLG'),
+    ('5179b12f-325c-4f15-87f5-4c4f51ede703', (SELECT id FROM states WHERE name = 'Kebbi'), 'Koko/Besse', 'NG-KE-KKB'),
+    ('0c276cf9-6481-4045-8e2f-36584928b9ba', (SELECT id FROM states WHERE name = 'Kebbi'), 'Maiyama', 'NG-KE-MYM'),
+    ('61558b52-b701-4268-9b92-7ed0054e5070', (SELECT id FROM states WHERE name = 'Kebbi'), 'Ngaski', 'NG-KE-NGS'),
+    ('30eedc9b-2aa4-4e19-92a2-889fb1235c10', (SELECT id FROM states WHERE name = 'Kebbi'), 'Sakaba', 'NG-KE-SKB'),
+    ('3443fca6-a442-4d2f-8e7b-e49d3c70e29c', (SELECT id FROM states WHERE name = 'Kebbi'), 'Shanga', 'NG-KE-SHN'),
+    ('43f31f0d-6e6b-4636-bd5b-72c5fa1613ba', (SELECT id FROM states WHERE name = 'Kebbi'), 'Suru', 'NG-KE-SRU'),
+    ('349577ea-3756-4c3b-af12-0d71a799033c', (SELECT id FROM states WHERE name = 'Kebbi'), 'Wasagu/Danko', 'NG-KE-WSD'),
+    ('bd3d84c5-03c5-4a2c-b061-1f954dcc1326', (SELECT id FROM states WHERE name = 'Kebbi'), 'Yauri', 'NG-KE-YRA'),
+    ('3cb2fe92-d75a-40b8-85d4-16be33f6ad9b', (SELECT id FROM states WHERE name = 'Kebbi'), 'Zuru', 'NG-KE-ZRU'),
+    ('5e568d8c-a061-4954-8acd-857d0def3ea2', (SELECT id FROM states WHERE name = 'Kogi'), 'Adavi', 'NG-KO-ADV'),
+    ('35bce242-2fb4-441e-b92c-9ad3483a5c3e', (SELECT id FROM states WHERE name = 'Kogi'), 'Ajaokuta', 'NG-KO-AJK'),
+    ('6770596a-10e7-40f7-abc2-a08e3de41d2d', (SELECT id FROM states WHERE name = 'Kogi'), 'Ankpa', 'NG-KO-ANK'),
+    ('c1275108-13b7-42b2-827b-edadd3d105d1', (SELECT id FROM states WHERE name = 'Kogi'), 'Bassa', 'NG-KO-BSS'),
+    ('8bea750a-53c4-41e8-b75c-bbfb50f6b22f', (SELECT id FROM states WHERE name = 'Kogi'), 'Dekina', 'NG-KO-DKN'),
+    ('545f3eca-e7fb-4252-8714-604e06483438', (SELECT id FROM states WHERE name = 'Kogi'), 'Ibaji', 'NG-KO-IBJ'),
+    ('d32c0723-f2b5-4476-a500-459cf1923a54', (SELECT id FROM states WHERE name = 'Kogi'), 'Idah', 'NG-KO-IDH'),
+    ('e293de4f-7b1b-4ac7-aa7e-cb793498b2b8', (SELECT id FROM states WHERE name = 'Kogi'), 'Igalamela-Odolu', 'NG-KO-IGO'),
+    ('0d537d33-7362-4ac7-82b4-d392cf810774', (SELECT id FROM states WHERE name = 'Kogi'), 'Ijumu', 'NG-KO-IJM'),
+    ('71476656-5ba2-4f2c-8004-811b7f2ef11f', (SELECT id FROM states WHERE name = 'Kogi'), 'Kabba/Bunu', 'NG-KO-KBB'),
+    ('63f80889-9eaf-4126-b7e0-43866bdd4141', (SELECT id FROM states WHERE name = 'Kogi'), 'Kogi', 'NG-KO-KGO'),
+    ('d943bd74-338e-4bc6-8a98-19f373adc2bb', (SELECT id FROM states WHERE name = 'Kogi'), 'Lokoja', 'NG-KO-LKJ'),
+    ('7a2bc94b-6ad3-4c01-bfcf-3551a6c98d71', (SELECT id FROM states WHERE name = 'Kogi'), 'Mopa-Muro', 'NG-KO-MPM'),
+    ('e38e4a25-1154-442a-86fa-90a3e443b6fb', (SELECT id FROM states WHERE name = 'Kogi'), 'Ofu', 'NG-KO-OFU'),
+    ('a734e25d-3f32-4f00-aa90-1d33fc871f42', (SELECT id FROM states WHERE name = 'Kogi'), 'Ogori/Magongo', 'NG-KO-OGM'),
+    ('38dbf599-cf91-4b51-af41-16c7b3df93fa', (SELECT id FROM states WHERE name = 'Kogi'), 'Okehi', 'NG-KO-OKH'),
+    ('2cdcd0ec-b2d2-44e2-9fc8-add0968a537e', (SELECT id FROM states WHERE name = 'Kogi'), 'Okene', 'NG-KO-OKN'),
+    ('1c7e807e-bb74-47f6-9019-fbe9f3325bfc', (SELECT id FROM states WHERE name = 'Kogi'), 'Olamaboro', 'NG-KO-OLM'),
+    ('c20eb8c0-24d3-44f8-b04e-c2684814a7ff', (SELECT id FROM states WHERE name = 'Kogi'), 'Omala', 'NG-KO-OML'),
+    ('99bc8873-5691-49e9-8008-dbd482b7f955', (SELECT id FROM states WHERE name = 'Kogi'), 'Yagba East', 'NG-KO-YGE'),
+    ('583dc4a6-1546-4ee4-afb9-b78270f9af66', (SELECT id FROM states WHERE name = 'Kogi'), 'Yagba West', 'NG-KO-YGW'),
+    ('41eebf8b-c0b2-4cc8-b3ca-7c21b526118c', (SELECT id FROM states WHERE name = 'Kwara'), 'Asa', 'NG-KW-ASA'),
+    ('8c8665e9-8fef-47a0-96dd-bb8acb2113b9', (SELECT id FROM states WHERE name = 'Kwara'), 'Baruten', 'NG-KW-BRT'),
+    ('8bb419e5-3fe9-44fe-aa12-48c79da9a5c7', (SELECT id FROM states WHERE name = 'Kwara'), 'Edu', 'NG-KW-EDU'),
+    ('14d21f82-08ea-483e-a3be-f6b5a1c6d1c6', (SELECT id FROM states WHERE name = 'Kwara'), 'Ekiti', 'NG-KW-EKT'),
+    ('e50d6ff6-0776-4a46-bd9d-ce0a570b1d1e', (SELECT id FROM states WHERE name = 'Kwara'), 'Ifelodun', 'NG-KW-IFL'),
+    ('91c67439-aee0-4a1c-8f10-d315007c594c', (SELECT id FROM states WHERE name = 'Kwara'), 'Ilorin East', 'NG-KW-ILE'),
+    ('ea8692e3-7a30-43e7-8f1d-b32ab54e7bc4', (SELECT id FROM states WHERE name = 'Kwara'), 'Ilorin South', 'NG-KW-ILS'),
+    ('27205670-93f7-4e78-afaf-a43be670b68f', (SELECT id FROM states WHERE name = 'Kwara'), 'Ilorin West', 'NG-KW-ILW'),
+    ('758d5794-653e-4093-99ff-b27310dad5f7', (SELECT id FROM states WHERE name = 'Kwara'), 'Irepodun', 'NG-KW-IRP'),
+    ('8abc8d60-72f5-4bf2-a3e8-3a8486a993bd', (SELECT id FROM states WHERE name = 'Kwara'), 'Isin', 'NG-KW-ISN'),
+    ('6484a472-5c16-4042-bc0a-89c8bf2e56d9', (SELECT id FROM states WHERE name = 'Kwara'), 'Kaiama', 'NG-KW-KMA'),
+    ('a580ec79-75cc-43ab-b5b3-ad359cd8bb0f', (SELECT id FROM states WHERE name = 'Kwara'), 'Moro', 'NG-KW-MRO'),
+    ('a044f4ff-ab0a-413c-8545-088cb5dfa0a1', (SELECT id FROM states WHERE name = 'Kwara'), 'Offa', 'NG-KW-OFF'),
+    ('bc7e904b-87ef-43ba-bd1d-9289072cab58', (SELECT id FROM states WHERE name = 'Kwara'), 'Oke Ero', 'NG-KW-OKE'),
+    ('d1335c02-643b-404a-b934-4f7f4f4995c0', (SELECT id FROM states WHERE name = 'Kwara'), 'Oyun', 'NG-KW-OYN'),
+    ('8b831046-94b6-4324-83cb-3e6b1bfb27ed', (SELECT id FROM states WHERE name = 'Kwara'), 'Pategi', 'NG-KW-PTG'),
+    ('8cdcfadb-4cf5-43e7-bacd-50aca466e372', (SELECT id FROM states WHERE name = 'Lagos'), 'Agege', 'NG-LA-AGG'),
+    ('c79d01a2-e26d-4ea9-afdf-f4710f0d2c95', (SELECT id FROM states WHERE name = 'Lagos'), 'Ajeromi-Ifelodun', 'NG-LA-AJI'),
+    ('a3c26a3e-43ff-4654-b0b1-76bca203fd34', (SELECT id FROM states WHERE name = 'Lagos'), 'Alimosho', 'NG-LA-ALM'),
+    ('17a1647d-0ebc-4d76-88eb-2c06c5d22fe2', (SELECT id FROM states WHERE name = 'Lagos'), 'Amuwo-Odofin', 'NG-LA-AMO'),
+    ('76cc35de-60cd-41b1-a350-80ee1d7e2e80', (SELECT id FROM states WHERE name = 'Lagos'), 'Apapa', 'NG-LA-APP'),
+    ('e363823e-1f92-4fb6-97ea-c97d53b90b7a', (SELECT id FROM states WHERE name = 'Lagos'), 'Badagry', 'NG-LA-BDG'),
+    ('a338878c-7983-48f6-903d-27cf42fdc4fe', (SELECT id FROM states WHERE name = 'Lagos'), 'Epe', 'NG-LA-EPE'),
+    ('f649a66f-74ff-4725-b591-486d387d04bc', (SELECT id FROM states WHERE name = 'Lagos'), 'Eti Osa', 'NG-LA-ETO'),
+    ('5830f7d0-9504-4786-a7de-27fd327f6f62', (SELECT id FROM states WHERE name = 'Lagos'), 'Ibeju-Lekki', 'NG-LA-IBL'),
+    ('7c39af8a-17fc-4273-b1b2-fa4d7442b8ca', (SELECT id FROM states WHERE name = 'Lagos'), 'Ifako-Ijaiye', 'NG-LA-IFI'),
+    ('d965bd6b-d611-430b-9e72-253532d9caeb', (SELECT id FROM states WHERE name = 'Lagos'), 'Ikeja', 'NG-LA-IKJ'),
+    ('27e46beb-ce9e-4ac3-95de-586934ceceb8', (SELECT id FROM states WHERE name = 'Lagos'), 'Ikorodu', 'NG-LA-IKR'),
+    ('169e9a0a-5ede-451a-a3d9-520abe81c272', (SELECT id FROM states WHERE name = 'Lagos'), 'Kosofe', 'NG-LA-KSF'),
+    ('5541b8cc-5336-40d8-a20e-832d2b5eda69', (SELECT id FROM states WHERE name = 'Lagos'), 'Lagos Island', 'NG-LA-LGI'),
+    ('481a4688-0d2d-4a9d-a4ac-f782081caa00', (SELECT id FROM states WHERE name = 'Lagos'), 'Lagos Mainland', 'NG-LA-LGM'),
+    ('edb9dfc8-8556-4422-8639-09a0e38da0b7', (SELECT id FROM states WHERE name = 'Lagos'), 'Mushin', 'NG-LA-MSH'),
+    ('19d383e9-265c-4c03-bd45-3ca53e81ae02', (SELECT id FROM states WHERE name = 'Lagos'), 'Ojo', 'NG-LA-OJO'),
+    ('bdcf44c5-12cf-4f96-8051-b7cf7a4227ab', (SELECT id FROM states WHERE name = 'Lagos'), 'Oshodi-Isolo', 'NG-LA-OSI'),
+    ('1dafb6ed-639e-4fb5-a727-6125be697fc4', (SELECT id FROM states WHERE name = 'Lagos'), 'Shomolu', 'NG-LA-SHM'),
+    ('bbf30cac-3137-4fb1-90d1-259bf22579aa', (SELECT id FROM states WHERE name = 'Lagos'), 'Surulere', 'NG-LA-SRL'),
+    ('3a0dfd7e-9784-40e1-9e35-20a40c7b6c2c', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Akwanga', 'NG-NA-AKW'),
+    ('1462b49f-cd90-4020-a83f-fd1ece8bd3c6', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Awe', 'NG-NA-AWE'),
+    ('cd188a1a-c3d5-4514-b231-95ed21897d9c', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Doma', 'NG-NA-DMO'),
+    ('d5759082-32e8-4fc2-95c2-052ad12883cc', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Karu', 'NG-NA-KRA'),
+    ('8ecc99f1-58c5-43e2-a38c-5303bb98bbbb', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Keana', 'NG-NA-KNE'),
+    ('5ee37e95-4c39-425a-ae5f-645a65d88faa', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Keffi', 'NG-NA-KFF'),
+    ('2684d430-f45c-409e-b402-31c3ddbc3288', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Kokona', 'NG-NA-KKN'),
+    ('a35ca8a4-b542-4ff5-b05d-8040db589ea2', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Lafia', 'NG-NA-LFA'),
+    ('04e73260-d4ff-47a1-ba58-065028bb32c5', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Nasarawa', 'NG-NA-NSR'),
+    ('d231cb65-9615-4051-a892-29b591674290', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Nasarawa Eggon', 'NG-NA-NSE'),
+    ('dca01d17-844c-40db-b1e7-860269bcae59', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Obi', 'NG-NA-OBI'),
+    ('d63472ae-34cc-43d7-a3d3-843096efbb80', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Toto', 'NG-NA-TTO'),
+    ('f9293d77-1032-4d1d-9e2f-49245f3192a7', (SELECT id FROM states WHERE name = 'Nasarawa'), 'Wamba', 'NG-NA-WMB'),
+    ('996a5337-dbc4-4cf9-8e75-1eb3a64df582', (SELECT id FROM states WHERE name = 'Niger'), 'Agaie', 'NG-NI-AGA'),
+    ('dcd2e858-e340-49b0-b1ad-32c0bc1274f3', (SELECT id FROM states WHERE name = 'Niger'), 'Agwara', 'NG-NI-AGW'),
+    ('cfac7f78-6462-4943-a395-17e9b3b27f28', (SELECT id FROM states WHERE name = 'Niger'), 'Bida', 'NG-NI-BDI'),
+    ('bc1c4451-e19c-4b06-8cda-42246004bf92', (SELECT id FROM states WHERE name = 'Niger'), 'Borgu', 'NG-NI-BRG'),
+    ('1c99f5ba-b926-4576-8839-15e415ef88c9', (SELECT id FROM states WHERE name = 'Niger'), 'Bosso', 'NG-NI-BSS'),
+    ('15ced91b-e504-4225-bc06-6517db5e5377', (SELECT id FROM states WHERE name = 'Niger'), 'Chanchaga', 'NG-NI-CHN'),
+    ('b36cf634-7d75-4140-9d79-c56c0a678691', (SELECT id FROM states WHERE name = 'Niger'), 'Edati', 'NG-NI-EDT'),
+    ('a3c5a277-b241-4c1d-8317-a7fdfdd35281', (SELECT id FROM states WHERE name = 'Niger'), 'Gbako', 'NG-NI-GBK'),
+    ('373b8426-0ca0-410b-9a89-a4963ebc4e13', (SELECT id FROM states WHERE name = 'Niger'), 'Gurara', 'NG-NI-GRR'),
+    ('f7183b94-d7a1-4a89-af27-b1a681eaee78', (SELECT id FROM states WHERE name = 'Niger'), 'Katcha', 'NG-NI-KTC'),
+    ('2cff00b1-bb60-4f9b-868c-d4a6672a8524', (SELECT id FROM states WHERE name = 'Niger'), 'Kontagora', 'NG-NI-KNT'),
+    ('7df9f14b-8477-4861-bfeb-4c5645670939', (SELECT id FROM states WHERE name = 'Niger'), 'Lapai', 'NG-NI-LPA'),
+    ('8dc106ce-38af-4beb-bbe2-78d03b4c416b', (SELECT id FROM states WHERE name = 'Niger'), 'Lavun', 'NG-NI-LVN'),
+    ('4d8f1596-0857-4765-b025-543c60f062c0', (SELECT id FROM states WHERE name = 'Niger'), 'Magama', 'NG-NI-MGM'),
+    ('7cd55579-3608-450b-a1a4-721eec3ef4dc', (SELECT id FROM states WHERE name = 'Niger'), 'Mariga', 'NG-NI-MRG'),
+    ('3194fe9c-dfce-47b3-8a6a-bfd05edbc8e8', (SELECT id FROM states WHERE name = 'Niger'), 'Mashegu', 'NG-NI-MSH'),
+    ('1e3bde69-75ca-41d4-b80f-c444375d110d', (SELECT id FROM states WHERE name = 'Niger'), 'Mokwa', 'NG-NI-MKW'),
+    ('53da8b3c-2cbb-453d-b614-e703905ac018', (SELECT id FROM states WHERE name = 'Niger'), 'Munya', 'NG-NI-MNY'),
+    ('2844f0d0-c6be-4fe2-86c5-4423d259be07', (SELECT id FROM states WHERE name = 'Niger'), 'Paikoro', 'NG-NI-PKR'),
+    ('7eacada6-8201-4479-8046-9dff0f5d91ab', (SELECT id FROM states WHERE name = 'Niger'), 'Rafi', 'NG-NI-RFA'),
+    ('dff147b2-6e12-4bcd-bbb2-7eb2d44bd9e1', (SELECT id FROM states WHERE name = 'Niger'), 'Rijau', 'NG-NI-RJI'),
+    ('ae8ec26b-eab1-4a57-8e32-7bc2062a74d9', (SELECT id FROM states WHERE name = 'Niger'), 'Shiroro', 'NG-NI-SHR'),
+    ('0bd50933-ac16-455f-9e7d-11d8e1837744', (SELECT id FROM states WHERE name = 'Niger'), 'Suleja', 'NG-NI-SLJ'),
+    ('3775040b-f61c-46c9-90be-0b8c7c84d29d', (SELECT id FROM states WHERE name = 'Niger'), 'Tafa', 'NG-NI-TFA'),
+    ('baf0f299-af33-4172-8785-fc816dd98407', (SELECT id FROM states WHERE name = 'Niger'), 'Wushishi', 'NG-NI-WSH'),
+    ('5f3e2d54-1ce7-4d07-8aba-aefdb28a9638', (SELECT id FROM states WHERE name = 'Ogun'), 'Abeokuta North', 'NG-OG-ABN'),
+    ('81b82c1e-dbbb-4c6a-9886-5908123ae655', (SELECT id FROM states WHERE name = 'Ogun'), 'Abeokuta South', 'NG-OG-ABS'),
+    ('b004dceb-e4c3-4520-9594-22e92e5b39f4', (SELECT id FROM states WHERE name = 'Ogun'), 'Ado-Odo/Ota', 'NG-OG-AOO'),
+    ('d3298489-f00f-449e-a0ed-5828726a82ac', (SELECT id FROM states WHERE name = 'Ogun'), 'Ewekoro', 'NG-OG-EWK'),
+    ('6efe0c66-fead-4519-8e10-e2f741324114', (SELECT id FROM states WHERE name = 'Ogun'), 'Ifo', 'NG-OG-IFO'),
+    ('5b11785a-ed63-46ea-8dac-f2f74ac0d087', (SELECT id FROM states WHERE name = 'Ogun'), 'Ijebu East', 'NG-OG-IJE'),
+    ('f0bed0ec-c395-49ee-834e-a5869bb74913', (SELECT id FROM states WHERE name = 'Ogun'), 'Ijebu North', 'NG-OG-IJN'),
+    ('6313f1df-8845-48b9-b1cf-1e9eb637ffea', (SELECT id FROM states WHERE name = 'Ogun'), 'Ijebu North East', 'NG-OG-INE'),
+    ('1085e417-a25a-429e-8f94-805462010308', (SELECT id FROM states WHERE name = 'Ogun'), 'Ijebu Ode', 'NG-OG-IJO'),
+    ('9708186c-3f93-4417-8d4e-efaf9e87fd11', (SELECT id FROM states WHERE name = 'Ogun'), 'Ikenne', 'NG-OG-IKN'),
+    ('a169083c-57bb-40ff-b092-58e5068b46e8', (SELECT id FROM states WHERE name = 'Ogun'), 'Imeko Afon', 'NG-OG-IMA'),
+    ('15339474-4221-486d-95f7-bf692bd1bfc5', (SELECT id FROM states WHERE name = 'Ogun'), 'Ipokia', 'NG-OG-IPK'),
+    ('28193633-3c08-4056-b78c-5d691603fee6', (SELECT id FROM states WHERE name = 'Ogun'), 'Obafemi Owode', 'NG-OG-OBO'),
+    ('2885bd01-223d-499f-bb0a-71bf97cece52', (SELECT id FROM states WHERE name = 'Ogun'), 'Odeda', 'NG-OG-ODD'),
+    ('b9a5b040-32f4-4b45-a107-4b6653d1a17a', (SELECT id FROM states WHERE name = 'Ogun'), 'Odogbolu', 'NG-OG-ODG'),
+    ('f9d9108d-ee91-4a82-bf1e-aa53ed076210', (SELECT id FROM states WHERE name = 'Ogun'), 'Ogun Waterside', 'NG-OG-OGW'),
+    ('88be31ec-6586-402c-a52f-cf5a2920a4aa', (SELECT id FROM states WHERE name = 'Ogun'), 'Remo North', 'NG-OG-RMN'),
+    ('bb4fac5d-2491-4ce9-b44a-783188b0621d', (SELECT id FROM states WHERE name = 'Ogun'), 'Shagamu', 'NG-OG-SHG'),
+    ('bc5a9336-6553-4afd-bd3d-a06f084b38c9', (SELECT id FROM states WHERE name = 'Ogun'), 'Yewa North', 'NG-OG-YWN'),
+    ('98b64d0c-db95-4ceb-83a0-51d24fd5b947', (SELECT id FROM states WHERE name = 'Ogun'), 'Yewa South', 'NG-OG-YWS'),
+    ('f715d0e0-336f-4717-8b6e-631b13a33c68', (SELECT id FROM states WHERE name = 'Ondo'), 'Akoko North-East', 'NG-ON-ANE'),
+    ('5ab0fade-50b1-470c-bf17-f1cc33549b56', (SELECT id FROM states WHERE name = 'Ondo'), 'Akoko North-West', 'NG-ON-ANW'),
+    ('bc51a1d2-8237-49db-81de-9c32682999b3', (SELECT id FROM states WHERE name = 'Ondo'), 'Akoko South-East', 'NG-ON-ASE'),
+    ('7cc138d5-b49a-4a34-9979-bf5eeac29ece', (SELECT id FROM states WHERE name = 'Ondo'), 'Akoko South-West', 'NG-ON-ASW'),
+    ('38c23ab1-bec1-4934-8226-4f2eec86225f', (SELECT id FROM states WHERE name = 'Ondo'), 'Akure North', 'NG-ON-AKN'),
+    ('8f611602-3436-446e-aed0-d5b7a6e35d40', (SELECT id FROM states WHERE name = 'Ondo'), 'Akure South', 'NG-ON-AKS'),
+    ('372b47dc-4f01-4da3-9274-ac93c1cc85e2', (SELECT id FROM states WHERE name = 'Ondo'), 'Ese Odo', 'NG-ON-ESO'),
+    ('51629b4a-6d4a-4964-8f44-c90631b6d5a6', (SELECT id FROM states WHERE name = 'Ondo'), 'Idanre', 'NG-ON-IDN'),
+    ('add045e4-1e7b-442e-a318-658587be9d10', (SELECT id FROM states WHERE name = 'Ondo'), 'Ifedore', 'NG-ON-IFD'),
+    ('09aa3dbb-7bd2-47a8-ba43-9d62141b78c0', (SELECT id FROM states WHERE name = 'Ondo'), 'Ilaje', 'NG-ON-ILJ'),
+    ('fc29b513-cb82-4b50-bfd1-ad92e27dac59', (SELECT id FROM states WHERE name = 'Ondo'), 'Ile Oluji/Okeigbo', 'NG-ON-IOO'),
+    ('84369971-34da-4fb4-aed2-dccfb676dca5', (SELECT id FROM states WHERE name = 'Ondo'), 'Irele', 'NG-ON-IRL'),
+    ('3ecd7242-7dfc-4da3-8a8d-a7c14fc2bbac', (SELECT id FROM states WHERE name = 'Ondo'), 'Odigbo', 'NG-ON-ODG'),
+    ('19a854e0-a5a3-4f40-a996-6449684b440f', (SELECT id FROM states WHERE name = 'Ondo'), 'Okitipupa', 'NG-ON-OKT'),
+    ('ad227732-fa82-46f8-affc-c33df65a80f2', (SELECT id FROM states WHERE name = 'Ondo'), 'Ondo East', 'NG-ON-ONE'),
+    ('28c21c3f-0078-42bc-af8d-311c41e6090b', (SELECT id FROM states WHERE name = 'Ondo'), 'Ondo West', 'NG-ON-ONW'),
+    ('49905263-f7c9-44c4-ac3b-7d6d46310966', (SELECT id FROM states WHERE name = 'Ondo'), 'Ose', 'NG-ON-OSE'),
+    ('717cfd33-8147-4982-99d9-42b07b1a079c', (SELECT id FROM states WHERE name = 'Ondo'), 'Owo', 'NG-ON-OWO'),
+    ('8bdc6090-a78c-45bb-a0c1-04aae8374387', (SELECT id FROM states WHERE name = 'Osun'), 'Aiyedaade', 'NG-OS-AYD'),
+    ('b2875678-b4b0-4ddb-a3e6-778294439341', (SELECT id FROM states WHERE name = 'Osun'), 'Aiyedire', 'NG-OS-AY2'),
+    ('d35337cc-73cc-4ad3-b82c-395ad439ab7c', (SELECT id FROM states WHERE name = 'Osun'), 'Atakunmosa East', 'NG-OS-ATE'),
+    ('0404a141-c24c-4bbf-a6cf-d753ca96c64c', (SELECT id FROM states WHERE name = 'Osun'), 'Atakunmosa West', 'NG-OS-ATW'),
+    ('4de75e68-f0b3-44f4-87ad-d1e6d4d7137e', (SELECT id FROM states WHERE name = 'Osun'), 'Boluwaduro', 'NG-OS-BLW'),
+    ('9a375438-85fe-43f9-87f8-43eab3d5aa87', (SELECT id FROM states WHERE name = 'Osun'), 'Boripe', 'NG-OS-BRP'),
+    ('ecb724bd-6709-4a10-92ad-1af3300fa991', (SELECT id FROM states WHERE name = 'Osun'), 'Ede North', 'NG-OS-EDN'),
+    ('739a247e-b8d0-4fcf-a5d2-2d1809b1667a', (SELECT id FROM states WHERE name = 'Osun'), 'Ede South', 'NG-OS-EDS'),
+    ('f06c3c61-e298-4623-b158-f29383f0715f', (SELECT id FROM states WHERE name = 'Osun'), 'Egbedore', 'NG-OS-EGB'),
+    ('9268c48d-8184-4e87-b3b1-8a6e7d764a37', (SELECT id FROM states WHERE name = 'Osun'), 'Ejigbo', 'NG-OS-EJG'),
+    ('d4f32733-aba6-426c-8a87-f81068d01b78', (SELECT id FROM states WHERE name = 'Osun'), 'Ife Central', 'NG-OS-IFC'),
+    ('f896b76a-025c-4b9d-a09b-eb460cf6521c', (SELECT id FROM states WHERE name = 'Osun'), 'Ife East', 'NG-OS-IFE'),
+    ('93d317b5-2f95-4aae-b3a3-84aee631adde', (SELECT id FROM states WHERE name = 'Osun'), 'Ife North', 'NG-OS-IFN'),
+    ('76c2bd2c-0bc7-4434-a1b0-ddf6d7b95e27', (SELECT id FROM states WHERE name = 'Osun'), 'Ife South', 'NG-OS-IFS'),
+    ('245f72e3-0c3d-4bb3-b4df-d246a7613089', (SELECT id FROM states WHERE name = 'Osun'), 'Ifedayo', 'NG-OS-IFD'),
+    ('1b59e905-2499-42db-b881-2b7307ead655', (SELECT id FROM states WHERE name = 'Osun'), 'Ifelodun', 'NG-OS-IFL'),
+    ('169d46fa-55f5-410b-aa10-44d2ecb8ec99', (SELECT id FROM states WHERE name = 'Osun'), 'Ila', 'NG-OS-ILA'),
+    ('decddd07-f53c-4cd1-b8e6-f4b66bb3738c', (SELECT id FROM states WHERE name = 'Osun'), 'Ilesa East', 'NG-OS-ILE'),
+    ('0107339d-34c2-482b-a911-5f0a801234e5', (SELECT id FROM states WHERE name = 'Osun'), 'Ilesa West', 'NG-OS-ILW'),
+    ('47dccca8-6476-44f3-b86f-8b3409469f28', (SELECT id FROM states WHERE name = 'Osun'), 'Irepodun', 'NG-OS-IRP'),
+    ('9042f87c-d232-4a37-8525-6a5f0c307965', (SELECT id FROM states WHERE name = 'Osun'), 'Irewole', 'NG-OS-IRW'),
+    ('be41b433-d6f1-4fdd-9f39-d9e56a5de5f9', (SELECT id FROM states WHERE name = 'Osun'), 'Isokan', 'NG-OS-ISK'),
+    ('d04d8963-9c63-424e-9ddd-6409ba68be98', (SELECT id FROM states WHERE name = 'Osun'), 'Iwo', 'NG-OS-IWO'),
+    ('84c69b4b-0c70-468f-be66-a4484f316a53', (SELECT id FROM states WHERE name = 'Osun'), 'Obokun', 'NG-OS-OBK'),
+    ('0405de5c-96e8-4117-986b-1e4db765454f', (SELECT id FROM states WHERE name = 'Osun'), 'Odo Otin', 'NG-OS-ODO'),
+    ('e319360e-d649-4cc2-a50a-4dc498a94c7d', (SELECT id FROM states WHERE name = 'Osun'), 'Ola Oluwa', 'NG-OS-OLO'),
+    ('1b886a5c-72a6-4e83-b09d-22d94c9865b7', (SELECT id FROM states WHERE name = 'Osun'), 'Olorunda', 'NG-OS-OLR'),
+    ('644eab7e-d845-46e2-8b3a-8eb0b1e8db8a', (SELECT id FROM states WHERE name = 'Osun'), 'Oriade', 'NG-OS-ORD'),
+    ('d4f682d2-a08b-465d-a16c-2b96f39220c1', (SELECT id FROM states WHERE name = 'Osun'), 'Orolu', 'NG-OS-ORL'),
+    ('c196b03a-0171-4b28-9a7b-aa95671cf029', (SELECT id FROM states WHERE name = 'Osun'), 'Osogbo', 'NG-OS-OSG'),
+    ('cc9bf6f1-a758-4ad6-a21f-2d3d3dc6b1b8', (SELECT id FROM states WHERE name = 'Oyo'), 'Afijio', 'NG-OY-AFJ'),
+    ('6d91a246-c2f1-483d-9235-9a3e33cc71c5', (SELECT id FROM states WHERE name = 'Oyo'), 'Akinyele', 'NG-OY-AKN'),
+    ('e5b1ee0d-9c11-4318-b455-ea368ebbaf6a', (SELECT id FROM states WHERE name = 'Oyo'), 'Atiba', 'NG-OY-ATB'),
+    ('3e3f042f-aca5-4991-8de2-cd261ab41254', (SELECT id FROM states WHERE name = 'Oyo'), 'Atisbo', 'NG-OY-ATS'),
+    ('ba627f44-8695-4fc2-b592-7df98c386d5b', (SELECT id FROM states WHERE name = 'Oyo'), 'Egbeda', 'NG-OY-EGB'),
+    ('71f5f775-1cde-4bce-9cf4-d9a0512ec493', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibadan North', 'NG-OY-IBN'),
+    ('0d1c595f-bdb7-4892-a67c-a3e8dc056cfc', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibadan North-East', 'NG-OY-INE'),
+    ('eeb763ed-d6b4-4d9d-a1a2-a3fd8f71fa12', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibadan North-West', 'NG-OY-INW'),
+    ('f0bb039d-89a8-4ef9-8dd5-68fef4c6f35e', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibadan South-East', 'NG-OY-ISE'),
+    ('ed7da07f-f80c-47da-9f42-96821c698662', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibadan South-West', 'NG-OY-ISW'),
+    ('06ce4961-1c73-43fe-8ca3-6823602aa971', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibarapa Central', 'NG-OY-IBC'),
+    ('d89459a4-ba0b-4f4b-9c1b-f10095d3ab5d', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibarapa East', 'NG-OY-IBE'),
+    ('ff1cd786-22d9-40cb-a54b-6b09ecde252a', (SELECT id FROM states WHERE name = 'Oyo'), 'Ibarapa North', 'NG-OY-IB2'),
+    ('a463ddf5-71bc-4dbd-9017-5139d63fe694', (SELECT id FROM states WHERE name = 'Oyo'), 'Ido', 'NG-OY-IDO'),
+    ('885dfcc1-b992-4380-be0e-79f03633fd76', (SELECT id FROM states WHERE name = 'Oyo'), 'Irepo', 'NG-OY-IRP'),
+    ('eb045d58-a29b-41dc-b54b-2dc64acf0adf', (SELECT id FROM states WHERE name = 'Oyo'), 'Iseyin', 'NG-OY-ISY'),
+    ('f0ac77b1-0240-419d-be63-f0a9f7d6c94e', (SELECT id FROM states WHERE name = 'Oyo'), 'Itesiwaju', 'NG-OY-ITS'),
+    ('6829ffdf-e538-4851-9527-f6c39acb4354', (SELECT id FROM states WHERE name = 'Oyo'), 'Iwajowa', 'NG-OY-IWJ'),
+    ('9fdb9e97-6cb4-4a1d-b1ed-ec224abf6c14', (SELECT id FROM states WHERE name = 'Oyo'), 'Kajola', 'NG-OY-KJL'),
+    ('3a99c91d-8cda-4f39-817d-5e2d3a8b9660', (SELECT id FROM states WHERE name = 'Oyo'), 'Lagelu', 'NG-OY-LGL'),
+    ('a2a65c71-304e-4a36-ba78-a8b56c920198', (SELECT id FROM states WHERE name = 'Oyo'), 'Ogbomosho North', 'NG-OY-OGN'),
+    ('568024bf-4969-4445-aafa-7ae03b92238f', (SELECT id FROM states WHERE name = 'Oyo'), 'Ogbomosho South', 'NG-OY-OGS'),
+    ('dc9fe157-8b5e-4510-9747-a14bedd71188', (SELECT id FROM states WHERE name = 'Oyo'), 'Ogo Oluwa', 'NG-OY-OGO'),
+    ('b180e8fc-9eb2-451c-8e20-69f42a49bea3', (SELECT id FROM states WHERE name = 'Oyo'), 'Olorunsogo', 'NG-OY-OLR'),
+    ('3de48ed8-0647-41c3-b710-b41ada6deed3', (SELECT id FROM states WHERE name = 'Oyo'), 'Oluyole', 'NG-OY-OLY'),
+    ('2c67d7b4-f5f9-45ca-b0f2-132db5146421', (SELECT id FROM states WHERE name = 'Oyo'), 'Ona Ara', 'NG-OY-ONA'),
+    ('208a4f98-6984-4bcb-aab2-e7a4614b11b4', (SELECT id FROM states WHERE name = 'Oyo'), 'Orelope', 'NG-OY-ORL'),
+    ('36c41a84-2d09-4335-9289-95f5c89f2b25', (SELECT id FROM states WHERE name = 'Oyo'), 'Ori Ire', 'NG-OY-ORI'),
+    ('142b956e-1ad2-4385-9fb6-6b08db58f747', (SELECT id FROM states WHERE name = 'Oyo'), 'Oyo East', 'NG-OY-OYE'),
+    ('dfbb6049-e9c9-456d-9542-be33ed91a72c', (SELECT id FROM states WHERE name = 'Oyo'), 'Oyo West', 'NG-OY-OYW'),
+    ('16ed8b14-d958-4f04-bb6f-29fb07757049', (SELECT id FROM states WHERE name = 'Oyo'), 'Saki East', 'NG-OY-SKE'),
+    ('ac9c14e9-a5bd-469d-be5b-4b9242d7430c', (SELECT id FROM states WHERE name = 'Oyo'), 'Saki West', 'NG-OY-SKW'),
+    ('23d20c9a-341d-4807-8895-909592a16d66', (SELECT id FROM states WHERE name = 'Oyo'), 'Surulere', 'NG-OY-SRL'),
+    ('15556c66-23da-40d4-a2da-dbb63c58cd0a', (SELECT id FROM states WHERE name = 'Plateau'), 'Barkin Ladi', 'NG-PL-BRL'),
+    ('003da7b0-7fc4-4b3e-b110-c624f6744139', (SELECT id FROM states WHERE name = 'Plateau'), 'Bassa', 'NG-PL-BSS'),
+    ('3fa18a49-9329-42e3-8351-cb6a15e2c793', (SELECT id FROM states WHERE name = 'Plateau'), 'Bokkos', 'NG-PL-BKK'),
+    ('df20c675-10ce-4b3f-a615-c5093f612b0a', (SELECT id FROM states WHERE name = 'Plateau'), 'Jos East', 'NG-PL-JSE'),
+    ('0e9deafd-e93a-448c-bb4a-e8ea8bc3c323', (SELECT id FROM states WHERE name = 'Plateau'), 'Jos North', 'NG-PL-JSN'),
+    ('6905c016-8024-4c68-8c80-491a8ae31877', (SELECT id FROM states WHERE name = 'Plateau'), 'Jos South', 'NG-PL-JSS'),
+    ('70fad1e2-c8e1-4346-9032-638e73c1306a', (SELECT id FROM states WHERE name = 'Plateau'), 'Kanam', 'NG-PL-KNM'),
+    ('9817618e-63b7-4fcd-932d-c9e009c61e6d', (SELECT id FROM states WHERE name = 'Plateau'), 'Kanke', 'NG-PL-KNK'),
+    ('8126dbac-c528-4686-b91b-c557ad70bfc5', (SELECT id FROM states WHERE name = 'Plateau'), 'Langtang North', 'NG-PL-LNN'),
+    ('543f8a75-acc5-4fff-95b5-8a261d8649aa', (SELECT id FROM states WHERE name = 'Plateau'), 'Langtang South', 'NG-PL-LNS'),
+    ('c4516357-0413-43d5-9e6c-d17d7da1a1db', (SELECT id FROM states WHERE name = 'Plateau'), 'Mangu', 'NG-PL-MNG'),
+    ('7ea0da20-87be-4f1c-a46b-e9d3cef5a061', (SELECT id FROM states WHERE name = 'Plateau'), 'Mikang', 'NG-PL-MKN'),
+    ('1707bee2-ce5a-43cf-9f72-1d594dc32e7d', (SELECT id FROM states WHERE name = 'Plateau'), 'Pankshin', 'NG-PL-PNK'),
+    ('9e052665-af05-4d1b-9e61-96d2f5f33e26', (SELECT id FROM states WHERE name = 'Plateau'), 'Qua''an Pan', 'NG-PL-QAP'),
+    ('1ad4ecb3-d871-462d-ade9-f9fb42cb7e54', (SELECT id FROM states WHERE name = 'Plateau'), 'Riyom', 'NG-PL-RYM'),
+    ('92e890e1-2027-4557-b131-c43526d101f5', (SELECT id FROM states WHERE name = 'Plateau'), 'Shendam', 'NG-PL-SHN'),
+    ('d033d5ab-73be-4c89-a410-4214741f103b', (SELECT id FROM states WHERE name = 'Plateau'), 'Wase', 'NG-PL-WSA'),
+    ('23f0f938-06f1-4831-9f95-b3b94e3c29d9', (SELECT id FROM states WHERE name = 'Rivers'), 'Abua/Odual', 'NG-RI-ABO'),
+    ('4223559e-45e8-4800-840e-786cebdb7500', (SELECT id FROM states WHERE name = 'Rivers'), 'Ahoada East', 'NG-RI-AHE'),
+    ('e1d1c609-9ccc-4f7a-a472-fd9db5f6e2b8', (SELECT id FROM states WHERE name = 'Rivers'), 'Ahoada West', 'NG-RI-AHW'),
+    ('575453d6-ce17-4c33-943d-aa123c12e1d8', (SELECT id FROM states WHERE name = 'Rivers'), 'Akuku-Toru', 'NG-RI-AKT'),
+    ('610872fe-6b0f-4fb9-99ff-7020d7c88f1f', (SELECT id FROM states WHERE name = 'Rivers'), 'Andoni', 'NG-RI-AND'),
+    ('18a82154-27f0-4d9d-866e-f8872cf6c6b7', (SELECT id FROM states WHERE name = 'Rivers'), 'Asari-Toru', 'NG-RI-AST'),
+    ('4dcba0e9-77e4-4976-99d2-ab577e51f2ee', (SELECT id FROM states WHERE name = 'Rivers'), 'Bonny', 'NG-RI-BNN'),
+    ('d1be9ea6-931b-4a9d-9284-dead24a5095f', (SELECT id FROM states WHERE name = 'Rivers'), 'Degema', 'NG-RI-DGM'),
+    ('3dba42a9-e255-4f32-ace5-a633d751ddef', (SELECT id FROM states WHERE name = 'Rivers'), 'Eleme', 'NG-RI-ELM'),
+    ('842e3605-aeab-4c89-b9d5-b43339b8ffe7', (SELECT id FROM states WHERE name = 'Rivers'), 'Emohua', 'NG-RI-EMH'),
+    ('d73da8de-c74e-4934-9b2f-a5f1c369d20c', (SELECT id FROM states WHERE name = 'Rivers'), 'Etche', 'NG-RI-ETC'),
+    ('6402c840-241a-4464-8143-d27245a842ae', (SELECT id FROM states WHERE name = 'Rivers'), 'Gokana', 'NG-RI-GKN'),
+    ('8e7e6605-1101-4402-940b-14a892152461', (SELECT id FROM states WHERE name = 'Rivers'), 'Ikwerre', 'NG-RI-IKW'),
+    ('2d60c731-b582-4b87-8cf2-0c67f5edb128', (SELECT id FROM states WHERE name = 'Rivers'), 'Khana', 'NG-RI-KHN'),
+    ('82e4ce21-637d-4de2-afa0-a9b4cc3b5947', (SELECT id FROM states WHERE name = 'Rivers'), 'Obio/Akpor', 'NG-RI-OBA'),
+    ('2cd2ceb6-8deb-47b1-9585-ec49596b926f', (SELECT id FROM states WHERE name = 'Rivers'), 'Ogba/Egbema/Ndoni', 'NG-RI-OEN'),
+    ('6c105670-e17c-4b6c-9401-00b6ba975b02', (SELECT id FROM states WHERE name = 'Rivers'), 'Ogu/Bolo', 'NG-RI-OGB'),
+    ('96fd9830-1be8-4062-93b8-614314ea8be7', (SELECT id FROM states WHERE name = 'Rivers'), 'Okrika', 'NG-RI-OKR'),
+    ('acabd2e8-2a4f-4f99-8f4d-f6cfb9a35c1b', (SELECT id FROM states WHERE name = 'Rivers'), 'Omuma', 'NG-RI-OMM'),
+    ('7cb94a83-ea1a-414f-8b8b-ef2ac037cfa9', (SELECT id FROM states WHERE name = 'Rivers'), 'Opobo/Nkoro', 'NG-RI-OPN'),
+    ('4c3c2290-6973-44cd-a62a-6db90d8e326c', (SELECT id FROM states WHERE name = 'Rivers'), 'Oyigbo', 'NG-RI-OYG'),
+    ('405ae352-bcb2-4d02-876a-8d85fce9fc66', (SELECT id FROM states WHERE name = 'Rivers'), 'Port Harcourt', 'NG-RI-PRH'),
+    ('df359153-4c57-487d-8e0f-02bbcc1f6dca', (SELECT id FROM states WHERE name = 'Rivers'), 'Tai', 'NG-RI-TAI'),
+    ('6f544b0b-2818-4f4e-984e-f247cf7faa1c', (SELECT id FROM states WHERE name = 'Sokoto'), 'Binji', 'NG-SO-BNJ'),
+    ('08f2b338-a45c-42c7-8ae3-d174b6cd7082', (SELECT id FROM states WHERE name = 'Sokoto'), 'Bodinga', 'NG-SO-BDN'),
+    ('e667b037-ae42-438d-b4b7-9b07647b0904', (SELECT id FROM states WHERE name = 'Sokoto'), 'Dange Shuni', 'NG-SO-DNS'),
+    ('1f6ffee3-95d4-4db4-a624-3682de517807', (SELECT id FROM states WHERE name = 'Sokoto'), 'Gada', 'NG-SO-GDA'),
+    ('3f65b7d5-043e-4bcb-b771-f0d7ac0fe071', (SELECT id FROM states WHERE name = 'Sokoto'), 'Goronyo', 'NG-SO-GRN'),
+    ('4703be8f-6f11-4beb-af97-fe4c63ccddc9', (SELECT id FROM states WHERE name = 'Sokoto'), 'Gudu', 'NG-SO-GDU'),
+    ('3bc2c225-3971-4628-955e-700fb23062ee', (SELECT id FROM states WHERE name = 'Sokoto'), 'Gwadabawa', 'NG-SO-GWD'),
+    ('97cde3d4-3582-4b6c-ba5c-1f34ade8f0c5', (SELECT id FROM states WHERE name = 'Sokoto'), 'Illela', 'NG-SO-ILL'),
+    ('c6de90ca-cd44-47da-92c0-d117ba6a22b2', (SELECT id FROM states WHERE name = 'Sokoto'), 'Isa', 'NG-SO-ISA'),
+    ('f6d73fda-04a2-46bb-8358-302d64d6174b', (SELECT id FROM states WHERE name = 'Sokoto'), 'Kebbe', 'NG-SO-KBB'),
+    ('145dbd8a-ba25-468e-968d-9a7a572ef9e0', (SELECT id FROM states WHERE name = 'Sokoto'), 'Kware', 'NG-SO-KWR'),
+    ('32fdad7b-d6b5-4d07-8cb8-5ad6a006f3da', (SELECT id FROM states WHERE name = 'Sokoto'), 'Rabah', 'NG-SO-RBH'),
+    ('9a351abc-1f02-46e0-bb67-6b84ec2dab1a', (SELECT id FROM states WHERE name = 'Sokoto'), 'Sabon Birni', 'NG-SO-SBB'),
+    ('83c5c166-83ea-4f98-afb2-1c6eeb34c473', (SELECT id FROM states WHERE name = 'Sokoto'), 'Shagari', 'NG-SO-SHG'),
+    ('01e75207-cfae-4b46-b5e9-739c8f14ab7f', (SELECT id FROM states WHERE name = 'Sokoto'), 'Silame', 'NG-SO-SLM'),
+    ('0b4a7deb-eda2-4901-828a-553ad6ddb997', (SELECT id FROM states WHERE name = 'Sokoto'), 'Sokoto North', 'NG-SO-SKN'),
+    ('3154605d-bea0-4f2d-881f-46ad7a8e144a', (SELECT id FROM states WHERE name = 'Sokoto'), 'Sokoto South', 'NG-SO-SKS'),
+    ('e7625d2d-796c-4c21-a217-02dcfa2cc841', (SELECT id FROM states WHERE name = 'Sokoto'), 'Tambuwal', 'NG-SO-TMB'),
+    ('e8572ecd-b2c4-4b95-9df4-6af0ee2682c4', (SELECT id FROM states WHERE name = 'Sokoto'), 'Tangaza', 'NG-SO-TNG'),
+    ('f2f0422d-8a8f-47f1-8d93-3a6eda8a1d28', (SELECT id FROM states WHERE name = 'Sokoto'), 'Tureta', 'NG-SO-TRT'),
+    ('ca2ce843-a1b8-4c85-aa0a-9e44d2633f04', (SELECT id FROM states WHERE name = 'Sokoto'), 'Wamakko', 'NG-SO-WMK'),
+    ('937eb9bd-5c64-451a-a617-7365b5cb5ca9', (SELECT id FROM states WHERE name = 'Sokoto'), 'Wurno', 'NG-SO-WRN'),
+    ('bbf020fa-01cc-44e3-903e-e81fdee1a0cc', (SELECT id FROM states WHERE name = 'Sokoto'), 'Yabo', 'NG-SO-YBA'),
+    ('4679a03c-1a5e-4957-9291-381120939145', (SELECT id FROM states WHERE name = 'Taraba'), 'Ardo Kola', 'NG-TA-ARK'),
+    ('124e2fdd-8b4e-4f3c-a1e7-65da150214dc', (SELECT id FROM states WHERE name = 'Taraba'), 'Bali', 'NG-TA-BLA'),
+    ('5c399b43-ff6b-4bde-b39b-96c9df541353', (SELECT id FROM states WHERE name = 'Taraba'), 'Donga', 'NG-TA-DNG'),
+    ('c8356ebd-6835-44b9-8d6d-7a82832e991e', (SELECT id FROM states WHERE name = 'Taraba'), 'Gashaka', 'NG-TA-GSH'),
+    ('c88a23a0-96dc-410c-9fa0-4d4148eea45d', (SELECT id FROM states WHERE name = 'Taraba'), 'Gassol', 'NG-TA-GSS'),
+    ('65a4b8ab-193c-461d-aa7f-36f2147b7645', (SELECT id FROM states WHERE name = 'Taraba'), 'Ibi', 'NG-TA-IBI'),
+    ('db55cc7a-29a0-4538-bd12-f199b4345ea9', (SELECT id FROM states WHERE name = 'Taraba'), 'Jalingo', 'NG-TA-JLN'),
+    ('fd22f60c-971f-4817-a6d2-bf89776f6202', (SELECT id FROM states WHERE name = 'Taraba'), 'Karim Lamido', 'NG-TA-KRL'),
+    ('40635fcc-54c9-4da3-a3d3-70c5adf7f0a6', (SELECT id FROM states WHERE name = 'Taraba'), 'Kurmi', 'NG-TA-KRM'),
+    ('fdb41fcb-b9b4-4a75-bf15-1585596ec2e0', (SELECT id FROM states WHERE name = 'Taraba'), 'Lau', 'NG-TA-LAU'),
+    ('ca6c2a67-9ce3-4342-8e03-af68f24c115c', (SELECT id FROM states WHERE name = 'Taraba'), 'Sardauna', 'NG-TA-SRD'),
+    ('535a6f28-6e00-41e3-859e-53237d971e6c', (SELECT id FROM states WHERE name = 'Taraba'), 'Takum', 'NG-TA-TKM'),
+    ('33e083e8-ce1a-434f-8940-d4c416744488', (SELECT id FROM states WHERE name = 'Taraba'), 'Ussa', 'NG-TA-USS'),
+    ('de51ccfa-98a3-40fb-8f47-5bc344bc69e7', (SELECT id FROM states WHERE name = 'Taraba'), 'Wukari', 'NG-TA-WKR'),
+    ('da48b8fc-67c3-4819-8770-70fc966538eb', (SELECT id FROM states WHERE name = 'Taraba'), 'Yorro', 'NG-TA-YRR'),
+    ('f1af3629-5818-4289-84ff-1e33599c5b18', (SELECT id FROM states WHERE name = 'Taraba'), 'Zing', 'NG-TA-ZNG'),
+    ('621df2ae-48f4-4303-80b8-3e89b75a12ba', (SELECT id FROM states WHERE name = 'Yobe'), 'Bade', 'NG-YO-BDA'),
+    ('4b4d5ec9-b926-458c-b2c9-dedbc61bdfaf', (SELECT id FROM states WHERE name = 'Yobe'), 'Bursari', 'NG-YO-BRS'),
+    ('faa22218-cd3c-42a6-871c-88b25ec7b30b', (SELECT id FROM states WHERE name = 'Yobe'), 'Damaturu', 'NG-YO-DMT'),
+    ('330bed92-5e60-4c02-887c-a949bac07c48', (SELECT id FROM states WHERE name = 'Yobe'), 'Fika', 'NG-YO-FKI'),
+    ('5ae5adf9-6117-4da3-a65f-65a688636fac', (SELECT id FROM states WHERE name = 'Yobe'), 'Fune', 'NG-YO-FNU'),
+    ('bce8ccd0-dffa-4d4b-831d-66b45c049f5f', (SELECT id FROM states WHERE name = 'Yobe'), 'Geidam', 'NG-YO-GDM'),
+    ('0985306b-791b-4b6e-903d-cf19f3270b27', (SELECT id FROM states WHERE name = 'Yobe'), 'Gujba', 'NG-YO-GJB'),
+    ('acffec4f-e648-4f4b-bcff-7dfd3dd29d3f', (SELECT id FROM states WHERE name = 'Yobe'), 'Gulani', 'NG-YO-GLN'),
+    ('ce985a2a-e05b-463a-b83d-a0fd764e6784', (SELECT id FROM states WHERE name = 'Yobe'), 'Jakusko', 'NG-YO-JKS'),
+    ('4bac4079-95e6-4c8e-9603-663b06097e07', (SELECT id FROM states WHERE name = 'Yobe'), 'Karasuwa', 'NG-YO-KRS'),
+    ('c1867d0f-f527-4204-8811-0cfe9a471768', (SELECT id FROM states WHERE name = 'Yobe'), 'Machina', 'NG-YO-MCH'),
+    ('3c371ad2-583e-4e26-a3ac-92180b4f6a79', (SELECT id FROM states WHERE name = 'Yobe'), 'Nangere', 'NG-YO-NNG'),
+    ('e733a78e-322e-42f8-a779-a9264a47b9b5', (SELECT id FROM states WHERE name = 'Yobe'), 'Nguru', 'NG-YO-NGR'),
+    ('425e1a49-9267-4103-936f-66177c2a23ed', (SELECT id FROM states WHERE name = 'Yobe'), 'Potiskum', 'NG-YO-PTS'),
+    ('50fc09b2-802e-4d75-907d-2ab76193aa12', (SELECT id FROM states WHERE name = 'Yobe'), 'Tarmuwa', 'NG-YO-TRM'),
+    ('d89171f5-9988-490d-bf26-b97580737a33', (SELECT id FROM states WHERE name = 'Yobe'), 'Yunusari', 'NG-YO-YNS'),
+    ('395dd78f-7548-4842-95f6-ea203ff20150', (SELECT id FROM states WHERE name = 'Yobe'), 'Yusufari', 'NG-YO-YSF'),
+    ('01a758f0-56ad-4c37-9888-2da9843c1131', (SELECT id FROM states WHERE name = 'Zamfara'), 'Anka', 'NG-ZA-ANK'),
+    ('a21ffd9f-9bb0-4783-9cb5-a7f64dc722c2', (SELECT id FROM states WHERE name = 'Zamfara'), 'Bakura', 'NG-ZA-BKR'),
+    ('65c45105-3af0-4ab2-90e7-783823c1626d', (SELECT id FROM states WHERE name = 'Zamfara'), 'Birnin Magaji/Kiyaw', 'NG-ZA-BMK'),
+    ('574b65be-87a4-45da-82b1-66f51e1115d7', (SELECT id FROM states WHERE name = 'Zamfara'), 'Bukkuyum', 'NG-ZA-BKK'),
+    ('ed995267-3dcc-404f-9a7c-fbc434cb10eb', (SELECT id FROM states WHERE name = 'Zamfara'), 'Bungudu', 'NG-ZA-BNG'),
+    ('55b1c546-424f-4be4-b0c4-0125665433aa', (SELECT id FROM states WHERE name = 'Zamfara'), 'Gummi', 'NG-ZA-GMM'),
+    ('7f4f943c-72f5-48a4-a3df-1e2206bf099f', (SELECT id FROM states WHERE name = 'Zamfara'), 'Gusau', 'NG-ZA-GSU'),
+    ('71f09f4d-9097-41b6-81c5-d6427f4df497', (SELECT id FROM states WHERE name = 'Zamfara'), 'Kaura Namoda', 'NG-ZA-KRN'),
+    ('17a29954-d37a-4868-8dab-c90e3d8182e9', (SELECT id FROM states WHERE name = 'Zamfara'), 'Maradun', 'NG-ZA-MRD'),
+    ('01fb44a7-b54a-45b8-b35d-bc6c67845956', (SELECT id FROM states WHERE name = 'Zamfara'), 'Maru', 'NG-ZA-MRA'),
+    ('53e93d09-6f49-4190-a55a-83234b9d06b6', (SELECT id FROM states WHERE name = 'Zamfara'), 'Shinkafi', 'NG-ZA-SHN'),
+    ('56674305-2482-46b6-91cb-1bb05271ae93', (SELECT id FROM states WHERE name = 'Zamfara'), 'Talata Mafara', 'NG-ZA-TLM'),
+    ('e35becc9-c117-4e83-a452-bd6d934cd204', (SELECT id FROM states WHERE name = 'Zamfara'), 'Chafe', 'NG-ZA-CHF'),
+    ('1d9d4201-da4b-4a96-91b2-72db8d1354c0', (SELECT id FROM states WHERE name = 'Zamfara'), 'Zurmi', 'NG-ZA-ZRM');
\ No newline at end of file
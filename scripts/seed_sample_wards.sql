@@ -2,110 +2,110 @@
 -- Note: Replace with authentic data from official sources
 
 INSERT INTO wards (id, lga_id, name, code) VALUES
-    ('043cfb91-c36d-4f49-862b-494f776eedac', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Ward I', 'WARD-0001'),
-    ('1a0f864f-c69e-441f-bf29-ac679ec08bb3', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), '2nd Ward', 'WARD-0002'),
-    ('26f2fe54-77c7-4663-b805-ccb43b13b7dc', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Abuja Municipal Northwest', 'WARD-0003'),
-    ('3c5d31ff-b84c-40b1-9dff-81140f6551b0', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Market Ward', 'WARD-0004'),
-    ('3b1bf8ec-1b80-43f5-9504-b96c19d49e20', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'North Ward', 'WARD-0005'),
-    ('05a84804-5007-46dc-a0f1-77ec869f697f', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Ward VI', 'WARD-0006'),
-    ('0e88fc48-fb4a-427a-add8-7550d4801140', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Ward VII', 'WARD-0007'),
-    ('89e4841a-6dfe-486d-887b-5bc6e422844f', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), '8th Ward', 'WARD-0008'),
-    ('4aca9c2c-a6e5-434d-825c-0103e4cd18e8', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Ward IX', 'WARD-0009'),
-    ('4244c75e-a1d6-438b-a8de-808abc49024e', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Ward X', 'WARD-0010'),
-    ('99931664-c24f-454f-bed3-37dcd05c60aa', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), '1st Ward', 'WARD-0011'),
-    ('cc922376-1699-4222-866e-7a5edd3b7e5b', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), '2nd Ward', 'WARD-0012'),
-    ('dbf52b38-8c7c-4206-80c3-c1ca0420409f', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Abuja Municipal South', 'WARD-0013'),
-    ('dc7d7623-d2cc-4b7a-9979-824066857368', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Abuja Municipal West', 'WARD-0014'),
-    ('bad891f3-925d-4eb1-ba05-354e30818d9e', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Musa Ward', 'WARD-0015'),
-    ('320fbf28-78ee-4773-92bb-756fd492875c', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), '2nd Ward', 'WARD-0016'),
-    ('d9112f99-88d5-429d-a97a-75dd8cff28d1', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), '3rd Ward', 'WARD-0017'),
-    ('7be9e5d8-99b6-4180-a1b2-128636e798b3', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Babatunde Ward', 'WARD-0018'),
-    ('4b3b7f75-43ca-4ad9-a8b8-244bf60cb82a', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'South Ward', 'WARD-0019'),
-    ('a2ad5545-148c-4724-9d77-97caeb1b31d4', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Village Ward', 'WARD-0020'),
-    ('322b4e7e-5ea1-479d-951e-9806614968e8', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Ibrahim Ward', 'WARD-0021'),
-    ('fb7206f7-cdfb-4805-a5c7-a1d75de54365', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Village Ward', 'WARD-0022'),
-    ('76296b45-054d-4eef-94cd-c265455f3eeb', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Seun Ward', 'WARD-0023'),
-    ('3ff0d893-df01-4583-8bac-29eee0721ee9', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Northwest Ward', 'WARD-0024'),
-    ('ac4f48f9-7b31-48d2-b148-f146f8c66328', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Ward I', 'WARD-0025'),
-    ('fb03baa9-0823-485f-a41a-fdfef3751653', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Ward II', 'WARD-0026'),
-    ('566b8f7d-20c6-4936-9c8f-cd49ae84dfbf', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Northwest Ward', 'WARD-0027'),
-    ('1ab8e855-27cc-40a5-ac3d-a0701b1b6779', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Commercial Ward', 'WARD-0028'),
-    ('566b7fdf-8255-4a4a-85ac-3050153db681', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Adewale Ward', 'WARD-0029'),
-    ('fc44b150-e034-4ca5-a538-044d3086b762', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Northeast Ward', 'WARD-0030'),
-    ('ee14abc7-a4aa-4151-8e71-01f87a056934', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Ward VII', 'WARD-0031'),
-    ('48d1ed8f-2969-48c0-977a-d06112eef0c1', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'River Ward', 'WARD-0032'),
-    ('d2e89cdf-09f6-43d1-a48f-c0b62d574a28', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'West Ward', 'WARD-0033'),
-    ('7faeb2a6-267c-4bd5-acbb-132a1a105301', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'Southeast Ward', 'WARD-0034'),
-    ('efb920af-0309-45db-a50a-17ef0a9bbb8b', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'Modupe Ward', 'WARD-0035'),
-    ('42781969-210c-485c-b5ea-5cca15ffc06f', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'River Ward', 'WARD-0036'),
-    ('dc35d73f-95d8-4835-b03a-b584081f36be', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'West Ward', 'WARD-0037'),
-    ('89d09268-e68c-412f-8181-c8b5d06b7b33', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'Surulere Northwest', 'WARD-0038'),
-    ('b1c988df-fae9-4732-8e75-341cd510babb', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'South Ward', 'WARD-0039'),
-    ('a3da59c0-d1de-4c9b-9eb0-dfd48b94c221', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'Ward VIII', 'WARD-0040'),
-    ('41fc08b7-700c-4671-a43f-21a9b89f5b8d', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Northeast Ward', 'WARD-0041'),
-    ('d233af47-a8f6-4f25-a6e2-9e6eeea15d0f', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Hill Ward', 'WARD-0042'),
-    ('ab98a847-edaf-4cc2-8fac-7889928fb1cb', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Market Ward', 'WARD-0043'),
-    ('f826d4db-8ff4-402f-9a8e-c705facd1fae', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Adamu Ward', 'WARD-0044'),
-    ('8900096d-0571-488c-bb7a-fec688d0a672', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Ward V', 'WARD-0045'),
-    ('d52d5f9e-53d5-4307-b1a9-d74a453b84e0', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Kano Municipal Southwest', 'WARD-0046'),
-    ('d0e79607-5990-4014-9c4f-74399835badd', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Rural Ward', 'WARD-0047'),
-    ('779da0f4-b42f-458a-8a40-97c400397590', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Market Ward', 'WARD-0048'),
-    ('a79e8ff9-fd66-4ab4-bd84-489e32cd7e44', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Central Ward', 'WARD-0049'),
-    ('741abfaa-a44a-4ec7-b130-71c9a5330af7', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Residential Ward', 'WARD-0050'),
-    ('4395ceff-79e9-4dc5-a17b-19a089b865de', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'South Ward', 'WARD-0051'),
-    ('97ee2f81-8e5a-48a4-8568-34e8382b5400', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Ward II', 'WARD-0052'),
-    ('38c4eb24-224d-4af1-a19d-c5f22885ff6f', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Ward III', 'WARD-0053'),
-    ('914c6ad5-2dd4-4246-a9bf-c128e5a9943b', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Kano Municipal North', 'WARD-0054'),
-    ('9bb55e66-b579-4ba1-a760-68a7ea0da2a2', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Kano Municipal Southeast', 'WARD-0055'),
-    ('fac564c9-c80c-42f2-9815-4b8adc4116fb', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Village Ward', 'WARD-0056'),
-    ('46de6575-1a62-4b31-b3b5-ede16d0470f5', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'North Ward', 'WARD-0057'),
-    ('4e04b0e4-ea72-46c8-935a-148d4c36d848', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Adunni Ward', 'WARD-0058'),
-    ('72874e10-a6a0-4122-b138-31aaa616869a', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Kaduna North Southeast', 'WARD-0059'),
-    ('8aaa7a15-33e8-4d78-b728-83939eaa541f', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Residential Ward', 'WARD-0060'),
-    ('7022cd5c-e780-4eb0-9b88-2cb9150d6dee', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Hill Ward', 'WARD-0061'),
-    ('5aefdce7-8547-44ea-a772-fe3b57c21a37', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Hill Ward', 'WARD-0062'),
-    ('8e36bb26-7569-40e6-96af-d777eb49da63', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'East Ward', 'WARD-0063'),
-    ('0b00a8b2-a269-4c9b-aa23-608554747f61', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Mamman Ward', 'WARD-0064'),
-    ('3676b1fd-d16b-4e53-b972-2bbd5658554b', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Southwest Ward', 'WARD-0065'),
-    ('ae47ada1-7ab3-4771-9f17-8782a4fb9e13', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Ward II', 'WARD-0066'),
-    ('7d119047-9846-41c1-85d3-fd743e0508f1', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'East Ward', 'WARD-0067'),
-    ('82a78444-5fd9-445f-8c44-2ff34c02a465', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Southeast Ward', 'WARD-0068'),
-    ('13ef20b7-17f1-476e-93ae-ccd350116214', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'West Ward', 'WARD-0069'),
-    ('e90d11a7-8aac-497d-9b28-04ab7fbfe391', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), '6th Ward', 'WARD-0070'),
-    ('01be8098-8133-471e-a008-853cb71940fb', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Rural Ward', 'WARD-0071'),
-    ('2373215d-e569-41c4-86fb-920d276547a2', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Port Harcourt North', 'WARD-0072'),
-    ('a9da5b09-2794-4e7e-ba3b-c7da968b5c92', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Village Ward', 'WARD-0073'),
-    ('68de9394-2de5-4d33-b114-26778e83e951', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Market Ward', 'WARD-0074'),
-    ('e9f74dc5-ae62-47f8-8f81-b2ab235b28db', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Port Harcourt West', 'WARD-0075'),
-    ('0b839167-f2f7-4130-a921-61a0abbbf4af', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'Northeast Ward', 'WARD-0076'),
-    ('2ea78d0c-5c08-4063-b609-673fd054dd1a', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'Market Ward', 'WARD-0077'),
-    ('7ce4ba97-8e30-47a5-8273-03e26b67823e', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), '3rd Ward', 'WARD-0078'),
-    ('80a3432b-8710-43ac-9b41-7d87cfbb81ad', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), '4th Ward', 'WARD-0079'),
-    ('50c1788c-3ccf-4642-9d72-96319d0f45c9', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'Urban Ward', 'WARD-0080'),
-    ('814d480f-ddd2-49ee-81e3-cd5438671b27', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), '6th Ward', 'WARD-0081'),
-    ('acaf1571-b438-4378-bde9-1c53dbbaf102', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'Ward VII', 'WARD-0082'),
-    ('5ae6d3cd-cb5f-4708-9123-44a1bd89a6ab', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'North Ward', 'WARD-0083'),
-    ('b8548a2e-870e-49fa-9fd1-2f85c48de29c', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'North Ward', 'WARD-0084'),
-    ('8e3eb320-ce49-432f-a000-cb99f329df74', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'Ward X', 'WARD-0085'),
-    ('26e82634-b794-4be6-bacc-a5347907eea1', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'Ibadan North Southwest', 'WARD-0086'),
-    ('ca767295-e71f-416c-bac1-0ff6cfc54c40', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), '2nd Ward', 'WARD-0087'),
-    ('e0f91de8-03de-4e44-a8cd-b6db40a1411c', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Dele Ward', 'WARD-0088'),
-    ('5fd3b2cb-c95b-40f7-90f2-7aa80582ad90', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Town Ward', 'WARD-0089'),
-    ('ddb58371-7aac-4b5c-aadd-4a09581c3889', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Ward III', 'WARD-0090'),
-    ('355faa02-306f-495b-978f-6180f55b4500', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Jos North Central', 'WARD-0091'),
-    ('6f053f78-20ac-404a-8822-0b23f789682e', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Jos North Southeast', 'WARD-0092'),
-    ('16fa351b-fd00-4dac-9199-30421f252b49', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Market Ward', 'WARD-0093'),
-    ('2ccbde83-259f-4601-ad71-41990dead28d', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Ward VII', 'WARD-0094'),
-    ('cd4c9601-3439-40ae-90e7-530a4ea9fe1a', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Residential Ward', 'WARD-0095'),
-    ('34570ab1-ae1e-4b62-8068-8d4a0b8a393c', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Maiduguri East', 'WARD-0096'),
-    ('cc03c042-3464-4eb6-977c-43fd0222a5ed', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Ward II', 'WARD-0097'),
-    ('6965dc87-7ccb-4465-b280-86ee6f540fae', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Southwest Ward', 'WARD-0098'),
-    ('475e9d26-5093-4a57-9508-05ece031abbc', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), '4th Ward', 'WARD-0099'),
-    ('6df28d0d-0afc-492a-9ae3-3e061e6925c4', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Maiduguri Northwest', 'WARD-0100'),
-    ('3714f2f8-ce3f-4183-ac66-3e8aabaf329f', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Ward VI', 'WARD-0101'),
-    ('90ae2a2c-0c71-48d5-9174-c98694c2e156', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Ward VII', 'WARD-0102'),
-    ('2c7b701a-f9f9-46da-bd82-bb71e78c5a48', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Hill Ward', 'WARD-0103'),
-    ('92168ee7-02f7-403d-b211-b944517318c7', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Maiduguri Northeast', 'WARD-0104');
+    ('043cfb91-c36d-4f49-862b-494f776eedac', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Ward I', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W01'),
+    ('1a0f864f-c69e-441f-bf29-ac679ec08bb3', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), '2nd Ward', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W02'),
+    ('26f2fe54-77c7-4663-b805-ccb43b13b7dc', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Abuja Municipal Northwest', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W03'),
+    ('3c5d31ff-b84c-40b1-9dff-81140f6551b0', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Market Ward', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W04'),
+    ('3b1bf8ec-1b80-43f5-9504-b96c19d49e20', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'North Ward', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W05'),
+    ('05a84804-5007-46dc-a0f1-77ec869f697f', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Ward VI', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W06'),
+    ('0e88fc48-fb4a-427a-add8-7550d4801140', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Ward VII', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W07'),
+    ('89e4841a-6dfe-486d-887b-5bc6e422844f', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), '8th Ward', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W08'),
+    ('4aca9c2c-a6e5-434d-825c-0103e4cd18e8', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Ward IX', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W09'),
+    ('4244c75e-a1d6-438b-a8de-808abc49024e', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Ward X', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W10'),
+    ('99931664-c24f-454f-bed3-37dcd05c60aa', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), '1st Ward', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W11'),
+    ('cc922376-1699-4222-866e-7a5edd3b7e5b', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), '2nd Ward', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W12'),
+    ('dbf52b38-8c7c-4206-80c3-c1ca0420409f', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Abuja Municipal South', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W13'),
+    ('dc7d7623-d2cc-4b7a-9979-824066857368', (SELECT id FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1), 'Abuja Municipal West', (SELECT code FROM lgas WHERE name LIKE '%Abuja Municipal%' LIMIT 1) || '-W14'),
+    ('bad891f3-925d-4eb1-ba05-354e30818d9e', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Musa Ward', (SELECT code FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1) || '-W01'),
+    ('320fbf28-78ee-4773-92bb-756fd492875c', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), '2nd Ward', (SELECT code FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1) || '-W02'),
+    ('d9112f99-88d5-429d-a97a-75dd8cff28d1', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), '3rd Ward', (SELECT code FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1) || '-W03'),
+    ('7be9e5d8-99b6-4180-a1b2-128636e798b3', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Babatunde Ward', (SELECT code FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1) || '-W04'),
+    ('4b3b7f75-43ca-4ad9-a8b8-244bf60cb82a', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'South Ward', (SELECT code FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1) || '-W05'),
+    ('a2ad5545-148c-4724-9d77-97caeb1b31d4', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Village Ward', (SELECT code FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1) || '-W06'),
+    ('322b4e7e-5ea1-479d-951e-9806614968e8', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Ibrahim Ward', (SELECT code FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1) || '-W07'),
+    ('fb7206f7-cdfb-4805-a5c7-a1d75de54365', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Village Ward', (SELECT code FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1) || '-W08'),
+    ('76296b45-054d-4eef-94cd-c265455f3eeb', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Seun Ward', (SELECT code FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1) || '-W09'),
+    ('3ff0d893-df01-4583-8bac-29eee0721ee9', (SELECT id FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1), 'Northwest Ward', (SELECT code FROM lgas WHERE name LIKE '%Ikeja%' LIMIT 1) || '-W10'),
+    ('ac4f48f9-7b31-48d2-b148-f146f8c66328', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Ward I', (SELECT code FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1) || '-W01'),
+    ('fb03baa9-0823-485f-a41a-fdfef3751653', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Ward II', (SELECT code FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1) || '-W02'),
+    ('566b8f7d-20c6-4936-9c8f-cd49ae84dfbf', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Northwest Ward', (SELECT code FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1) || '-W03'),
+    ('1ab8e855-27cc-40a5-ac3d-a0701b1b6779', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Commercial Ward', (SELECT code FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1) || '-W04'),
+    ('566b7fdf-8255-4a4a-85ac-3050153db681', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Adewale Ward', (SELECT code FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1) || '-W05'),
+    ('fc44b150-e034-4ca5-a538-044d3086b762', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Northeast Ward', (SELECT code FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1) || '-W06'),
+    ('ee14abc7-a4aa-4151-8e71-01f87a056934', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'Ward VII', (SELECT code FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1) || '-W07'),
+    ('48d1ed8f-2969-48c0-977a-d06112eef0c1', (SELECT id FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1), 'River Ward', (SELECT code FROM lgas WHERE name LIKE '%Victoria Island%' LIMIT 1) || '-W08'),
+    ('d2e89cdf-09f6-43d1-a48f-c0b62d574a28', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'West Ward', (SELECT code FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1) || '-W01'),
+    ('7faeb2a6-267c-4bd5-acbb-132a1a105301', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'Southeast Ward', (SELECT code FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1) || '-W02'),
+    ('efb920af-0309-45db-a50a-17ef0a9bbb8b', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'Modupe Ward', (SELECT code FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1) || '-W03'),
+    ('42781969-210c-485c-b5ea-5cca15ffc06f', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'River Ward', (SELECT code FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1) || '-W04'),
+    ('dc35d73f-95d8-4835-b03a-b584081f36be', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'West Ward', (SELECT code FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1) || '-W05'),
+    ('89d09268-e68c-412f-8181-c8b5d06b7b33', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'Surulere Northwest', (SELECT code FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1) || '-W06'),
+    ('b1c988df-fae9-4732-8e75-341cd510babb', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'South Ward', (SELECT code FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1) || '-W07'),
+    ('a3da59c0-d1de-4c9b-9eb0-dfd48b94c221', (SELECT id FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1), 'Ward VIII', (SELECT code FROM lgas WHERE name LIKE '%Surulere%' LIMIT 1) || '-W08'),
+    ('41fc08b7-700c-4671-a43f-21a9b89f5b8d', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Northeast Ward', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W01'),
+    ('d233af47-a8f6-4f25-a6e2-9e6eeea15d0f', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Hill Ward', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W02'),
+    ('ab98a847-edaf-4cc2-8fac-7889928fb1cb', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Market Ward', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W03'),
+    ('f826d4db-8ff4-402f-9a8e-c705facd1fae', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Adamu Ward', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W04'),
+    ('8900096d-0571-488c-bb7a-fec688d0a672', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Ward V', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W05'),
+    ('d52d5f9e-53d5-4307-b1a9-d74a453b84e0', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Kano Municipal Southwest', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W06'),
+    ('d0e79607-5990-4014-9c4f-74399835badd', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Rural Ward', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W07'),
+    ('779da0f4-b42f-458a-8a40-97c400397590', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Market Ward', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W08'),
+    ('a79e8ff9-fd66-4ab4-bd84-489e32cd7e44', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Central Ward', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W09'),
+    ('741abfaa-a44a-4ec7-b130-71c9a5330af7', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Residential Ward', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W10'),
+    ('4395ceff-79e9-4dc5-a17b-19a089b865de', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'South Ward', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W11'),
+    ('97ee2f81-8e5a-48a4-8568-34e8382b5400', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Ward II', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W12'),
+    ('38c4eb24-224d-4af1-a19d-c5f22885ff6f', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Ward III', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W13'),
+    ('914c6ad5-2dd4-4246-a9bf-c128e5a9943b', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Kano Municipal North', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W14'),
+    ('9bb55e66-b579-4ba1-a760-68a7ea0da2a2', (SELECT id FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1), 'Kano Municipal Southeast', (SELECT code FROM lgas WHERE name LIKE '%Kano Municipal%' LIMIT 1) || '-W15'),
+    ('fac564c9-c80c-42f2-9815-4b8adc4116fb', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Village Ward', (SELECT code FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1) || '-W01'),
+    ('46de6575-1a62-4b31-b3b5-ede16d0470f5', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'North Ward', (SELECT code FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1) || '-W02'),
+    ('4e04b0e4-ea72-46c8-935a-148d4c36d848', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Adunni Ward', (SELECT code FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1) || '-W03'),
+    ('72874e10-a6a0-4122-b138-31aaa616869a', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Kaduna North Southeast', (SELECT code FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1) || '-W04'),
+    ('8aaa7a15-33e8-4d78-b728-83939eaa541f', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Residential Ward', (SELECT code FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1) || '-W05'),
+    ('7022cd5c-e780-4eb0-9b88-2cb9150d6dee', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Hill Ward', (SELECT code FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1) || '-W06'),
+    ('5aefdce7-8547-44ea-a772-fe3b57c21a37', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Hill Ward', (SELECT code FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1) || '-W07'),
+    ('8e36bb26-7569-40e6-96af-d777eb49da63', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'East Ward', (SELECT code FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1) || '-W08'),
+    ('0b00a8b2-a269-4c9b-aa23-608554747f61', (SELECT id FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1), 'Mamman Ward', (SELECT code FROM lgas WHERE name LIKE '%Kaduna North%' LIMIT 1) || '-W09'),
+    ('3676b1fd-d16b-4e53-b972-2bbd5658554b', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Southwest Ward', (SELECT code FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1) || '-W01'),
+    ('ae47ada1-7ab3-4771-9f17-8782a4fb9e13', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Ward II', (SELECT code FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1) || '-W02'),
+    ('7d119047-9846-41c1-85d3-fd743e0508f1', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'East Ward', (SELECT code FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1) || '-W03'),
+    ('82a78444-5fd9-445f-8c44-2ff34c02a465', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Southeast Ward', (SELECT code FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1) || '-W04'),
+    ('13ef20b7-17f1-476e-93ae-ccd350116214', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'West Ward', (SELECT code FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1) || '-W05'),
+    ('e90d11a7-8aac-497d-9b28-04ab7fbfe391', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), '6th Ward', (SELECT code FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1) || '-W06'),
+    ('01be8098-8133-471e-a008-853cb71940fb', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Rural Ward', (SELECT code FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1) || '-W07'),
+    ('2373215d-e569-41c4-86fb-920d276547a2', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Port Harcourt North', (SELECT code FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1) || '-W08'),
+    ('a9da5b09-2794-4e7e-ba3b-c7da968b5c92', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Village Ward', (SELECT code FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1) || '-W09'),
+    ('68de9394-2de5-4d33-b114-26778e83e951', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Market Ward', (SELECT code FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1) || '-W10'),
+    ('e9f74dc5-ae62-47f8-8f81-b2ab235b28db', (SELECT id FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1), 'Port Harcourt West', (SELECT code FROM lgas WHERE name LIKE '%Port Harcourt%' LIMIT 1) || '-W11'),
+    ('0b839167-f2f7-4130-a921-61a0abbbf4af', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'Northeast Ward', (SELECT code FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1) || '-W01'),
+    ('2ea78d0c-5c08-4063-b609-673fd054dd1a', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'Market Ward', (SELECT code FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1) || '-W02'),
+    ('7ce4ba97-8e30-47a5-8273-03e26b67823e', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), '3rd Ward', (SELECT code FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1) || '-W03'),
+    ('80a3432b-8710-43ac-9b41-7d87cfbb81ad', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), '4th Ward', (SELECT code FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1) || '-W04'),
+    ('50c1788c-3ccf-4642-9d72-96319d0f45c9', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'Urban Ward', (SELECT code FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1) || '-W05'),
+    ('814d480f-ddd2-49ee-81e3-cd5438671b27', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), '6th Ward', (SELECT code FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1) || '-W06'),
+    ('acaf1571-b438-4378-bde9-1c53dbbaf102', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'Ward VII', (SELECT code FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1) || '-W07'),
+    ('5ae6d3cd-cb5f-4708-9123-44a1bd89a6ab', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'North Ward', (SELECT code FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1) || '-W08'),
+    ('b8548a2e-870e-49fa-9fd1-2f85c48de29c', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'North Ward', (SELECT code FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1) || '-W09'),
+    ('8e3eb320-ce49-432f-a000-cb99f329df74', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'Ward X', (SELECT code FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1) || '-W10'),
+    ('26e82634-b794-4be6-bacc-a5347907eea1', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), 'Ibadan North Southwest', (SELECT code FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1) || '-W11'),
+    ('ca767295-e71f-416c-bac1-0ff6cfc54c40', (SELECT id FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1), '2nd Ward', (SELECT code FROM lgas WHERE name LIKE '%Ibadan North%' LIMIT 1) || '-W12'),
+    ('e0f91de8-03de-4e44-a8cd-b6db40a1411c', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Dele Ward', (SELECT code FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1) || '-W01'),
+    ('5fd3b2cb-c95b-40f7-90f2-7aa80582ad90', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Town Ward', (SELECT code FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1) || '-W02'),
+    ('ddb58371-7aac-4b5c-aadd-4a09581c3889', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Ward III', (SELECT code FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1) || '-W03'),
+    ('355faa02-306f-495b-978f-6180f55b4500', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Jos North Central', (SELECT code FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1) || '-W04'),
+    ('6f053f78-20ac-404a-8822-0b23f789682e', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Jos North Southeast', (SELECT code FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1) || '-W05'),
+    ('16fa351b-fd00-4dac-9199-30421f252b49', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Market Ward', (SELECT code FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1) || '-W06'),
+    ('2ccbde83-259f-4601-ad71-41990dead28d', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Ward VII', (SELECT code FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1) || '-W07'),
+    ('cd4c9601-3439-40ae-90e7-530a4ea9fe1a', (SELECT id FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1), 'Residential Ward', (SELECT code FROM lgas WHERE name LIKE '%Jos North%' LIMIT 1) || '-W08'),
+    ('34570ab1-ae1e-4b62-8068-8d4a0b8a393c', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Maiduguri East', (SELECT code FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1) || '-W01'),
+    ('cc03c042-3464-4eb6-977c-43fd0222a5ed', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Ward II', (SELECT code FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1) || '-W02'),
+    ('6965dc87-7ccb-4465-b280-86ee6f540fae', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Southwest Ward', (SELECT code FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1) || '-W03'),
+    ('475e9d26-5093-4a57-9508-05ece031abbc', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), '4th Ward', (SELECT code FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1) || '-W04'),
+    ('6df28d0d-0afc-492a-9ae3-3e061e6925c4', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Maiduguri Northwest', (SELECT code FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1) || '-W05'),
+    ('3714f2f8-ce3f-4183-ac66-3e8aabaf329f', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Ward VI', (SELECT code FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1) || '-W06'),
+    ('90ae2a2c-0c71-48d5-9174-c98694c2e156', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Ward VII', (SELECT code FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1) || '-W07'),
+    ('2c7b701a-f9f9-46da-bd82-bb71e78c5a48', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Hill Ward', (SELECT code FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1) || '-W08'),
+    ('92168ee7-02f7-403d-b211-b944517318c7', (SELECT id FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1), 'Maiduguri Northeast', (SELECT code FROM lgas WHERE name LIKE '%Maiduguri%' LIMIT 1) || '-W09');
 
 -- Sample dataset contains 104 wards
 -- Nigeria has approximately 8,809 wards nationwide
@@ -1,13 +1,13 @@
-use axum::{
-    extract::{Query, State},
-    Json,
-};
+use axum::{extract::State, Json};
 use chrono::{DateTime, Utc, Duration};
 use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
 
-use crate::domain::entities::api_usage::{UsageStats, EndpointStats, HourlyStats, StatusCodeStats};
+use crate::application::dtos::{PaginationMeta, PaginationParams};
+use crate::domain::entities::api_usage::{ApiUsage, UsageStats, EndpointStats, HourlyStats, StatusCodeStats};
 use crate::domain::repositories::api_usage_repository::ApiUsageRepository;
-use crate::errors::AppResult;
+use crate::errors::{AppError, AppResult};
+use crate::presentation::extractors::ValidQuery;
 use crate::presentation::state::AppState;
 
 #[derive(Debug, Deserialize)]
@@ -44,6 +44,14 @@ pub struct ApiUsageResponse<T> {
     pub period: UsagePeriod,
 }
 
+/// One page of usage records
+#[derive(Debug, Serialize)]
+pub struct UsageRecordsResponse {
+    pub data: Vec<ApiUsage>,
+    pub period: UsagePeriod,
+    pub pagination: PaginationMeta,
+}
+
 #[derive(Debug, Serialize)]
 pub struct UsagePeriod {
     pub start_date: DateTime<Utc>,
@@ -53,7 +61,7 @@ pub struct UsagePeriod {
 /// Get general usage statistics
 pub async fn get_usage_stats_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<UsageStatsQuery>,
+    ValidQuery(params): ValidQuery<UsageStatsQuery>,
 ) -> AppResult<Json<ApiUsageResponse<UsageStats>>> {
     let end_date = params.end_date.unwrap_or_else(Utc::now);
     let start_date = params.start_date.unwrap_or_else(|| {
@@ -75,7 +83,7 @@ pub async fn get_usage_stats_handler(
 /// Get top endpoints by request count
 pub async fn get_top_endpoints_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<TopEndpointsQuery>,
+    ValidQuery(params): ValidQuery<TopEndpointsQuery>,
 ) -> AppResult<Json<ApiUsageResponse<Vec<EndpointStats>>>> {
     let end_date = params.end_date.unwrap_or_else(Utc::now);
     let start_date = params.start_date.unwrap_or_else(|| end_date - Duration::hours(24));
@@ -95,7 +103,7 @@ pub async fn get_top_endpoints_handler(
 /// Get hourly usage statistics
 pub async fn get_hourly_stats_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<UsageStatsQuery>,
+    ValidQuery(params): ValidQuery<UsageStatsQuery>,
 ) -> AppResult<Json<ApiUsageResponse<Vec<HourlyStats>>>> {
     let end_date = params.end_date.unwrap_or_else(Utc::now);
     let start_date = params.start_date.unwrap_or_else(|| {
@@ -117,7 +125,7 @@ pub async fn get_hourly_stats_handler(
 /// Get status code distribution
 pub async fn get_status_code_stats_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<UsageStatsQuery>,
+    ValidQuery(params): ValidQuery<UsageStatsQuery>,
 ) -> AppResult<Json<ApiUsageResponse<Vec<StatusCodeStats>>>> {
     let end_date = params.end_date.unwrap_or_else(Utc::now);
     let start_date = params.start_date.unwrap_or_else(|| {
@@ -136,40 +144,55 @@ pub async fn get_status_code_stats_handler(
     }))
 }
 
-/// Get usage by IP address
+/// Get usage by IP address, newest first
 pub async fn get_usage_by_ip_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<IpUsageQuery>,
-) -> AppResult<Json<ApiUsageResponse<Vec<crate::domain::entities::api_usage::ApiUsage>>>> {
+    ValidQuery(params): ValidQuery<IpUsageQuery>,
+    ValidQuery(pagination): ValidQuery<PaginationParams>,
+) -> AppResult<Json<UsageRecordsResponse>> {
     let end_date = params.end_date.unwrap_or_else(Utc::now);
     let start_date = params.start_date.unwrap_or_else(|| end_date - Duration::hours(24));
-
-    let usage = app_state
-        .api_usage_repository
-        .get_usage_by_ip(&params.ip_address, start_date, end_date)
-        .await?;
-
-    Ok(Json(ApiUsageResponse {
-        data: usage,
+    let request = pagination.page_request()?;
+
+    if params.ip_address.parse::<IpAddr>().is_err() {
+        return Err(AppError::BadRequest(format!(
+            "'{}' is not an IP address",
+            params.ip_address
+        )));
+    }
+
+    let repository = &app_state.api_usage_repository;
+    let (usage, total) = tokio::try_join!(
+        repository.get_usage_by_ip(&params.ip_address, start_date, end_date, &request),
+        repository.count_usage_by_ip(&params.ip_address, start_date, end_date)
+    )?;
+
+    Ok(Json(UsageRecordsResponse {
+        pagination: PaginationMeta::for_page(&usage, &request, total),
+        data: usage.items,
         period: UsagePeriod { start_date, end_date },
     }))
 }
 
-/// Get usage by API key
+/// Get usage by API key, newest first
 pub async fn get_usage_by_api_key_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<ApiKeyUsageQuery>,
-) -> AppResult<Json<ApiUsageResponse<Vec<crate::domain::entities::api_usage::ApiUsage>>>> {
+    ValidQuery(params): ValidQuery<ApiKeyUsageQuery>,
+    ValidQuery(pagination): ValidQuery<PaginationParams>,
+) -> AppResult<Json<UsageRecordsResponse>> {
     let end_date = params.end_date.unwrap_or_else(Utc::now);
     let start_date = params.start_date.unwrap_or_else(|| end_date - Duration::hours(24));
+    let request = pagination.page_request()?;
 
-    let usage = app_state
-        .api_usage_repository
-        .get_usage_by_api_key(&params.api_key, start_date, end_date)
-        .await?;
+    let repository = &app_state.api_usage_repository;
+    let (usage, total) = tokio::try_join!(
+        repository.get_usage_by_api_key(&params.api_key, start_date, end_date, &request),
+        repository.count_usage_by_api_key(&params.api_key, start_date, end_date)
+    )?;
 
-    Ok(Json(ApiUsageResponse {
-        data: usage,
+    Ok(Json(UsageRecordsResponse {
+        pagination: PaginationMeta::for_page(&usage, &request, total),
+        data: usage.items,
         period: UsagePeriod { start_date, end_date },
     }))
 }
@@ -194,7 +217,7 @@ pub struct CleanupQuery {
 
 pub async fn cleanup_old_records_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<CleanupQuery>,
+    ValidQuery(params): ValidQuery<CleanupQuery>,
 ) -> AppResult<Json<serde_json::Value>> {
     let days_to_keep = params.days_to_keep.unwrap_or(90); // Default: keep 90 days
     let cutoff_date = Utc::now() - Duration::days(days_to_keep);
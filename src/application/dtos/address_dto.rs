@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use uuid::Uuid;
 
-use crate::domain::entities::address::{Address, AddressValidationRequest, AddressValidationResponse, AddressSuggestion};
-use crate::application::dtos::{StateDto, LgaDto, WardDto, PostalCodeDto};
+use crate::application::dtos::{LgaDto, PostalCodeDto, StateDto, WardDto};
+use crate::domain::entities::address::{
+    Address, AddressSuggestion, AddressValidationRequest, AddressValidationResponse,
+    ComponentMatch, DeprecatedComponent, SimilarAddress,
+};
+use crate::domain::entities::search::MatchMode;
 
 /// Address DTO for API responses
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -28,8 +33,28 @@ impl From<Address> for AddressDto {
     }
 }
 
+/// Similar address DTO: an address plus its relevance score
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SimilarAddressDto {
+    /// The matched address
+    #[serde(flatten)]
+    pub address: AddressDto,
+    /// Relevance score (0.0 to 1.0), higher is closer
+    #[schema(example = 0.92, minimum = 0.0, maximum = 1.0)]
+    pub score: f64,
+}
+
+impl From<SimilarAddress> for SimilarAddressDto {
+    fn from(similar: SimilarAddress) -> Self {
+        Self {
+            address: similar.address.into(),
+            score: similar.score,
+        }
+    }
+}
+
 /// Address validation request DTO
-#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct AddressValidationRequestDto {
     /// State name
     #[schema(example = "Lagos")]
@@ -52,6 +77,7 @@ impl From<AddressValidationRequestDto> for AddressValidationRequest {
             lga: dto.lga,
             ward: dto.ward,
             postal_code: dto.postal_code,
+            match_mode: MatchMode::default(),
         }
     }
 }
@@ -64,15 +90,81 @@ pub struct AddressValidationResponseDto {
     pub valid: bool,
     /// Canonical address if valid
     pub canonical: Option<AddressDto>,
+    /// How each state, LGA and ward that was found matched its input
+    pub matches: Vec<ComponentMatchDto>,
+    /// Components of the canonical address that have been retired; the
+    /// address is still valid
+    pub deprecated: Vec<DeprecatedComponentDto>,
     /// Suggested corrections if invalid
     pub suggestions: Vec<AddressSuggestionDto>,
 }
 
+/// How one address component was matched
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ComponentMatchDto {
+    /// Component: `state`, `lga` or `ward`
+    #[schema(example = "state")]
+    pub component: String,
+    /// Name as given in the request
+    #[schema(example = "Ọ̀yọ́ State")]
+    pub input: String,
+    /// Name or alias the input matched
+    #[schema(example = "Oyo")]
+    pub matched_name: String,
+    /// Normalization rules the input needed to match: `case`, `diacritics`,
+    /// `punctuation` or `stopwords`
+    #[schema(example = json!(["diacritics", "stopwords"]))]
+    pub rules: Vec<String>,
+}
+
+impl From<ComponentMatch> for ComponentMatchDto {
+    fn from(component_match: ComponentMatch) -> Self {
+        Self {
+            component: component_match.component.to_string(),
+            input: component_match.input,
+            matched_name: component_match.matched_name,
+            rules: component_match
+                .rules
+                .iter()
+                .map(|rule| rule.as_str().to_string())
+                .collect(),
+        }
+    }
+}
+
+/// An address component that is no longer active
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeprecatedComponentDto {
+    /// Component: `state`, `lga`, `ward` or `postal_code`
+    #[schema(example = "ward")]
+    pub component: String,
+    /// ID of the retired unit
+    pub id: Uuid,
+    /// `deprecated` or `merged`
+    #[schema(example = "merged")]
+    pub status: String,
+    /// The unit to use instead, if one was named
+    pub successor_id: Option<Uuid>,
+}
+
+impl From<DeprecatedComponent> for DeprecatedComponentDto {
+    fn from(component: DeprecatedComponent) -> Self {
+        Self {
+            component: component.component.to_string(),
+            id: component.id,
+            status: component.status.as_str().to_string(),
+            successor_id: component.successor_id,
+        }
+    }
+}
+
 impl From<AddressValidationResponse> for AddressValidationResponseDto {
     fn from(response: AddressValidationResponse) -> Self {
         Self {
             valid: response.valid,
             canonical: response.canonical.map(|addr| addr.into()),
+            matches: response.matches.into_iter().map(|m| m.into()).collect(),
+            deprecated: response.deprecated.into_iter().map(|d| d.into()).collect(),
             suggestions: response.suggestions.into_iter().map(|s| s.into()).collect(),
         }
     }
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::alias::Alias;
+
+/// Alias DTO for API responses
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AliasDto {
+    /// Unique identifier for the alias
+    #[schema(example = "850e8400-e29b-41d4-a716-446655440001")]
+    pub id: Uuid,
+    /// Kind of entity the alias names: `state`, `lga` or `ward`
+    #[schema(example = "lga")]
+    pub entity_type: String,
+    /// ID of the state, LGA or ward
+    #[schema(example = "650e8400-e29b-41d4-a716-446655440001")]
+    pub entity_id: Uuid,
+    /// The alternative name
+    #[schema(example = "PH")]
+    pub name: String,
+    /// `alternate`, `abbreviation`, `historical` or `local`
+    #[schema(example = "abbreviation")]
+    pub alias_type: String,
+    /// ISO 639 language code
+    #[schema(example = "en")]
+    pub language: Option<String>,
+    /// Creation timestamp
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Last update timestamp
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<Alias> for AliasDto {
+    fn from(alias: Alias) -> Self {
+        Self {
+            id: alias.id,
+            entity_type: alias.entity_type.as_str().to_string(),
+            entity_id: alias.entity_id,
+            name: alias.name,
+            alias_type: alias.alias_type.as_str().to_string(),
+            language: alias.language,
+            created_at: alias.created_at,
+            updated_at: alias.updated_at,
+        }
+    }
+}
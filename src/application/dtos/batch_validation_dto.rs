@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::dtos::address_dto::{
+    AddressDto, AddressSuggestionDto, AddressValidationRequestDto,
+};
+
+/// Result of validating a single row of a batch
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchValidationItemDto {
+    /// 1-based position of the row in the submitted batch
+    #[schema(example = 1)]
+    pub row: usize,
+    /// The address as submitted, if the row could be parsed
+    pub input: Option<AddressValidationRequestDto>,
+    /// Whether the address is valid
+    #[schema(example = true)]
+    pub valid: bool,
+    /// Canonical address if valid
+    pub canonical: Option<AddressDto>,
+    /// Suggested corrections if invalid
+    pub suggestions: Vec<AddressSuggestionDto>,
+    /// Why the row could not be validated (parse or lookup failure)
+    #[schema(example = "missing field `postal_code`")]
+    pub error: Option<String>,
+}
+
+/// Aggregate counts for a batch
+#[derive(Debug, Clone, Default, Serialize, ToSchema)]
+pub struct BatchValidationSummaryDto {
+    /// Number of rows submitted
+    #[schema(example = 3)]
+    pub total: usize,
+    /// Rows that matched a canonical address
+    #[schema(example = 2)]
+    pub valid: usize,
+    /// Rows that were validated but did not match
+    #[schema(example = 1)]
+    pub invalid: usize,
+    /// Rows that could not be validated at all
+    #[schema(example = 0)]
+    pub failed: usize,
+}
+
+impl BatchValidationSummaryDto {
+    /// Count a validated row
+    pub fn record(&mut self, item: &BatchValidationItemDto) {
+        if item.error.is_some() {
+            self.failed += 1;
+        } else if item.valid {
+            self.valid += 1;
+        } else {
+            self.invalid += 1;
+        }
+    }
+}
+
+/// Response for batches processed synchronously
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchValidationResponseDto {
+    /// Aggregate counts
+    pub summary: BatchValidationSummaryDto,
+    /// Per-row results in submission order
+    pub results: Vec<BatchValidationItemDto>,
+}
+
+/// Lifecycle of a background batch job
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchJobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// Status of a background batch validation job
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct BatchJobDto {
+    /// Job identifier
+    #[schema(example = "0f4c2a9e-8d1b-4c7a-9a51-6f2b1e3d4c5a")]
+    pub job_id: Uuid,
+    /// Current job status
+    pub status: BatchJobStatus,
+    /// Rows processed so far
+    #[schema(example = 1200)]
+    pub processed: usize,
+    /// Aggregate counts for the processed rows (`total` is the full batch size)
+    pub summary: BatchValidationSummaryDto,
+    /// Error that aborted the job, if any
+    pub error: Option<String>,
+    /// When the job was submitted
+    pub created_at: DateTime<Utc>,
+    /// When the job finished
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Where to poll for status
+    #[schema(example = "/api/v1/validate/batch/0f4c2a9e-8d1b-4c7a-9a51-6f2b1e3d4c5a")]
+    pub status_url: String,
+    /// Where to download results once completed
+    #[schema(example = "/api/v1/validate/batch/0f4c2a9e-8d1b-4c7a-9a51-6f2b1e3d4c5a/results")]
+    pub results_url: String,
+}
+
+/// Query parameters for downloading batch results
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BatchResultsParams {
+    /// Output format: `json`, `ndjson` or `csv` (default `ndjson`)
+    #[schema(example = "csv")]
+    pub format: Option<String>,
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Units and aliases in an offline bundle
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BundleCountsDto {
+    pub states: u64,
+    pub lgas: u64,
+    pub wards: u64,
+    pub postal_codes: u64,
+    pub aliases: u64,
+}
+
+/// Description of the latest offline bundle, for clients deciding whether to
+/// download it
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BundleManifestDto {
+    /// Changes whenever the data does; the same data always has the same version
+    pub version: String,
+    /// Version of the bundle's SQLite schema, also its `user_version`
+    pub schema_version: u32,
+    pub created_at: DateTime<Utc>,
+    /// Size of the gzip-compressed bundle in bytes
+    pub size: u64,
+    /// SHA-256 of the gzip-compressed bundle, in hex
+    pub sha256: String,
+    /// Size of the SQLite database once decompressed
+    pub uncompressed_size: u64,
+    /// Token to pass as `since` to `/api/v1/sync` to bring the bundle up to
+    /// date; also in the bundle's `metadata` table
+    pub sync_token: String,
+    pub counts: BundleCountsDto,
+}
@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::dtos::history_dto::{ChangeCountsDto, EntityDiffDto};
+use crate::domain::entities::dataset_version::DatasetVersion;
+
+/// A named, immutable release of the dataset
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DatasetVersionDto {
+    /// Name to pin the release by with `X-Dataset-Version`
+    #[schema(example = "2024.1")]
+    pub name: String,
+    #[schema(example = "Ward boundaries from the 2023 delimitation")]
+    pub notes: Option<String>,
+    pub released_at: DateTime<Utc>,
+    #[schema(example = 37)]
+    pub states: u64,
+    #[schema(example = 774)]
+    pub lgas: u64,
+    #[schema(example = 8809)]
+    pub wards: u64,
+    #[schema(example = 8809)]
+    pub postal_codes: u64,
+    /// Entities created, updated and deleted since the previous release
+    pub changes: ChangeCountsDto,
+}
+
+impl DatasetVersionDto {
+    pub fn new(version: DatasetVersion, changes: ChangeCountsDto) -> Self {
+        Self {
+            name: version.name,
+            notes: version.notes,
+            released_at: version.released_at,
+            states: version.states,
+            lgas: version.lgas,
+            wards: version.wards,
+            postal_codes: version.postal_codes,
+            changes,
+        }
+    }
+}
+
+/// A release with its changelog
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DatasetVersionDetailDto {
+    #[serde(flatten)]
+    pub version: DatasetVersionDto,
+    /// Entities changed since the previous release, in the order they were
+    /// first changed; for the first release, everything recorded before it
+    pub entities: Vec<EntityDiffDto>,
+}
@@ -0,0 +1,65 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use crate::domain::entities::unit_level::UnitLevel;
+
+/// Formats the dataset is exported in
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+    Ndjson,
+    GeoJson,
+}
+
+impl ExportFormat {
+    /// Parse a format name such as `csv` or `geojson`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "json" => Some(Self::Json),
+            "ndjson" | "jsonl" => Some(Self::Ndjson),
+            "geojson" => Some(Self::GeoJson),
+            _ => None,
+        }
+    }
+
+    /// MIME type used when serving an export in this format
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Json => "application/json",
+            Self::Ndjson => "application/x-ndjson",
+            Self::GeoJson => "application/geo+json",
+        }
+    }
+
+    /// File extension used when serving an export in this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+            Self::Ndjson => "ndjson",
+            Self::GeoJson => "geojson",
+        }
+    }
+}
+
+/// Query parameters for exporting the dataset
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ExportParams {
+    /// `csv` (default), `json`, `ndjson` or `geojson`
+    #[serde(default)]
+    pub format: ExportFormat,
+    /// Units to export, one per row: `state`, `lga`, `ward` or `postal_code`
+    /// (default)
+    #[serde(default = "default_export_level")]
+    #[schema(value_type = String, example = "ward")]
+    pub level: UnitLevel,
+}
+
+fn default_export_level() -> UnitLevel {
+    UnitLevel::PostalCode
+}
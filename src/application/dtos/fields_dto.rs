@@ -0,0 +1,131 @@
+use std::sync::{Arc, OnceLock};
+
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
+use utoipa::openapi::{RefOr, Schema};
+use utoipa::ToSchema;
+
+use crate::application::dtos::{LgaDto, PostalCodeDto, StateDto, WardDto};
+use crate::errors::{AppError, AppResult};
+
+/// Sparse fieldset parameters
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct FieldsParams {
+    /// Comma-separated fields to return for each entity, e.g. `id,name`.
+    /// Any property of the entity's schema may be named:
+    /// states `id, name, code, status, successor_id, redirected_from,
+    /// created_at, updated_at`; LGAs and wards the same plus `state_id` or
+    /// `lga_id`; postal codes `id, ward_id, postal_code, lat, lng, urban,
+    /// status, successor_id, redirected_from, created_at, updated_at`.
+    /// All are returned when omitted.
+    #[schema(example = "id,name")]
+    pub fields: Option<String>,
+}
+
+/// Fields asked for with `?fields=`, checked against a DTO's schema
+///
+/// Applies to every entity in a response, including those nested by
+/// `include`; an entity keeps whichever of the fields it has. Nested entities
+/// themselves are always kept, since they were asked for explicitly, but
+/// object fields such as `redirected_from` only when named.
+#[derive(Debug, Clone, Default)]
+pub struct Fields(Option<Arc<[String]>>);
+
+impl Fields {
+    /// Parse the `fields` parameter of an endpoint returning `T` entities
+    pub fn parse<T: for<'s> ToSchema<'s>>(value: Option<&str>) -> AppResult<Self> {
+        let names: Vec<String> = value
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .collect();
+        if names.is_empty() {
+            return Ok(Fields(None));
+        }
+
+        let known = schema_properties::<T>();
+        if let Some(unknown) = names.iter().find(|name| !known.contains(name)) {
+            return Err(AppError::BadRequest(format!(
+                "Unknown field '{}'; expected {}",
+                unknown,
+                known.join(", ")
+            )));
+        }
+
+        Ok(Fields(Some(names.into())))
+    }
+
+    /// Wrap a response value so that it serializes with these fields only
+    pub fn apply<T>(&self, value: T) -> Sparse<T> {
+        Sparse {
+            value,
+            fields: self.clone(),
+        }
+    }
+
+    fn retain(names: &[String], value: &mut Value) {
+        match value {
+            Value::Object(object) => {
+                let fields = entity_fields();
+                object.retain(|key, _| names.contains(key) || !fields.contains(key));
+                // What remains unnamed are the entities nested by `include`
+                object
+                    .iter_mut()
+                    .filter(|(key, _)| !names.contains(key))
+                    .for_each(|(_, nested)| Self::retain(names, nested));
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| Self::retain(names, item)),
+            _ => {}
+        }
+    }
+}
+
+/// Response value trimmed to a [`Fields`] selection when serialized
+#[derive(Debug)]
+pub struct Sparse<T> {
+    value: T,
+    fields: Fields,
+}
+
+impl<T: Serialize> Serialize for Sparse<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Some(names) = &self.fields.0 else {
+            return self.value.serialize(serializer);
+        };
+
+        let mut value = serde_json::to_value(&self.value).map_err(serde::ser::Error::custom)?;
+        Fields::retain(names, &mut value);
+        value.serialize(serializer)
+    }
+}
+
+impl<'s, T: ToSchema<'s>> ToSchema<'s> for Sparse<T> {
+    fn schema() -> (&'s str, RefOr<Schema>) {
+        T::schema()
+    }
+}
+
+/// Property names of any entity DTO, as opposed to the relations `include`
+/// adds alongside them
+fn entity_fields() -> &'static [String] {
+    static FIELDS: OnceLock<Vec<String>> = OnceLock::new();
+    FIELDS.get_or_init(|| {
+        let mut fields = schema_properties::<StateDto>();
+        fields.extend(schema_properties::<LgaDto>());
+        fields.extend(schema_properties::<WardDto>());
+        fields.extend(schema_properties::<PostalCodeDto>());
+        fields.sort();
+        fields.dedup();
+        fields
+    })
+}
+
+/// Property names of a DTO's OpenAPI schema
+fn schema_properties<T: for<'s> ToSchema<'s>>() -> Vec<String> {
+    match T::schema().1 {
+        RefOr::T(Schema::Object(object)) => object.properties.into_keys().collect(),
+        _ => Vec::new(),
+    }
+}
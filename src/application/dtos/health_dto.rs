@@ -1,5 +1,5 @@
-use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 /// Health check response DTO
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,192 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::dtos::{LgaDto, PostalCodeDto, StateDto, WardDto};
+use crate::errors::{AppError, AppResult};
+
+/// Level of the administrative hierarchy, top down
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HierarchyLevel {
+    State,
+    Lga,
+    Ward,
+    PostalCode,
+}
+
+impl HierarchyLevel {
+    const ALL: [HierarchyLevel; 4] = [
+        HierarchyLevel::State,
+        HierarchyLevel::Lga,
+        HierarchyLevel::Ward,
+        HierarchyLevel::PostalCode,
+    ];
+
+    /// Name of one entity at this level, as used in `include`
+    fn singular(self) -> &'static str {
+        match self {
+            HierarchyLevel::State => "state",
+            HierarchyLevel::Lga => "lga",
+            HierarchyLevel::Ward => "ward",
+            HierarchyLevel::PostalCode => "postal_code",
+        }
+    }
+
+    /// Name of the entities at this level, as used in `include`
+    fn plural(self) -> &'static str {
+        match self {
+            HierarchyLevel::State => "states",
+            HierarchyLevel::Lga => "lgas",
+            HierarchyLevel::Ward => "wards",
+            HierarchyLevel::PostalCode => "postal_codes",
+        }
+    }
+
+    fn ancestors(self) -> impl Iterator<Item = HierarchyLevel> {
+        Self::ALL.into_iter().filter(move |level| *level < self)
+    }
+
+    fn descendants(self) -> impl Iterator<Item = HierarchyLevel> {
+        Self::ALL.into_iter().filter(move |level| *level > self)
+    }
+}
+
+/// Related entities requested with `?include=`
+///
+/// Descendants are named as a dotted path down the hierarchy, e.g.
+/// `lgas.wards.postal_codes` on a state; ancestors by level (`state`, `lga`,
+/// `ward`) or all at once with `ancestors`. Several can be given separated by
+/// commas.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Include {
+    /// Levels of descendants to nest: 1 for `lgas` on a state, 2 for `lgas.wards`
+    pub depth: usize,
+    pub state: bool,
+    pub lga: bool,
+    pub ward: bool,
+}
+
+impl Include {
+    /// Parse the `include` parameter of an endpoint returning a `level` entity
+    pub fn parse(value: Option<&str>, level: HierarchyLevel) -> AppResult<Self> {
+        let mut include = Include::default();
+
+        for name in value.unwrap_or_default().split(',').map(str::trim) {
+            if name.is_empty() {
+                continue;
+            }
+
+            if name == "ancestors" && level != HierarchyLevel::State {
+                level
+                    .ancestors()
+                    .for_each(|ancestor| include.add_ancestor(ancestor));
+                continue;
+            }
+
+            if let Some(ancestor) = level.ancestors().find(|a| a.singular() == name) {
+                include.add_ancestor(ancestor);
+                continue;
+            }
+
+            let path: Vec<&str> = name.split('.').collect();
+            let is_descendant_path = path.len() <= level.descendants().count()
+                && path
+                    .iter()
+                    .zip(level.descendants())
+                    .all(|(segment, descendant)| *segment == descendant.plural());
+            if !is_descendant_path {
+                return Err(AppError::BadRequest(format!(
+                    "Unknown include '{}'; expected {}",
+                    name,
+                    Self::options(level).join(", ")
+                )));
+            }
+
+            include.depth = include.depth.max(path.len());
+        }
+
+        Ok(include)
+    }
+
+    fn add_ancestor(&mut self, level: HierarchyLevel) {
+        match level {
+            HierarchyLevel::State => self.state = true,
+            HierarchyLevel::Lga => self.lga = true,
+            HierarchyLevel::Ward => self.ward = true,
+            HierarchyLevel::PostalCode => {}
+        }
+    }
+
+    /// Every value `include` accepts for a `level` entity
+    fn options(level: HierarchyLevel) -> Vec<String> {
+        let mut options: Vec<String> = level
+            .ancestors()
+            .map(|ancestor| ancestor.singular().to_string())
+            .collect();
+        if level != HierarchyLevel::State {
+            options.push("ancestors".to_string());
+        }
+
+        let mut path = Vec::new();
+        for descendant in level.descendants() {
+            path.push(descendant.plural());
+            options.push(path.join("."));
+        }
+
+        options
+    }
+}
+
+/// State with the related entities asked for by `include`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct StateNodeDto {
+    #[serde(flatten)]
+    pub state: StateDto,
+    /// LGAs in the state, with `include=lgas`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lgas: Option<Vec<LgaNodeDto>>,
+}
+
+/// LGA with the related entities asked for by `include`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LgaNodeDto {
+    #[serde(flatten)]
+    pub lga: LgaDto,
+    /// Enclosing state, with `include=state` or `include=ancestors`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<StateDto>,
+    /// Wards in the LGA, with `include=wards`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wards: Option<Vec<WardNodeDto>>,
+}
+
+/// Ward with the related entities asked for by `include`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WardNodeDto {
+    #[serde(flatten)]
+    pub ward: WardDto,
+    /// Enclosing state, with `include=state` or `include=ancestors`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<StateDto>,
+    /// Enclosing LGA, with `include=lga` or `include=ancestors`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lga: Option<LgaDto>,
+    /// Postal codes in the ward, with `include=postal_codes`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postal_codes: Option<Vec<PostalCodeDto>>,
+}
+
+/// Postal code with the related entities asked for by `include`
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PostalCodeNodeDto {
+    #[serde(flatten)]
+    pub postal_code: PostalCodeDto,
+    /// Enclosing state, with `include=state` or `include=ancestors`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<StateDto>,
+    /// Enclosing LGA, with `include=lga` or `include=ancestors`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lga: Option<LgaDto>,
+    /// Enclosing ward, with `include=ward` or `include=ancestors`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ward: Option<WardDto>,
+}
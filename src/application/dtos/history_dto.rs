@@ -0,0 +1,190 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::change::{Change, ChangeEntityType, ChangeOperation, EntityDiff};
+
+/// One recorded write to a state, LGA, ward, postal code or alias
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ChangeDto {
+    /// Position in the history; later changes have larger IDs
+    #[schema(example = 1042)]
+    pub id: i64,
+    /// `state`, `lga`, `ward`, `postal_code` or `alias`
+    #[schema(example = "ward")]
+    pub entity_type: String,
+    #[schema(example = "750e8400-e29b-41d4-a716-446655440001")]
+    pub entity_id: Uuid,
+    /// `create`, `update` or `delete`
+    #[schema(example = "update")]
+    pub operation: String,
+    /// The entity before the change; absent for creates
+    #[schema(value_type = Option<Object>)]
+    pub before: Option<Value>,
+    /// The entity after the change; absent for deletes
+    #[schema(value_type = Option<Object>)]
+    pub after: Option<Value>,
+    /// Fields an update changed, apart from `updated_at`
+    #[schema(example = json!(["name"]))]
+    pub changed_fields: Vec<String>,
+    /// Who made the change
+    #[schema(example = "ops@example.com")]
+    pub actor: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl From<Change> for ChangeDto {
+    fn from(change: Change) -> Self {
+        Self {
+            id: change.id,
+            entity_type: change.entity_type.as_str().to_string(),
+            entity_id: change.entity_id,
+            operation: change.operation.as_str().to_string(),
+            changed_fields: changed_fields(change.before.as_ref(), change.after.as_ref()),
+            before: change.before,
+            after: change.after,
+            actor: change.actor,
+            changed_at: change.changed_at,
+        }
+    }
+}
+
+/// Net change to one entity between two points in time
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EntityDiffDto {
+    /// `state`, `lga`, `ward`, `postal_code` or `alias`
+    #[schema(example = "postal_code")]
+    pub entity_type: String,
+    pub entity_id: Uuid,
+    /// `create` if the entity is new, `delete` if it is gone, otherwise `update`
+    #[schema(example = "update")]
+    pub operation: String,
+    /// The entity at the start of the period; absent if it was created since
+    #[schema(value_type = Option<Object>)]
+    pub before: Option<Value>,
+    /// The entity at the end of the period; absent if it was deleted since
+    #[schema(value_type = Option<Object>)]
+    pub after: Option<Value>,
+    /// Fields that differ between `before` and `after`, apart from `updated_at`
+    #[schema(example = json!(["coordinates", "postal_code"]))]
+    pub changed_fields: Vec<String>,
+    /// Number of writes to the entity during the period
+    #[schema(example = 2)]
+    pub changes: u64,
+    pub last_changed_at: DateTime<Utc>,
+}
+
+impl EntityDiffDto {
+    /// DTOs of the diffs whose changes do not cancel out
+    pub fn from_diffs(diffs: Vec<EntityDiff>) -> Vec<Self> {
+        diffs.into_iter().filter_map(Self::from_diff).collect()
+    }
+
+    /// The diff's DTO, or `None` when the changes cancel out: an entity
+    /// created and deleted again, or updated and then changed back
+    fn from_diff(diff: EntityDiff) -> Option<Self> {
+        let operation = ChangeOperation::between(diff.before.as_ref(), diff.after.as_ref())?;
+        let changed_fields = changed_fields(diff.before.as_ref(), diff.after.as_ref());
+        if operation == ChangeOperation::Update && changed_fields.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            entity_type: diff.entity_type.as_str().to_string(),
+            entity_id: diff.entity_id,
+            operation: operation.as_str().to_string(),
+            before: diff.before,
+            after: diff.after,
+            changed_fields,
+            changes: diff.changes,
+            last_changed_at: diff.last_changed_at,
+        })
+    }
+}
+
+/// How many entities a set of changes created, updated and deleted
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+pub struct ChangeCountsDto {
+    /// Entities created during the period and still present at its end
+    #[schema(example = 3)]
+    pub created: usize,
+    /// Entities present throughout the period whose data changed
+    #[schema(example = 12)]
+    pub updated: usize,
+    /// Entities present at the start of the period and gone at its end
+    #[schema(example = 1)]
+    pub deleted: usize,
+}
+
+impl ChangeCountsDto {
+    pub fn of(entities: &[EntityDiffDto]) -> Self {
+        let count = |operation: ChangeOperation| {
+            entities
+                .iter()
+                .filter(|e| e.operation == operation.as_str())
+                .count()
+        };
+
+        Self {
+            created: count(ChangeOperation::Create),
+            updated: count(ChangeOperation::Update),
+            deleted: count(ChangeOperation::Delete),
+        }
+    }
+}
+
+/// Everything that changed in the dataset between two points in time
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DatasetDiffDto {
+    /// Start of the period, exclusive
+    pub from: DateTime<Utc>,
+    /// End of the period, inclusive
+    pub to: DateTime<Utc>,
+    #[serde(flatten)]
+    pub counts: ChangeCountsDto,
+    /// The changed entities, in the order they were first changed
+    pub entities: Vec<EntityDiffDto>,
+}
+
+impl DatasetDiffDto {
+    pub fn new(from: DateTime<Utc>, to: DateTime<Utc>, diffs: Vec<EntityDiff>) -> Self {
+        let entities = EntityDiffDto::from_diffs(diffs);
+
+        Self {
+            from,
+            to,
+            counts: ChangeCountsDto::of(&entities),
+            entities,
+        }
+    }
+}
+
+/// Parameters of a dataset diff
+#[derive(Debug, Deserialize)]
+pub struct DiffParams {
+    /// Start of the period, exclusive, e.g. `2024-01-01T00:00:00Z`
+    pub from: DateTime<Utc>,
+    /// End of the period, inclusive; now when omitted
+    pub to: Option<DateTime<Utc>>,
+    /// Only entities of this type
+    pub entity_type: Option<ChangeEntityType>,
+}
+
+/// Top-level fields whose values differ between two snapshots of an entity,
+/// apart from `updated_at`; none unless both snapshots exist
+fn changed_fields(before: Option<&Value>, after: Option<&Value>) -> Vec<String> {
+    let (Some(Value::Object(before)), Some(Value::Object(after))) = (before, after) else {
+        return Vec::new();
+    };
+
+    let mut fields: Vec<String> = before
+        .keys()
+        .chain(after.keys().filter(|key| !before.contains_key(*key)))
+        .filter(|key| key.as_str() != "updated_at" && before.get(*key) != after.get(*key))
+        .cloned()
+        .collect();
+    fields.sort();
+    fields
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::unit_level::UnitLevel;
+
+/// One row of an import file
+///
+/// `code` is the unit's own code, or the postal code itself for postal codes.
+/// `level` may be left out for states, LGAs and wards, whose codes show it.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ImportRecordDto {
+    #[serde(default)]
+    pub level: Option<String>,
+    pub code: String,
+    /// Required except for states, which default to their canonical name
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Ward a postal code belongs to
+    #[serde(default)]
+    pub ward_code: Option<String>,
+    #[serde(default)]
+    pub lat: Option<f64>,
+    #[serde(default)]
+    pub lng: Option<f64>,
+    #[serde(default)]
+    pub urban: Option<bool>,
+}
+
+/// What an import did to the units of one level
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportCountsDto {
+    pub created: usize,
+    pub updated: usize,
+    /// Units that already existed exactly as given
+    pub skipped: usize,
+    pub rejected: usize,
+}
+
+/// A row that was not imported
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportRejectionDto {
+    /// 1-based position of the row in the file
+    pub row: usize,
+    /// `state`, `lga`, `ward` or `postal_code`, if it could be told
+    pub level: Option<String>,
+    pub code: Option<String>,
+    pub reason: String,
+}
+
+/// Outcome of an import
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportReportDto {
+    /// Whether the changes were rolled back rather than committed
+    pub dry_run: bool,
+    /// Rows read from the file
+    pub rows: usize,
+    pub total: ImportCountsDto,
+    pub states: ImportCountsDto,
+    pub lgas: ImportCountsDto,
+    pub wards: ImportCountsDto,
+    pub postal_codes: ImportCountsDto,
+    /// Rejected rows in file order
+    pub rejected: Vec<ImportRejectionDto>,
+}
+
+impl ImportReportDto {
+    /// Counts for the units of `level`
+    pub fn counts_mut(&mut self, level: UnitLevel) -> &mut ImportCountsDto {
+        match level {
+            UnitLevel::State => &mut self.states,
+            UnitLevel::Lga => &mut self.lgas,
+            UnitLevel::Ward => &mut self.wards,
+            UnitLevel::PostalCode => &mut self.postal_codes,
+        }
+    }
+
+    /// Counts for each level, states first
+    pub fn level_counts(&self) -> impl Iterator<Item = &ImportCountsDto> {
+        [&self.states, &self.lgas, &self.wards, &self.postal_codes].into_iter()
+    }
+}
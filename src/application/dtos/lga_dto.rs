@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 use utoipa::ToSchema;
+use uuid::Uuid;
 
+use crate::application::dtos::redirect_dto::RedirectDto;
 use crate::domain::entities::lga::Lga;
 
 /// LGA DTO for API responses
@@ -19,6 +20,14 @@ pub struct LgaDto {
     /// LGA code (e.g., NG-LA-01)
     #[schema(example = "NG-LA-01")]
     pub code: String,
+    /// `active`, `deprecated` or `merged`
+    #[schema(example = "active")]
+    pub status: String,
+    /// The LGA that replaces this one once it is retired
+    pub successor_id: Option<Uuid>,
+    /// Set when a lookup followed a retired unit to this one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirected_from: Option<RedirectDto>,
     /// Creation timestamp
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// Last update timestamp
@@ -32,6 +41,9 @@ impl From<Lga> for LgaDto {
             state_id: lga.state_id,
             name: lga.name,
             code: lga.code.to_string(),
+            status: lga.status.as_str().to_string(),
+            successor_id: lga.successor_id,
+            redirected_from: None,
             created_at: lga.created_at,
             updated_at: lga.updated_at,
         }
@@ -1,18 +1,50 @@
 pub mod address_dto;
+pub mod alias_dto;
+pub mod batch_validation_dto;
+pub mod bundle_dto;
+pub mod dataset_version_dto;
+pub mod export_dto;
+pub mod fields_dto;
 pub mod health_dto;
+pub mod hierarchy_dto;
+pub mod history_dto;
+pub mod import_dto;
 pub mod lga_dto;
 pub mod pagination_dto;
 pub mod postal_code_dto;
+pub mod redirect_dto;
 pub mod state_dto;
+pub mod sync_dto;
+pub mod verification_dto;
 pub mod ward_dto;
 
 // Re-exports for convenience
 pub use address_dto::{
     AddressDto, AddressSuggestionDto, AddressValidationRequestDto, AddressValidationResponseDto,
+    ComponentMatchDto, DeprecatedComponentDto, SimilarAddressDto,
 };
+pub use alias_dto::AliasDto;
+pub use batch_validation_dto::{
+    BatchJobDto, BatchJobStatus, BatchValidationItemDto, BatchValidationResponseDto,
+    BatchValidationSummaryDto,
+};
+pub use bundle_dto::{BundleCountsDto, BundleManifestDto};
+pub use dataset_version_dto::{DatasetVersionDetailDto, DatasetVersionDto};
+pub use export_dto::{ExportFormat, ExportParams};
+pub use fields_dto::{Fields, FieldsParams, Sparse};
 pub use health_dto::{HealthCheckResponse, HealthStatus, ServiceHealth};
+pub use hierarchy_dto::{
+    HierarchyLevel, Include, LgaNodeDto, PostalCodeNodeDto, StateNodeDto, WardNodeDto,
+};
+pub use history_dto::{ChangeCountsDto, ChangeDto, DatasetDiffDto, DiffParams, EntityDiffDto};
+pub use import_dto::{ImportCountsDto, ImportRecordDto, ImportRejectionDto, ImportReportDto};
 pub use lga_dto::LgaDto;
 pub use pagination_dto::{PaginatedResponse, PaginationMeta, PaginationParams};
-pub use postal_code_dto::PostalCodeDto;
+pub use postal_code_dto::{PostalCodeBreakdownDto, PostalCodeDto, PostalCodeStateCheckDto};
+pub use redirect_dto::RedirectDto;
 pub use state_dto::StateDto;
+pub use sync_dto::{SyncChangesDto, SyncParams, SyncResponseDto, TombstoneDto};
+pub use verification_dto::{
+    FindingDto, RuleReportDto, VerificationReportDto, VerificationSummaryDto, VerifyParams,
+};
 pub use ward_dto::WardDto;
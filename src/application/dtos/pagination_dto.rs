@@ -1,22 +1,84 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::domain::pagination::{Cursor, KeysetPage, PageRequest, MAX_PAGE, MAX_PAGE_SIZE};
+use crate::errors::{AppError, AppResult, InvalidParam};
+
+const DEFAULT_PAGE_SIZE: u32 = 20;
+
 /// Pagination parameters
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct PaginationParams {
-    /// Page number (1-based)
-    #[schema(example = 1, minimum = 1)]
+    /// Page number (1-based); kept for compatibility, prefer `cursor`
+    #[schema(example = 1, minimum = 1, maximum = 42949672)]
     pub page: Option<u32>,
     /// Number of items per page
     #[schema(example = 20, minimum = 1, maximum = 100)]
     pub limit: Option<u32>,
+    /// `next_cursor` or `prev_cursor` from a previous page; cannot be
+    /// combined with `page`
+    pub cursor: Option<String>,
 }
 
 impl Default for PaginationParams {
     fn default() -> Self {
         Self {
             page: Some(1),
-            limit: Some(20),
+            limit: Some(DEFAULT_PAGE_SIZE),
+            cursor: None,
+        }
+    }
+}
+
+impl PaginationParams {
+    /// Validated page size
+    pub fn limit(&self) -> AppResult<u32> {
+        match self.limit.unwrap_or(DEFAULT_PAGE_SIZE) {
+            limit @ 1..=MAX_PAGE_SIZE => Ok(limit),
+            limit => Err(AppError::InvalidParam(InvalidParam::new(
+                "limit",
+                "range",
+                format!("must be between 1 and {}, got {}", MAX_PAGE_SIZE, limit),
+            ))),
+        }
+    }
+
+    /// Validated page number and size, for listings without cursor support
+    pub fn page_and_limit(&self) -> AppResult<(u32, u32)> {
+        if self.cursor.is_some() {
+            return Err(AppError::InvalidParam(InvalidParam::new(
+                "cursor",
+                "unsupported",
+                "this listing is paged by page number, not cursor",
+            )));
+        }
+
+        match self.page.unwrap_or(1) {
+            page @ 1..=MAX_PAGE => Ok((page, self.limit()?)),
+            page => Err(AppError::InvalidParam(InvalidParam::new(
+                "page",
+                "range",
+                format!("must be between 1 and {}, got {}", MAX_PAGE, page),
+            ))),
+        }
+    }
+
+    /// Page to read: from the cursor if one was given, otherwise by number
+    pub fn page_request(&self) -> AppResult<PageRequest> {
+        match &self.cursor {
+            Some(_) if self.page.is_some() => Err(AppError::InvalidParam(InvalidParam::new(
+                "cursor",
+                "exclusive",
+                "cannot be combined with page",
+            ))),
+            Some(cursor) => Ok(PageRequest::Cursor {
+                cursor: Cursor::decode(cursor)?,
+                limit: self.limit()?,
+            }),
+            None => {
+                let (page, limit) = self.page_and_limit()?;
+                Ok(PageRequest::Offset { page, limit })
+            }
         }
     }
 }
@@ -33,9 +95,9 @@ pub struct PaginatedResponse<T: for<'a> ToSchema<'a>> {
 /// Pagination metadata
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PaginationMeta {
-    /// Current page number
+    /// Current page number; null when the page was read from a cursor
     #[schema(example = 1)]
-    pub page: u32,
+    pub page: Option<u32>,
     /// Items per page
     #[schema(example = 20)]
     pub limit: u32,
@@ -51,22 +113,69 @@ pub struct PaginationMeta {
     /// Whether there is a previous page
     #[schema(example = false)]
     pub has_prev: bool,
+    /// Cursor for the next page, on listings that support cursors
+    #[schema(example = "eyJsIjoid2FyZHMiLCJkIjoiYWZ0ZXIiLCJrIjpbIkFndWRhIl19")]
+    pub next_cursor: Option<String>,
+    /// Cursor for the previous page, on listings that support cursors
+    pub prev_cursor: Option<String>,
+}
+
+impl PaginationMeta {
+    /// Metadata for one page of a cursor-enabled listing
+    pub fn for_page<T>(page: &KeysetPage<T>, request: &PageRequest, total: u64) -> Self {
+        let limit = request.limit();
+
+        Self {
+            page: request.page(),
+            limit,
+            total,
+            total_pages: total_pages(total, limit),
+            has_next: page.next.is_some(),
+            has_prev: page.prev.is_some(),
+            next_cursor: page.next.as_ref().map(Cursor::encode),
+            prev_cursor: page.prev.as_ref().map(Cursor::encode),
+        }
+    }
+}
+
+fn total_pages(total: u64, limit: u32) -> u32 {
+    total.div_ceil(limit.max(1) as u64) as u32
 }
 
 impl<T: for<'a> ToSchema<'a>> PaginatedResponse<T> {
     pub fn new(data: Vec<T>, page: u32, limit: u32, total: u64) -> Self {
-        let total_pages = (total as f64 / limit as f64).ceil() as u32;
-        
+        let total_pages = total_pages(total, limit);
+
         Self {
             data,
             pagination: PaginationMeta {
-                page,
+                page: Some(page),
                 limit,
                 total,
                 total_pages,
                 has_next: page < total_pages,
                 has_prev: page > 1,
+                next_cursor: None,
+                prev_cursor: None,
             },
         }
     }
+
+    /// Response for one page of a cursor-enabled listing
+    pub fn from_page<U: Into<T>>(page: KeysetPage<U>, request: &PageRequest, total: u64) -> Self {
+        let pagination = PaginationMeta::for_page(&page, request, total);
+
+        Self {
+            data: page.items.into_iter().map(Into::into).collect(),
+            pagination,
+        }
+    }
+
+    /// Same page with each item converted by `f`
+    pub fn map<U: for<'a> ToSchema<'a>>(self, f: impl FnMut(T) -> U) -> PaginatedResponse<U> {
+        PaginatedResponse {
+            data: self.data.into_iter().map(f).collect(),
+            pagination: self.pagination,
+        }
+    }
 }
@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 use utoipa::ToSchema;
+use uuid::Uuid;
 
+use crate::application::dtos::redirect_dto::RedirectDto;
 use crate::domain::entities::postal_code::PostalCode;
+use crate::domain::value_objects::PostalCode as PostalCodeValue;
 
 /// Postal code DTO for API responses
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -25,6 +27,14 @@ pub struct PostalCodeDto {
     /// Whether this is an urban area
     #[schema(example = true)]
     pub urban: bool,
+    /// `active`, `deprecated` or `merged`
+    #[schema(example = "active")]
+    pub status: String,
+    /// The postal code that replaces this one once it is retired
+    pub successor_id: Option<Uuid>,
+    /// Set when a lookup followed a retired unit to this one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirected_from: Option<RedirectDto>,
     /// Creation timestamp
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// Last update timestamp
@@ -40,8 +50,74 @@ impl From<PostalCode> for PostalCodeDto {
             lat: postal_code.coordinates.as_ref().map(|c| c.latitude),
             lng: postal_code.coordinates.as_ref().map(|c| c.longitude),
             urban: postal_code.urban,
+            status: postal_code.status.as_str().to_string(),
+            successor_id: postal_code.successor_id,
+            redirected_from: None,
             created_at: postal_code.created_at,
             updated_at: postal_code.updated_at,
         }
     }
 }
+
+/// Decoded structure of a NIPOST postal code
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PostalCodeBreakdownDto {
+    /// Postal code that was decoded
+    #[schema(example = "100001")]
+    pub postal_code: String,
+    /// First digit, identifying the postal region
+    #[schema(example = "1")]
+    pub region_digit: String,
+    /// Name of the postal region
+    #[schema(example = "Lagos")]
+    pub region: String,
+    /// First two digits, identifying the state
+    #[schema(example = "10")]
+    pub state_prefix: String,
+    /// ISO code of the state the prefix is assigned to
+    #[schema(example = "NG-LA")]
+    pub state_code: String,
+    /// Name of the state the prefix is assigned to
+    #[schema(example = "Lagos")]
+    pub state_name: String,
+    /// First three digits, identifying the delivery district
+    #[schema(example = "100")]
+    pub district: String,
+    /// Last three digits, identifying the delivery location within the district
+    #[schema(example = "001")]
+    pub delivery_location: String,
+    /// Result of checking the code against a claimed state, if one was given
+    pub state_check: Option<PostalCodeStateCheckDto>,
+}
+
+/// Whether a postal code belongs to the state it is claimed for
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PostalCodeStateCheckDto {
+    /// State code the postal code was claimed for
+    #[schema(example = "NG-OG")]
+    pub claimed_state_code: String,
+    /// Whether the postal code prefix is assigned to the claimed state
+    #[schema(example = false)]
+    pub matches: bool,
+    /// Prefixes assigned to the claimed state
+    #[schema(example = json!(["11", "12"]))]
+    pub expected_prefixes: Vec<String>,
+}
+
+impl From<&PostalCodeValue> for PostalCodeBreakdownDto {
+    fn from(code: &PostalCodeValue) -> Self {
+        let region = code.region();
+        let state_code = code.state_code();
+        Self {
+            postal_code: code.to_string(),
+            region_digit: region.digit.to_string(),
+            region: region.name.to_string(),
+            state_prefix: code.state_prefix().to_string(),
+            state_code: state_code.to_string(),
+            state_name: state_code.name().to_string(),
+            district: code.district().to_string(),
+            delivery_location: code.delivery_location().to_string(),
+            state_check: None,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::unit_status::UnitStatus;
+
+/// The retired unit a lookup asked for, when the response is its successor
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RedirectDto {
+    /// ID of the unit that was asked for
+    #[schema(example = "650e8400-e29b-41d4-a716-446655440001")]
+    pub id: Uuid,
+    /// Its code
+    #[schema(example = "NG-LA-01")]
+    pub code: String,
+    /// Why it was replaced: `deprecated` or `merged`
+    #[schema(example = "merged")]
+    pub status: String,
+}
+
+impl RedirectDto {
+    pub fn new(id: Uuid, code: String, status: UnitStatus) -> Self {
+        Self {
+            id,
+            code,
+            status: status.as_str().to_string(),
+        }
+    }
+}
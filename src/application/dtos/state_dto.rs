@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 use utoipa::ToSchema;
+use uuid::Uuid;
 
+use crate::application::dtos::redirect_dto::RedirectDto;
 use crate::domain::entities::state::State;
 
 /// State DTO for API responses
@@ -16,6 +17,14 @@ pub struct StateDto {
     /// State code (e.g., NG-LA)
     #[schema(example = "NG-LA")]
     pub code: String,
+    /// `active`, `deprecated` or `merged`
+    #[schema(example = "active")]
+    pub status: String,
+    /// The state that replaces this one once it is retired
+    pub successor_id: Option<Uuid>,
+    /// Set when a lookup followed a retired unit to this one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirected_from: Option<RedirectDto>,
     /// Creation timestamp
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// Last update timestamp
@@ -28,6 +37,9 @@ impl From<State> for StateDto {
             id: state.id,
             name: state.name,
             code: state.code.to_string(),
+            status: state.status.as_str().to_string(),
+            successor_id: state.successor_id,
+            redirected_from: None,
             created_at: state.created_at,
             updated_at: state.updated_at,
         }
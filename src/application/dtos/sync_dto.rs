@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::dtos::{AliasDto, LgaDto, PostalCodeDto, StateDto, WardDto};
+use crate::domain::entities::sync::{SyncChanges, Tombstone, TombstoneReason};
+
+/// Query parameters for a delta sync
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SyncParams {
+    /// Token from the previous sync or a bundle manifest; without one, the
+    /// sync returns a full copy
+    #[serde(default)]
+    pub since: Option<String>,
+}
+
+/// An entity the client should drop from its copy
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TombstoneDto {
+    pub id: Uuid,
+    /// `deleted`, or `retired` for units deprecated or merged
+    #[schema(example = "retired")]
+    pub reason: String,
+    /// For retired units, the unit that replaces this one, if any
+    pub successor_id: Option<Uuid>,
+    pub deleted_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<Tombstone> for TombstoneDto {
+    fn from(tombstone: Tombstone) -> Self {
+        Self {
+            id: tombstone.id,
+            reason: match tombstone.reason {
+                TombstoneReason::Deleted => "deleted",
+                TombstoneReason::Retired => "retired",
+            }
+            .to_string(),
+            successor_id: tombstone.successor_id,
+            deleted_at: tombstone.deleted_at,
+        }
+    }
+}
+
+/// Changes to one type of entity
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SyncChangesDto<T: for<'a> ToSchema<'a>> {
+    pub created: Vec<T>,
+    pub updated: Vec<T>,
+    pub deleted: Vec<TombstoneDto>,
+}
+
+impl<T: for<'a> ToSchema<'a>> SyncChangesDto<T> {
+    pub fn from_changes<E>(changes: SyncChanges<E>) -> Self
+    where
+        T: From<E>,
+    {
+        Self {
+            created: changes.created.into_iter().map(T::from).collect(),
+            updated: changes.updated.into_iter().map(T::from).collect(),
+            deleted: changes
+                .deleted
+                .into_iter()
+                .map(TombstoneDto::from)
+                .collect(),
+        }
+    }
+}
+
+/// Changes since a sync token
+///
+/// Clients apply `created` and `updated` entities as upserts and remove
+/// `deleted` ones. A change can be sent again by a later sync; applying it
+/// twice leaves the copy the same.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SyncResponseDto {
+    /// Pass as `since` on the next sync
+    pub token: String,
+    /// Whether more changes remain; sync again with `token` straight away
+    pub has_more: bool,
+    /// Whether this is a full copy of the active units rather than changes
+    pub full: bool,
+    pub states: SyncChangesDto<StateDto>,
+    pub lgas: SyncChangesDto<LgaDto>,
+    pub wards: SyncChangesDto<WardDto>,
+    pub postal_codes: SyncChangesDto<PostalCodeDto>,
+    pub aliases: SyncChangesDto<AliasDto>,
+}
@@ -0,0 +1,128 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::verification::{Finding, IntegrityRule};
+use crate::errors::{AppError, AppResult, InvalidParam};
+
+/// Findings listed per rule unless a limit is given
+pub const DEFAULT_FINDING_LIMIT: usize = 50;
+
+/// Most findings a report lists per rule
+pub const MAX_FINDING_LIMIT: usize = 10_000;
+
+/// Query parameters for verifying the dataset
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct VerifyParams {
+    /// Rules to run, separated by commas; all of them when omitted
+    #[schema(example = "lgas_have_wards,code_formats")]
+    pub rules: Option<String>,
+    /// Findings to list per rule, up to 10000; 50 when omitted. Violations
+    /// are counted in full either way
+    pub limit: Option<usize>,
+}
+
+impl VerifyParams {
+    /// The rules named, in the order they are listed in `IntegrityRule::ALL`
+    pub fn rules(&self) -> AppResult<Vec<IntegrityRule>> {
+        let Some(value) = self.rules.as_deref() else {
+            return Ok(IntegrityRule::ALL.to_vec());
+        };
+
+        let mut named = Vec::new();
+        for name in value
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+        {
+            let rule = IntegrityRule::parse(name).ok_or_else(|| {
+                let expected: Vec<&str> = IntegrityRule::ALL.iter().map(|r| r.as_str()).collect();
+                AppError::InvalidParam(InvalidParam::new(
+                    "rules",
+                    "one_of",
+                    format!("Unknown rule '{}'; expected {}", name, expected.join(", ")),
+                ))
+            })?;
+            named.push(rule);
+        }
+
+        Ok(IntegrityRule::ALL
+            .into_iter()
+            .filter(|rule| named.is_empty() || named.contains(rule))
+            .collect())
+    }
+
+    /// Findings to list per rule
+    pub fn limit(&self) -> AppResult<usize> {
+        match self.limit {
+            None => Ok(DEFAULT_FINDING_LIMIT),
+            Some(limit) if limit <= MAX_FINDING_LIMIT => Ok(limit),
+            Some(_) => Err(AppError::InvalidParam(InvalidParam::new(
+                "limit",
+                "range",
+                format!("must be at most {}", MAX_FINDING_LIMIT),
+            ))),
+        }
+    }
+}
+
+/// A unit breaking a rule, or the dataset as a whole when `id` is absent
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FindingDto {
+    /// `state`, `lga`, `ward` or `postal_code`
+    #[schema(example = "lga")]
+    pub level: String,
+    pub id: Option<Uuid>,
+    #[schema(example = "NG-LA-IKJ")]
+    pub code: Option<String>,
+    #[schema(example = "has no active wards")]
+    pub detail: String,
+}
+
+impl From<Finding> for FindingDto {
+    fn from(finding: Finding) -> Self {
+        Self {
+            level: finding.level.as_str().to_string(),
+            id: finding.id,
+            code: finding.code,
+            detail: finding.detail,
+        }
+    }
+}
+
+/// Outcome of one rule
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RuleReportDto {
+    #[schema(example = "lgas_have_wards")]
+    pub rule: String,
+    pub description: String,
+    pub passed: bool,
+    /// Every violation found, including those not listed
+    pub violations: usize,
+    /// The first violations, up to the requested limit
+    pub findings: Vec<FindingDto>,
+    /// Whether some violations were left out of `findings`
+    pub truncated: bool,
+}
+
+/// Rules and violations across a report
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct VerificationSummaryDto {
+    pub rules: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub violations: usize,
+}
+
+/// Result of running the integrity rules against the dataset
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct VerificationReportDto {
+    /// Whether every rule run passed
+    pub passed: bool,
+    pub checked_at: DateTime<Utc>,
+    /// Data fingerprint of the snapshot checked
+    pub fingerprint: String,
+    pub summary: VerificationSummaryDto,
+    pub rules: Vec<RuleReportDto>,
+}
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 use utoipa::ToSchema;
+use uuid::Uuid;
 
+use crate::application::dtos::redirect_dto::RedirectDto;
 use crate::domain::entities::ward::Ward;
 
 /// Ward DTO for API responses
@@ -19,6 +20,14 @@ pub struct WardDto {
     /// Ward code (e.g., NG-LA-01-01)
     #[schema(example = "NG-LA-01-01")]
     pub code: String,
+    /// `active`, `deprecated` or `merged`
+    #[schema(example = "active")]
+    pub status: String,
+    /// The ward that replaces this one once it is retired
+    pub successor_id: Option<Uuid>,
+    /// Set when a lookup followed a retired unit to this one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirected_from: Option<RedirectDto>,
     /// Creation timestamp
     pub created_at: chrono::DateTime<chrono::Utc>,
     /// Last update timestamp
@@ -32,6 +41,9 @@ impl From<Ward> for WardDto {
             lga_id: ward.lga_id,
             name: ward.name,
             code: ward.code.to_string(),
+            status: ward.status.as_str().to_string(),
+            successor_id: ward.successor_id,
+            redirected_from: None,
             created_at: ward.created_at,
             updated_at: ward.updated_at,
         }
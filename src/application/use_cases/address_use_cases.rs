@@ -1,8 +1,14 @@
-use crate::application::dtos::address_dto::{AddressDto, AddressValidationResponseDto};
+use crate::application::dtos::address_dto::{AddressDto, AddressValidationResponseDto, SimilarAddressDto};
 use crate::domain::entities::address::AddressValidationRequest;
 use crate::domain::repositories::address_repository::AddressRepository;
 use crate::errors::AppResult;
 
+/// Default number of similar addresses returned
+pub const DEFAULT_SIMILAR_ADDRESS_LIMIT: u32 = 10;
+
+/// Maximum number of similar addresses returned
+pub const MAX_SIMILAR_ADDRESS_LIMIT: u32 = 50;
+
 /// Address use cases
 pub struct AddressUseCases<R: AddressRepository> {
     address_repository: R,
@@ -37,14 +43,16 @@ impl<R: AddressRepository> AddressUseCases<R> {
         Ok(address.map(|a| a.into()))
     }
 
-    /// Find similar addresses
+    /// Find similar addresses, best match first
     pub async fn find_similar_addresses(
         &self,
         request: AddressValidationRequest,
-    ) -> AppResult<Vec<AddressDto>> {
+        limit: u32,
+    ) -> AppResult<Vec<SimilarAddressDto>> {
+        let limit = limit.clamp(1, MAX_SIMILAR_ADDRESS_LIMIT);
         let addresses = self
             .address_repository
-            .find_similar_addresses(&request)
+            .find_similar_addresses(&request, limit)
             .await?;
         Ok(addresses.into_iter().map(|a| a.into()).collect())
     }
@@ -0,0 +1,74 @@
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::application::dtos::{AliasDto, PaginatedResponse, PaginationParams};
+use crate::domain::entities::alias::{AliasEntityType, CreateAliasRequest, UpdateAliasRequest};
+use crate::domain::repositories::alias_repository::AliasRepository;
+use crate::errors::{AppError, AppResult};
+
+/// Alias use cases
+#[derive(Clone)]
+pub struct AliasUseCases<R: AliasRepository + Clone> {
+    alias_repository: R,
+}
+
+impl<R: AliasRepository + Clone> AliasUseCases<R> {
+    pub fn new(alias_repository: R) -> Self {
+        Self { alias_repository }
+    }
+
+    /// List aliases, optionally of one entity type (`state`, `lga`, `ward`) or one entity
+    pub async fn get_aliases(
+        &self,
+        entity_type: Option<&str>,
+        entity_id: Option<Uuid>,
+        params: PaginationParams,
+    ) -> AppResult<PaginatedResponse<AliasDto>> {
+        let (page, limit) = params.page_and_limit()?;
+        let entity_type = entity_type.map(parse_entity_type).transpose()?;
+
+        let (aliases, total) = tokio::try_join!(
+            self.alias_repository
+                .list(entity_type, entity_id, page, limit),
+            self.alias_repository.count(entity_type, entity_id)
+        )?;
+
+        let alias_dtos: Vec<AliasDto> = aliases.into_iter().map(|a| a.into()).collect();
+
+        Ok(PaginatedResponse::new(alias_dtos, page, limit, total))
+    }
+
+    /// Get alias by ID
+    pub async fn get_alias_by_id(&self, id: Uuid) -> AppResult<Option<AliasDto>> {
+        let alias = self.alias_repository.find_by_id(id).await?;
+        Ok(alias.map(|a| a.into()))
+    }
+
+    /// Create a new alias
+    pub async fn create_alias(&self, request: CreateAliasRequest) -> AppResult<AliasDto> {
+        request.validate()?;
+        let alias = self.alias_repository.create(&request).await?;
+        Ok(alias.into())
+    }
+
+    /// Update alias
+    pub async fn update_alias(&self, id: Uuid, request: UpdateAliasRequest) -> AppResult<AliasDto> {
+        request.validate()?;
+        let alias = self.alias_repository.update(id, &request).await?;
+        Ok(alias.into())
+    }
+
+    /// Delete alias
+    pub async fn delete_alias(&self, id: Uuid) -> AppResult<()> {
+        self.alias_repository.delete(id).await
+    }
+}
+
+fn parse_entity_type(value: &str) -> AppResult<AliasEntityType> {
+    AliasEntityType::parse(&value.to_ascii_lowercase()).ok_or_else(|| {
+        AppError::BadRequest(format!(
+            "Unknown entity_type '{}'; expected state, lga or ward",
+            value
+        ))
+    })
+}
@@ -0,0 +1,398 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use tracing::{info, warn};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::entities::search::{AutocompleteEntry, SearchEntityType};
+use crate::domain::normalization::{normalized, rules_needed_within};
+use crate::domain::repositories::search_repository::SearchRepository;
+use crate::domain::value_objects::{LgaCode, StateCode};
+use crate::errors::{AppError, AppResult};
+
+/// Suggestions returned when no limit is given
+pub const DEFAULT_AUTOCOMPLETE_LIMIT: usize = 10;
+
+/// Largest number of suggestions returned for one query
+pub const MAX_AUTOCOMPLETE_LIMIT: usize = 50;
+
+/// How often the index checks the database for changes, unless configured otherwise
+pub const DEFAULT_AUTOCOMPLETE_REFRESH_SECS: u64 = 60;
+
+/// Single typeahead suggestion
+#[derive(Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct AutocompleteSuggestionDto {
+    /// Entity type: `state`, `lga`, `ward` or `postal_code`
+    #[schema(example = "lga")]
+    pub entity_type: String,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub id: Uuid,
+    /// Entity name, or the code itself for postal codes
+    #[schema(example = "Ikeja")]
+    pub name: String,
+    #[schema(example = "NG-LA-IKJ")]
+    pub code: String,
+    /// Code of the enclosing state
+    #[schema(example = "NG-LA")]
+    pub state_code: String,
+    /// Enclosing entities, nearest first
+    #[schema(example = "Lagos")]
+    pub parent_path: Option<String>,
+    /// Alias the query matched, when it matched an alias rather than the name
+    #[schema(example = "PH")]
+    pub matched_alias: Option<String>,
+    /// Normalization rules the query needed to match: `case`, `diacritics`,
+    /// `punctuation` or `stopwords`
+    #[schema(example = json!(["diacritics"]))]
+    pub normalization: Vec<String>,
+}
+
+impl AutocompleteSuggestionDto {
+    fn new(query: &str, entry: &AutocompleteEntry, matched_alias: Option<&str>) -> Self {
+        let normalization = rules_needed_within(query.trim(), matched_alias.unwrap_or(&entry.name))
+            .unwrap_or_default()
+            .iter()
+            .map(|rule| rule.as_str().to_string())
+            .collect();
+
+        Self {
+            entity_type: entry.entity_type.as_str().to_string(),
+            id: entry.id,
+            name: entry.name.clone(),
+            code: entry.code.clone(),
+            state_code: entry.state_code.clone(),
+            parent_path: entry.parent_path.clone(),
+            matched_alias: matched_alias.map(str::to_string),
+            normalization,
+        }
+    }
+}
+
+/// Restrictions applied to autocomplete matches
+#[derive(Debug, Clone, Default)]
+pub struct AutocompleteFilter {
+    /// Entity types to return; all types when `None`
+    pub entity_types: Option<Vec<SearchEntityType>>,
+    /// Only entities within this state
+    pub state_code: Option<StateCode>,
+    /// Only entities within this LGA
+    pub lga_code: Option<LgaCode>,
+}
+
+impl AutocompleteFilter {
+    fn accepts(&self, entry: &AutocompleteEntry) -> bool {
+        self.entity_types
+            .as_ref()
+            .is_none_or(|types| types.contains(&entry.entity_type))
+            && self
+                .state_code
+                .as_ref()
+                .is_none_or(|state| entry.state_code == state.as_str())
+            && self
+                .lga_code
+                .as_ref()
+                .is_none_or(|lga| entry.lga_code.as_deref() == Some(lga.as_str()))
+    }
+}
+
+/// How a query matched an entry; earlier variants rank higher
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum MatchKind {
+    ExactName,
+    ExactAlias,
+    NamePrefix,
+    AliasPrefix,
+    WordPrefix,
+    CodePrefix,
+}
+
+/// Which part of an entry an index key was taken from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeySource {
+    Name,
+    Word,
+    Code,
+    /// Position of the alias in [`AutocompleteEntry::aliases`]
+    Alias(u16),
+}
+
+/// In-memory prefix index over entity names and codes
+///
+/// Every entry is indexed under its normalized name, each later word of the
+/// name (so "east" finds "Ikeja East"), its code and each of its aliases. Keys are kept sorted, so
+/// the keys starting with a prefix form one contiguous range found by binary
+/// search.
+#[derive(Debug, Default)]
+pub struct AutocompleteIndex {
+    entries: Vec<AutocompleteEntry>,
+    keys: Vec<(String, u32, KeySource)>,
+    fingerprint: String,
+}
+
+impl AutocompleteIndex {
+    /// Build an index over the given entries
+    pub fn build(entries: Vec<AutocompleteEntry>, fingerprint: String) -> Self {
+        let mut keys = Vec::with_capacity(entries.len() * 3);
+
+        for (position, entry) in entries.iter().enumerate() {
+            let position = position as u32;
+            let name = normalized(&entry.name);
+            let code = normalized(&entry.code);
+
+            for (offset, _) in name.match_indices(' ') {
+                keys.push((name[offset + 1..].to_string(), position, KeySource::Word));
+            }
+            if code != name {
+                keys.push((code, position, KeySource::Code));
+            }
+            for (alias_position, alias) in entry.aliases.iter().enumerate() {
+                keys.push((
+                    normalized(alias),
+                    position,
+                    KeySource::Alias(alias_position as u16),
+                ));
+            }
+            keys.push((name, position, KeySource::Name));
+        }
+
+        keys.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        Self {
+            entries,
+            keys,
+            fingerprint,
+        }
+    }
+
+    /// Number of indexed entities
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the index holds no entities
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Best matches for a prefix, exact names and aliases first, then name,
+    /// alias, word and code prefixes; ties go to the higher level, then the
+    /// shorter name
+    pub fn suggest(
+        &self,
+        query: &str,
+        filter: &AutocompleteFilter,
+        limit: usize,
+    ) -> Vec<AutocompleteMatch<'_>> {
+        let prefix = normalized(query);
+        if prefix.is_empty() {
+            return Vec::new();
+        }
+
+        let start = self
+            .keys
+            .partition_point(|(key, _, _)| key.as_str() < prefix.as_str());
+        let mut matches: HashMap<u32, (MatchKind, Option<u16>)> = HashMap::new();
+
+        for (key, position, source) in self.keys[start..]
+            .iter()
+            .take_while(|(key, _, _)| key.starts_with(&prefix))
+        {
+            if !filter.accepts(&self.entries[*position as usize]) {
+                continue;
+            }
+
+            let exact = *key == prefix;
+            let (kind, alias) = match source {
+                KeySource::Name if exact => (MatchKind::ExactName, None),
+                KeySource::Name => (MatchKind::NamePrefix, None),
+                KeySource::Alias(alias) if exact => (MatchKind::ExactAlias, Some(*alias)),
+                KeySource::Alias(alias) => (MatchKind::AliasPrefix, Some(*alias)),
+                KeySource::Word => (MatchKind::WordPrefix, None),
+                KeySource::Code => (MatchKind::CodePrefix, None),
+            };
+            matches
+                .entry(*position)
+                .and_modify(|best| {
+                    if kind < best.0 {
+                        *best = (kind, alias);
+                    }
+                })
+                .or_insert((kind, alias));
+        }
+
+        let mut ranked: Vec<(MatchKind, AutocompleteMatch<'_>)> = matches
+            .into_iter()
+            .map(|(position, (kind, alias))| {
+                let entry = &self.entries[position as usize];
+                let matched_alias = alias.map(|alias| entry.aliases[alias as usize].as_str());
+                (
+                    kind,
+                    AutocompleteMatch {
+                        entry,
+                        matched_alias,
+                    },
+                )
+            })
+            .collect();
+        ranked.sort_unstable_by(|(a_kind, a), (b_kind, b)| {
+            let (a, b) = (a.entry, b.entry);
+            a_kind
+                .cmp(b_kind)
+                .then_with(|| level(a.entity_type).cmp(&level(b.entity_type)))
+                .then_with(|| a.name.len().cmp(&b.name.len()))
+                .then_with(|| a.name.cmp(&b.name))
+                .then_with(|| a.code.cmp(&b.code))
+        });
+
+        ranked
+            .into_iter()
+            .take(limit)
+            .map(|(_, found)| found)
+            .collect()
+    }
+}
+
+/// Entry found for a query, with the alias it was found by, if any
+#[derive(Debug, Clone, Copy)]
+pub struct AutocompleteMatch<'a> {
+    pub entry: &'a AutocompleteEntry,
+    pub matched_alias: Option<&'a str>,
+}
+
+fn level(entity_type: SearchEntityType) -> u8 {
+    match entity_type {
+        SearchEntityType::State => 1,
+        SearchEntityType::Lga => 2,
+        SearchEntityType::Ward => 3,
+        SearchEntityType::PostalCode => 4,
+    }
+}
+
+/// Autocomplete use cases
+///
+/// Suggestions are answered from an in-memory index, never from the
+/// database. The index is rebuilt whenever the repository's data fingerprint
+/// changes; see [`AutocompleteUseCases::spawn_refresh`].
+#[derive(Clone)]
+pub struct AutocompleteUseCases<XR: SearchRepository + Clone> {
+    search_repository: XR,
+    index: Arc<RwLock<Arc<AutocompleteIndex>>>,
+}
+
+impl<XR: SearchRepository + Clone + 'static> AutocompleteUseCases<XR> {
+    /// Create the use cases with an empty index; call [`Self::refresh`] to load it
+    pub fn new(search_repository: XR) -> Self {
+        Self {
+            search_repository,
+            index: Arc::new(RwLock::new(Arc::new(AutocompleteIndex::default()))),
+        }
+    }
+
+    /// Rebuild the index if the underlying data changed since the last build
+    ///
+    /// Returns whether the index was rebuilt.
+    pub async fn refresh(&self) -> AppResult<bool> {
+        let fingerprint = self.search_repository.data_fingerprint().await?;
+        if self.current_index().fingerprint == fingerprint {
+            return Ok(false);
+        }
+
+        let started = Instant::now();
+        let entries = self.search_repository.autocomplete_entries().await?;
+        let index = AutocompleteIndex::build(entries, fingerprint);
+        info!(
+            "Autocomplete index built with {} entries in {:?}",
+            index.len(),
+            started.elapsed()
+        );
+
+        *self
+            .index
+            .write()
+            .expect("autocomplete index lock poisoned") = Arc::new(index);
+        Ok(true)
+    }
+
+    /// Keep the index in step with the database by checking for changes periodically
+    ///
+    /// Panics if `every` is zero; [`crate::config::Config`] rejects that setting.
+    pub fn spawn_refresh(&self, every: Duration) -> tokio::task::JoinHandle<()> {
+        let use_cases = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(every);
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                if let Err(e) = use_cases.refresh().await {
+                    warn!("Failed to refresh autocomplete index: {}", e);
+                }
+            }
+        })
+    }
+
+    /// Typeahead suggestions for a partial name or code
+    ///
+    /// `entity_types` is a comma-separated list such as `lga,ward`; `state`
+    /// accepts anything [`StateCode::lookup`] does and `lga` an LGA code.
+    pub fn suggest(
+        &self,
+        query: &str,
+        entity_types: Option<&str>,
+        state: Option<&str>,
+        lga: Option<&str>,
+        limit: Option<usize>,
+    ) -> AppResult<Vec<AutocompleteSuggestionDto>> {
+        if normalized(query).is_empty() {
+            return Err(AppError::BadRequest(
+                "Query parameter q must contain a letter or digit".to_string(),
+            ));
+        }
+
+        let filter = AutocompleteFilter {
+            entity_types: entity_types.map(parse_entity_types).transpose()?,
+            state_code: state
+                .map(StateCode::lookup)
+                .transpose()
+                .map_err(|e| AppError::invalid_param("state", e))?,
+            lga_code: lga
+                .map(|code| LgaCode::new(code.to_string()))
+                .transpose()
+                .map_err(|e| AppError::invalid_param("lga", e))?,
+        };
+        let limit = limit
+            .unwrap_or(DEFAULT_AUTOCOMPLETE_LIMIT)
+            .clamp(1, MAX_AUTOCOMPLETE_LIMIT);
+
+        let index = self.current_index();
+        Ok(index
+            .suggest(query, &filter, limit)
+            .into_iter()
+            .map(|found| AutocompleteSuggestionDto::new(query, found.entry, found.matched_alias))
+            .collect())
+    }
+
+    fn current_index(&self) -> Arc<AutocompleteIndex> {
+        self.index
+            .read()
+            .expect("autocomplete index lock poisoned")
+            .clone()
+    }
+}
+
+fn parse_entity_types(value: &str) -> AppResult<Vec<SearchEntityType>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            SearchEntityType::parse(&name.to_ascii_lowercase()).ok_or_else(|| {
+                AppError::BadRequest(format!(
+                    "Unknown type '{}'; expected state, lga, ward or postal_code",
+                    name
+                ))
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,649 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use futures::stream::{self, StreamExt};
+use serde::de::{self, Deserializer as _, SeqAccess, Visitor};
+use tokio::sync::{RwLock, Semaphore};
+use uuid::Uuid;
+
+use crate::application::dtos::address_dto::AddressValidationRequestDto;
+use crate::application::dtos::batch_validation_dto::{
+    BatchJobDto, BatchJobStatus, BatchValidationItemDto, BatchValidationResponseDto,
+    BatchValidationSummaryDto,
+};
+use crate::application::use_cases::address_use_cases::AddressUseCases;
+use crate::domain::repositories::address_repository::AddressRepository;
+use crate::errors::{AppError, AppResult};
+
+/// Batches up to this many rows are validated inline; larger ones become background jobs
+pub const SYNC_BATCH_LIMIT: usize = 500;
+
+/// Largest number of rows accepted in a single submission
+pub const MAX_BATCH_ROWS: usize = 100_000;
+
+/// Largest request body accepted by the batch endpoint
+pub const MAX_BATCH_UPLOAD_BYTES: usize = 64 * 1024 * 1024;
+
+/// Addresses validated concurrently (kept below the database pool size)
+const VALIDATION_CONCURRENCY: usize = 4;
+
+/// Rows processed between job progress updates
+const PROGRESS_CHUNK_SIZE: usize = 250;
+
+/// Background jobs validated at the same time; later ones wait their turn
+const JOB_WORKERS: usize = 2;
+
+/// Unfinished jobs, queued or running, beyond which submissions are refused
+pub const MAX_PENDING_JOBS: usize = 16;
+
+/// How long finished jobs are kept around for download
+const JOB_RETENTION_HOURS: i64 = 24;
+
+/// Jobs, finished or not, held in memory at once; the oldest finished ones are
+/// dropped early to make room
+pub const MAX_RETAINED_JOBS: usize = 64;
+
+/// Rows across all jobs held in memory at once, dropped as for [`MAX_RETAINED_JOBS`]
+pub const MAX_RETAINED_ROWS: usize = 1_000_000;
+
+/// How often expired jobs are dropped
+const JOB_PURGE_INTERVAL_SECS: u64 = 15 * 60;
+
+/// Columns every CSV upload must provide
+const CSV_COLUMNS: [&str; 4] = ["state", "lga", "ward", "postal_code"];
+
+/// A parsed batch row, or the reason it could not be parsed
+pub type BatchRow = Result<AddressValidationRequestDto, String>;
+
+/// Formats accepted for uploads and offered for result downloads
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchFormat {
+    Json,
+    Ndjson,
+    Csv,
+}
+
+impl BatchFormat {
+    /// Detect the format from a `Content-Type` header value
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+
+        match mime.as_str() {
+            "application/json" => Some(Self::Json),
+            "application/x-ndjson" | "application/ndjson" | "application/jsonl" => {
+                Some(Self::Ndjson)
+            }
+            "text/csv" | "application/csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    /// Detect the format from an uploaded file name
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        let (_, extension) = file_name.rsplit_once('.')?;
+        Self::from_name(extension)
+    }
+
+    /// Parse a format name such as `csv` or `ndjson`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "ndjson" | "jsonl" => Some(Self::Ndjson),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    /// MIME type used when serving results in this format
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Ndjson => "application/x-ndjson",
+            Self::Csv => "text/csv; charset=utf-8",
+        }
+    }
+
+    /// File extension used when serving results in this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Ndjson => "ndjson",
+            Self::Csv => "csv",
+        }
+    }
+
+    /// Parse an uploaded batch into rows.
+    ///
+    /// Malformed rows are kept as `Err` so they are reported in the results
+    /// rather than rejecting the whole batch. Parsing stops as soon as the
+    /// batch is known to exceed [`MAX_BATCH_ROWS`].
+    pub fn parse(&self, data: &[u8]) -> AppResult<Vec<BatchRow>> {
+        // Spreadsheet exports frequently start with a UTF-8 byte order mark
+        let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+
+        let rows: Vec<BatchRow> = match self {
+            Self::Json => {
+                let too_many = Cell::new(false);
+                let mut deserializer = serde_json::Deserializer::from_slice(data);
+                let rows = deserializer
+                    .deserialize_seq(JsonRows {
+                        too_many: &too_many,
+                    })
+                    .and_then(|rows| deserializer.end().map(|_| rows));
+
+                match rows {
+                    Ok(rows) => rows,
+                    Err(_) if too_many.get() => return Err(too_many_rows()),
+                    Err(e) => {
+                        return Err(AppError::BadRequest(format!("Invalid JSON array: {}", e)))
+                    }
+                }
+            }
+            Self::Ndjson => {
+                let text = std::str::from_utf8(data)
+                    .map_err(|_| AppError::BadRequest("NDJSON body must be UTF-8".to_string()))?;
+
+                text.lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .take(MAX_BATCH_ROWS + 1)
+                    .map(|line| serde_json::from_str(line).map_err(|e| e.to_string()))
+                    .collect()
+            }
+            Self::Csv => {
+                let mut reader = csv::ReaderBuilder::new()
+                    .trim(csv::Trim::All)
+                    .flexible(true)
+                    .from_reader(data);
+
+                let headers: csv::StringRecord = reader
+                    .headers()
+                    .map_err(|e| AppError::BadRequest(format!("Invalid CSV header: {}", e)))?
+                    .iter()
+                    .map(|header| header.to_ascii_lowercase())
+                    .collect();
+
+                let missing: Vec<&str> = CSV_COLUMNS
+                    .iter()
+                    .copied()
+                    .filter(|column| !headers.iter().any(|header| header == *column))
+                    .collect();
+                if !missing.is_empty() {
+                    return Err(AppError::BadRequest(format!(
+                        "CSV is missing required column(s): {}",
+                        missing.join(", ")
+                    )));
+                }
+                reader.set_headers(headers);
+
+                reader
+                    .deserialize::<AddressValidationRequestDto>()
+                    .take(MAX_BATCH_ROWS + 1)
+                    .map(|record| record.map_err(|e| e.to_string()))
+                    .collect()
+            }
+        };
+
+        if rows.len() > MAX_BATCH_ROWS {
+            return Err(too_many_rows());
+        }
+        Ok(rows)
+    }
+
+    /// Serialize batch results in this format
+    pub fn render(&self, results: &[BatchValidationItemDto]) -> AppResult<Vec<u8>> {
+        match self {
+            Self::Json => Ok(serde_json::to_vec(results)?),
+            Self::Ndjson => {
+                let mut output = Vec::new();
+                for item in results {
+                    serde_json::to_writer(&mut output, item)?;
+                    output.push(b'\n');
+                }
+                Ok(output)
+            }
+            Self::Csv => {
+                let mut writer = csv::Writer::from_writer(Vec::new());
+                writer
+                    .write_record([
+                        "row",
+                        "state",
+                        "lga",
+                        "ward",
+                        "postal_code",
+                        "valid",
+                        "canonical_state",
+                        "canonical_lga",
+                        "canonical_ward",
+                        "canonical_postal_code",
+                        "suggestions",
+                        "top_suggestion",
+                        "error",
+                    ])
+                    .map_err(|e| AppError::Internal(anyhow::anyhow!(e)))?;
+
+                for item in results {
+                    let input = item.input.as_ref();
+                    let canonical = item.canonical.as_ref();
+                    let top_suggestion = item.suggestions.first().map(|s| s.reason.as_str());
+
+                    writer
+                        .write_record([
+                            item.row.to_string().as_str(),
+                            input.map(|i| i.state.as_str()).unwrap_or_default(),
+                            input.map(|i| i.lga.as_str()).unwrap_or_default(),
+                            input.map(|i| i.ward.as_str()).unwrap_or_default(),
+                            input.map(|i| i.postal_code.as_str()).unwrap_or_default(),
+                            if item.valid { "true" } else { "false" },
+                            canonical.map(|c| c.state.name.as_str()).unwrap_or_default(),
+                            canonical.map(|c| c.lga.name.as_str()).unwrap_or_default(),
+                            canonical.map(|c| c.ward.name.as_str()).unwrap_or_default(),
+                            canonical
+                                .map(|c| c.postal_code.postal_code.as_str())
+                                .unwrap_or_default(),
+                            item.suggestions.len().to_string().as_str(),
+                            top_suggestion.unwrap_or_default(),
+                            item.error.as_deref().unwrap_or_default(),
+                        ])
+                        .map_err(|e| AppError::Internal(anyhow::anyhow!(e)))?;
+                }
+
+                writer
+                    .into_inner()
+                    .map_err(|e| AppError::Internal(anyhow::anyhow!(e.to_string())))
+            }
+        }
+    }
+}
+
+/// Rejection of a batch with more than [`MAX_BATCH_ROWS`] rows
+fn too_many_rows() -> AppError {
+    AppError::BadRequest(format!(
+        "Batch contains more than {} addresses",
+        MAX_BATCH_ROWS
+    ))
+}
+
+/// Reads the rows of a JSON array one at a time, giving up once there are
+/// more than [`MAX_BATCH_ROWS`]
+struct JsonRows<'a> {
+    too_many: &'a Cell<bool>,
+}
+
+impl<'de> Visitor<'de> for JsonRows<'_> {
+    type Value = Vec<BatchRow>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an array of addresses")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut rows = Vec::new();
+        while let Some(value) = seq.next_element::<serde_json::Value>()? {
+            if rows.len() == MAX_BATCH_ROWS {
+                self.too_many.set(true);
+                return Err(de::Error::custom("too many addresses"));
+            }
+            rows.push(serde_json::from_value(value).map_err(|e| e.to_string()));
+        }
+        Ok(rows)
+    }
+}
+
+/// Outcome of submitting a batch
+pub enum BatchSubmission {
+    /// The batch was small enough to validate inline
+    Completed(BatchValidationResponseDto),
+    /// The batch was queued as a background job
+    Queued(BatchJobDto),
+}
+
+/// In-memory record of a background batch job
+struct BatchJob {
+    id: Uuid,
+    status: BatchJobStatus,
+    processed: usize,
+    summary: BatchValidationSummaryDto,
+    error: Option<String>,
+    created_at: DateTime<Utc>,
+    completed_at: Option<DateTime<Utc>>,
+    /// Shared so downloads can render them without holding the job store
+    results: Arc<Vec<BatchValidationItemDto>>,
+}
+
+impl BatchJob {
+    fn new(total: usize) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            status: BatchJobStatus::Queued,
+            processed: 0,
+            summary: BatchValidationSummaryDto {
+                total,
+                ..Default::default()
+            },
+            error: None,
+            created_at: Utc::now(),
+            completed_at: None,
+            results: Arc::new(Vec::with_capacity(total)),
+        }
+    }
+
+    fn finish(&mut self, status: BatchJobStatus, error: Option<String>) {
+        self.status = status;
+        self.error = error;
+        self.completed_at = Some(Utc::now());
+    }
+
+    fn to_dto(&self) -> BatchJobDto {
+        let status_url = format!("/api/v1/validate/batch/{}", self.id);
+        BatchJobDto {
+            job_id: self.id,
+            status: self.status,
+            processed: self.processed,
+            summary: self.summary.clone(),
+            error: self.error.clone(),
+            created_at: self.created_at,
+            completed_at: self.completed_at,
+            results_url: format!("{}/results", status_url),
+            status_url,
+        }
+    }
+}
+
+type JobStore = Arc<RwLock<HashMap<Uuid, BatchJob>>>;
+
+/// Batch address validation use cases
+///
+/// Jobs are held in memory, so queued or unfinished jobs do not survive a restart.
+/// At most [`JOB_WORKERS`] jobs run at once and at most [`MAX_PENDING_JOBS`]
+/// may be unfinished. Finished jobs are dropped once expired (see
+/// [`BatchValidationUseCases::spawn_purge`]) or, oldest first, when a new job
+/// would exceed [`MAX_RETAINED_JOBS`] or [`MAX_RETAINED_ROWS`].
+pub struct BatchValidationUseCases<R: AddressRepository + 'static> {
+    address_use_cases: Arc<AddressUseCases<R>>,
+    jobs: JobStore,
+    workers: Arc<Semaphore>,
+}
+
+impl<R: AddressRepository + 'static> BatchValidationUseCases<R> {
+    pub fn new(address_use_cases: Arc<AddressUseCases<R>>) -> Self {
+        Self {
+            address_use_cases,
+            jobs: Arc::new(RwLock::new(HashMap::new())),
+            workers: Arc::new(Semaphore::new(JOB_WORKERS)),
+        }
+    }
+
+    /// Drop expired jobs periodically, so their results do not outlive the
+    /// retention window when no new batches arrive
+    pub fn spawn_purge(&self) -> tokio::task::JoinHandle<()> {
+        let jobs = self.jobs.clone();
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(JOB_PURGE_INTERVAL_SECS));
+            loop {
+                interval.tick().await;
+                purge_expired_jobs(&jobs).await;
+            }
+        })
+    }
+
+    /// Validate a batch inline, or queue it as a background job if it is large
+    pub async fn submit(&self, rows: Vec<BatchRow>) -> AppResult<BatchSubmission> {
+        if rows.is_empty() {
+            return Err(AppError::BadRequest(
+                "Batch contains no addresses".to_string(),
+            ));
+        }
+
+        if rows.len() > MAX_BATCH_ROWS {
+            return Err(AppError::BadRequest(format!(
+                "Batch contains {} addresses; the maximum is {}",
+                rows.len(),
+                MAX_BATCH_ROWS
+            )));
+        }
+
+        if rows.len() <= SYNC_BATCH_LIMIT {
+            let results = validate_rows(&self.address_use_cases, 0, rows).await;
+            let mut summary = BatchValidationSummaryDto {
+                total: results.len(),
+                ..Default::default()
+            };
+            results.iter().for_each(|item| summary.record(item));
+
+            return Ok(BatchSubmission::Completed(BatchValidationResponseDto {
+                summary,
+                results,
+            }));
+        }
+
+        let job = BatchJob::new(rows.len());
+        let job_id = job.id;
+        let dto = job.to_dto();
+        {
+            let mut jobs = self.jobs.write().await;
+            let pending = jobs
+                .values()
+                .filter(|job| job.completed_at.is_none())
+                .count();
+            if pending >= MAX_PENDING_JOBS {
+                return Err(AppError::ServiceUnavailable(format!(
+                    "{} batch jobs are already waiting or running; try again later",
+                    pending
+                )));
+            }
+            make_room(&mut jobs, job.summary.total)?;
+            jobs.insert(job_id, job);
+        }
+
+        let address_use_cases = self.address_use_cases.clone();
+        let jobs = self.jobs.clone();
+        let workers = self.workers.clone();
+        tokio::spawn(async move {
+            // The job stays queued until a worker is free
+            let Ok(_permit) = workers.acquire_owned().await else {
+                return;
+            };
+            let worker = tokio::spawn(run_job(address_use_cases, jobs.clone(), job_id, rows));
+
+            // A panicking worker must not leave the job stuck in `running`
+            if let Err(e) = worker.await {
+                tracing::error!("Batch job {} aborted: {}", job_id, e);
+                if let Some(job) = jobs.write().await.get_mut(&job_id) {
+                    job.finish(
+                        BatchJobStatus::Failed,
+                        Some("Batch job aborted unexpectedly".to_string()),
+                    );
+                }
+            }
+        });
+
+        Ok(BatchSubmission::Queued(dto))
+    }
+
+    /// Get the status of a background job
+    pub async fn get_job(&self, job_id: Uuid) -> AppResult<BatchJobDto> {
+        self.jobs
+            .read()
+            .await
+            .get(&job_id)
+            .map(BatchJob::to_dto)
+            .ok_or_else(|| AppError::NotFound(format!("Batch job {} not found", job_id)))
+    }
+
+    /// Render the results of a completed job
+    pub async fn export_results(&self, job_id: Uuid, format: BatchFormat) -> AppResult<Vec<u8>> {
+        let results = {
+            let jobs = self.jobs.read().await;
+            let job = jobs
+                .get(&job_id)
+                .ok_or_else(|| AppError::NotFound(format!("Batch job {} not found", job_id)))?;
+            finished_results(job)?
+        };
+
+        // Rendered without the lock, so workers can keep publishing progress
+        format.render(&results)
+    }
+}
+
+/// The results of a job that completed
+fn finished_results(job: &BatchJob) -> AppResult<Arc<Vec<BatchValidationItemDto>>> {
+    let job_id = job.id;
+    match job.status {
+        BatchJobStatus::Completed => Ok(job.results.clone()),
+        BatchJobStatus::Failed => Err(AppError::Conflict(format!(
+            "Batch job {} failed: {}",
+            job_id,
+            job.error.as_deref().unwrap_or("unknown error")
+        ))),
+        BatchJobStatus::Queued | BatchJobStatus::Running => Err(AppError::Conflict(format!(
+            "Batch job {} has not finished ({} of {} rows processed)",
+            job_id, job.processed, job.summary.total
+        ))),
+    }
+}
+
+/// Drop finished jobs older than the retention window
+async fn purge_expired_jobs(jobs: &JobStore) {
+    let cutoff = Utc::now() - Duration::hours(JOB_RETENTION_HOURS);
+    jobs.write()
+        .await
+        .retain(|_, job| job.completed_at.is_none_or(|completed| completed > cutoff));
+}
+
+/// Drop the oldest finished jobs until a new job of `rows` rows fits within
+/// [`MAX_RETAINED_JOBS`] and [`MAX_RETAINED_ROWS`]; refused when unfinished
+/// jobs alone leave no room
+fn make_room(jobs: &mut HashMap<Uuid, BatchJob>, rows: usize) -> AppResult<()> {
+    let mut retained: usize = jobs.values().map(|job| job.summary.total).sum();
+    while jobs.len() >= MAX_RETAINED_JOBS || retained + rows > MAX_RETAINED_ROWS {
+        let oldest = jobs
+            .values()
+            .filter_map(|job| job.completed_at.map(|completed| (completed, job.id)))
+            .min();
+        let Some((_, job_id)) = oldest else {
+            return Err(AppError::ServiceUnavailable(format!(
+                "Batch jobs already hold {} rows; try again later",
+                retained
+            )));
+        };
+        if let Some(job) = jobs.remove(&job_id) {
+            tracing::info!("Batch job {} dropped early to make room", job_id);
+            retained -= job.summary.total;
+        }
+    }
+    Ok(())
+}
+
+/// Process a queued job in chunks, publishing progress after each chunk
+async fn run_job<R: AddressRepository + 'static>(
+    address_use_cases: Arc<AddressUseCases<R>>,
+    jobs: JobStore,
+    job_id: Uuid,
+    rows: Vec<BatchRow>,
+) {
+    if let Some(job) = jobs.write().await.get_mut(&job_id) {
+        job.status = BatchJobStatus::Running;
+    }
+
+    let mut processed = 0;
+    let mut rows = rows.into_iter();
+    loop {
+        let chunk: Vec<BatchRow> = rows.by_ref().take(PROGRESS_CHUNK_SIZE).collect();
+        if chunk.is_empty() {
+            break;
+        }
+
+        let chunk_len = chunk.len();
+        let results = validate_rows(&address_use_cases, processed, chunk).await;
+        processed += chunk_len;
+
+        let mut jobs = jobs.write().await;
+        let Some(job) = jobs.get_mut(&job_id) else {
+            return;
+        };
+        let job_results =
+            Arc::get_mut(&mut job.results).expect("results are only shared once the job completes");
+        for item in results {
+            job.summary.record(&item);
+            job_results.push(item);
+        }
+        job.processed = processed;
+    }
+
+    if let Some(job) = jobs.write().await.get_mut(&job_id) {
+        job.finish(BatchJobStatus::Completed, None);
+    }
+    tracing::info!("Batch job {} completed ({} rows)", job_id, processed);
+}
+
+/// Validate rows concurrently, preserving submission order.
+/// `offset` is the number of rows of the batch already processed.
+async fn validate_rows<R: AddressRepository>(
+    address_use_cases: &AddressUseCases<R>,
+    offset: usize,
+    rows: Vec<BatchRow>,
+) -> Vec<BatchValidationItemDto> {
+    stream::iter(rows.into_iter().enumerate())
+        .map(|(index, row)| validate_row(address_use_cases, offset + index + 1, row))
+        .buffered(VALIDATION_CONCURRENCY)
+        .collect()
+        .await
+}
+
+async fn validate_row<R: AddressRepository>(
+    address_use_cases: &AddressUseCases<R>,
+    row: usize,
+    input: BatchRow,
+) -> BatchValidationItemDto {
+    let input = match input {
+        Ok(input) => input,
+        Err(error) => {
+            return BatchValidationItemDto {
+                row,
+                input: None,
+                valid: false,
+                canonical: None,
+                suggestions: vec![],
+                error: Some(error),
+            }
+        }
+    };
+
+    match address_use_cases
+        .validate_address(input.clone().into())
+        .await
+    {
+        Ok(response) => BatchValidationItemDto {
+            row,
+            input: Some(input),
+            valid: response.valid,
+            canonical: response.canonical,
+            suggestions: response.suggestions,
+            error: None,
+        },
+        Err(e) => {
+            // Internal causes are logged, not written into results clients read
+            let error = e.client_detail().unwrap_or_else(|| {
+                tracing::error!("Batch row {} could not be validated: {}", row, e);
+                "The address could not be validated".to_string()
+            });
+            BatchValidationItemDto {
+                row,
+                input: Some(input),
+                valid: false,
+                canonical: None,
+                suggestions: vec![],
+                error: Some(error),
+            }
+        }
+    }
+}
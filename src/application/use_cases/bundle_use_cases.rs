@@ -0,0 +1,192 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+
+use chrono::Utc;
+use flate2::{write::GzEncoder, Compression};
+use sha2::{Digest, Sha256};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::application::dtos::bundle_dto::{BundleCountsDto, BundleManifestDto};
+use crate::domain::entities::bundle::{BundleContents, BUNDLE_SCHEMA_VERSION};
+use crate::domain::entities::sync::SyncToken;
+use crate::domain::repositories::bundle_repository::BundleRepository;
+use crate::errors::{AppError, AppResult};
+
+/// A compressed bundle on disk, removed once nothing refers to it
+pub struct BundleFile {
+    pub path: PathBuf,
+    /// Data fingerprint of the snapshot bundled
+    pub fingerprint: String,
+    pub manifest: BundleManifestDto,
+}
+
+impl Drop for BundleFile {
+    fn drop(&mut self) {
+        // Responses still streaming the file hold it open
+        if let Err(e) = std::fs::remove_file(&self.path) {
+            warn!("Failed to remove bundle {}: {}", self.path.display(), e);
+        }
+    }
+}
+
+/// Offline bundle use cases
+///
+/// The bundle is a gzip-compressed SQLite database of every active unit and
+/// alias, with a full-text search index, for the mobile SDKs to use without
+/// a connection. It is built once per data fingerprint and served from disk.
+#[derive(Clone)]
+pub struct BundleUseCases<R: BundleRepository + Clone> {
+    bundle_repository: R,
+    directory: PathBuf,
+    latest: Arc<RwLock<Option<Arc<BundleFile>>>>,
+    build: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl<R: BundleRepository + Clone> BundleUseCases<R> {
+    pub fn new(bundle_repository: R) -> Self {
+        Self {
+            bundle_repository,
+            directory: std::env::temp_dir().join("nigeria-geo-api-bundles"),
+            latest: Arc::new(RwLock::new(None)),
+            build: Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    /// The bundle of the data as it is now, built on first request and again
+    /// after the data changes
+    pub async fn latest(&self) -> AppResult<Arc<BundleFile>> {
+        let fingerprint = self.bundle_repository.data_fingerprint().await?;
+        if let Some(bundle) = self.cached_bundle(&fingerprint) {
+            return Ok(bundle);
+        }
+
+        // One build at a time; requests that waited get the bundle just built
+        let _building = self.build.lock().await;
+        if let Some(bundle) = self.cached_bundle(&fingerprint) {
+            return Ok(bundle);
+        }
+
+        let started = Instant::now();
+        tokio::fs::create_dir_all(&self.directory).await?;
+        let id = Uuid::new_v4();
+        let database = self.directory.join(format!("bundle-{}.sqlite", id));
+        let path = self.directory.join(format!("bundle-{}.sqlite.gz", id));
+
+        let built = self.build_bundle(&database, &path).await;
+        let _ = tokio::fs::remove_file(&database).await;
+        let (contents, compressed) = match built {
+            Ok(built) => built,
+            Err(e) => {
+                let _ = tokio::fs::remove_file(&path).await;
+                return Err(e);
+            }
+        };
+        info!(
+            "Built bundle {} in {:?}: {} bytes, {} compressed",
+            contents.version,
+            started.elapsed(),
+            compressed.uncompressed_size,
+            compressed.size
+        );
+
+        let bundle = Arc::new(BundleFile {
+            path,
+            fingerprint: contents.fingerprint,
+            manifest: BundleManifestDto {
+                version: contents.version,
+                schema_version: BUNDLE_SCHEMA_VERSION,
+                created_at: Utc::now(),
+                size: compressed.size,
+                sha256: compressed.sha256,
+                uncompressed_size: compressed.uncompressed_size,
+                sync_token: SyncToken::new(contents.watermark).encode(),
+                counts: BundleCountsDto {
+                    states: contents.states,
+                    lgas: contents.lgas,
+                    wards: contents.wards,
+                    postal_codes: contents.postal_codes,
+                    aliases: contents.aliases,
+                },
+            },
+        });
+        *self.latest.write().expect("bundle lock poisoned") = Some(bundle.clone());
+        Ok(bundle)
+    }
+
+    /// Write the SQLite database to `database`, then its compressed copy to
+    /// `path`
+    async fn build_bundle(
+        &self,
+        database: &Path,
+        path: &Path,
+    ) -> AppResult<(BundleContents, Compressed)> {
+        let contents = self.bundle_repository.write_bundle(database).await?;
+
+        let (database, path) = (database.to_path_buf(), path.to_path_buf());
+        let compressed = tokio::task::spawn_blocking(move || compress(&database, &path))
+            .await
+            .map_err(|e| AppError::Internal(e.into()))??;
+
+        Ok((contents, compressed))
+    }
+
+    fn cached_bundle(&self, fingerprint: &str) -> Option<Arc<BundleFile>> {
+        self.latest
+            .read()
+            .expect("bundle lock poisoned")
+            .as_ref()
+            .filter(|bundle| bundle.fingerprint == fingerprint)
+            .cloned()
+    }
+}
+
+/// Size and checksum of a compressed bundle
+struct Compressed {
+    size: u64,
+    sha256: String,
+    uncompressed_size: u64,
+}
+
+/// Gzip `source` into `target`, hashing the output as it is written
+fn compress(source: &Path, target: &Path) -> std::io::Result<Compressed> {
+    let mut output = HashingWriter {
+        inner: BufWriter::new(File::create(target)?),
+        sha256: Sha256::new(),
+        bytes: 0,
+    };
+
+    let mut encoder = GzEncoder::new(&mut output, Compression::best());
+    let uncompressed_size = std::io::copy(&mut File::open(source)?, &mut encoder)?;
+    encoder.finish()?;
+    output.flush()?;
+
+    Ok(Compressed {
+        size: output.bytes,
+        sha256: format!("{:x}", output.sha256.finalize()),
+        uncompressed_size,
+    })
+}
+
+/// Passes writes through, keeping a running SHA-256 and byte count
+struct HashingWriter<W: Write> {
+    inner: W,
+    sha256: Sha256,
+    bytes: u64,
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.sha256.update(&buf[..written]);
+        self.bytes += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+use validator::Validate;
+
+use crate::application::dtos::{
+    ChangeCountsDto, DatasetVersionDetailDto, DatasetVersionDto, EntityDiffDto,
+};
+use crate::domain::entities::dataset_version::{CreateDatasetVersionRequest, DatasetVersion};
+use crate::domain::repositories::dataset_version_repository::DatasetVersionRepository;
+use crate::domain::repositories::history_repository::HistoryRepository;
+use crate::errors::{AppError, AppResult};
+
+/// Dataset release use cases
+#[derive(Clone)]
+pub struct DatasetVersionUseCases<V, H>
+where
+    V: DatasetVersionRepository + Clone,
+    H: HistoryRepository + Clone,
+{
+    version_repository: V,
+    history_repository: H,
+    /// Changelog counts by release ID; releases never change, so neither do
+    /// these
+    change_counts: Arc<RwLock<HashMap<i32, ChangeCountsDto>>>,
+}
+
+impl<V, H> DatasetVersionUseCases<V, H>
+where
+    V: DatasetVersionRepository + Clone,
+    H: HistoryRepository + Clone,
+{
+    pub fn new(version_repository: V, history_repository: H) -> Self {
+        Self {
+            version_repository,
+            history_repository,
+            change_counts: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Release the live data as it is now
+    pub async fn release(
+        &self,
+        request: CreateDatasetVersionRequest,
+    ) -> AppResult<DatasetVersionDto> {
+        request.validate()?;
+
+        let version = self.version_repository.release(&request).await?;
+        self.to_dto(version).await
+    }
+
+    /// All releases, newest first
+    pub async fn list_versions(&self) -> AppResult<Vec<DatasetVersionDto>> {
+        let versions = self.version_repository.list().await?;
+
+        let mut dtos = Vec::with_capacity(versions.len());
+        for version in versions {
+            dtos.push(self.to_dto(version).await?);
+        }
+        Ok(dtos)
+    }
+
+    /// A release with the changes made since the one before it
+    pub async fn get_version(&self, name: &str) -> AppResult<DatasetVersionDetailDto> {
+        let version = self.find_by_name(name).await?;
+        let entities = self.changelog(&version).await?;
+        let counts = ChangeCountsDto::of(&entities);
+
+        Ok(DatasetVersionDetailDto {
+            version: DatasetVersionDto::new(version, counts),
+            entities,
+        })
+    }
+
+    /// The release named `name`
+    pub async fn find_by_name(&self, name: &str) -> AppResult<DatasetVersion> {
+        self.version_repository
+            .find_by_name(name)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Dataset version '{}' not found", name)))
+    }
+
+    /// The release that was current at `at`
+    pub async fn find_as_of(&self, at: DateTime<Utc>) -> AppResult<DatasetVersion> {
+        self.version_repository
+            .find_as_of(at)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!(
+                    "No dataset version was released at or before {}",
+                    at.to_rfc3339()
+                ))
+            })
+    }
+
+    async fn changelog(&self, version: &DatasetVersion) -> AppResult<Vec<EntityDiffDto>> {
+        let diffs = self
+            .history_repository
+            .diff_between_ids(version.previous_history_id, version.history_id)
+            .await?;
+        Ok(EntityDiffDto::from_diffs(diffs))
+    }
+
+    async fn to_dto(&self, version: DatasetVersion) -> AppResult<DatasetVersionDto> {
+        let cached = self.change_counts.read().await.get(&version.id).copied();
+        let counts = match cached {
+            Some(counts) => counts,
+            None => {
+                let counts = ChangeCountsDto::of(&self.changelog(&version).await?);
+                self.change_counts.write().await.insert(version.id, counts);
+                counts
+            }
+        };
+
+        Ok(DatasetVersionDto::new(version, counts))
+    }
+}
@@ -0,0 +1,348 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+
+use futures::StreamExt;
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncWrite, AsyncWriteExt, BufWriter};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::application::dtos::export_dto::ExportFormat;
+use crate::domain::entities::export::{ExportRow, ExportSnapshot};
+use crate::domain::entities::unit_level::UnitLevel;
+use crate::domain::repositories::export_repository::ExportRepository;
+use crate::errors::{AppError, AppResult};
+
+/// Columns of an export of `level`: the unit's ID, then the code and name of
+/// each level down to it, then a postal code's own fields
+pub fn export_columns(level: UnitLevel) -> &'static [&'static str] {
+    const COLUMNS: [&str; 11] = [
+        "id",
+        "state_code",
+        "state_name",
+        "lga_code",
+        "lga_name",
+        "ward_code",
+        "ward_name",
+        "postal_code",
+        "lat",
+        "lng",
+        "urban",
+    ];
+
+    match level {
+        UnitLevel::State => &COLUMNS[..3],
+        UnitLevel::Lga => &COLUMNS[..5],
+        UnitLevel::Ward => &COLUMNS[..7],
+        UnitLevel::PostalCode => &COLUMNS,
+    }
+}
+
+/// What writing an export produced
+#[derive(Debug, Clone)]
+pub struct ExportSummary {
+    /// Data fingerprint of the snapshot exported
+    pub fingerprint: String,
+    pub rows: u64,
+    pub bytes: u64,
+    /// SHA-256 of the output, in hex
+    pub sha256: String,
+}
+
+/// An export written to disk, removed once nothing refers to it
+pub struct ExportFile {
+    pub format: ExportFormat,
+    pub level: UnitLevel,
+    pub path: PathBuf,
+    pub summary: ExportSummary,
+}
+
+impl Drop for ExportFile {
+    fn drop(&mut self) {
+        // Responses still streaming the file hold it open
+        if let Err(e) = std::fs::remove_file(&self.path) {
+            warn!("Failed to remove export {}: {}", self.path.display(), e);
+        }
+    }
+}
+
+/// Latest export of each format and level
+type ExportFiles = HashMap<(ExportFormat, UnitLevel), Arc<ExportFile>>;
+
+/// Full dataset export use cases
+///
+/// An export is written to disk once per data fingerprint, format and level,
+/// then served from there, so its checksum is known before the first byte is
+/// sent and the dataset is never held in memory.
+#[derive(Clone)]
+pub struct ExportUseCases<R: ExportRepository + Clone> {
+    export_repository: R,
+    directory: PathBuf,
+    files: Arc<RwLock<ExportFiles>>,
+    build: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl<R: ExportRepository + Clone> ExportUseCases<R> {
+    pub fn new(export_repository: R) -> Self {
+        Self {
+            export_repository,
+            directory: std::env::temp_dir().join("nigeria-geo-api-exports"),
+            files: Arc::new(RwLock::new(HashMap::new())),
+            build: Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    /// The export of `level` in `format` as the data is now, written on
+    /// first request and again after the data changes
+    pub async fn export_file(
+        &self,
+        format: ExportFormat,
+        level: UnitLevel,
+    ) -> AppResult<Arc<ExportFile>> {
+        let fingerprint = self.export_repository.data_fingerprint().await?;
+        if let Some(file) = self.cached_file(format, level, &fingerprint) {
+            return Ok(file);
+        }
+
+        // One export at a time; requests that waited get the file just written
+        let _building = self.build.lock().await;
+        if let Some(file) = self.cached_file(format, level, &fingerprint) {
+            return Ok(file);
+        }
+
+        let started = Instant::now();
+        tokio::fs::create_dir_all(&self.directory).await?;
+        let path = self.directory.join(format!(
+            "{}-{}.{}",
+            level.as_str(),
+            Uuid::new_v4(),
+            format.extension()
+        ));
+
+        let written = async {
+            let file = tokio::fs::File::create(&path).await?;
+            let mut writer = BufWriter::new(file);
+            let summary = self.write(format, level, &mut writer).await?;
+            writer.shutdown().await?;
+            Ok::<_, AppError>(summary)
+        }
+        .await;
+        let summary = match written {
+            Ok(summary) => summary,
+            Err(e) => {
+                let _ = tokio::fs::remove_file(&path).await;
+                return Err(e);
+            }
+        };
+        info!(
+            "Exported {} {} rows as {} in {:?}: {} bytes",
+            summary.rows,
+            level.as_str(),
+            format.extension(),
+            started.elapsed(),
+            summary.bytes
+        );
+
+        let file = Arc::new(ExportFile {
+            format,
+            level,
+            path,
+            summary,
+        });
+        self.files
+            .write()
+            .expect("export files lock poisoned")
+            .insert((format, level), file.clone());
+        Ok(file)
+    }
+
+    /// Write a fresh export of `level` in `format` to `writer`
+    pub async fn write<W: AsyncWrite + Unpin>(
+        &self,
+        format: ExportFormat,
+        level: UnitLevel,
+        writer: &mut W,
+    ) -> AppResult<ExportSummary> {
+        let snapshot = self.export_repository.export(level).await?;
+        write_export(snapshot, format, writer).await
+    }
+
+    fn cached_file(
+        &self,
+        format: ExportFormat,
+        level: UnitLevel,
+        fingerprint: &str,
+    ) -> Option<Arc<ExportFile>> {
+        self.files
+            .read()
+            .expect("export files lock poisoned")
+            .get(&(format, level))
+            .filter(|file| file.summary.fingerprint == fingerprint)
+            .cloned()
+    }
+}
+
+/// Encode `snapshot` into `writer`, hashing the output as it goes
+async fn write_export<W: AsyncWrite + Unpin>(
+    mut snapshot: ExportSnapshot,
+    format: ExportFormat,
+    writer: &mut W,
+) -> AppResult<ExportSummary> {
+    let mut encoder = ExportEncoder::new(format, snapshot.level);
+    let mut sha256 = Sha256::new();
+    let mut rows = 0;
+    let mut bytes = 0;
+
+    let mut emit = |chunk: Vec<u8>| {
+        sha256.update(&chunk);
+        bytes += chunk.len() as u64;
+        chunk
+    };
+
+    writer.write_all(&emit(encoder.header()?)).await?;
+    while let Some(row) = snapshot.rows.next().await {
+        writer.write_all(&emit(encoder.row(&row?)?)).await?;
+        rows += 1;
+    }
+    writer.write_all(&emit(encoder.footer())).await?;
+    writer.flush().await?;
+
+    Ok(ExportSummary {
+        fingerprint: snapshot.fingerprint,
+        rows,
+        bytes,
+        sha256: format!("{:x}", sha256.finalize()),
+    })
+}
+
+/// Encodes export rows one at a time
+struct ExportEncoder {
+    format: ExportFormat,
+    columns: &'static [&'static str],
+    first: bool,
+}
+
+impl ExportEncoder {
+    fn new(format: ExportFormat, level: UnitLevel) -> Self {
+        Self {
+            format,
+            columns: export_columns(level),
+            first: true,
+        }
+    }
+
+    fn header(&self) -> AppResult<Vec<u8>> {
+        Ok(match self.format {
+            ExportFormat::Csv => csv_record(self.columns.iter().copied())?,
+            ExportFormat::Json => b"[".to_vec(),
+            ExportFormat::Ndjson => Vec::new(),
+            ExportFormat::GeoJson => br#"{"type":"FeatureCollection","features":["#.to_vec(),
+        })
+    }
+
+    fn row(&mut self, row: &ExportRow) -> AppResult<Vec<u8>> {
+        let fields = self.fields(row);
+        let separator = if self.first { "\n" } else { ",\n" };
+        self.first = false;
+
+        Ok(match self.format {
+            ExportFormat::Csv => csv_record(
+                self.columns
+                    .iter()
+                    .map(|&column| csv_field(&fields[column])),
+            )?,
+            ExportFormat::Json => {
+                let mut chunk = separator.as_bytes().to_vec();
+                serde_json::to_writer(&mut chunk, &fields)?;
+                chunk
+            }
+            ExportFormat::Ndjson => {
+                let mut chunk = serde_json::to_vec(&fields)?;
+                chunk.push(b'\n');
+                chunk
+            }
+            ExportFormat::GeoJson => {
+                let mut chunk = separator.as_bytes().to_vec();
+                serde_json::to_writer(&mut chunk, &feature(fields))?;
+                chunk
+            }
+        })
+    }
+
+    fn footer(&self) -> Vec<u8> {
+        match self.format {
+            ExportFormat::Csv | ExportFormat::Ndjson => Vec::new(),
+            ExportFormat::Json => b"\n]\n".to_vec(),
+            ExportFormat::GeoJson => b"\n]}\n".to_vec(),
+        }
+    }
+
+    /// The row's values for this export's columns
+    ///
+    /// The map is keyed in sorted order, so CSV rows are read back from it in
+    /// column order.
+    fn fields(&self, row: &ExportRow) -> Map<String, Value> {
+        self.columns
+            .iter()
+            .map(|&column| {
+                let value = match column {
+                    "id" => json!(row.id),
+                    "state_code" => json!(row.state_code),
+                    "state_name" => json!(row.state_name),
+                    "lga_code" => json!(row.lga_code),
+                    "lga_name" => json!(row.lga_name),
+                    "ward_code" => json!(row.ward_code),
+                    "ward_name" => json!(row.ward_name),
+                    "postal_code" => json!(row.postal_code),
+                    "lat" => json!(row.lat),
+                    "lng" => json!(row.lng),
+                    "urban" => json!(row.urban),
+                    _ => Value::Null,
+                };
+                (column.to_string(), value)
+            })
+            .collect()
+    }
+}
+
+/// A GeoJSON feature with the row's coordinates as a point, if it has any
+fn feature(mut properties: Map<String, Value>) -> Value {
+    let lat = properties.remove("lat").and_then(|lat| lat.as_f64());
+    let lng = properties.remove("lng").and_then(|lng| lng.as_f64());
+    let geometry = match (lat, lng) {
+        (Some(lat), Some(lng)) => json!({"type": "Point", "coordinates": [lng, lat]}),
+        _ => Value::Null,
+    };
+
+    json!({
+        "type": "Feature",
+        "id": properties["id"],
+        "geometry": geometry,
+        "properties": properties,
+    })
+}
+
+fn csv_field(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn csv_record<I, T>(fields: I) -> AppResult<Vec<u8>>
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]>,
+{
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(fields)
+        .map_err(|e| AppError::Internal(anyhow::anyhow!(e)))?;
+    writer
+        .into_inner()
+        .map_err(|e| AppError::Internal(anyhow::anyhow!(e.to_string())))
+}
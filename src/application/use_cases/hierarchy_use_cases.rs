@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+
+use bytes::Bytes;
+use flate2::{write::GzEncoder, Compression};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::application::dtos::hierarchy_dto::{
+    Include, LgaNodeDto, PostalCodeNodeDto, StateNodeDto, WardNodeDto,
+};
+use crate::application::dtos::{LgaDto, PostalCodeDto, StateDto, WardDto};
+use crate::domain::repositories::{
+    lga_repository::LgaRepository, postal_code_repository::PostalCodeRepository,
+    search_repository::SearchRepository, state_repository::StateRepository,
+    ward_repository::WardRepository,
+};
+use crate::errors::{AppError, AppResult};
+
+/// Every state with its LGAs, wards and postal codes, serialized once
+pub struct CountryTree {
+    /// Data fingerprint the tree was built from
+    pub fingerprint: String,
+    /// The tree as JSON
+    pub json: Bytes,
+    /// The same JSON, gzip-compressed
+    pub gzip: Bytes,
+}
+
+/// Hierarchy use cases: nesting related entities into responses
+///
+/// Each level of descendants is loaded with one query for all parents at
+/// once, so a state with `include=lgas.wards.postal_codes` costs three
+/// queries however many LGAs and wards it has.
+#[derive(Clone)]
+pub struct HierarchyUseCases<
+    SR: StateRepository + Clone,
+    LR: LgaRepository + Clone,
+    WR: WardRepository + Clone,
+    PR: PostalCodeRepository + Clone,
+    XR: SearchRepository + Clone,
+> {
+    state_repository: SR,
+    lga_repository: LR,
+    ward_repository: WR,
+    postal_code_repository: PR,
+    search_repository: XR,
+    tree: Arc<RwLock<Option<Arc<CountryTree>>>>,
+    tree_build: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl<
+        SR: StateRepository + Clone,
+        LR: LgaRepository + Clone,
+        WR: WardRepository + Clone,
+        PR: PostalCodeRepository + Clone,
+        XR: SearchRepository + Clone,
+    > HierarchyUseCases<SR, LR, WR, PR, XR>
+{
+    pub fn new(
+        state_repository: SR,
+        lga_repository: LR,
+        ward_repository: WR,
+        postal_code_repository: PR,
+        search_repository: XR,
+    ) -> Self {
+        Self {
+            state_repository,
+            lga_repository,
+            ward_repository,
+            postal_code_repository,
+            search_repository,
+            tree: Arc::new(RwLock::new(None)),
+            tree_build: Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    /// Add the descendants asked for by `include` to a state
+    pub async fn expand_state(
+        &self,
+        state: StateDto,
+        include: &Include,
+    ) -> AppResult<StateNodeDto> {
+        let mut nodes = self.state_nodes(vec![state], include.depth).await?;
+        Ok(nodes.remove(0))
+    }
+
+    /// Add the state and descendants asked for by `include` to an LGA
+    pub async fn expand_lga(&self, lga: LgaDto, include: &Include) -> AppResult<LgaNodeDto> {
+        let state = if include.state {
+            self.state(lga.state_id).await?
+        } else {
+            None
+        };
+
+        let mut node = self.lga_nodes(vec![lga], include.depth).await?.remove(0);
+        node.state = state;
+        Ok(node)
+    }
+
+    /// Add the ancestors and descendants asked for by `include` to a ward
+    pub async fn expand_ward(&self, ward: WardDto, include: &Include) -> AppResult<WardNodeDto> {
+        let lga = if include.lga || include.state {
+            self.lga(ward.lga_id).await?
+        } else {
+            None
+        };
+        let state = match (&lga, include.state) {
+            (Some(lga), true) => self.state(lga.state_id).await?,
+            _ => None,
+        };
+
+        let mut node = self.ward_nodes(vec![ward], include.depth).await?.remove(0);
+        node.lga = lga.filter(|_| include.lga);
+        node.state = state;
+        Ok(node)
+    }
+
+    /// Add the ancestors asked for by `include` to a postal code
+    pub async fn expand_postal_code(
+        &self,
+        postal_code: PostalCodeDto,
+        include: &Include,
+    ) -> AppResult<PostalCodeNodeDto> {
+        let ward = if include.ward || include.lga || include.state {
+            self.ward(postal_code.ward_id).await?
+        } else {
+            None
+        };
+        let lga = match (&ward, include.lga || include.state) {
+            (Some(ward), true) => self.lga(ward.lga_id).await?,
+            _ => None,
+        };
+        let state = match (&lga, include.state) {
+            (Some(lga), true) => self.state(lga.state_id).await?,
+            _ => None,
+        };
+
+        Ok(PostalCodeNodeDto {
+            postal_code,
+            state,
+            lga: lga.filter(|_| include.lga),
+            ward: ward.filter(|_| include.ward),
+        })
+    }
+
+    /// The whole country hierarchy, rebuilt when the data has changed since
+    /// the last request
+    pub async fn tree(&self) -> AppResult<Arc<CountryTree>> {
+        let fingerprint = self.search_repository.data_fingerprint().await?;
+        if let Some(tree) = self.cached_tree(&fingerprint) {
+            return Ok(tree);
+        }
+
+        // One build at a time; requests that waited get the tree just built
+        let _building = self.tree_build.lock().await;
+        if let Some(tree) = self.cached_tree(&fingerprint) {
+            return Ok(tree);
+        }
+
+        let started = Instant::now();
+        let states = self
+            .state_repository
+            .list_all()
+            .await?
+            .into_iter()
+            .map(StateDto::from)
+            .collect();
+        let nodes = self.state_nodes(states, 3).await?;
+
+        let tree = tokio::task::spawn_blocking(move || serialize_tree(&nodes, fingerprint))
+            .await
+            .map_err(|e| AppError::Internal(anyhow::anyhow!(e)))??;
+        info!(
+            "Country tree built in {:?}: {} bytes, {} gzipped",
+            started.elapsed(),
+            tree.json.len(),
+            tree.gzip.len()
+        );
+
+        let tree = Arc::new(tree);
+        *self.tree.write().expect("country tree lock poisoned") = Some(tree.clone());
+        Ok(tree)
+    }
+
+    fn cached_tree(&self, fingerprint: &str) -> Option<Arc<CountryTree>> {
+        self.tree
+            .read()
+            .expect("country tree lock poisoned")
+            .clone()
+            .filter(|tree| tree.fingerprint == fingerprint)
+    }
+
+    async fn state_nodes(
+        &self,
+        states: Vec<StateDto>,
+        depth: usize,
+    ) -> AppResult<Vec<StateNodeDto>> {
+        let mut lgas = match depth {
+            0 => None,
+            _ => {
+                let ids: Vec<Uuid> = states.iter().map(|s| s.id).collect();
+                let lgas = self.lga_repository.find_by_state_ids(&ids).await?;
+                let nodes = self
+                    .lga_nodes(lgas.into_iter().map(LgaDto::from).collect(), depth - 1)
+                    .await?;
+                Some(group_by(nodes, |node| node.lga.state_id))
+            }
+        };
+
+        Ok(states
+            .into_iter()
+            .map(|state| StateNodeDto {
+                lgas: lgas
+                    .as_mut()
+                    .map(|lgas| lgas.remove(&state.id).unwrap_or_default()),
+                state,
+            })
+            .collect())
+    }
+
+    async fn lga_nodes(&self, lgas: Vec<LgaDto>, depth: usize) -> AppResult<Vec<LgaNodeDto>> {
+        let mut wards = match depth {
+            0 => None,
+            _ => {
+                let ids: Vec<Uuid> = lgas.iter().map(|l| l.id).collect();
+                let wards = self.ward_repository.find_by_lga_ids(&ids).await?;
+                let nodes = self
+                    .ward_nodes(wards.into_iter().map(WardDto::from).collect(), depth - 1)
+                    .await?;
+                Some(group_by(nodes, |node| node.ward.lga_id))
+            }
+        };
+
+        Ok(lgas
+            .into_iter()
+            .map(|lga| LgaNodeDto {
+                state: None,
+                wards: wards
+                    .as_mut()
+                    .map(|wards| wards.remove(&lga.id).unwrap_or_default()),
+                lga,
+            })
+            .collect())
+    }
+
+    async fn ward_nodes(&self, wards: Vec<WardDto>, depth: usize) -> AppResult<Vec<WardNodeDto>> {
+        let mut postal_codes = match depth {
+            0 => None,
+            _ => {
+                let ids: Vec<Uuid> = wards.iter().map(|w| w.id).collect();
+                let postal_codes = self.postal_code_repository.find_by_ward_ids(&ids).await?;
+                Some(group_by(
+                    postal_codes.into_iter().map(PostalCodeDto::from).collect(),
+                    |postal_code| postal_code.ward_id,
+                ))
+            }
+        };
+
+        Ok(wards
+            .into_iter()
+            .map(|ward| WardNodeDto {
+                state: None,
+                lga: None,
+                postal_codes: postal_codes
+                    .as_mut()
+                    .map(|codes| codes.remove(&ward.id).unwrap_or_default()),
+                ward,
+            })
+            .collect())
+    }
+
+    async fn state(&self, id: Uuid) -> AppResult<Option<StateDto>> {
+        Ok(self.state_repository.find_by_id(id).await?.map(Into::into))
+    }
+
+    async fn lga(&self, id: Uuid) -> AppResult<Option<LgaDto>> {
+        Ok(self.lga_repository.find_by_id(id).await?.map(Into::into))
+    }
+
+    async fn ward(&self, id: Uuid) -> AppResult<Option<WardDto>> {
+        Ok(self.ward_repository.find_by_id(id).await?.map(Into::into))
+    }
+}
+
+/// Group items by their parent's ID, keeping their order within each group
+fn group_by<T>(items: Vec<T>, parent_id: impl Fn(&T) -> Uuid) -> HashMap<Uuid, Vec<T>> {
+    let mut groups: HashMap<Uuid, Vec<T>> = HashMap::new();
+    for item in items {
+        groups.entry(parent_id(&item)).or_default().push(item);
+    }
+    groups
+}
+
+fn serialize_tree(nodes: &[StateNodeDto], fingerprint: String) -> AppResult<CountryTree> {
+    let json = serde_json::to_vec(nodes)?;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(&json)
+        .map_err(|e| AppError::Internal(anyhow::anyhow!(e)))?;
+    let gzip = encoder
+        .finish()
+        .map_err(|e| AppError::Internal(anyhow::anyhow!(e)))?;
+
+    Ok(CountryTree {
+        fingerprint,
+        json: json.into(),
+        gzip: gzip.into(),
+    })
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::application::dtos::{ChangeDto, DatasetDiffDto, PaginatedResponse, PaginationParams};
+use crate::domain::entities::change::ChangeEntityType;
+use crate::domain::repositories::history_repository::HistoryRepository;
+use crate::errors::{AppError, AppResult};
+
+/// Change history use cases
+#[derive(Clone)]
+pub struct HistoryUseCases<R: HistoryRepository + Clone> {
+    history_repository: R,
+}
+
+impl<R: HistoryRepository + Clone> HistoryUseCases<R> {
+    pub fn new(history_repository: R) -> Self {
+        Self { history_repository }
+    }
+
+    /// Changes to one entity, oldest first
+    ///
+    /// Deleted entities keep their history, so this works for IDs that no
+    /// longer resolve.
+    pub async fn get_entity_history(
+        &self,
+        entity_type: ChangeEntityType,
+        entity_id: Uuid,
+        params: PaginationParams,
+    ) -> AppResult<PaginatedResponse<ChangeDto>> {
+        let request = params.page_request()?;
+
+        let (changes, total) = tokio::try_join!(
+            self.history_repository
+                .find_by_entity(entity_type, entity_id, &request),
+            self.history_repository
+                .count_by_entity(entity_type, entity_id)
+        )?;
+
+        Ok(PaginatedResponse::from_page(changes, &request, total))
+    }
+
+    /// What changed in the dataset after `from` and up to `to` (default now)
+    pub async fn diff(
+        &self,
+        from: DateTime<Utc>,
+        to: Option<DateTime<Utc>>,
+        entity_type: Option<ChangeEntityType>,
+    ) -> AppResult<DatasetDiffDto> {
+        let to = to.unwrap_or_else(Utc::now);
+        if from > to {
+            return Err(AppError::BadRequest(format!(
+                "from ({}) is later than to ({})",
+                from, to
+            )));
+        }
+
+        let diffs = self.history_repository.diff(from, to, entity_type).await?;
+        Ok(DatasetDiffDto::new(from, to, diffs))
+    }
+}
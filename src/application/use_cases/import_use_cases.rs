@@ -0,0 +1,301 @@
+use crate::application::dtos::import_dto::{
+    ImportCountsDto, ImportRecordDto, ImportRejectionDto, ImportReportDto,
+};
+use crate::domain::entities::import::{ImportOutcome, ImportUnit};
+use crate::domain::entities::unit_level::UnitLevel;
+use crate::domain::repositories::import_repository::ImportRepository;
+use crate::domain::value_objects::{
+    Coordinates, GeoCode, LgaCode, PostalCode as PostalCodeValue, StateCode, WardCode,
+};
+use crate::errors::{AppError, AppResult};
+
+/// A parsed import row, or the reason it could not be parsed
+pub type ImportRow = Result<ImportRecordDto, String>;
+
+/// Formats accepted by the importer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    Csv,
+    Json,
+    GeoJson,
+}
+
+impl ImportFormat {
+    /// Detect the format from a file name
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        let (_, extension) = file_name.rsplit_once('.')?;
+        Self::from_name(extension)
+    }
+
+    /// Parse a format name such as `csv` or `geojson`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "json" => Some(Self::Json),
+            "geojson" => Some(Self::GeoJson),
+            _ => None,
+        }
+    }
+
+    /// Parse an import file into rows.
+    ///
+    /// Malformed rows are kept as `Err` so they are reported as rejected
+    /// rather than failing the whole import.
+    pub fn parse(&self, data: &[u8]) -> AppResult<Vec<ImportRow>> {
+        // Spreadsheet exports frequently start with a UTF-8 byte order mark
+        let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+
+        match self {
+            Self::Csv => {
+                let mut reader = csv::ReaderBuilder::new()
+                    .trim(csv::Trim::All)
+                    .flexible(true)
+                    .from_reader(data);
+
+                let headers: csv::StringRecord = reader
+                    .headers()
+                    .map_err(|e| AppError::BadRequest(format!("Invalid CSV header: {}", e)))?
+                    .iter()
+                    .map(|header| header.to_ascii_lowercase())
+                    .collect();
+                if !headers.iter().any(|header| header == "code") {
+                    return Err(AppError::BadRequest(
+                        "CSV is missing required column: code".to_string(),
+                    ));
+                }
+                reader.set_headers(headers);
+
+                Ok(reader
+                    .deserialize::<ImportRecordDto>()
+                    .map(|record| record.map_err(|e| e.to_string()))
+                    .collect())
+            }
+            Self::Json => {
+                let values: Vec<serde_json::Value> = serde_json::from_slice(data)
+                    .map_err(|e| AppError::BadRequest(format!("Invalid JSON array: {}", e)))?;
+
+                Ok(values
+                    .into_iter()
+                    .map(|value| serde_json::from_value(value).map_err(|e| e.to_string()))
+                    .collect())
+            }
+            Self::GeoJson => {
+                let collection: serde_json::Value = serde_json::from_slice(data)
+                    .map_err(|e| AppError::BadRequest(format!("Invalid GeoJSON: {}", e)))?;
+                let features = collection
+                    .get("features")
+                    .and_then(|features| features.as_array())
+                    .filter(|_| collection["type"] == "FeatureCollection")
+                    .ok_or_else(|| {
+                        AppError::BadRequest("GeoJSON must be a FeatureCollection".to_string())
+                    })?;
+
+                Ok(features.iter().map(feature_record).collect())
+            }
+        }
+    }
+}
+
+/// The record held in a feature's properties, taking `lat` and `lng` from a
+/// point geometry when the properties do not give them
+fn feature_record(feature: &serde_json::Value) -> ImportRow {
+    let mut properties = match feature.get("properties") {
+        Some(serde_json::Value::Object(properties)) => properties.clone(),
+        _ => return Err("feature has no properties".to_string()),
+    };
+
+    let geometry = &feature["geometry"];
+    if geometry["type"] == "Point" {
+        if let Some([lng, lat, ..]) = geometry["coordinates"].as_array().map(Vec::as_slice) {
+            properties.entry("lat").or_insert_with(|| lat.clone());
+            properties.entry("lng").or_insert_with(|| lng.clone());
+        }
+    }
+
+    serde_json::from_value(serde_json::Value::Object(properties)).map_err(|e| e.to_string())
+}
+
+/// Bulk import use cases
+#[derive(Clone)]
+pub struct ImportUseCases<R: ImportRepository + Clone> {
+    import_repository: R,
+}
+
+impl<R: ImportRepository + Clone> ImportUseCases<R> {
+    pub fn new(import_repository: R) -> Self {
+        Self { import_repository }
+    }
+
+    /// Validate and import `data`, creating units that are new and updating
+    /// those that differ.
+    ///
+    /// Units are written parents first, whatever their order in the file, so
+    /// a file can hold a whole hierarchy. With `dry_run` the report is the
+    /// same but nothing is committed.
+    pub async fn import(
+        &self,
+        data: &[u8],
+        format: ImportFormat,
+        dry_run: bool,
+    ) -> AppResult<ImportReportDto> {
+        let rows = format.parse(data)?;
+        let mut report = ImportReportDto {
+            dry_run,
+            rows: rows.len(),
+            ..Default::default()
+        };
+
+        let mut units = Vec::new();
+        for (index, row) in rows.into_iter().enumerate() {
+            let row_number = index + 1;
+            let record = match row {
+                Ok(record) => record,
+                Err(reason) => {
+                    report.rejected.push(ImportRejectionDto {
+                        row: row_number,
+                        level: None,
+                        code: None,
+                        reason,
+                    });
+                    continue;
+                }
+            };
+
+            let level = record_level(&record);
+            match level.clone().and_then(|level| to_unit(&record, level)) {
+                Ok(unit) => units.push((row_number, unit)),
+                Err(reason) => {
+                    if let Ok(level) = level {
+                        report.counts_mut(level).rejected += 1;
+                    }
+                    report.rejected.push(ImportRejectionDto {
+                        row: row_number,
+                        level: level.ok().map(|level| level.as_str().to_string()),
+                        code: Some(record.code),
+                        reason,
+                    });
+                }
+            }
+        }
+        units.sort_by_key(|(_, unit)| unit.level());
+
+        let (row_numbers, units): (Vec<usize>, Vec<ImportUnit>) = units.into_iter().unzip();
+        let outcomes = self.import_repository.import(&units, dry_run).await?;
+
+        for ((row, unit), outcome) in row_numbers.into_iter().zip(&units).zip(outcomes) {
+            let counts = report.counts_mut(unit.level());
+            match outcome {
+                ImportOutcome::Created => counts.created += 1,
+                ImportOutcome::Updated => counts.updated += 1,
+                ImportOutcome::Unchanged => counts.skipped += 1,
+                ImportOutcome::Rejected(reason) => {
+                    counts.rejected += 1;
+                    report.rejected.push(ImportRejectionDto {
+                        row,
+                        level: Some(unit.level().as_str().to_string()),
+                        code: Some(unit.code().to_string()),
+                        reason,
+                    });
+                }
+            }
+        }
+
+        report.rejected.sort_by_key(|rejection| rejection.row);
+        report.total = ImportCountsDto {
+            created: report.level_counts().map(|counts| counts.created).sum(),
+            updated: report.level_counts().map(|counts| counts.updated).sum(),
+            skipped: report.level_counts().map(|counts| counts.skipped).sum(),
+            // Including rows whose level could not be told
+            rejected: report.rejected.len(),
+        };
+
+        Ok(report)
+    }
+}
+
+/// The level of a record, as given or as shown by its code
+fn record_level(record: &ImportRecordDto) -> Result<UnitLevel, String> {
+    if let Some(level) = record
+        .level
+        .as_deref()
+        .filter(|level| !level.trim().is_empty())
+    {
+        return UnitLevel::parse(level)
+            .ok_or_else(|| format!("level: '{}' is not state, lga, ward or postal_code", level));
+    }
+    if record.ward_code.is_some() {
+        return Ok(UnitLevel::PostalCode);
+    }
+
+    match GeoCode::parse(&record.code).map_err(|e| invalid("code", e))? {
+        GeoCode::State(_) => Ok(UnitLevel::State),
+        GeoCode::Lga(_) => Ok(UnitLevel::Lga),
+        GeoCode::Ward(_) => Ok(UnitLevel::Ward),
+    }
+}
+
+/// Validate a record through the value objects
+fn to_unit(record: &ImportRecordDto, level: UnitLevel) -> Result<ImportUnit, String> {
+    let name = record
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string);
+    let required_name = || name.clone().ok_or_else(|| "name: is required".to_string());
+
+    match level {
+        UnitLevel::State => {
+            let code = StateCode::new(record.code.clone()).map_err(|e| invalid("code", e))?;
+            let name = name.unwrap_or_else(|| code.name().to_string());
+            Ok(ImportUnit::State { code, name })
+        }
+        UnitLevel::Lga => Ok(ImportUnit::Lga {
+            code: LgaCode::new(record.code.clone()).map_err(|e| invalid("code", e))?,
+            name: required_name()?,
+        }),
+        UnitLevel::Ward => Ok(ImportUnit::Ward {
+            code: WardCode::new(record.code.clone()).map_err(|e| invalid("code", e))?,
+            name: required_name()?,
+        }),
+        UnitLevel::PostalCode => {
+            let postal_code =
+                PostalCodeValue::new(record.code.clone()).map_err(|e| invalid("code", e))?;
+            let ward_code = record
+                .ward_code
+                .clone()
+                .ok_or_else(|| "ward_code: is required".to_string())?;
+            let ward_code = WardCode::new(ward_code).map_err(|e| invalid("ward_code", e))?;
+            if !postal_code.matches_state(&ward_code.state_code()) {
+                return Err(invalid(
+                    "code",
+                    format!(
+                        "postal code {} is outside the postal region of state {}",
+                        postal_code,
+                        ward_code.state_code()
+                    ),
+                ));
+            }
+
+            let coordinates = match (record.lat, record.lng) {
+                (Some(lat), Some(lng)) => {
+                    Some(Coordinates::new(lat, lng).map_err(|e| invalid(e.field(), e))?)
+                }
+                (None, None) => None,
+                _ => return Err("lat, lng: must be given together".to_string()),
+            };
+
+            Ok(ImportUnit::PostalCode {
+                postal_code,
+                ward_code,
+                coordinates,
+                urban: record.urban,
+            })
+        }
+    }
+}
+
+/// A rejection reason naming the field at fault
+fn invalid(field: &str, error: impl std::fmt::Display) -> String {
+    format!("{}: {}", field, error)
+}
@@ -1,8 +1,12 @@
 use uuid::Uuid;
+use validator::Validate;
 
-use crate::domain::entities::lga::{CreateLgaRequest, UpdateLgaRequest};
+use crate::domain::entities::lga::{Lga, CreateLgaRequest, UpdateLgaRequest};
+use crate::domain::entities::unit_status::UpdateStatusRequest;
 use crate::domain::repositories::lga_repository::LgaRepository;
-use crate::application::dtos::{LgaDto, PaginatedResponse, PaginationParams};
+use crate::domain::value_objects::LgaCode;
+use crate::application::dtos::{LgaDto, RedirectDto, PaginatedResponse, PaginationParams};
+use crate::application::use_cases::successors::follow_successors;
 use crate::errors::AppResult;
 
 /// LGA use cases
@@ -18,8 +22,7 @@ impl<R: LgaRepository + Clone> LgaUseCases<R> {
 
     /// Get LGAs by state ID with pagination
     pub async fn get_lgas_by_state(&self, state_id: Uuid, params: PaginationParams) -> AppResult<PaginatedResponse<LgaDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
+        let (page, limit) = params.page_and_limit()?;
 
         let lgas = self.lga_repository.find_by_state_id(state_id, page, limit).await?;
         let total = self.lga_repository.count_by_state(state_id).await?;
@@ -37,17 +40,28 @@ impl<R: LgaRepository + Clone> LgaUseCases<R> {
     /// Get LGA by ID
     pub async fn get_lga_by_id(&self, id: Uuid) -> AppResult<Option<LgaDto>> {
         let lga = self.lga_repository.find_by_id(id).await?;
-        Ok(lga.map(|l| l.into()))
+        self.follow_to_current(lga).await
+    }
+
+    /// Get LGA by code, e.g. `NG-LA-IKJ`
+    pub async fn get_lga_by_code(&self, code: &str) -> AppResult<Option<LgaDto>> {
+        let lga_code = LgaCode::new(code.to_string())
+            .map_err(|e| crate::errors::AppError::invalid_param("code", e))?;
+
+        let lga = self.lga_repository.find_by_code(&lga_code).await?;
+        self.follow_to_current(lga).await
     }
 
     /// Create a new LGA
     pub async fn create_lga(&self, state_id: Uuid, request: CreateLgaRequest) -> AppResult<LgaDto> {
+        request.validate()?;
         let lga = self.lga_repository.create(&request, state_id).await?;
         Ok(lga.into())
     }
 
     /// Update LGA
     pub async fn update_lga(&self, id: Uuid, request: UpdateLgaRequest) -> AppResult<LgaDto> {
+        request.validate()?;
         let lga = self.lga_repository.update(id, &request).await?;
         Ok(lga.into())
     }
@@ -57,12 +71,31 @@ impl<R: LgaRepository + Clone> LgaUseCases<R> {
         self.lga_repository.delete(id).await
     }
 
+    /// Deprecate, merge or reactivate an LGA
+    pub async fn set_lga_status(&self, id: Uuid, request: UpdateStatusRequest) -> AppResult<LgaDto> {
+        request.validate()?;
+        let lga = self.lga_repository.set_status(id, &request).await?;
+        Ok(lga.into())
+    }
+
     /// Search LGAs
     pub async fn search_lgas(&self, query: &str, params: PaginationParams) -> AppResult<Vec<LgaDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
+        let (page, limit) = params.page_and_limit()?;
 
         let lgas = self.lga_repository.search(query, page, limit).await?;
         Ok(lgas.into_iter().map(|l| l.into()).collect())
     }
+
+    /// The LGA to show for a lookup: a retired one is followed to its
+    /// successor, noting where the lookup started
+    async fn follow_to_current(&self, lga: Option<Lga>) -> AppResult<Option<LgaDto>> {
+        let Some(lga) = lga else {
+            return Ok(None);
+        };
+
+        let (lga, requested) = follow_successors(lga, |id| self.lga_repository.find_by_id(id)).await?;
+        let mut dto = LgaDto::from(lga);
+        dto.redirected_from = requested.map(|lga| RedirectDto::new(lga.id, lga.code.to_string(), lga.status));
+        Ok(Some(dto))
+    }
 }
\ No newline at end of file
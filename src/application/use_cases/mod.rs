@@ -3,4 +3,16 @@ pub mod lga_use_cases;
 pub mod ward_use_cases;
 pub mod postal_code_use_cases;
 pub mod address_use_cases;
+pub mod batch_validation_use_cases;
 pub mod search_use_cases;
+pub mod autocomplete_use_cases;
+pub mod alias_use_cases;
+pub mod hierarchy_use_cases;
+pub mod history_use_cases;
+pub mod dataset_version_use_cases;
+pub mod export_use_cases;
+pub mod import_use_cases;
+pub mod bundle_use_cases;
+pub mod sync_use_cases;
+pub mod verification_use_cases;
+pub(crate) mod successors;
@@ -1,10 +1,16 @@
 use uuid::Uuid;
+use validator::Validate;
 
-use crate::domain::entities::postal_code::{CreatePostalCodeRequest, UpdatePostalCodeRequest};
+use crate::domain::entities::postal_code::{PostalCode, CreatePostalCodeRequest, UpdatePostalCodeRequest};
+use crate::domain::entities::unit_status::UpdateStatusRequest;
 use crate::domain::repositories::postal_code_repository::PostalCodeRepository;
-use crate::domain::value_objects::{PostalCode as PostalCodeValue, Coordinates};
-use crate::application::dtos::{PostalCodeDto, PaginatedResponse, PaginationParams};
-use crate::errors::AppResult;
+use crate::domain::value_objects::{PostalCode as PostalCodeValue, Coordinates, StateCode};
+use crate::application::dtos::{PostalCodeBreakdownDto, PostalCodeDto, RedirectDto, PostalCodeStateCheckDto, PaginatedResponse, PaginationParams};
+use crate::application::use_cases::successors::follow_successors;
+use crate::errors::{AppError, AppResult, InvalidParam};
+
+/// Largest radius, in kilometres, a nearby search may cover
+pub const MAX_NEARBY_RADIUS_KM: f64 = 500.0;
 
 /// Postal code use cases
 #[derive(Clone)]
@@ -19,15 +25,12 @@ impl<R: PostalCodeRepository + Clone> PostalCodeUseCases<R> {
 
     /// Get postal codes by ward ID with pagination
     pub async fn get_postal_codes_by_ward(&self, ward_id: Uuid, params: PaginationParams) -> AppResult<PaginatedResponse<PostalCodeDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
+        let request = params.page_request()?;
 
-        let postal_codes = self.postal_code_repository.find_by_ward_id(ward_id, page, limit).await?;
+        let postal_codes = self.postal_code_repository.find_by_ward_id(ward_id, &request).await?;
         let total = self.postal_code_repository.count_by_ward(ward_id).await?;
 
-        let postal_code_dtos: Vec<PostalCodeDto> = postal_codes.into_iter().map(|p| p.into()).collect();
-
-        Ok(PaginatedResponse::new(postal_code_dtos, page, limit, total))
+        Ok(PaginatedResponse::from_page(postal_codes, &request, total))
     }
 
     /// Get postal codes by ward ID with pagination (alternative method name)
@@ -38,22 +41,46 @@ impl<R: PostalCodeRepository + Clone> PostalCodeUseCases<R> {
     /// Get postal code by ID
     pub async fn get_postal_code_by_id(&self, id: Uuid) -> AppResult<Option<PostalCodeDto>> {
         let postal_code = self.postal_code_repository.find_by_id(id).await?;
-        Ok(postal_code.map(|p| p.into()))
+        self.follow_to_current(postal_code).await
     }
 
     /// Get postal code by code
     pub async fn get_postal_code_by_code(&self, code: &str) -> AppResult<Option<PostalCodeDto>> {
         let postal_code_value = PostalCodeValue::new(code.to_string())
-            .map_err(|e| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+            .map_err(|e| crate::errors::AppError::invalid_param("code", e))?;
         
         let postal_code = self.postal_code_repository.find_by_code(&postal_code_value).await?;
-        Ok(postal_code.map(|p| p.into()))
+        self.follow_to_current(postal_code).await
+    }
+
+    /// Decode a postal code into region, state and district, optionally
+    /// checking it against the state it is claimed for
+    pub fn get_postal_code_breakdown(&self, code: &str, claimed_state: Option<&str>) -> AppResult<PostalCodeBreakdownDto> {
+        let postal_code_value = PostalCodeValue::new(code.to_string())
+            .map_err(|e| crate::errors::AppError::invalid_param("code", e))?;
+
+        let mut breakdown = PostalCodeBreakdownDto::from(&postal_code_value);
+
+        if let Some(claimed_state) = claimed_state {
+            let state_code = StateCode::lookup(claimed_state)
+                .map_err(|e| crate::errors::AppError::invalid_param("state", e))?;
+            let expected_prefixes = PostalCodeValue::prefixes_for_state(&state_code);
+
+            breakdown.state_check = Some(PostalCodeStateCheckDto {
+                claimed_state_code: state_code.to_string(),
+                matches: postal_code_value.matches_state(&state_code),
+                expected_prefixes: expected_prefixes.iter().map(|p| p.to_string()).collect(),
+            });
+        }
+
+        Ok(breakdown)
     }
 
     /// Find postal codes near coordinates
     pub async fn find_nearby_postal_codes(&self, lat: f64, lng: f64, radius_km: f64) -> AppResult<Vec<PostalCodeDto>> {
         let coordinates = Coordinates::new(lat, lng)
-            .map_err(|e| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+            .map_err(|e| AppError::invalid_param(e.field(), e))?;
+        ensure_radius(radius_km)?;
         
         let postal_codes = self.postal_code_repository.find_near_coordinates(&coordinates, radius_km).await?;
         Ok(postal_codes.into_iter().map(|p| p.into()).collect())
@@ -61,18 +88,23 @@ impl<R: PostalCodeRepository + Clone> PostalCodeUseCases<R> {
 
     /// Find postal codes near coordinates (alternative method name)
     pub async fn find_near_coordinates(&self, coordinates: Coordinates, radius_km: f64) -> AppResult<Vec<PostalCodeDto>> {
+        ensure_radius(radius_km)?;
         let postal_codes = self.postal_code_repository.find_near_coordinates(&coordinates, radius_km).await?;
         Ok(postal_codes.into_iter().map(|p| p.into()).collect())
     }
 
     /// Create a new postal code
     pub async fn create_postal_code(&self, ward_id: Uuid, request: CreatePostalCodeRequest) -> AppResult<PostalCodeDto> {
+        request.validate()?;
+        ensure_coordinate_pair(request.lat, request.lng)?;
         let postal_code = self.postal_code_repository.create(&request, ward_id).await?;
         Ok(postal_code.into())
     }
 
     /// Update postal code
     pub async fn update_postal_code(&self, id: Uuid, request: UpdatePostalCodeRequest) -> AppResult<PostalCodeDto> {
+        request.validate()?;
+        ensure_coordinate_pair(request.lat, request.lng)?;
         let postal_code = self.postal_code_repository.update(id, &request).await?;
         Ok(postal_code.into())
     }
@@ -82,12 +114,57 @@ impl<R: PostalCodeRepository + Clone> PostalCodeUseCases<R> {
         self.postal_code_repository.delete(id).await
     }
 
+    /// Deprecate, merge or reactivate a postal code
+    pub async fn set_postal_code_status(&self, id: Uuid, request: UpdateStatusRequest) -> AppResult<PostalCodeDto> {
+        request.validate()?;
+        let postal_code = self.postal_code_repository.set_status(id, &request).await?;
+        Ok(postal_code.into())
+    }
+
     /// Search postal codes
     pub async fn search_postal_codes(&self, query: &str, params: PaginationParams) -> AppResult<Vec<PostalCodeDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
+        let (page, limit) = params.page_and_limit()?;
 
         let postal_codes = self.postal_code_repository.search(query, page, limit).await?;
         Ok(postal_codes.into_iter().map(|p| p.into()).collect())
     }
-}
\ No newline at end of file
+
+    /// The postal code to show for a lookup: a retired one is followed to its
+    /// successor, noting where the lookup started
+    async fn follow_to_current(&self, postal_code: Option<PostalCode>) -> AppResult<Option<PostalCodeDto>> {
+        let Some(postal_code) = postal_code else {
+            return Ok(None);
+        };
+
+        let (postal_code, requested) = follow_successors(postal_code, |id| self.postal_code_repository.find_by_id(id)).await?;
+        let mut dto = PostalCodeDto::from(postal_code);
+        dto.redirected_from = requested.map(|postal_code| RedirectDto::new(postal_code.id, postal_code.postal_code.to_string(), postal_code.status));
+        Ok(Some(dto))
+    }
+}
+
+/// A nearby search covers a positive radius of at most [`MAX_NEARBY_RADIUS_KM`]
+fn ensure_radius(radius_km: f64) -> AppResult<()> {
+    if radius_km > 0.0 && radius_km <= MAX_NEARBY_RADIUS_KM {
+        return Ok(());
+    }
+    Err(AppError::InvalidParam(InvalidParam::new(
+        "radius_km",
+        "range",
+        format!("Radius must be greater than 0 and at most {} km", MAX_NEARBY_RADIUS_KM),
+    )))
+}
+
+/// Coordinates are stored as a pair, so one without the other is refused
+fn ensure_coordinate_pair(lat: Option<f64>, lng: Option<f64>) -> AppResult<()> {
+    let missing = match (lat, lng) {
+        (Some(_), None) => "lng",
+        (None, Some(_)) => "lat",
+        _ => return Ok(()),
+    };
+    Err(AppError::InvalidParam(InvalidParam::new(
+        missing,
+        "required",
+        "lat and lng must be given together",
+    )))
+}
@@ -3,18 +3,109 @@ use crate::domain::repositories::{
     lga_repository::LgaRepository,
     ward_repository::WardRepository,
     postal_code_repository::PostalCodeRepository,
+    search_repository::SearchRepository,
 };
-use crate::application::dtos::{StateDto, LgaDto, WardDto, PostalCodeDto, PaginationParams};
+use crate::application::dtos::{StateDto, LgaDto, WardDto, PostalCodeDto, PaginatedResponse, PaginationMeta, PaginationParams};
+use crate::domain::entities::search::{MatchMode, SearchCounts, SearchHit};
+use crate::domain::value_objects::GeoCode;
 use crate::errors::AppResult;
 use utoipa::ToSchema;
 
+/// Single ranked hit of a unified search
+#[derive(Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct SearchHitDto {
+    /// Entity type: `state`, `lga`, `ward` or `postal_code`
+    #[schema(example = "lga")]
+    pub entity_type: String,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub id: uuid::Uuid,
+    /// Entity name, or the code itself for postal codes
+    #[schema(example = "Ikeja")]
+    pub name: String,
+    #[schema(example = "NG-LA-IKJ")]
+    pub code: String,
+    /// Enclosing entities, nearest first
+    #[schema(example = "Lagos")]
+    pub parent_path: Option<String>,
+    /// Alias that matched the query, when it matched better than the name
+    #[schema(example = "PH")]
+    pub matched_alias: Option<String>,
+    /// Normalization rules the query needed to match: `case`, `diacritics`,
+    /// `punctuation` or `stopwords`
+    #[serde(default)]
+    #[schema(example = json!(["diacritics"]))]
+    pub normalization: Vec<String>,
+    /// Relevance from 0.0 to 1.0
+    #[schema(example = 0.9)]
+    pub score: f64,
+}
+
+impl From<SearchHit> for SearchHitDto {
+    fn from(hit: SearchHit) -> Self {
+        Self {
+            entity_type: hit.entity_type.as_str().to_string(),
+            id: hit.id,
+            name: hit.name,
+            code: hit.code,
+            parent_path: hit.parent_path,
+            matched_alias: hit.matched_alias,
+            normalization: hit
+                .normalization
+                .iter()
+                .map(|rule| rule.as_str().to_string())
+                .collect(),
+            score: hit.score,
+        }
+    }
+}
+
+/// Number of matches per entity type
+#[derive(Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct SearchCountsDto {
+    #[schema(example = 1)]
+    pub states: u64,
+    #[schema(example = 3)]
+    pub lgas: u64,
+    #[schema(example = 12)]
+    pub wards: u64,
+    #[schema(example = 0)]
+    pub postal_codes: u64,
+}
+
+impl From<SearchCounts> for SearchCountsDto {
+    fn from(counts: SearchCounts) -> Self {
+        Self {
+            states: counts.states,
+            lgas: counts.lgas,
+            wards: counts.wards,
+            postal_codes: counts.postal_codes,
+        }
+    }
+}
+
 /// Search result DTO
 #[derive(Debug, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct SearchResultDto {
-    pub states: Vec<StateDto>,
-    pub lgas: Vec<LgaDto>,
-    pub wards: Vec<WardDto>,
-    pub postal_codes: Vec<PostalCodeDto>,
+    /// Hits across all entity types, best match first
+    pub data: Vec<SearchHitDto>,
+    /// Matches per entity type across all pages
+    pub counts: SearchCountsDto,
+    /// Pagination metadata
+    pub pagination: PaginationMeta,
+}
+
+/// Entity resolved from a code of any hierarchy level, with its ancestors
+#[derive(Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct CodeLookupDto {
+    /// Level the code identifies: `state`, `lga` or `ward`
+    #[schema(example = "ward")]
+    pub level: String,
+    /// Normalised code that was looked up
+    #[schema(example = "NG-LA-IKJ-W03")]
+    pub code: String,
+    pub state: Option<StateDto>,
+    pub lga: Option<LgaDto>,
+    pub ward: Option<WardDto>,
 }
 
 /// Search use cases
@@ -24,82 +115,135 @@ pub struct SearchUseCases<
     LR: LgaRepository + Clone,
     WR: WardRepository + Clone,
     PR: PostalCodeRepository + Clone,
+    XR: SearchRepository + Clone,
 > {
     state_repository: SR,
     lga_repository: LR,
     ward_repository: WR,
     postal_code_repository: PR,
+    search_repository: XR,
 }
 
-impl<SR: StateRepository + Clone, LR: LgaRepository + Clone, WR: WardRepository + Clone, PR: PostalCodeRepository + Clone>
-    SearchUseCases<SR, LR, WR, PR>
+impl<SR: StateRepository + Clone, LR: LgaRepository + Clone, WR: WardRepository + Clone, PR: PostalCodeRepository + Clone, XR: SearchRepository + Clone>
+    SearchUseCases<SR, LR, WR, PR, XR>
 {
     pub fn new(
         state_repository: SR,
         lga_repository: LR,
         ward_repository: WR,
         postal_code_repository: PR,
+        search_repository: XR,
     ) -> Self {
         Self {
             state_repository,
             lga_repository,
             ward_repository,
             postal_code_repository,
+            search_repository,
         }
     }
 
-    /// Search across all entities
-    pub async fn search_all(&self, query: &str, params: PaginationParams) -> AppResult<SearchResultDto> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
-
-        // Search all entities in parallel
-        let (states, lgas, wards, postal_codes) = tokio::try_join!(
-            self.state_repository.search(query, page, limit),
-            self.lga_repository.search(query, page, limit),
-            self.ward_repository.search(query, page, limit),
-            self.postal_code_repository.search(query, page, limit)
+    /// Search across all entities, returning one list ranked by relevance
+    pub async fn search_all(&self, query: &str, mode: MatchMode, params: PaginationParams) -> AppResult<SearchResultDto> {
+        let request = params.page_request()?;
+
+        let (hits, counts) = tokio::try_join!(
+            self.search_repository.search(query, mode, &request),
+            self.search_repository.count(query, mode)
         )?;
 
+        let PaginatedResponse { data, pagination } =
+            PaginatedResponse::<SearchHitDto>::from_page(hits, &request, counts.total());
+
         Ok(SearchResultDto {
-            states: states.into_iter().map(|s| s.into()).collect(),
-            lgas: lgas.into_iter().map(|l| l.into()).collect(),
-            wards: wards.into_iter().map(|w| w.into()).collect(),
-            postal_codes: postal_codes.into_iter().map(|p| p.into()).collect(),
+            data,
+            counts: counts.into(),
+            pagination,
         })
     }
 
+    /// Look up a state, LGA or ward by its code, resolving parents from the code itself
+    pub async fn lookup_code(&self, code: &str) -> AppResult<Option<CodeLookupDto>> {
+        let geo_code = GeoCode::parse(code)
+            .map_err(|e| crate::errors::AppError::invalid_param("code", e))?;
+
+        let state_code = geo_code.state_code();
+        let lga_code = geo_code.lga_code();
+        let ward_code = match &geo_code {
+            GeoCode::Ward(code) => Some(code.clone()),
+            _ => None,
+        };
+
+        let (state, lga, ward) = tokio::try_join!(
+            self.state_repository.find_by_code(&state_code),
+            async {
+                match &lga_code {
+                    Some(code) => self.lga_repository.find_by_code(code).await,
+                    None => Ok(None),
+                }
+            },
+            async {
+                match &ward_code {
+                    Some(code) => self.ward_repository.find_by_code(code).await,
+                    None => Ok(None),
+                }
+            }
+        )?;
+
+        let found = match geo_code {
+            GeoCode::State(_) => state.is_some(),
+            GeoCode::Lga(_) => lga.is_some(),
+            GeoCode::Ward(_) => ward.is_some(),
+        };
+        if !found {
+            return Ok(None);
+        }
+
+        Ok(Some(CodeLookupDto {
+            level: geo_code.level().to_string(),
+            code: geo_code.to_string(),
+            state: state.map(|s| s.into()),
+            lga: lga.map(|l| l.into()),
+            ward: ward.map(|w| w.into()),
+        }))
+    }
+
     /// Search states only
-    pub async fn search_states(&self, query: &str, params: PaginationParams) -> AppResult<Vec<StateDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
+    pub async fn search_states(&self, query: &str, mode: MatchMode, params: PaginationParams) -> AppResult<Vec<StateDto>> {
+        let (page, limit) = params.page_and_limit()?;
 
-        let states = self.state_repository.search(query, page, limit).await?;
+        let states = match mode {
+            MatchMode::Standard => self.state_repository.search(query, page, limit).await?,
+            MatchMode::Phonetic => self.state_repository.search_phonetic(query, page, limit).await?,
+        };
         Ok(states.into_iter().map(|s| s.into()).collect())
     }
 
     /// Search LGAs only
-    pub async fn search_lgas(&self, query: &str, params: PaginationParams) -> AppResult<Vec<LgaDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
+    pub async fn search_lgas(&self, query: &str, mode: MatchMode, params: PaginationParams) -> AppResult<Vec<LgaDto>> {
+        let (page, limit) = params.page_and_limit()?;
 
-        let lgas = self.lga_repository.search(query, page, limit).await?;
+        let lgas = match mode {
+            MatchMode::Standard => self.lga_repository.search(query, page, limit).await?,
+            MatchMode::Phonetic => self.lga_repository.search_phonetic(query, page, limit).await?,
+        };
         Ok(lgas.into_iter().map(|l| l.into()).collect())
     }
 
     /// Search wards only
-    pub async fn search_wards(&self, query: &str, params: PaginationParams) -> AppResult<Vec<WardDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
+    pub async fn search_wards(&self, query: &str, mode: MatchMode, params: PaginationParams) -> AppResult<Vec<WardDto>> {
+        let (page, limit) = params.page_and_limit()?;
 
-        let wards = self.ward_repository.search(query, page, limit).await?;
+        let wards = match mode {
+            MatchMode::Standard => self.ward_repository.search(query, page, limit).await?,
+            MatchMode::Phonetic => self.ward_repository.search_phonetic(query, page, limit).await?,
+        };
         Ok(wards.into_iter().map(|w| w.into()).collect())
     }
 
     /// Search postal codes only
     pub async fn search_postal_codes(&self, query: &str, params: PaginationParams) -> AppResult<Vec<PostalCodeDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
+        let (page, limit) = params.page_and_limit()?;
 
         let postal_codes = self.postal_code_repository.search(query, page, limit).await?;
         Ok(postal_codes.into_iter().map(|p| p.into()).collect())
@@ -1,8 +1,12 @@
 use uuid::Uuid;
+use validator::Validate;
 
-use crate::domain::entities::state::{CreateStateRequest, UpdateStateRequest};
+use crate::domain::entities::state::{State, CreateStateRequest, UpdateStateRequest};
+use crate::domain::entities::unit_status::UpdateStatusRequest;
 use crate::domain::repositories::state_repository::StateRepository;
-use crate::application::dtos::{StateDto, PaginatedResponse, PaginationParams};
+use crate::domain::value_objects::StateCode;
+use crate::application::dtos::{StateDto, RedirectDto, PaginatedResponse, PaginationParams};
+use crate::application::use_cases::successors::follow_successors;
 use crate::errors::AppResult;
 
 /// State use cases
@@ -18,8 +22,7 @@ impl<R: StateRepository + Clone> StateUseCases<R> {
 
     /// Get all states with pagination
     pub async fn get_states(&self, params: PaginationParams) -> AppResult<PaginatedResponse<StateDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
+        let (page, limit) = params.page_and_limit()?;
 
         let states = self.state_repository.list(page, limit).await?;
         let total = self.state_repository.count().await?;
@@ -32,17 +35,28 @@ impl<R: StateRepository + Clone> StateUseCases<R> {
     /// Get state by ID
     pub async fn get_state_by_id(&self, id: Uuid) -> AppResult<Option<StateDto>> {
         let state = self.state_repository.find_by_id(id).await?;
-        Ok(state.map(|s| s.into()))
+        self.follow_to_current(state).await
+    }
+
+    /// Get state by ISO code, abbreviation, capital or alternative name
+    pub async fn get_state_by_code(&self, code: &str) -> AppResult<Option<StateDto>> {
+        let state_code = StateCode::lookup(code)
+            .map_err(|e| crate::errors::AppError::invalid_param("code", e))?;
+
+        let state = self.state_repository.find_by_code(&state_code).await?;
+        self.follow_to_current(state).await
     }
 
     /// Create a new state
     pub async fn create_state(&self, request: CreateStateRequest) -> AppResult<StateDto> {
+        request.validate()?;
         let state = self.state_repository.create(&request).await?;
         Ok(state.into())
     }
 
     /// Update state
     pub async fn update_state(&self, id: Uuid, request: UpdateStateRequest) -> AppResult<StateDto> {
+        request.validate()?;
         let state = self.state_repository.update(id, &request).await?;
         Ok(state.into())
     }
@@ -52,12 +66,31 @@ impl<R: StateRepository + Clone> StateUseCases<R> {
         self.state_repository.delete(id).await
     }
 
+    /// Deprecate, merge or reactivate a state
+    pub async fn set_state_status(&self, id: Uuid, request: UpdateStatusRequest) -> AppResult<StateDto> {
+        request.validate()?;
+        let state = self.state_repository.set_status(id, &request).await?;
+        Ok(state.into())
+    }
+
     /// Search states
     pub async fn search_states(&self, query: &str, params: PaginationParams) -> AppResult<Vec<StateDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
+        let (page, limit) = params.page_and_limit()?;
 
         let states = self.state_repository.search(query, page, limit).await?;
         Ok(states.into_iter().map(|s| s.into()).collect())
     }
+
+    /// The state to show for a lookup: a retired one is followed to its
+    /// successor, noting where the lookup started
+    async fn follow_to_current(&self, state: Option<State>) -> AppResult<Option<StateDto>> {
+        let Some(state) = state else {
+            return Ok(None);
+        };
+
+        let (state, requested) = follow_successors(state, |id| self.state_repository.find_by_id(id)).await?;
+        let mut dto = StateDto::from(state);
+        dto.redirected_from = requested.map(|state| RedirectDto::new(state.id, state.code.to_string(), state.status));
+        Ok(Some(dto))
+    }
 }
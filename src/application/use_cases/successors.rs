@@ -0,0 +1,39 @@
+//! Following retired units to the units that replaced them
+
+use std::future::Future;
+
+use uuid::Uuid;
+
+use crate::domain::entities::unit_status::Retirable;
+use crate::errors::AppResult;
+
+/// Successors followed before giving up; reforms rarely chain more than once
+const MAX_SUCCESSOR_HOPS: usize = 8;
+
+/// Follow `unit` through its successors
+///
+/// Returns the unit to show and, when that is a successor, the unit that was
+/// asked for. A retired unit without a successor is returned as it is.
+pub(crate) async fn follow_successors<T, F, Fut>(
+    unit: T,
+    find_by_id: F,
+) -> AppResult<(T, Option<T>)>
+where
+    T: Retirable,
+    F: Fn(Uuid) -> Fut,
+    Fut: Future<Output = AppResult<Option<T>>>,
+{
+    let mut current = unit;
+    let mut requested = None;
+    for _ in 0..MAX_SUCCESSOR_HOPS {
+        let Some(successor_id) = current.successor_id() else {
+            break;
+        };
+        let Some(successor) = find_by_id(successor_id).await? else {
+            break;
+        };
+        let previous = std::mem::replace(&mut current, successor);
+        requested.get_or_insert(previous);
+    }
+    Ok((current, requested))
+}
@@ -0,0 +1,58 @@
+use crate::application::dtos::sync_dto::{SyncChangesDto, SyncResponseDto};
+use crate::domain::entities::sync::{SyncResume, SyncToken};
+use crate::domain::repositories::sync_repository::SyncRepository;
+use crate::errors::AppResult;
+
+/// Most entities handed out by one sync; the rest follow on later pages
+pub const SYNC_PAGE_ROWS: usize = 5_000;
+
+/// Delta sync use cases
+#[derive(Clone)]
+pub struct SyncUseCases<R: SyncRepository + Clone> {
+    sync_repository: R,
+}
+
+impl<R: SyncRepository + Clone> SyncUseCases<R> {
+    pub fn new(sync_repository: R) -> Self {
+        Self { sync_repository }
+    }
+
+    /// Everything that changed since `since`, a token from an earlier sync or
+    /// a bundle manifest, or a full copy without one
+    ///
+    /// Changes come a page at a time; while `has_more` is set, the token
+    /// carries on from where the page stopped.
+    pub async fn sync(&self, since: Option<&str>) -> AppResult<SyncResponseDto> {
+        let token = since.map(SyncToken::decode).transpose()?;
+        let resume = token.and_then(|token| token.resume_at());
+        let since = match resume {
+            Some(resume) => resume.since,
+            None => token.map(|token| token.watermark()),
+        };
+        let batch = self
+            .sync_repository
+            .changes_since(since, resume.map(|resume| resume.position), SYNC_PAGE_ROWS)
+            .await?;
+
+        // The watermark of the first page holds until the last one is read
+        let watermark = match (token, resume) {
+            (Some(token), Some(_)) => token.watermark(),
+            _ => batch.watermark,
+        };
+        let token = match batch.next {
+            Some(position) => SyncToken::resume(watermark, SyncResume { since, position }),
+            None => SyncToken::new(watermark),
+        };
+
+        Ok(SyncResponseDto {
+            token: token.encode(),
+            has_more: batch.next.is_some(),
+            full: since.is_none(),
+            states: SyncChangesDto::from_changes(batch.states),
+            lgas: SyncChangesDto::from_changes(batch.lgas),
+            wards: SyncChangesDto::from_changes(batch.wards),
+            postal_codes: SyncChangesDto::from_changes(batch.postal_codes),
+            aliases: SyncChangesDto::from_changes(batch.aliases),
+        })
+    }
+}
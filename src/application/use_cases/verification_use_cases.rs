@@ -0,0 +1,300 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use chrono::Utc;
+
+use crate::application::dtos::verification_dto::{
+    FindingDto, RuleReportDto, VerificationReportDto, VerificationSummaryDto,
+};
+use crate::domain::entities::export::ExportRow;
+use crate::domain::entities::unit_level::UnitLevel;
+use crate::domain::entities::verification::{
+    DatasetSnapshot, Finding, IntegrityRule, EXPECTED_LGAS, EXPECTED_STATES,
+};
+use crate::domain::normalization::normalized;
+use crate::domain::repositories::verification_repository::VerificationRepository;
+use crate::domain::value_objects::{
+    BoundingBox, LgaCode, PostalCode, StateCode, WardCode, NIGERIA, NIGERIAN_STATES,
+};
+use crate::errors::AppResult;
+
+/// Dataset verification use cases
+///
+/// Every rule runs against the same snapshot, read once, so a report never
+/// mixes data from before and after a write.
+#[derive(Clone)]
+pub struct VerificationUseCases<R: VerificationRepository + Clone> {
+    verification_repository: R,
+}
+
+impl<R: VerificationRepository + Clone> VerificationUseCases<R> {
+    pub fn new(verification_repository: R) -> Self {
+        Self {
+            verification_repository,
+        }
+    }
+
+    /// Run `rules` against the data as it is now, listing up to `limit`
+    /// findings for each
+    pub async fn verify(
+        &self,
+        rules: &[IntegrityRule],
+        limit: usize,
+    ) -> AppResult<VerificationReportDto> {
+        let snapshot = self.verification_repository.snapshot().await?;
+
+        let rules: Vec<RuleReportDto> = rules
+            .iter()
+            .map(|&rule| {
+                let findings = check(rule, &snapshot);
+                RuleReportDto {
+                    rule: rule.as_str().to_string(),
+                    description: rule.description().to_string(),
+                    passed: findings.is_empty(),
+                    violations: findings.len(),
+                    truncated: findings.len() > limit,
+                    findings: findings
+                        .into_iter()
+                        .take(limit)
+                        .map(FindingDto::from)
+                        .collect(),
+                }
+            })
+            .collect();
+
+        let passed = rules.iter().filter(|report| report.passed).count();
+        Ok(VerificationReportDto {
+            passed: passed == rules.len(),
+            checked_at: Utc::now(),
+            fingerprint: snapshot.fingerprint,
+            summary: VerificationSummaryDto {
+                rules: rules.len(),
+                passed,
+                failed: rules.len() - passed,
+                violations: rules.iter().map(|report| report.violations).sum(),
+            },
+            rules,
+        })
+    }
+}
+
+/// Every violation of `rule` in the snapshot, in code order
+fn check(rule: IntegrityRule, snapshot: &DatasetSnapshot) -> Vec<Finding> {
+    match rule {
+        IntegrityRule::ExpectedCounts => expected_counts(snapshot),
+        IntegrityRule::LgasHaveWards => childless(snapshot, UnitLevel::Lga, UnitLevel::Ward),
+        IntegrityRule::WardsHavePostalCodes => {
+            childless(snapshot, UnitLevel::Ward, UnitLevel::PostalCode)
+        }
+        IntegrityRule::CoordinatesInNigeria => located_outside(snapshot, |_| Some(NIGERIA))
+            .map(|(row, (lat, lng))| {
+                Finding::unit(
+                    UnitLevel::PostalCode,
+                    row,
+                    format!("({}, {}) is outside Nigeria", lat, lng),
+                )
+            })
+            .collect(),
+        IntegrityRule::CoordinatesInState => located_outside(snapshot, |row| {
+            StateCode::new(row.state_code.clone())
+                .ok()
+                .and_then(|state_code| BoundingBox::for_state(&state_code))
+        })
+        .map(|(row, (lat, lng))| {
+            Finding::unit(
+                UnitLevel::PostalCode,
+                row,
+                format!(
+                    "({}, {}) is outside {} ({})",
+                    lat, lng, row.state_name, row.state_code
+                ),
+            )
+        })
+        .collect(),
+        IntegrityRule::UniqueNames => [
+            (UnitLevel::State, None),
+            (UnitLevel::Lga, Some(UnitLevel::State)),
+            (UnitLevel::Ward, Some(UnitLevel::Lga)),
+        ]
+        .into_iter()
+        .flat_map(|(level, parent)| duplicate_names(snapshot, level, parent))
+        .collect(),
+        IntegrityRule::CodeFormats => code_formats(snapshot),
+    }
+}
+
+fn expected_counts(snapshot: &DatasetSnapshot) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    if snapshot.states.len() != EXPECTED_STATES {
+        findings.push(Finding::dataset(
+            UnitLevel::State,
+            format!(
+                "{} active states, expected {}",
+                snapshot.states.len(),
+                EXPECTED_STATES
+            ),
+        ));
+    }
+    for state in NIGERIAN_STATES.iter() {
+        if !snapshot
+            .states
+            .iter()
+            .any(|row| row.state_code == state.iso_code)
+        {
+            findings.push(Finding {
+                code: Some(state.iso_code.to_string()),
+                ..Finding::dataset(
+                    UnitLevel::State,
+                    format!("no active state for {}", state.name),
+                )
+            });
+        }
+    }
+    if snapshot.lgas.len() != EXPECTED_LGAS {
+        findings.push(Finding::dataset(
+            UnitLevel::Lga,
+            format!(
+                "{} active LGAs, expected {}",
+                snapshot.lgas.len(),
+                EXPECTED_LGAS
+            ),
+        ));
+    }
+
+    findings
+}
+
+/// Units of `level` with no active units of `child` beneath them
+fn childless(snapshot: &DatasetSnapshot, level: UnitLevel, child: UnitLevel) -> Vec<Finding> {
+    let parents: HashSet<&str> = snapshot
+        .units(child)
+        .iter()
+        .filter_map(|row| row.code(level))
+        .collect();
+
+    snapshot
+        .units(level)
+        .iter()
+        .filter(|row| !row.code(level).is_some_and(|code| parents.contains(code)))
+        .map(|row| {
+            Finding::unit(
+                level,
+                row,
+                format!("has no active {}s", child.as_str().replace('_', " ")),
+            )
+        })
+        .collect()
+}
+
+/// Postal codes whose coordinates lie outside the box `extent` gives them;
+/// those without coordinates, or without a box, are skipped
+fn located_outside<'a>(
+    snapshot: &'a DatasetSnapshot,
+    extent: impl Fn(&ExportRow) -> Option<BoundingBox> + 'a,
+) -> impl Iterator<Item = (&'a ExportRow, (f64, f64))> + 'a {
+    snapshot.postal_codes.iter().filter_map(move |row| {
+        let (lat, lng) = (row.lat?, row.lng?);
+        let extent = extent(row)?;
+        (!extent.contains(lat, lng)).then_some((row, (lat, lng)))
+    })
+}
+
+/// Units of `level` whose normalized name matches an earlier unit's under the
+/// same `parent`, or nationally when there is none
+fn duplicate_names(
+    snapshot: &DatasetSnapshot,
+    level: UnitLevel,
+    parent: Option<UnitLevel>,
+) -> Vec<Finding> {
+    let mut seen: HashMap<(Option<&str>, String), &ExportRow> = HashMap::new();
+    let mut findings = Vec::new();
+
+    for row in snapshot.units(level) {
+        let Some(name) = row.name(level) else {
+            continue;
+        };
+        let parent_code = parent.and_then(|parent| row.code(parent));
+        match seen.entry((parent_code, normalized(name))) {
+            Entry::Vacant(entry) => {
+                entry.insert(row);
+            }
+            Entry::Occupied(entry) => {
+                let first = entry.get();
+                let under = parent_code
+                    .map(|code| format!(" under {}", code))
+                    .unwrap_or_default();
+                findings.push(Finding::unit(
+                    level,
+                    row,
+                    format!(
+                        "'{}' normalizes to the same name as '{}' ({}){}",
+                        name,
+                        first.name(level).unwrap_or_default(),
+                        first.code(level).unwrap_or_default(),
+                        under
+                    ),
+                ));
+            }
+        }
+    }
+
+    findings
+}
+
+fn code_formats(snapshot: &DatasetSnapshot) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut unit = |level: UnitLevel, row: &ExportRow, detail: String| {
+        findings.push(Finding::unit(level, row, detail))
+    };
+
+    for row in &snapshot.states {
+        if let Err(e) = StateCode::new(row.state_code.clone()) {
+            unit(UnitLevel::State, row, e.to_string());
+        }
+    }
+    for row in &snapshot.lgas {
+        match LgaCode::new(row.lga_code.clone().unwrap_or_default()) {
+            Err(e) => unit(UnitLevel::Lga, row, e.to_string()),
+            Ok(code) if code.state_code().as_str() != row.state_code => unit(
+                UnitLevel::Lga,
+                row,
+                format!("does not start with its state's code {}", row.state_code),
+            ),
+            Ok(_) => {}
+        }
+    }
+    for row in &snapshot.wards {
+        let lga_code = row.lga_code.as_deref().unwrap_or_default();
+        match WardCode::new(row.ward_code.clone().unwrap_or_default()) {
+            Err(e) => unit(UnitLevel::Ward, row, e.to_string()),
+            Ok(code) if code.lga_code().as_str() != lga_code => unit(
+                UnitLevel::Ward,
+                row,
+                format!("does not start with its LGA's code {}", lga_code),
+            ),
+            Ok(_) => {}
+        }
+    }
+    for row in &snapshot.postal_codes {
+        match PostalCode::new(row.postal_code.clone().unwrap_or_default()) {
+            Err(e) => unit(UnitLevel::PostalCode, row, e.to_string()),
+            Ok(code) => {
+                let in_state = StateCode::new(row.state_code.clone())
+                    .map(|state_code| code.matches_state(&state_code))
+                    .unwrap_or(true);
+                if !in_state {
+                    unit(
+                        UnitLevel::PostalCode,
+                        row,
+                        format!(
+                            "prefix is not one assigned to {} ({})",
+                            row.state_name, row.state_code
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    findings
+}
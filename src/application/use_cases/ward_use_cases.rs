@@ -1,8 +1,12 @@
 use uuid::Uuid;
+use validator::Validate;
 
-use crate::domain::entities::ward::{CreateWardRequest, UpdateWardRequest};
+use crate::domain::entities::ward::{Ward, CreateWardRequest, UpdateWardRequest};
+use crate::domain::entities::unit_status::UpdateStatusRequest;
 use crate::domain::repositories::ward_repository::WardRepository;
-use crate::application::dtos::{WardDto, PaginatedResponse, PaginationParams};
+use crate::domain::value_objects::WardCode;
+use crate::application::dtos::{WardDto, RedirectDto, PaginatedResponse, PaginationParams};
+use crate::application::use_cases::successors::follow_successors;
 use crate::errors::AppResult;
 
 /// Ward use cases
@@ -18,15 +22,12 @@ impl<R: WardRepository + Clone> WardUseCases<R> {
 
     /// Get wards by LGA ID with pagination
     pub async fn get_wards_by_lga(&self, lga_id: Uuid, params: PaginationParams) -> AppResult<PaginatedResponse<WardDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
+        let request = params.page_request()?;
 
-        let wards = self.ward_repository.find_by_lga_id(lga_id, page, limit).await?;
+        let wards = self.ward_repository.find_by_lga_id(lga_id, &request).await?;
         let total = self.ward_repository.count_by_lga(lga_id).await?;
 
-        let ward_dtos: Vec<WardDto> = wards.into_iter().map(|w| w.into()).collect();
-
-        Ok(PaginatedResponse::new(ward_dtos, page, limit, total))
+        Ok(PaginatedResponse::from_page(wards, &request, total))
     }
 
     /// Get wards by LGA ID with pagination (alternative method name)
@@ -37,17 +38,28 @@ impl<R: WardRepository + Clone> WardUseCases<R> {
     /// Get ward by ID
     pub async fn get_ward_by_id(&self, id: Uuid) -> AppResult<Option<WardDto>> {
         let ward = self.ward_repository.find_by_id(id).await?;
-        Ok(ward.map(|w| w.into()))
+        self.follow_to_current(ward).await
+    }
+
+    /// Get ward by code, e.g. `NG-LA-IKJ-W03`
+    pub async fn get_ward_by_code(&self, code: &str) -> AppResult<Option<WardDto>> {
+        let ward_code = WardCode::new(code.to_string())
+            .map_err(|e| crate::errors::AppError::invalid_param("code", e))?;
+
+        let ward = self.ward_repository.find_by_code(&ward_code).await?;
+        self.follow_to_current(ward).await
     }
 
     /// Create a new ward
     pub async fn create_ward(&self, lga_id: Uuid, request: CreateWardRequest) -> AppResult<WardDto> {
+        request.validate()?;
         let ward = self.ward_repository.create(&request, lga_id).await?;
         Ok(ward.into())
     }
 
     /// Update ward
     pub async fn update_ward(&self, id: Uuid, request: UpdateWardRequest) -> AppResult<WardDto> {
+        request.validate()?;
         let ward = self.ward_repository.update(id, &request).await?;
         Ok(ward.into())
     }
@@ -57,12 +69,31 @@ impl<R: WardRepository + Clone> WardUseCases<R> {
         self.ward_repository.delete(id).await
     }
 
+    /// Deprecate, merge or reactivate a ward
+    pub async fn set_ward_status(&self, id: Uuid, request: UpdateStatusRequest) -> AppResult<WardDto> {
+        request.validate()?;
+        let ward = self.ward_repository.set_status(id, &request).await?;
+        Ok(ward.into())
+    }
+
     /// Search wards
     pub async fn search_wards(&self, query: &str, params: PaginationParams) -> AppResult<Vec<WardDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
+        let (page, limit) = params.page_and_limit()?;
 
         let wards = self.ward_repository.search(query, page, limit).await?;
         Ok(wards.into_iter().map(|w| w.into()).collect())
     }
+
+    /// The ward to show for a lookup: a retired one is followed to its
+    /// successor, noting where the lookup started
+    async fn follow_to_current(&self, ward: Option<Ward>) -> AppResult<Option<WardDto>> {
+        let Some(ward) = ward else {
+            return Ok(None);
+        };
+
+        let (ward, requested) = follow_successors(ward, |id| self.ward_repository.find_by_id(id)).await?;
+        let mut dto = WardDto::from(ward);
+        dto.redirected_from = requested.map(|ward| RedirectDto::new(ward.id, ward.code.to_string(), ward.status));
+        Ok(Some(dto))
+    }
 }
\ No newline at end of file
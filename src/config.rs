@@ -1,11 +1,20 @@
 use serde::Deserialize;
 use std::env;
+use std::path::PathBuf;
+
+use crate::application::use_cases::autocomplete_use_cases::DEFAULT_AUTOCOMPLETE_REFRESH_SECS;
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct Config {
     pub database_url: String,
     pub server_host: String,
     pub server_port: u16,
+    /// Seconds between checks for data changes that rebuild the autocomplete index
+    pub autocomplete_refresh_secs: u64,
+    /// Bearer token for the `/admin/v1` API; the admin API is disabled when unset
+    pub admin_api_token: Option<String>,
+    /// Dataset bundle to serve from memory instead of the database
+    pub embedded_dataset: Option<PathBuf>,
 }
 
 impl Config {
@@ -13,6 +22,16 @@ impl Config {
         // Load from environment variables
         dotenvy::dotenv().ok(); // Load .env file if it exists
 
+        let autocomplete_refresh_secs = env::var("AUTOCOMPLETE_REFRESH_SECS")
+            .ok()
+            .and_then(|secs| secs.parse().ok())
+            .unwrap_or(DEFAULT_AUTOCOMPLETE_REFRESH_SECS);
+        if autocomplete_refresh_secs == 0 {
+            return Err(config::ConfigError::Message(
+                "AUTOCOMPLETE_REFRESH_SECS must be at least 1".to_string(),
+            ));
+        }
+
         Ok(Config {
             database_url: env::var("DATABASE_URL")
                 .unwrap_or_else(|_| "postgresql://localhost/nigeria_geo".to_string()),
@@ -21,6 +40,14 @@ impl Config {
                 .unwrap_or_else(|_| "3000".to_string())
                 .parse()
                 .unwrap_or(3000),
+            autocomplete_refresh_secs,
+            admin_api_token: env::var("ADMIN_API_TOKEN")
+                .ok()
+                .filter(|token| !token.is_empty()),
+            embedded_dataset: env::var("EMBEDDED_DATASET")
+                .ok()
+                .filter(|path| !path.is_empty())
+                .map(PathBuf::from),
         })
     }
 }
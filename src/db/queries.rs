@@ -10,7 +10,7 @@ pub async fn get_states(
     page: u32,
     limit: u32,
 ) -> AppResult<PaginatedResponse<State>> {
-    let offset = (page - 1) * limit;
+    let offset = page.saturating_sub(1) * limit;
     
     let states = sqlx::query_as::<_, State>(
         "SELECT id, name, code, created_at, updated_at FROM states ORDER BY name LIMIT $1 OFFSET $2"
@@ -46,7 +46,7 @@ pub async fn get_lgas_by_state(
     page: u32,
     limit: u32,
 ) -> AppResult<PaginatedResponse<Lga>> {
-    let offset = (page - 1) * limit;
+    let offset = page.saturating_sub(1) * limit;
     
     let lgas = sqlx::query_as::<_, Lga>(
         "SELECT id, state_id, name, code, created_at, updated_at FROM lgas WHERE state_id = $1 ORDER BY name LIMIT $2 OFFSET $3"
@@ -84,7 +84,7 @@ pub async fn get_wards_by_lga(
     page: u32,
     limit: u32,
 ) -> AppResult<PaginatedResponse<Ward>> {
-    let offset = (page - 1) * limit;
+    let offset = page.saturating_sub(1) * limit;
     
     let wards = sqlx::query_as::<_, Ward>(
         "SELECT id, lga_id, name, code, created_at, updated_at FROM wards WHERE lga_id = $1 ORDER BY name LIMIT $2 OFFSET $3"
@@ -122,7 +122,7 @@ pub async fn get_postal_codes_by_ward(
     page: u32,
     limit: u32,
 ) -> AppResult<PaginatedResponse<PostalCode>> {
-    let offset = (page - 1) * limit;
+    let offset = page.saturating_sub(1) * limit;
     
     let postal_codes = sqlx::query_as::<_, PostalCode>(
         "SELECT id, ward_id, postal_code, lat, lng, urban, created_at, updated_at FROM postal_codes WHERE ward_id = $1 ORDER BY postal_code LIMIT $2 OFFSET $3"
@@ -160,7 +160,7 @@ pub async fn search_all(
     page: u32,
     limit: u32,
 ) -> AppResult<SearchResult> {
-    let offset = (page - 1) * limit;
+    let offset = page.saturating_sub(1) * limit;
     let search_pattern = format!("%{}%", query);
 
     // Search states
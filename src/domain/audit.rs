@@ -0,0 +1,27 @@
+//! Who the writes made by the current task are attributed to
+//!
+//! The admin API runs each request as the caller it authenticated; anything
+//! else, such as startup backfills and command-line tools, writes as
+//! [`SYSTEM_ACTOR`] unless it says otherwise with [`as_actor`].
+
+use std::future::Future;
+use std::sync::Arc;
+
+/// Actor recorded for writes made outside [`as_actor`]
+pub const SYSTEM_ACTOR: &str = "system";
+
+tokio::task_local! {
+    static ACTOR: Arc<str>;
+}
+
+/// Run `future`, attributing the writes it makes to `actor`
+pub async fn as_actor<F: Future>(actor: impl Into<Arc<str>>, future: F) -> F::Output {
+    ACTOR.scope(actor.into(), future).await
+}
+
+/// Actor the current task's writes are attributed to
+pub fn current_actor() -> String {
+    ACTOR
+        .try_with(|actor| actor.to_string())
+        .unwrap_or_else(|_| SYSTEM_ACTOR.to_string())
+}
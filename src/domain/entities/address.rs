@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 use validator::Validate;
 
 use crate::domain::entities::{State, Lga, Ward, PostalCode};
-// use crate::domain::value_objects::{StateCode, LgaCode, WardCode, PostalCode as PostalCodeValue};
+use crate::domain::entities::unit_status::{Retirable, UnitStatus};
+use crate::domain::entities::search::MatchMode;
+use crate::domain::normalization::NormalizationRule;
 
 /// Complete address aggregate
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -37,12 +40,40 @@ impl Address {
 
     /// Check if this is a valid Nigerian address
     pub fn is_valid(&self) -> bool {
-        // Business rules for address validation
-        self.state.code.as_str().starts_with("NG-") &&
-        self.lga.code.as_str().starts_with("NG-") &&
-        self.ward.code.as_str().starts_with("NG-") &&
-        self.postal_code.postal_code.as_str().len() >= 5
+        // Each component's code must nest under its parent's, and the postal
+        // code prefix must belong to the state
+        self.lga.code.state_code() == self.state.code
+            && self.ward.code.lga_code() == self.lga.code
+            && self.postal_code.postal_code.matches_state(&self.state.code)
     }
+
+    /// Components that have been deprecated or merged away
+    pub fn deprecated_components(&self) -> Vec<DeprecatedComponent> {
+        let components: [(&'static str, &dyn Retirable); 4] = [
+            ("state", &self.state),
+            ("lga", &self.lga),
+            ("ward", &self.ward),
+            ("postal_code", &self.postal_code),
+        ];
+
+        components
+            .into_iter()
+            .filter(|(_, unit)| unit.status() != UnitStatus::Active)
+            .map(|(component, unit)| DeprecatedComponent {
+                component,
+                id: unit.id(),
+                status: unit.status(),
+                successor_id: unit.successor_id(),
+            })
+            .collect()
+    }
+}
+
+/// Address matched by similarity search, with its relevance score
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SimilarAddress {
+    pub address: Address,
+    pub score: f64, // 0.0 to 1.0
 }
 
 /// Address validation request
@@ -59,6 +90,10 @@ pub struct AddressValidationRequest {
     
     #[validate(length(min = 1))]
     pub postal_code: String,
+
+    /// How suggestions for an invalid address are found
+    #[serde(rename = "match", default)]
+    pub match_mode: MatchMode,
 }
 
 /// Address validation response
@@ -66,9 +101,37 @@ pub struct AddressValidationRequest {
 pub struct AddressValidationResponse {
     pub valid: bool,
     pub canonical: Option<Address>,
+    /// How each state, LGA and ward that was found matched its input
+    pub matches: Vec<ComponentMatch>,
+    /// Components of the canonical address that have been retired; the
+    /// address is still valid so callers can move to the successors
+    pub deprecated: Vec<DeprecatedComponent>,
     pub suggestions: Vec<AddressSuggestion>,
 }
 
+/// An address component that is no longer active
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DeprecatedComponent {
+    /// `state`, `lga`, `ward` or `postal_code`
+    pub component: &'static str,
+    pub id: Uuid,
+    pub status: UnitStatus,
+    /// The unit that replaces it, if one was named
+    pub successor_id: Option<Uuid>,
+}
+
+/// How one address component was matched by name or alias
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ComponentMatch {
+    /// `state`, `lga` or `ward`
+    pub component: &'static str,
+    pub input: String,
+    /// Name or alias the input matched
+    pub matched_name: String,
+    /// Normalization the input needed to match; empty for an exact match
+    pub rules: Vec<NormalizationRule>,
+}
+
 /// Address suggestion
 #[derive(Debug, Serialize)]
 pub struct AddressSuggestion {
@@ -0,0 +1,157 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::entities::search::SearchEntityType;
+
+/// Kind of entity an alias can name
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AliasEntityType {
+    State,
+    Lga,
+    Ward,
+}
+
+impl AliasEntityType {
+    /// Name stored in the `entity_type` column
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AliasEntityType::State => "state",
+            AliasEntityType::Lga => "lga",
+            AliasEntityType::Ward => "ward",
+        }
+    }
+
+    /// Parse the name returned by [`AliasEntityType::as_str`]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "state" => Some(AliasEntityType::State),
+            "lga" => Some(AliasEntityType::Lga),
+            "ward" => Some(AliasEntityType::Ward),
+            _ => None,
+        }
+    }
+
+    /// Table holding the entities of this type
+    pub fn table(&self) -> &'static str {
+        match self {
+            AliasEntityType::State => "states",
+            AliasEntityType::Lga => "lgas",
+            AliasEntityType::Ward => "wards",
+        }
+    }
+}
+
+impl From<AliasEntityType> for SearchEntityType {
+    fn from(entity_type: AliasEntityType) -> Self {
+        match entity_type {
+            AliasEntityType::State => SearchEntityType::State,
+            AliasEntityType::Lga => SearchEntityType::Lga,
+            AliasEntityType::Ward => SearchEntityType::Ward,
+        }
+    }
+}
+
+/// How an alias relates to the canonical name
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AliasType {
+    /// Another spelling in common use, e.g. "Ile-Ife"
+    Alternate,
+    /// Short form, e.g. "PH" or "AMAC"
+    Abbreviation,
+    /// Former name, e.g. "Bendel"
+    Historical,
+    /// Name in a local language, e.g. "Èkó"
+    Local,
+}
+
+impl AliasType {
+    /// Name stored in the `alias_type` column
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AliasType::Alternate => "alternate",
+            AliasType::Abbreviation => "abbreviation",
+            AliasType::Historical => "historical",
+            AliasType::Local => "local",
+        }
+    }
+
+    /// Parse the name returned by [`AliasType::as_str`]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "alternate" => Some(AliasType::Alternate),
+            "abbreviation" => Some(AliasType::Abbreviation),
+            "historical" => Some(AliasType::Historical),
+            "local" => Some(AliasType::Local),
+            _ => None,
+        }
+    }
+}
+
+/// Alias domain entity: another name for a state, LGA or ward
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Alias {
+    pub id: Uuid,
+    pub entity_type: AliasEntityType,
+    pub entity_id: Uuid,
+    pub name: String,
+    pub alias_type: AliasType,
+    /// ISO 639 language code, e.g. `yo`
+    pub language: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Alias {
+    /// Create a new alias
+    pub fn new(
+        entity_type: AliasEntityType,
+        entity_id: Uuid,
+        name: String,
+        alias_type: AliasType,
+        language: Option<String>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            entity_type,
+            entity_id,
+            name,
+            alias_type,
+            language,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// Alias creation request
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateAliasRequest {
+    pub entity_type: AliasEntityType,
+
+    pub entity_id: Uuid,
+
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+
+    pub alias_type: AliasType,
+
+    #[validate(length(min = 2, max = 3))]
+    pub language: Option<String>,
+}
+
+/// Alias update request
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateAliasRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+
+    pub alias_type: Option<AliasType>,
+
+    #[validate(length(min = 2, max = 3))]
+    pub language: Option<String>,
+}
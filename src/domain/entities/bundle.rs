@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+
+/// Version of the bundle's SQLite schema, stored as its `user_version`
+///
+/// Raised whenever a table or column changes, so SDKs can refuse bundles
+/// they cannot read.
+pub const BUNDLE_SCHEMA_VERSION: u32 = 2;
+
+/// Version of the bundle built from the data with `fingerprint`
+///
+/// The same data always gives the same version, so clients can tell whether
+/// their copy is current without downloading the bundle.
+pub fn bundle_version(fingerprint: &str) -> String {
+    let digest = format!("{:x}", Sha256::digest(fingerprint.as_bytes()));
+    digest[..16].to_string()
+}
+
+/// What was written to an offline bundle
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BundleContents {
+    /// Data fingerprint of the snapshot the bundle was written from
+    pub fingerprint: String,
+    pub version: String,
+    /// Where a delta sync from this bundle should start
+    pub watermark: DateTime<Utc>,
+    pub states: u64,
+    pub lgas: u64,
+    pub wards: u64,
+    pub postal_codes: u64,
+    pub aliases: u64,
+}
@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Kind of entity whose changes are recorded
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeEntityType {
+    State,
+    Lga,
+    Ward,
+    PostalCode,
+    Alias,
+}
+
+impl ChangeEntityType {
+    /// Name stored in the `entity_type` column
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeEntityType::State => "state",
+            ChangeEntityType::Lga => "lga",
+            ChangeEntityType::Ward => "ward",
+            ChangeEntityType::PostalCode => "postal_code",
+            ChangeEntityType::Alias => "alias",
+        }
+    }
+
+    /// Parse the name returned by [`ChangeEntityType::as_str`]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "state" => Some(ChangeEntityType::State),
+            "lga" => Some(ChangeEntityType::Lga),
+            "ward" => Some(ChangeEntityType::Ward),
+            "postal_code" => Some(ChangeEntityType::PostalCode),
+            "alias" => Some(ChangeEntityType::Alias),
+            _ => None,
+        }
+    }
+}
+
+/// What a change did to its entity
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeOperation {
+    Create,
+    Update,
+    Delete,
+}
+
+impl ChangeOperation {
+    /// Name stored in the `operation` column
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeOperation::Create => "create",
+            ChangeOperation::Update => "update",
+            ChangeOperation::Delete => "delete",
+        }
+    }
+
+    /// Parse the name returned by [`ChangeOperation::as_str`]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "create" => Some(ChangeOperation::Create),
+            "update" => Some(ChangeOperation::Update),
+            "delete" => Some(ChangeOperation::Delete),
+            _ => None,
+        }
+    }
+
+    /// Operation taking an entity from `before` to `after`; `None` when it
+    /// neither existed before nor exists after
+    pub fn between(before: Option<&Value>, after: Option<&Value>) -> Option<Self> {
+        match (before, after) {
+            (None, Some(_)) => Some(ChangeOperation::Create),
+            (Some(_), Some(_)) => Some(ChangeOperation::Update),
+            (Some(_), None) => Some(ChangeOperation::Delete),
+            (None, None) => None,
+        }
+    }
+}
+
+/// One recorded write to an entity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Change {
+    /// Position in the history; later changes have larger IDs
+    pub id: i64,
+    pub entity_type: ChangeEntityType,
+    pub entity_id: Uuid,
+    pub operation: ChangeOperation,
+    /// The entity before the change; `None` for creates
+    pub before: Option<Value>,
+    /// The entity after the change; `None` for deletes
+    pub after: Option<Value>,
+    /// Who made the change
+    pub actor: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// The net effect of the changes to one entity over a period
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EntityDiff {
+    pub entity_type: ChangeEntityType,
+    pub entity_id: Uuid,
+    /// The entity before the first change of the period
+    pub before: Option<Value>,
+    /// The entity after the last change of the period
+    pub after: Option<Value>,
+    /// Number of changes the entity went through
+    pub changes: u64,
+    pub last_changed_at: DateTime<Utc>,
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+/// A named, immutable release of the full hierarchy
+///
+/// Releasing copies the states, LGAs, wards, postal codes and aliases as they
+/// are at that moment; later edits change the live data but never a release.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DatasetVersion {
+    pub id: i32,
+    /// Name clients pin the release by, e.g. `2024.1`
+    pub name: String,
+    pub notes: Option<String>,
+    pub released_at: DateTime<Utc>,
+    /// Who made the release
+    pub released_by: String,
+    /// Last change history entry included in the release
+    pub history_id: i64,
+    /// Last change history entry included in the release before this one;
+    /// the changes between the two are this release's changelog
+    pub previous_history_id: i64,
+    pub states: u64,
+    pub lgas: u64,
+    pub wards: u64,
+    pub postal_codes: u64,
+}
+
+impl DatasetVersion {
+    /// Database schema holding the release's copy of the data
+    pub fn schema_name(&self) -> String {
+        schema_name(self.id)
+    }
+}
+
+/// Database schema holding the copy of the data for release `id`
+pub fn schema_name(id: i32) -> String {
+    format!("dataset_v{}", id)
+}
+
+/// Dataset release request
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateDatasetVersionRequest {
+    #[validate(length(min = 1, max = 50), custom(function = "validate_version_name"))]
+    pub name: String,
+
+    #[validate(length(max = 2000))]
+    pub notes: Option<String>,
+}
+
+/// Release names travel in the `X-Dataset-Version` header and in URLs, so
+/// they are limited to letters, digits, `.`, `_` and `-`
+fn validate_version_name(name: &str) -> Result<(), ValidationError> {
+    let valid = name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+    if valid {
+        Ok(())
+    } else {
+        let mut error = ValidationError::new("format");
+        error.message = Some("may only contain letters, digits, '.', '_' and '-'".into());
+        Err(error)
+    }
+}
@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use uuid::Uuid;
+
+use crate::domain::entities::unit_level::UnitLevel;
+use crate::errors::AppResult;
+
+/// One active unit with the codes and names of its ancestors
+///
+/// Fields below the exported level are `None`, as are the coordinates of
+/// postal codes that have none.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExportRow {
+    pub id: Uuid,
+    pub state_code: String,
+    pub state_name: String,
+    pub lga_code: Option<String>,
+    pub lga_name: Option<String>,
+    pub ward_code: Option<String>,
+    pub ward_name: Option<String>,
+    pub postal_code: Option<String>,
+    pub lat: Option<f64>,
+    pub lng: Option<f64>,
+    pub urban: Option<bool>,
+    /// When the unit itself was created and last changed
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ExportRow {
+    /// Code of the row's unit at `level`, itself or one of its ancestors
+    pub fn code(&self, level: UnitLevel) -> Option<&str> {
+        match level {
+            UnitLevel::State => Some(&self.state_code),
+            UnitLevel::Lga => self.lga_code.as_deref(),
+            UnitLevel::Ward => self.ward_code.as_deref(),
+            UnitLevel::PostalCode => self.postal_code.as_deref(),
+        }
+    }
+
+    /// Name of the row's unit at `level`; postal codes have none
+    pub fn name(&self, level: UnitLevel) -> Option<&str> {
+        match level {
+            UnitLevel::State => Some(&self.state_name),
+            UnitLevel::Lga => self.lga_name.as_deref(),
+            UnitLevel::Ward => self.ward_name.as_deref(),
+            UnitLevel::PostalCode => None,
+        }
+    }
+}
+
+/// Every active unit of one level, read from a single snapshot of the data
+pub struct ExportSnapshot {
+    /// Data fingerprint of the snapshot
+    pub fingerprint: String,
+    pub level: UnitLevel,
+    /// Rows in code order, fetched as they are consumed
+    pub rows: BoxStream<'static, AppResult<ExportRow>>,
+}
@@ -0,0 +1,65 @@
+use crate::domain::entities::unit_level::UnitLevel;
+use crate::domain::value_objects::{
+    Coordinates, LgaCode, PostalCode as PostalCodeValue, StateCode, WardCode,
+};
+
+/// One validated unit to create or update
+///
+/// Units are matched by code. A postal code is matched by its code within its
+/// ward, since one code can cover several wards. Parents are found from the
+/// code itself, except for postal codes, which name their ward.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportUnit {
+    State {
+        code: StateCode,
+        name: String,
+    },
+    Lga {
+        code: LgaCode,
+        name: String,
+    },
+    Ward {
+        code: WardCode,
+        name: String,
+    },
+    PostalCode {
+        postal_code: PostalCodeValue,
+        ward_code: WardCode,
+        /// Left as stored when updating and not given
+        coordinates: Option<Coordinates>,
+        /// Left as stored when updating and not given; `false` for new codes
+        urban: Option<bool>,
+    },
+}
+
+impl ImportUnit {
+    pub fn level(&self) -> UnitLevel {
+        match self {
+            ImportUnit::State { .. } => UnitLevel::State,
+            ImportUnit::Lga { .. } => UnitLevel::Lga,
+            ImportUnit::Ward { .. } => UnitLevel::Ward,
+            ImportUnit::PostalCode { .. } => UnitLevel::PostalCode,
+        }
+    }
+
+    /// The code the unit is matched by
+    pub fn code(&self) -> &str {
+        match self {
+            ImportUnit::State { code, .. } => code.as_str(),
+            ImportUnit::Lga { code, .. } => code.as_str(),
+            ImportUnit::Ward { code, .. } => code.as_str(),
+            ImportUnit::PostalCode { postal_code, .. } => postal_code.as_str(),
+        }
+    }
+}
+
+/// What importing one unit did
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportOutcome {
+    Created,
+    Updated,
+    /// The unit already exists exactly as given
+    Unchanged,
+    /// The unit was left alone, for the reason given
+    Rejected(String),
+}
@@ -1,14 +1,36 @@
 pub mod address;
+pub mod alias;
 pub mod api_usage;
+pub mod bundle;
+pub mod change;
+pub mod dataset_version;
+pub mod export;
+pub mod import;
 pub mod lga;
 pub mod postal_code;
+pub mod search;
 pub mod state;
+pub mod sync;
+pub mod unit_level;
+pub mod unit_status;
+pub mod verification;
 pub mod ward;
 
 // Re-exports for convenience
-pub use address::{Address, AddressValidationRequest};
+pub use address::{Address, AddressValidationRequest, SimilarAddress};
+pub use alias::{Alias, AliasEntityType, AliasType, CreateAliasRequest, UpdateAliasRequest};
 pub use api_usage::{ApiUsage, EndpointStats, HourlyStats, StatusCodeStats, UsageStats};
+pub use bundle::{BundleContents, BUNDLE_SCHEMA_VERSION};
+pub use change::{Change, ChangeEntityType, ChangeOperation, EntityDiff};
+pub use dataset_version::{CreateDatasetVersionRequest, DatasetVersion};
+pub use export::{ExportRow, ExportSnapshot};
+pub use import::{ImportOutcome, ImportUnit};
 pub use lga::{CreateLgaRequest, Lga, UpdateLgaRequest};
 pub use postal_code::{CreatePostalCodeRequest, PostalCode, UpdatePostalCodeRequest};
+pub use search::{AutocompleteEntry, SearchCounts, SearchEntityType, SearchHit};
 pub use state::{CreateStateRequest, State, UpdateStateRequest};
+pub use sync::{SyncBatch, SyncChanges, SyncToken, Tombstone, TombstoneReason};
+pub use unit_level::UnitLevel;
+pub use unit_status::{Retirable, UnitStatus, UpdateStatusRequest};
+pub use verification::{DatasetSnapshot, Finding, IntegrityRule};
 pub use ward::{CreateWardRequest, UpdateWardRequest, Ward};
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::domain::entities::unit_status::{Retirable, UnitStatus};
 use crate::domain::value_objects::{PostalCode as PostalCodeValue, Coordinates};
 
 /// Postal code domain entity
@@ -13,6 +14,9 @@ pub struct PostalCode {
     pub postal_code: PostalCodeValue,
     pub coordinates: Option<Coordinates>,
     pub urban: bool,
+    pub status: UnitStatus,
+    /// The unit that replaces this one once it is deprecated or merged
+    pub successor_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -32,11 +36,19 @@ impl PostalCode {
             postal_code,
             coordinates,
             urban,
+            status: UnitStatus::Active,
+            successor_id: None,
             created_at: now,
             updated_at: now,
         }
     }
 
+    /// Update postal code
+    pub fn update_code(&mut self, postal_code: PostalCodeValue) {
+        self.postal_code = postal_code;
+        self.updated_at = Utc::now();
+    }
+
     /// Update coordinates
     pub fn update_coordinates(&mut self, coordinates: Option<Coordinates>) {
         self.coordinates = coordinates;
@@ -58,12 +70,33 @@ impl PostalCode {
     pub fn get_coordinates(&self) -> Option<&Coordinates> {
         self.coordinates.as_ref()
     }
+
+    /// Deprecate, merge or reactivate the postal code
+    pub fn set_status(&mut self, status: UnitStatus, successor_id: Option<Uuid>) {
+        self.status = status;
+        self.successor_id = successor_id;
+        self.updated_at = Utc::now();
+    }
+}
+
+impl Retirable for PostalCode {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn status(&self) -> UnitStatus {
+        self.status
+    }
+
+    fn successor_id(&self) -> Option<Uuid> {
+        self.successor_id
+    }
 }
 
 /// Postal code creation request
 #[derive(Debug, Deserialize, Validate)]
 pub struct CreatePostalCodeRequest {
-    #[validate(length(min = 5, max = 10))]
+    #[validate(length(equal = 6))]
     pub postal_code: String,
     
     pub lat: Option<f64>,
@@ -74,7 +107,7 @@ pub struct CreatePostalCodeRequest {
 /// Postal code update request
 #[derive(Debug, Deserialize, Validate)]
 pub struct UpdatePostalCodeRequest {
-    #[validate(length(min = 5, max = 10))]
+    #[validate(length(equal = 6))]
     pub postal_code: Option<String>,
     
     pub lat: Option<f64>,
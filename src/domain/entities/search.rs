@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::normalization::NormalizationRule;
+
+/// Kind of entity a search hit refers to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchEntityType {
+    State,
+    Lga,
+    Ward,
+    PostalCode,
+}
+
+impl SearchEntityType {
+    /// Name used in queries and responses
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchEntityType::State => "state",
+            SearchEntityType::Lga => "lga",
+            SearchEntityType::Ward => "ward",
+            SearchEntityType::PostalCode => "postal_code",
+        }
+    }
+
+    /// Order among equally scored hits: states, then LGAs, wards and postal codes
+    pub fn level(&self) -> i32 {
+        match self {
+            SearchEntityType::State => 1,
+            SearchEntityType::Lga => 2,
+            SearchEntityType::Ward => 3,
+            SearchEntityType::PostalCode => 4,
+        }
+    }
+
+    /// Parse the name returned by [`SearchEntityType::as_str`]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "state" => Some(SearchEntityType::State),
+            "lga" => Some(SearchEntityType::Lga),
+            "ward" => Some(SearchEntityType::Ward),
+            "postal_code" => Some(SearchEntityType::PostalCode),
+            _ => None,
+        }
+    }
+}
+
+/// How a search query is compared with names
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchMode {
+    /// Literal, normalized and trigram matching
+    #[default]
+    Standard,
+    /// Names that sound like the query; see [`crate::domain::phonetic`]
+    Phonetic,
+}
+
+impl MatchMode {
+    /// Name used in the `match` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchMode::Standard => "standard",
+            MatchMode::Phonetic => "phonetic",
+        }
+    }
+}
+
+/// Single ranked result of a unified search
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SearchHit {
+    pub entity_type: SearchEntityType,
+    pub id: Uuid,
+    pub name: String,
+    pub code: String,
+    /// Names of the enclosing entities, nearest first, e.g. "Ikeja, Lagos"
+    pub parent_path: Option<String>,
+    /// Alias that matched the query when it matched better than the name
+    pub matched_alias: Option<String>,
+    /// Normalization the query needed to match the name or alias; empty for
+    /// literal and fuzzy matches
+    #[serde(default)]
+    pub normalization: Vec<NormalizationRule>,
+    pub score: f64, // 0.0 to 1.0
+}
+
+/// Number of matches per entity type for a unified search
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SearchCounts {
+    pub states: u64,
+    pub lgas: u64,
+    pub wards: u64,
+    pub postal_codes: u64,
+}
+
+impl SearchCounts {
+    /// Total matches across all entity types
+    pub fn total(&self) -> u64 {
+        self.states + self.lgas + self.wards + self.postal_codes
+    }
+}
+
+/// Entity loaded into the in-memory autocomplete index
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AutocompleteEntry {
+    pub entity_type: SearchEntityType,
+    pub id: Uuid,
+    pub name: String,
+    pub code: String,
+    /// Code of the enclosing state, or the state's own code
+    pub state_code: String,
+    /// Code of the enclosing LGA, or the LGA's own code; `None` for states
+    pub lga_code: Option<String>,
+    /// Names of the enclosing entities, nearest first, e.g. "Ikeja, Lagos"
+    pub parent_path: Option<String>,
+    /// Other names the entity is known by
+    pub aliases: Vec<String>,
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::domain::entities::unit_status::{Retirable, UnitStatus};
 use crate::domain::value_objects::StateCode;
 
 /// State domain entity
@@ -11,6 +12,9 @@ pub struct State {
     pub id: Uuid,
     pub name: String,
     pub code: StateCode,
+    pub status: UnitStatus,
+    /// The unit that replaces this one once it is deprecated or merged
+    pub successor_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -23,6 +27,8 @@ impl State {
             id: Uuid::new_v4(),
             name,
             code,
+            status: UnitStatus::Active,
+            successor_id: None,
             created_at: now,
             updated_at: now,
         }
@@ -39,6 +45,27 @@ impl State {
         self.code = code;
         self.updated_at = Utc::now();
     }
+
+    /// Deprecate, merge or reactivate the state
+    pub fn set_status(&mut self, status: UnitStatus, successor_id: Option<Uuid>) {
+        self.status = status;
+        self.successor_id = successor_id;
+        self.updated_at = Utc::now();
+    }
+}
+
+impl Retirable for State {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn status(&self) -> UnitStatus {
+        self.status
+    }
+
+    fn successor_id(&self) -> Option<Uuid> {
+        self.successor_id
+    }
 }
 
 /// State creation request
@@ -47,7 +74,7 @@ pub struct CreateStateRequest {
     #[validate(length(min = 2, max = 100))]
     pub name: String,
     
-    #[validate(length(min = 2, max = 10))]
+    #[validate(length(equal = 5))]
     pub code: String,
 }
 
@@ -57,6 +84,6 @@ pub struct UpdateStateRequest {
     #[validate(length(min = 2, max = 100))]
     pub name: Option<String>,
     
-    #[validate(length(min = 2, max = 10))]
+    #[validate(length(equal = 5))]
     pub code: Option<String>,
 }
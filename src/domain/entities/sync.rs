@@ -0,0 +1,173 @@
+//! Delta sync for clients holding a local copy of the data
+//!
+//! A sync token marks how far a client has read. It is opaque to clients:
+//! base64url-encoded JSON holding a watermark, the time up to which every
+//! change has been handed out. Changes are found by `updated_at`, and hard
+//! deletes through the append-only change history, so a token never expires.
+//!
+//! Changes are handed out a page at a time. The token of a page that is not
+//! the last also holds where the changes being paged started and the
+//! position of the last entity sent; the watermark only takes over once the
+//! last page has been read.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::{Alias, Lga, PostalCode, State, Ward};
+use crate::errors::{AppError, InvalidParam};
+
+/// Position in the stream of changes, handed to clients after each sync
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncToken {
+    #[serde(rename = "w")]
+    watermark: DateTime<Utc>,
+    /// Set while more pages of changes remain to be read
+    #[serde(rename = "r", default, skip_serializing_if = "Option::is_none")]
+    resume: Option<SyncResume>,
+}
+
+/// Where to carry on reading a paged sync
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncResume {
+    /// The `since` of the first page; `None` for a full copy
+    #[serde(rename = "s")]
+    pub since: Option<DateTime<Utc>>,
+    #[serde(rename = "p")]
+    pub position: SyncPosition,
+}
+
+/// The kinds of change a sync reads, in the order it reads them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncStage {
+    States,
+    Lgas,
+    Wards,
+    PostalCodes,
+    Aliases,
+    Deletions,
+}
+
+impl SyncStage {
+    pub const ALL: [SyncStage; 6] = [
+        SyncStage::States,
+        SyncStage::Lgas,
+        SyncStage::Wards,
+        SyncStage::PostalCodes,
+        SyncStage::Aliases,
+        SyncStage::Deletions,
+    ];
+}
+
+/// The last entity handed out: its kind of change, and the `updated_at` and
+/// `id` it is ordered by; `after` is `None` when nothing of `stage` was sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncPosition {
+    #[serde(rename = "e")]
+    pub stage: SyncStage,
+    #[serde(rename = "a")]
+    pub after: Option<(DateTime<Utc>, Uuid)>,
+}
+
+impl SyncToken {
+    pub fn new(watermark: DateTime<Utc>) -> Self {
+        Self {
+            watermark,
+            resume: None,
+        }
+    }
+
+    /// Token for the next page of a sync that began with `resume.since`
+    pub fn resume(watermark: DateTime<Utc>, resume: SyncResume) -> Self {
+        Self {
+            watermark,
+            resume: Some(resume),
+        }
+    }
+
+    /// Parse a token returned by a sync or in a bundle's manifest
+    pub fn decode(encoded: &str) -> Result<Self, AppError> {
+        URL_SAFE_NO_PAD
+            .decode(encoded.trim())
+            .ok()
+            .and_then(|json| serde_json::from_slice(&json).ok())
+            .ok_or_else(|| {
+                AppError::InvalidParam(InvalidParam::new(
+                    "since",
+                    "sync_token",
+                    "must be a token returned by a sync or a bundle manifest",
+                ))
+            })
+    }
+
+    /// Opaque form handed to clients
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("sync token serializes to JSON");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    /// Changes made at or after this time have not been handed out yet,
+    /// once any remaining pages have been read
+    pub fn watermark(&self) -> DateTime<Utc> {
+        self.watermark
+    }
+
+    /// Where to carry on, if this token is from a page that is not the last
+    pub fn resume_at(&self) -> Option<SyncResume> {
+        self.resume
+    }
+}
+
+/// Why a client should drop an entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TombstoneReason {
+    /// The entity was deleted outright
+    Deleted,
+    /// The unit was deprecated or merged; see its successor
+    Retired,
+}
+
+/// Marker for an entity a client should drop from its copy
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tombstone {
+    pub id: Uuid,
+    pub reason: TombstoneReason,
+    /// For retired units, the unit that replaces this one, if any
+    pub successor_id: Option<Uuid>,
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// Changes to one type of entity
+#[derive(Debug, Clone)]
+pub struct SyncChanges<T> {
+    pub created: Vec<T>,
+    pub updated: Vec<T>,
+    pub deleted: Vec<Tombstone>,
+}
+
+impl<T> Default for SyncChanges<T> {
+    fn default() -> Self {
+        Self {
+            created: Vec::new(),
+            updated: Vec::new(),
+            deleted: Vec::new(),
+        }
+    }
+}
+
+/// A page of what changed after a watermark, read from one snapshot
+#[derive(Debug, Clone, Default)]
+pub struct SyncBatch {
+    /// Where the next sync should start, taken before the snapshot
+    pub watermark: DateTime<Utc>,
+    /// Where the next page starts, if the changes did not fit in this one
+    pub next: Option<SyncPosition>,
+    pub states: SyncChanges<State>,
+    pub lgas: SyncChanges<Lga>,
+    pub wards: SyncChanges<Ward>,
+    pub postal_codes: SyncChanges<PostalCode>,
+    pub aliases: SyncChanges<Alias>,
+}
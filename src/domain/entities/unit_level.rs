@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// A level of the hierarchy, ordered parents first
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum UnitLevel {
+    State,
+    Lga,
+    Ward,
+    PostalCode,
+}
+
+impl UnitLevel {
+    pub const ALL: [UnitLevel; 4] = [
+        UnitLevel::State,
+        UnitLevel::Lga,
+        UnitLevel::Ward,
+        UnitLevel::PostalCode,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnitLevel::State => "state",
+            UnitLevel::Lga => "lga",
+            UnitLevel::Ward => "ward",
+            UnitLevel::PostalCode => "postal_code",
+        }
+    }
+
+    /// Parse a level name, accepting the plural and hyphenated forms
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "state" | "states" => Some(UnitLevel::State),
+            "lga" | "lgas" => Some(UnitLevel::Lga),
+            "ward" | "wards" => Some(UnitLevel::Ward),
+            "postal_code" | "postal_codes" => Some(UnitLevel::PostalCode),
+            _ => None,
+        }
+    }
+}
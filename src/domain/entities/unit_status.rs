@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::errors::{AppError, AppResult, InvalidParam};
+
+/// Where a state, LGA, ward or postal code is in its lifecycle
+///
+/// Units are never deleted. A boundary reform deprecates a unit, optionally
+/// naming the unit that replaces it, or merges it into a successor.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum UnitStatus {
+    #[default]
+    Active,
+    Deprecated,
+    Merged,
+}
+
+impl UnitStatus {
+    /// Name stored in the `status` column
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnitStatus::Active => "active",
+            UnitStatus::Deprecated => "deprecated",
+            UnitStatus::Merged => "merged",
+        }
+    }
+
+    /// Parse the name returned by [`UnitStatus::as_str`]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "active" => Some(UnitStatus::Active),
+            "deprecated" => Some(UnitStatus::Deprecated),
+            "merged" => Some(UnitStatus::Merged),
+            _ => None,
+        }
+    }
+}
+
+/// A unit that can be retired in favour of a successor
+pub trait Retirable {
+    fn id(&self) -> Uuid;
+
+    fn status(&self) -> UnitStatus;
+
+    /// The unit that replaces this one, if any
+    fn successor_id(&self) -> Option<Uuid>;
+}
+
+/// Status change request
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateStatusRequest {
+    pub status: UnitStatus,
+
+    /// Required when merging, optional when deprecating, and not allowed
+    /// when reactivating
+    pub successor_id: Option<Uuid>,
+}
+
+impl UpdateStatusRequest {
+    /// Check the successor suits the status
+    pub fn validate(&self) -> AppResult<()> {
+        match (self.status, self.successor_id) {
+            (UnitStatus::Merged, None) => Err(AppError::InvalidParam(InvalidParam::new(
+                "successor_id",
+                "required",
+                "a merged unit needs the successor it was merged into",
+            ))),
+            (UnitStatus::Active, Some(_)) => Err(AppError::InvalidParam(InvalidParam::new(
+                "successor_id",
+                "absent",
+                "an active unit has no successor",
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
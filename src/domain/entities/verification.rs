@@ -0,0 +1,141 @@
+//! Integrity rules checked against the whole dataset
+//!
+//! The rules cover what the schema cannot enforce: every unit having
+//! children, coordinates lying where their state is, names staying distinct
+//! once normalized, and codes following the formats of the value objects.
+
+use uuid::Uuid;
+
+use crate::domain::entities::export::ExportRow;
+use crate::domain::entities::unit_level::UnitLevel;
+use crate::domain::value_objects::NIGERIAN_STATES;
+
+/// Active states expected: the 36 states and the Federal Capital Territory
+pub const EXPECTED_STATES: usize = NIGERIAN_STATES.len();
+
+/// Active LGAs expected
+pub const EXPECTED_LGAS: usize = 774;
+
+/// A check run over every active unit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntegrityRule {
+    ExpectedCounts,
+    LgasHaveWards,
+    WardsHavePostalCodes,
+    CoordinatesInNigeria,
+    CoordinatesInState,
+    UniqueNames,
+    CodeFormats,
+}
+
+impl IntegrityRule {
+    pub const ALL: [IntegrityRule; 7] = [
+        IntegrityRule::ExpectedCounts,
+        IntegrityRule::LgasHaveWards,
+        IntegrityRule::WardsHavePostalCodes,
+        IntegrityRule::CoordinatesInNigeria,
+        IntegrityRule::CoordinatesInState,
+        IntegrityRule::UniqueNames,
+        IntegrityRule::CodeFormats,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IntegrityRule::ExpectedCounts => "expected_counts",
+            IntegrityRule::LgasHaveWards => "lgas_have_wards",
+            IntegrityRule::WardsHavePostalCodes => "wards_have_postal_codes",
+            IntegrityRule::CoordinatesInNigeria => "coordinates_in_nigeria",
+            IntegrityRule::CoordinatesInState => "coordinates_in_state",
+            IntegrityRule::UniqueNames => "unique_names",
+            IntegrityRule::CodeFormats => "code_formats",
+        }
+    }
+
+    /// Parse a rule name, accepting the hyphenated form
+    pub fn parse(value: &str) -> Option<Self> {
+        let name = value.trim().to_ascii_lowercase().replace('-', "_");
+        Self::ALL.into_iter().find(|rule| rule.as_str() == name)
+    }
+
+    /// What the rule checks, for people reading a report
+    pub fn description(&self) -> &'static str {
+        match self {
+            IntegrityRule::ExpectedCounts => {
+                "There are 37 active states, one for each ISO code, and 774 active LGAs"
+            }
+            IntegrityRule::LgasHaveWards => "Every active LGA has at least one active ward",
+            IntegrityRule::WardsHavePostalCodes => {
+                "Every active ward has at least one active postal code"
+            }
+            IntegrityRule::CoordinatesInNigeria => {
+                "Postal code coordinates fall inside Nigeria's bounding box"
+            }
+            IntegrityRule::CoordinatesInState => {
+                "Postal code coordinates fall inside the extent of their state"
+            }
+            IntegrityRule::UniqueNames => {
+                "No two units under the same parent share a normalized name"
+            }
+            IntegrityRule::CodeFormats => {
+                "Codes follow their formats and start with their parent's code"
+            }
+        }
+    }
+}
+
+/// A unit breaking an integrity rule, or the dataset as a whole when no
+/// single unit is to blame
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub level: UnitLevel,
+    pub id: Option<Uuid>,
+    pub code: Option<String>,
+    /// What is wrong, for people reading a report
+    pub detail: String,
+}
+
+impl Finding {
+    /// Finding about the unit in `row`, an export row of `level`
+    pub fn unit(level: UnitLevel, row: &ExportRow, detail: impl Into<String>) -> Self {
+        Self {
+            level,
+            id: Some(row.id),
+            code: row.code(level).map(str::to_string),
+            detail: detail.into(),
+        }
+    }
+
+    /// Finding about the units of `level` as a whole
+    pub fn dataset(level: UnitLevel, detail: impl Into<String>) -> Self {
+        Self {
+            level,
+            id: None,
+            code: None,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Every active unit, with its parents, read from a single snapshot of the
+/// data
+#[derive(Debug, Clone, Default)]
+pub struct DatasetSnapshot {
+    /// Data fingerprint of the snapshot
+    pub fingerprint: String,
+    pub states: Vec<ExportRow>,
+    pub lgas: Vec<ExportRow>,
+    pub wards: Vec<ExportRow>,
+    pub postal_codes: Vec<ExportRow>,
+}
+
+impl DatasetSnapshot {
+    /// The active units of `level`, in code order
+    pub fn units(&self, level: UnitLevel) -> &[ExportRow] {
+        match level {
+            UnitLevel::State => &self.states,
+            UnitLevel::Lga => &self.lgas,
+            UnitLevel::Ward => &self.wards,
+            UnitLevel::PostalCode => &self.postal_codes,
+        }
+    }
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::domain::entities::unit_status::{Retirable, UnitStatus};
 use crate::domain::value_objects::WardCode;
 
 /// Ward domain entity
@@ -12,6 +13,9 @@ pub struct Ward {
     pub lga_id: Uuid,
     pub name: String,
     pub code: WardCode,
+    pub status: UnitStatus,
+    /// The unit that replaces this one once it is deprecated or merged
+    pub successor_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -25,6 +29,8 @@ impl Ward {
             lga_id,
             name,
             code,
+            status: UnitStatus::Active,
+            successor_id: None,
             created_at: now,
             updated_at: now,
         }
@@ -41,6 +47,27 @@ impl Ward {
         self.code = code;
         self.updated_at = Utc::now();
     }
+
+    /// Deprecate, merge or reactivate the ward
+    pub fn set_status(&mut self, status: UnitStatus, successor_id: Option<Uuid>) {
+        self.status = status;
+        self.successor_id = successor_id;
+        self.updated_at = Utc::now();
+    }
+}
+
+impl Retirable for Ward {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn status(&self) -> UnitStatus {
+        self.status
+    }
+
+    fn successor_id(&self) -> Option<Uuid> {
+        self.successor_id
+    }
 }
 
 /// Ward creation request
@@ -49,7 +76,7 @@ pub struct CreateWardRequest {
     #[validate(length(min = 2, max = 100))]
     pub name: String,
     
-    #[validate(length(min = 2, max = 25))]
+    #[validate(length(min = 13, max = 14))]
     pub code: String,
 }
 
@@ -59,6 +86,6 @@ pub struct UpdateWardRequest {
     #[validate(length(min = 2, max = 100))]
     pub name: Option<String>,
     
-    #[validate(length(min = 2, max = 25))]
+    #[validate(length(min = 13, max = 14))]
     pub code: Option<String>,
 }
@@ -1,3 +1,7 @@
+pub mod audit;
 pub mod entities;
+pub mod normalization;
+pub mod pagination;
+pub mod phonetic;
 pub mod value_objects;
 pub mod repositories;
@@ -0,0 +1,218 @@
+//! Name normalization for lookups and search
+//!
+//! Place names reach us with and without tone marks ("Ọ̀yọ́" / "Oyo"), with
+//! hyphens, slashes or apostrophes ("Obio/Akpor", "Dan'Agundi") and with
+//! administrative suffixes ("Ikeja Local Government", "Lagos State"). Every
+//! stored name keeps a normalized form so these variants compare equal.
+//!
+//! Rules are applied in a fixed order: case folding, diacritic folding,
+//! punctuation stripping, then stopword removal.
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// A single normalization step
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum NormalizationRule {
+    /// Upper and lower case compare equal
+    Case,
+    /// Tone marks and other diacritics are removed, e.g. "Ọ̀yọ́" → "oyo"
+    Diacritics,
+    /// Hyphens, slashes, dots and apostrophes are removed, e.g. "Obio/Akpor" → "obio akpor"
+    Punctuation,
+    /// Administrative words are dropped, e.g. "Ikeja Local Government" → "ikeja"
+    Stopwords,
+}
+
+impl NormalizationRule {
+    /// Every rule, in the order they are applied
+    pub const ALL: [NormalizationRule; 4] = [
+        NormalizationRule::Case,
+        NormalizationRule::Diacritics,
+        NormalizationRule::Punctuation,
+        NormalizationRule::Stopwords,
+    ];
+
+    /// Name used in responses
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NormalizationRule::Case => "case",
+            NormalizationRule::Diacritics => "diacritics",
+            NormalizationRule::Punctuation => "punctuation",
+            NormalizationRule::Stopwords => "stopwords",
+        }
+    }
+
+    fn apply(&self, value: &str) -> String {
+        match self {
+            NormalizationRule::Case => value.to_lowercase(),
+            NormalizationRule::Diacritics => fold_diacritics(value),
+            NormalizationRule::Punctuation => strip_punctuation(value),
+            NormalizationRule::Stopwords => remove_stopwords(value),
+        }
+    }
+}
+
+/// Words that qualify a name rather than identify it
+///
+/// Multi-word phrases are listed before their parts so the longest phrase is
+/// removed first.
+const STOPWORDS: [&[&str]; 7] = [
+    &["local", "government", "area"],
+    &["local", "government", "council"],
+    &["local", "government"],
+    &["l", "g", "a"],
+    &["lga"],
+    &["state"],
+    &["ward"],
+];
+
+/// Letters without a Unicode decomposition that still carry a diacritic
+const LETTER_FOLDS: [(char, char); 8] = [
+    ('ɓ', 'b'),
+    ('ɗ', 'd'),
+    ('ƙ', 'k'),
+    ('ƴ', 'y'),
+    ('ŋ', 'n'),
+    ('ǝ', 'e'),
+    ('ø', 'o'),
+    ('ł', 'l'),
+];
+
+/// A name in normalized form, with the rules that changed it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedName {
+    pub value: String,
+    pub rules: Vec<NormalizationRule>,
+}
+
+/// Normalize a name for storage or comparison
+pub fn normalize_name(name: &str) -> NormalizedName {
+    let mut value = collapse_whitespace(name);
+    let mut rules = Vec::new();
+
+    for rule in NormalizationRule::ALL {
+        let next = rule.apply(&value);
+        if next != value {
+            rules.push(rule);
+            value = next;
+        }
+    }
+
+    NormalizedName { value, rules }
+}
+
+/// Normalized form of a name, without the applied rules
+pub fn normalized(name: &str) -> String {
+    normalize_name(name).value
+}
+
+/// Rules needed for `input` to match `candidate`, or `None` if they differ
+/// even after full normalization
+///
+/// Rules are added in order until both sides are equal; of those, a rule is
+/// reported only if the sides differ without it. An exact match needs no rules.
+pub fn rules_needed(input: &str, candidate: &str) -> Option<Vec<NormalizationRule>> {
+    rules_for(input, candidate, |input, candidate| input == candidate)
+}
+
+/// Like [`rules_needed`], but `input` need only appear within `candidate`,
+/// as it does for prefix and substring matches
+pub fn rules_needed_within(input: &str, candidate: &str) -> Option<Vec<NormalizationRule>> {
+    rules_for(input, candidate, |input, candidate| {
+        candidate.contains(input)
+    })
+}
+
+fn rules_for(
+    input: &str,
+    candidate: &str,
+    matches: impl Fn(&str, &str) -> bool,
+) -> Option<Vec<NormalizationRule>> {
+    let matches_with = |rules: &[NormalizationRule]| {
+        matches(&apply_rules(input, rules), &apply_rules(candidate, rules))
+    };
+
+    let applied = (0..=NormalizationRule::ALL.len())
+        .map(|count| &NormalizationRule::ALL[..count])
+        .find(|rules| matches_with(rules))?;
+
+    Some(
+        applied
+            .iter()
+            .filter(|rule| {
+                let without: Vec<NormalizationRule> =
+                    applied.iter().copied().filter(|r| r != *rule).collect();
+                !matches_with(&without)
+            })
+            .copied()
+            .collect(),
+    )
+}
+
+fn apply_rules(value: &str, rules: &[NormalizationRule]) -> String {
+    rules
+        .iter()
+        .fold(collapse_whitespace(value), |value, rule| rule.apply(&value))
+}
+
+fn collapse_whitespace(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn fold_diacritics(value: &str) -> String {
+    value
+        .nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .map(|c| {
+            LETTER_FOLDS
+                .iter()
+                .find(|(from, _)| *from == c)
+                .map_or(c, |(_, to)| *to)
+        })
+        .collect()
+}
+
+/// Apostrophes join their neighbours ("Dan'Agundi" → "DanAgundi"); every other
+/// non-alphanumeric character separates words
+fn strip_punctuation(value: &str) -> String {
+    let joined: String = value
+        .chars()
+        .filter(|c| !matches!(c, '\'' | '’' | '‘' | '`' | 'ʼ'))
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+
+    collapse_whitespace(&joined)
+}
+
+/// Drop administrative words, unless that would leave nothing of the name
+fn remove_stopwords(value: &str) -> String {
+    let words: Vec<&str> = value.split(' ').collect();
+    let mut kept = Vec::with_capacity(words.len());
+    let mut position = 0;
+
+    while position < words.len() {
+        let phrase = STOPWORDS.iter().find(|phrase| {
+            words[position..].len() >= phrase.len()
+                && phrase
+                    .iter()
+                    .zip(&words[position..])
+                    .all(|(stopword, word)| word.eq_ignore_ascii_case(stopword))
+        });
+
+        match phrase {
+            Some(phrase) => position += phrase.len(),
+            None => {
+                kept.push(words[position]);
+                position += 1;
+            }
+        }
+    }
+
+    if kept.is_empty() {
+        value.to_string()
+    } else {
+        kept.join(" ")
+    }
+}
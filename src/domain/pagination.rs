@@ -0,0 +1,205 @@
+//! Keyset pagination
+//!
+//! A cursor names the row a page ended (or started) on by its sort key. The
+//! next page is read with `WHERE (sort key) > (cursor key)` instead of
+//! `OFFSET`, so the database seeks through an index rather than counting past
+//! every earlier row, and rows added or removed before the cursor don't shift
+//! the page.
+//!
+//! Cursors are opaque to clients: base64url-encoded JSON holding the listing
+//! the cursor belongs to, which way to read, and the boundary row's sort key.
+//! A listing filtered by a parent or a query is named with that filter (see
+//! [`scoped`]), so a cursor cannot be carried over to a different one.
+//! Page numbers remain available for compatibility; pages read by number also
+//! return cursors, so a client can switch to cursors at any page.
+
+use std::fmt;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Largest number of items any page returns
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Largest page number that may be requested, so that the rows skipped
+/// before it always fit in a `u32`
+pub const MAX_PAGE: u32 = u32::MAX / MAX_PAGE_SIZE;
+
+/// Name of a listing restricted to `scope`, such as one LGA's wards or the
+/// hits for one query
+pub fn scoped(listing: &str, scope: impl fmt::Display) -> String {
+    format!("{}:{}", listing, scope)
+}
+
+/// Which side of the cursor to read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    /// Rows after the cursor, for the next page
+    After,
+    /// Rows before the cursor, for the previous page
+    Before,
+}
+
+/// Position in a listing, given as the sort key of a boundary row
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cursor {
+    #[serde(rename = "l")]
+    listing: String,
+    #[serde(rename = "d")]
+    direction: Direction,
+    #[serde(rename = "k")]
+    key: Vec<String>,
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CursorError {
+    #[error("cursor is not one returned by this API")]
+    Malformed,
+    #[error("cursor belongs to a different listing")]
+    WrongListing,
+}
+
+impl Cursor {
+    pub fn new(listing: &str, direction: Direction, key: Vec<String>) -> Self {
+        Self {
+            listing: listing.to_string(),
+            direction,
+            key,
+        }
+    }
+
+    /// Parse a cursor returned in `next_cursor` or `prev_cursor`
+    pub fn decode(encoded: &str) -> Result<Self, CursorError> {
+        let json = URL_SAFE_NO_PAD
+            .decode(encoded.trim())
+            .map_err(|_| CursorError::Malformed)?;
+
+        serde_json::from_slice(&json).map_err(|_| CursorError::Malformed)
+    }
+
+    /// Opaque form handed to clients
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("cursor serializes to JSON");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Sort key of the boundary row, checked to come from `listing` and to
+    /// have one value per sort column
+    pub fn key<const N: usize>(&self, listing: &str) -> Result<&[String; N], CursorError> {
+        if self.listing != listing {
+            return Err(CursorError::WrongListing);
+        }
+
+        self.key
+            .as_slice()
+            .try_into()
+            .map_err(|_| CursorError::Malformed)
+    }
+}
+
+/// Page of a listing to read: by page number, or next to a cursor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageRequest {
+    /// 1-based page number, read with `OFFSET`
+    Offset { page: u32, limit: u32 },
+    /// Rows on one side of a cursor
+    Cursor { cursor: Cursor, limit: u32 },
+}
+
+impl PageRequest {
+    pub fn limit(&self) -> u32 {
+        match self {
+            PageRequest::Offset { limit, .. } | PageRequest::Cursor { limit, .. } => *limit,
+        }
+    }
+
+    /// Page number, when the page was requested by number
+    pub fn page(&self) -> Option<u32> {
+        match self {
+            PageRequest::Offset { page, .. } => Some(*page),
+            PageRequest::Cursor { .. } => None,
+        }
+    }
+
+    /// Rows to skip before the page; always 0 when reading from a cursor
+    pub fn offset(&self) -> u64 {
+        match self {
+            PageRequest::Offset { page, limit } => {
+                u64::from(page.saturating_sub(1)) * u64::from(*limit)
+            }
+            PageRequest::Cursor { .. } => 0,
+        }
+    }
+
+    pub fn cursor(&self) -> Option<&Cursor> {
+        match self {
+            PageRequest::Offset { .. } => None,
+            PageRequest::Cursor { cursor, .. } => Some(cursor),
+        }
+    }
+
+    /// Direction rows are read in; pages before a cursor are read backwards
+    pub fn direction(&self) -> Direction {
+        self.cursor().map_or(Direction::After, Cursor::direction)
+    }
+
+    /// Rows to fetch: one more than the page holds, to tell whether another
+    /// page follows in the reading direction
+    pub fn fetch_limit(&self) -> u32 {
+        self.limit() + 1
+    }
+}
+
+/// One page of a listing with cursors to the pages either side
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeysetPage<T> {
+    pub items: Vec<T>,
+    pub next: Option<Cursor>,
+    pub prev: Option<Cursor>,
+}
+
+impl<T> KeysetPage<T> {
+    /// Build a page from the rows fetched for `request`: up to
+    /// [`PageRequest::fetch_limit`] rows, in reading direction
+    pub fn from_rows(
+        mut rows: Vec<T>,
+        request: &PageRequest,
+        listing: &str,
+        key: impl Fn(&T) -> Vec<String>,
+    ) -> Self {
+        let more = rows.len() > request.limit() as usize;
+        rows.truncate(request.limit() as usize);
+
+        let (has_next, has_prev) = match request {
+            PageRequest::Offset { page, .. } => (more, *page > 1),
+            PageRequest::Cursor { cursor, .. } => match cursor.direction {
+                Direction::After => (more, true),
+                Direction::Before => {
+                    rows.reverse();
+                    (true, more)
+                }
+            },
+        };
+
+        let next = rows
+            .last()
+            .filter(|_| has_next)
+            .map(|row| Cursor::new(listing, Direction::After, key(row)));
+        let prev = rows
+            .first()
+            .filter(|_| has_prev)
+            .map(|row| Cursor::new(listing, Direction::Before, key(row)));
+
+        Self {
+            items: rows,
+            next,
+            prev,
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Phonetic keys for place names
+//!
+//! Names taken down by ear get spelled the way they sound: "Oshoodi" for
+//! Oshodi, "Kadoona" for Kaduna, "Onicha" for Onitsha, "Pot Akot" for Port
+//! Harcourt. A phonetic key keeps what a listener can reliably hear and drops
+//! what they can't, so these spellings share a key.
+//!
+//! The scheme is tuned for Yoruba, Hausa and Igbo names as heard in Nigerian
+//! English rather than for English spelling:
+//!
+//! - vowels fall into three classes: `a`, front (`e`, `i`) and back (`o`, `u`);
+//!   tone and vowel length are not heard reliably
+//! - repeated sounds count once ("oo" = "o", "bb" = "b", "Nnewi" = "Newi")
+//! - "sh" and "s" are one sound (Yoruba ṣ is written either way), as are
+//!   "ch", "tch" and "tsh" ("Onitsha" = "Onicha"), and Hausa "ts" and "s"
+//! - "h" is silent except in those digraphs, and "ph" is "f"
+//! - "c" and "q" are "k", "x" is "ks"
+//! - "r" is silent unless a vowel follows, as Nigerian English is non-rhotic
+//! - "y" is a vowel unless a vowel follows
+//! - labial-velars "kp" and "gb" keep both letters, so they stay distinct
+//!   from plain "k", "p", "g" and "b"
+//!
+//! Keys are built from the [normalized](crate::domain::normalization) name, so
+//! diacritics, punctuation and administrative suffixes are already gone.
+
+use crate::domain::normalization::normalized;
+
+/// Letter sequences that spell one sound, longest first
+const DIGRAPHS: [(&str, &str); 6] = [
+    ("tsh", "c"),
+    ("tch", "c"),
+    ("sh", "s"),
+    ("ch", "c"),
+    ("ts", "s"),
+    ("ph", "f"),
+];
+
+/// Phonetic key of a name: one key per word, separated by spaces
+pub fn phonetic_key(name: &str) -> String {
+    normalized(name)
+        .split(' ')
+        .map(word_key)
+        .filter(|key| !key.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn word_key(word: &str) -> String {
+    let mut key = String::with_capacity(word.len());
+    let mut rest = word;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some((from, to)) = DIGRAPHS.iter().find(|(from, _)| rest.starts_with(from)) {
+            push_sound(&mut key, to);
+            rest = &rest[from.len()..];
+            continue;
+        }
+
+        rest = &rest[c.len_utf8()..];
+        let vowel_follows = rest.chars().next().is_some_and(is_vowel);
+
+        match c {
+            'a' => push_sound(&mut key, "a"),
+            'e' | 'i' => push_sound(&mut key, "i"),
+            'o' | 'u' => push_sound(&mut key, "u"),
+            'y' if !vowel_follows => push_sound(&mut key, "i"),
+            'c' | 'q' => push_sound(&mut key, "k"),
+            'x' => push_sound(&mut key, "ks"),
+            'h' => {}
+            'r' if !vowel_follows => {}
+            c => push_sound(&mut key, c.encode_utf8(&mut [0; 4])),
+        }
+    }
+
+    key
+}
+
+/// Append a sound, skipping a letter that repeats the one before it
+fn push_sound(key: &mut String, sound: &str) {
+    for c in sound.chars() {
+        if !key.ends_with(c) {
+            key.push(c);
+        }
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
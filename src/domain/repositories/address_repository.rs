@@ -1,6 +1,8 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 
-use crate::domain::entities::address::{Address, AddressValidationRequest, AddressValidationResponse};
+use crate::domain::entities::address::{Address, AddressValidationRequest, AddressValidationResponse, SimilarAddress};
 use crate::errors::AppResult;
 
 /// Address repository interface for complex address operations
@@ -18,6 +20,21 @@ pub trait AddressRepository: Send + Sync {
         postal_code: &str,
     ) -> AppResult<Option<Address>>;
     
-    /// Search for similar addresses
-    async fn find_similar_addresses(&self, request: &AddressValidationRequest) -> AppResult<Vec<Address>>;
+    /// Search for the `limit` most similar complete addresses, best match first
+    async fn find_similar_addresses(&self, request: &AddressValidationRequest, limit: u32) -> AppResult<Vec<SimilarAddress>>;
+}
+
+#[async_trait]
+impl<T: AddressRepository + ?Sized> AddressRepository for Arc<T> {
+    async fn validate_address(&self, request: &AddressValidationRequest) -> AppResult<AddressValidationResponse> {
+        (**self).validate_address(request).await
+    }
+
+    async fn find_by_components(&self, state: &str, lga: &str, ward: &str, postal_code: &str) -> AppResult<Option<Address>> {
+        (**self).find_by_components(state, lga, ward, postal_code).await
+    }
+
+    async fn find_similar_addresses(&self, request: &AddressValidationRequest, limit: u32) -> AppResult<Vec<SimilarAddress>> {
+        (**self).find_similar_addresses(request, limit).await
+    }
 }
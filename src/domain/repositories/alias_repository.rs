@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::alias::{
+    Alias, AliasEntityType, CreateAliasRequest, UpdateAliasRequest,
+};
+use crate::errors::AppResult;
+
+/// Alias repository interface
+#[async_trait]
+pub trait AliasRepository: Send + Sync {
+    /// Create a new alias for an existing state, LGA or ward
+    async fn create(&self, request: &CreateAliasRequest) -> AppResult<Alias>;
+
+    /// Find alias by ID
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Alias>>;
+
+    /// List aliases, optionally only those of one entity type or one entity
+    async fn list(
+        &self,
+        entity_type: Option<AliasEntityType>,
+        entity_id: Option<Uuid>,
+        page: u32,
+        limit: u32,
+    ) -> AppResult<Vec<Alias>>;
+
+    /// Count aliases matching the same filters as [`AliasRepository::list`]
+    async fn count(
+        &self,
+        entity_type: Option<AliasEntityType>,
+        entity_id: Option<Uuid>,
+    ) -> AppResult<u64>;
+
+    /// Update alias
+    async fn update(&self, id: Uuid, request: &UpdateAliasRequest) -> AppResult<Alias>;
+
+    /// Delete alias
+    async fn delete(&self, id: Uuid) -> AppResult<()>;
+}
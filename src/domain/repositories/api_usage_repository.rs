@@ -4,6 +4,7 @@ use chrono::{DateTime, Utc};
 use crate::domain::entities::api_usage::{
     ApiUsage, EndpointStats, HourlyStats, StatusCodeStats, UsageStats,
 };
+use crate::domain::pagination::{KeysetPage, PageRequest};
 use crate::errors::AppResult;
 
 #[async_trait]
@@ -40,21 +41,39 @@ pub trait ApiUsageRepository: Send + Sync {
         end_date: DateTime<Utc>,
     ) -> AppResult<Vec<StatusCodeStats>>;
 
-    /// Get usage by IP address
+    /// Get usage by IP address, newest first
     async fn get_usage_by_ip(
         &self,
         ip_address: &str,
         start_date: DateTime<Utc>,
         end_date: DateTime<Utc>,
-    ) -> AppResult<Vec<ApiUsage>>;
+        request: &PageRequest,
+    ) -> AppResult<KeysetPage<ApiUsage>>;
 
-    /// Get usage by API key
+    /// Get usage by API key, newest first
     async fn get_usage_by_api_key(
         &self,
         api_key: &str,
         start_date: DateTime<Utc>,
         end_date: DateTime<Utc>,
-    ) -> AppResult<Vec<ApiUsage>>;
+        request: &PageRequest,
+    ) -> AppResult<KeysetPage<ApiUsage>>;
+
+    /// Count usage by IP address
+    async fn count_usage_by_ip(
+        &self,
+        ip_address: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> AppResult<u64>;
+
+    /// Count usage by API key
+    async fn count_usage_by_api_key(
+        &self,
+        api_key: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> AppResult<u64>;
 
     /// Clean up old usage records (for data retention)
     async fn cleanup_old_records(&self, older_than: DateTime<Utc>) -> AppResult<u64>;
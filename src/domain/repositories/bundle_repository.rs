@@ -0,0 +1,18 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use crate::domain::entities::bundle::BundleContents;
+use crate::errors::AppResult;
+
+/// Offline bundle repository interface
+#[async_trait]
+pub trait BundleRepository: Send + Sync {
+    /// Value that changes whenever any unit or alias is added, changed or
+    /// retired
+    async fn data_fingerprint(&self) -> AppResult<String>;
+
+    /// Write every active unit and its aliases, from a single snapshot of the
+    /// data, to a new SQLite database at `path`
+    async fn write_bundle(&self, path: &Path) -> AppResult<BundleContents>;
+}
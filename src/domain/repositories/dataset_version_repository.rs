@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::dataset_version::{CreateDatasetVersionRequest, DatasetVersion};
+use crate::errors::AppResult;
+
+/// Dataset release repository interface
+#[async_trait]
+pub trait DatasetVersionRepository: Send + Sync {
+    /// Release the live data as it is now under a new name
+    async fn release(&self, request: &CreateDatasetVersionRequest) -> AppResult<DatasetVersion>;
+
+    /// All releases, newest first
+    async fn list(&self) -> AppResult<Vec<DatasetVersion>>;
+
+    /// Find a release by name
+    async fn find_by_name(&self, name: &str) -> AppResult<Option<DatasetVersion>>;
+
+    /// The latest release made at or before `at`
+    async fn find_as_of(&self, at: DateTime<Utc>) -> AppResult<Option<DatasetVersion>>;
+}
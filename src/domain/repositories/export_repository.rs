@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::export::ExportSnapshot;
+use crate::domain::entities::unit_level::UnitLevel;
+use crate::errors::AppResult;
+
+/// Full dataset export repository interface
+#[async_trait]
+pub trait ExportRepository: Send + Sync {
+    /// Value that changes whenever any unit is added, changed or retired
+    async fn data_fingerprint(&self) -> AppResult<String>;
+
+    /// Stream every active unit of `level`, ordered by code
+    async fn export(&self, level: UnitLevel) -> AppResult<ExportSnapshot>;
+}
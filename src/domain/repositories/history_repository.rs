@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::change::{Change, ChangeEntityType, EntityDiff};
+use crate::domain::pagination::{KeysetPage, PageRequest};
+use crate::errors::AppResult;
+
+/// Change history repository interface
+///
+/// Changes are recorded by the other repositories as they write; this only
+/// reads them back.
+#[async_trait]
+pub trait HistoryRepository: Send + Sync {
+    /// Changes to one entity, oldest first
+    async fn find_by_entity(
+        &self,
+        entity_type: ChangeEntityType,
+        entity_id: Uuid,
+        request: &PageRequest,
+    ) -> AppResult<KeysetPage<Change>>;
+
+    /// Count the changes to one entity
+    async fn count_by_entity(
+        &self,
+        entity_type: ChangeEntityType,
+        entity_id: Uuid,
+    ) -> AppResult<u64>;
+
+    /// Net change to each entity changed after `from` and up to `to`, in the
+    /// order they were first changed
+    async fn diff(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        entity_type: Option<ChangeEntityType>,
+    ) -> AppResult<Vec<EntityDiff>>;
+
+    /// Net change to each entity changed by the history entries after
+    /// `after_id` up to and including `up_to_id`, in the order they were
+    /// first changed
+    async fn diff_between_ids(&self, after_id: i64, up_to_id: i64) -> AppResult<Vec<EntityDiff>>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::import::{ImportOutcome, ImportUnit};
+use crate::errors::AppResult;
+
+/// Bulk import repository interface
+#[async_trait]
+pub trait ImportRepository: Send + Sync {
+    /// Create or update `units` in order, in one transaction, returning what
+    /// happened to each. A unit that cannot be written is rejected without
+    /// affecting the others. With `dry_run` nothing is committed.
+    async fn import(&self, units: &[ImportUnit], dry_run: bool) -> AppResult<Vec<ImportOutcome>>;
+}
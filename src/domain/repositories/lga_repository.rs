@@ -1,7 +1,10 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::domain::entities::lga::{Lga, CreateLgaRequest, UpdateLgaRequest};
+use crate::domain::entities::unit_status::UpdateStatusRequest;
 use crate::domain::value_objects::LgaCode;
 use crate::errors::AppResult;
 
@@ -23,15 +26,75 @@ pub trait LgaRepository: Send + Sync {
     /// Find LGAs by state ID
     async fn find_by_state_id(&self, state_id: Uuid, page: u32, limit: u32) -> AppResult<Vec<Lga>>;
     
+    /// Find every LGA in any of the given states, ordered by name
+    async fn find_by_state_ids(&self, state_ids: &[Uuid]) -> AppResult<Vec<Lga>>;
+    
     /// Update LGA
     async fn update(&self, id: Uuid, request: &UpdateLgaRequest) -> AppResult<Lga>;
     
-    /// Delete LGA
+    /// Deprecate LGA, keeping the row for its children and history
     async fn delete(&self, id: Uuid) -> AppResult<()>;
     
+    /// Deprecate, merge or reactivate LGA
+    async fn set_status(&self, id: Uuid, request: &UpdateStatusRequest) -> AppResult<Lga>;
+    
     /// Count LGAs by state
     async fn count_by_state(&self, state_id: Uuid) -> AppResult<u64>;
     
     /// Search LGAs by name or code
     async fn search(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<Lga>>;
+    
+    /// Search LGAs whose name or alias sounds like the query, closest first
+    async fn search_phonetic(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<Lga>>;
+}
+
+#[async_trait]
+impl<T: LgaRepository + ?Sized> LgaRepository for Arc<T> {
+    async fn create(&self, request: &CreateLgaRequest, state_id: Uuid) -> AppResult<Lga> {
+        (**self).create(request, state_id).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Lga>> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_by_name(&self, name: &str) -> AppResult<Option<Lga>> {
+        (**self).find_by_name(name).await
+    }
+
+    async fn find_by_code(&self, code: &LgaCode) -> AppResult<Option<Lga>> {
+        (**self).find_by_code(code).await
+    }
+
+    async fn find_by_state_id(&self, state_id: Uuid, page: u32, limit: u32) -> AppResult<Vec<Lga>> {
+        (**self).find_by_state_id(state_id, page, limit).await
+    }
+
+    async fn find_by_state_ids(&self, state_ids: &[Uuid]) -> AppResult<Vec<Lga>> {
+        (**self).find_by_state_ids(state_ids).await
+    }
+
+    async fn update(&self, id: Uuid, request: &UpdateLgaRequest) -> AppResult<Lga> {
+        (**self).update(id, request).await
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        (**self).delete(id).await
+    }
+
+    async fn set_status(&self, id: Uuid, request: &UpdateStatusRequest) -> AppResult<Lga> {
+        (**self).set_status(id, request).await
+    }
+
+    async fn count_by_state(&self, state_id: Uuid) -> AppResult<u64> {
+        (**self).count_by_state(state_id).await
+    }
+
+    async fn search(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<Lga>> {
+        (**self).search(query, page, limit).await
+    }
+
+    async fn search_phonetic(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<Lga>> {
+        (**self).search_phonetic(query, page, limit).await
+    }
 }
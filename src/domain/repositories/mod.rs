@@ -4,3 +4,12 @@ pub mod ward_repository;
 pub mod postal_code_repository;
 pub mod address_repository;
 pub mod api_usage_repository;
+pub mod search_repository;
+pub mod alias_repository;
+pub mod history_repository;
+pub mod dataset_version_repository;
+pub mod import_repository;
+pub mod export_repository;
+pub mod bundle_repository;
+pub mod sync_repository;
+pub mod verification_repository;
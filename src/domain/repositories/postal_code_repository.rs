@@ -1,7 +1,11 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::domain::entities::postal_code::{PostalCode, CreatePostalCodeRequest, UpdatePostalCodeRequest};
+use crate::domain::entities::unit_status::UpdateStatusRequest;
+use crate::domain::pagination::{KeysetPage, PageRequest};
 use crate::domain::value_objects::{PostalCode as PostalCodeValue, Coordinates};
 use crate::errors::AppResult;
 
@@ -17,8 +21,11 @@ pub trait PostalCodeRepository: Send + Sync {
     /// Find postal code by code
     async fn find_by_code(&self, code: &PostalCodeValue) -> AppResult<Option<PostalCode>>;
     
-    /// Find postal codes by ward ID
-    async fn find_by_ward_id(&self, ward_id: Uuid, page: u32, limit: u32) -> AppResult<Vec<PostalCode>>;
+    /// Find postal codes by ward ID, ordered by code
+    async fn find_by_ward_id(&self, ward_id: Uuid, request: &PageRequest) -> AppResult<KeysetPage<PostalCode>>;
+    
+    /// Find every postal code in any of the given wards, ordered by code
+    async fn find_by_ward_ids(&self, ward_ids: &[Uuid]) -> AppResult<Vec<PostalCode>>;
     
     /// Find postal codes near coordinates
     async fn find_near_coordinates(&self, coordinates: &Coordinates, radius_km: f64) -> AppResult<Vec<PostalCode>>;
@@ -26,12 +33,62 @@ pub trait PostalCodeRepository: Send + Sync {
     /// Update postal code
     async fn update(&self, id: Uuid, request: &UpdatePostalCodeRequest) -> AppResult<PostalCode>;
     
-    /// Delete postal code
+    /// Deprecate postal code, keeping the row for its children and history
     async fn delete(&self, id: Uuid) -> AppResult<()>;
     
+    /// Deprecate, merge or reactivate postal code
+    async fn set_status(&self, id: Uuid, request: &UpdateStatusRequest) -> AppResult<PostalCode>;
+    
     /// Count postal codes by ward
     async fn count_by_ward(&self, ward_id: Uuid) -> AppResult<u64>;
     
     /// Search postal codes by code
     async fn search(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<PostalCode>>;
 }
+
+#[async_trait]
+impl<T: PostalCodeRepository + ?Sized> PostalCodeRepository for Arc<T> {
+    async fn create(&self, request: &CreatePostalCodeRequest, ward_id: Uuid) -> AppResult<PostalCode> {
+        (**self).create(request, ward_id).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<PostalCode>> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_by_code(&self, code: &PostalCodeValue) -> AppResult<Option<PostalCode>> {
+        (**self).find_by_code(code).await
+    }
+
+    async fn find_by_ward_id(&self, ward_id: Uuid, request: &PageRequest) -> AppResult<KeysetPage<PostalCode>> {
+        (**self).find_by_ward_id(ward_id, request).await
+    }
+
+    async fn find_by_ward_ids(&self, ward_ids: &[Uuid]) -> AppResult<Vec<PostalCode>> {
+        (**self).find_by_ward_ids(ward_ids).await
+    }
+
+    async fn find_near_coordinates(&self, coordinates: &Coordinates, radius_km: f64) -> AppResult<Vec<PostalCode>> {
+        (**self).find_near_coordinates(coordinates, radius_km).await
+    }
+
+    async fn update(&self, id: Uuid, request: &UpdatePostalCodeRequest) -> AppResult<PostalCode> {
+        (**self).update(id, request).await
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        (**self).delete(id).await
+    }
+
+    async fn set_status(&self, id: Uuid, request: &UpdateStatusRequest) -> AppResult<PostalCode> {
+        (**self).set_status(id, request).await
+    }
+
+    async fn count_by_ward(&self, ward_id: Uuid) -> AppResult<u64> {
+        (**self).count_by_ward(ward_id).await
+    }
+
+    async fn search(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<PostalCode>> {
+        (**self).search(query, page, limit).await
+    }
+}
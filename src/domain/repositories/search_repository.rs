@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::entities::search::{AutocompleteEntry, MatchMode, SearchCounts, SearchHit};
+use crate::domain::pagination::{KeysetPage, PageRequest};
+use crate::errors::AppResult;
+
+/// Repository interface for ranked search across all entity types
+#[async_trait]
+pub trait SearchRepository: Send + Sync {
+    /// One page of hits across states, LGAs, wards and postal codes, best match first
+    async fn search(
+        &self,
+        query: &str,
+        mode: MatchMode,
+        request: &PageRequest,
+    ) -> AppResult<KeysetPage<SearchHit>>;
+
+    /// Number of hits per entity type for the same query
+    async fn count(&self, query: &str, mode: MatchMode) -> AppResult<SearchCounts>;
+
+    /// Every state, LGA, ward and postal code with its parent codes, for the autocomplete index
+    async fn autocomplete_entries(&self) -> AppResult<Vec<AutocompleteEntry>>;
+
+    /// Value that changes whenever any state, LGA, ward or postal code is added, changed or removed
+    async fn data_fingerprint(&self) -> AppResult<String>;
+}
+
+#[async_trait]
+impl<T: SearchRepository + ?Sized> SearchRepository for Arc<T> {
+    async fn search(
+        &self,
+        query: &str,
+        mode: MatchMode,
+        request: &PageRequest,
+    ) -> AppResult<KeysetPage<SearchHit>> {
+        (**self).search(query, mode, request).await
+    }
+
+    async fn count(&self, query: &str, mode: MatchMode) -> AppResult<SearchCounts> {
+        (**self).count(query, mode).await
+    }
+
+    async fn autocomplete_entries(&self) -> AppResult<Vec<AutocompleteEntry>> {
+        (**self).autocomplete_entries().await
+    }
+
+    async fn data_fingerprint(&self) -> AppResult<String> {
+        (**self).data_fingerprint().await
+    }
+}
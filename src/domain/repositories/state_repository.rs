@@ -1,7 +1,10 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::domain::entities::state::{State, CreateStateRequest, UpdateStateRequest};
+use crate::domain::entities::unit_status::UpdateStatusRequest;
 use crate::domain::value_objects::StateCode;
 use crate::errors::AppResult;
 
@@ -23,15 +26,75 @@ pub trait StateRepository: Send + Sync {
     /// Update state
     async fn update(&self, id: Uuid, request: &UpdateStateRequest) -> AppResult<State>;
     
-    /// Delete state
+    /// Deprecate state, keeping the row for its children and history
     async fn delete(&self, id: Uuid) -> AppResult<()>;
     
+    /// Deprecate, merge or reactivate state
+    async fn set_status(&self, id: Uuid, request: &UpdateStatusRequest) -> AppResult<State>;
+    
     /// List all states with pagination
     async fn list(&self, page: u32, limit: u32) -> AppResult<Vec<State>>;
     
+    /// List every state, ordered by name
+    async fn list_all(&self) -> AppResult<Vec<State>>;
+    
     /// Count total states
     async fn count(&self) -> AppResult<u64>;
     
     /// Search states by name or code
     async fn search(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<State>>;
+    
+    /// Search states whose name or alias sounds like the query, closest first
+    async fn search_phonetic(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<State>>;
+}
+
+#[async_trait]
+impl<T: StateRepository + ?Sized> StateRepository for Arc<T> {
+    async fn create(&self, request: &CreateStateRequest) -> AppResult<State> {
+        (**self).create(request).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<State>> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_by_name(&self, name: &str) -> AppResult<Option<State>> {
+        (**self).find_by_name(name).await
+    }
+
+    async fn find_by_code(&self, code: &StateCode) -> AppResult<Option<State>> {
+        (**self).find_by_code(code).await
+    }
+
+    async fn update(&self, id: Uuid, request: &UpdateStateRequest) -> AppResult<State> {
+        (**self).update(id, request).await
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        (**self).delete(id).await
+    }
+
+    async fn set_status(&self, id: Uuid, request: &UpdateStatusRequest) -> AppResult<State> {
+        (**self).set_status(id, request).await
+    }
+
+    async fn list(&self, page: u32, limit: u32) -> AppResult<Vec<State>> {
+        (**self).list(page, limit).await
+    }
+
+    async fn list_all(&self) -> AppResult<Vec<State>> {
+        (**self).list_all().await
+    }
+
+    async fn count(&self) -> AppResult<u64> {
+        (**self).count().await
+    }
+
+    async fn search(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<State>> {
+        (**self).search(query, page, limit).await
+    }
+
+    async fn search_phonetic(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<State>> {
+        (**self).search_phonetic(query, page, limit).await
+    }
 }
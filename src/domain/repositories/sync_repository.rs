@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::sync::{SyncBatch, SyncPosition};
+use crate::errors::AppResult;
+
+/// Delta sync repository interface
+#[async_trait]
+pub trait SyncRepository: Send + Sync {
+    /// Entities created, updated, retired or deleted at or after `since`, from
+    /// a single snapshot of the data; without `since`, every active unit and
+    /// every alias
+    ///
+    /// At most `limit` entities are returned, starting after `after` when
+    /// carrying on from an earlier page.
+    async fn changes_since(
+        &self,
+        since: Option<DateTime<Utc>>,
+        after: Option<SyncPosition>,
+        limit: usize,
+    ) -> AppResult<SyncBatch>;
+}
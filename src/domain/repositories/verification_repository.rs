@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::verification::DatasetSnapshot;
+use crate::errors::AppResult;
+
+/// Dataset verification repository interface
+#[async_trait]
+pub trait VerificationRepository: Send + Sync {
+    /// Every active unit of every level, from a single snapshot of the data
+    async fn snapshot(&self) -> AppResult<DatasetSnapshot>;
+}
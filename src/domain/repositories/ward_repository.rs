@@ -1,7 +1,11 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::domain::entities::ward::{Ward, CreateWardRequest, UpdateWardRequest};
+use crate::domain::entities::unit_status::UpdateStatusRequest;
+use crate::domain::pagination::{KeysetPage, PageRequest};
 use crate::domain::value_objects::WardCode;
 use crate::errors::AppResult;
 
@@ -20,18 +24,78 @@ pub trait WardRepository: Send + Sync {
     /// Find ward by code
     async fn find_by_code(&self, code: &WardCode) -> AppResult<Option<Ward>>;
     
-    /// Find wards by LGA ID
-    async fn find_by_lga_id(&self, lga_id: Uuid, page: u32, limit: u32) -> AppResult<Vec<Ward>>;
+    /// Find wards by LGA ID, ordered by name
+    async fn find_by_lga_id(&self, lga_id: Uuid, request: &PageRequest) -> AppResult<KeysetPage<Ward>>;
+    
+    /// Find every ward in any of the given LGAs, ordered by name
+    async fn find_by_lga_ids(&self, lga_ids: &[Uuid]) -> AppResult<Vec<Ward>>;
     
     /// Update ward
     async fn update(&self, id: Uuid, request: &UpdateWardRequest) -> AppResult<Ward>;
     
-    /// Delete ward
+    /// Deprecate ward, keeping the row for its children and history
     async fn delete(&self, id: Uuid) -> AppResult<()>;
     
+    /// Deprecate, merge or reactivate ward
+    async fn set_status(&self, id: Uuid, request: &UpdateStatusRequest) -> AppResult<Ward>;
+    
     /// Count wards by LGA
     async fn count_by_lga(&self, lga_id: Uuid) -> AppResult<u64>;
     
     /// Search wards by name or code
     async fn search(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<Ward>>;
+    
+    /// Search wards whose name or alias sounds like the query, closest first
+    async fn search_phonetic(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<Ward>>;
+}
+
+#[async_trait]
+impl<T: WardRepository + ?Sized> WardRepository for Arc<T> {
+    async fn create(&self, request: &CreateWardRequest, lga_id: Uuid) -> AppResult<Ward> {
+        (**self).create(request, lga_id).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Ward>> {
+        (**self).find_by_id(id).await
+    }
+
+    async fn find_by_name(&self, name: &str) -> AppResult<Option<Ward>> {
+        (**self).find_by_name(name).await
+    }
+
+    async fn find_by_code(&self, code: &WardCode) -> AppResult<Option<Ward>> {
+        (**self).find_by_code(code).await
+    }
+
+    async fn find_by_lga_id(&self, lga_id: Uuid, request: &PageRequest) -> AppResult<KeysetPage<Ward>> {
+        (**self).find_by_lga_id(lga_id, request).await
+    }
+
+    async fn find_by_lga_ids(&self, lga_ids: &[Uuid]) -> AppResult<Vec<Ward>> {
+        (**self).find_by_lga_ids(lga_ids).await
+    }
+
+    async fn update(&self, id: Uuid, request: &UpdateWardRequest) -> AppResult<Ward> {
+        (**self).update(id, request).await
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        (**self).delete(id).await
+    }
+
+    async fn set_status(&self, id: Uuid, request: &UpdateStatusRequest) -> AppResult<Ward> {
+        (**self).set_status(id, request).await
+    }
+
+    async fn count_by_lga(&self, lga_id: Uuid) -> AppResult<u64> {
+        (**self).count_by_lga(lga_id).await
+    }
+
+    async fn search(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<Ward>> {
+        (**self).search(query, page, limit).await
+    }
+
+    async fn search_phonetic(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<Ward>> {
+        (**self).search_phonetic(query, page, limit).await
+    }
 }
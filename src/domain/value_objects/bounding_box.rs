@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::StateCode;
+
+/// Rectangle of latitudes and longitudes, in degrees
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct BoundingBox {
+    pub min_lat: f64,
+    pub max_lat: f64,
+    pub min_lng: f64,
+    pub max_lng: f64,
+}
+
+/// Nigeria's land territory, with a little room around the borders and coast
+pub const NIGERIA: BoundingBox = BoundingBox::new(4.0, 14.0, 2.5, 14.8);
+
+/// Degrees allowed outside a state's extent, since the extents are approximate
+pub const STATE_EXTENT_MARGIN: f64 = 0.1;
+
+/// Approximate extent of each state, by ISO code
+#[rustfmt::skip]
+const STATE_EXTENTS: [(&str, BoundingBox); 37] = [
+    ("NG-AB", BoundingBox::new(4.75, 6.12, 7.00, 8.00)),
+    ("NG-AD", BoundingBox::new(7.45, 10.96, 11.40, 13.70)),
+    ("NG-AK", BoundingBox::new(4.30, 5.55, 7.45, 8.35)),
+    ("NG-AN", BoundingBox::new(5.68, 6.78, 6.60, 7.35)),
+    ("NG-BA", BoundingBox::new(9.30, 12.30, 8.50, 11.00)),
+    ("NG-BE", BoundingBox::new(6.40, 8.15, 7.75, 10.00)),
+    ("NG-BO", BoundingBox::new(10.00, 13.75, 11.50, 14.70)),
+    ("NG-BY", BoundingBox::new(4.20, 5.40, 5.35, 6.75)),
+    ("NG-CR", BoundingBox::new(4.45, 6.90, 7.75, 9.50)),
+    ("NG-DE", BoundingBox::new(5.05, 6.50, 5.00, 6.80)),
+    ("NG-EB", BoundingBox::new(5.65, 6.80, 7.50, 8.45)),
+    ("NG-ED", BoundingBox::new(5.75, 7.60, 5.00, 6.75)),
+    ("NG-EK", BoundingBox::new(7.25, 8.10, 4.70, 5.80)),
+    ("NG-EN", BoundingBox::new(5.90, 7.10, 6.90, 7.90)),
+    ("NG-FC", BoundingBox::new(8.40, 9.45, 6.75, 7.65)),
+    ("NG-GO", BoundingBox::new(9.30, 11.20, 10.70, 12.00)),
+    ("NG-IM", BoundingBox::new(5.10, 5.95, 6.60, 7.50)),
+    ("NG-JI", BoundingBox::new(11.00, 13.00, 8.10, 10.60)),
+    ("NG-KD", BoundingBox::new(9.00, 11.30, 6.10, 8.80)),
+    ("NG-KE", BoundingBox::new(10.10, 13.30, 3.50, 6.00)),
+    ("NG-KN", BoundingBox::new(10.30, 12.65, 7.65, 9.45)),
+    ("NG-KO", BoundingBox::new(6.70, 8.75, 5.35, 7.90)),
+    ("NG-KT", BoundingBox::new(11.10, 13.40, 6.85, 8.65)),
+    ("NG-KW", BoundingBox::new(7.95, 10.15, 2.70, 6.10)),
+    ("NG-LA", BoundingBox::new(6.35, 6.75, 2.70, 4.35)),
+    ("NG-NA", BoundingBox::new(7.70, 9.40, 7.00, 9.60)),
+    ("NG-NI", BoundingBox::new(8.30, 11.50, 3.50, 7.50)),
+    ("NG-OG", BoundingBox::new(6.30, 7.95, 2.70, 4.60)),
+    ("NG-ON", BoundingBox::new(5.75, 7.80, 4.30, 6.05)),
+    ("NG-OS", BoundingBox::new(7.05, 8.10, 4.00, 5.10)),
+    ("NG-OY", BoundingBox::new(7.05, 9.15, 2.65, 4.60)),
+    ("NG-PL", BoundingBox::new(8.30, 10.40, 8.30, 10.65)),
+    ("NG-RI", BoundingBox::new(4.25, 5.70, 6.45, 7.60)),
+    ("NG-SO", BoundingBox::new(11.50, 13.85, 4.10, 6.95)),
+    ("NG-TA", BoundingBox::new(6.45, 9.60, 9.30, 11.95)),
+    ("NG-YO", BoundingBox::new(10.45, 13.40, 9.60, 12.55)),
+    ("NG-ZA", BoundingBox::new(11.05, 13.10, 5.35, 7.25)),
+];
+
+impl BoundingBox {
+    pub const fn new(min_lat: f64, max_lat: f64, min_lng: f64, max_lng: f64) -> Self {
+        Self {
+            min_lat,
+            max_lat,
+            min_lng,
+            max_lng,
+        }
+    }
+
+    /// Approximate extent of a state, widened by [`STATE_EXTENT_MARGIN`]
+    pub fn for_state(state_code: &StateCode) -> Option<Self> {
+        STATE_EXTENTS
+            .iter()
+            .find(|(iso_code, _)| *iso_code == state_code.as_str())
+            .map(|(_, extent)| extent.expanded(STATE_EXTENT_MARGIN))
+    }
+
+    /// Whether the point lies inside the box or on its edge
+    pub fn contains(&self, latitude: f64, longitude: f64) -> bool {
+        (self.min_lat..=self.max_lat).contains(&latitude)
+            && (self.min_lng..=self.max_lng).contains(&longitude)
+    }
+
+    /// The box grown by `margin` degrees on every side
+    pub fn expanded(&self, margin: f64) -> Self {
+        Self::new(
+            self.min_lat - margin,
+            self.max_lat + margin,
+            self.min_lng - margin,
+            self.max_lng + margin,
+        )
+    }
+}
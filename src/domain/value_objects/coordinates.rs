@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
 
+use crate::domain::value_objects::Violation;
+
 /// Coordinates value object
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Coordinates {
@@ -17,14 +19,30 @@ pub enum CoordinatesError {
     InvalidLongitude,
 }
 
+impl CoordinatesError {
+    /// Request field holding the rejected value
+    pub fn field(&self) -> &'static str {
+        match self {
+            CoordinatesError::InvalidLatitude => "lat",
+            CoordinatesError::InvalidLongitude => "lng",
+        }
+    }
+}
+
+impl Violation for CoordinatesError {
+    fn rule(&self) -> &'static str {
+        "range"
+    }
+}
+
 impl Coordinates {
-    /// Create new coordinates
+    /// Create new coordinates; NaN and infinities are out of range
     pub fn new(latitude: f64, longitude: f64) -> Result<Self, CoordinatesError> {
-        if latitude < -90.0 || latitude > 90.0 {
+        if !(-90.0..=90.0).contains(&latitude) {
             return Err(CoordinatesError::InvalidLatitude);
         }
         
-        if longitude < -180.0 || longitude > 180.0 {
+        if !(-180.0..=180.0).contains(&longitude) {
             return Err(CoordinatesError::InvalidLongitude);
         }
         
@@ -0,0 +1,107 @@
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+use crate::domain::value_objects::{LgaCode, StateCode, Violation, WardCode};
+
+/// Code of any level in the administrative hierarchy
+///
+/// Codes nest, so the level is given by the number of segments:
+///
+/// | Level | Format          | Example         |
+/// |-------|-----------------|-----------------|
+/// | State | `NG-XX`         | `NG-LA`         |
+/// | LGA   | `NG-XX-YYY`     | `NG-LA-IKJ`     |
+/// | Ward  | `NG-XX-YYY-WNN` | `NG-LA-IKJ-W03` |
+///
+/// Every parent code is a prefix of its children's codes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GeoCode {
+    State(StateCode),
+    Lga(LgaCode),
+    Ward(WardCode),
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GeoCodeError {
+    #[error("'{0}' is not a state (NG-XX), LGA (NG-XX-YYY) or ward (NG-XX-YYY-WNN) code")]
+    UnknownLevel(String),
+    #[error("{0}")]
+    Invalid(String),
+}
+
+impl Violation for GeoCodeError {
+    fn rule(&self) -> &'static str {
+        "format"
+    }
+}
+
+impl GeoCode {
+    /// Parse a code of any level
+    pub fn parse(code: &str) -> Result<Self, GeoCodeError> {
+        let invalid = |e: &dyn std::error::Error| GeoCodeError::Invalid(e.to_string());
+
+        match code.trim().split('-').count() {
+            2 => StateCode::new(code.to_string())
+                .map(GeoCode::State)
+                .map_err(|e| invalid(&e)),
+            3 => LgaCode::new(code.to_string())
+                .map(GeoCode::Lga)
+                .map_err(|e| invalid(&e)),
+            4 => WardCode::new(code.to_string())
+                .map(GeoCode::Ward)
+                .map_err(|e| invalid(&e)),
+            _ => Err(GeoCodeError::UnknownLevel(code.to_string())),
+        }
+    }
+
+    /// Name of the hierarchy level: `state`, `lga` or `ward`
+    pub fn level(&self) -> &'static str {
+        match self {
+            GeoCode::State(_) => "state",
+            GeoCode::Lga(_) => "lga",
+            GeoCode::Ward(_) => "ward",
+        }
+    }
+
+    /// Code of the state at the root of this code
+    pub fn state_code(&self) -> StateCode {
+        match self {
+            GeoCode::State(code) => code.clone(),
+            GeoCode::Lga(code) => code.state_code(),
+            GeoCode::Ward(code) => code.state_code(),
+        }
+    }
+
+    /// Code of the LGA this code belongs to, if it is below state level
+    pub fn lga_code(&self) -> Option<LgaCode> {
+        match self {
+            GeoCode::State(_) => None,
+            GeoCode::Lga(code) => Some(code.clone()),
+            GeoCode::Ward(code) => Some(code.lga_code()),
+        }
+    }
+
+    /// Get the code as string
+    pub fn as_str(&self) -> &str {
+        match self {
+            GeoCode::State(code) => code.as_str(),
+            GeoCode::Lga(code) => code.as_str(),
+            GeoCode::Ward(code) => code.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for GeoCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for GeoCode {
+    type Err = GeoCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
@@ -2,36 +2,73 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
 
+use crate::domain::value_objects::{StateCode, StateCodeError, Violation};
+
 /// LGA code value object
+///
+/// Format `NG-XX-YYY`: the parent state's ISO 3166-2:NG code followed by a
+/// three-character abbreviation of the LGA name, e.g. `NG-LA-IKJ` for Ikeja.
+/// The abbreviation is letters, optionally ending in a digit when two LGAs in
+/// the same state would otherwise share it.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
 pub struct LgaCode(String);
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum LgaCodeError {
-    #[error("LGA code must be between 2 and 20 characters")]
-    InvalidLength,
-    #[error("LGA code must follow format: LGA-XXXX")]
-    InvalidFormat,
+    #[error("LGA code must follow format NG-XX-YYY, got '{0}'")]
+    InvalidFormat(String),
+    #[error("LGA code has an invalid state part: {0}")]
+    InvalidState(#[from] StateCodeError),
+}
+
+impl Violation for LgaCodeError {
+    fn rule(&self) -> &'static str {
+        match self {
+            LgaCodeError::InvalidFormat(_) => "format",
+            LgaCodeError::InvalidState(e) => e.rule(),
+        }
+    }
 }
 
 impl LgaCode {
     /// Create a new LGA code
     pub fn new(code: String) -> Result<Self, LgaCodeError> {
-        if code.len() < 2 || code.len() > 20 {
-            return Err(LgaCodeError::InvalidLength);
-        }
+        let upper = code.trim().to_ascii_uppercase();
+        let (state_part, abbreviation) = upper
+            .rsplit_once('-')
+            .ok_or_else(|| LgaCodeError::InvalidFormat(code.clone()))?;
 
-        if !code.starts_with("LGA-") {
-            return Err(LgaCodeError::InvalidFormat);
+        if !is_lga_abbreviation(abbreviation) {
+            return Err(LgaCodeError::InvalidFormat(code));
         }
 
-        Ok(Self(code))
+        StateCode::new(state_part.to_string())?;
+
+        Ok(Self(upper))
     }
 
     /// Get the code as string
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Code of the state this LGA belongs to
+    pub fn state_code(&self) -> StateCode {
+        StateCode::new(self.0[..5].to_string()).expect("validated on construction")
+    }
+
+    /// Three-character LGA abbreviation, e.g. `IKJ`
+    pub fn abbreviation(&self) -> &str {
+        &self.0[6..]
+    }
+}
+
+fn is_lga_abbreviation(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    bytes.len() == 3
+        && bytes[..2].iter().all(u8::is_ascii_uppercase)
+        && (bytes[2].is_ascii_uppercase() || bytes[2].is_ascii_digit())
 }
 
 impl fmt::Display for LgaCode {
@@ -40,6 +77,14 @@ impl fmt::Display for LgaCode {
     }
 }
 
+impl TryFrom<String> for LgaCode {
+    type Error = LgaCodeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
 impl From<LgaCode> for String {
     fn from(code: LgaCode) -> Self {
         code.0
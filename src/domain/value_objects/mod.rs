@@ -3,10 +3,16 @@ pub mod lga_code;
 pub mod ward_code;
 pub mod postal_code;
 pub mod coordinates;
+pub mod bounding_box;
+pub mod geo_code;
+pub mod violation;
 
 // Re-exports for convenience
-pub use state_code::{StateCode, StateCodeError};
+pub use state_code::{CanonicalState, StateCode, StateCodeError, NIGERIAN_STATES};
 pub use lga_code::{LgaCode, LgaCodeError};
 pub use ward_code::{WardCode, WardCodeError};
-pub use postal_code::{PostalCode, PostalCodeError};
+pub use postal_code::{PostalCode, PostalCodeError, PostalRegion};
 pub use coordinates::{Coordinates, CoordinatesError};
+pub use bounding_box::{BoundingBox, NIGERIA};
+pub use geo_code::{GeoCode, GeoCodeError};
+pub use violation::Violation;
@@ -2,29 +2,128 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
 
+use crate::domain::value_objects::{StateCode, Violation};
+
+/// Number of digits in a NIPOST postal code
+pub const POSTAL_CODE_LENGTH: usize = 6;
+
 /// Postal code value object
+///
+/// NIPOST codes are six digits read left to right: the first digit is the
+/// postal region, the first two digits identify the state, the first three
+/// the delivery district and the last three the delivery location within it.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
 pub struct PostalCode(String);
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum PostalCodeError {
-    #[error("Postal code must be between 5 and 10 characters")]
-    InvalidLength,
-    #[error("Postal code must contain only digits")]
-    InvalidFormat,
+    #[error("Postal code must be exactly 6 digits, got {0}")]
+    InvalidLength(usize),
+    #[error("Postal code must contain only digits, found '{0}'")]
+    InvalidFormat(char),
+    #[error("Postal code cannot start with {0}: NIPOST regions are numbered 1 to 9")]
+    UnknownRegion(char),
+    #[error("Postal code prefix {prefix} is not assigned to any state in the {region} region")]
+    UnassignedPrefix {
+        prefix: String,
+        region: &'static str,
+    },
 }
 
+impl Violation for PostalCodeError {
+    fn rule(&self) -> &'static str {
+        match self {
+            PostalCodeError::InvalidLength(_) => "length",
+            PostalCodeError::InvalidFormat(_) => "digits",
+            PostalCodeError::UnknownRegion(_) => "region",
+            PostalCodeError::UnassignedPrefix { .. } => "state_prefix",
+        }
+    }
+}
+
+/// NIPOST postal region, identified by the first digit of a code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PostalRegion {
+    pub digit: char,
+    pub name: &'static str,
+}
+
+#[rustfmt::skip]
+const POSTAL_REGIONS: [PostalRegion; 9] = [
+    PostalRegion { digit: '1', name: "Lagos" },
+    PostalRegion { digit: '2', name: "Ibadan" },
+    PostalRegion { digit: '3', name: "Benin" },
+    PostalRegion { digit: '4', name: "Enugu" },
+    PostalRegion { digit: '5', name: "Port Harcourt" },
+    PostalRegion { digit: '6', name: "Maiduguri" },
+    PostalRegion { digit: '7', name: "Kano" },
+    PostalRegion { digit: '8', name: "Kaduna" },
+    PostalRegion { digit: '9', name: "Abuja" },
+];
+
+/// Two-digit prefixes assigned to each state, keyed by ISO 3166-2:NG code
+#[rustfmt::skip]
+const POSTAL_STATE_PREFIXES: [(&str, &[&str]); 37] = [
+    ("NG-LA", &["10"]),
+    ("NG-OG", &["11", "12"]),
+    ("NG-OY", &["20", "21", "22"]),
+    ("NG-OS", &["23"]),
+    ("NG-KW", &["24", "25"]),
+    ("NG-KO", &["26", "27"]),
+    ("NG-ED", &["30", "31"]),
+    ("NG-DE", &["32", "33"]),
+    ("NG-ON", &["34", "35"]),
+    ("NG-EK", &["36", "37"]),
+    ("NG-EN", &["40", "41"]),
+    ("NG-AN", &["42", "43"]),
+    ("NG-AB", &["44", "45"]),
+    ("NG-IM", &["46", "47"]),
+    ("NG-EB", &["48", "49"]),
+    ("NG-RI", &["50", "51"]),
+    ("NG-AK", &["52", "53"]),
+    ("NG-CR", &["54", "55"]),
+    ("NG-BY", &["56"]),
+    ("NG-BO", &["60", "61"]),
+    ("NG-YO", &["62", "63"]),
+    ("NG-AD", &["64", "65"]),
+    ("NG-TA", &["66", "67"]),
+    ("NG-KN", &["70", "71"]),
+    ("NG-JI", &["72", "73"]),
+    ("NG-BA", &["74", "75"]),
+    ("NG-GO", &["76", "77"]),
+    ("NG-KD", &["80", "81"]),
+    ("NG-KT", &["82", "83"]),
+    ("NG-SO", &["84", "85"]),
+    ("NG-KE", &["86", "87"]),
+    ("NG-ZA", &["88", "89"]),
+    ("NG-FC", &["90", "91"]),
+    ("NG-NI", &["92"]),
+    ("NG-PL", &["93", "94"]),
+    ("NG-NA", &["95", "96"]),
+    ("NG-BE", &["97", "98"]),
+];
+
 impl PostalCode {
-    /// Create a new postal code
+    /// Create a new postal code, rejecting codes whose prefix NIPOST never assigns
     pub fn new(code: String) -> Result<Self, PostalCodeError> {
-        if code.len() < 5 || code.len() > 10 {
-            return Err(PostalCodeError::InvalidLength);
+        let length = code.chars().count();
+        if length != POSTAL_CODE_LENGTH {
+            return Err(PostalCodeError::InvalidLength(length));
         }
-        
-        if !code.chars().all(|c| c.is_ascii_digit()) {
-            return Err(PostalCodeError::InvalidFormat);
+
+        if let Some(c) = code.chars().find(|c| !c.is_ascii_digit()) {
+            return Err(PostalCodeError::InvalidFormat(c));
         }
-        
+
+        let region = Self::lookup_region(&code)?;
+        if Self::lookup_state(&code).is_none() {
+            return Err(PostalCodeError::UnassignedPrefix {
+                prefix: code[..2].to_string(),
+                region: region.name,
+            });
+        }
+
         Ok(Self(code))
     }
 
@@ -32,6 +131,64 @@ impl PostalCode {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Postal region encoded by the first digit
+    pub fn region(&self) -> PostalRegion {
+        Self::lookup_region(&self.0).expect("validated on construction")
+    }
+
+    /// State encoded by the first two digits
+    pub fn state_code(&self) -> StateCode {
+        let (iso_code, _) = Self::lookup_state(&self.0).expect("validated on construction");
+        StateCode::new(iso_code.to_string())
+            .expect("postal prefix table uses canonical state codes")
+    }
+
+    /// Two-digit state prefix, e.g. `10` for `100001`
+    pub fn state_prefix(&self) -> &str {
+        &self.0[..2]
+    }
+
+    /// Three-digit delivery district, e.g. `100` for `100001`
+    pub fn district(&self) -> &str {
+        &self.0[..3]
+    }
+
+    /// Three-digit delivery location within the district, e.g. `001` for `100001`
+    pub fn delivery_location(&self) -> &str {
+        &self.0[3..]
+    }
+
+    /// Check that the code's prefix belongs to the given state
+    pub fn matches_state(&self, state_code: &StateCode) -> bool {
+        Self::lookup_state(&self.0).is_some_and(|(iso_code, _)| iso_code == state_code.as_str())
+    }
+
+    /// Postal prefixes assigned to a state
+    pub fn prefixes_for_state(state_code: &StateCode) -> &'static [&'static str] {
+        POSTAL_STATE_PREFIXES
+            .iter()
+            .find(|(iso_code, _)| *iso_code == state_code.as_str())
+            .map(|(_, prefixes)| *prefixes)
+            .unwrap_or(&[])
+    }
+
+    fn lookup_region(code: &str) -> Result<PostalRegion, PostalCodeError> {
+        let digit = code.chars().next().unwrap_or('0');
+        POSTAL_REGIONS
+            .iter()
+            .find(|region| region.digit == digit)
+            .copied()
+            .ok_or(PostalCodeError::UnknownRegion(digit))
+    }
+
+    fn lookup_state(code: &str) -> Option<(&'static str, &'static [&'static str])> {
+        let prefix = code.get(..2)?;
+        POSTAL_STATE_PREFIXES
+            .iter()
+            .find(|(_, prefixes)| prefixes.contains(&prefix))
+            .copied()
+    }
 }
 
 impl fmt::Display for PostalCode {
@@ -40,6 +197,14 @@ impl fmt::Display for PostalCode {
     }
 }
 
+impl TryFrom<String> for PostalCode {
+    type Error = PostalCodeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
 impl From<PostalCode> for String {
     fn from(code: PostalCode) -> Self {
         code.0
@@ -1,37 +1,182 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 use thiserror::Error;
 
+use crate::domain::normalization::normalized;
+use crate::domain::value_objects::Violation;
+
 /// State code value object
+///
+/// Always holds one of the 37 ISO 3166-2:NG codes (36 states plus the FCT),
+/// normalised to upper case.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
 pub struct StateCode(String);
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum StateCodeError {
-    #[error("State code must be between 2 and 10 characters")]
-    InvalidLength,
-    #[error("State code must follow format: NG-XX")]
-    InvalidFormat,
+    #[error("State code must follow format NG-XX, got '{0}'")]
+    InvalidFormat(String),
+    #[error("'{0}' is not an ISO 3166-2:NG state code")]
+    UnknownCode(String),
+    #[error("'{0}' does not match any Nigerian state code, abbreviation, capital or name")]
+    Unrecognized(String),
+}
+
+impl Violation for StateCodeError {
+    fn rule(&self) -> &'static str {
+        match self {
+            StateCodeError::InvalidFormat(_) => "format",
+            StateCodeError::UnknownCode(_) => "known_code",
+            StateCodeError::Unrecognized(_) => "known_state",
+        }
+    }
+}
+
+/// Entry in the canonical table of Nigerian states
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CanonicalState {
+    /// ISO 3166-2:NG code, e.g. `NG-LA`
+    pub iso_code: &'static str,
+    /// Official name
+    pub name: &'static str,
+    /// Capital city
+    pub capital: &'static str,
+    /// Common abbreviations besides the ISO suffix
+    pub abbreviations: &'static [&'static str],
+    /// Alternative names and spellings
+    pub alternative_names: &'static [&'static str],
+}
+
+impl CanonicalState {
+    /// Two-letter ISO suffix, e.g. `LA` for `NG-LA`
+    pub fn abbreviation(&self) -> &'static str {
+        &self.iso_code[3..]
+    }
 }
 
+/// The 36 states and the Federal Capital Territory
+#[rustfmt::skip]
+pub const NIGERIAN_STATES: [CanonicalState; 37] = [
+    CanonicalState { iso_code: "NG-AB", name: "Abia", capital: "Umuahia", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-AD", name: "Adamawa", capital: "Yola", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-AK", name: "Akwa Ibom", capital: "Uyo", abbreviations: &["AKS"], alternative_names: &["Akwa-Ibom"] },
+    CanonicalState { iso_code: "NG-AN", name: "Anambra", capital: "Awka", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-BA", name: "Bauchi", capital: "Bauchi", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-BE", name: "Benue", capital: "Makurdi", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-BO", name: "Borno", capital: "Maiduguri", abbreviations: &[], alternative_names: &["Bornu"] },
+    CanonicalState { iso_code: "NG-BY", name: "Bayelsa", capital: "Yenagoa", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-CR", name: "Cross River", capital: "Calabar", abbreviations: &["CRS"], alternative_names: &["Cross-River"] },
+    CanonicalState { iso_code: "NG-DE", name: "Delta", capital: "Asaba", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-EB", name: "Ebonyi", capital: "Abakaliki", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-ED", name: "Edo", capital: "Benin City", abbreviations: &[], alternative_names: &["Benin"] },
+    CanonicalState { iso_code: "NG-EK", name: "Ekiti", capital: "Ado-Ekiti", abbreviations: &[], alternative_names: &["Ado Ekiti"] },
+    CanonicalState { iso_code: "NG-EN", name: "Enugu", capital: "Enugu", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-FC", name: "Federal Capital Territory", capital: "Abuja", abbreviations: &["FCT"], alternative_names: &["Abuja FCT", "FCT Abuja"] },
+    CanonicalState { iso_code: "NG-GO", name: "Gombe", capital: "Gombe", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-IM", name: "Imo", capital: "Owerri", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-JI", name: "Jigawa", capital: "Dutse", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-KD", name: "Kaduna", capital: "Kaduna", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-KE", name: "Kebbi", capital: "Birnin Kebbi", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-KN", name: "Kano", capital: "Kano", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-KO", name: "Kogi", capital: "Lokoja", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-KT", name: "Katsina", capital: "Katsina", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-KW", name: "Kwara", capital: "Ilorin", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-LA", name: "Lagos", capital: "Ikeja", abbreviations: &[], alternative_names: &["Eko"] },
+    CanonicalState { iso_code: "NG-NA", name: "Nasarawa", capital: "Lafia", abbreviations: &[], alternative_names: &["Nassarawa"] },
+    CanonicalState { iso_code: "NG-NI", name: "Niger", capital: "Minna", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-OG", name: "Ogun", capital: "Abeokuta", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-ON", name: "Ondo", capital: "Akure", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-OS", name: "Osun", capital: "Osogbo", abbreviations: &[], alternative_names: &["Oshogbo"] },
+    CanonicalState { iso_code: "NG-OY", name: "Oyo", capital: "Ibadan", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-PL", name: "Plateau", capital: "Jos", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-RI", name: "Rivers", capital: "Port Harcourt", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-SO", name: "Sokoto", capital: "Sokoto", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-TA", name: "Taraba", capital: "Jalingo", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-YO", name: "Yobe", capital: "Damaturu", abbreviations: &[], alternative_names: &[] },
+    CanonicalState { iso_code: "NG-ZA", name: "Zamfara", capital: "Gusau", abbreviations: &[], alternative_names: &[] },
+];
+
 impl StateCode {
-    /// Create a new state code
+    /// Create a state code from its ISO 3166-2:NG form (case-insensitive)
     pub fn new(code: String) -> Result<Self, StateCodeError> {
-        if code.len() < 2 || code.len() > 10 {
-            return Err(StateCodeError::InvalidLength);
+        let upper = code.trim().to_ascii_uppercase();
+        if upper.len() != 5
+            || !upper.starts_with("NG-")
+            || !upper[3..].chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return Err(StateCodeError::InvalidFormat(code));
         }
-        
-        if !code.starts_with("NG-") {
-            return Err(StateCodeError::InvalidFormat);
+
+        NIGERIAN_STATES
+            .iter()
+            .find(|state| state.iso_code == upper)
+            .map(|state| Self(state.iso_code.to_string()))
+            .ok_or(StateCodeError::UnknownCode(code))
+    }
+
+    /// Resolve a state from its ISO code, abbreviation, capital or any known name
+    ///
+    /// Names are compared in [normalized](crate::domain::normalization) form,
+    /// so `NG-LA`, `la`, `Ikeja`, `Èkó` and `Lagos State` all resolve to `NG-LA`.
+    pub fn lookup(value: &str) -> Result<Self, StateCodeError> {
+        if value.trim().to_ascii_uppercase().starts_with("NG-") {
+            return Self::new(value.to_string());
         }
-        
-        Ok(Self(code))
+
+        let needle = normalized(value);
+
+        NIGERIAN_STATES
+            .iter()
+            .find(|state| {
+                normalized(state.abbreviation()) == needle
+                    || normalized(state.name) == needle
+                    || normalized(state.capital) == needle
+                    || state.abbreviations.iter().any(|a| normalized(a) == needle)
+                    || state
+                        .alternative_names
+                        .iter()
+                        .any(|n| normalized(n) == needle)
+            })
+            .map(|state| Self(state.iso_code.to_string()))
+            .ok_or_else(|| StateCodeError::Unrecognized(value.to_string()))
+    }
+
+    /// All canonical state codes in ISO order
+    pub fn all() -> impl Iterator<Item = StateCode> {
+        NIGERIAN_STATES
+            .iter()
+            .map(|state| Self(state.iso_code.to_string()))
     }
 
     /// Get the code as string
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Canonical table entry for this code
+    pub fn state(&self) -> &'static CanonicalState {
+        NIGERIAN_STATES
+            .iter()
+            .find(|state| state.iso_code == self.0)
+            .expect("validated on construction")
+    }
+
+    /// Official state name
+    pub fn name(&self) -> &'static str {
+        self.state().name
+    }
+
+    /// State capital
+    pub fn capital(&self) -> &'static str {
+        self.state().capital
+    }
+
+    /// Two-letter ISO suffix, e.g. `LA`
+    pub fn abbreviation(&self) -> &'static str {
+        self.state().abbreviation()
+    }
 }
 
 impl fmt::Display for StateCode {
@@ -40,6 +185,30 @@ impl fmt::Display for StateCode {
     }
 }
 
+impl FromStr for StateCode {
+    type Err = StateCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::lookup(s)
+    }
+}
+
+impl TryFrom<&str> for StateCode {
+    type Error = StateCodeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::lookup(value)
+    }
+}
+
+impl TryFrom<String> for StateCode {
+    type Error = StateCodeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::lookup(&value)
+    }
+}
+
 impl From<StateCode> for String {
     fn from(code: StateCode) -> Self {
         code.0
@@ -0,0 +1,7 @@
+/// Error for input a value object rejected, naming the rule it broke
+///
+/// Rules are short, stable names (`format`, `range`, ...) that clients can
+/// branch on; the error's message explains the failure to a person.
+pub trait Violation: std::error::Error {
+    fn rule(&self) -> &'static str;
+}
@@ -2,36 +2,74 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use thiserror::Error;
 
+use crate::domain::value_objects::{LgaCode, LgaCodeError, StateCode, Violation};
+
 /// Ward code value object
+///
+/// Format `NG-XX-YYY-WNN`: the parent LGA's code followed by `W` and the
+/// ward's two- or three-digit number within that LGA, e.g. `NG-LA-IKJ-W03`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
 pub struct WardCode(String);
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum WardCodeError {
-    #[error("Ward code must be between 2 and 25 characters")]
-    InvalidLength,
-    #[error("Ward code must follow format: WARD-XXXXX")]
-    InvalidFormat,
+    #[error("Ward code must follow format NG-XX-YYY-WNN, got '{0}'")]
+    InvalidFormat(String),
+    #[error("Ward code has an invalid LGA part: {0}")]
+    InvalidLga(#[from] LgaCodeError),
+}
+
+impl Violation for WardCodeError {
+    fn rule(&self) -> &'static str {
+        match self {
+            WardCodeError::InvalidFormat(_) => "format",
+            WardCodeError::InvalidLga(e) => e.rule(),
+        }
+    }
 }
 
 impl WardCode {
     /// Create a new ward code
     pub fn new(code: String) -> Result<Self, WardCodeError> {
-        if code.len() < 2 || code.len() > 25 {
-            return Err(WardCodeError::InvalidLength);
-        }
+        let upper = code.trim().to_ascii_uppercase();
+        let (lga_part, ward_part) = upper
+            .rsplit_once('-')
+            .ok_or_else(|| WardCodeError::InvalidFormat(code.clone()))?;
 
-        if !code.starts_with("WARD-") {
-            return Err(WardCodeError::InvalidFormat);
+        let digits = ward_part
+            .strip_prefix('W')
+            .ok_or_else(|| WardCodeError::InvalidFormat(code.clone()))?;
+        if !(2..=3).contains(&digits.len()) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(WardCodeError::InvalidFormat(code));
         }
 
-        Ok(Self(code))
+        LgaCode::new(lga_part.to_string())?;
+
+        Ok(Self(upper))
     }
 
     /// Get the code as string
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Code of the LGA this ward belongs to
+    pub fn lga_code(&self) -> LgaCode {
+        let (lga_part, _) = self.0.rsplit_once('-').expect("validated on construction");
+        LgaCode::new(lga_part.to_string()).expect("validated on construction")
+    }
+
+    /// Code of the state this ward belongs to
+    pub fn state_code(&self) -> StateCode {
+        self.lga_code().state_code()
+    }
+
+    /// Ward number within its LGA, e.g. `3` for `NG-LA-IKJ-W03`
+    pub fn number(&self) -> u16 {
+        let (_, ward_part) = self.0.rsplit_once('-').expect("validated on construction");
+        ward_part[1..].parse().expect("validated on construction")
+    }
 }
 
 impl fmt::Display for WardCode {
@@ -40,6 +78,14 @@ impl fmt::Display for WardCode {
     }
 }
 
+impl TryFrom<String> for WardCode {
+    type Error = WardCodeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
 impl From<WardCode> for String {
     fn from(code: WardCode) -> Self {
         code.0
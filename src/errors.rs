@@ -1,12 +1,16 @@
 use axum::{
-    http::StatusCode,
+    http::{header, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
-use serde_json::json;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use tracing::error;
 use utoipa::ToSchema;
 
+use crate::domain::value_objects::Violation;
+use crate::presentation::middleware::request_id::current_request;
+
 /// Application result type
 pub type AppResult<T> = Result<T, AppError>;
 
@@ -22,6 +26,18 @@ pub enum AppError {
     #[error("Not found: {0}")]
     NotFound(String),
     
+    #[error("Bad request: {0}")]
+    BadRequest(String),
+    
+    #[error("Conflict: {0}")]
+    Conflict(String),
+    
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+    
+    #[error("Service unavailable: {0}")]
+    ServiceUnavailable(String),
+    
     #[error("Internal server error: {0}")]
     Internal(#[from] anyhow::Error),
     
@@ -33,26 +49,225 @@ pub enum AppError {
     
     #[error("Coordinates error: {0}")]
     Coordinates(#[from] crate::domain::value_objects::CoordinatesError),
+    
+    #[error("Invalid cursor: {0}")]
+    Cursor(#[from] crate::domain::pagination::CursorError),
+    
+    #[error("Invalid parameter '{}': {}", .0.field, .0.detail)]
+    InvalidParam(InvalidParam),
+}
+
+/// Request field that was rejected, and the rule it broke
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InvalidParam {
+    /// Path, query or body field, e.g. `id`, `lat` or `address.postal_code`
+    #[schema(example = "lat")]
+    pub field: String,
+    /// Stable name of the rule, e.g. `type`, `required`, `format` or `range`
+    #[schema(example = "range")]
+    pub rule: String,
+    /// What was wrong with the value
+    #[schema(example = "Latitude must be between -90 and 90 degrees")]
+    pub detail: String,
+}
+
+impl InvalidParam {
+    pub fn new(
+        field: impl Into<String>,
+        rule: impl Into<String>,
+        detail: impl Into<String>,
+    ) -> Self {
+        Self {
+            field: field.into(),
+            rule: rule.into(),
+            detail: detail.into(),
+        }
+    }
+}
+
+impl AppError {
+    /// Error for a request field a value object rejected
+    pub fn invalid_param(field: &str, violation: impl Violation) -> Self {
+        AppError::InvalidParam(InvalidParam::new(
+            field,
+            violation.rule(),
+            violation.to_string(),
+        ))
+    }
+}
+
+/// File and stream failures are the server's fault
+impl From<std::io::Error> for AppError {
+    fn from(error: std::io::Error) -> Self {
+        AppError::Internal(error.into())
+    }
+}
+
+impl From<rusqlite::Error> for AppError {
+    fn from(error: rusqlite::Error) -> Self {
+        AppError::Internal(error.into())
+    }
+}
+
+/// Base of the `type` URIs identifying each kind of problem
+pub const PROBLEM_TYPE_BASE: &str = "https://api.nigeriageo.com/problems/";
+
+/// RFC 7807 problem details, the body of every error response
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProblemDetails {
+    /// URI identifying the kind of problem; stable across releases
+    #[serde(rename = "type")]
+    #[schema(example = "https://api.nigeriageo.com/problems/not-found")]
+    pub problem_type: String,
+    /// Short summary of the kind of problem
+    #[schema(example = "Resource not found")]
+    pub title: String,
+    /// HTTP status code
+    #[schema(example = 404)]
+    pub status: u16,
+    /// What went wrong with this request
+    #[schema(example = "State not found")]
+    pub detail: String,
+    /// Path of the request that failed
+    #[schema(example = "/api/v1/states/550e8400-e29b-41d4-a716-446655440001")]
+    pub instance: Option<String>,
+    /// ID of the request, also sent in the `X-Request-Id` header
+    pub correlation_id: Option<String>,
+    /// Fields that were rejected, for invalid path, query or body values
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invalid_params: Vec<InvalidParam>,
+}
+
+impl AppError {
+    /// Status, `type` URI slug and title of the response for this error
+    fn kind(&self) -> (StatusCode, &'static str, &'static str) {
+        match self {
+            AppError::Database(sqlx::Error::RowNotFound) | AppError::NotFound(_) => {
+                (StatusCode::NOT_FOUND, "not-found", "Resource not found")
+            }
+            AppError::Database(_) | AppError::Internal(_) | AppError::Config(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "internal-error",
+                "Internal server error",
+            ),
+            AppError::Validation(_) => (
+                StatusCode::BAD_REQUEST,
+                "validation-error",
+                "Validation error",
+            ),
+            AppError::BadRequest(_) => (StatusCode::BAD_REQUEST, "bad-request", "Bad request"),
+            AppError::Conflict(_) => (StatusCode::CONFLICT, "conflict", "Conflict"),
+            AppError::Unauthorized(_) => (StatusCode::UNAUTHORIZED, "unauthorized", "Unauthorized"),
+            AppError::ServiceUnavailable(_) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "service-unavailable",
+                "Service unavailable",
+            ),
+            AppError::Serialization(_) => (
+                StatusCode::BAD_REQUEST,
+                "invalid-data-format",
+                "Invalid data format",
+            ),
+            AppError::Coordinates(_) => (
+                StatusCode::BAD_REQUEST,
+                "invalid-coordinates",
+                "Invalid coordinates",
+            ),
+            AppError::Cursor(_) => (StatusCode::BAD_REQUEST, "invalid-cursor", "Invalid cursor"),
+            AppError::InvalidParam(_) => (
+                StatusCode::BAD_REQUEST,
+                "invalid-parameter",
+                "Invalid parameter",
+            ),
+        }
+    }
+
+    /// Explanation safe to show the client; `None` for internal errors,
+    /// whose causes are only logged
+    pub(crate) fn client_detail(&self) -> Option<String> {
+        match self {
+            AppError::Database(sqlx::Error::RowNotFound) => Some("Resource not found".to_string()),
+            AppError::Database(_) | AppError::Internal(_) | AppError::Config(_) => None,
+            AppError::Validation(e) => Some(e.to_string()),
+            AppError::NotFound(message)
+            | AppError::BadRequest(message)
+            | AppError::Conflict(message)
+            | AppError::Unauthorized(message)
+            | AppError::ServiceUnavailable(message) => Some(message.clone()),
+            AppError::Serialization(e) => Some(e.to_string()),
+            AppError::Coordinates(e) => Some(e.to_string()),
+            AppError::Cursor(e) => Some(e.to_string()),
+            AppError::InvalidParam(param) if param.rule == "required" => {
+                Some(format!("Missing required parameter '{}'", param.field))
+            }
+            AppError::InvalidParam(param) => Some(format!(
+                "Invalid value for '{}': {}",
+                param.field, param.detail
+            )),
+        }
+    }
+
+    /// Fields to list in the response, for errors caused by particular fields
+    fn invalid_params(&self) -> Vec<InvalidParam> {
+        match self {
+            AppError::InvalidParam(param) => vec![param.clone()],
+            AppError::Coordinates(e) => vec![InvalidParam::new(e.field(), e.rule(), e.to_string())],
+            AppError::Cursor(e) => vec![InvalidParam::new("cursor", "cursor", e.to_string())],
+            AppError::Validation(errors) => {
+                let mut params: Vec<InvalidParam> = errors
+                    .field_errors()
+                    .into_iter()
+                    .flat_map(|(field, errors)| {
+                        errors.iter().map(move |error| {
+                            let detail = error
+                                .message
+                                .as_ref()
+                                .map_or_else(|| error.code.to_string(), ToString::to_string);
+                            InvalidParam::new(field, error.code.as_ref(), detail)
+                        })
+                    })
+                    .collect();
+                params.sort_by(|a, b| a.field.cmp(&b.field));
+                params
+            }
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            AppError::Database(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Database error"),
-            AppError::Validation(_) => (StatusCode::BAD_REQUEST, "Validation error"),
-            AppError::NotFound(_) => (StatusCode::NOT_FOUND, "Resource not found"),
-            AppError::Internal(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
-            AppError::Config(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Configuration error"),
-            AppError::Serialization(_) => (StatusCode::BAD_REQUEST, "Invalid data format"),
-            AppError::Coordinates(_) => (StatusCode::BAD_REQUEST, "Invalid coordinates"),
+        let (status, slug, title) = self.kind();
+        let request = current_request();
+        let correlation_id = request.as_ref().map(|r| r.correlation_id.clone());
+
+        let detail = match self.client_detail() {
+            Some(detail) => detail,
+            None => {
+                error!(
+                    correlation_id = correlation_id.as_deref().unwrap_or("-"),
+                    "{}", self
+                );
+                "An unexpected error occurred; quote the correlation ID when reporting it"
+                    .to_string()
+            }
         };
 
-        let body = Json(json!({
-            "error": error_message,
-            "message": self.to_string(),
-            "status": status.as_u16()
-        }));
+        let problem = ProblemDetails {
+            problem_type: format!("{}{}", PROBLEM_TYPE_BASE, slug),
+            title: title.to_string(),
+            status: status.as_u16(),
+            detail,
+            instance: request.map(|r| r.path),
+            correlation_id,
+            invalid_params: self.invalid_params(),
+        };
 
-        (status, body).into_response()
+        let mut response = (status, Json(problem)).into_response();
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/problem+json"),
+        );
+        response
     }
 }
@@ -3,6 +3,9 @@ use serde::{Deserialize, Serialize};
 
 use tracing::{debug, error, info};
 
+use crate::domain::entities::search::MatchMode;
+use crate::domain::pagination::PageRequest;
+
 /// Redis cache client wrapper
 #[derive(Clone)]
 pub struct CacheClient {
@@ -147,18 +150,19 @@ impl CacheKeys {
         format!("nigeria_geo:lga:{}", id)
     }
 
-    pub fn wards_by_lga(lga_id: &uuid::Uuid, page: u32, limit: u32) -> String {
-        format!("nigeria_geo:lga:{}:wards:p{}:l{}", lga_id, page, limit)
+    pub fn wards_by_lga(lga_id: &uuid::Uuid, request: &PageRequest) -> String {
+        format!("nigeria_geo:lga:{}:wards:{}", lga_id, page_segment(request))
     }
 
     pub fn ward_by_id(id: &uuid::Uuid) -> String {
         format!("nigeria_geo:ward:{}", id)
     }
 
-    pub fn postal_codes_by_ward(ward_id: &uuid::Uuid, page: u32, limit: u32) -> String {
+    pub fn postal_codes_by_ward(ward_id: &uuid::Uuid, request: &PageRequest) -> String {
         format!(
-            "nigeria_geo:ward:{}:postal_codes:p{}:l{}",
-            ward_id, page, limit
+            "nigeria_geo:ward:{}:postal_codes:{}",
+            ward_id,
+            page_segment(request)
         )
     }
 
@@ -170,8 +174,13 @@ impl CacheKeys {
         format!("nigeria_geo:postal_code:code:{}", code)
     }
 
-    pub fn search_results(query: &str, page: u32, limit: u32) -> String {
-        format!("nigeria_geo:search:{}:p{}:l{}", query, page, limit)
+    pub fn search_results(query: &str, mode: MatchMode, request: &PageRequest) -> String {
+        format!(
+            "nigeria_geo:search:{}:{}:{}",
+            mode.as_str(),
+            query,
+            page_segment(request)
+        )
     }
 
     pub fn rate_limit(identifier: &str) -> String {
@@ -179,6 +188,14 @@ impl CacheKeys {
     }
 }
 
+/// Key segment for one page of a cursor-enabled listing
+fn page_segment(request: &PageRequest) -> String {
+    match request {
+        PageRequest::Offset { page, limit } => format!("p{}:l{}", page, limit),
+        PageRequest::Cursor { cursor, limit } => format!("c{}:l{}", cursor.encode(), limit),
+    }
+}
+
 /// Cache TTL constants (in seconds)
 pub struct CacheTTL;
 
@@ -11,12 +11,14 @@ use crate::application::use_cases::{
     state_use_cases::StateUseCases,
     ward_use_cases::WardUseCases,
 };
+use crate::domain::entities::search::MatchMode;
 use crate::errors::AppResult;
 use crate::infrastructure::{
     cache::{CacheClient, CacheKeys, CacheTTL},
     repositories::{
         lga_repository_impl::PostgresLgaRepository,
         postal_code_repository_impl::PostgresPostalCodeRepository,
+        search_repository_impl::PostgresSearchRepository,
         state_repository_impl::PostgresStateRepository,
         ward_repository_impl::PostgresWardRepository,
     },
@@ -35,6 +37,7 @@ pub struct CachedServices {
         PostgresLgaRepository,
         PostgresWardRepository,
         PostgresPostalCodeRepository,
+        PostgresSearchRepository,
     >,
 }
 
@@ -50,6 +53,7 @@ impl CachedServices {
             PostgresLgaRepository,
             PostgresWardRepository,
             PostgresPostalCodeRepository,
+            PostgresSearchRepository,
         >,
     ) -> Self {
         Self {
@@ -116,8 +120,7 @@ impl CachedServices {
         state_id: Uuid,
         params: PaginationParams,
     ) -> AppResult<PaginatedResponse<LgaDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
+        let (page, limit) = params.page_and_limit()?;
         let cache_key = CacheKeys::lgas_by_state(&state_id, page, limit);
 
         if let Ok(Some(cached_result)) = self
@@ -170,9 +173,8 @@ impl CachedServices {
         lga_id: Uuid,
         params: PaginationParams,
     ) -> AppResult<PaginatedResponse<WardDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
-        let cache_key = CacheKeys::wards_by_lga(&lga_id, page, limit);
+        let request = params.page_request()?;
+        let cache_key = CacheKeys::wards_by_lga(&lga_id, &request);
 
         if let Ok(Some(cached_result)) = self
             .cache
@@ -221,9 +223,8 @@ impl CachedServices {
         ward_id: Uuid,
         params: PaginationParams,
     ) -> AppResult<PaginatedResponse<PostalCodeDto>> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
-        let cache_key = CacheKeys::postal_codes_by_ward(&ward_id, page, limit);
+        let request = params.page_request()?;
+        let cache_key = CacheKeys::postal_codes_by_ward(&ward_id, &request);
 
         if let Ok(Some(cached_result)) = self
             .cache
@@ -310,11 +311,11 @@ impl CachedServices {
     pub async fn search_all(
         &self,
         query: &str,
+        mode: MatchMode,
         params: PaginationParams,
     ) -> AppResult<SearchResultDto> {
-        let page = params.page.unwrap_or(1);
-        let limit = params.limit.unwrap_or(20);
-        let cache_key = CacheKeys::search_results(query, page, limit);
+        let request = params.page_request()?;
+        let cache_key = CacheKeys::search_results(query, mode, &request);
 
         if let Ok(Some(cached_result)) = self.cache.get::<SearchResultDto>(&cache_key).await {
             info!("Cache hit for search query: {}", query);
@@ -325,7 +326,7 @@ impl CachedServices {
             "Cache miss for search query: {} - fetching from database",
             query
         );
-        let result = self.search_use_cases.search_all(query, params).await?;
+        let result = self.search_use_cases.search_all(query, mode, params).await?;
 
         if let Err(e) = self
             .cache
@@ -352,32 +353,32 @@ impl CachedServices {
             .await
     }
 
-    // Individual search methods that delegate to search_all and extract relevant parts
+    // Per-entity searches are not cached
     pub async fn search_states(
         &self,
         query: &str,
+        mode: MatchMode,
         params: PaginationParams,
     ) -> AppResult<Vec<StateDto>> {
-        let search_result = self.search_all(query, params).await?;
-        Ok(search_result.states)
+        self.search_use_cases.search_states(query, mode, params).await
     }
 
     pub async fn search_lgas(
         &self,
         query: &str,
+        mode: MatchMode,
         params: PaginationParams,
     ) -> AppResult<Vec<LgaDto>> {
-        let search_result = self.search_all(query, params).await?;
-        Ok(search_result.lgas)
+        self.search_use_cases.search_lgas(query, mode, params).await
     }
 
     pub async fn search_wards(
         &self,
         query: &str,
+        mode: MatchMode,
         params: PaginationParams,
     ) -> AppResult<Vec<WardDto>> {
-        let search_result = self.search_all(query, params).await?;
-        Ok(search_result.wards)
+        self.search_use_cases.search_wards(query, mode, params).await
     }
 
     pub async fn search_postal_codes(
@@ -385,8 +386,7 @@ impl CachedServices {
         query: &str,
         params: PaginationParams,
     ) -> AppResult<Vec<PostalCodeDto>> {
-        let search_result = self.search_all(query, params).await?;
-        Ok(search_result.postal_codes)
+        self.search_use_cases.search_postal_codes(query, params).await
     }
 
     /// Access to underlying use cases for methods that shouldn't be cached
@@ -413,6 +413,7 @@ impl CachedServices {
         PostgresLgaRepository,
         PostgresWardRepository,
         PostgresPostalCodeRepository,
+        PostgresSearchRepository,
     > {
         &self.search_use_cases
     }
@@ -1,2 +1,88 @@
-// Database utilities will be added here
-// For now, this is a placeholder to satisfy the module declaration
+use std::time::Duration;
+
+use sqlx::{postgres::PgPoolOptions, PgPool, Row};
+use uuid::Uuid;
+
+use crate::domain::entities::dataset_version::DatasetVersion;
+use crate::domain::normalization::normalized;
+use crate::domain::phonetic::phonetic_key;
+use crate::errors::AppResult;
+
+/// Tables whose `name` has `normalized_name` and `phonetic_key` counterparts
+const NAME_KEY_TABLES: [&str; 4] = ["states", "lgas", "wards", "aliases"];
+
+/// Fill in `normalized_name` and `phonetic_key` wherever they are missing or
+/// were computed by an older version of the rules, returning the number of
+/// rows updated
+pub async fn backfill_name_keys(pool: &PgPool) -> AppResult<u64> {
+    let mut updated = 0;
+
+    for table in NAME_KEY_TABLES {
+        let rows = sqlx::query(&format!(
+            "SELECT id, name, normalized_name, phonetic_key FROM {}",
+            table
+        ))
+        .fetch_all(pool)
+        .await?;
+
+        let mut ids = Vec::new();
+        let mut normalized_names = Vec::new();
+        let mut phonetic_keys = Vec::new();
+        for row in &rows {
+            let name = row.get::<&str, _>("name");
+            let (normalized_name, key) = (normalized(name), phonetic_key(name));
+            let current = (
+                row.get::<Option<&str>, _>("normalized_name"),
+                row.get::<Option<&str>, _>("phonetic_key"),
+            );
+
+            if current != (Some(normalized_name.as_str()), Some(key.as_str())) {
+                ids.push(row.get::<Uuid, _>("id"));
+                normalized_names.push(normalized_name);
+                phonetic_keys.push(key);
+            }
+        }
+
+        if ids.is_empty() {
+            continue;
+        }
+
+        let result = sqlx::query(&format!(
+            "UPDATE {} AS t
+             SET normalized_name = v.normalized_name, phonetic_key = v.phonetic_key
+             FROM UNNEST($1::uuid[], $2::text[], $3::text[]) AS v(id, normalized_name, phonetic_key)
+             WHERE t.id = v.id",
+            table
+        ))
+        .bind(&ids)
+        .bind(&normalized_names)
+        .bind(&phonetic_keys)
+        .execute(pool)
+        .await?;
+
+        updated += result.rows_affected();
+    }
+
+    Ok(updated)
+}
+
+/// Connections each pinned release may hold open
+const RELEASE_POOL_CONNECTIONS: u32 = 2;
+
+/// A pool reading `version`'s copy of the data in place of the live tables
+///
+/// Connections are opened on first use, with the release's schema ahead of
+/// `public` on the search path, and are read-only. Anything not copied into
+/// the release, such as extension functions, still resolves to `public`.
+pub fn release_pool(pool: &PgPool, version: &DatasetVersion) -> PgPool {
+    let options = (*pool.connect_options()).clone().options([
+        ("search_path", format!("{},public", version.schema_name())),
+        ("default_transaction_read_only", "on".to_string()),
+    ]);
+
+    PgPoolOptions::new()
+        .max_connections(RELEASE_POOL_CONNECTIONS)
+        .acquire_timeout(Duration::from_secs(5))
+        .idle_timeout(Duration::from_secs(300))
+        .connect_lazy_with(options)
+}
@@ -0,0 +1,308 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::entities::address::{
+    Address, AddressValidationRequest, AddressValidationResponse, ComponentMatch, SimilarAddress,
+};
+use crate::domain::entities::alias::AliasEntityType;
+use crate::domain::entities::{Lga, State, Ward};
+use crate::domain::normalization::{normalized, rules_needed};
+use crate::domain::repositories::address_repository::AddressRepository;
+use crate::domain::value_objects::PostalCode as PostalCodeValue;
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::embedded::dataset::{Entry, NameForms};
+use crate::infrastructure::embedded::matching::{lower, Like, Trigrams, SIMILARITY_THRESHOLD};
+use crate::infrastructure::embedded::{
+    EmbeddedDataset, EmbeddedLgaRepository, EmbeddedPostalCodeRepository, EmbeddedStateRepository,
+    EmbeddedWardRepository,
+};
+use crate::infrastructure::repositories::address_repository_impl::generate_suggestions;
+
+/// The state, LGA and ward an address names, those that were found
+type Components<'a> = (
+    Option<&'a Entry<State>>,
+    Option<&'a Entry<Lga>>,
+    Option<&'a Entry<Ward>>,
+);
+
+/// Embedded implementation of AddressRepository
+pub struct EmbeddedAddressRepository {
+    dataset: Arc<EmbeddedDataset>,
+    state_repo: EmbeddedStateRepository,
+    lga_repo: EmbeddedLgaRepository,
+    ward_repo: EmbeddedWardRepository,
+    postal_code_repo: EmbeddedPostalCodeRepository,
+}
+
+impl EmbeddedAddressRepository {
+    pub fn new(dataset: Arc<EmbeddedDataset>) -> Self {
+        Self {
+            state_repo: EmbeddedStateRepository::new(dataset.clone()),
+            lga_repo: EmbeddedLgaRepository::new(dataset.clone()),
+            ward_repo: EmbeddedWardRepository::new(dataset.clone()),
+            postal_code_repo: EmbeddedPostalCodeRepository::new(dataset.clone()),
+            dataset,
+        }
+    }
+
+    /// Find the state, LGA and ward by name or alias, each child within its
+    /// parent once the parent is found
+    fn find_components(&self, state: &str, lga: &str, ward: &str) -> Components<'_> {
+        let state_entry = self.dataset.states.find_by_name(state);
+        let lga_entry = match state_entry {
+            Some(state) => self.dataset.lgas.find_child_by_name(state.unit.id, lga),
+            None => self.dataset.lgas.find_by_name(lga),
+        };
+        let ward_entry = match lga_entry {
+            Some(lga) => self.dataset.wards.find_child_by_name(lga.unit.id, ward),
+            None => self.dataset.wards.find_by_name(ward),
+        };
+
+        (state_entry, lga_entry, ward_entry)
+    }
+}
+
+/// Report the name or alias a component was found by, and the normalization
+/// rules the input needed to match it
+fn component_match(
+    entity_type: AliasEntityType,
+    input: &str,
+    name: &NameForms,
+    aliases: &[NameForms],
+) -> ComponentMatch {
+    let matched_name = match rules_needed(input, &name.name) {
+        Some(_) => name.name.clone(),
+        None => {
+            let (lower_input, normalized_input) = (lower(input), normalized(input));
+            aliases
+                .iter()
+                .filter(|alias| alias.lower == lower_input || alias.normalized == normalized_input)
+                .min_by_key(|alias| alias.lower != lower_input)
+                .map_or_else(|| name.name.clone(), |alias| alias.name.clone())
+        }
+    };
+
+    ComponentMatch {
+        component: entity_type.as_str(),
+        input: input.to_string(),
+        rules: rules_needed(input, &matched_name).unwrap_or_default(),
+        matched_name,
+    }
+}
+
+#[async_trait]
+impl AddressRepository for EmbeddedAddressRepository {
+    async fn validate_address(
+        &self,
+        request: &AddressValidationRequest,
+    ) -> AppResult<AddressValidationResponse> {
+        let (state, lga, ward) = self.find_components(&request.state, &request.lga, &request.ward);
+
+        let mut matches = Vec::new();
+        if let Some(state) = state {
+            matches.push(component_match(
+                AliasEntityType::State,
+                &request.state,
+                &state.name,
+                &state.aliases,
+            ));
+        }
+        if let Some(lga) = lga {
+            matches.push(component_match(
+                AliasEntityType::Lga,
+                &request.lga,
+                &lga.name,
+                &lga.aliases,
+            ));
+        }
+        if let Some(ward) = ward {
+            matches.push(component_match(
+                AliasEntityType::Ward,
+                &request.ward,
+                &ward.name,
+                &ward.aliases,
+            ));
+        }
+        let postal_code_value = PostalCodeValue::new(request.postal_code.clone())
+            .map_err(|e| AppError::invalid_param("postal_code", e))?;
+        let postal_code = self
+            .dataset
+            .postal_codes
+            .by_code(postal_code_value.as_str());
+
+        if let (Some(state), Some(lga), Some(ward), Some(postal_code)) =
+            (state, lga, ward, postal_code)
+        {
+            let canonical = Address::new(
+                state.unit.clone(),
+                lga.unit.clone(),
+                ward.unit.clone(),
+                postal_code.clone(),
+            );
+            if canonical.is_valid() {
+                return Ok(AddressValidationResponse {
+                    valid: true,
+                    deprecated: canonical.deprecated_components(),
+                    canonical: Some(canonical),
+                    matches,
+                    suggestions: vec![],
+                });
+            }
+        }
+
+        let suggestions = generate_suggestions(
+            &self.state_repo,
+            &self.lga_repo,
+            &self.ward_repo,
+            &self.postal_code_repo,
+            request,
+        )
+        .await?;
+
+        Ok(AddressValidationResponse {
+            valid: false,
+            canonical: None,
+            matches,
+            deprecated: vec![],
+            suggestions,
+        })
+    }
+
+    async fn find_by_components(
+        &self,
+        state: &str,
+        lga: &str,
+        ward: &str,
+        postal_code: &str,
+    ) -> AppResult<Option<Address>> {
+        let (state, lga, ward) = self.find_components(state, lga, ward);
+        let postal_code_value = PostalCodeValue::new(postal_code.to_string())
+            .map_err(|e| AppError::invalid_param("postal_code", e))?;
+        let postal_code = self
+            .dataset
+            .postal_codes
+            .by_code(postal_code_value.as_str());
+
+        match (state, lga, ward, postal_code) {
+            (Some(state), Some(lga), Some(ward), Some(postal_code)) => {
+                let address = Address::new(
+                    state.unit.clone(),
+                    lga.unit.clone(),
+                    ward.unit.clone(),
+                    postal_code.clone(),
+                );
+                Ok(address.is_valid().then_some(address))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    async fn find_similar_addresses(
+        &self,
+        request: &AddressValidationRequest,
+        limit: u32,
+    ) -> AppResult<Vec<SimilarAddress>> {
+        // Every complete address under a matching state and LGA is ranked;
+        // ward and postal code similarity only affect the ordering
+        let state = NameQuery::new(&request.state);
+        let lga = NameQuery::new(&request.lga);
+        let ward = NameQuery::new(&request.ward);
+        let postal_code_trigrams = Trigrams::new(&request.postal_code);
+
+        let mut ranked = Vec::new();
+        for state_entry in self.dataset.states.entries() {
+            let Some(state_score) = state.filtered_score(state_entry) else {
+                continue;
+            };
+            for lga_entry in self.dataset.lgas.children(state_entry.unit.id) {
+                let Some(lga_score) = lga.filtered_score(lga_entry) else {
+                    continue;
+                };
+                for ward_entry in self.dataset.wards.children(lga_entry.unit.id) {
+                    let ward_score = ward.score(ward_entry);
+                    for postal_code in self.dataset.postal_codes.in_ward(ward_entry.unit.id) {
+                        let postal_code_score =
+                            if postal_code.postal_code.as_str() == request.postal_code {
+                                1.0
+                            } else {
+                                Trigrams::new(postal_code.postal_code.as_str())
+                                    .similarity(&postal_code_trigrams)
+                            };
+                        let score = 0.2 * state_score as f64
+                            + 0.3 * lga_score as f64
+                            + 0.3 * ward_score as f64
+                            + 0.2 * postal_code_score as f64;
+                        ranked.push((score, state_entry, lga_entry, ward_entry, postal_code));
+                    }
+                }
+            }
+        }
+
+        ranked.sort_by(|a, b| {
+            b.0.total_cmp(&a.0)
+                .then_with(|| a.1.unit.name.cmp(&b.1.unit.name))
+                .then_with(|| a.2.unit.name.cmp(&b.2.unit.name))
+                .then_with(|| a.3.unit.name.cmp(&b.3.unit.name))
+                .then_with(|| a.4.postal_code.as_str().cmp(b.4.postal_code.as_str()))
+        });
+
+        Ok(ranked
+            .into_iter()
+            .take(limit as usize)
+            .map(|(score, state, lga, ward, postal_code)| SimilarAddress {
+                address: Address::new(
+                    state.unit.clone(),
+                    lga.unit.clone(),
+                    ward.unit.clone(),
+                    postal_code.clone(),
+                ),
+                score,
+            })
+            .collect())
+    }
+}
+
+/// A component of a similarity search, compared with names in normalized
+/// form; an exact alias counts as an exact name
+struct NameQuery {
+    normalized: String,
+    trigrams: Trigrams,
+    contained: Like,
+}
+
+impl NameQuery {
+    fn new(input: &str) -> Self {
+        let normalized = normalized(input);
+        Self {
+            trigrams: Trigrams::new(&normalized),
+            contained: Like::new(&format!("%{}%", normalized)),
+            normalized,
+        }
+    }
+
+    fn score<T>(&self, entry: &Entry<T>) -> f32 {
+        if entry.name.normalized == self.normalized || self.names_alias(entry) {
+            1.0
+        } else {
+            entry.name.normalized_trigrams.similarity(&self.trigrams)
+        }
+    }
+
+    /// The score of a state or LGA that counts as a match: its name contains
+    /// the input, is similar to it, or has it as an alias
+    fn filtered_score<T>(&self, entry: &Entry<T>) -> Option<f32> {
+        let similarity = entry.name.normalized_trigrams.similarity(&self.trigrams);
+        let matched = self.contained.matches(&entry.name.normalized)
+            || similarity >= SIMILARITY_THRESHOLD
+            || self.names_alias(entry);
+        matched.then(|| self.score(entry))
+    }
+
+    fn names_alias<T>(&self, entry: &Entry<T>) -> bool {
+        entry
+            .aliases
+            .iter()
+            .any(|alias| alias.normalized == self.normalized)
+    }
+}
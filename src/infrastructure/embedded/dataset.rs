@@ -0,0 +1,841 @@
+//! The dataset served without PostgreSQL, held in memory
+//!
+//! It is read once from an offline bundle, gzip-compressed or not, and
+//! indexed for the embedded repositories: units by id and code, children by
+//! parent, postal codes in code order for prefix lookups and in a grid of
+//! cells for nearby searches. Names are kept in every form queries compare
+//! them in, trigrams included, so no query recomputes them.
+
+use std::collections::HashMap;
+use std::f64::consts::FRAC_PI_2;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use flate2::read::GzDecoder;
+use rusqlite::{Connection, OpenFlags, Row};
+use uuid::Uuid;
+
+use crate::domain::entities::bundle::BUNDLE_SCHEMA_VERSION;
+use crate::domain::entities::search::MatchMode;
+use crate::domain::entities::unit_status::UnitStatus;
+use crate::domain::entities::{Lga, PostalCode, State, Ward};
+use crate::domain::normalization::normalized;
+use crate::domain::phonetic::phonetic_key;
+use crate::domain::value_objects::{
+    Coordinates, LgaCode, PostalCode as PostalCodeValue, StateCode, WardCode,
+};
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::embedded::matching::{lower, Like, Trigrams, SIMILARITY_THRESHOLD};
+
+/// Bundle compiled into the binary with the `embedded-dataset` feature, from
+/// the file the `NIGERIA_GEO_DATASET` variable names at build time
+#[cfg(feature = "embedded-dataset")]
+pub const COMPILED_DATASET: Option<&[u8]> = Some(include_bytes!(env!("NIGERIA_GEO_DATASET")));
+
+/// Without the `embedded-dataset` feature, no bundle is compiled in
+#[cfg(not(feature = "embedded-dataset"))]
+pub const COMPILED_DATASET: Option<&[u8]> = None;
+
+/// Size of a cell of the nearby-search grid, in degrees
+const GRID_CELL_DEGREES: f64 = 0.1;
+
+/// Mean radius of the Earth, in kilometres, as the PostgreSQL query uses
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Postal codes a nearby search returns, as in PostgreSQL
+pub(crate) const NEARBY_LIMIT: usize = 50;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// A name in the forms queries compare it in
+#[derive(Debug, Clone)]
+pub(crate) struct NameForms {
+    pub(crate) name: String,
+    /// `lower(name)`, for `ILIKE` and case-insensitive equality
+    pub(crate) lower: String,
+    pub(crate) normalized: String,
+    pub(crate) phonetic: String,
+    pub(crate) trigrams: Trigrams,
+    pub(crate) normalized_trigrams: Trigrams,
+    pub(crate) phonetic_trigrams: Trigrams,
+}
+
+impl NameForms {
+    pub(crate) fn new(name: &str) -> Self {
+        let normalized = normalized(name);
+        let phonetic = phonetic_key(name);
+        Self {
+            name: name.to_string(),
+            lower: lower(name),
+            trigrams: Trigrams::new(name),
+            normalized_trigrams: Trigrams::new(&normalized),
+            phonetic_trigrams: Trigrams::new(&phonetic),
+            normalized,
+            phonetic,
+        }
+    }
+
+    /// The key a search of `mode` compares with the query's: the normalized
+    /// name, or the phonetic key
+    pub(crate) fn key(&self, mode: MatchMode) -> (&str, &Trigrams) {
+        match mode {
+            MatchMode::Standard => (&self.normalized, &self.normalized_trigrams),
+            MatchMode::Phonetic => (&self.phonetic, &self.phonetic_trigrams),
+        }
+    }
+
+    /// Whether `find_by_name` finds the unit through this name: the name
+    /// ignoring case, or the normalized name
+    fn names(&self, lower_input: &str, normalized_input: &str) -> bool {
+        self.lower == lower_input || self.normalized == normalized_input
+    }
+}
+
+/// A state, LGA or ward as the dataset holds it
+pub(crate) trait Unit: Clone {
+    fn id(&self) -> Uuid;
+    fn name(&self) -> &str;
+    fn code(&self) -> &str;
+    /// The enclosing unit; states have none
+    fn parent_id(&self) -> Option<Uuid>;
+}
+
+impl Unit for State {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    fn parent_id(&self) -> Option<Uuid> {
+        None
+    }
+}
+
+impl Unit for Lga {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    fn parent_id(&self) -> Option<Uuid> {
+        Some(self.state_id)
+    }
+}
+
+impl Unit for Ward {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn code(&self) -> &str {
+        self.code.as_str()
+    }
+
+    fn parent_id(&self) -> Option<Uuid> {
+        Some(self.lga_id)
+    }
+}
+
+/// A unit with its names
+#[derive(Debug, Clone)]
+pub(crate) struct Entry<T> {
+    pub(crate) unit: T,
+    pub(crate) name: NameForms,
+    /// `lower(code)`
+    pub(crate) code_lower: String,
+    /// The unit's aliases, in name order
+    pub(crate) aliases: Vec<NameForms>,
+}
+
+impl<T: Unit> Entry<T> {
+    /// Whether `find_by_name` finds the unit: by its name, its normalized
+    /// name, or an alias
+    fn is_named(&self, name: &str, lower_input: &str, normalized_input: &str) -> bool {
+        self.unit.name() == name
+            || self.name.normalized == normalized_input
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.names(lower_input, normalized_input))
+    }
+}
+
+/// The states, LGAs or wards of the dataset
+#[derive(Debug)]
+pub(crate) struct Units<T> {
+    /// Ordered by name, then id
+    entries: Vec<Entry<T>>,
+    by_id: HashMap<Uuid, usize>,
+    by_code: HashMap<String, usize>,
+    /// Positions of each parent's units, in name order
+    by_parent: HashMap<Uuid, Vec<usize>>,
+}
+
+impl<T: Unit> Units<T> {
+    fn new(mut units: Vec<T>, mut aliases: HashMap<Uuid, Vec<String>>) -> Self {
+        units.sort_by(|a, b| (a.name(), a.id()).cmp(&(b.name(), b.id())));
+
+        let entries: Vec<Entry<T>> = units
+            .into_iter()
+            .map(|unit| {
+                let mut names = aliases.remove(&unit.id()).unwrap_or_default();
+                names.sort();
+                Entry {
+                    name: NameForms::new(unit.name()),
+                    code_lower: lower(unit.code()),
+                    aliases: names.iter().map(|name| NameForms::new(name)).collect(),
+                    unit,
+                }
+            })
+            .collect();
+
+        let mut by_id = HashMap::with_capacity(entries.len());
+        let mut by_code = HashMap::with_capacity(entries.len());
+        let mut by_parent: HashMap<Uuid, Vec<usize>> = HashMap::new();
+        for (position, entry) in entries.iter().enumerate() {
+            by_id.insert(entry.unit.id(), position);
+            by_code.insert(entry.unit.code().to_string(), position);
+            if let Some(parent_id) = entry.unit.parent_id() {
+                by_parent.entry(parent_id).or_default().push(position);
+            }
+        }
+
+        Self {
+            entries,
+            by_id,
+            by_code,
+            by_parent,
+        }
+    }
+
+    /// Every unit, in name order
+    pub(crate) fn entries(&self) -> &[Entry<T>] {
+        &self.entries
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub(crate) fn get(&self, id: Uuid) -> Option<&Entry<T>> {
+        self.by_id.get(&id).map(|&position| &self.entries[position])
+    }
+
+    pub(crate) fn by_code(&self, code: &str) -> Option<&Entry<T>> {
+        self.by_code
+            .get(code)
+            .map(|&position| &self.entries[position])
+    }
+
+    /// Units within `parent_id`, in name order
+    pub(crate) fn children(
+        &self,
+        parent_id: Uuid,
+    ) -> impl DoubleEndedIterator<Item = &Entry<T>> + '_ {
+        self.by_parent
+            .get(&parent_id)
+            .into_iter()
+            .flatten()
+            .map(|&position| &self.entries[position])
+    }
+
+    pub(crate) fn count_children(&self, parent_id: Uuid) -> u64 {
+        self.by_parent.get(&parent_id).map_or(0, Vec::len) as u64
+    }
+
+    /// Units `search` finds for `query`, in name order: those whose name or
+    /// code contains it ignoring case, whose normalized name contains it
+    /// normalized, or with an alias that does either. As in PostgreSQL, `%`
+    /// and `_` in the query are wildcards
+    pub(crate) fn search(&self, query: &str) -> impl Iterator<Item = &Entry<T>> + '_ {
+        let pattern = Like::insensitive(&format!("%{}%", query));
+        let normalized_pattern = Like::new(&format!("%{}%", normalized(query)));
+        self.entries.iter().filter(move |entry| {
+            let named = |forms: &NameForms| {
+                pattern.matches(&forms.lower) || normalized_pattern.matches(&forms.normalized)
+            };
+            named(&entry.name)
+                || pattern.matches(&entry.code_lower)
+                || entry.aliases.iter().any(named)
+        })
+    }
+
+    /// Units `search_phonetic` finds for `query`, closest first: an exact
+    /// phonetic key, then keys containing the query's words, then similar
+    /// keys, whether the unit's own or an alias's
+    pub(crate) fn search_phonetic(&self, query: &str) -> Vec<&Entry<T>> {
+        let key = phonetic_key(query);
+        if key.is_empty() {
+            return Vec::new();
+        }
+        let trigrams = Trigrams::new(&key);
+        let words = Like::new(&format!("% {} %", key));
+        let score = |forms: &NameForms| -> Option<f32> {
+            if forms.phonetic == key {
+                Some(1.0)
+            } else if words.matches(&format!(" {} ", forms.phonetic)) {
+                Some(0.8)
+            } else {
+                let similarity = forms.phonetic_trigrams.similarity(&trigrams);
+                (similarity >= SIMILARITY_THRESHOLD).then_some(similarity)
+            }
+        };
+
+        let mut matches: Vec<(f32, &Entry<T>)> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let best = std::iter::once(&entry.name)
+                    .chain(&entry.aliases)
+                    .filter_map(score)
+                    .reduce(f32::max)?;
+                Some((best, entry))
+            })
+            .collect();
+        // Stable, so equal scores stay in name order
+        matches.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+        matches.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    /// The unit `find_by_name` returns for `name`
+    pub(crate) fn find_by_name(&self, name: &str) -> Option<&Entry<T>> {
+        find_by_name(self.entries.iter(), name)
+    }
+
+    /// The unit within `parent_id` that `find_by_name` would return if names
+    /// were unique within the parent
+    pub(crate) fn find_child_by_name(&self, parent_id: Uuid, name: &str) -> Option<&Entry<T>> {
+        find_by_name(self.children(parent_id), name)
+    }
+}
+
+/// The unit among `candidates`, taken in name order, found by `name`: exact
+/// names first, then normalized matches, then aliases
+fn find_by_name<'a, T: Unit + 'a>(
+    candidates: impl Iterator<Item = &'a Entry<T>>,
+    name: &str,
+) -> Option<&'a Entry<T>> {
+    let (lower_input, normalized_input) = (lower(name), normalized(name));
+    let mut best: Option<(&Entry<T>, (bool, bool))> = None;
+    for entry in candidates.filter(|entry| entry.is_named(name, &lower_input, &normalized_input)) {
+        let rank = (
+            entry.unit.name() == name,
+            entry.name.normalized == normalized_input,
+        );
+        if best.is_none_or(|(_, best_rank)| rank > best_rank) {
+            best = Some((entry, rank));
+        }
+    }
+    best.map(|(entry, _)| entry)
+}
+
+/// The postal codes of the dataset
+#[derive(Debug)]
+pub(crate) struct PostalCodes {
+    /// Ordered by code, then id
+    postal_codes: Vec<PostalCode>,
+    by_id: HashMap<Uuid, usize>,
+    /// Positions of each ward's postal codes, in code order
+    by_ward: HashMap<Uuid, Vec<usize>>,
+    /// Positions of the postal codes with coordinates, by grid cell
+    grid: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl PostalCodes {
+    fn new(mut postal_codes: Vec<PostalCode>) -> Self {
+        postal_codes
+            .sort_by(|a, b| (a.postal_code.as_str(), a.id).cmp(&(b.postal_code.as_str(), b.id)));
+
+        let mut by_id = HashMap::with_capacity(postal_codes.len());
+        let mut by_ward: HashMap<Uuid, Vec<usize>> = HashMap::new();
+        let mut grid: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for (position, postal_code) in postal_codes.iter().enumerate() {
+            by_id.insert(postal_code.id, position);
+            by_ward
+                .entry(postal_code.ward_id)
+                .or_default()
+                .push(position);
+            if let Some(coordinates) = &postal_code.coordinates {
+                grid.entry(cell(coordinates.latitude, coordinates.longitude))
+                    .or_default()
+                    .push(position);
+            }
+        }
+
+        Self {
+            postal_codes,
+            by_id,
+            by_ward,
+            grid,
+        }
+    }
+
+    /// Every postal code, in code order
+    pub(crate) fn all(&self) -> &[PostalCode] {
+        &self.postal_codes
+    }
+
+    pub(crate) fn get(&self, id: Uuid) -> Option<&PostalCode> {
+        self.by_id
+            .get(&id)
+            .map(|&position| &self.postal_codes[position])
+    }
+
+    /// Postal codes starting with `prefix`, in code order
+    pub(crate) fn with_prefix(&self, prefix: &str) -> &[PostalCode] {
+        let start = self
+            .postal_codes
+            .partition_point(|postal_code| postal_code.postal_code.as_str() < prefix);
+        let end = start
+            + self.postal_codes[start..].partition_point(|postal_code| {
+                postal_code.postal_code.as_str().starts_with(prefix)
+            });
+        &self.postal_codes[start..end]
+    }
+
+    pub(crate) fn by_code(&self, code: &str) -> Option<&PostalCode> {
+        self.with_prefix(code)
+            .iter()
+            .find(|postal_code| postal_code.postal_code.as_str() == code)
+    }
+
+    /// Postal codes within `ward_id`, in code order
+    pub(crate) fn in_ward(
+        &self,
+        ward_id: Uuid,
+    ) -> impl DoubleEndedIterator<Item = &PostalCode> + '_ {
+        self.by_ward
+            .get(&ward_id)
+            .into_iter()
+            .flatten()
+            .map(|&position| &self.postal_codes[position])
+    }
+
+    pub(crate) fn count_in_ward(&self, ward_id: Uuid) -> u64 {
+        self.by_ward.get(&ward_id).map_or(0, Vec::len) as u64
+    }
+
+    /// The nearest postal codes within `radius_km` of `coordinates`, closest
+    /// first, up to [`NEARBY_LIMIT`]
+    ///
+    /// Only the grid cells a circle of the radius can reach are visited; the
+    /// distance is then worked out as the PostgreSQL query does.
+    pub(crate) fn near(&self, coordinates: &Coordinates, radius_km: f64) -> Vec<&PostalCode> {
+        let (latitude, longitude) = (coordinates.latitude, coordinates.longitude);
+        // A little slack for the rounding of the law of cosines
+        let angle = (radius_km + 0.01).max(0.0) / EARTH_RADIUS_KM;
+
+        let min_lat = latitude - angle.to_degrees();
+        let max_lat = latitude + angle.to_degrees();
+        // Widest longitude the circle spans, at the latitude it touches
+        let lng_span = match angle.sin() / latitude.to_radians().cos() {
+            ratio if ratio < 1.0 && angle < FRAC_PI_2 && min_lat > -90.0 && max_lat < 90.0 => {
+                Some(ratio.asin().to_degrees())
+            }
+            _ => None,
+        };
+
+        let candidates: Vec<usize> = match lng_span {
+            Some(span) if span < 180.0 => {
+                let (low, high) = (
+                    cell(min_lat, longitude - span),
+                    cell(max_lat, longitude + span),
+                );
+                let cells = (high.0 - low.0 + 1) as usize * (high.1 - low.1 + 1) as usize;
+                if cells < self.grid.len() {
+                    (low.0..=high.0)
+                        .flat_map(|row| (low.1..=high.1).map(move |column| (row, column)))
+                        .filter_map(|key| self.grid.get(&key))
+                        .flatten()
+                        .copied()
+                        .collect()
+                } else {
+                    self.grid.values().flatten().copied().collect()
+                }
+            }
+            _ => self.grid.values().flatten().copied().collect(),
+        };
+
+        let mut nearby: Vec<(f64, &PostalCode)> = candidates
+            .into_iter()
+            .filter_map(|position| {
+                let postal_code = &self.postal_codes[position];
+                let point = postal_code.coordinates.as_ref()?;
+                let distance = distance_km(latitude, longitude, point.latitude, point.longitude);
+                (distance <= radius_km).then_some((distance, postal_code))
+            })
+            .collect();
+        nearby.sort_by(|(a, a_code), (b, b_code)| {
+            a.total_cmp(b)
+                .then_with(|| a_code.postal_code.as_str().cmp(b_code.postal_code.as_str()))
+        });
+        nearby
+            .into_iter()
+            .take(NEARBY_LIMIT)
+            .map(|(_, postal_code)| postal_code)
+            .collect()
+    }
+}
+
+/// Grid cell holding a point
+fn cell(latitude: f64, longitude: f64) -> (i32, i32) {
+    (
+        (latitude / GRID_CELL_DEGREES).floor() as i32,
+        (longitude / GRID_CELL_DEGREES).floor() as i32,
+    )
+}
+
+/// Great-circle distance by the spherical law of cosines, as the PostgreSQL
+/// query works it out
+fn distance_km(lat1: f64, lng1: f64, lat2: f64, lng2: f64) -> f64 {
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let cosine = lat1.cos() * lat2.cos() * (lng2.to_radians() - lng1.to_radians()).cos()
+        + lat1.sin() * lat2.sin();
+    EARTH_RADIUS_KM * cosine.clamp(-1.0, 1.0).acos()
+}
+
+/// Every active unit and alias of a bundle, indexed for the embedded
+/// repositories
+#[derive(Debug)]
+pub struct EmbeddedDataset {
+    version: String,
+    pub(crate) states: Units<State>,
+    pub(crate) lgas: Units<Lga>,
+    pub(crate) wards: Units<Ward>,
+    pub(crate) postal_codes: PostalCodes,
+}
+
+impl EmbeddedDataset {
+    /// The dataset to serve in place of PostgreSQL, if any: the bundle at
+    /// `path`, or else the one compiled in
+    pub fn configured(path: Option<&Path>) -> Option<AppResult<Self>> {
+        match (path, COMPILED_DATASET) {
+            (Some(path), _) => Some(Self::open(path)),
+            (None, Some(bytes)) => Some(Self::from_bytes(bytes)),
+            (None, None) => None,
+        }
+    }
+
+    /// Load the bundle at `path`, compressed or not
+    pub fn open(path: &Path) -> AppResult<Self> {
+        let mut file = File::open(path).map_err(|e| {
+            AppError::Internal(anyhow::anyhow!(
+                "Cannot open dataset bundle {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let mut magic = [0; 2];
+        let compressed = file.read_exact(&mut magic).is_ok() && magic == GZIP_MAGIC;
+        if compressed {
+            let database = TemporaryDatabase::new();
+            io::copy(
+                &mut GzDecoder::new(File::open(path)?),
+                &mut File::create(&database.0)?,
+            )?;
+            return Self::read(&database.0);
+        }
+
+        Self::read(path)
+    }
+
+    /// Load a bundle held in memory, compressed or not
+    pub fn from_bytes(bytes: &[u8]) -> AppResult<Self> {
+        let database = TemporaryDatabase::new();
+        let mut file = File::create(&database.0)?;
+        if bytes.starts_with(&GZIP_MAGIC) {
+            io::copy(&mut GzDecoder::new(bytes), &mut file)?;
+        } else {
+            io::copy(&mut &bytes[..], &mut file)?;
+        }
+        drop(file);
+
+        Self::read(&database.0)
+    }
+
+    /// Version of the bundle the dataset was loaded from
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Number of states, LGAs, wards and postal codes
+    pub fn counts(&self) -> (usize, usize, usize, usize) {
+        (
+            self.states.len(),
+            self.lgas.len(),
+            self.wards.len(),
+            self.postal_codes.all().len(),
+        )
+    }
+
+    /// Names of the units enclosing an LGA, nearest first
+    pub(crate) fn lga_path(&self, lga: &Lga) -> String {
+        self.states
+            .get(lga.state_id)
+            .map(|state| state.unit.name.clone())
+            .unwrap_or_default()
+    }
+
+    /// Names of the units enclosing a ward, nearest first
+    pub(crate) fn ward_path(&self, ward: &Ward) -> String {
+        match self.lgas.get(ward.lga_id) {
+            Some(lga) => format!("{}, {}", lga.unit.name, self.lga_path(&lga.unit)),
+            None => String::new(),
+        }
+    }
+
+    /// Names of the units enclosing a postal code, nearest first
+    pub(crate) fn postal_code_path(&self, postal_code: &PostalCode) -> String {
+        match self.wards.get(postal_code.ward_id) {
+            Some(ward) => format!("{}, {}", ward.unit.name, self.ward_path(&ward.unit)),
+            None => String::new(),
+        }
+    }
+
+    fn read(path: &Path) -> AppResult<Self> {
+        let connection = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+
+        let schema_version: u32 =
+            connection.pragma_query_value(None, "user_version", |row| row.get(0))?;
+        if schema_version != BUNDLE_SCHEMA_VERSION {
+            return Err(invalid(format!(
+                "bundle schema version {} is not {}",
+                schema_version, BUNDLE_SCHEMA_VERSION
+            )));
+        }
+        let version: String = connection.query_row(
+            "SELECT value FROM metadata WHERE key = 'version'",
+            [],
+            |row| row.get(0),
+        )?;
+
+        let mut aliases: HashMap<String, HashMap<Uuid, Vec<String>>> = HashMap::new();
+        for alias in query(
+            &connection,
+            "SELECT level, entity_id, name FROM aliases",
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            },
+        )? {
+            let (level, entity_id, name) = alias;
+            aliases
+                .entry(level)
+                .or_default()
+                .entry(uuid(&entity_id)?)
+                .or_default()
+                .push(name);
+        }
+        let mut aliases_of = |level: &str| aliases.remove(level).unwrap_or_default();
+
+        let states = query(
+            &connection,
+            "SELECT id, code, name, NULL, created_at, updated_at FROM states",
+            unit_row,
+        )?
+        .into_iter()
+        .map(|row| {
+            Ok(State {
+                id: uuid(&row.id)?,
+                name: row.name,
+                code: StateCode::new(row.code).map_err(|e| invalid(e.to_string()))?,
+                status: UnitStatus::Active,
+                successor_id: None,
+                created_at: timestamp(&row.created_at)?,
+                updated_at: timestamp(&row.updated_at)?,
+            })
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+        let states = Units::new(states, aliases_of("state"));
+
+        let lgas = query(
+            &connection,
+            "SELECT id, code, name, state_code, created_at, updated_at FROM lgas",
+            unit_row,
+        )?
+        .into_iter()
+        .map(|row| {
+            Ok(Lga {
+                id: uuid(&row.id)?,
+                state_id: parent_id(&states, row.parent_code.as_deref())?,
+                name: row.name,
+                code: LgaCode::new(row.code).map_err(|e| invalid(e.to_string()))?,
+                status: UnitStatus::Active,
+                successor_id: None,
+                created_at: timestamp(&row.created_at)?,
+                updated_at: timestamp(&row.updated_at)?,
+            })
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+        let lgas = Units::new(lgas, aliases_of("lga"));
+
+        let wards = query(
+            &connection,
+            "SELECT id, code, name, lga_code, created_at, updated_at FROM wards",
+            unit_row,
+        )?
+        .into_iter()
+        .map(|row| {
+            Ok(Ward {
+                id: uuid(&row.id)?,
+                lga_id: parent_id(&lgas, row.parent_code.as_deref())?,
+                name: row.name,
+                code: WardCode::new(row.code).map_err(|e| invalid(e.to_string()))?,
+                status: UnitStatus::Active,
+                successor_id: None,
+                created_at: timestamp(&row.created_at)?,
+                updated_at: timestamp(&row.updated_at)?,
+            })
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+        let wards = Units::new(wards, aliases_of("ward"));
+
+        let postal_codes = query(
+            &connection,
+            "SELECT id, postal_code, ward_code, lat, lng, urban, created_at, updated_at
+             FROM postal_codes",
+            |row| {
+                Ok(PostalCodeRow {
+                    id: row.get(0)?,
+                    postal_code: row.get(1)?,
+                    ward_code: row.get(2)?,
+                    lat: row.get(3)?,
+                    lng: row.get(4)?,
+                    urban: row.get(5)?,
+                    created_at: row.get(6)?,
+                    updated_at: row.get(7)?,
+                })
+            },
+        )?
+        .into_iter()
+        .map(|row| {
+            let coordinates = match (row.lat, row.lng) {
+                (Some(lat), Some(lng)) => Some(Coordinates::new(lat, lng)?),
+                _ => None,
+            };
+            Ok(PostalCode {
+                id: uuid(&row.id)?,
+                ward_id: parent_id(&wards, Some(&row.ward_code))?,
+                postal_code: PostalCodeValue::new(row.postal_code)
+                    .map_err(|e| invalid(e.to_string()))?,
+                coordinates,
+                urban: row.urban.unwrap_or(false),
+                status: UnitStatus::Active,
+                successor_id: None,
+                created_at: timestamp(&row.created_at)?,
+                updated_at: timestamp(&row.updated_at)?,
+            })
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+
+        Ok(Self {
+            version,
+            states,
+            lgas,
+            wards,
+            postal_codes: PostalCodes::new(postal_codes),
+        })
+    }
+}
+
+/// A state, LGA or ward row, naming its parent by code
+struct UnitRow {
+    id: String,
+    code: String,
+    name: String,
+    parent_code: Option<String>,
+    created_at: String,
+    updated_at: String,
+}
+
+fn unit_row(row: &Row<'_>) -> rusqlite::Result<UnitRow> {
+    Ok(UnitRow {
+        id: row.get(0)?,
+        code: row.get(1)?,
+        name: row.get(2)?,
+        parent_code: row.get(3)?,
+        created_at: row.get(4)?,
+        updated_at: row.get(5)?,
+    })
+}
+
+struct PostalCodeRow {
+    id: String,
+    postal_code: String,
+    ward_code: String,
+    lat: Option<f64>,
+    lng: Option<f64>,
+    urban: Option<bool>,
+    created_at: String,
+    updated_at: String,
+}
+
+fn query<T>(
+    connection: &Connection,
+    sql: &str,
+    map: impl FnMut(&Row<'_>) -> rusqlite::Result<T>,
+) -> AppResult<Vec<T>> {
+    let mut statement = connection.prepare(sql)?;
+    let rows = statement.query_map([], map)?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+fn parent_id<T: Unit>(parents: &Units<T>, code: Option<&str>) -> AppResult<Uuid> {
+    let code = code.unwrap_or_default();
+    parents
+        .by_code(code)
+        .map(|parent| parent.unit.id())
+        .ok_or_else(|| invalid(format!("no parent unit with code '{}'", code)))
+}
+
+fn uuid(value: &str) -> AppResult<Uuid> {
+    Uuid::parse_str(value).map_err(|e| invalid(format!("bad id {}: {}", value, e)))
+}
+
+fn timestamp(value: &str) -> AppResult<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|at| at.with_timezone(&Utc))
+        .map_err(|e| invalid(format!("bad timestamp {}: {}", value, e)))
+}
+
+fn invalid(detail: String) -> AppError {
+    AppError::Internal(anyhow::anyhow!("Invalid dataset bundle: {}", detail))
+}
+
+/// A decompressed copy of a bundle for SQLite to open, removed once read
+struct TemporaryDatabase(PathBuf);
+
+impl TemporaryDatabase {
+    fn new() -> Self {
+        Self(std::env::temp_dir().join(format!("nigeria-geo-dataset-{}.sqlite", Uuid::new_v4())))
+    }
+}
+
+impl Drop for TemporaryDatabase {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::lga::{CreateLgaRequest, Lga, UpdateLgaRequest};
+use crate::domain::entities::unit_status::UpdateStatusRequest;
+use crate::domain::repositories::lga_repository::LgaRepository;
+use crate::domain::value_objects::LgaCode;
+use crate::errors::AppResult;
+use crate::infrastructure::embedded::{page, read_only, EmbeddedDataset};
+
+/// Embedded, read-only implementation of LgaRepository
+#[derive(Clone)]
+pub struct EmbeddedLgaRepository {
+    dataset: Arc<EmbeddedDataset>,
+}
+
+impl EmbeddedLgaRepository {
+    pub fn new(dataset: Arc<EmbeddedDataset>) -> Self {
+        Self { dataset }
+    }
+}
+
+#[async_trait]
+impl LgaRepository for EmbeddedLgaRepository {
+    async fn create(&self, _request: &CreateLgaRequest, _state_id: Uuid) -> AppResult<Lga> {
+        read_only()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Lga>> {
+        Ok(self.dataset.lgas.get(id).map(|entry| entry.unit.clone()))
+    }
+
+    async fn find_by_name(&self, name: &str) -> AppResult<Option<Lga>> {
+        Ok(self
+            .dataset
+            .lgas
+            .find_by_name(name)
+            .map(|entry| entry.unit.clone()))
+    }
+
+    async fn find_by_code(&self, code: &LgaCode) -> AppResult<Option<Lga>> {
+        Ok(self
+            .dataset
+            .lgas
+            .by_code(code.as_str())
+            .map(|entry| entry.unit.clone()))
+    }
+
+    async fn find_by_state_id(
+        &self,
+        state_id: Uuid,
+        page_number: u32,
+        limit: u32,
+    ) -> AppResult<Vec<Lga>> {
+        let lgas = self
+            .dataset
+            .lgas
+            .children(state_id)
+            .map(|entry| &entry.unit);
+        Ok(page(lgas, page_number, limit))
+    }
+
+    async fn find_by_state_ids(&self, state_ids: &[Uuid]) -> AppResult<Vec<Lga>> {
+        Ok(self
+            .dataset
+            .lgas
+            .entries()
+            .iter()
+            .filter(|entry| state_ids.contains(&entry.unit.state_id))
+            .map(|entry| entry.unit.clone())
+            .collect())
+    }
+
+    async fn update(&self, _id: Uuid, _request: &UpdateLgaRequest) -> AppResult<Lga> {
+        read_only()
+    }
+
+    async fn delete(&self, _id: Uuid) -> AppResult<()> {
+        read_only()
+    }
+
+    async fn set_status(&self, _id: Uuid, _request: &UpdateStatusRequest) -> AppResult<Lga> {
+        read_only()
+    }
+
+    async fn count_by_state(&self, state_id: Uuid) -> AppResult<u64> {
+        Ok(self.dataset.lgas.count_children(state_id))
+    }
+
+    async fn search(&self, query: &str, page_number: u32, limit: u32) -> AppResult<Vec<Lga>> {
+        let lgas = self.dataset.lgas.search(query).map(|entry| &entry.unit);
+        Ok(page(lgas, page_number, limit))
+    }
+
+    async fn search_phonetic(
+        &self,
+        query: &str,
+        page_number: u32,
+        limit: u32,
+    ) -> AppResult<Vec<Lga>> {
+        let lgas = self.dataset.lgas.search_phonetic(query);
+        Ok(page(
+            lgas.into_iter().map(|entry| &entry.unit),
+            page_number,
+            limit,
+        ))
+    }
+}
@@ -0,0 +1,170 @@
+//! PostgreSQL text matching, reproduced for the embedded dataset
+//!
+//! `LIKE` patterns and pg_trgm similarity behave as they do in the database,
+//! so the embedded repositories find and rank the same rows the PostgreSQL
+//! ones do. The database uses the C locale, where only ASCII letters change
+//! case and only ASCII letters and digits make up words.
+
+/// Trigram similarity at or above which pg_trgm's `%` operator matches, its
+/// default `pg_trgm.similarity_threshold`
+pub(crate) const SIMILARITY_THRESHOLD: f32 = 0.3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    /// `%`: any run of characters, possibly empty
+    Any,
+    /// `_`: exactly one character
+    One,
+    Char(char),
+}
+
+/// PostgreSQL's `lower`
+pub(crate) fn lower(text: &str) -> String {
+    text.to_ascii_lowercase()
+}
+
+/// A compiled `LIKE` pattern, with `\` escaping the character after it
+#[derive(Debug, Clone)]
+pub(crate) struct Like {
+    tokens: Vec<Token>,
+}
+
+impl Like {
+    pub(crate) fn new(pattern: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            tokens.push(match c {
+                '%' => Token::Any,
+                '_' => Token::One,
+                '\\' => Token::Char(chars.next().unwrap_or('\\')),
+                c => Token::Char(c),
+            });
+        }
+        Self { tokens }
+    }
+
+    /// Pattern for `ILIKE`, matched against text passed through [`lower`]
+    pub(crate) fn insensitive(pattern: &str) -> Self {
+        Self::new(&lower(pattern))
+    }
+
+    pub(crate) fn matches(&self, text: &str) -> bool {
+        let text: Vec<char> = text.chars().collect();
+        let (mut t, mut p) = (0, 0);
+        // Where to resume after the last `%`: the token after it and the
+        // text position it has swallowed up to
+        let mut resume: Option<(usize, usize)> = None;
+
+        while t < text.len() {
+            match self.tokens.get(p) {
+                Some(Token::Any) => {
+                    p += 1;
+                    resume = Some((p, t));
+                }
+                Some(Token::One) => {
+                    p += 1;
+                    t += 1;
+                }
+                Some(Token::Char(c)) if *c == text[t] => {
+                    p += 1;
+                    t += 1;
+                }
+                _ => match resume {
+                    Some((after, swallowed)) => {
+                        p = after;
+                        t = swallowed + 1;
+                        resume = Some((after, swallowed + 1));
+                    }
+                    None => return false,
+                },
+            }
+        }
+
+        self.tokens[p..].iter().all(|token| *token == Token::Any)
+    }
+}
+
+/// The distinct trigrams of a string, as pg_trgm extracts them
+///
+/// The string is lowercased and split into words of letters and digits; each
+/// word is padded with two spaces before and one after, and every run of
+/// three characters is a trigram.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Trigrams(Vec<[char; 3]>);
+
+impl Trigrams {
+    pub(crate) fn new(text: &str) -> Self {
+        let mut trigrams = Vec::new();
+        for word in text
+            .to_ascii_lowercase()
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+        {
+            let padded: Vec<char> = "  "
+                .chars()
+                .chain(word.chars())
+                .chain(std::iter::once(' '))
+                .collect();
+            trigrams.extend(padded.windows(3).map(|w| [w[0], w[1], w[2]]));
+        }
+        trigrams.sort_unstable();
+        trigrams.dedup();
+        Self(trigrams)
+    }
+
+    /// pg_trgm's `similarity`: shared trigrams over all distinct trigrams,
+    /// and 0 when either side has none
+    pub(crate) fn similarity(&self, other: &Trigrams) -> f32 {
+        if self.0.is_empty() || other.0.is_empty() {
+            return 0.0;
+        }
+
+        let (mut i, mut j, mut shared) = (0, 0, 0);
+        while i < self.0.len() && j < other.0.len() {
+            match self.0[i].cmp(&other.0[j]) {
+                std::cmp::Ordering::Less => i += 1,
+                std::cmp::Ordering::Greater => j += 1,
+                std::cmp::Ordering::Equal => {
+                    shared += 1;
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+
+        shared as f32 / (self.0.len() + other.0.len() - shared) as f32
+    }
+
+    /// pg_trgm's `%` operator
+    pub(crate) fn is_similar(&self, other: &Trigrams) -> bool {
+        self.similarity(other) >= SIMILARITY_THRESHOLD
+    }
+}
+
+/// `round(score::numeric, 4)`: PostgreSQL converts a `float8` to `numeric`
+/// through its 15 significant digits, then rounds half away from zero
+pub(crate) fn round_rank(score: f64) -> f64 {
+    if !score.is_finite() || score == 0.0 {
+        return score;
+    }
+
+    // d.dddddddddddddde±x, the 15 digits PostgreSQL keeps
+    let formatted = format!("{:.14e}", score.abs());
+    let (mantissa, exponent) = formatted.split_once('e').unwrap_or((&formatted, "0"));
+    let digits: u128 = mantissa.replace('.', "").parse().unwrap_or(0);
+    let exponent: i32 = exponent.parse().unwrap_or(0);
+
+    // The digits are the score scaled by 10^(14 - exponent); rescale to 10^4
+    let shift = exponent - 10;
+    let scaled = if shift >= 0 {
+        digits * 10u128.pow(shift as u32)
+    } else if shift > -30 {
+        let divisor = 10u128.pow((-shift) as u32);
+        (digits + divisor / 2) / divisor
+    } else {
+        0
+    };
+
+    (scaled as f64 / 10_000.0).copysign(score)
+}
@@ -0,0 +1,88 @@
+//! Read-only repositories over a dataset held in memory
+//!
+//! With a bundle configured, the API serves lookups, search, nearby search
+//! and address validation without PostgreSQL. The repositories answer as the
+//! PostgreSQL ones do, down to ordering, paging cursors and scores, but hold
+//! only the active units a bundle carries, and refuse writes.
+
+pub mod address_repository;
+pub mod dataset;
+pub mod lga_repository;
+pub(crate) mod matching;
+pub mod postal_code_repository;
+pub mod search_repository;
+pub mod state_repository;
+pub mod ward_repository;
+
+#[cfg(test)]
+mod tests;
+
+pub use address_repository::EmbeddedAddressRepository;
+pub use dataset::EmbeddedDataset;
+pub use lga_repository::EmbeddedLgaRepository;
+pub use postal_code_repository::EmbeddedPostalCodeRepository;
+pub use search_repository::EmbeddedSearchRepository;
+pub use state_repository::EmbeddedStateRepository;
+pub use ward_repository::EmbeddedWardRepository;
+
+use crate::domain::pagination::{Direction, KeysetPage, PageRequest};
+use crate::errors::{AppError, AppResult};
+
+/// Error for any write to the embedded dataset
+pub(crate) fn read_only<T>() -> AppResult<T> {
+    Err(AppError::Conflict(
+        "The embedded dataset is read-only".to_string(),
+    ))
+}
+
+/// The `page`th run of `limit` items, as `LIMIT` and `OFFSET` read it
+pub(crate) fn page<'a, T: Clone + 'a>(
+    items: impl Iterator<Item = &'a T>,
+    page: u32,
+    limit: u32,
+) -> Vec<T> {
+    items
+        .skip(page.saturating_sub(1).saturating_mul(limit) as usize)
+        .take(limit as usize)
+        .cloned()
+        .collect()
+}
+
+/// One page of a listing held in ascending `key` order, read for `request`
+/// as the keyset SQL reads it: rows beyond the cursor's `boundary` key, in
+/// reading direction
+pub(crate) fn keyset_page<'a, T: Clone + 'a, K: Ord>(
+    sorted: impl DoubleEndedIterator<Item = &'a T>,
+    request: &PageRequest,
+    listing: &str,
+    boundary: Option<K>,
+    key: impl Fn(&T) -> K,
+    cursor_key: impl Fn(&T) -> Vec<String>,
+) -> KeysetPage<T> {
+    let (offset, fetch) = (request.offset() as usize, request.fetch_limit() as usize);
+    let rows: Vec<T> = match request.direction() {
+        Direction::After => sorted
+            .filter(|row| {
+                boundary
+                    .as_ref()
+                    .is_none_or(|boundary| key(row) > *boundary)
+            })
+            .skip(offset)
+            .take(fetch)
+            .cloned()
+            .collect(),
+        Direction::Before => sorted
+            .rev()
+            .filter(|row| {
+                boundary
+                    .as_ref()
+                    .is_none_or(|boundary| key(row) < *boundary)
+            })
+            .skip(offset)
+            .take(fetch)
+            .cloned()
+            .collect(),
+    };
+
+    KeysetPage::from_rows(rows, request, listing, cursor_key)
+}
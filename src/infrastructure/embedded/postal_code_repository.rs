@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::postal_code::{
+    CreatePostalCodeRequest, PostalCode, UpdatePostalCodeRequest,
+};
+use crate::domain::entities::unit_status::UpdateStatusRequest;
+use crate::domain::pagination::{scoped, KeysetPage, PageRequest};
+use crate::domain::repositories::postal_code_repository::PostalCodeRepository;
+use crate::domain::value_objects::{Coordinates, PostalCode as PostalCodeValue};
+use crate::errors::AppResult;
+use crate::infrastructure::embedded::matching::{lower, Like};
+use crate::infrastructure::embedded::{keyset_page, page, read_only, EmbeddedDataset};
+use crate::infrastructure::repositories::keyset::{cursor_key, key_value};
+use crate::infrastructure::repositories::postal_code_repository_impl::POSTAL_CODES_BY_WARD;
+
+/// Embedded, read-only implementation of PostalCodeRepository
+#[derive(Clone)]
+pub struct EmbeddedPostalCodeRepository {
+    dataset: Arc<EmbeddedDataset>,
+}
+
+impl EmbeddedPostalCodeRepository {
+    pub fn new(dataset: Arc<EmbeddedDataset>) -> Self {
+        Self { dataset }
+    }
+}
+
+#[async_trait]
+impl PostalCodeRepository for EmbeddedPostalCodeRepository {
+    async fn create(
+        &self,
+        _request: &CreatePostalCodeRequest,
+        _ward_id: Uuid,
+    ) -> AppResult<PostalCode> {
+        read_only()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<PostalCode>> {
+        Ok(self.dataset.postal_codes.get(id).cloned())
+    }
+
+    async fn find_by_code(&self, code: &PostalCodeValue) -> AppResult<Option<PostalCode>> {
+        Ok(self.dataset.postal_codes.by_code(code.as_str()).cloned())
+    }
+
+    async fn find_by_ward_id(
+        &self,
+        ward_id: Uuid,
+        request: &PageRequest,
+    ) -> AppResult<KeysetPage<PostalCode>> {
+        let listing = scoped(POSTAL_CODES_BY_WARD, ward_id);
+        let boundary = match cursor_key::<2>(request, &listing)? {
+            Some([code, id]) => Some((code.clone(), key_value::<Uuid>(id)?)),
+            None => None,
+        };
+
+        // A ward's postal codes are held in (code, id) order, the listing's
+        // sort key
+        Ok(keyset_page(
+            self.dataset.postal_codes.in_ward(ward_id),
+            request,
+            &listing,
+            boundary,
+            |postal_code| (postal_code.postal_code.to_string(), postal_code.id),
+            |postal_code| {
+                vec![
+                    postal_code.postal_code.to_string(),
+                    postal_code.id.to_string(),
+                ]
+            },
+        ))
+    }
+
+    async fn find_by_ward_ids(&self, ward_ids: &[Uuid]) -> AppResult<Vec<PostalCode>> {
+        Ok(self
+            .dataset
+            .postal_codes
+            .all()
+            .iter()
+            .filter(|postal_code| ward_ids.contains(&postal_code.ward_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_near_coordinates(
+        &self,
+        coordinates: &Coordinates,
+        radius_km: f64,
+    ) -> AppResult<Vec<PostalCode>> {
+        Ok(self
+            .dataset
+            .postal_codes
+            .near(coordinates, radius_km)
+            .into_iter()
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, _id: Uuid, _request: &UpdatePostalCodeRequest) -> AppResult<PostalCode> {
+        read_only()
+    }
+
+    async fn delete(&self, _id: Uuid) -> AppResult<()> {
+        read_only()
+    }
+
+    async fn set_status(&self, _id: Uuid, _request: &UpdateStatusRequest) -> AppResult<PostalCode> {
+        read_only()
+    }
+
+    async fn count_by_ward(&self, ward_id: Uuid) -> AppResult<u64> {
+        Ok(self.dataset.postal_codes.count_in_ward(ward_id))
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        page_number: u32,
+        limit: u32,
+    ) -> AppResult<Vec<PostalCode>> {
+        let pattern = Like::insensitive(&format!("%{}%", query));
+        let postal_codes = self
+            .dataset
+            .postal_codes
+            .all()
+            .iter()
+            .filter(|postal_code| pattern.matches(&lower(postal_code.postal_code.as_str())));
+        Ok(page(postal_codes, page_number, limit))
+    }
+}
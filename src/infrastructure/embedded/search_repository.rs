@@ -0,0 +1,390 @@
+use std::cmp::Reverse;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::search::{
+    AutocompleteEntry, MatchMode, SearchCounts, SearchEntityType, SearchHit,
+};
+use crate::domain::normalization::{normalized, rules_needed_within};
+use crate::domain::pagination::{KeysetPage, PageRequest};
+use crate::domain::phonetic::phonetic_key;
+use crate::domain::repositories::search_repository::SearchRepository;
+use crate::errors::AppResult;
+use crate::infrastructure::embedded::dataset::{Entry, NameForms, Unit, Units};
+use crate::infrastructure::embedded::matching::{lower, round_rank, Like, Trigrams};
+use crate::infrastructure::embedded::{keyset_page, EmbeddedDataset};
+use crate::infrastructure::repositories::keyset::{cursor_key, key_value};
+use crate::infrastructure::repositories::search_repository_impl::{escape_like, search_listing};
+
+/// Embedded implementation of SearchRepository
+///
+/// Hits are found, scored and ordered as the PostgreSQL search does, see
+/// [`crate::infrastructure::repositories::search_repository_impl`].
+pub struct EmbeddedSearchRepository {
+    dataset: Arc<EmbeddedDataset>,
+}
+
+impl EmbeddedSearchRepository {
+    pub fn new(dataset: Arc<EmbeddedDataset>) -> Self {
+        Self { dataset }
+    }
+
+    /// Every hit for the query, best match first, with scores rounded as
+    /// cursors carry them and normalization left to fill in
+    fn hits(&self, term: &str, mode: MatchMode) -> Vec<SearchHit> {
+        let terms = Terms::new(term, mode);
+        let dataset = &self.dataset;
+
+        let mut hits = Vec::new();
+        entity_hits(
+            &mut hits,
+            &terms,
+            &dataset.states,
+            SearchEntityType::State,
+            1.0,
+            |_| None,
+        );
+        entity_hits(
+            &mut hits,
+            &terms,
+            &dataset.lgas,
+            SearchEntityType::Lga,
+            0.9,
+            |lga| Some(dataset.lga_path(lga)),
+        );
+        entity_hits(
+            &mut hits,
+            &terms,
+            &dataset.wards,
+            SearchEntityType::Ward,
+            0.8,
+            |ward| Some(dataset.ward_path(ward)),
+        );
+        hits.extend(
+            dataset
+                .postal_codes
+                .with_prefix(term)
+                .iter()
+                .map(|postal_code| {
+                    let exact = if postal_code.postal_code.as_str() == term {
+                        1.0
+                    } else {
+                        0.8
+                    };
+                    SearchHit {
+                        entity_type: SearchEntityType::PostalCode,
+                        id: postal_code.id,
+                        name: postal_code.postal_code.to_string(),
+                        code: postal_code.postal_code.to_string(),
+                        parent_path: Some(dataset.postal_code_path(postal_code)),
+                        matched_alias: None,
+                        normalization: vec![],
+                        score: round_rank(exact * 0.8),
+                    }
+                }),
+        );
+
+        hits.sort_by_cached_key(rank_key);
+        hits
+    }
+}
+
+/// A hit's place in the ranking: rank descending, then level, name and id
+fn rank_key(hit: &SearchHit) -> (Reverse<i64>, i32, String, Uuid) {
+    (
+        Reverse(rank_units(hit.score)),
+        hit.entity_type.level(),
+        hit.name.clone(),
+        hit.id,
+    )
+}
+
+/// A rank, rounded to four places, as a whole number of ten-thousandths
+fn rank_units(rank: f64) -> i64 {
+    (rank * 10_000.0).round() as i64
+}
+
+/// The query in the forms names are compared with it
+struct Terms {
+    term: String,
+    lower: String,
+    trigrams: Trigrams,
+    /// `ILIKE` patterns for names containing, and starting with, the query
+    substring: Like,
+    prefix: Like,
+    /// The query's key: its normalized form, or its phonetic key
+    key: String,
+    key_trigrams: Trigrams,
+    key_substring: Like,
+    key_prefix: Like,
+    mode: MatchMode,
+}
+
+impl Terms {
+    fn new(term: &str, mode: MatchMode) -> Self {
+        let escaped = escape_like(term);
+        let key = match mode {
+            MatchMode::Standard => normalized(term),
+            MatchMode::Phonetic => phonetic_key(term),
+        };
+
+        Self {
+            term: term.to_string(),
+            lower: lower(term),
+            trigrams: Trigrams::new(term),
+            substring: Like::insensitive(&format!("%{}%", escaped)),
+            prefix: Like::insensitive(&format!("{}%", escaped)),
+            key_trigrams: Trigrams::new(&key),
+            key_substring: Like::new(&format!("%{}%", key)),
+            key_prefix: Like::new(&format!("{}%", key)),
+            key,
+            mode,
+        }
+    }
+
+    /// Whether a name/code pair is a hit at all; `%` is the pg_trgm
+    /// similarity threshold
+    fn is_hit(&self, name: &NameForms, code: &str) -> bool {
+        let (key, key_trigrams) = name.key(self.mode);
+
+        self.substring.matches(&name.lower)
+            || self.prefix.matches(&lower(code))
+            || name.trigrams.is_similar(&self.trigrams)
+            || (!self.key.is_empty()
+                && (self.key_substring.matches(key) || key_trigrams.is_similar(&self.key_trigrams)))
+    }
+
+    /// Relevance of a name/code pair to the query, before the entity-level
+    /// weight: exact 1.0, prefix 0.8, substring 0.6, or 0.7 × trigram
+    /// similarity of the name or its key, whichever is highest
+    fn score(&self, name: &NameForms, code: &str) -> f64 {
+        let (key, key_trigrams) = name.key(self.mode);
+
+        let literal =
+            if name.lower == self.lower || key == self.key || code.eq_ignore_ascii_case(&self.term)
+            {
+                1.0
+            } else if self.prefix.matches(&name.lower)
+                || self.key_prefix.matches(key)
+                || self.prefix.matches(&lower(code))
+            {
+                0.8
+            } else if self.substring.matches(&name.lower) || self.key_substring.matches(key) {
+                0.6
+            } else {
+                0.0
+            };
+
+        f64::max(
+            literal,
+            0.7 * name.trigrams.similarity(&self.trigrams) as f64,
+        )
+        .max(0.7 * key_trigrams.similarity(&self.key_trigrams) as f64)
+    }
+}
+
+/// Hits among one level's units: each takes the better of its name's score
+/// and its best alias's, and reports the alias when that matched better
+fn entity_hits<T: Unit>(
+    hits: &mut Vec<SearchHit>,
+    terms: &Terms,
+    units: &Units<T>,
+    entity_type: SearchEntityType,
+    weight: f64,
+    parent_path: impl Fn(&T) -> Option<String>,
+) {
+    for entry in units.entries() {
+        let alias_hit = best_alias(terms, entry);
+        if alias_hit.is_none() && !terms.is_hit(&entry.name, entry.unit.code()) {
+            continue;
+        }
+
+        let name_score = terms.score(&entry.name, entry.unit.code());
+        let (alias_score, alias) = alias_hit.unzip();
+        let matched_alias = match alias_score {
+            Some(alias_score) if alias_score > name_score => alias.map(|alias| alias.name.clone()),
+            _ => None,
+        };
+
+        hits.push(SearchHit {
+            entity_type,
+            id: entry.unit.id(),
+            name: entry.unit.name().to_string(),
+            code: entry.unit.code().to_string(),
+            parent_path: parent_path(&entry.unit),
+            matched_alias,
+            normalization: vec![],
+            score: round_rank(name_score.max(alias_score.unwrap_or(0.0)) * weight),
+        });
+    }
+}
+
+/// The best-scoring alias that is a hit, the first by name among equals; an
+/// alias is its own code
+fn best_alias<'a, T>(terms: &Terms, entry: &'a Entry<T>) -> Option<(f64, &'a NameForms)> {
+    let mut best: Option<(f64, &NameForms)> = None;
+    for alias in entry
+        .aliases
+        .iter()
+        .filter(|alias| terms.is_hit(alias, &alias.name))
+    {
+        let score = terms.score(alias, &alias.name);
+        if best.is_none_or(|(best_score, _)| score > best_score) {
+            best = Some((score, alias));
+        }
+    }
+    best
+}
+
+#[async_trait]
+impl SearchRepository for EmbeddedSearchRepository {
+    async fn search(
+        &self,
+        query: &str,
+        mode: MatchMode,
+        request: &PageRequest,
+    ) -> AppResult<KeysetPage<SearchHit>> {
+        let term = query.trim();
+        let listing = search_listing(term, mode);
+        let boundary = match cursor_key::<4>(request, &listing)? {
+            Some([score, level, name, id]) => Some((
+                Reverse(rank_units(round_rank(key_value::<f64>(score)?))),
+                key_value::<i32>(level)?,
+                name.clone(),
+                key_value::<Uuid>(id)?,
+            )),
+            None => None,
+        };
+
+        let hits = self.hits(term, mode);
+        let mut page = keyset_page(
+            hits.iter(),
+            request,
+            &listing,
+            boundary,
+            rank_key,
+            |hit: &SearchHit| {
+                vec![
+                    format!("{:.4}", hit.score),
+                    hit.entity_type.level().to_string(),
+                    hit.name.clone(),
+                    hit.id.to_string(),
+                ]
+            },
+        );
+        for hit in &mut page.items {
+            let matched = hit.matched_alias.as_deref().unwrap_or(&hit.name);
+            hit.normalization = rules_needed_within(term, matched).unwrap_or_default();
+        }
+
+        Ok(page)
+    }
+
+    async fn count(&self, query: &str, mode: MatchMode) -> AppResult<SearchCounts> {
+        let mut counts = SearchCounts::default();
+        for hit in self.hits(query.trim(), mode) {
+            match hit.entity_type {
+                SearchEntityType::State => counts.states += 1,
+                SearchEntityType::Lga => counts.lgas += 1,
+                SearchEntityType::Ward => counts.wards += 1,
+                SearchEntityType::PostalCode => counts.postal_codes += 1,
+            }
+        }
+
+        Ok(counts)
+    }
+
+    async fn autocomplete_entries(&self) -> AppResult<Vec<AutocompleteEntry>> {
+        let dataset = &self.dataset;
+        let aliases = |entry_aliases: &[NameForms]| {
+            entry_aliases
+                .iter()
+                .map(|alias| alias.name.clone())
+                .collect::<Vec<_>>()
+        };
+        let state_code = |state_id: Uuid| {
+            dataset
+                .states
+                .get(state_id)
+                .map(|state| state.unit.code.to_string())
+                .unwrap_or_default()
+        };
+
+        let mut entries = Vec::new();
+        entries.extend(
+            dataset
+                .states
+                .entries()
+                .iter()
+                .map(|entry| AutocompleteEntry {
+                    entity_type: SearchEntityType::State,
+                    id: entry.unit.id,
+                    name: entry.unit.name.clone(),
+                    code: entry.unit.code.to_string(),
+                    state_code: entry.unit.code.to_string(),
+                    lga_code: None,
+                    parent_path: None,
+                    aliases: aliases(&entry.aliases),
+                }),
+        );
+        entries.extend(
+            dataset
+                .lgas
+                .entries()
+                .iter()
+                .map(|entry| AutocompleteEntry {
+                    entity_type: SearchEntityType::Lga,
+                    id: entry.unit.id,
+                    name: entry.unit.name.clone(),
+                    code: entry.unit.code.to_string(),
+                    state_code: state_code(entry.unit.state_id),
+                    lga_code: Some(entry.unit.code.to_string()),
+                    parent_path: Some(dataset.lga_path(&entry.unit)),
+                    aliases: aliases(&entry.aliases),
+                }),
+        );
+        for entry in dataset.wards.entries() {
+            let Some(lga) = dataset.lgas.get(entry.unit.lga_id) else {
+                continue;
+            };
+            entries.push(AutocompleteEntry {
+                entity_type: SearchEntityType::Ward,
+                id: entry.unit.id,
+                name: entry.unit.name.clone(),
+                code: entry.unit.code.to_string(),
+                state_code: state_code(lga.unit.state_id),
+                lga_code: Some(lga.unit.code.to_string()),
+                parent_path: Some(dataset.ward_path(&entry.unit)),
+                aliases: aliases(&entry.aliases),
+            });
+        }
+        for postal_code in dataset.postal_codes.all() {
+            let Some(lga) = dataset
+                .wards
+                .get(postal_code.ward_id)
+                .and_then(|ward| dataset.lgas.get(ward.unit.lga_id))
+            else {
+                continue;
+            };
+            entries.push(AutocompleteEntry {
+                entity_type: SearchEntityType::PostalCode,
+                id: postal_code.id,
+                name: postal_code.postal_code.to_string(),
+                code: postal_code.postal_code.to_string(),
+                state_code: state_code(lga.unit.state_id),
+                lga_code: Some(lga.unit.code.to_string()),
+                parent_path: Some(dataset.postal_code_path(postal_code)),
+                aliases: vec![],
+            });
+        }
+
+        Ok(entries)
+    }
+
+    async fn data_fingerprint(&self) -> AppResult<String> {
+        // The dataset never changes while the process runs
+        Ok(format!("bundle:{}", self.dataset.version()))
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::state::{CreateStateRequest, State, UpdateStateRequest};
+use crate::domain::entities::unit_status::UpdateStatusRequest;
+use crate::domain::repositories::state_repository::StateRepository;
+use crate::domain::value_objects::StateCode;
+use crate::errors::AppResult;
+use crate::infrastructure::embedded::{page, read_only, EmbeddedDataset};
+
+/// Embedded, read-only implementation of StateRepository
+#[derive(Clone)]
+pub struct EmbeddedStateRepository {
+    dataset: Arc<EmbeddedDataset>,
+}
+
+impl EmbeddedStateRepository {
+    pub fn new(dataset: Arc<EmbeddedDataset>) -> Self {
+        Self { dataset }
+    }
+}
+
+#[async_trait]
+impl StateRepository for EmbeddedStateRepository {
+    async fn create(&self, _request: &CreateStateRequest) -> AppResult<State> {
+        read_only()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<State>> {
+        Ok(self.dataset.states.get(id).map(|entry| entry.unit.clone()))
+    }
+
+    async fn find_by_name(&self, name: &str) -> AppResult<Option<State>> {
+        Ok(self
+            .dataset
+            .states
+            .find_by_name(name)
+            .map(|entry| entry.unit.clone()))
+    }
+
+    async fn find_by_code(&self, code: &StateCode) -> AppResult<Option<State>> {
+        Ok(self
+            .dataset
+            .states
+            .by_code(code.as_str())
+            .map(|entry| entry.unit.clone()))
+    }
+
+    async fn update(&self, _id: Uuid, _request: &UpdateStateRequest) -> AppResult<State> {
+        read_only()
+    }
+
+    async fn delete(&self, _id: Uuid) -> AppResult<()> {
+        read_only()
+    }
+
+    async fn set_status(&self, _id: Uuid, _request: &UpdateStatusRequest) -> AppResult<State> {
+        read_only()
+    }
+
+    async fn list(&self, page_number: u32, limit: u32) -> AppResult<Vec<State>> {
+        let states = self
+            .dataset
+            .states
+            .entries()
+            .iter()
+            .map(|entry| &entry.unit);
+        Ok(page(states, page_number, limit))
+    }
+
+    async fn list_all(&self) -> AppResult<Vec<State>> {
+        Ok(self
+            .dataset
+            .states
+            .entries()
+            .iter()
+            .map(|entry| entry.unit.clone())
+            .collect())
+    }
+
+    async fn count(&self) -> AppResult<u64> {
+        Ok(self.dataset.states.len() as u64)
+    }
+
+    async fn search(&self, query: &str, page_number: u32, limit: u32) -> AppResult<Vec<State>> {
+        let states = self.dataset.states.search(query).map(|entry| &entry.unit);
+        Ok(page(states, page_number, limit))
+    }
+
+    async fn search_phonetic(
+        &self,
+        query: &str,
+        page_number: u32,
+        limit: u32,
+    ) -> AppResult<Vec<State>> {
+        let states = self.dataset.states.search_phonetic(query);
+        Ok(page(
+            states.into_iter().map(|entry| &entry.unit),
+            page_number,
+            limit,
+        ))
+    }
+}
@@ -0,0 +1,172 @@
+//! The embedded repositories over a small bundle written as the API writes
+//! one, checked against the order PostgreSQL gives the same rows: names by
+//! byte value (`COLLATE "C"`), ties broken by id
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use crate::domain::entities::export::ExportRow;
+use crate::domain::entities::search::MatchMode;
+use crate::domain::entities::unit_level::UnitLevel;
+use crate::domain::pagination::PageRequest;
+use crate::domain::repositories::lga_repository::LgaRepository;
+use crate::domain::repositories::search_repository::SearchRepository;
+use crate::domain::repositories::ward_repository::WardRepository;
+use crate::infrastructure::embedded::{
+    EmbeddedDataset, EmbeddedLgaRepository, EmbeddedSearchRepository, EmbeddedWardRepository,
+};
+use crate::infrastructure::repositories::bundle_repository_impl::{write_sqlite, BundleRow};
+
+const STATE: Uuid = Uuid::from_u128(1);
+const IKEJA: Uuid = Uuid::from_u128(10);
+const AGEGE: Uuid = Uuid::from_u128(11);
+const OREGUN_A: Uuid = Uuid::from_u128(100);
+const OREGUN_B: Uuid = Uuid::from_u128(101);
+const AGIDINGBI: Uuid = Uuid::from_u128(102);
+const ALAUSA: Uuid = Uuid::from_u128(103);
+const OJODU: Uuid = Uuid::from_u128(104);
+
+/// Ikeja's wards in the order PostgreSQL lists them: uppercase before
+/// lowercase, accented letters after both, equal names by id
+const IKEJA_WARDS: [(Uuid, &str); 5] = [
+    (AGIDINGBI, "Agidingbi"),
+    (OREGUN_A, "Oregun"),
+    (OREGUN_B, "Oregun"),
+    (ALAUSA, "alausa"),
+    (OJODU, "Ọjọdu"),
+];
+
+fn unit(id: Uuid, lga: Option<(&str, &str)>, ward: Option<(&str, &str)>) -> ExportRow {
+    ExportRow {
+        id,
+        state_code: "NG-LA".to_string(),
+        state_name: "Lagos".to_string(),
+        lga_code: lga.map(|(code, _)| code.to_string()),
+        lga_name: lga.map(|(_, name)| name.to_string()),
+        ward_code: ward.map(|(code, _)| code.to_string()),
+        ward_name: ward.map(|(_, name)| name.to_string()),
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        ..Default::default()
+    }
+}
+
+/// Write the fixture bundle and load it; rows go in out of name order so
+/// the order read back is the dataset's own
+fn fixture() -> EmbeddedDataset {
+    let ikeja = Some(("NG-LA-IKJ", "Ikeja"));
+    let rows = vec![
+        BundleRow::Unit(UnitLevel::State, unit(STATE, None, None)),
+        BundleRow::Unit(UnitLevel::Lga, unit(IKEJA, ikeja, None)),
+        BundleRow::Unit(
+            UnitLevel::Lga,
+            unit(AGEGE, Some(("NG-LA-AGE", "Agege")), None),
+        ),
+        BundleRow::Unit(
+            UnitLevel::Ward,
+            unit(OJODU, ikeja, Some(("NG-LA-IKJ-W05", "Ọjọdu"))),
+        ),
+        BundleRow::Unit(
+            UnitLevel::Ward,
+            unit(OREGUN_B, ikeja, Some(("NG-LA-IKJ-W02", "Oregun"))),
+        ),
+        BundleRow::Unit(
+            UnitLevel::Ward,
+            unit(ALAUSA, ikeja, Some(("NG-LA-IKJ-W04", "alausa"))),
+        ),
+        BundleRow::Unit(
+            UnitLevel::Ward,
+            unit(OREGUN_A, ikeja, Some(("NG-LA-IKJ-W01", "Oregun"))),
+        ),
+        BundleRow::Unit(
+            UnitLevel::Ward,
+            unit(AGIDINGBI, ikeja, Some(("NG-LA-IKJ-W03", "Agidingbi"))),
+        ),
+    ];
+
+    let (sender, receiver) = mpsc::channel(rows.len());
+    for row in rows {
+        sender.try_send(row).expect("channel holds every row");
+    }
+    drop(sender);
+
+    let path = std::env::temp_dir().join(format!("nigeria-geo-test-{}.sqlite", Uuid::new_v4()));
+    write_sqlite(&path, "test", "0", receiver).expect("fixture bundle is written");
+    let dataset = EmbeddedDataset::open(&path);
+    let _ = std::fs::remove_file(&path);
+    dataset.expect("fixture bundle is read")
+}
+
+async fn dataset() -> Arc<EmbeddedDataset> {
+    Arc::new(tokio::task::spawn_blocking(fixture).await.unwrap())
+}
+
+fn first_page(limit: u32) -> PageRequest {
+    PageRequest::Offset { page: 1, limit }
+}
+
+#[tokio::test]
+async fn wards_are_listed_by_name_bytes_then_id() {
+    let wards = EmbeddedWardRepository::new(dataset().await);
+
+    let page = wards.find_by_lga_id(IKEJA, &first_page(10)).await.unwrap();
+    let listed: Vec<_> = page.items.iter().map(|w| (w.id, w.name.as_str())).collect();
+
+    assert_eq!(listed, IKEJA_WARDS);
+    assert!(page.next.is_none() && page.prev.is_none());
+}
+
+#[tokio::test]
+async fn ward_cursors_walk_the_listing_both_ways() {
+    let wards = EmbeddedWardRepository::new(dataset().await);
+
+    let mut pages = vec![wards.find_by_lga_id(IKEJA, &first_page(2)).await.unwrap()];
+    while let Some(cursor) = pages.last().unwrap().next.clone() {
+        let request = PageRequest::Cursor { cursor, limit: 2 };
+        pages.push(wards.find_by_lga_id(IKEJA, &request).await.unwrap());
+    }
+    let forward: Vec<_> = pages
+        .iter()
+        .flat_map(|p| p.items.iter().map(|w| w.id))
+        .collect();
+    assert_eq!(forward, IKEJA_WARDS.map(|(id, _)| id));
+
+    let cursor = pages.last().unwrap().prev.clone().unwrap();
+    let back = wards
+        .find_by_lga_id(IKEJA, &PageRequest::Cursor { cursor, limit: 2 })
+        .await
+        .unwrap();
+    assert_eq!(back.items, pages[1].items);
+}
+
+#[tokio::test]
+async fn lgas_and_name_lookups_follow_the_same_order() {
+    let dataset = dataset().await;
+    let lgas = EmbeddedLgaRepository::new(dataset.clone());
+    let wards = EmbeddedWardRepository::new(dataset);
+
+    let listed = lgas.find_by_state_id(STATE, 1, 10).await.unwrap();
+    let names: Vec<_> = listed.iter().map(|lga| lga.name.as_str()).collect();
+    assert_eq!(names, ["Agege", "Ikeja"]);
+
+    // Of two wards with the same name, the one listed first is found
+    let found = wards.find_by_name("Oregun").await.unwrap().unwrap();
+    assert_eq!(found.id, OREGUN_A);
+    let found = wards.find_by_name("ojodu").await.unwrap().unwrap();
+    assert_eq!(found.id, OJODU);
+}
+
+#[tokio::test]
+async fn equally_ranked_search_hits_are_ordered_by_id() {
+    let search = EmbeddedSearchRepository::new(dataset().await);
+
+    let page = search
+        .search("Oregun", MatchMode::Standard, &first_page(2))
+        .await
+        .unwrap();
+    let ids: Vec<_> = page.items.iter().map(|hit| hit.id).collect();
+    assert_eq!(ids, [OREGUN_A, OREGUN_B]);
+}
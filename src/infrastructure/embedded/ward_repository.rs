@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::unit_status::UpdateStatusRequest;
+use crate::domain::entities::ward::{CreateWardRequest, UpdateWardRequest, Ward};
+use crate::domain::pagination::{scoped, KeysetPage, PageRequest};
+use crate::domain::repositories::ward_repository::WardRepository;
+use crate::domain::value_objects::WardCode;
+use crate::errors::AppResult;
+use crate::infrastructure::embedded::{keyset_page, page, read_only, EmbeddedDataset};
+use crate::infrastructure::repositories::keyset::{cursor_key, key_value};
+use crate::infrastructure::repositories::ward_repository_impl::WARDS_BY_LGA;
+
+/// Embedded, read-only implementation of WardRepository
+#[derive(Clone)]
+pub struct EmbeddedWardRepository {
+    dataset: Arc<EmbeddedDataset>,
+}
+
+impl EmbeddedWardRepository {
+    pub fn new(dataset: Arc<EmbeddedDataset>) -> Self {
+        Self { dataset }
+    }
+}
+
+#[async_trait]
+impl WardRepository for EmbeddedWardRepository {
+    async fn create(&self, _request: &CreateWardRequest, _lga_id: Uuid) -> AppResult<Ward> {
+        read_only()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Ward>> {
+        Ok(self.dataset.wards.get(id).map(|entry| entry.unit.clone()))
+    }
+
+    async fn find_by_name(&self, name: &str) -> AppResult<Option<Ward>> {
+        Ok(self
+            .dataset
+            .wards
+            .find_by_name(name)
+            .map(|entry| entry.unit.clone()))
+    }
+
+    async fn find_by_code(&self, code: &WardCode) -> AppResult<Option<Ward>> {
+        Ok(self
+            .dataset
+            .wards
+            .by_code(code.as_str())
+            .map(|entry| entry.unit.clone()))
+    }
+
+    async fn find_by_lga_id(
+        &self,
+        lga_id: Uuid,
+        request: &PageRequest,
+    ) -> AppResult<KeysetPage<Ward>> {
+        let listing = scoped(WARDS_BY_LGA, lga_id);
+        let boundary = match cursor_key::<2>(request, &listing)? {
+            Some([name, id]) => Some((name.clone(), key_value::<Uuid>(id)?)),
+            None => None,
+        };
+
+        // Children are held in (name, id) order, the listing's sort key
+        Ok(keyset_page(
+            self.dataset.wards.children(lga_id).map(|entry| &entry.unit),
+            request,
+            &listing,
+            boundary,
+            |ward| (ward.name.clone(), ward.id),
+            |ward| vec![ward.name.clone(), ward.id.to_string()],
+        ))
+    }
+
+    async fn find_by_lga_ids(&self, lga_ids: &[Uuid]) -> AppResult<Vec<Ward>> {
+        Ok(self
+            .dataset
+            .wards
+            .entries()
+            .iter()
+            .filter(|entry| lga_ids.contains(&entry.unit.lga_id))
+            .map(|entry| entry.unit.clone())
+            .collect())
+    }
+
+    async fn update(&self, _id: Uuid, _request: &UpdateWardRequest) -> AppResult<Ward> {
+        read_only()
+    }
+
+    async fn delete(&self, _id: Uuid) -> AppResult<()> {
+        read_only()
+    }
+
+    async fn set_status(&self, _id: Uuid, _request: &UpdateStatusRequest) -> AppResult<Ward> {
+        read_only()
+    }
+
+    async fn count_by_lga(&self, lga_id: Uuid) -> AppResult<u64> {
+        Ok(self.dataset.wards.count_children(lga_id))
+    }
+
+    async fn search(&self, query: &str, page_number: u32, limit: u32) -> AppResult<Vec<Ward>> {
+        let wards = self.dataset.wards.search(query).map(|entry| &entry.unit);
+        Ok(page(wards, page_number, limit))
+    }
+
+    async fn search_phonetic(
+        &self,
+        query: &str,
+        page_number: u32,
+        limit: u32,
+    ) -> AppResult<Vec<Ward>> {
+        let wards = self.dataset.wards.search_phonetic(query);
+        Ok(page(
+            wards.into_iter().map(|entry| &entry.unit),
+            page_number,
+            limit,
+        ))
+    }
+}
@@ -1,5 +1,6 @@
 pub mod database;
 pub mod repositories;
+pub mod embedded;
 pub mod external;
 pub mod cache;
 pub mod cached_services;
@@ -1,15 +1,25 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{postgres::PgRow, PgPool, Row};
+use uuid::Uuid;
 
 use crate::domain::entities::address::{
     Address, AddressSuggestion, AddressValidationRequest, AddressValidationResponse,
+    ComponentMatch, SimilarAddress,
 };
+use crate::domain::entities::alias::AliasEntityType;
+use crate::domain::entities::search::MatchMode;
+use crate::domain::entities::unit_status::UnitStatus;
+use crate::domain::entities::{Lga, PostalCode, State, Ward};
+use crate::domain::normalization::{normalized, rules_needed};
 use crate::domain::repositories::address_repository::AddressRepository;
 use crate::domain::repositories::{
     lga_repository::LgaRepository, postal_code_repository::PostalCodeRepository,
     state_repository::StateRepository, ward_repository::WardRepository,
 };
-use crate::errors::AppResult;
+use crate::domain::value_objects::{
+    Coordinates, LgaCode, PostalCode as PostalCodeValue, StateCode, WardCode,
+};
+use crate::errors::{AppError, AppResult};
 
 /// PostgreSQL implementation of AddressRepository
 pub struct PostgresAddressRepository {
@@ -36,6 +46,106 @@ impl PostgresAddressRepository {
             postal_code_repo,
         }
     }
+
+    /// Find the state, LGA and ward by name or alias
+    ///
+    /// LGA and ward names repeat across the country, so once a parent is found
+    /// the child is looked up within it rather than by name alone.
+    async fn find_components(
+        &self,
+        state: &str,
+        lga: &str,
+        ward: &str,
+    ) -> AppResult<(Option<State>, Option<Lga>, Option<Ward>)> {
+        let state_entity = self.state_repo.find_by_name(state).await?;
+
+        let lga_entity = match &state_entity {
+            Some(state) => {
+                let id = sqlx::query_scalar(
+                    "SELECT id FROM lgas
+                     WHERE state_id = $1
+                       AND (name = $2 OR normalized_name = $3 OR id IN (
+                           SELECT entity_id FROM aliases
+                           WHERE entity_type = 'lga' AND (lower(name) = lower($2) OR normalized_name = $3)
+                       ))
+                     ORDER BY status = 'active' DESC, name = $2 DESC, normalized_name = $3 DESC, name COLLATE \"C\", id
+                     LIMIT 1",
+                )
+                .bind(state.id)
+                .bind(lga)
+                .bind(normalized(lga))
+                .fetch_optional(&self.pool)
+                .await?;
+                match id {
+                    Some(id) => self.lga_repo.find_by_id(id).await?,
+                    None => None,
+                }
+            }
+            None => self.lga_repo.find_by_name(lga).await?,
+        };
+
+        let ward_entity = match &lga_entity {
+            Some(lga) => {
+                let id = sqlx::query_scalar(
+                    "SELECT id FROM wards
+                     WHERE lga_id = $1
+                       AND (name = $2 OR normalized_name = $3 OR id IN (
+                           SELECT entity_id FROM aliases
+                           WHERE entity_type = 'ward' AND (lower(name) = lower($2) OR normalized_name = $3)
+                       ))
+                     ORDER BY status = 'active' DESC, name = $2 DESC, normalized_name = $3 DESC, name COLLATE \"C\", id
+                     LIMIT 1",
+                )
+                .bind(lga.id)
+                .bind(ward)
+                .bind(normalized(ward))
+                .fetch_optional(&self.pool)
+                .await?;
+                match id {
+                    Some(id) => self.ward_repo.find_by_id(id).await?,
+                    None => None,
+                }
+            }
+            None => self.ward_repo.find_by_name(ward).await?,
+        };
+
+        Ok((state_entity, lga_entity, ward_entity))
+    }
+
+    /// Report the name or alias a component was found by, and the
+    /// normalization rules the input needed to match it
+    async fn component_match(
+        &self,
+        entity_type: AliasEntityType,
+        input: &str,
+        id: Uuid,
+        name: &str,
+    ) -> AppResult<ComponentMatch> {
+        let matched_name = match rules_needed(input, name) {
+            Some(_) => name.to_string(),
+            None => sqlx::query_scalar(
+                "SELECT name FROM aliases
+                 WHERE entity_type = $1 AND entity_id = $2
+                   AND (lower(name) = lower($3) OR normalized_name = $4)
+                 ORDER BY lower(name) = lower($3) DESC, name COLLATE \"C\"
+                 LIMIT 1",
+            )
+            .bind(entity_type.as_str())
+            .bind(id)
+            .bind(input)
+            .bind(normalized(input))
+            .fetch_optional(&self.pool)
+            .await?
+            .unwrap_or_else(|| name.to_string()),
+        };
+
+        Ok(ComponentMatch {
+            component: entity_type.as_str(),
+            input: input.to_string(),
+            rules: rules_needed(input, &matched_name).unwrap_or_default(),
+            matched_name,
+        })
+    }
 }
 
 #[async_trait]
@@ -45,42 +155,73 @@ impl AddressRepository for PostgresAddressRepository {
         request: &AddressValidationRequest,
     ) -> AppResult<AddressValidationResponse> {
         // Find exact matches for each component
-        let state = self.state_repo.find_by_name(&request.state).await?;
-        let lga = self.lga_repo.find_by_name(&request.lga).await?;
-        let ward = self.ward_repo.find_by_name(&request.ward).await?;
+        let (state, lga, ward) = self
+            .find_components(&request.state, &request.lga, &request.ward)
+            .await?;
+
+        let mut matches = Vec::new();
+        for (entity_type, input, found) in [
+            (
+                AliasEntityType::State,
+                &request.state,
+                state.as_ref().map(|s| (s.id, s.name.as_str())),
+            ),
+            (
+                AliasEntityType::Lga,
+                &request.lga,
+                lga.as_ref().map(|l| (l.id, l.name.as_str())),
+            ),
+            (
+                AliasEntityType::Ward,
+                &request.ward,
+                ward.as_ref().map(|w| (w.id, w.name.as_str())),
+            ),
+        ] {
+            if let Some((id, name)) = found {
+                matches.push(self.component_match(entity_type, input, id, name).await?);
+            }
+        }
         let postal_code_value =
             crate::domain::value_objects::PostalCode::new(request.postal_code.clone())
-                .map_err(|e| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+                .map_err(|e| crate::errors::AppError::invalid_param("postal_code", e))?;
         let postal_code = self
             .postal_code_repo
             .find_by_code(&postal_code_value)
             .await?;
 
-        // Check if all components exist and form a valid address
-        let is_valid = state.is_some() && lga.is_some() && ward.is_some() && postal_code.is_some();
-
-        if is_valid {
+        if let (Some(state), Some(lga), Some(ward), Some(postal_code)) =
+            (state, lga, ward, postal_code)
+        {
             // Create canonical address
-            let canonical = Address::new(
-                state.unwrap(),
-                lga.unwrap(),
-                ward.unwrap(),
-                postal_code.unwrap(),
-            );
-
-            return Ok(AddressValidationResponse {
-                valid: true,
-                canonical: Some(canonical),
-                suggestions: vec![],
-            });
+            let canonical = Address::new(state, lga, ward, postal_code);
+
+            // Components found by name must also nest within each other
+            if canonical.is_valid() {
+                return Ok(AddressValidationResponse {
+                    valid: true,
+                    deprecated: canonical.deprecated_components(),
+                    canonical: Some(canonical),
+                    matches,
+                    suggestions: vec![],
+                });
+            }
         }
 
         // Generate suggestions for invalid addresses
-        let suggestions = self.generate_suggestions(request).await?;
+        let suggestions = generate_suggestions(
+            &*self.state_repo,
+            &*self.lga_repo,
+            &*self.ward_repo,
+            &*self.postal_code_repo,
+            request,
+        )
+        .await?;
 
         Ok(AddressValidationResponse {
             valid: false,
             canonical: None,
+            matches,
+            deprecated: vec![],
             suggestions,
         })
     }
@@ -93,12 +234,11 @@ impl AddressRepository for PostgresAddressRepository {
         postal_code: &str,
     ) -> AppResult<Option<Address>> {
         // Find all components
-        let state_entity = self.state_repo.find_by_name(state).await?;
-        let lga_entity = self.lga_repo.find_by_name(lga).await?;
-        let ward_entity = self.ward_repo.find_by_name(ward).await?;
+        let (state_entity, lga_entity, ward_entity) =
+            self.find_components(state, lga, ward).await?;
         let postal_code_value =
             crate::domain::value_objects::PostalCode::new(postal_code.to_string())
-                .map_err(|e| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+                .map_err(|e| crate::errors::AppError::invalid_param("postal_code", e))?;
         let postal_code_entity = self
             .postal_code_repo
             .find_by_code(&postal_code_value)
@@ -106,7 +246,8 @@ impl AddressRepository for PostgresAddressRepository {
 
         match (state_entity, lga_entity, ward_entity, postal_code_entity) {
             (Some(state), Some(lga), Some(ward), Some(postal_code)) => {
-                Ok(Some(Address::new(state, lga, ward, postal_code)))
+                let address = Address::new(state, lga, ward, postal_code);
+                Ok(address.is_valid().then_some(address))
             }
             _ => Ok(None),
         }
@@ -115,108 +256,228 @@ impl AddressRepository for PostgresAddressRepository {
     async fn find_similar_addresses(
         &self,
         request: &AddressValidationRequest,
-    ) -> AppResult<Vec<Address>> {
-        // Search for similar addresses using fuzzy matching
-        let mut similar_addresses = Vec::new();
-
-        // Search for states with similar names
-        let states = self.state_repo.search(&request.state, 1, 10).await?;
-        for state in states {
-            // Search for LGAs in this state
-            let lgas = self.lga_repo.search(&request.lga, 1, 10).await?;
-            for lga in lgas {
-                if lga.state_id == state.id {
-                    // Search for wards in this LGA
-                    let wards = self.ward_repo.search(&request.ward, 1, 10).await?;
-                    for ward in wards {
-                        if ward.lga_id == lga.id {
-                            // Search for postal codes in this ward
-                            let postal_codes = self
-                                .postal_code_repo
-                                .search(&request.postal_code, 1, 10)
-                                .await?;
-                            for postal_code in postal_codes {
-                                if postal_code.ward_id == ward.id {
-                                    similar_addresses.push(Address::new(
-                                        state.clone(),
-                                        lga.clone(),
-                                        ward.clone(),
-                                        postal_code,
-                                    ));
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
+        limit: u32,
+    ) -> AppResult<Vec<SimilarAddress>> {
+        // Rank every complete address under a matching state and LGA in a single
+        // query; ward and postal code similarity only affect the ordering.
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                s.id AS state_id, s.name AS state_name, s.code AS state_code,
+                s.created_at AS state_created_at, s.updated_at AS state_updated_at,
+                l.id AS lga_id, l.state_id AS lga_state_id, l.name AS lga_name, l.code AS lga_code,
+                l.created_at AS lga_created_at, l.updated_at AS lga_updated_at,
+                w.id AS ward_id, w.lga_id AS ward_lga_id, w.name AS ward_name, w.code AS ward_code,
+                w.created_at AS ward_created_at, w.updated_at AS ward_updated_at,
+                p.id AS postal_code_id, p.ward_id AS postal_code_ward_id, p.postal_code,
+                p.lat, p.lng, p.urban,
+                p.created_at AS postal_code_created_at, p.updated_at AS postal_code_updated_at,
+                (
+                    0.2 * CASE WHEN s.normalized_name = $1 OR sa.entity_id IS NOT NULL THEN 1.0 ELSE similarity(s.normalized_name, $1) END
+                  + 0.3 * CASE WHEN l.normalized_name = $2 OR la.entity_id IS NOT NULL THEN 1.0 ELSE similarity(l.normalized_name, $2) END
+                  + 0.3 * CASE WHEN w.normalized_name = $3 OR wa.entity_id IS NOT NULL THEN 1.0 ELSE similarity(w.normalized_name, $3) END
+                  + 0.2 * CASE WHEN p.postal_code = $4 THEN 1.0 ELSE similarity(p.postal_code, $4) END
+                )::float8 AS score
+            FROM postal_codes p
+            JOIN wards w ON w.id = p.ward_id
+            JOIN lgas l ON l.id = w.lga_id
+            JOIN states s ON s.id = l.state_id
+            -- Names are compared in normalized form; an exact alias counts as an exact name match
+            LEFT JOIN (
+                SELECT DISTINCT entity_id FROM aliases
+                WHERE entity_type = 'state' AND normalized_name = $1
+            ) sa ON sa.entity_id = s.id
+            LEFT JOIN (
+                SELECT DISTINCT entity_id FROM aliases
+                WHERE entity_type = 'lga' AND normalized_name = $2
+            ) la ON la.entity_id = l.id
+            LEFT JOIN (
+                SELECT DISTINCT entity_id FROM aliases
+                WHERE entity_type = 'ward' AND normalized_name = $3
+            ) wa ON wa.entity_id = w.id
+            WHERE p.status = 'active' AND w.status = 'active' AND l.status = 'active' AND s.status = 'active'
+              AND (s.normalized_name LIKE '%' || $1 || '%' OR similarity(s.normalized_name, $1) >= $5 OR sa.entity_id IS NOT NULL)
+              AND (l.normalized_name LIKE '%' || $2 || '%' OR similarity(l.normalized_name, $2) >= $5 OR la.entity_id IS NOT NULL)
+            ORDER BY score DESC, s.name COLLATE "C", l.name COLLATE "C", w.name COLLATE "C", p.postal_code
+            LIMIT $6
+            "#,
+        )
+        .bind(normalized(&request.state))
+        .bind(normalized(&request.lga))
+        .bind(normalized(&request.ward))
+        .bind(&request.postal_code)
+        .bind(SIMILARITY_THRESHOLD)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await?;
 
-        Ok(similar_addresses)
+        rows.iter()
+            .map(|row| {
+                Ok(SimilarAddress {
+                    address: address_from_row(row)?,
+                    score: row.get("score"),
+                })
+            })
+            .collect()
     }
 }
 
-impl PostgresAddressRepository {
-    async fn generate_suggestions(
-        &self,
-        request: &AddressValidationRequest,
-    ) -> AppResult<Vec<AddressSuggestion>> {
-        let mut suggestions = Vec::new();
-
-        // Find similar states
-        let states = self.state_repo.search(&request.state, 1, 5).await?;
-        for state in states {
-            suggestions.push(AddressSuggestion {
-                state: Some(state),
-                lga: None,
-                ward: None,
-                postal_code: None,
-                reason: "Similar state name found".to_string(),
-                confidence: 0.8,
-            });
-        }
+/// Minimum trigram similarity for a state or LGA name to count as a match
+const SIMILARITY_THRESHOLD: f32 = 0.3;
 
-        // Find similar LGAs
-        let lgas = self.lga_repo.search(&request.lga, 1, 5).await?;
-        for lga in lgas {
-            suggestions.push(AddressSuggestion {
-                state: None,
-                lga: Some(lga),
-                ward: None,
-                postal_code: None,
-                reason: "Similar LGA name found".to_string(),
-                confidence: 0.8,
-            });
-        }
+/// Build an address from a row of the joined state/LGA/ward/postal code query,
+/// which only selects active units
+fn address_from_row(row: &PgRow) -> AppResult<Address> {
+    let state = State {
+        id: row.get("state_id"),
+        name: row.get("state_name"),
+        code: StateCode::new(row.get::<String, _>("state_code"))
+            .map_err(|e| AppError::Internal(anyhow::anyhow!(e)))?,
+        status: UnitStatus::Active,
+        successor_id: None,
+        created_at: row.get("state_created_at"),
+        updated_at: row.get("state_updated_at"),
+    };
 
-        // Find similar wards
-        let wards = self.ward_repo.search(&request.ward, 1, 5).await?;
-        for ward in wards {
-            suggestions.push(AddressSuggestion {
-                state: None,
-                lga: None,
-                ward: Some(ward),
-                postal_code: None,
-                reason: "Similar ward name found".to_string(),
-                confidence: 0.8,
-            });
-        }
+    let lga = Lga {
+        id: row.get("lga_id"),
+        state_id: row.get("lga_state_id"),
+        name: row.get("lga_name"),
+        code: LgaCode::new(row.get::<String, _>("lga_code"))
+            .map_err(|e| AppError::Internal(anyhow::anyhow!(e)))?,
+        status: UnitStatus::Active,
+        successor_id: None,
+        created_at: row.get("lga_created_at"),
+        updated_at: row.get("lga_updated_at"),
+    };
 
-        // Find similar postal codes
-        let postal_codes = self
-            .postal_code_repo
-            .search(&request.postal_code, 1, 5)
-            .await?;
-        for postal_code in postal_codes {
-            suggestions.push(AddressSuggestion {
-                state: None,
-                lga: None,
-                ward: None,
-                postal_code: Some(postal_code),
-                reason: "Similar postal code found".to_string(),
-                confidence: 0.9,
-            });
-        }
+    let ward = Ward {
+        id: row.get("ward_id"),
+        lga_id: row.get("ward_lga_id"),
+        name: row.get("ward_name"),
+        code: WardCode::new(row.get::<String, _>("ward_code"))
+            .map_err(|e| AppError::Internal(anyhow::anyhow!(e)))?,
+        status: UnitStatus::Active,
+        successor_id: None,
+        created_at: row.get("ward_created_at"),
+        updated_at: row.get("ward_updated_at"),
+    };
+
+    let coordinates = match (
+        row.get::<Option<f64>, _>("lat"),
+        row.get::<Option<f64>, _>("lng"),
+    ) {
+        (Some(lat), Some(lng)) => Some(Coordinates::new(lat, lng)?),
+        _ => None,
+    };
+
+    let postal_code = PostalCode {
+        id: row.get("postal_code_id"),
+        ward_id: row.get("postal_code_ward_id"),
+        postal_code: PostalCodeValue::new(row.get::<String, _>("postal_code"))
+            .map_err(|e| AppError::Internal(anyhow::anyhow!(e)))?,
+        coordinates,
+        urban: row.get("urban"),
+        status: UnitStatus::Active,
+        successor_id: None,
+        created_at: row.get("postal_code_created_at"),
+        updated_at: row.get("postal_code_updated_at"),
+    };
 
-        Ok(suggestions)
+    Ok(Address::new(state, lga, ward, postal_code))
+}
+
+/// States, LGAs, wards and postal codes like those of an address that did not
+/// validate, found through the repositories' own searches
+pub(crate) async fn generate_suggestions(
+    state_repo: &dyn StateRepository,
+    lga_repo: &dyn LgaRepository,
+    ward_repo: &dyn WardRepository,
+    postal_code_repo: &dyn PostalCodeRepository,
+    request: &AddressValidationRequest,
+) -> AppResult<Vec<AddressSuggestion>> {
+    let mut suggestions = Vec::new();
+    let phonetic = request.match_mode == MatchMode::Phonetic;
+    let name_confidence = if phonetic { 0.7 } else { 0.8 };
+
+    // Find similar states
+    let states = if phonetic {
+        state_repo.search_phonetic(&request.state, 1, 5).await?
+    } else {
+        state_repo.search(&request.state, 1, 5).await?
+    };
+    for state in states {
+        suggestions.push(AddressSuggestion {
+            state: Some(state),
+            lga: None,
+            ward: None,
+            postal_code: None,
+            reason: if phonetic {
+                "State name sounds alike"
+            } else {
+                "Similar state name found"
+            }
+            .to_string(),
+            confidence: name_confidence,
+        });
+    }
+
+    // Find similar LGAs
+    let lgas = if phonetic {
+        lga_repo.search_phonetic(&request.lga, 1, 5).await?
+    } else {
+        lga_repo.search(&request.lga, 1, 5).await?
+    };
+    for lga in lgas {
+        suggestions.push(AddressSuggestion {
+            state: None,
+            lga: Some(lga),
+            ward: None,
+            postal_code: None,
+            reason: if phonetic {
+                "LGA name sounds alike"
+            } else {
+                "Similar LGA name found"
+            }
+            .to_string(),
+            confidence: name_confidence,
+        });
     }
+
+    // Find similar wards
+    let wards = if phonetic {
+        ward_repo.search_phonetic(&request.ward, 1, 5).await?
+    } else {
+        ward_repo.search(&request.ward, 1, 5).await?
+    };
+    for ward in wards {
+        suggestions.push(AddressSuggestion {
+            state: None,
+            lga: None,
+            ward: Some(ward),
+            postal_code: None,
+            reason: if phonetic {
+                "Ward name sounds alike"
+            } else {
+                "Similar ward name found"
+            }
+            .to_string(),
+            confidence: name_confidence,
+        });
+    }
+
+    // Find similar postal codes
+    let postal_codes = postal_code_repo.search(&request.postal_code, 1, 5).await?;
+    for postal_code in postal_codes {
+        suggestions.push(AddressSuggestion {
+            state: None,
+            lga: None,
+            ward: None,
+            postal_code: Some(postal_code),
+            reason: "Similar postal code found".to_string(),
+            confidence: 0.9,
+        });
+    }
+
+    Ok(suggestions)
 }
@@ -0,0 +1,269 @@
+use async_trait::async_trait;
+use sqlx::{postgres::PgRow, PgConnection, PgPool, Row};
+use uuid::Uuid;
+
+use crate::domain::entities::alias::{
+    Alias, AliasEntityType, AliasType, CreateAliasRequest, UpdateAliasRequest,
+};
+use crate::domain::entities::change::ChangeEntityType;
+use crate::domain::normalization::normalized;
+use crate::domain::phonetic::phonetic_key;
+use crate::domain::repositories::alias_repository::AliasRepository;
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::repositories::history_repository_impl as history;
+
+/// PostgreSQL implementation of AliasRepository
+#[derive(Clone)]
+pub struct PostgresAliasRepository {
+    pool: PgPool,
+}
+
+impl PostgresAliasRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// `aliases.entity_id` has no foreign key, so check the entity exists
+    async fn ensure_entity_exists(&self, entity_type: AliasEntityType, id: Uuid) -> AppResult<()> {
+        let exists: bool = sqlx::query_scalar(&format!(
+            "SELECT EXISTS (SELECT 1 FROM {} WHERE id = $1)",
+            entity_type.table()
+        ))
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        if exists {
+            Ok(())
+        } else {
+            Err(AppError::NotFound(format!(
+                "No {} with id {}",
+                entity_type.as_str(),
+                id
+            )))
+        }
+    }
+
+    /// Write an alias, reporting a duplicate name for the same entity as a conflict
+    async fn save(&self, connection: &mut PgConnection, sql: &str, alias: &Alias) -> AppResult<()> {
+        let result = sqlx::query(sql)
+            .bind(alias.id)
+            .bind(alias.entity_type.as_str())
+            .bind(alias.entity_id)
+            .bind(&alias.name)
+            .bind(alias.alias_type.as_str())
+            .bind(&alias.language)
+            .bind(alias.created_at)
+            .bind(alias.updated_at)
+            .bind(normalized(&alias.name))
+            .bind(phonetic_key(&alias.name))
+            .execute(connection)
+            .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+                Err(AppError::Conflict(format!(
+                    "{} already has the alias '{}'",
+                    alias.entity_type.as_str(),
+                    alias.name
+                )))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Lower-case language codes so `YO` and `yo` are stored alike
+fn normalize_language(language: &Option<String>) -> AppResult<Option<String>> {
+    let Some(language) = language else {
+        return Ok(None);
+    };
+
+    let language = language.trim().to_ascii_lowercase();
+    if !(2..=3).contains(&language.len()) || !language.bytes().all(|b| b.is_ascii_lowercase()) {
+        return Err(AppError::BadRequest(format!(
+            "Language must be a two- or three-letter ISO 639 code, got '{}'",
+            language
+        )));
+    }
+
+    Ok(Some(language))
+}
+
+pub(crate) fn alias_from_row(row: &PgRow) -> AppResult<Alias> {
+    let entity_type: String = row.get("entity_type");
+    let alias_type: String = row.get("alias_type");
+
+    Ok(Alias {
+        id: row.get("id"),
+        entity_type: AliasEntityType::parse(&entity_type).ok_or_else(|| {
+            AppError::Internal(anyhow::anyhow!("Unknown alias entity type {}", entity_type))
+        })?,
+        entity_id: row.get("entity_id"),
+        name: row.get("name"),
+        alias_type: AliasType::parse(&alias_type).ok_or_else(|| {
+            AppError::Internal(anyhow::anyhow!("Unknown alias type {}", alias_type))
+        })?,
+        language: row.get("language"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+#[async_trait]
+impl AliasRepository for PostgresAliasRepository {
+    async fn create(&self, request: &CreateAliasRequest) -> AppResult<Alias> {
+        self.ensure_entity_exists(request.entity_type, request.entity_id)
+            .await?;
+
+        let alias = Alias::new(
+            request.entity_type,
+            request.entity_id,
+            request.name.trim().to_string(),
+            request.alias_type,
+            normalize_language(&request.language)?,
+        );
+
+        let mut tx = self.pool.begin().await?;
+        self.save(
+            &mut tx,
+            "INSERT INTO aliases (id, entity_type, entity_id, name, alias_type, language, created_at, updated_at,
+                                  normalized_name, phonetic_key)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+            &alias,
+        )
+        .await?;
+        history::record(
+            &mut tx,
+            ChangeEntityType::Alias,
+            alias.id,
+            None,
+            Some(&alias),
+        )
+        .await?;
+        tx.commit().await?;
+
+        Ok(alias)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Alias>> {
+        let row = sqlx::query(
+            "SELECT id, entity_type, entity_id, name, alias_type, language, created_at, updated_at
+             FROM aliases WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(alias_from_row).transpose()
+    }
+
+    async fn list(
+        &self,
+        entity_type: Option<AliasEntityType>,
+        entity_id: Option<Uuid>,
+        page: u32,
+        limit: u32,
+    ) -> AppResult<Vec<Alias>> {
+        let offset = page.saturating_sub(1) * limit;
+
+        let rows = sqlx::query(
+            "SELECT id, entity_type, entity_id, name, alias_type, language, created_at, updated_at
+             FROM aliases
+             WHERE ($1::text IS NULL OR entity_type = $1)
+               AND ($2::uuid IS NULL OR entity_id = $2)
+             ORDER BY entity_type, lower(name), id
+             LIMIT $3 OFFSET $4",
+        )
+        .bind(entity_type.map(|t| t.as_str()))
+        .bind(entity_id)
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(alias_from_row).collect()
+    }
+
+    async fn count(
+        &self,
+        entity_type: Option<AliasEntityType>,
+        entity_id: Option<Uuid>,
+    ) -> AppResult<u64> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM aliases
+             WHERE ($1::text IS NULL OR entity_type = $1)
+               AND ($2::uuid IS NULL OR entity_id = $2)",
+        )
+        .bind(entity_type.map(|t| t.as_str()))
+        .bind(entity_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count as u64)
+    }
+
+    async fn update(&self, id: Uuid, request: &UpdateAliasRequest) -> AppResult<Alias> {
+        let mut alias = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Alias not found".to_string()))?;
+        let before = alias.clone();
+
+        if let Some(name) = &request.name {
+            alias.name = name.trim().to_string();
+        }
+        if let Some(alias_type) = request.alias_type {
+            alias.alias_type = alias_type;
+        }
+        if request.language.is_some() {
+            alias.language = normalize_language(&request.language)?;
+        }
+        alias.updated_at = chrono::Utc::now();
+
+        let mut tx = self.pool.begin().await?;
+        self.save(
+            &mut tx,
+            "UPDATE aliases
+             SET entity_type = $2, entity_id = $3, name = $4, alias_type = $5, language = $6,
+                 created_at = $7, updated_at = $8, normalized_name = $9, phonetic_key = $10
+             WHERE id = $1",
+            &alias,
+        )
+        .await?;
+        history::record(
+            &mut tx,
+            ChangeEntityType::Alias,
+            id,
+            Some(&before),
+            Some(&alias),
+        )
+        .await?;
+        tx.commit().await?;
+
+        Ok(alias)
+    }
+
+    async fn delete(&self, id: Uuid) -> AppResult<()> {
+        let alias = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Alias not found".to_string()))?;
+
+        let mut tx = self.pool.begin().await?;
+        let result = sqlx::query("DELETE FROM aliases WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound("Alias not found".to_string()));
+        }
+
+        history::record(&mut tx, ChangeEntityType::Alias, id, Some(&alias), None).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
@@ -1,10 +1,17 @@
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
-use sqlx::PgPool;
+use chrono::{DateTime, SecondsFormat, Utc};
+use sqlx::{PgPool, Row};
+use uuid::Uuid;
 
 use crate::domain::entities::api_usage::{ApiUsage, UsageStats, EndpointStats, HourlyStats, StatusCodeStats};
+use crate::domain::pagination::{Direction, KeysetPage, PageRequest};
 use crate::domain::repositories::api_usage_repository::ApiUsageRepository;
 use crate::errors::AppResult;
+use crate::infrastructure::repositories::keyset::{cursor_key, key_value};
+
+/// Listing names carried in cursors over usage records
+const USAGE_BY_IP: &str = "usage_by_ip";
+const USAGE_BY_API_KEY: &str = "usage_by_api_key";
 
 pub struct PostgresApiUsageRepository {
     pool: PgPool,
@@ -14,6 +21,97 @@ impl PostgresApiUsageRepository {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Records in the date range matching `filter` (on `$1`), newest first,
+    /// paged by timestamp and ID
+    async fn usage_page(
+        &self,
+        listing: &str,
+        filter: &str,
+        value: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+        request: &PageRequest,
+    ) -> AppResult<KeysetPage<ApiUsage>> {
+        let (timestamp, id) = match cursor_key::<2>(request, listing)? {
+            Some([timestamp, id]) => (
+                Some(key_value::<DateTime<Utc>>(timestamp)?),
+                Some(key_value::<Uuid>(id)?),
+            ),
+            None => (None, None),
+        };
+
+        // Newest first, so the page after a cursor holds older records
+        let (seek, order) = match request.direction() {
+            Direction::After => ("<", "DESC"),
+            Direction::Before => (">", "ASC"),
+        };
+
+        let rows = sqlx::query(&format!(
+            "SELECT id, endpoint, method, user_agent, host(ip_address) AS ip_address,
+                    response_status, response_time_ms, request_size_bytes, response_size_bytes,
+                    timestamp, api_key, user_id
+             FROM api_usage
+             WHERE {filter} AND timestamp BETWEEN $2 AND $3
+               AND ($4::timestamptz IS NULL OR (timestamp, id) {seek} ($4, $5))
+             ORDER BY timestamp {order}, id {order}
+             LIMIT $6 OFFSET $7"
+        ))
+        .bind(value)
+        .bind(start_date)
+        .bind(end_date)
+        .bind(timestamp)
+        .bind(id)
+        .bind(request.fetch_limit() as i64)
+        .bind(request.offset() as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let usage = rows
+            .into_iter()
+            .map(|row| ApiUsage {
+                id: row.get("id"),
+                endpoint: row.get("endpoint"),
+                method: row.get("method"),
+                user_agent: row.get("user_agent"),
+                ip_address: row.get("ip_address"),
+                response_status: row.get::<i16, _>("response_status") as u16,
+                response_time_ms: row.get::<i32, _>("response_time_ms") as u32,
+                request_size_bytes: row.get::<Option<i32>, _>("request_size_bytes").map(|b| b as u32),
+                response_size_bytes: row.get::<Option<i32>, _>("response_size_bytes").map(|b| b as u32),
+                timestamp: row.get("timestamp"),
+                api_key: row.get("api_key"),
+                user_id: row.get("user_id"),
+            })
+            .collect();
+
+        Ok(KeysetPage::from_rows(usage, request, listing, |usage: &ApiUsage| {
+            vec![
+                usage.timestamp.to_rfc3339_opts(SecondsFormat::Micros, true),
+                usage.id.to_string(),
+            ]
+        }))
+    }
+
+    /// Number of records in the date range matching `filter` (on `$1`)
+    async fn count_usage(
+        &self,
+        filter: &str,
+        value: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> AppResult<u64> {
+        let count = sqlx::query_scalar::<_, i64>(&format!(
+            "SELECT COUNT(*) FROM api_usage WHERE {filter} AND timestamp BETWEEN $2 AND $3"
+        ))
+        .bind(value)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count as u64)
+    }
 }
 
 #[async_trait]
@@ -78,20 +176,44 @@ impl ApiUsageRepository for PostgresApiUsageRepository {
 
     async fn get_usage_by_ip(
         &self,
-        _ip_address: &str,
-        _start_date: DateTime<Utc>,
-        _end_date: DateTime<Utc>,
-    ) -> AppResult<Vec<ApiUsage>> {
-        Ok(vec![])
+        ip_address: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+        request: &PageRequest,
+    ) -> AppResult<KeysetPage<ApiUsage>> {
+        self.usage_page(USAGE_BY_IP, "ip_address = $1::inet", ip_address, start_date, end_date, request)
+            .await
     }
 
     async fn get_usage_by_api_key(
         &self,
-        _api_key: &str,
-        _start_date: DateTime<Utc>,
-        _end_date: DateTime<Utc>,
-    ) -> AppResult<Vec<ApiUsage>> {
-        Ok(vec![])
+        api_key: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+        request: &PageRequest,
+    ) -> AppResult<KeysetPage<ApiUsage>> {
+        self.usage_page(USAGE_BY_API_KEY, "api_key = $1", api_key, start_date, end_date, request)
+            .await
+    }
+
+    async fn count_usage_by_ip(
+        &self,
+        ip_address: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> AppResult<u64> {
+        self.count_usage("ip_address = $1::inet", ip_address, start_date, end_date)
+            .await
+    }
+
+    async fn count_usage_by_api_key(
+        &self,
+        api_key: &str,
+        start_date: DateTime<Utc>,
+        end_date: DateTime<Utc>,
+    ) -> AppResult<u64> {
+        self.count_usage("api_key = $1", api_key, start_date, end_date)
+            .await
     }
 
     async fn cleanup_old_records(&self, _older_than: DateTime<Utc>) -> AppResult<u64> {
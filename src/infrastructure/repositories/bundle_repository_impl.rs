@@ -0,0 +1,388 @@
+//! Copying every active unit and alias into an offline SQLite bundle
+//!
+//! Rows are read inside one repeatable-read transaction and handed through a
+//! bounded channel to a blocking task that writes SQLite, so the dataset is
+//! never held in memory and the bundle matches its fingerprint exactly.
+
+use std::path::Path;
+
+use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures::TryStreamExt;
+use rusqlite::{params, Connection, Transaction};
+use sqlx::{postgres::PgRow, PgConnection, PgPool, Row};
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use crate::domain::entities::bundle::{bundle_version, BundleContents, BUNDLE_SCHEMA_VERSION};
+use crate::domain::entities::export::ExportRow;
+use crate::domain::entities::sync::SyncToken;
+use crate::domain::entities::unit_level::UnitLevel;
+use crate::domain::repositories::bundle_repository::BundleRepository;
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::repositories::export_repository_impl::{
+    begin_snapshot, export_row_from_row, level_sql,
+};
+use crate::infrastructure::repositories::search_repository_impl::DATA_FINGERPRINT_SQL;
+use crate::infrastructure::repositories::sync_repository_impl::watermark;
+
+/// Rows fetched ahead of the SQLite writer
+const BUNDLE_BUFFER_ROWS: usize = 512;
+
+/// Aliases of the units the bundle carries: active, with active ancestors,
+/// along with the code of the unit they name
+const ALIASES_SQL: &str =
+    "SELECT a.id, a.entity_type, a.entity_id, coalesce(s.code, l.code, w.code) AS code,
+        a.name, a.alias_type, a.language
+    FROM aliases a
+    LEFT JOIN states s ON a.entity_type = 'state' AND s.id = a.entity_id AND s.status = 'active'
+    LEFT JOIN lgas l ON a.entity_type = 'lga' AND l.id = a.entity_id AND l.status = 'active'
+        AND EXISTS (SELECT 1 FROM states ls WHERE ls.id = l.state_id AND ls.status = 'active')
+    LEFT JOIN wards w ON a.entity_type = 'ward' AND w.id = a.entity_id AND w.status = 'active'
+        AND EXISTS (
+            SELECT 1 FROM lgas wl JOIN states ws ON ws.id = wl.state_id
+            WHERE wl.id = w.lga_id AND wl.status = 'active' AND ws.status = 'active'
+        )
+    WHERE coalesce(s.code, l.code, w.code) IS NOT NULL
+    ORDER BY code, a.name";
+
+/// Tables of the bundle, version [`BUNDLE_SCHEMA_VERSION`]
+///
+/// Units refer to their parents by code, and carry their timestamps as
+/// RFC 3339 text. `search` is a full-text index over
+/// the names of states, LGAs and wards, their aliases and postal codes, each
+/// entry naming the `level` and `code` of the unit it finds.
+const SCHEMA_SQL: &str = "
+    CREATE TABLE metadata (key TEXT PRIMARY KEY, value TEXT NOT NULL);
+    CREATE TABLE states (
+        id TEXT PRIMARY KEY,
+        code TEXT NOT NULL UNIQUE,
+        name TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+    CREATE TABLE lgas (
+        id TEXT PRIMARY KEY,
+        code TEXT NOT NULL UNIQUE,
+        name TEXT NOT NULL,
+        state_code TEXT NOT NULL REFERENCES states (code),
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+    CREATE TABLE wards (
+        id TEXT PRIMARY KEY,
+        code TEXT NOT NULL UNIQUE,
+        name TEXT NOT NULL,
+        lga_code TEXT NOT NULL REFERENCES lgas (code),
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+    CREATE TABLE postal_codes (
+        id TEXT PRIMARY KEY,
+        postal_code TEXT NOT NULL,
+        ward_code TEXT NOT NULL REFERENCES wards (code),
+        lat REAL,
+        lng REAL,
+        urban INTEGER,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
+    CREATE TABLE aliases (
+        id TEXT PRIMARY KEY,
+        level TEXT NOT NULL,
+        entity_id TEXT NOT NULL,
+        code TEXT NOT NULL,
+        name TEXT NOT NULL,
+        alias_type TEXT NOT NULL,
+        language TEXT
+    );
+    CREATE VIRTUAL TABLE search USING fts5 (
+        name,
+        level UNINDEXED,
+        code UNINDEXED,
+        tokenize = 'unicode61 remove_diacritics 2',
+        prefix = '2 3'
+    );
+";
+
+/// Indexes, built once the rows are in
+const INDEXES_SQL: &str = "
+    CREATE INDEX lgas_state_code ON lgas (state_code);
+    CREATE INDEX wards_lga_code ON wards (lga_code);
+    CREATE INDEX postal_codes_postal_code ON postal_codes (postal_code);
+    CREATE INDEX postal_codes_ward_code ON postal_codes (ward_code);
+    CREATE INDEX postal_codes_location ON postal_codes (lat, lng);
+    CREATE INDEX aliases_unit ON aliases (level, code);
+    INSERT INTO search (search) VALUES ('optimize');
+";
+
+const INSERT_SEARCH_SQL: &str = "INSERT INTO search (name, level, code) VALUES (?1, ?2, ?3)";
+
+/// An alias as written to the bundle
+pub(crate) struct BundleAlias {
+    id: Uuid,
+    level: String,
+    entity_id: Uuid,
+    code: String,
+    name: String,
+    alias_type: String,
+    language: Option<String>,
+}
+
+/// One row on its way to the bundle
+pub(crate) enum BundleRow {
+    Unit(UnitLevel, ExportRow),
+    Alias(BundleAlias),
+}
+
+/// PostgreSQL implementation of BundleRepository, writing SQLite
+#[derive(Clone)]
+pub struct PostgresBundleRepository {
+    pool: PgPool,
+}
+
+impl PostgresBundleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BundleRepository for PostgresBundleRepository {
+    async fn data_fingerprint(&self) -> AppResult<String> {
+        let fingerprint = sqlx::query_scalar::<_, String>(DATA_FINGERPRINT_SQL)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(fingerprint)
+    }
+
+    async fn write_bundle(&self, path: &Path) -> AppResult<BundleContents> {
+        let watermark = watermark(&self.pool).await?;
+        let (mut tx, fingerprint) = begin_snapshot(&self.pool).await?;
+        let version = bundle_version(&fingerprint);
+        let sync_token = SyncToken::new(watermark).encode();
+
+        let (sender, receiver) = mpsc::channel(BUNDLE_BUFFER_ROWS);
+        let writer = {
+            let path = path.to_path_buf();
+            let version = version.clone();
+            tokio::task::spawn_blocking(move || {
+                write_sqlite(&path, &version, &sync_token, receiver)
+            })
+        };
+
+        let read = send_rows(&mut tx, &sender).await;
+        drop(sender);
+        // A writer that failed stopped taking rows, so its error comes first
+        let mut contents = writer.await.map_err(|e| AppError::Internal(e.into()))??;
+        read?;
+
+        contents.fingerprint = fingerprint;
+        contents.watermark = watermark;
+        Ok(contents)
+    }
+}
+
+/// Send every unit, parents first, then the aliases, until they run out or
+/// the writer goes away
+async fn send_rows(
+    connection: &mut PgConnection,
+    sender: &mpsc::Sender<BundleRow>,
+) -> AppResult<()> {
+    for level in UnitLevel::ALL {
+        let mut rows = sqlx::query(level_sql(level)).fetch(&mut *connection);
+        while let Some(row) = rows.try_next().await? {
+            let row = BundleRow::Unit(level, export_row_from_row(&row));
+            if sender.send(row).await.is_err() {
+                return Ok(());
+            }
+        }
+    }
+
+    let mut rows = sqlx::query(ALIASES_SQL).fetch(&mut *connection);
+    while let Some(row) = rows.try_next().await? {
+        if sender
+            .send(BundleRow::Alias(alias_from_row(&row)))
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn alias_from_row(row: &PgRow) -> BundleAlias {
+    BundleAlias {
+        id: row.get("id"),
+        level: row.get("entity_type"),
+        entity_id: row.get("entity_id"),
+        code: row.get("code"),
+        name: row.get("name"),
+        alias_type: row.get("alias_type"),
+        language: row.get("language"),
+    }
+}
+
+/// Create the bundle at `path` and fill it with the rows received
+pub(crate) fn write_sqlite(
+    path: &Path,
+    version: &str,
+    sync_token: &str,
+    mut rows: mpsc::Receiver<BundleRow>,
+) -> AppResult<BundleContents> {
+    let mut connection = Connection::open(path)?;
+    // The file is thrown away if anything fails, so there is nothing to recover
+    connection.execute_batch("PRAGMA journal_mode = OFF; PRAGMA synchronous = OFF;")?;
+    connection.pragma_update(None, "user_version", BUNDLE_SCHEMA_VERSION)?;
+
+    let tx = connection.transaction()?;
+    tx.execute_batch(SCHEMA_SQL)?;
+    tx.execute(
+        "INSERT INTO metadata (key, value)
+         VALUES ('version', ?1), ('schema_version', ?2), ('sync_token', ?3)",
+        params![version, BUNDLE_SCHEMA_VERSION.to_string(), sync_token],
+    )?;
+
+    let mut writer = SqliteBundleWriter {
+        tx: &tx,
+        contents: BundleContents {
+            version: version.to_string(),
+            ..Default::default()
+        },
+        last_postal_code: None,
+    };
+    while let Some(row) = rows.blocking_recv() {
+        writer.insert(&row)?;
+    }
+    let contents = writer.contents;
+
+    tx.execute_batch(INDEXES_SQL)?;
+    tx.commit()?;
+    Ok(contents)
+}
+
+/// Inserts rows into an open bundle, counting them
+struct SqliteBundleWriter<'a> {
+    tx: &'a Transaction<'a>,
+    contents: BundleContents,
+    /// Postal codes arrive in code order; one code can cover several wards
+    /// but is indexed for search once
+    last_postal_code: Option<String>,
+}
+
+impl SqliteBundleWriter<'_> {
+    fn insert(&mut self, row: &BundleRow) -> rusqlite::Result<()> {
+        let tx = self.tx;
+        let contents = &mut self.contents;
+
+        match row {
+            BundleRow::Unit(UnitLevel::State, unit) => {
+                tx.prepare_cached(
+                    "INSERT INTO states (id, code, name, created_at, updated_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                )?
+                .execute(params![
+                    unit.id.to_string(),
+                    unit.state_code,
+                    unit.state_name,
+                    timestamp(&unit.created_at),
+                    timestamp(&unit.updated_at)
+                ])?;
+                index(tx, &unit.state_name, "state", &unit.state_code)?;
+                contents.states += 1;
+            }
+            BundleRow::Unit(UnitLevel::Lga, unit) => {
+                tx.prepare_cached(
+                    "INSERT INTO lgas (id, code, name, state_code, created_at, updated_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                )?
+                .execute(params![
+                    unit.id.to_string(),
+                    unit.lga_code,
+                    unit.lga_name,
+                    unit.state_code,
+                    timestamp(&unit.created_at),
+                    timestamp(&unit.updated_at)
+                ])?;
+                index(tx, &unit.lga_name, "lga", &unit.lga_code)?;
+                contents.lgas += 1;
+            }
+            BundleRow::Unit(UnitLevel::Ward, unit) => {
+                tx.prepare_cached(
+                    "INSERT INTO wards (id, code, name, lga_code, created_at, updated_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                )?
+                .execute(params![
+                    unit.id.to_string(),
+                    unit.ward_code,
+                    unit.ward_name,
+                    unit.lga_code,
+                    timestamp(&unit.created_at),
+                    timestamp(&unit.updated_at)
+                ])?;
+                index(tx, &unit.ward_name, "ward", &unit.ward_code)?;
+                contents.wards += 1;
+            }
+            BundleRow::Unit(UnitLevel::PostalCode, unit) => {
+                tx.prepare_cached(
+                    "INSERT INTO postal_codes
+                        (id, postal_code, ward_code, lat, lng, urban, created_at, updated_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                )?
+                .execute(params![
+                    unit.id.to_string(),
+                    unit.postal_code,
+                    unit.ward_code,
+                    unit.lat,
+                    unit.lng,
+                    unit.urban,
+                    timestamp(&unit.created_at),
+                    timestamp(&unit.updated_at)
+                ])?;
+                if unit.postal_code != self.last_postal_code {
+                    index(tx, &unit.postal_code, "postal_code", &unit.postal_code)?;
+                    self.last_postal_code = unit.postal_code.clone();
+                }
+                contents.postal_codes += 1;
+            }
+            BundleRow::Alias(alias) => {
+                tx.prepare_cached(
+                    "INSERT INTO aliases (id, level, entity_id, code, name, alias_type, language)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                )?
+                .execute(params![
+                    alias.id.to_string(),
+                    alias.level,
+                    alias.entity_id.to_string(),
+                    alias.code,
+                    alias.name,
+                    alias.alias_type,
+                    alias.language
+                ])?;
+                index(tx, &alias.name, &alias.level, &alias.code)?;
+                contents.aliases += 1;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Timestamp as stored in the bundle, to the microsecond PostgreSQL keeps
+fn timestamp(at: &DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// Add a name to the search index, pointing at the unit it finds
+fn index<T: rusqlite::ToSql>(
+    tx: &Transaction<'_>,
+    name: &T,
+    level: &str,
+    code: &T,
+) -> rusqlite::Result<()> {
+    tx.prepare_cached(INSERT_SEARCH_SQL)?
+        .execute(params![name, level, code])?;
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgRow, PgPool, Row};
+
+use crate::domain::audit::current_actor;
+use crate::domain::entities::dataset_version::{
+    schema_name, CreateDatasetVersionRequest, DatasetVersion,
+};
+use crate::domain::repositories::dataset_version_repository::DatasetVersionRepository;
+use crate::errors::AppResult;
+use crate::infrastructure::repositories::integrity::conflict_on_duplicate;
+
+/// Tables copied into a release, parents before children
+const RELEASED_TABLES: [&str; 5] = ["states", "lgas", "wards", "postal_codes", "aliases"];
+
+/// Releases with the history position of the release before each
+const VERSIONS_SQL: &str = "SELECT * FROM (
+        SELECT v.*, LAG(history_id, 1, 0::bigint) OVER (ORDER BY id) AS previous_history_id
+        FROM dataset_versions v
+    ) v";
+
+/// PostgreSQL implementation of DatasetVersionRepository
+///
+/// Each release is a schema of its own holding copies of the released tables;
+/// see [`DatasetVersion::schema_name`].
+#[derive(Clone)]
+pub struct PostgresDatasetVersionRepository {
+    pool: PgPool,
+}
+
+impl PostgresDatasetVersionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DatasetVersionRepository for PostgresDatasetVersionRepository {
+    async fn release(&self, request: &CreateDatasetVersionRequest) -> AppResult<DatasetVersion> {
+        let mut tx = self.pool.begin().await?;
+
+        // Every table is copied from the same snapshot. Locking the history
+        // first waits out writes in flight and holds off new ones until the
+        // copy is done, so the release has exactly the changes up to its
+        // history position.
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ")
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("LOCK TABLE change_history IN SHARE MODE")
+            .execute(&mut *tx)
+            .await?;
+
+        let id: i32 = sqlx::query_scalar(
+            "INSERT INTO dataset_versions
+                (name, notes, released_by, history_id, states, lgas, wards, postal_codes)
+             SELECT $1, $2, $3,
+                    COALESCE((SELECT MAX(id) FROM change_history), 0),
+                    (SELECT COUNT(*) FROM states WHERE status = 'active'),
+                    (SELECT COUNT(*) FROM lgas WHERE status = 'active'),
+                    (SELECT COUNT(*) FROM wards WHERE status = 'active'),
+                    (SELECT COUNT(*) FROM postal_codes WHERE status = 'active')
+             RETURNING id",
+        )
+        .bind(&request.name)
+        .bind(&request.notes)
+        .bind(current_actor())
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            conflict_on_duplicate(e, || {
+                format!("A release named '{}' already exists", request.name)
+            })
+        })?;
+
+        let schema = schema_name(id);
+        sqlx::query(&format!("CREATE SCHEMA {}", schema))
+            .execute(&mut *tx)
+            .await?;
+        for table in RELEASED_TABLES {
+            sqlx::query(&format!(
+                "CREATE TABLE {schema}.{table} (LIKE {table} INCLUDING ALL)"
+            ))
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(&format!(
+                "INSERT INTO {schema}.{table} SELECT * FROM {table}"
+            ))
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        let row = sqlx::query(&format!("{} WHERE id = $1", VERSIONS_SQL))
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(version_from_row(&row))
+    }
+
+    async fn list(&self) -> AppResult<Vec<DatasetVersion>> {
+        let rows = sqlx::query(&format!("{} ORDER BY id DESC", VERSIONS_SQL))
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.iter().map(version_from_row).collect())
+    }
+
+    async fn find_by_name(&self, name: &str) -> AppResult<Option<DatasetVersion>> {
+        let row = sqlx::query(&format!("{} WHERE name = $1", VERSIONS_SQL))
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.as_ref().map(version_from_row))
+    }
+
+    async fn find_as_of(&self, at: DateTime<Utc>) -> AppResult<Option<DatasetVersion>> {
+        let row = sqlx::query(&format!(
+            "{} WHERE released_at <= $1 ORDER BY released_at DESC, id DESC LIMIT 1",
+            VERSIONS_SQL
+        ))
+        .bind(at)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.as_ref().map(version_from_row))
+    }
+}
+
+fn version_from_row(row: &PgRow) -> DatasetVersion {
+    DatasetVersion {
+        id: row.get("id"),
+        name: row.get("name"),
+        notes: row.get("notes"),
+        released_at: row.get("released_at"),
+        released_by: row.get("released_by"),
+        history_id: row.get("history_id"),
+        previous_history_id: row.get("previous_history_id"),
+        states: row.get::<i64, _>("states") as u64,
+        lgas: row.get::<i64, _>("lgas") as u64,
+        wards: row.get::<i64, _>("wards") as u64,
+        postal_codes: row.get::<i64, _>("postal_codes") as u64,
+    }
+}
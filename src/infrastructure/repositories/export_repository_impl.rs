@@ -0,0 +1,176 @@
+//! Streaming every active unit of one level out of the database
+//!
+//! Rows are read inside a repeatable-read transaction on a task of their own
+//! and handed over through a bounded channel, so the whole level is never
+//! held in memory and the fingerprint matches the rows exactly.
+
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use sqlx::{postgres::PgRow, PgConnection, PgPool, Row};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::domain::entities::export::{ExportRow, ExportSnapshot};
+use crate::domain::entities::unit_level::UnitLevel;
+use crate::domain::repositories::export_repository::ExportRepository;
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::repositories::search_repository_impl::DATA_FINGERPRINT_SQL;
+
+/// Rows fetched ahead of the consumer
+const EXPORT_BUFFER_ROWS: usize = 512;
+
+const STATES_SQL: &str = "SELECT s.id, s.code AS state_code, s.name AS state_name,
+        NULL::text AS lga_code, NULL::text AS lga_name, NULL::text AS ward_code,
+        NULL::text AS ward_name, NULL::text AS postal_code, NULL::float8 AS lat,
+        NULL::float8 AS lng, NULL::bool AS urban, s.created_at, s.updated_at
+    FROM states s
+    WHERE s.status = 'active'
+    ORDER BY s.code";
+
+const LGAS_SQL: &str = "SELECT l.id, s.code AS state_code, s.name AS state_name,
+        l.code AS lga_code, l.name AS lga_name, NULL::text AS ward_code,
+        NULL::text AS ward_name, NULL::text AS postal_code, NULL::float8 AS lat,
+        NULL::float8 AS lng, NULL::bool AS urban, l.created_at, l.updated_at
+    FROM lgas l
+    JOIN states s ON s.id = l.state_id
+    WHERE l.status = 'active' AND s.status = 'active'
+    ORDER BY l.code";
+
+const WARDS_SQL: &str = "SELECT w.id, s.code AS state_code, s.name AS state_name,
+        l.code AS lga_code, l.name AS lga_name, w.code AS ward_code,
+        w.name AS ward_name, NULL::text AS postal_code, NULL::float8 AS lat,
+        NULL::float8 AS lng, NULL::bool AS urban, w.created_at, w.updated_at
+    FROM wards w
+    JOIN lgas l ON l.id = w.lga_id
+    JOIN states s ON s.id = l.state_id
+    WHERE w.status = 'active' AND l.status = 'active' AND s.status = 'active'
+    ORDER BY w.code";
+
+const POSTAL_CODES_SQL: &str = "SELECT p.id, s.code AS state_code, s.name AS state_name,
+        l.code AS lga_code, l.name AS lga_name, w.code AS ward_code,
+        w.name AS ward_name, p.postal_code, p.lat, p.lng, p.urban, p.created_at,
+        p.updated_at
+    FROM postal_codes p
+    JOIN wards w ON w.id = p.ward_id
+    JOIN lgas l ON l.id = w.lga_id
+    JOIN states s ON s.id = l.state_id
+    WHERE p.status = 'active' AND w.status = 'active' AND l.status = 'active'
+        AND s.status = 'active'
+    ORDER BY p.postal_code, w.code";
+
+/// Query for every active unit of `level` whose ancestors are all active,
+/// ordered by code, so each row's parent is exported too
+pub(crate) fn level_sql(level: UnitLevel) -> &'static str {
+    match level {
+        UnitLevel::State => STATES_SQL,
+        UnitLevel::Lga => LGAS_SQL,
+        UnitLevel::Ward => WARDS_SQL,
+        UnitLevel::PostalCode => POSTAL_CODES_SQL,
+    }
+}
+
+/// PostgreSQL implementation of ExportRepository
+#[derive(Clone)]
+pub struct PostgresExportRepository {
+    pool: PgPool,
+}
+
+impl PostgresExportRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ExportRepository for PostgresExportRepository {
+    async fn data_fingerprint(&self) -> AppResult<String> {
+        let fingerprint = sqlx::query_scalar::<_, String>(DATA_FINGERPRINT_SQL)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(fingerprint)
+    }
+
+    async fn export(&self, level: UnitLevel) -> AppResult<ExportSnapshot> {
+        let sql = level_sql(level);
+        let pool = self.pool.clone();
+        let (fingerprint_sender, fingerprint) = oneshot::channel();
+        let (row_sender, rows) = mpsc::channel(EXPORT_BUFFER_ROWS);
+        tokio::spawn(async move {
+            let mut tx = match begin_snapshot(&pool).await {
+                Ok((tx, fingerprint)) => {
+                    let _ = fingerprint_sender.send(Ok(fingerprint));
+                    tx
+                }
+                Err(e) => {
+                    let _ = fingerprint_sender.send(Err(e));
+                    return;
+                }
+            };
+
+            if let Err(e) = send_rows(&mut tx, sql, &row_sender).await {
+                let _ = row_sender.send(Err(e)).await;
+            }
+        });
+
+        let fingerprint = fingerprint
+            .await
+            .map_err(|e| AppError::Internal(e.into()))??;
+        let rows = stream::unfold(rows, |mut rows| async move {
+            rows.recv().await.map(|row| (row, rows))
+        });
+
+        Ok(ExportSnapshot {
+            fingerprint,
+            level,
+            rows: rows.boxed(),
+        })
+    }
+}
+
+/// Open a read-only snapshot of the data and take its fingerprint
+pub(crate) async fn begin_snapshot(
+    pool: &PgPool,
+) -> AppResult<(sqlx::Transaction<'static, sqlx::Postgres>, String)> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+        .execute(&mut *tx)
+        .await?;
+    let fingerprint = sqlx::query_scalar::<_, String>(DATA_FINGERPRINT_SQL)
+        .fetch_one(&mut *tx)
+        .await?;
+
+    Ok((tx, fingerprint))
+}
+
+/// Send the rows of `sql` until they run out or the consumer goes away
+async fn send_rows(
+    connection: &mut PgConnection,
+    sql: &'static str,
+    sender: &mpsc::Sender<AppResult<ExportRow>>,
+) -> AppResult<()> {
+    let mut rows = sqlx::query(sql).fetch(connection);
+    while let Some(row) = rows.try_next().await? {
+        if sender.send(Ok(export_row_from_row(&row))).await.is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn export_row_from_row(row: &PgRow) -> ExportRow {
+    ExportRow {
+        id: row.get("id"),
+        state_code: row.get("state_code"),
+        state_name: row.get("state_name"),
+        lga_code: row.get("lga_code"),
+        lga_name: row.get("lga_name"),
+        ward_code: row.get("ward_code"),
+        ward_name: row.get("ward_name"),
+        postal_code: row.get("postal_code"),
+        lat: row.get("lat"),
+        lng: row.get("lng"),
+        urban: row.get("urban"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
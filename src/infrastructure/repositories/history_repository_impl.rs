@@ -0,0 +1,209 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{postgres::PgRow, PgConnection, PgPool, Row};
+use uuid::Uuid;
+
+use crate::domain::audit::current_actor;
+use crate::domain::entities::change::{Change, ChangeEntityType, ChangeOperation, EntityDiff};
+use crate::domain::pagination::{scoped, KeysetPage, PageRequest};
+use crate::domain::repositories::history_repository::HistoryRepository;
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::repositories::keyset::{self, cursor_key, key_value};
+
+/// Listing name carried in cursors over an entity's history
+const ENTITY_HISTORY: &str = "entity_history";
+
+/// Net change per entity over the history rows matched by a `WHERE` clause
+/// placed between this and [`DIFF_GROUPING`]
+const DIFF_SELECT: &str = "SELECT entity_type, entity_id,
+        (array_agg(before ORDER BY id))[1] AS before,
+        (array_agg(after ORDER BY id DESC))[1] AS after,
+        COUNT(*) AS changes,
+        MAX(changed_at) AS last_changed_at
+    FROM change_history";
+
+const DIFF_GROUPING: &str = "GROUP BY entity_type, entity_id ORDER BY MIN(id)";
+
+/// PostgreSQL implementation of HistoryRepository
+#[derive(Clone)]
+pub struct PostgresHistoryRepository {
+    pool: PgPool,
+}
+
+impl PostgresHistoryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Append a change to the history, on the connection making the change so
+/// that both commit or neither does
+///
+/// `before` is `None` for a create and `after` `None` for a delete. The change
+/// is attributed to [`current_actor`].
+pub(crate) async fn record<T: Serialize>(
+    connection: &mut PgConnection,
+    entity_type: ChangeEntityType,
+    entity_id: Uuid,
+    before: Option<&T>,
+    after: Option<&T>,
+) -> AppResult<()> {
+    let before = before.map(serde_json::to_value).transpose()?;
+    let after = after.map(serde_json::to_value).transpose()?;
+    let operation = ChangeOperation::between(before.as_ref(), after.as_ref()).ok_or_else(|| {
+        AppError::Internal(anyhow::anyhow!(
+            "change to {} {} has no snapshot",
+            entity_type.as_str(),
+            entity_id
+        ))
+    })?;
+
+    sqlx::query(
+        "INSERT INTO change_history (entity_type, entity_id, operation, before, after, actor)
+         VALUES ($1, $2, $3, $4, $5, $6)",
+    )
+    .bind(entity_type.as_str())
+    .bind(entity_id)
+    .bind(operation.as_str())
+    .bind(before)
+    .bind(after)
+    .bind(current_actor())
+    .execute(connection)
+    .await?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl HistoryRepository for PostgresHistoryRepository {
+    async fn find_by_entity(
+        &self,
+        entity_type: ChangeEntityType,
+        entity_id: Uuid,
+        request: &PageRequest,
+    ) -> AppResult<KeysetPage<Change>> {
+        let listing = scoped(
+            ENTITY_HISTORY,
+            format_args!("{}:{}", entity_type.as_str(), entity_id),
+        );
+        let boundary = match cursor_key::<1>(request, &listing)? {
+            Some([id]) => Some(key_value::<i64>(id)?),
+            None => None,
+        };
+
+        let rows = sqlx::query(&format!(
+            "SELECT id, entity_type, entity_id, operation, before, after, actor, changed_at
+             FROM change_history
+             WHERE entity_type = $1 AND entity_id = $2 AND ($3::bigint IS NULL OR id {seek} $3)
+             ORDER BY id {order} LIMIT $4 OFFSET $5",
+            seek = keyset::seek(request),
+            order = keyset::order(request),
+        ))
+        .bind(entity_type.as_str())
+        .bind(entity_id)
+        .bind(boundary)
+        .bind(request.fetch_limit() as i64)
+        .bind(request.offset() as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let changes = rows
+            .iter()
+            .map(change_from_row)
+            .collect::<AppResult<Vec<_>>>()?;
+
+        Ok(KeysetPage::from_rows(
+            changes,
+            request,
+            &listing,
+            |change| vec![change.id.to_string()],
+        ))
+    }
+
+    async fn count_by_entity(
+        &self,
+        entity_type: ChangeEntityType,
+        entity_id: Uuid,
+    ) -> AppResult<u64> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM change_history WHERE entity_type = $1 AND entity_id = $2",
+        )
+        .bind(entity_type.as_str())
+        .bind(entity_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count as u64)
+    }
+
+    async fn diff(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        entity_type: Option<ChangeEntityType>,
+    ) -> AppResult<Vec<EntityDiff>> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE changed_at > $1 AND changed_at <= $2 AND ($3::text IS NULL OR entity_type = $3) {}",
+            DIFF_SELECT, DIFF_GROUPING
+        ))
+        .bind(from)
+        .bind(to)
+        .bind(entity_type.map(|t| t.as_str()))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(diff_from_row).collect()
+    }
+
+    async fn diff_between_ids(&self, after_id: i64, up_to_id: i64) -> AppResult<Vec<EntityDiff>> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE id > $1 AND id <= $2 {}",
+            DIFF_SELECT, DIFF_GROUPING
+        ))
+        .bind(after_id)
+        .bind(up_to_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(diff_from_row).collect()
+    }
+}
+
+fn diff_from_row(row: &PgRow) -> AppResult<EntityDiff> {
+    Ok(EntityDiff {
+        entity_type: parse_entity_type(row)?,
+        entity_id: row.get("entity_id"),
+        before: row.get("before"),
+        after: row.get("after"),
+        changes: row.get::<i64, _>("changes") as u64,
+        last_changed_at: row.get("last_changed_at"),
+    })
+}
+
+fn change_from_row(row: &PgRow) -> AppResult<Change> {
+    let operation: String = row.get("operation");
+
+    Ok(Change {
+        id: row.get("id"),
+        entity_type: parse_entity_type(row)?,
+        entity_id: row.get("entity_id"),
+        operation: ChangeOperation::parse(&operation).ok_or_else(|| {
+            AppError::Internal(anyhow::anyhow!("Unknown change operation {}", operation))
+        })?,
+        before: row.get("before"),
+        after: row.get("after"),
+        actor: row.get("actor"),
+        changed_at: row.get("changed_at"),
+    })
+}
+
+fn parse_entity_type(row: &PgRow) -> AppResult<ChangeEntityType> {
+    let entity_type: String = row.get("entity_type");
+    ChangeEntityType::parse(&entity_type).ok_or_else(|| {
+        AppError::Internal(anyhow::anyhow!(
+            "Unknown change entity type {}",
+            entity_type
+        ))
+    })
+}
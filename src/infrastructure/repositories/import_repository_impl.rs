@@ -0,0 +1,491 @@
+//! Bulk creation and update of states, LGAs, wards and postal codes
+//!
+//! Each unit is written under its own savepoint inside one transaction, so a
+//! unit the database refuses is rolled back and rejected on its own while the
+//! rest of the import goes ahead.
+
+use async_trait::async_trait;
+use sqlx::{Connection, PgConnection, PgPool, Row};
+use uuid::Uuid;
+
+use crate::domain::entities::change::ChangeEntityType;
+use crate::domain::entities::import::{ImportOutcome, ImportUnit};
+use crate::domain::entities::lga::Lga;
+use crate::domain::entities::postal_code::PostalCode;
+use crate::domain::entities::state::State;
+use crate::domain::entities::unit_status::UnitStatus;
+use crate::domain::entities::ward::Ward;
+use crate::domain::normalization::normalized;
+use crate::domain::phonetic::phonetic_key;
+use crate::domain::repositories::import_repository::ImportRepository;
+use crate::domain::value_objects::{
+    Coordinates, LgaCode, PostalCode as PostalCodeValue, StateCode, WardCode,
+};
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::repositories::history_repository_impl as history;
+use crate::infrastructure::repositories::lga_repository_impl::{duplicate_lga, lga_from_row};
+use crate::infrastructure::repositories::postal_code_repository_impl::{
+    duplicate_postal_code, postal_code_from_row,
+};
+use crate::infrastructure::repositories::state_repository_impl::{duplicate_state, state_from_row};
+use crate::infrastructure::repositories::ward_repository_impl::{duplicate_ward, ward_from_row};
+
+/// A level units are attached to, as used to look up parents
+struct Parent {
+    table: &'static str,
+    /// Used at the start of messages
+    noun: &'static str,
+}
+
+const STATE: Parent = Parent {
+    table: "states",
+    noun: "State",
+};
+
+const LGA: Parent = Parent {
+    table: "lgas",
+    noun: "LGA",
+};
+
+const WARD: Parent = Parent {
+    table: "wards",
+    noun: "Ward",
+};
+
+/// PostgreSQL implementation of ImportRepository
+#[derive(Clone)]
+pub struct PostgresImportRepository {
+    pool: PgPool,
+}
+
+impl PostgresImportRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ImportRepository for PostgresImportRepository {
+    async fn import(&self, units: &[ImportUnit], dry_run: bool) -> AppResult<Vec<ImportOutcome>> {
+        let mut tx = self.pool.begin().await?;
+        let mut outcomes = Vec::with_capacity(units.len());
+
+        for unit in units {
+            let mut savepoint = tx.begin().await?;
+            match import_unit(&mut savepoint, unit).await {
+                Ok(outcome) => {
+                    savepoint.commit().await?;
+                    outcomes.push(outcome);
+                }
+                Err(error) => {
+                    let reason = rejection(error)?;
+                    savepoint.rollback().await?;
+                    outcomes.push(ImportOutcome::Rejected(reason));
+                }
+            }
+        }
+
+        if dry_run {
+            tx.rollback().await?;
+        } else {
+            tx.commit().await?;
+        }
+        Ok(outcomes)
+    }
+}
+
+async fn import_unit(connection: &mut PgConnection, unit: &ImportUnit) -> AppResult<ImportOutcome> {
+    match unit {
+        ImportUnit::State { code, name } => import_state(connection, code, name).await,
+        ImportUnit::Lga { code, name } => import_lga(connection, code, name).await,
+        ImportUnit::Ward { code, name } => import_ward(connection, code, name).await,
+        ImportUnit::PostalCode {
+            postal_code,
+            ward_code,
+            coordinates,
+            urban,
+        } => import_postal_code(connection, postal_code, ward_code, coordinates, *urban).await,
+    }
+}
+
+async fn import_state(
+    connection: &mut PgConnection,
+    code: &StateCode,
+    name: &str,
+) -> AppResult<ImportOutcome> {
+    let row = sqlx::query(
+        "SELECT id, name, code, status, successor_id, created_at, updated_at FROM states WHERE code = $1",
+    )
+    .bind(code.as_str())
+    .fetch_optional(&mut *connection)
+    .await?;
+
+    let Some(mut state) = row.as_ref().map(state_from_row).transpose()? else {
+        let state = State::new(name.to_string(), code.clone());
+        sqlx::query(
+            "INSERT INTO states (id, name, normalized_name, phonetic_key, code, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(state.id)
+        .bind(&state.name)
+        .bind(normalized(&state.name))
+        .bind(phonetic_key(&state.name))
+        .bind(state.code.as_str())
+        .bind(state.created_at)
+        .bind(state.updated_at)
+        .execute(&mut *connection)
+        .await
+        .map_err(|e| duplicate_state(e, &state))?;
+
+        history::record(
+            connection,
+            ChangeEntityType::State,
+            state.id,
+            None,
+            Some(&state),
+        )
+        .await?;
+        return Ok(ImportOutcome::Created);
+    };
+
+    if let Some(rejected) = retired(STATE.noun, code.as_str(), state.status) {
+        return Ok(rejected);
+    }
+    if state.name == name {
+        return Ok(ImportOutcome::Unchanged);
+    }
+
+    let before = state.clone();
+    state.update_name(name.to_string());
+    sqlx::query(
+        "UPDATE states SET name = $1, normalized_name = $2, phonetic_key = $3, updated_at = $4 WHERE id = $5",
+    )
+    .bind(&state.name)
+    .bind(normalized(&state.name))
+    .bind(phonetic_key(&state.name))
+    .bind(state.updated_at)
+    .bind(state.id)
+    .execute(&mut *connection)
+    .await
+    .map_err(|e| duplicate_state(e, &state))?;
+
+    history::record(
+        connection,
+        ChangeEntityType::State,
+        state.id,
+        Some(&before),
+        Some(&state),
+    )
+    .await?;
+    Ok(ImportOutcome::Updated)
+}
+
+async fn import_lga(
+    connection: &mut PgConnection,
+    code: &LgaCode,
+    name: &str,
+) -> AppResult<ImportOutcome> {
+    let row = sqlx::query(
+        "SELECT id, state_id, name, code, status, successor_id, created_at, updated_at FROM lgas WHERE code = $1",
+    )
+    .bind(code.as_str())
+    .fetch_optional(&mut *connection)
+    .await?;
+
+    let Some(mut lga) = row.as_ref().map(lga_from_row).transpose()? else {
+        let state_id = match active_parent(connection, &STATE, code.state_code().as_str()).await? {
+            Ok(state_id) => state_id,
+            Err(rejected) => return Ok(rejected),
+        };
+
+        let lga = Lga::new(state_id, name.to_string(), code.clone());
+        sqlx::query(
+            "INSERT INTO lgas (id, state_id, name, normalized_name, phonetic_key, code, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+        )
+        .bind(lga.id)
+        .bind(lga.state_id)
+        .bind(&lga.name)
+        .bind(normalized(&lga.name))
+        .bind(phonetic_key(&lga.name))
+        .bind(lga.code.as_str())
+        .bind(lga.created_at)
+        .bind(lga.updated_at)
+        .execute(&mut *connection)
+        .await
+        .map_err(|e| duplicate_lga(e, &lga))?;
+
+        history::record(connection, ChangeEntityType::Lga, lga.id, None, Some(&lga)).await?;
+        return Ok(ImportOutcome::Created);
+    };
+
+    if let Some(rejected) = retired(LGA.noun, code.as_str(), lga.status) {
+        return Ok(rejected);
+    }
+    if lga.name == name {
+        return Ok(ImportOutcome::Unchanged);
+    }
+
+    let before = lga.clone();
+    lga.update_name(name.to_string());
+    sqlx::query(
+        "UPDATE lgas SET name = $1, normalized_name = $2, phonetic_key = $3, updated_at = $4 WHERE id = $5",
+    )
+    .bind(&lga.name)
+    .bind(normalized(&lga.name))
+    .bind(phonetic_key(&lga.name))
+    .bind(lga.updated_at)
+    .bind(lga.id)
+    .execute(&mut *connection)
+    .await
+    .map_err(|e| duplicate_lga(e, &lga))?;
+
+    history::record(
+        connection,
+        ChangeEntityType::Lga,
+        lga.id,
+        Some(&before),
+        Some(&lga),
+    )
+    .await?;
+    Ok(ImportOutcome::Updated)
+}
+
+async fn import_ward(
+    connection: &mut PgConnection,
+    code: &WardCode,
+    name: &str,
+) -> AppResult<ImportOutcome> {
+    let row = sqlx::query(
+        "SELECT id, lga_id, name, code, status, successor_id, created_at, updated_at FROM wards WHERE code = $1",
+    )
+    .bind(code.as_str())
+    .fetch_optional(&mut *connection)
+    .await?;
+
+    let Some(mut ward) = row.as_ref().map(ward_from_row).transpose()? else {
+        let lga_id = match active_parent(connection, &LGA, code.lga_code().as_str()).await? {
+            Ok(lga_id) => lga_id,
+            Err(rejected) => return Ok(rejected),
+        };
+
+        let ward = Ward::new(lga_id, name.to_string(), code.clone());
+        sqlx::query(
+            "INSERT INTO wards (id, lga_id, name, normalized_name, phonetic_key, code, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+        )
+        .bind(ward.id)
+        .bind(ward.lga_id)
+        .bind(&ward.name)
+        .bind(normalized(&ward.name))
+        .bind(phonetic_key(&ward.name))
+        .bind(ward.code.as_str())
+        .bind(ward.created_at)
+        .bind(ward.updated_at)
+        .execute(&mut *connection)
+        .await
+        .map_err(|e| duplicate_ward(e, &ward))?;
+
+        history::record(
+            connection,
+            ChangeEntityType::Ward,
+            ward.id,
+            None,
+            Some(&ward),
+        )
+        .await?;
+        return Ok(ImportOutcome::Created);
+    };
+
+    if let Some(rejected) = retired(WARD.noun, code.as_str(), ward.status) {
+        return Ok(rejected);
+    }
+    if ward.name == name {
+        return Ok(ImportOutcome::Unchanged);
+    }
+
+    let before = ward.clone();
+    ward.update_name(name.to_string());
+    sqlx::query(
+        "UPDATE wards SET name = $1, normalized_name = $2, phonetic_key = $3, updated_at = $4 WHERE id = $5",
+    )
+    .bind(&ward.name)
+    .bind(normalized(&ward.name))
+    .bind(phonetic_key(&ward.name))
+    .bind(ward.updated_at)
+    .bind(ward.id)
+    .execute(&mut *connection)
+    .await
+    .map_err(|e| duplicate_ward(e, &ward))?;
+
+    history::record(
+        connection,
+        ChangeEntityType::Ward,
+        ward.id,
+        Some(&before),
+        Some(&ward),
+    )
+    .await?;
+    Ok(ImportOutcome::Updated)
+}
+
+/// Postal codes are matched within their ward
+async fn import_postal_code(
+    connection: &mut PgConnection,
+    value: &PostalCodeValue,
+    ward_code: &WardCode,
+    coordinates: &Option<Coordinates>,
+    urban: Option<bool>,
+) -> AppResult<ImportOutcome> {
+    let Some((ward_id, ward_status)) = parent(connection, &WARD, ward_code.as_str()).await? else {
+        return Ok(missing(&WARD, ward_code.as_str()));
+    };
+
+    let row = sqlx::query(
+        "SELECT id, ward_id, postal_code, lat, lng, urban, status, successor_id, created_at, updated_at FROM postal_codes WHERE ward_id = $1 AND postal_code = $2",
+    )
+    .bind(ward_id)
+    .bind(value.as_str())
+    .fetch_optional(&mut *connection)
+    .await?;
+
+    let Some(mut postal_code) = row.as_ref().map(postal_code_from_row).transpose()? else {
+        if ward_status != UnitStatus::Active.as_str() {
+            return Ok(closed(&WARD, ward_code.as_str(), &ward_status));
+        }
+
+        let postal_code = PostalCode::new(
+            ward_id,
+            value.clone(),
+            coordinates.clone(),
+            urban.unwrap_or(false),
+        );
+        sqlx::query(
+            "INSERT INTO postal_codes (id, ward_id, postal_code, lat, lng, urban, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+        )
+        .bind(postal_code.id)
+        .bind(postal_code.ward_id)
+        .bind(postal_code.postal_code.as_str())
+        .bind(postal_code.coordinates.as_ref().map(|c| c.latitude))
+        .bind(postal_code.coordinates.as_ref().map(|c| c.longitude))
+        .bind(postal_code.urban)
+        .bind(postal_code.created_at)
+        .bind(postal_code.updated_at)
+        .execute(&mut *connection)
+        .await
+        .map_err(|e| duplicate_postal_code(e, &postal_code))?;
+
+        history::record(
+            connection,
+            ChangeEntityType::PostalCode,
+            postal_code.id,
+            None,
+            Some(&postal_code),
+        )
+        .await?;
+        return Ok(ImportOutcome::Created);
+    };
+
+    if let Some(rejected) = retired("Postal code", value.as_str(), postal_code.status) {
+        return Ok(rejected);
+    }
+    let coordinates = coordinates
+        .clone()
+        .or_else(|| postal_code.coordinates.clone());
+    let urban = urban.unwrap_or(postal_code.urban);
+    if postal_code.coordinates == coordinates && postal_code.urban == urban {
+        return Ok(ImportOutcome::Unchanged);
+    }
+
+    let before = postal_code.clone();
+    postal_code.update_coordinates(coordinates);
+    postal_code.update_urban_status(urban);
+    sqlx::query(
+        "UPDATE postal_codes SET lat = $1, lng = $2, urban = $3, updated_at = $4 WHERE id = $5",
+    )
+    .bind(postal_code.coordinates.as_ref().map(|c| c.latitude))
+    .bind(postal_code.coordinates.as_ref().map(|c| c.longitude))
+    .bind(postal_code.urban)
+    .bind(postal_code.updated_at)
+    .bind(postal_code.id)
+    .execute(&mut *connection)
+    .await?;
+
+    history::record(
+        connection,
+        ChangeEntityType::PostalCode,
+        postal_code.id,
+        Some(&before),
+        Some(&postal_code),
+    )
+    .await?;
+    Ok(ImportOutcome::Updated)
+}
+
+/// ID and status of the parent with `code`
+async fn parent(
+    connection: &mut PgConnection,
+    parent: &Parent,
+    code: &str,
+) -> AppResult<Option<(Uuid, String)>> {
+    let row = sqlx::query(&format!(
+        "SELECT id, status FROM {} WHERE code = $1",
+        parent.table
+    ))
+    .bind(code)
+    .fetch_optional(&mut *connection)
+    .await?;
+
+    Ok(row.map(|row| (row.get("id"), row.get("status"))))
+}
+
+/// ID of the parent with `code`, or the rejection when it is missing or
+/// retired
+async fn active_parent(
+    connection: &mut PgConnection,
+    level: &Parent,
+    code: &str,
+) -> AppResult<Result<Uuid, ImportOutcome>> {
+    Ok(match parent(connection, level, code).await? {
+        Some((id, status)) if status == UnitStatus::Active.as_str() => Ok(id),
+        Some((_, status)) => Err(closed(level, code, &status)),
+        None => Err(missing(level, code)),
+    })
+}
+
+fn closed(level: &Parent, code: &str, status: &str) -> ImportOutcome {
+    ImportOutcome::Rejected(format!(
+        "{} {} is {}; new units cannot be added to it",
+        level.noun, code, status
+    ))
+}
+
+fn missing(level: &Parent, code: &str) -> ImportOutcome {
+    ImportOutcome::Rejected(format!(
+        "{} {} does not exist; import it first",
+        level.noun, code
+    ))
+}
+
+/// Retired units are left as they are; the admin API reactivates them
+fn retired(noun: &str, code: &str, status: UnitStatus) -> Option<ImportOutcome> {
+    (status != UnitStatus::Active).then(|| {
+        ImportOutcome::Rejected(format!(
+            "{} {} is {}; reactivate it before importing it",
+            noun,
+            code,
+            status.as_str()
+        ))
+    })
+}
+
+/// The reason to reject a unit the database refused, or the error when the
+/// import cannot go on
+fn rejection(error: AppError) -> AppResult<String> {
+    match error {
+        AppError::Conflict(message) => Ok(message),
+        AppError::Database(sqlx::Error::Database(e))
+            if e.is_check_violation() || e.is_foreign_key_violation() =>
+        {
+            Ok(e.message().to_string())
+        }
+        error => Err(error),
+    }
+}
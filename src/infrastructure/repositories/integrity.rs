@@ -0,0 +1,74 @@
+//! Checks that keep the state → LGA → ward → postal code hierarchy consistent
+//!
+//! LGA and ward codes start with their parent's code, so a parent's code is
+//! fixed while it has children.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::errors::{AppError, AppResult};
+
+/// The children of one level of the hierarchy
+pub(crate) struct Children {
+    /// The parent, with its article, as used in messages
+    parent: &'static str,
+    /// One child
+    noun: &'static str,
+    /// More than one
+    nouns: &'static str,
+    /// Counts a parent's children
+    count_sql: &'static str,
+}
+
+pub(crate) const STATE_LGAS: Children = Children {
+    parent: "a state",
+    noun: "LGA",
+    nouns: "LGAs",
+    count_sql: "SELECT COUNT(*) FROM lgas WHERE state_id = $1",
+};
+
+pub(crate) const LGA_WARDS: Children = Children {
+    parent: "an LGA",
+    noun: "ward",
+    nouns: "wards",
+    count_sql: "SELECT COUNT(*) FROM wards WHERE lga_id = $1",
+};
+
+/// Refuse to `action` a parent that still has children
+pub(crate) async fn ensure_no_children(
+    pool: &PgPool,
+    children: &Children,
+    parent_id: Uuid,
+    action: &str,
+) -> AppResult<()> {
+    let count: i64 = sqlx::query_scalar(children.count_sql)
+        .bind(parent_id)
+        .fetch_one(pool)
+        .await?;
+
+    if count > 0 {
+        return Err(AppError::Conflict(format!(
+            "Cannot {} {} that still has {} {}",
+            action,
+            children.parent,
+            count,
+            if count == 1 {
+                children.noun
+            } else {
+                children.nouns
+            }
+        )));
+    }
+    Ok(())
+}
+
+/// Report a write that broke a unique constraint as a conflict
+pub(crate) fn conflict_on_duplicate(
+    error: sqlx::Error,
+    message: impl FnOnce() -> String,
+) -> AppError {
+    match &error {
+        sqlx::Error::Database(e) if e.is_unique_violation() => AppError::Conflict(message()),
+        _ => error.into(),
+    }
+}
@@ -0,0 +1,42 @@
+//! SQL fragments for reading a listing by [`PageRequest`]
+//!
+//! Listings sort ascending on their key columns. A page after a cursor takes
+//! rows whose key is greater than the cursor's; a page before it takes rows
+//! whose key is smaller, read in descending order so that `LIMIT` keeps the
+//! rows nearest the cursor. [`KeysetPage::from_rows`] turns them back round.
+//!
+//! [`KeysetPage::from_rows`]: crate::domain::pagination::KeysetPage::from_rows
+
+use std::str::FromStr;
+
+use crate::domain::pagination::{CursorError, Direction, PageRequest};
+use crate::errors::AppResult;
+
+/// Operator comparing a row's sort key with the cursor's
+pub(crate) fn seek(request: &PageRequest) -> &'static str {
+    match request.direction() {
+        Direction::After => ">",
+        Direction::Before => "<",
+    }
+}
+
+/// Order ascending sort columns are read in
+pub(crate) fn order(request: &PageRequest) -> &'static str {
+    match request.direction() {
+        Direction::After => "ASC",
+        Direction::Before => "DESC",
+    }
+}
+
+/// Sort key of the request's cursor, if it has one, checked against `listing`
+pub(crate) fn cursor_key<'r, const N: usize>(
+    request: &'r PageRequest,
+    listing: &str,
+) -> AppResult<Option<&'r [String; N]>> {
+    Ok(request.cursor().map(|c| c.key(listing)).transpose()?)
+}
+
+/// Parse one value of a cursor's sort key
+pub(crate) fn key_value<T: FromStr>(value: &str) -> AppResult<T> {
+    value.parse().map_err(|_| CursorError::Malformed.into())
+}
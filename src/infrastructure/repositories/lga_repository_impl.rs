@@ -1,11 +1,18 @@
 use async_trait::async_trait;
-use sqlx::{PgPool, Row};
+use sqlx::{postgres::PgRow, PgPool, Row};
 use uuid::Uuid;
 
+use crate::domain::entities::change::ChangeEntityType;
 use crate::domain::entities::lga::{Lga, CreateLgaRequest, UpdateLgaRequest};
+use crate::domain::entities::unit_status::{UnitStatus, UpdateStatusRequest};
+use crate::domain::normalization::normalized;
+use crate::domain::phonetic::phonetic_key;
 use crate::domain::repositories::lga_repository::LgaRepository;
 use crate::domain::value_objects::{LgaCode, LgaCodeError};
 use crate::errors::AppResult;
+use crate::infrastructure::repositories::history_repository_impl as history;
+use crate::infrastructure::repositories::integrity::{self, LGA_WARDS};
+use crate::infrastructure::repositories::lifecycle;
 
 /// PostgreSQL implementation of LgaRepository
 #[derive(Clone)]
@@ -17,114 +24,100 @@ impl PostgresLgaRepository {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Reject LGA codes whose state part does not match the parent state
+    async fn ensure_code_matches_state(&self, code: &LgaCode, state_id: Uuid) -> AppResult<()> {
+        let state_code: Option<String> = sqlx::query_scalar("SELECT code FROM states WHERE id = $1")
+            .bind(state_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        match state_code {
+            Some(state_code) if state_code == code.state_code().as_str() => Ok(()),
+            Some(state_code) => Err(crate::errors::AppError::BadRequest(format!(
+                "LGA code {} does not belong to state {}", code, state_code
+            ))),
+            None => Err(crate::errors::AppError::NotFound("State not found".to_string())),
+        }
+    }
 }
 
 #[async_trait]
 impl LgaRepository for PostgresLgaRepository {
     async fn create(&self, request: &CreateLgaRequest, state_id: Uuid) -> AppResult<Lga> {
         let lga_code = LgaCode::new(request.code.clone())
-            .map_err(|e: LgaCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+            .map_err(|e| crate::errors::AppError::invalid_param("code", e))?;
+        self.ensure_code_matches_state(&lga_code, state_id).await?;
 
         let lga = Lga::new(state_id, request.name.clone(), lga_code);
 
+        let mut tx = self.pool.begin().await?;
+        lifecycle::ensure_active_parent(&mut tx, &lifecycle::STATES, state_id, "an LGA").await?;
         sqlx::query(
-            "INSERT INTO lgas (id, state_id, name, code, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6)"
+            "INSERT INTO lgas (id, state_id, name, normalized_name, phonetic_key, code, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"
         )
         .bind(&lga.id)
         .bind(&lga.state_id)
         .bind(&lga.name)
+        .bind(normalized(&lga.name))
+        .bind(phonetic_key(&lga.name))
         .bind(lga.code.to_string())
         .bind(&lga.created_at)
         .bind(&lga.updated_at)
-        .execute(&self.pool)
-        .await?;
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| duplicate_lga(e, &lga))?;
+
+        history::record(&mut tx, ChangeEntityType::Lga, lga.id, None, Some(&lga)).await?;
+        tx.commit().await?;
 
         Ok(lga)
     }
 
     async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Lga>> {
         let row = sqlx::query(
-            "SELECT id, state_id, name, code, created_at, updated_at FROM lgas WHERE id = $1"
+            "SELECT id, state_id, name, code, status, successor_id, created_at, updated_at FROM lgas WHERE id = $1"
         )
         .bind(&id)
         .fetch_optional(&self.pool)
         .await?;
 
-        match row {
-            Some(row) => {
-                let lga_code = LgaCode::new(row.get::<String, _>("code"))
-                    .map_err(|e: LgaCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-                
-                Ok(Some(Lga {
-                    id: row.get("id"),
-                    state_id: row.get("state_id"),
-                    name: row.get("name"),
-                    code: lga_code,
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                }))
-            }
-            None => Ok(None),
-        }
+        row.as_ref().map(lga_from_row).transpose()
     }
 
     async fn find_by_name(&self, name: &str) -> AppResult<Option<Lga>> {
         let row = sqlx::query(
-            "SELECT id, state_id, name, code, created_at, updated_at FROM lgas WHERE name = $1"
+            "SELECT id, state_id, name, code, status, successor_id, created_at, updated_at FROM lgas
+             WHERE name = $1 OR normalized_name = $2
+                OR id IN (SELECT entity_id FROM aliases
+                          WHERE entity_type = 'lga' AND (lower(name) = lower($1) OR normalized_name = $2))
+             ORDER BY status = 'active' DESC, name = $1 DESC, normalized_name = $2 DESC, name COLLATE \"C\", id
+             LIMIT 1"
         )
         .bind(name)
+        .bind(normalized(name))
         .fetch_optional(&self.pool)
         .await?;
 
-        match row {
-            Some(row) => {
-                let lga_code = LgaCode::new(row.get::<String, _>("code"))
-                    .map_err(|e: LgaCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-                
-                Ok(Some(Lga {
-                    id: row.get("id"),
-                    state_id: row.get("state_id"),
-                    name: row.get("name"),
-                    code: lga_code,
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                }))
-            }
-            None => Ok(None),
-        }
+        row.as_ref().map(lga_from_row).transpose()
     }
 
     async fn find_by_code(&self, code: &LgaCode) -> AppResult<Option<Lga>> {
         let row = sqlx::query(
-            "SELECT id, state_id, name, code, created_at, updated_at FROM lgas WHERE code = $1"
+            "SELECT id, state_id, name, code, status, successor_id, created_at, updated_at FROM lgas WHERE code = $1"
         )
         .bind(code.to_string())
         .fetch_optional(&self.pool)
         .await?;
 
-        match row {
-            Some(row) => {
-                let lga_code = LgaCode::new(row.get::<String, _>("code"))
-                    .map_err(|e: LgaCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-                
-                Ok(Some(Lga {
-                    id: row.get("id"),
-                    state_id: row.get("state_id"),
-                    name: row.get("name"),
-                    code: lga_code,
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                }))
-            }
-            None => Ok(None),
-        }
+        row.as_ref().map(lga_from_row).transpose()
     }
 
     async fn find_by_state_id(&self, state_id: Uuid, page: u32, limit: u32) -> AppResult<Vec<Lga>> {
-        let offset = (page - 1) * limit;
+        let offset = page.saturating_sub(1) * limit;
         
         let rows = sqlx::query(
-            "SELECT id, state_id, name, code, created_at, updated_at FROM lgas WHERE state_id = $1 ORDER BY name LIMIT $2 OFFSET $3"
+            "SELECT id, state_id, name, code, status, successor_id, created_at, updated_at FROM lgas WHERE state_id = $1 AND status = 'active' ORDER BY name COLLATE \"C\", id LIMIT $2 OFFSET $3"
         )
         .bind(&state_id)
         .bind(limit as i64)
@@ -132,27 +125,24 @@ impl LgaRepository for PostgresLgaRepository {
         .fetch_all(&self.pool)
         .await?;
 
-        let mut lgas = Vec::new();
-        for row in rows {
-            let lga_code = LgaCode::new(row.get::<String, _>("code"))
-                .map_err(|e: LgaCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-            
-            lgas.push(Lga {
-                id: row.get("id"),
-                state_id: row.get("state_id"),
-                name: row.get("name"),
-                code: lga_code,
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            });
-        }
+        rows.iter().map(lga_from_row).collect()
+    }
+
+    async fn find_by_state_ids(&self, state_ids: &[Uuid]) -> AppResult<Vec<Lga>> {
+        let rows = sqlx::query(
+            "SELECT id, state_id, name, code, status, successor_id, created_at, updated_at FROM lgas WHERE state_id = ANY($1) AND status = 'active' ORDER BY name COLLATE \"C\", id"
+        )
+        .bind(state_ids)
+        .fetch_all(&self.pool)
+        .await?;
 
-        Ok(lgas)
+        rows.iter().map(lga_from_row).collect()
     }
 
     async fn update(&self, id: Uuid, request: &UpdateLgaRequest) -> AppResult<Lga> {
         let mut lga = self.find_by_id(id).await?
             .ok_or_else(|| crate::errors::AppError::NotFound("LGA not found".to_string()))?;
+        let before = lga.clone();
 
         if let Some(name) = &request.name {
             lga.update_name(name.clone());
@@ -160,38 +150,70 @@ impl LgaRepository for PostgresLgaRepository {
 
         if let Some(code) = &request.code {
             let lga_code = LgaCode::new(code.clone())
-                .map_err(|e: LgaCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+                .map_err(|e| crate::errors::AppError::invalid_param("code", e))?;
+            self.ensure_code_matches_state(&lga_code, lga.state_id).await?;
+            if lga_code != lga.code {
+                // Ward codes start with the LGA's
+                integrity::ensure_no_children(&self.pool, &LGA_WARDS, id, "change the code of").await?;
+            }
             lga.update_code(lga_code);
         }
 
+        let mut tx = self.pool.begin().await?;
         sqlx::query(
-            "UPDATE lgas SET name = $1, code = $2, updated_at = $3 WHERE id = $4"
+            "UPDATE lgas SET name = $1, code = $2, updated_at = $3, normalized_name = $5, phonetic_key = $6 WHERE id = $4"
         )
         .bind(&lga.name)
         .bind(lga.code.to_string())
         .bind(&lga.updated_at)
         .bind(&lga.id)
-        .execute(&self.pool)
-        .await?;
+        .bind(normalized(&lga.name))
+        .bind(phonetic_key(&lga.name))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| duplicate_lga(e, &lga))?;
+
+        history::record(&mut tx, ChangeEntityType::Lga, id, Some(&before), Some(&lga)).await?;
+        tx.commit().await?;
 
         Ok(lga)
     }
 
     async fn delete(&self, id: Uuid) -> AppResult<()> {
-        let result = sqlx::query("DELETE FROM lgas WHERE id = $1")
-            .bind(&id)
-            .execute(&self.pool)
-            .await?;
-
-        if result.rows_affected() == 0 {
-            return Err(crate::errors::AppError::NotFound("LGA not found".to_string()));
+        let lga = self.find_by_id(id).await?
+            .ok_or_else(|| crate::errors::AppError::NotFound("LGA not found".to_string()))?;
+        if lga.status != UnitStatus::Active {
+            return Ok(());
         }
 
+        let request = UpdateStatusRequest { status: UnitStatus::Deprecated, successor_id: None };
+        self.set_status(id, &request).await?;
         Ok(())
     }
 
+    async fn set_status(&self, id: Uuid, request: &UpdateStatusRequest) -> AppResult<Lga> {
+        let mut tx = self.pool.begin().await?;
+        let row = sqlx::query(
+            "SELECT id, state_id, name, code, status, successor_id, created_at, updated_at FROM lgas WHERE id = $1 FOR UPDATE"
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let mut lga = row.as_ref().map(lga_from_row).transpose()?
+            .ok_or_else(|| crate::errors::AppError::NotFound("LGA not found".to_string()))?;
+        lifecycle::ensure_status_change_allowed(&mut tx, &lifecycle::LGAS, id, Some((&lifecycle::STATES, lga.state_id)), request).await?;
+        let before = lga.clone();
+        lga.set_status(request.status, request.successor_id);
+
+        lifecycle::save_status(&mut tx, &lifecycle::LGAS, id, lga.status, lga.successor_id, lga.updated_at).await?;
+        history::record(&mut tx, ChangeEntityType::Lga, id, Some(&before), Some(&lga)).await?;
+        tx.commit().await?;
+
+        Ok(lga)
+    }
+
     async fn count_by_state(&self, state_id: Uuid) -> AppResult<u64> {
-        let row = sqlx::query("SELECT COUNT(*) as count FROM lgas WHERE state_id = $1")
+        let row = sqlx::query("SELECT COUNT(*) as count FROM lgas WHERE state_id = $1 AND status = 'active'")
             .bind(&state_id)
             .fetch_one(&self.pool)
             .await?;
@@ -200,33 +222,82 @@ impl LgaRepository for PostgresLgaRepository {
     }
 
     async fn search(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<Lga>> {
-        let offset = (page - 1) * limit;
+        let offset = page.saturating_sub(1) * limit;
         let search_pattern = format!("%{}%", query);
+        let normalized_pattern = format!("%{}%", normalized(query));
         
         let rows = sqlx::query(
-            "SELECT id, state_id, name, code, created_at, updated_at FROM lgas WHERE name ILIKE $1 OR code ILIKE $1 ORDER BY name LIMIT $2 OFFSET $3"
+            "SELECT id, state_id, name, code, status, successor_id, created_at, updated_at FROM lgas
+             WHERE status = 'active'
+               AND (name ILIKE $1 OR code ILIKE $1 OR normalized_name LIKE $4
+                    OR id IN (SELECT entity_id FROM aliases
+                              WHERE entity_type = 'lga' AND (name ILIKE $1 OR normalized_name LIKE $4)))
+             ORDER BY name COLLATE \"C\", id LIMIT $2 OFFSET $3"
         )
         .bind(&search_pattern)
         .bind(limit as i64)
         .bind(offset as i64)
+        .bind(&normalized_pattern)
         .fetch_all(&self.pool)
         .await?;
 
-        let mut lgas = Vec::new();
-        for row in rows {
-            let lga_code = LgaCode::new(row.get::<String, _>("code"))
-                .map_err(|e: LgaCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-            
-            lgas.push(Lga {
-                id: row.get("id"),
-                state_id: row.get("state_id"),
-                name: row.get("name"),
-                code: lga_code,
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            });
+        rows.iter().map(lga_from_row).collect()
+    }
+
+    async fn search_phonetic(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<Lga>> {
+        let key = phonetic_key(query);
+        if key.is_empty() {
+            return Ok(Vec::new());
         }
+        let offset = page.saturating_sub(1) * limit;
 
-        Ok(lgas)
+        // Exact key first, then keys containing the query's words, then similar keys
+        let rows = sqlx::query(
+            "SELECT lgas.id, lgas.state_id, lgas.name, lgas.code, lgas.status, lgas.successor_id, lgas.created_at, lgas.updated_at FROM lgas
+             JOIN (
+                 SELECT entity_id, MAX(CASE WHEN phonetic_key = $1 THEN 1.0
+                                            WHEN ' ' || phonetic_key || ' ' LIKE $2 THEN 0.8
+                                            ELSE similarity(phonetic_key, $1) END) AS score
+                 FROM (
+                     SELECT id AS entity_id, phonetic_key FROM lgas
+                     UNION ALL
+                     SELECT entity_id, phonetic_key FROM aliases WHERE entity_type = 'lga'
+                 ) keys
+                 WHERE phonetic_key = $1 OR ' ' || phonetic_key || ' ' LIKE $2 OR phonetic_key % $1
+                 GROUP BY entity_id
+             ) matches ON matches.entity_id = lgas.id
+             WHERE lgas.status = 'active'
+             ORDER BY matches.score DESC, lgas.name COLLATE \"C\", lgas.id LIMIT $3 OFFSET $4"
+        )
+        .bind(&key)
+        .bind(format!("% {} %", key))
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(lga_from_row).collect()
     }
-}
\ No newline at end of file
+}
+
+pub(crate) fn lga_from_row(row: &PgRow) -> AppResult<Lga> {
+    let lga_code = LgaCode::new(row.get::<String, _>("code"))
+        .map_err(|e: LgaCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+
+    Ok(Lga {
+        id: row.get("id"),
+        state_id: row.get("state_id"),
+        name: row.get("name"),
+        code: lga_code,
+        status: lifecycle::status_from_row(row)?,
+        successor_id: row.get("successor_id"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+pub(crate) fn duplicate_lga(error: sqlx::Error, lga: &Lga) -> crate::errors::AppError {
+    integrity::conflict_on_duplicate(error, || {
+        format!("An LGA coded {} or named '{}' in this state already exists", lga.code, lga.name)
+    })
+}
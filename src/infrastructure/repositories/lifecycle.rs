@@ -0,0 +1,205 @@
+//! Deprecating and merging states, LGAs, wards and postal codes
+//!
+//! Units are retired in place rather than deleted, so their children, aliases
+//! and history keep pointing at a row. Listings and searches only return
+//! active units; lookups by ID or code still find retired ones so callers can
+//! follow them to their successor.
+
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgRow, Postgres, Row, Transaction};
+use uuid::Uuid;
+
+use crate::domain::entities::unit_status::{UnitStatus, UpdateStatusRequest};
+use crate::errors::{AppError, AppResult, InvalidParam};
+
+/// One level of the hierarchy
+pub(crate) struct Level {
+    table: &'static str,
+    /// One unit, with its article, as used in messages
+    noun: &'static str,
+    /// Counts the active units directly below one of this level, with one
+    /// and more than one of them as used in messages
+    active_children: Option<(&'static str, &'static str, &'static str)>,
+}
+
+pub(crate) const STATES: Level = Level {
+    table: "states",
+    noun: "a state",
+    active_children: Some((
+        "SELECT COUNT(*) FROM lgas WHERE state_id = $1 AND status = 'active'",
+        "LGA",
+        "LGAs",
+    )),
+};
+
+pub(crate) const LGAS: Level = Level {
+    table: "lgas",
+    noun: "an LGA",
+    active_children: Some((
+        "SELECT COUNT(*) FROM wards WHERE lga_id = $1 AND status = 'active'",
+        "ward",
+        "wards",
+    )),
+};
+
+pub(crate) const WARDS: Level = Level {
+    table: "wards",
+    noun: "a ward",
+    active_children: Some((
+        "SELECT COUNT(*) FROM postal_codes WHERE ward_id = $1 AND status = 'active'",
+        "postal code",
+        "postal codes",
+    )),
+};
+
+pub(crate) const POSTAL_CODES: Level = Level {
+    table: "postal_codes",
+    noun: "a postal code",
+    active_children: None,
+};
+
+/// The `status` column of a row
+pub(crate) fn status_from_row(row: &PgRow) -> AppResult<UnitStatus> {
+    let status: String = row.get("status");
+    UnitStatus::parse(&status)
+        .ok_or_else(|| AppError::Internal(anyhow::anyhow!("Unknown unit status '{}'", status)))
+}
+
+/// Check `request` can be applied to the unit `id`, below `parent` when it
+/// has one, within the transaction that applies it and has locked the unit's
+/// row `FOR UPDATE`
+///
+/// The successor and parent are locked against being retired in turn. The
+/// successor must be another active unit of the same level; a unit cannot be
+/// retired while active units remain below it, nor made active again below a
+/// parent that is not: either way active units would be left under a parent
+/// that listings, exports and bundles no longer carry.
+pub(crate) async fn ensure_status_change_allowed(
+    tx: &mut Transaction<'_, Postgres>,
+    level: &Level,
+    id: Uuid,
+    parent: Option<(&Level, Uuid)>,
+    request: &UpdateStatusRequest,
+) -> AppResult<()> {
+    if let Some(successor_id) = request.successor_id {
+        if successor_id == id {
+            return Err(AppError::InvalidParam(InvalidParam::new(
+                "successor_id",
+                "distinct",
+                "a unit cannot succeed itself",
+            )));
+        }
+
+        let status: Option<String> = sqlx::query_scalar(&format!(
+            "SELECT status FROM {} WHERE id = $1 FOR SHARE",
+            level.table
+        ))
+        .bind(successor_id)
+        .fetch_optional(&mut **tx)
+        .await?;
+
+        match status.as_deref() {
+            Some("active") => {}
+            Some(_) => {
+                return Err(AppError::InvalidParam(InvalidParam::new(
+                    "successor_id",
+                    "active",
+                    format!("the successor must be {} that is still active", level.noun),
+                )))
+            }
+            None => {
+                return Err(AppError::InvalidParam(InvalidParam::new(
+                    "successor_id",
+                    "exists",
+                    format!("must be the ID of {}", level.noun),
+                )))
+            }
+        }
+    }
+
+    if let (UnitStatus::Active, Some((parent, parent_id))) = (request.status, parent) {
+        if let Some(status) = retired_status(tx, parent, parent_id).await? {
+            return Err(AppError::Conflict(format!(
+                "Cannot mark {} active while its parent is {}; reactivate the parent first",
+                level.noun, status
+            )));
+        }
+    }
+
+    if let (true, Some((count_sql, noun, nouns))) =
+        (request.status != UnitStatus::Active, level.active_children)
+    {
+        let count: i64 = sqlx::query_scalar(count_sql)
+            .bind(id)
+            .fetch_one(&mut **tx)
+            .await?;
+        if count > 0 {
+            return Err(AppError::Conflict(format!(
+                "Cannot mark {} {} while it still has {} active {}; retire or move them first",
+                level.noun,
+                request.status.as_str(),
+                count,
+                if count == 1 { noun } else { nouns }
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Refuse to add children to a parent that has been retired, within the
+/// transaction that inserts them
+pub(crate) async fn ensure_active_parent(
+    tx: &mut Transaction<'_, Postgres>,
+    parent: &Level,
+    parent_id: Uuid,
+    child: &str,
+) -> AppResult<()> {
+    match retired_status(tx, parent, parent_id).await? {
+        Some(status) => Err(AppError::Conflict(format!(
+            "Cannot add {} to {} that is {}",
+            child, parent.noun, status
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// The status of the unit `id` when it is not active, read `FOR SHARE` so it
+/// cannot be retired until the transaction ends
+async fn retired_status(
+    tx: &mut Transaction<'_, Postgres>,
+    level: &Level,
+    id: Uuid,
+) -> AppResult<Option<String>> {
+    let status: Option<String> = sqlx::query_scalar(&format!(
+        "SELECT status FROM {} WHERE id = $1 FOR SHARE",
+        level.table
+    ))
+    .bind(id)
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    Ok(status.filter(|status| status != UnitStatus::Active.as_str()))
+}
+
+/// Write a unit's status and successor
+pub(crate) async fn save_status(
+    tx: &mut Transaction<'_, Postgres>,
+    level: &Level,
+    id: Uuid,
+    status: UnitStatus,
+    successor_id: Option<Uuid>,
+    updated_at: DateTime<Utc>,
+) -> AppResult<()> {
+    sqlx::query(&format!(
+        "UPDATE {} SET status = $1, successor_id = $2, updated_at = $3 WHERE id = $4",
+        level.table
+    ))
+    .bind(status.as_str())
+    .bind(successor_id)
+    .bind(updated_at)
+    .bind(id)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
@@ -4,3 +4,15 @@ pub mod ward_repository_impl;
 pub mod postal_code_repository_impl;
 pub mod address_repository_impl;
 pub mod api_usage_repository_impl;
+pub mod search_repository_impl;
+pub mod alias_repository_impl;
+pub mod history_repository_impl;
+pub mod dataset_version_repository_impl;
+pub mod export_repository_impl;
+pub mod import_repository_impl;
+pub mod bundle_repository_impl;
+pub mod sync_repository_impl;
+pub mod verification_repository_impl;
+pub(crate) mod keyset;
+pub(crate) mod integrity;
+pub(crate) mod lifecycle;
@@ -1,11 +1,21 @@
 use async_trait::async_trait;
-use sqlx::{PgPool, Row};
+use sqlx::{postgres::PgRow, PgPool, Row};
 use uuid::Uuid;
 
+use crate::domain::entities::change::ChangeEntityType;
 use crate::domain::entities::postal_code::{PostalCode, CreatePostalCodeRequest, UpdatePostalCodeRequest};
+use crate::domain::entities::unit_status::{UnitStatus, UpdateStatusRequest};
+use crate::domain::pagination::{scoped, KeysetPage, PageRequest};
 use crate::domain::repositories::postal_code_repository::PostalCodeRepository;
-use crate::domain::value_objects::{PostalCode as PostalCodeValue, PostalCodeError, Coordinates, CoordinatesError};
+use crate::domain::value_objects::{PostalCode as PostalCodeValue, PostalCodeError, Coordinates, CoordinatesError, StateCode};
 use crate::errors::AppResult;
+use crate::infrastructure::repositories::history_repository_impl as history;
+use crate::infrastructure::repositories::integrity;
+use crate::infrastructure::repositories::lifecycle;
+use crate::infrastructure::repositories::keyset::{self, cursor_key, key_value};
+
+/// Listing name carried in cursors over a ward's postal codes
+pub(crate) const POSTAL_CODES_BY_WARD: &str = "postal_codes_by_ward";
 
 /// PostgreSQL implementation of PostalCodeRepository
 #[derive(Clone)]
@@ -17,24 +27,47 @@ impl PostgresPostalCodeRepository {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Reject postal codes outside the postal region of the ward's state
+    async fn ensure_code_matches_ward(&self, code: &PostalCodeValue, ward_id: Uuid) -> AppResult<()> {
+        let state_code: Option<String> = sqlx::query_scalar(
+            "SELECT s.code FROM wards w JOIN lgas l ON l.id = w.lga_id JOIN states s ON s.id = l.state_id WHERE w.id = $1"
+        )
+        .bind(ward_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(state_code) = state_code else {
+            return Err(crate::errors::AppError::NotFound("Ward not found".to_string()));
+        };
+        let state_code = StateCode::new(state_code)
+            .map_err(|e| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+
+        if !code.matches_state(&state_code) {
+            return Err(crate::errors::AppError::BadRequest(format!(
+                "Postal code {} is outside the postal region of state {}", code, state_code
+            )));
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl PostalCodeRepository for PostgresPostalCodeRepository {
     async fn create(&self, request: &CreatePostalCodeRequest, ward_id: Uuid) -> AppResult<PostalCode> {
         let postal_code_value = PostalCodeValue::new(request.postal_code.clone())
-            .map_err(|e: PostalCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+            .map_err(|e| crate::errors::AppError::invalid_param("postal_code", e))?;
+        self.ensure_code_matches_ward(&postal_code_value, ward_id).await?;
 
         let coordinates = match (request.lat, request.lng) {
-            (Some(lat), Some(lng)) => Some(
-                Coordinates::new(lat, lng)
-                    .map_err(|e: CoordinatesError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?
-            ),
+            (Some(lat), Some(lng)) => Some(Coordinates::new(lat, lng)?),
             _ => None,
         };
 
         let postal_code = PostalCode::new(ward_id, postal_code_value, coordinates, request.urban);
 
+        let mut tx = self.pool.begin().await?;
+        lifecycle::ensure_active_parent(&mut tx, &lifecycle::WARDS, ward_id, "a postal code").await?;
         sqlx::query(
             "INSERT INTO postal_codes (id, ward_id, postal_code, lat, lng, urban, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"
         )
@@ -46,127 +79,85 @@ impl PostalCodeRepository for PostgresPostalCodeRepository {
         .bind(&postal_code.urban)
         .bind(&postal_code.created_at)
         .bind(&postal_code.updated_at)
-        .execute(&self.pool)
-        .await?;
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| duplicate_postal_code(e, &postal_code))?;
+
+        history::record(&mut tx, ChangeEntityType::PostalCode, postal_code.id, None, Some(&postal_code)).await?;
+        tx.commit().await?;
 
         Ok(postal_code)
     }
 
     async fn find_by_id(&self, id: Uuid) -> AppResult<Option<PostalCode>> {
         let row = sqlx::query(
-            "SELECT id, ward_id, postal_code, lat, lng, urban, created_at, updated_at FROM postal_codes WHERE id = $1"
+            "SELECT id, ward_id, postal_code, lat, lng, urban, status, successor_id, created_at, updated_at FROM postal_codes WHERE id = $1"
         )
         .bind(&id)
         .fetch_optional(&self.pool)
         .await?;
 
-        match row {
-            Some(row) => {
-                let postal_code_value = PostalCodeValue::new(row.get::<String, _>("postal_code"))
-                    .map_err(|e: PostalCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-
-                let coordinates = match (row.get::<Option<f64>, _>("lat"), row.get::<Option<f64>, _>("lng")) {
-                    (Some(lat), Some(lng)) => Some(
-                        Coordinates::new(lat, lng)
-                            .map_err(|e: CoordinatesError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?
-                    ),
-                    _ => None,
-                };
-                
-                Ok(Some(PostalCode {
-                    id: row.get("id"),
-                    ward_id: row.get("ward_id"),
-                    postal_code: postal_code_value,
-                    coordinates,
-                    urban: row.get("urban"),
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                }))
-            }
-            None => Ok(None),
-        }
+        row.as_ref().map(postal_code_from_row).transpose()
     }
 
     async fn find_by_code(&self, code: &PostalCodeValue) -> AppResult<Option<PostalCode>> {
         let row = sqlx::query(
-            "SELECT id, ward_id, postal_code, lat, lng, urban, created_at, updated_at FROM postal_codes WHERE postal_code = $1"
+            "SELECT id, ward_id, postal_code, lat, lng, urban, status, successor_id, created_at, updated_at FROM postal_codes WHERE postal_code = $1"
         )
         .bind(code.to_string())
         .fetch_optional(&self.pool)
         .await?;
 
-        match row {
-            Some(row) => {
-                let postal_code_value = PostalCodeValue::new(row.get::<String, _>("postal_code"))
-                    .map_err(|e: PostalCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-
-                let coordinates = match (row.get::<Option<f64>, _>("lat"), row.get::<Option<f64>, _>("lng")) {
-                    (Some(lat), Some(lng)) => Some(
-                        Coordinates::new(lat, lng)
-                            .map_err(|e: CoordinatesError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?
-                    ),
-                    _ => None,
-                };
-                
-                Ok(Some(PostalCode {
-                    id: row.get("id"),
-                    ward_id: row.get("ward_id"),
-                    postal_code: postal_code_value,
-                    coordinates,
-                    urban: row.get("urban"),
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                }))
-            }
-            None => Ok(None),
-        }
+        row.as_ref().map(postal_code_from_row).transpose()
     }
 
-    async fn find_by_ward_id(&self, ward_id: Uuid, page: u32, limit: u32) -> AppResult<Vec<PostalCode>> {
-        let offset = (page - 1) * limit;
-        
-        let rows = sqlx::query(
-            "SELECT id, ward_id, postal_code, lat, lng, urban, created_at, updated_at FROM postal_codes WHERE ward_id = $1 ORDER BY postal_code LIMIT $2 OFFSET $3"
-        )
+    async fn find_by_ward_id(&self, ward_id: Uuid, request: &PageRequest) -> AppResult<KeysetPage<PostalCode>> {
+        let listing = scoped(POSTAL_CODES_BY_WARD, ward_id);
+        let boundary = cursor_key::<2>(request, &listing)?;
+        let (code, id) = match boundary {
+            Some([code, id]) => (Some(code.clone()), Some(key_value::<Uuid>(id)?)),
+            None => (None, None),
+        };
+
+        let rows = sqlx::query(&format!(
+            "SELECT id, ward_id, postal_code, lat, lng, urban, status, successor_id, created_at, updated_at FROM postal_codes
+             WHERE ward_id = $1 AND status = 'active' AND ($2::text IS NULL OR (postal_code, id) {seek} ($2, $3))
+             ORDER BY postal_code {order}, id {order} LIMIT $4 OFFSET $5",
+            seek = keyset::seek(request),
+            order = keyset::order(request),
+        ))
         .bind(&ward_id)
-        .bind(limit as i64)
-        .bind(offset as i64)
+        .bind(code)
+        .bind(id)
+        .bind(request.fetch_limit() as i64)
+        .bind(request.offset() as i64)
         .fetch_all(&self.pool)
         .await?;
 
-        let mut postal_codes = Vec::new();
-        for row in rows {
-            let postal_code_value = PostalCodeValue::new(row.get::<String, _>("postal_code"))
-                .map_err(|e: PostalCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-
-            let coordinates = match (row.get::<Option<f64>, _>("lat"), row.get::<Option<f64>, _>("lng")) {
-                (Some(lat), Some(lng)) => Some(
-                    Coordinates::new(lat, lng)
-                        .map_err(|e: CoordinatesError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?
-                ),
-                _ => None,
-            };
-            
-            postal_codes.push(PostalCode {
-                id: row.get("id"),
-                ward_id: row.get("ward_id"),
-                postal_code: postal_code_value,
-                coordinates,
-                urban: row.get("urban"),
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            });
-        }
+        let postal_codes = rows.iter().map(postal_code_from_row).collect::<AppResult<Vec<_>>>()?;
 
-        Ok(postal_codes)
+        Ok(KeysetPage::from_rows(postal_codes, request, &listing, |postal_code| {
+            vec![postal_code.postal_code.to_string(), postal_code.id.to_string()]
+        }))
+    }
+
+    async fn find_by_ward_ids(&self, ward_ids: &[Uuid]) -> AppResult<Vec<PostalCode>> {
+        let rows = sqlx::query(
+            "SELECT id, ward_id, postal_code, lat, lng, urban, status, successor_id, created_at, updated_at FROM postal_codes WHERE ward_id = ANY($1) AND status = 'active' ORDER BY postal_code"
+        )
+        .bind(ward_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(postal_code_from_row).collect()
     }
 
     async fn find_near_coordinates(&self, coordinates: &Coordinates, radius_km: f64) -> AppResult<Vec<PostalCode>> {
         let rows = sqlx::query(
             r#"
-            SELECT id, ward_id, postal_code, lat, lng, urban, created_at, updated_at
+            SELECT id, ward_id, postal_code, lat, lng, urban, status, successor_id, created_at, updated_at
             FROM postal_codes 
-            WHERE lat IS NOT NULL AND lng IS NOT NULL
+            WHERE status = 'active' AND lat IS NOT NULL AND lng IS NOT NULL
             AND (
                 6371 * acos(
                     cos(radians($1)) * cos(radians(lat)) * 
@@ -190,48 +181,23 @@ impl PostalCodeRepository for PostgresPostalCodeRepository {
         .fetch_all(&self.pool)
         .await?;
 
-        let mut postal_codes = Vec::new();
-        for row in rows {
-            let postal_code_value = PostalCodeValue::new(row.get::<String, _>("postal_code"))
-                .map_err(|e: PostalCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-
-            let coordinates = match (row.get::<Option<f64>, _>("lat"), row.get::<Option<f64>, _>("lng")) {
-                (Some(lat), Some(lng)) => Some(
-                    Coordinates::new(lat, lng)
-                        .map_err(|e: CoordinatesError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?
-                ),
-                _ => None,
-            };
-            
-            postal_codes.push(PostalCode {
-                id: row.get("id"),
-                ward_id: row.get("ward_id"),
-                postal_code: postal_code_value,
-                coordinates,
-                urban: row.get("urban"),
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            });
-        }
-
-        Ok(postal_codes)
+        rows.iter().map(postal_code_from_row).collect()
     }
 
     async fn update(&self, id: Uuid, request: &UpdatePostalCodeRequest) -> AppResult<PostalCode> {
         let mut postal_code = self.find_by_id(id).await?
             .ok_or_else(|| crate::errors::AppError::NotFound("Postal code not found".to_string()))?;
+        let before = postal_code.clone();
 
         if let Some(code) = &request.postal_code {
             let postal_code_value = PostalCodeValue::new(code.clone())
-                .map_err(|e: PostalCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-            postal_code.postal_code = postal_code_value;
+                .map_err(|e| crate::errors::AppError::invalid_param("postal_code", e))?;
+            self.ensure_code_matches_ward(&postal_code_value, postal_code.ward_id).await?;
+            postal_code.update_code(postal_code_value);
         }
 
         if let (Some(lat), Some(lng)) = (request.lat, request.lng) {
-            let coordinates = Some(
-                Coordinates::new(lat, lng)
-                    .map_err(|e: CoordinatesError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?
-            );
+            let coordinates = Some(Coordinates::new(lat, lng)?);
             postal_code.update_coordinates(coordinates);
         }
 
@@ -239,6 +205,7 @@ impl PostalCodeRepository for PostgresPostalCodeRepository {
             postal_code.update_urban_status(urban);
         }
 
+        let mut tx = self.pool.begin().await?;
         sqlx::query(
             "UPDATE postal_codes SET postal_code = $1, lat = $2, lng = $3, urban = $4, updated_at = $5 WHERE id = $6"
         )
@@ -248,27 +215,51 @@ impl PostalCodeRepository for PostgresPostalCodeRepository {
         .bind(&postal_code.urban)
         .bind(&postal_code.updated_at)
         .bind(&postal_code.id)
-        .execute(&self.pool)
-        .await?;
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| duplicate_postal_code(e, &postal_code))?;
+
+        history::record(&mut tx, ChangeEntityType::PostalCode, id, Some(&before), Some(&postal_code)).await?;
+        tx.commit().await?;
 
         Ok(postal_code)
     }
 
     async fn delete(&self, id: Uuid) -> AppResult<()> {
-        let result = sqlx::query("DELETE FROM postal_codes WHERE id = $1")
-            .bind(&id)
-            .execute(&self.pool)
-            .await?;
-
-        if result.rows_affected() == 0 {
-            return Err(crate::errors::AppError::NotFound("Postal code not found".to_string()));
+        let postal_code = self.find_by_id(id).await?
+            .ok_or_else(|| crate::errors::AppError::NotFound("Postal code not found".to_string()))?;
+        if postal_code.status != UnitStatus::Active {
+            return Ok(());
         }
 
+        let request = UpdateStatusRequest { status: UnitStatus::Deprecated, successor_id: None };
+        self.set_status(id, &request).await?;
         Ok(())
     }
 
+    async fn set_status(&self, id: Uuid, request: &UpdateStatusRequest) -> AppResult<PostalCode> {
+        let mut tx = self.pool.begin().await?;
+        let row = sqlx::query(
+            "SELECT id, ward_id, postal_code, lat, lng, urban, status, successor_id, created_at, updated_at FROM postal_codes WHERE id = $1 FOR UPDATE"
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let mut postal_code = row.as_ref().map(postal_code_from_row).transpose()?
+            .ok_or_else(|| crate::errors::AppError::NotFound("Postal code not found".to_string()))?;
+        lifecycle::ensure_status_change_allowed(&mut tx, &lifecycle::POSTAL_CODES, id, Some((&lifecycle::WARDS, postal_code.ward_id)), request).await?;
+        let before = postal_code.clone();
+        postal_code.set_status(request.status, request.successor_id);
+
+        lifecycle::save_status(&mut tx, &lifecycle::POSTAL_CODES, id, postal_code.status, postal_code.successor_id, postal_code.updated_at).await?;
+        history::record(&mut tx, ChangeEntityType::PostalCode, id, Some(&before), Some(&postal_code)).await?;
+        tx.commit().await?;
+
+        Ok(postal_code)
+    }
+
     async fn count_by_ward(&self, ward_id: Uuid) -> AppResult<u64> {
-        let row = sqlx::query("SELECT COUNT(*) as count FROM postal_codes WHERE ward_id = $1")
+        let row = sqlx::query("SELECT COUNT(*) as count FROM postal_codes WHERE ward_id = $1 AND status = 'active'")
             .bind(&ward_id)
             .fetch_one(&self.pool)
             .await?;
@@ -277,11 +268,11 @@ impl PostalCodeRepository for PostgresPostalCodeRepository {
     }
 
     async fn search(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<PostalCode>> {
-        let offset = (page - 1) * limit;
+        let offset = page.saturating_sub(1) * limit;
         let search_pattern = format!("%{}%", query);
         
         let rows = sqlx::query(
-            "SELECT id, ward_id, postal_code, lat, lng, urban, created_at, updated_at FROM postal_codes WHERE postal_code ILIKE $1 ORDER BY postal_code LIMIT $2 OFFSET $3"
+            "SELECT id, ward_id, postal_code, lat, lng, urban, status, successor_id, created_at, updated_at FROM postal_codes WHERE postal_code ILIKE $1 AND status = 'active' ORDER BY postal_code LIMIT $2 OFFSET $3"
         )
         .bind(&search_pattern)
         .bind(limit as i64)
@@ -289,30 +280,37 @@ impl PostalCodeRepository for PostgresPostalCodeRepository {
         .fetch_all(&self.pool)
         .await?;
 
-        let mut postal_codes = Vec::new();
-        for row in rows {
-            let postal_code_value = PostalCodeValue::new(row.get::<String, _>("postal_code"))
-                .map_err(|e: PostalCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-
-            let coordinates = match (row.get::<Option<f64>, _>("lat"), row.get::<Option<f64>, _>("lng")) {
-                (Some(lat), Some(lng)) => Some(
-                    Coordinates::new(lat, lng)
-                        .map_err(|e: CoordinatesError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?
-                ),
-                _ => None,
-            };
-            
-            postal_codes.push(PostalCode {
-                id: row.get("id"),
-                ward_id: row.get("ward_id"),
-                postal_code: postal_code_value,
-                coordinates,
-                urban: row.get("urban"),
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            });
-        }
-
-        Ok(postal_codes)
+        rows.iter().map(postal_code_from_row).collect()
     }
-}
\ No newline at end of file
+}
+
+pub(crate) fn postal_code_from_row(row: &PgRow) -> AppResult<PostalCode> {
+    let postal_code_value = PostalCodeValue::new(row.get::<String, _>("postal_code"))
+        .map_err(|e: PostalCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+
+    let coordinates = match (row.get::<Option<f64>, _>("lat"), row.get::<Option<f64>, _>("lng")) {
+        (Some(lat), Some(lng)) => Some(
+            Coordinates::new(lat, lng)
+                .map_err(|e: CoordinatesError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?
+        ),
+        _ => None,
+    };
+
+    Ok(PostalCode {
+        id: row.get("id"),
+        ward_id: row.get("ward_id"),
+        postal_code: postal_code_value,
+        coordinates,
+        urban: row.get("urban"),
+        status: lifecycle::status_from_row(row)?,
+        successor_id: row.get("successor_id"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+pub(crate) fn duplicate_postal_code(error: sqlx::Error, postal_code: &PostalCode) -> crate::errors::AppError {
+    integrity::conflict_on_duplicate(error, || {
+        format!("The ward already has postal code {}", postal_code.postal_code)
+    })
+}
@@ -0,0 +1,385 @@
+use async_trait::async_trait;
+use sqlx::{
+    postgres::{PgArguments, PgRow},
+    query::Query,
+    PgPool, Postgres, Row,
+};
+use uuid::Uuid;
+
+
+use crate::domain::entities::search::{
+    AutocompleteEntry, MatchMode, SearchCounts, SearchEntityType, SearchHit,
+};
+use crate::domain::normalization::{normalized, rules_needed_within, NormalizationRule};
+use crate::domain::pagination::{scoped, Direction, KeysetPage, PageRequest};
+use crate::domain::phonetic::phonetic_key;
+use crate::domain::repositories::search_repository::SearchRepository;
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::repositories::keyset::{self, cursor_key, key_value};
+
+/// Listing name carried in search cursors
+const SEARCH: &str = "search";
+
+/// Listing of the hits for one query, so a cursor only pages through the
+/// results it came from
+pub(crate) fn search_listing(term: &str, mode: MatchMode) -> String {
+    scoped(SEARCH, format_args!("{}:{}", mode.as_str(), term))
+}
+
+/// PostgreSQL implementation of SearchRepository
+///
+/// Each hit is scored by how well its name (or code) matches the query:
+/// exact 1.0, prefix 0.8, substring 0.6, or 0.7 × trigram similarity, whichever
+/// is highest. The match score is then weighted by entity level so that, for
+/// equally good matches, states rank above LGAs, and LGAs above wards.
+#[derive(Clone)]
+pub struct PostgresSearchRepository {
+    pool: PgPool,
+}
+
+impl PostgresSearchRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Relevance of a name/code pair to the query, before the entity-level weight;
+/// the name's key (normalized form or phonetic key) scores like the name itself
+fn match_score(name: &str, key: &str, code: &str) -> String {
+    format!(
+        "GREATEST(
+            CASE WHEN lower({name}) = lower($1) OR {key} = $4 OR upper({code}) = upper($1) THEN 1.0 ELSE 0 END,
+            CASE WHEN {name} ILIKE $3 OR {key} LIKE $6 OR {code} ILIKE $3 THEN 0.8 ELSE 0 END,
+            CASE WHEN {name} ILIKE $2 OR {key} LIKE $5 THEN 0.6 ELSE 0 END,
+            0.7 * similarity({name}, $1),
+            0.7 * similarity({key}, $4)
+        )"
+    )
+}
+
+/// Rows a name/code pair must satisfy to be a hit at all; `%` uses the
+/// pg_trgm similarity threshold (0.3 by default) and the trigram indexes
+fn match_filter(name: &str, key: &str, code: &str) -> String {
+    format!(
+        "({name} ILIKE $2 OR {code} ILIKE $3 OR {name} % $1
+          OR ($4 <> '' AND ({key} LIKE $5 OR {key} % $4)))"
+    )
+}
+
+/// Every active hit across the four entity types, with its weighted score
+///
+/// Parameters: $1 the query, $2 and $3 its substring and prefix patterns, and
+/// $4, $5 and $6 the same for its key: the normalized query, matched against
+/// `normalized_name`, or in phonetic mode its phonetic key, matched against
+/// `phonetic_key`.
+///
+/// States, LGAs and wards also match through their aliases: each entity takes
+/// the better of its name score and its best alias score, and reports the
+/// alias when that is what matched.
+fn hits_sql(mode: MatchMode) -> String {
+    let key = key_column(mode);
+
+    format!(
+        r#"
+        WITH alias_hits AS (
+            SELECT DISTINCT ON (a.entity_type, a.entity_id)
+                   a.entity_type, a.entity_id, a.name AS alias, {alias_score} AS score
+            FROM aliases a
+            WHERE {alias_filter}
+            ORDER BY a.entity_type, a.entity_id, score DESC, a.name COLLATE "C"
+        ),
+        entity_hits AS (
+            SELECT 'state' AS entity_type, 1 AS level, s.id, s.name, s.code,
+                   NULL::text AS parent_path,
+                   {state_score} AS name_score, ah.score AS alias_score, ah.alias,
+                   1.0 AS weight
+            FROM states s
+            LEFT JOIN alias_hits ah ON ah.entity_type = 'state' AND ah.entity_id = s.id
+            WHERE s.status = 'active' AND ({state_filter} OR ah.entity_id IS NOT NULL)
+            UNION ALL
+            SELECT 'lga', 2, l.id, l.name, l.code,
+                   s.name,
+                   {lga_score}, ah.score, ah.alias,
+                   0.9
+            FROM lgas l
+            JOIN states s ON s.id = l.state_id
+            LEFT JOIN alias_hits ah ON ah.entity_type = 'lga' AND ah.entity_id = l.id
+            WHERE l.status = 'active' AND ({lga_filter} OR ah.entity_id IS NOT NULL)
+            UNION ALL
+            SELECT 'ward', 3, w.id, w.name, w.code,
+                   l.name || ', ' || s.name,
+                   {ward_score}, ah.score, ah.alias,
+                   0.8
+            FROM wards w
+            JOIN lgas l ON l.id = w.lga_id
+            JOIN states s ON s.id = l.state_id
+            LEFT JOIN alias_hits ah ON ah.entity_type = 'ward' AND ah.entity_id = w.id
+            WHERE w.status = 'active' AND ({ward_filter} OR ah.entity_id IS NOT NULL)
+        )
+        SELECT entity_type, level, id, name, code, parent_path,
+               GREATEST(name_score, COALESCE(alias_score, 0)) * weight AS score,
+               CASE WHEN alias_score > name_score THEN alias END AS matched_alias
+        FROM entity_hits
+        UNION ALL
+        SELECT 'postal_code', 4, p.id, p.postal_code, p.postal_code,
+               w.name || ', ' || l.name || ', ' || s.name,
+               (CASE WHEN p.postal_code = $1 THEN 1.0 ELSE 0.8 END) * 0.8,
+               NULL
+        FROM postal_codes p
+        JOIN wards w ON w.id = p.ward_id
+        JOIN lgas l ON l.id = w.lga_id
+        JOIN states s ON s.id = l.state_id
+        WHERE p.status = 'active' AND p.postal_code LIKE $3
+        "#,
+        alias_score = match_score("a.name", &format!("a.{key}"), "a.name"),
+        alias_filter = match_filter("a.name", &format!("a.{key}"), "a.name"),
+        state_score = match_score("s.name", &format!("s.{key}"), "s.code"),
+        state_filter = match_filter("s.name", &format!("s.{key}"), "s.code"),
+        lga_score = match_score("l.name", &format!("l.{key}"), "l.code"),
+        lga_filter = match_filter("l.name", &format!("l.{key}"), "l.code"),
+        ward_score = match_score("w.name", &format!("w.{key}"), "w.code"),
+        ward_filter = match_filter("w.name", &format!("w.{key}"), "w.code"),
+    )
+}
+
+/// Every active entity with its enclosing codes and parent path
+const AUTOCOMPLETE_ENTRIES_SQL: &str = r#"
+    WITH alias_names AS (
+        SELECT entity_type, entity_id, array_agg(name ORDER BY name COLLATE "C") AS names
+        FROM aliases
+        GROUP BY entity_type, entity_id
+    )
+    SELECT 'state' AS entity_type, s.id, s.name, s.code,
+           s.code AS state_code, NULL::text AS lga_code, NULL::text AS parent_path,
+           COALESCE(an.names, '{}') AS aliases
+    FROM states s
+    LEFT JOIN alias_names an ON an.entity_type = 'state' AND an.entity_id = s.id
+    WHERE s.status = 'active'
+    UNION ALL
+    SELECT 'lga', l.id, l.name, l.code, s.code, l.code, s.name,
+           COALESCE(an.names, '{}')
+    FROM lgas l
+    JOIN states s ON s.id = l.state_id
+    LEFT JOIN alias_names an ON an.entity_type = 'lga' AND an.entity_id = l.id
+    WHERE l.status = 'active'
+    UNION ALL
+    SELECT 'ward', w.id, w.name, w.code, s.code, l.code, l.name || ', ' || s.name,
+           COALESCE(an.names, '{}')
+    FROM wards w
+    JOIN lgas l ON l.id = w.lga_id
+    JOIN states s ON s.id = l.state_id
+    LEFT JOIN alias_names an ON an.entity_type = 'ward' AND an.entity_id = w.id
+    WHERE w.status = 'active'
+    UNION ALL
+    SELECT 'postal_code', p.id, p.postal_code, p.postal_code, s.code, l.code,
+           w.name || ', ' || l.name || ', ' || s.name,
+           '{}'
+    FROM postal_codes p
+    JOIN wards w ON w.id = p.ward_id
+    JOIN lgas l ON l.id = w.lga_id
+    JOIN states s ON s.id = l.state_id
+    WHERE p.status = 'active'
+"#;
+
+/// Row count and latest update of every table; deletes change the count,
+/// inserts and updates the timestamp
+pub(crate) const DATA_FINGERPRINT_SQL: &str = r#"
+    SELECT concat_ws('|',
+        (SELECT count(*) || '@' || coalesce(max(updated_at)::text, '') FROM states),
+        (SELECT count(*) || '@' || coalesce(max(updated_at)::text, '') FROM lgas),
+        (SELECT count(*) || '@' || coalesce(max(updated_at)::text, '') FROM wards),
+        (SELECT count(*) || '@' || coalesce(max(updated_at)::text, '') FROM postal_codes),
+        (SELECT count(*) || '@' || coalesce(max(updated_at)::text, '') FROM aliases)
+    )
+"#;
+
+type PgQuery<'q> = Query<'q, Postgres, PgArguments>;
+
+/// Escape LIKE wildcards so the query is matched literally
+pub(crate) fn escape_like(query: &str) -> String {
+    query
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Column holding the form of each name that the query's key is compared with
+fn key_column(mode: MatchMode) -> &'static str {
+    match mode {
+        MatchMode::Standard => "normalized_name",
+        MatchMode::Phonetic => "phonetic_key",
+    }
+}
+
+/// Bind the query parameters of [`hits_sql`]
+fn bind_terms<'q>(query: PgQuery<'q>, term: &str, mode: MatchMode) -> PgQuery<'q> {
+    let escaped = escape_like(term);
+    let key = match mode {
+        MatchMode::Standard => normalized(term),
+        MatchMode::Phonetic => phonetic_key(term),
+    };
+
+    query
+        .bind(term.to_string())
+        .bind(format!("%{}%", escaped))
+        .bind(format!("{}%", escaped))
+        .bind(key.clone())
+        .bind(format!("%{}%", key))
+        .bind(format!("{}%", key))
+}
+
+/// Normalization the query needed to match a hit's alias or name
+fn match_rules(term: &str, row: &PgRow) -> Vec<NormalizationRule> {
+    let matched: String = row
+        .get::<Option<String>, _>("matched_alias")
+        .unwrap_or_else(|| row.get("name"));
+
+    rules_needed_within(term, &matched).unwrap_or_default()
+}
+
+#[async_trait]
+impl SearchRepository for PostgresSearchRepository {
+    async fn search(
+        &self,
+        query: &str,
+        mode: MatchMode,
+        request: &PageRequest,
+    ) -> AppResult<KeysetPage<SearchHit>> {
+        let term = query.trim();
+        let listing = search_listing(term, mode);
+        let (score, level, name, id) = match cursor_key::<4>(request, &listing)? {
+            Some([score, level, name, id]) => (
+                Some(key_value::<f64>(score)?),
+                Some(key_value::<i32>(level)?),
+                Some(name.clone()),
+                Some(key_value::<Uuid>(id)?),
+            ),
+            None => (None, None, None, None),
+        };
+
+        // Hits rank by score descending, so reading past the cursor moves to
+        // lower scores while the remaining sort columns still ascend
+        let (rank_seek, rank_order) = match request.direction() {
+            Direction::After => ("<", "DESC"),
+            Direction::Before => (">", "ASC"),
+        };
+
+        let sql = format!(
+            "SELECT entity_type, id, name, code, parent_path, matched_alias,
+                    rank::float8 AS score
+             FROM (SELECT *, round(score::numeric, 4) AS rank FROM ({hits}) hits) ranked
+             WHERE $9::float8 IS NULL
+                OR rank {rank_seek} round($9::float8::numeric, 4)
+                OR (rank = round($9::float8::numeric, 4)
+                    AND (level, name COLLATE \"C\", id) {seek} ($10::int, $11::text, $12::uuid))
+             ORDER BY rank {rank_order}, level {order}, name COLLATE \"C\" {order}, id {order}
+             LIMIT $7 OFFSET $8",
+            hits = hits_sql(mode),
+            seek = keyset::seek(request),
+            order = keyset::order(request),
+        );
+
+        let rows = bind_terms(sqlx::query(&sql), term, mode)
+            .bind(request.fetch_limit() as i64)
+            .bind(request.offset() as i64)
+            .bind(score)
+            .bind(level)
+            .bind(name)
+            .bind(id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let hits = rows
+            .into_iter()
+            .map(|row| {
+                let entity_type: String = row.get("entity_type");
+                Ok(SearchHit {
+                    entity_type: SearchEntityType::parse(&entity_type).ok_or_else(|| {
+                        AppError::Internal(anyhow::anyhow!(
+                            "Unknown search entity type {}",
+                            entity_type
+                        ))
+                    })?,
+                    id: row.get("id"),
+                    name: row.get("name"),
+                    code: row.get("code"),
+                    parent_path: row.get("parent_path"),
+                    normalization: match_rules(term, &row),
+                    matched_alias: row.get("matched_alias"),
+                    score: row.get("score"),
+                })
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+
+        Ok(KeysetPage::from_rows(hits, request, &listing, |hit: &SearchHit| {
+            vec![
+                format!("{:.4}", hit.score),
+                hit.entity_type.level().to_string(),
+                hit.name.clone(),
+                hit.id.to_string(),
+            ]
+        }))
+    }
+
+    async fn count(&self, query: &str, mode: MatchMode) -> AppResult<SearchCounts> {
+        let term = query.trim();
+
+        let sql = format!(
+            "SELECT entity_type, COUNT(*) AS total FROM ({hits}) hits GROUP BY entity_type",
+            hits = hits_sql(mode)
+        );
+
+        let rows = bind_terms(sqlx::query(&sql), term, mode)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut counts = SearchCounts::default();
+        for row in rows {
+            let total = row.get::<i64, _>("total") as u64;
+            match SearchEntityType::parse(row.get::<String, _>("entity_type").as_str()) {
+                Some(SearchEntityType::State) => counts.states = total,
+                Some(SearchEntityType::Lga) => counts.lgas = total,
+                Some(SearchEntityType::Ward) => counts.wards = total,
+                Some(SearchEntityType::PostalCode) => counts.postal_codes = total,
+                None => {}
+            }
+        }
+
+        Ok(counts)
+    }
+
+    async fn autocomplete_entries(&self) -> AppResult<Vec<AutocompleteEntry>> {
+        let rows = sqlx::query(AUTOCOMPLETE_ENTRIES_SQL)
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let entity_type: String = row.get("entity_type");
+                Ok(AutocompleteEntry {
+                    entity_type: SearchEntityType::parse(&entity_type).ok_or_else(|| {
+                        AppError::Internal(anyhow::anyhow!(
+                            "Unknown search entity type {}",
+                            entity_type
+                        ))
+                    })?,
+                    id: row.get("id"),
+                    name: row.get("name"),
+                    code: row.get("code"),
+                    state_code: row.get("state_code"),
+                    lga_code: row.get("lga_code"),
+                    parent_path: row.get("parent_path"),
+                    aliases: row.get("aliases"),
+                })
+            })
+            .collect()
+    }
+
+    async fn data_fingerprint(&self) -> AppResult<String> {
+        let fingerprint = sqlx::query_scalar::<_, String>(DATA_FINGERPRINT_SQL)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(fingerprint)
+    }
+}
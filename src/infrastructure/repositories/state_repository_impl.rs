@@ -1,11 +1,18 @@
 use async_trait::async_trait;
-use sqlx::{PgPool, Row};
+use sqlx::{postgres::PgRow, PgPool, Row};
 use uuid::Uuid;
 
+use crate::domain::entities::change::ChangeEntityType;
 use crate::domain::entities::state::{State, CreateStateRequest, UpdateStateRequest};
+use crate::domain::entities::unit_status::{UnitStatus, UpdateStatusRequest};
+use crate::domain::normalization::normalized;
+use crate::domain::phonetic::phonetic_key;
 use crate::domain::repositories::state_repository::StateRepository;
 use crate::domain::value_objects::{StateCode, StateCodeError};
 use crate::errors::AppResult;
+use crate::infrastructure::repositories::history_repository_impl as history;
+use crate::infrastructure::repositories::integrity::{self, STATE_LGAS};
+use crate::infrastructure::repositories::lifecycle;
 
 /// PostgreSQL implementation of StateRepository
 #[derive(Clone)]
@@ -23,102 +30,74 @@ impl PostgresStateRepository {
 impl StateRepository for PostgresStateRepository {
     async fn create(&self, request: &CreateStateRequest) -> AppResult<State> {
         let state_code = StateCode::new(request.code.clone())
-            .map_err(|e: StateCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+            .map_err(|e| crate::errors::AppError::invalid_param("code", e))?;
 
         let state = State::new(request.name.clone(), state_code);
 
+        let mut tx = self.pool.begin().await?;
         sqlx::query(
-            "INSERT INTO states (id, name, code, created_at, updated_at) VALUES ($1, $2, $3, $4, $5)"
+            "INSERT INTO states (id, name, normalized_name, phonetic_key, code, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7)"
         )
         .bind(&state.id)
         .bind(&state.name)
+        .bind(normalized(&state.name))
+        .bind(phonetic_key(&state.name))
         .bind(state.code.to_string())
         .bind(&state.created_at)
         .bind(&state.updated_at)
-        .execute(&self.pool)
-        .await?;
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| duplicate_state(e, &state))?;
+
+        history::record(&mut tx, ChangeEntityType::State, state.id, None, Some(&state)).await?;
+        tx.commit().await?;
 
         Ok(state)
     }
 
     async fn find_by_id(&self, id: Uuid) -> AppResult<Option<State>> {
         let row = sqlx::query(
-            "SELECT id, name, code, created_at, updated_at FROM states WHERE id = $1"
+            "SELECT id, name, code, status, successor_id, created_at, updated_at FROM states WHERE id = $1"
         )
         .bind(&id)
         .fetch_optional(&self.pool)
         .await?;
 
-        match row {
-            Some(row) => {
-                let state_code = StateCode::new(row.get::<String, _>("code"))
-                    .map_err(|e: StateCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-                
-                Ok(Some(State {
-                    id: row.get("id"),
-                    name: row.get("name"),
-                    code: state_code,
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                }))
-            }
-            None => Ok(None),
-        }
+        row.as_ref().map(state_from_row).transpose()
     }
 
     async fn find_by_name(&self, name: &str) -> AppResult<Option<State>> {
         let row = sqlx::query(
-            "SELECT id, name, code, created_at, updated_at FROM states WHERE name = $1"
+            "SELECT id, name, code, status, successor_id, created_at, updated_at FROM states
+             WHERE name = $1 OR normalized_name = $2
+                OR id IN (SELECT entity_id FROM aliases
+                          WHERE entity_type = 'state' AND (lower(name) = lower($1) OR normalized_name = $2))
+             ORDER BY status = 'active' DESC, name = $1 DESC, normalized_name = $2 DESC, name COLLATE \"C\", id
+             LIMIT 1"
         )
         .bind(name)
+        .bind(normalized(name))
         .fetch_optional(&self.pool)
         .await?;
 
-        match row {
-            Some(row) => {
-                let state_code = StateCode::new(row.get::<String, _>("code"))
-                    .map_err(|e: StateCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-                
-                Ok(Some(State {
-                    id: row.get("id"),
-                    name: row.get("name"),
-                    code: state_code,
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                }))
-            }
-            None => Ok(None),
-        }
+        row.as_ref().map(state_from_row).transpose()
     }
 
     async fn find_by_code(&self, code: &StateCode) -> AppResult<Option<State>> {
         let row = sqlx::query(
-            "SELECT id, name, code, created_at, updated_at FROM states WHERE code = $1"
+            "SELECT id, name, code, status, successor_id, created_at, updated_at FROM states WHERE code = $1"
         )
         .bind(code.to_string())
         .fetch_optional(&self.pool)
         .await?;
 
-        match row {
-            Some(row) => {
-                let state_code = StateCode::new(row.get::<String, _>("code"))
-                    .map_err(|e: StateCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-                
-                Ok(Some(State {
-                    id: row.get("id"),
-                    name: row.get("name"),
-                    code: state_code,
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                }))
-            }
-            None => Ok(None),
-        }
+        row.as_ref().map(state_from_row).transpose()
     }
 
     async fn update(&self, id: Uuid, request: &UpdateStateRequest) -> AppResult<State> {
         let mut state = self.find_by_id(id).await?
             .ok_or_else(|| crate::errors::AppError::NotFound("State not found".to_string()))?;
+        let before = state.clone();
 
         if let Some(name) = &request.name {
             state.update_name(name.clone());
@@ -126,66 +105,93 @@ impl StateRepository for PostgresStateRepository {
 
         if let Some(code) = &request.code {
             let state_code = StateCode::new(code.clone())
-                .map_err(|e: StateCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+                .map_err(|e| crate::errors::AppError::invalid_param("code", e))?;
+            if state_code != state.code {
+                // LGA and ward codes start with the state's
+                integrity::ensure_no_children(&self.pool, &STATE_LGAS, id, "change the code of").await?;
+            }
             state.update_code(state_code);
         }
 
+        let mut tx = self.pool.begin().await?;
         sqlx::query(
-            "UPDATE states SET name = $1, code = $2, updated_at = $3 WHERE id = $4"
+            "UPDATE states SET name = $1, code = $2, updated_at = $3, normalized_name = $5, phonetic_key = $6 WHERE id = $4"
         )
         .bind(&state.name)
         .bind(state.code.to_string())
         .bind(&state.updated_at)
         .bind(&state.id)
-        .execute(&self.pool)
-        .await?;
+        .bind(normalized(&state.name))
+        .bind(phonetic_key(&state.name))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| duplicate_state(e, &state))?;
+
+        history::record(&mut tx, ChangeEntityType::State, id, Some(&before), Some(&state)).await?;
+        tx.commit().await?;
 
         Ok(state)
     }
 
     async fn delete(&self, id: Uuid) -> AppResult<()> {
-        let result = sqlx::query("DELETE FROM states WHERE id = $1")
-            .bind(&id)
-            .execute(&self.pool)
-            .await?;
-
-        if result.rows_affected() == 0 {
-            return Err(crate::errors::AppError::NotFound("State not found".to_string()));
+        let state = self.find_by_id(id).await?
+            .ok_or_else(|| crate::errors::AppError::NotFound("State not found".to_string()))?;
+        if state.status != UnitStatus::Active {
+            return Ok(());
         }
 
+        let request = UpdateStatusRequest { status: UnitStatus::Deprecated, successor_id: None };
+        self.set_status(id, &request).await?;
         Ok(())
     }
 
+    async fn set_status(&self, id: Uuid, request: &UpdateStatusRequest) -> AppResult<State> {
+        let mut tx = self.pool.begin().await?;
+        let row = sqlx::query(
+            "SELECT id, name, code, status, successor_id, created_at, updated_at FROM states WHERE id = $1 FOR UPDATE"
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let mut state = row.as_ref().map(state_from_row).transpose()?
+            .ok_or_else(|| crate::errors::AppError::NotFound("State not found".to_string()))?;
+        lifecycle::ensure_status_change_allowed(&mut tx, &lifecycle::STATES, id, None, request).await?;
+        let before = state.clone();
+        state.set_status(request.status, request.successor_id);
+
+        lifecycle::save_status(&mut tx, &lifecycle::STATES, id, state.status, state.successor_id, state.updated_at).await?;
+        history::record(&mut tx, ChangeEntityType::State, id, Some(&before), Some(&state)).await?;
+        tx.commit().await?;
+
+        Ok(state)
+    }
+
     async fn list(&self, page: u32, limit: u32) -> AppResult<Vec<State>> {
-        let offset = (page - 1) * limit;
+        let offset = page.saturating_sub(1) * limit;
         
         let rows = sqlx::query(
-            "SELECT id, name, code, created_at, updated_at FROM states ORDER BY name LIMIT $1 OFFSET $2"
+            "SELECT id, name, code, status, successor_id, created_at, updated_at FROM states WHERE status = 'active' ORDER BY name COLLATE \"C\", id LIMIT $1 OFFSET $2"
         )
         .bind(limit as i64)
         .bind(offset as i64)
         .fetch_all(&self.pool)
         .await?;
 
-        let mut states = Vec::new();
-        for row in rows {
-            let state_code = StateCode::new(row.get::<String, _>("code"))
-                .map_err(|e: StateCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-            
-            states.push(State {
-                id: row.get("id"),
-                name: row.get("name"),
-                code: state_code,
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            });
-        }
+        rows.iter().map(state_from_row).collect()
+    }
 
-        Ok(states)
+    async fn list_all(&self) -> AppResult<Vec<State>> {
+        let rows = sqlx::query(
+            "SELECT id, name, code, status, successor_id, created_at, updated_at FROM states WHERE status = 'active' ORDER BY name COLLATE \"C\", id"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(state_from_row).collect()
     }
 
     async fn count(&self) -> AppResult<u64> {
-        let row = sqlx::query("SELECT COUNT(*) as count FROM states")
+        let row = sqlx::query("SELECT COUNT(*) as count FROM states WHERE status = 'active'")
             .fetch_one(&self.pool)
             .await?;
 
@@ -193,32 +199,81 @@ impl StateRepository for PostgresStateRepository {
     }
 
     async fn search(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<State>> {
-        let offset = (page - 1) * limit;
+        let offset = page.saturating_sub(1) * limit;
         let search_pattern = format!("%{}%", query);
+        let normalized_pattern = format!("%{}%", normalized(query));
         
         let rows = sqlx::query(
-            "SELECT id, name, code, created_at, updated_at FROM states WHERE name ILIKE $1 OR code ILIKE $1 ORDER BY name LIMIT $2 OFFSET $3"
+            "SELECT id, name, code, status, successor_id, created_at, updated_at FROM states
+             WHERE status = 'active'
+               AND (name ILIKE $1 OR code ILIKE $1 OR normalized_name LIKE $4
+                    OR id IN (SELECT entity_id FROM aliases
+                              WHERE entity_type = 'state' AND (name ILIKE $1 OR normalized_name LIKE $4)))
+             ORDER BY name COLLATE \"C\", id LIMIT $2 OFFSET $3"
         )
         .bind(&search_pattern)
         .bind(limit as i64)
         .bind(offset as i64)
+        .bind(&normalized_pattern)
         .fetch_all(&self.pool)
         .await?;
 
-        let mut states = Vec::new();
-        for row in rows {
-            let state_code = StateCode::new(row.get::<String, _>("code"))
-                .map_err(|e: StateCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-            
-            states.push(State {
-                id: row.get("id"),
-                name: row.get("name"),
-                code: state_code,
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            });
+        rows.iter().map(state_from_row).collect()
+    }
+
+    async fn search_phonetic(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<State>> {
+        let key = phonetic_key(query);
+        if key.is_empty() {
+            return Ok(Vec::new());
         }
+        let offset = page.saturating_sub(1) * limit;
+
+        // Exact key first, then keys containing the query's words, then similar keys
+        let rows = sqlx::query(
+            "SELECT states.id, states.name, states.code, states.status, states.successor_id, states.created_at, states.updated_at FROM states
+             JOIN (
+                 SELECT entity_id, MAX(CASE WHEN phonetic_key = $1 THEN 1.0
+                                            WHEN ' ' || phonetic_key || ' ' LIKE $2 THEN 0.8
+                                            ELSE similarity(phonetic_key, $1) END) AS score
+                 FROM (
+                     SELECT id AS entity_id, phonetic_key FROM states
+                     UNION ALL
+                     SELECT entity_id, phonetic_key FROM aliases WHERE entity_type = 'state'
+                 ) keys
+                 WHERE phonetic_key = $1 OR ' ' || phonetic_key || ' ' LIKE $2 OR phonetic_key % $1
+                 GROUP BY entity_id
+             ) matches ON matches.entity_id = states.id
+             WHERE states.status = 'active'
+             ORDER BY matches.score DESC, states.name COLLATE \"C\", states.id LIMIT $3 OFFSET $4"
+        )
+        .bind(&key)
+        .bind(format!("% {} %", key))
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(&self.pool)
+        .await?;
 
-        Ok(states)
+        rows.iter().map(state_from_row).collect()
     }
-}
\ No newline at end of file
+}
+
+pub(crate) fn state_from_row(row: &PgRow) -> AppResult<State> {
+    let state_code = StateCode::new(row.get::<String, _>("code"))
+        .map_err(|e: StateCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+
+    Ok(State {
+        id: row.get("id"),
+        name: row.get("name"),
+        code: state_code,
+        status: lifecycle::status_from_row(row)?,
+        successor_id: row.get("successor_id"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+pub(crate) fn duplicate_state(error: sqlx::Error, state: &State) -> crate::errors::AppError {
+    integrity::conflict_on_duplicate(error, || {
+        format!("A state named '{}' or coded {} already exists", state.name, state.code)
+    })
+}
@@ -0,0 +1,294 @@
+//! Reading what changed since a sync watermark
+//!
+//! Every write sets `updated_at` to the start time of its transaction, which
+//! can commit well after a snapshot taken in between has been read. The
+//! watermark handed on is therefore held back to the start of the oldest
+//! transaction still open, so nothing committed late is skipped; clients may
+//! see some changes twice, which applying them again makes harmless.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgRow, PgConnection, PgPool, Row};
+use uuid::Uuid;
+
+use crate::domain::entities::sync::{
+    SyncBatch, SyncChanges, SyncPosition, SyncStage, Tombstone, TombstoneReason,
+};
+use crate::domain::entities::{Alias, ChangeEntityType, Retirable, UnitStatus};
+use crate::domain::repositories::sync_repository::SyncRepository;
+use crate::errors::{AppError, AppResult};
+use crate::infrastructure::repositories::alias_repository_impl::alias_from_row;
+use crate::infrastructure::repositories::export_repository_impl::begin_snapshot;
+use crate::infrastructure::repositories::{
+    lga_repository_impl::lga_from_row, postal_code_repository_impl::postal_code_from_row,
+    state_repository_impl::state_from_row, ward_repository_impl::ward_from_row,
+};
+
+/// Earliest time a change not yet committed could carry
+///
+/// Sessions of other database roles are only visible to roles that may read
+/// all statistics, so every writer should connect as the same role.
+const WATERMARK_SQL: &str = "SELECT least(clock_timestamp(), (
+        SELECT min(xact_start) FROM pg_stat_activity
+        WHERE xact_start IS NOT NULL AND pid <> pg_backend_pid()
+    ))";
+
+const STATE_COLUMNS: &str = "id, name, code, status, successor_id, created_at, updated_at";
+const LGA_COLUMNS: &str = "id, state_id, name, code, status, successor_id, created_at, updated_at";
+const WARD_COLUMNS: &str = "id, lga_id, name, code, status, successor_id, created_at, updated_at";
+const POSTAL_CODE_COLUMNS: &str =
+    "id, ward_id, postal_code, lat, lng, urban, status, successor_id, created_at, updated_at";
+const ALIAS_COLUMNS: &str =
+    "id, entity_type, entity_id, name, alias_type, language, created_at, updated_at";
+
+/// Watermark to hand on after reading a snapshot taken from now on
+///
+/// Taken before the snapshot: any change the snapshot misses was either
+/// still open here, or started later and so carries a later time.
+pub(crate) async fn watermark(pool: &PgPool) -> AppResult<DateTime<Utc>> {
+    let watermark = sqlx::query_scalar::<_, DateTime<Utc>>(WATERMARK_SQL)
+        .fetch_one(pool)
+        .await?;
+
+    Ok(watermark)
+}
+
+/// PostgreSQL implementation of SyncRepository
+#[derive(Clone)]
+pub struct PostgresSyncRepository {
+    pool: PgPool,
+}
+
+impl PostgresSyncRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SyncRepository for PostgresSyncRepository {
+    async fn changes_since(
+        &self,
+        since: Option<DateTime<Utc>>,
+        after: Option<SyncPosition>,
+        limit: usize,
+    ) -> AppResult<SyncBatch> {
+        let watermark = watermark(&self.pool).await?;
+        let (mut tx, _) = begin_snapshot(&self.pool).await?;
+
+        let mut batch = SyncBatch {
+            watermark,
+            ..Default::default()
+        };
+        let mut page = Page {
+            since,
+            remaining: limit,
+            next: None,
+        };
+        let first = after.map_or(SyncStage::States, |position| position.stage);
+        for stage in SyncStage::ALL.into_iter().filter(|stage| *stage >= first) {
+            // A full copy has nothing to delete
+            if stage == SyncStage::Deletions && since.is_none() {
+                break;
+            }
+            if page.remaining == 0 {
+                page.next = Some(SyncPosition { stage, after: None });
+                break;
+            }
+
+            let after = after
+                .filter(|position| position.stage == stage)
+                .and_then(|position| position.after);
+            let rows = page.fetch(&mut tx, stage, &stage_sql(stage), after).await?;
+            match stage {
+                SyncStage::States => batch.states = unit_changes(&rows, since, state_from_row)?,
+                SyncStage::Lgas => batch.lgas = unit_changes(&rows, since, lga_from_row)?,
+                SyncStage::Wards => batch.wards = unit_changes(&rows, since, ward_from_row)?,
+                SyncStage::PostalCodes => {
+                    batch.postal_codes = unit_changes(&rows, since, postal_code_from_row)?
+                }
+                SyncStage::Aliases => batch.aliases = alias_changes(&rows, since)?,
+                SyncStage::Deletions => {
+                    for (entity_type, tombstone) in deletions(&rows)? {
+                        let changes = match entity_type {
+                            ChangeEntityType::State => &mut batch.states.deleted,
+                            ChangeEntityType::Lga => &mut batch.lgas.deleted,
+                            ChangeEntityType::Ward => &mut batch.wards.deleted,
+                            ChangeEntityType::PostalCode => &mut batch.postal_codes.deleted,
+                            ChangeEntityType::Alias => &mut batch.aliases.deleted,
+                        };
+                        changes.push(tombstone);
+                    }
+                }
+            }
+            if page.next.is_some() {
+                break;
+            }
+        }
+
+        batch.next = page.next;
+        Ok(batch)
+    }
+}
+
+/// The part of a sync response still to be filled, and where it stopped
+/// once full
+struct Page {
+    since: Option<DateTime<Utc>>,
+    remaining: usize,
+    next: Option<SyncPosition>,
+}
+
+impl Page {
+    /// Rows of `stage` after `after`, as many as still fit
+    ///
+    /// `sql` takes `since`, the `updated_at` and `id` to start after, and a
+    /// row limit as `$1` to `$4`, and orders by `updated_at, id`.
+    async fn fetch(
+        &mut self,
+        connection: &mut PgConnection,
+        stage: SyncStage,
+        sql: &str,
+        after: Option<(DateTime<Utc>, Uuid)>,
+    ) -> AppResult<Vec<PgRow>> {
+        // One row more than fits tells whether there are more to come
+        let mut rows = sqlx::query(sql)
+            .bind(self.since)
+            .bind(after.map(|(updated_at, _)| updated_at))
+            .bind(after.map(|(_, id)| id))
+            .bind(self.remaining as i64 + 1)
+            .fetch_all(&mut *connection)
+            .await?;
+
+        if rows.len() > self.remaining {
+            rows.truncate(self.remaining);
+            self.next = Some(SyncPosition {
+                stage,
+                after: rows
+                    .last()
+                    .map(|row| (row.get("updated_at"), row.get("id"))),
+            });
+        }
+        self.remaining -= rows.len();
+        Ok(rows)
+    }
+}
+
+/// Query for the changes of one stage, taking `since`, the `updated_at` and
+/// `id` to start after, and a row limit as `$1` to `$4`
+///
+/// Units are those written at or after `since`, or without it every active
+/// unit; aliases those written at or after `since`, or all of them; and
+/// deletions the entities deleted outright at or after `since`, read from the
+/// change history.
+fn stage_sql(stage: SyncStage) -> String {
+    let (columns, table, filter) = match stage {
+        SyncStage::States => (STATE_COLUMNS, "states", UNITS_SINCE),
+        SyncStage::Lgas => (LGA_COLUMNS, "lgas", UNITS_SINCE),
+        SyncStage::Wards => (WARD_COLUMNS, "wards", UNITS_SINCE),
+        SyncStage::PostalCodes => (POSTAL_CODE_COLUMNS, "postal_codes", UNITS_SINCE),
+        SyncStage::Aliases => (
+            ALIAS_COLUMNS,
+            "aliases",
+            "($1::timestamptz IS NULL OR updated_at >= $1)",
+        ),
+        SyncStage::Deletions => (
+            "entity_type, entity_id AS id, changed_at AS updated_at",
+            "change_history",
+            "operation = 'delete' AND changed_at >= $1",
+        ),
+    };
+    let (updated_at, id) = match stage {
+        SyncStage::Deletions => ("changed_at", "entity_id"),
+        _ => ("updated_at", "id"),
+    };
+
+    format!(
+        "SELECT {columns} FROM {table}
+         WHERE {filter}
+            AND ($2::timestamptz IS NULL OR ({updated_at}, {id}) > ($2, $3::uuid))
+         ORDER BY {updated_at}, {id}
+         LIMIT $4"
+    )
+}
+
+/// Filter on units: changed at or after `since`, or every active one
+const UNITS_SINCE: &str =
+    "CASE WHEN $1::timestamptz IS NULL THEN status = 'active' ELSE updated_at >= $1 END";
+
+/// Split units into those created, those updated and those retired
+fn unit_changes<T, F>(
+    rows: &[PgRow],
+    since: Option<DateTime<Utc>>,
+    from_row: F,
+) -> AppResult<SyncChanges<T>>
+where
+    T: Retirable,
+    F: Fn(&PgRow) -> AppResult<T>,
+{
+    let mut changes = SyncChanges::default();
+    for row in rows {
+        let unit = from_row(row)?;
+        if unit.status() != UnitStatus::Active {
+            changes.deleted.push(Tombstone {
+                id: unit.id(),
+                reason: TombstoneReason::Retired,
+                successor_id: unit.successor_id(),
+                deleted_at: row.get("updated_at"),
+            });
+        } else if created_since(row, since) {
+            changes.created.push(unit);
+        } else {
+            changes.updated.push(unit);
+        }
+    }
+
+    Ok(changes)
+}
+
+/// Split aliases into those created and those updated
+fn alias_changes(rows: &[PgRow], since: Option<DateTime<Utc>>) -> AppResult<SyncChanges<Alias>> {
+    let mut changes = SyncChanges::default();
+    for row in rows {
+        let alias = alias_from_row(row)?;
+        if created_since(row, since) {
+            changes.created.push(alias);
+        } else {
+            changes.updated.push(alias);
+        }
+    }
+
+    Ok(changes)
+}
+
+/// Tombstones for entities deleted outright
+fn deletions(rows: &[PgRow]) -> AppResult<Vec<(ChangeEntityType, Tombstone)>> {
+    rows.iter()
+        .map(|row| {
+            let entity_type: String = row.get("entity_type");
+            let entity_type = ChangeEntityType::parse(&entity_type).ok_or_else(|| {
+                AppError::Internal(anyhow::anyhow!(
+                    "Unknown change entity type {}",
+                    entity_type
+                ))
+            })?;
+            let id: Uuid = row.get("id");
+
+            Ok((
+                entity_type,
+                Tombstone {
+                    id,
+                    reason: TombstoneReason::Deleted,
+                    successor_id: None,
+                    deleted_at: row.get("updated_at"),
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Whether the row was created at or after `since`; every row is new to a
+/// client without a copy
+fn created_since(row: &PgRow, since: Option<DateTime<Utc>>) -> bool {
+    since.is_none_or(|since| row.get::<DateTime<Utc>, _>("created_at") >= since)
+}
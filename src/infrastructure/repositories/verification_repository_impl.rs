@@ -0,0 +1,57 @@
+//! Reading every active unit for the integrity rules
+//!
+//! The rules compare levels with each other, so all four are read inside one
+//! repeatable-read transaction. The whole dataset fits comfortably in memory.
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::entities::export::ExportRow;
+use crate::domain::entities::unit_level::UnitLevel;
+use crate::domain::entities::verification::DatasetSnapshot;
+use crate::domain::repositories::verification_repository::VerificationRepository;
+use crate::errors::AppResult;
+use crate::infrastructure::repositories::export_repository_impl::{
+    begin_snapshot, export_row_from_row, level_sql,
+};
+
+/// PostgreSQL implementation of VerificationRepository
+#[derive(Clone)]
+pub struct PostgresVerificationRepository {
+    pool: PgPool,
+}
+
+impl PostgresVerificationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl VerificationRepository for PostgresVerificationRepository {
+    async fn snapshot(&self) -> AppResult<DatasetSnapshot> {
+        let (mut tx, fingerprint) = begin_snapshot(&self.pool).await?;
+
+        let mut snapshot = DatasetSnapshot {
+            fingerprint,
+            ..DatasetSnapshot::default()
+        };
+        for level in UnitLevel::ALL {
+            let rows: Vec<ExportRow> = sqlx::query(level_sql(level))
+                .fetch_all(&mut *tx)
+                .await?
+                .iter()
+                .map(export_row_from_row)
+                .collect();
+            match level {
+                UnitLevel::State => snapshot.states = rows,
+                UnitLevel::Lga => snapshot.lgas = rows,
+                UnitLevel::Ward => snapshot.wards = rows,
+                UnitLevel::PostalCode => snapshot.postal_codes = rows,
+            }
+        }
+        tx.commit().await?;
+
+        Ok(snapshot)
+    }
+}
@@ -1,11 +1,23 @@
 use async_trait::async_trait;
-use sqlx::{PgPool, Row};
+use sqlx::{postgres::PgRow, PgPool, Row};
 use uuid::Uuid;
 
+use crate::domain::entities::change::ChangeEntityType;
 use crate::domain::entities::ward::{Ward, CreateWardRequest, UpdateWardRequest};
+use crate::domain::entities::unit_status::{UnitStatus, UpdateStatusRequest};
+use crate::domain::normalization::normalized;
+use crate::domain::pagination::{scoped, KeysetPage, PageRequest};
+use crate::domain::phonetic::phonetic_key;
 use crate::domain::repositories::ward_repository::WardRepository;
 use crate::domain::value_objects::{WardCode, WardCodeError};
 use crate::errors::AppResult;
+use crate::infrastructure::repositories::history_repository_impl as history;
+use crate::infrastructure::repositories::integrity;
+use crate::infrastructure::repositories::lifecycle;
+use crate::infrastructure::repositories::keyset::{self, cursor_key, key_value};
+
+/// Listing name carried in cursors over an LGA's wards
+pub(crate) const WARDS_BY_LGA: &str = "wards_by_lga";
 
 /// PostgreSQL implementation of WardRepository
 #[derive(Clone)]
@@ -17,142 +29,140 @@ impl PostgresWardRepository {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Reject ward codes whose LGA part does not match the parent LGA
+    async fn ensure_code_matches_lga(&self, code: &WardCode, lga_id: Uuid) -> AppResult<()> {
+        let lga_code: Option<String> = sqlx::query_scalar("SELECT code FROM lgas WHERE id = $1")
+            .bind(lga_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        match lga_code {
+            Some(lga_code) if lga_code == code.lga_code().as_str() => Ok(()),
+            Some(lga_code) => Err(crate::errors::AppError::BadRequest(format!(
+                "Ward code {} does not belong to LGA {}", code, lga_code
+            ))),
+            None => Err(crate::errors::AppError::NotFound("LGA not found".to_string())),
+        }
+    }
 }
 
 #[async_trait]
 impl WardRepository for PostgresWardRepository {
     async fn create(&self, request: &CreateWardRequest, lga_id: Uuid) -> AppResult<Ward> {
         let ward_code = WardCode::new(request.code.clone())
-            .map_err(|e: WardCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+            .map_err(|e| crate::errors::AppError::invalid_param("code", e))?;
+        self.ensure_code_matches_lga(&ward_code, lga_id).await?;
 
         let ward = Ward::new(lga_id, request.name.clone(), ward_code);
 
+        let mut tx = self.pool.begin().await?;
+        lifecycle::ensure_active_parent(&mut tx, &lifecycle::LGAS, lga_id, "a ward").await?;
         sqlx::query(
-            "INSERT INTO wards (id, lga_id, name, code, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6)"
+            "INSERT INTO wards (id, lga_id, name, normalized_name, phonetic_key, code, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"
         )
         .bind(&ward.id)
         .bind(&ward.lga_id)
         .bind(&ward.name)
+        .bind(normalized(&ward.name))
+        .bind(phonetic_key(&ward.name))
         .bind(ward.code.to_string())
         .bind(&ward.created_at)
         .bind(&ward.updated_at)
-        .execute(&self.pool)
-        .await?;
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| duplicate_ward(e, &ward))?;
+
+        history::record(&mut tx, ChangeEntityType::Ward, ward.id, None, Some(&ward)).await?;
+        tx.commit().await?;
 
         Ok(ward)
     }
 
     async fn find_by_id(&self, id: Uuid) -> AppResult<Option<Ward>> {
         let row = sqlx::query(
-            "SELECT id, lga_id, name, code, created_at, updated_at FROM wards WHERE id = $1"
+            "SELECT id, lga_id, name, code, status, successor_id, created_at, updated_at FROM wards WHERE id = $1"
         )
         .bind(&id)
         .fetch_optional(&self.pool)
         .await?;
 
-        match row {
-            Some(row) => {
-                let ward_code = WardCode::new(row.get::<String, _>("code"))
-                    .map_err(|e: WardCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-                
-                Ok(Some(Ward {
-                    id: row.get("id"),
-                    lga_id: row.get("lga_id"),
-                    name: row.get("name"),
-                    code: ward_code,
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                }))
-            }
-            None => Ok(None),
-        }
+        row.as_ref().map(ward_from_row).transpose()
     }
 
     async fn find_by_name(&self, name: &str) -> AppResult<Option<Ward>> {
         let row = sqlx::query(
-            "SELECT id, lga_id, name, code, created_at, updated_at FROM wards WHERE name = $1"
+            "SELECT id, lga_id, name, code, status, successor_id, created_at, updated_at FROM wards
+             WHERE name = $1 OR normalized_name = $2
+                OR id IN (SELECT entity_id FROM aliases
+                          WHERE entity_type = 'ward' AND (lower(name) = lower($1) OR normalized_name = $2))
+             ORDER BY status = 'active' DESC, name = $1 DESC, normalized_name = $2 DESC, name COLLATE \"C\", id
+             LIMIT 1"
         )
         .bind(name)
+        .bind(normalized(name))
         .fetch_optional(&self.pool)
         .await?;
 
-        match row {
-            Some(row) => {
-                let ward_code = WardCode::new(row.get::<String, _>("code"))
-                    .map_err(|e: WardCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-                
-                Ok(Some(Ward {
-                    id: row.get("id"),
-                    lga_id: row.get("lga_id"),
-                    name: row.get("name"),
-                    code: ward_code,
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                }))
-            }
-            None => Ok(None),
-        }
+        row.as_ref().map(ward_from_row).transpose()
     }
 
     async fn find_by_code(&self, code: &WardCode) -> AppResult<Option<Ward>> {
         let row = sqlx::query(
-            "SELECT id, lga_id, name, code, created_at, updated_at FROM wards WHERE code = $1"
+            "SELECT id, lga_id, name, code, status, successor_id, created_at, updated_at FROM wards WHERE code = $1"
         )
         .bind(code.to_string())
         .fetch_optional(&self.pool)
         .await?;
 
-        match row {
-            Some(row) => {
-                let ward_code = WardCode::new(row.get::<String, _>("code"))
-                    .map_err(|e: WardCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-                
-                Ok(Some(Ward {
-                    id: row.get("id"),
-                    lga_id: row.get("lga_id"),
-                    name: row.get("name"),
-                    code: ward_code,
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                }))
-            }
-            None => Ok(None),
-        }
+        row.as_ref().map(ward_from_row).transpose()
     }
 
-    async fn find_by_lga_id(&self, lga_id: Uuid, page: u32, limit: u32) -> AppResult<Vec<Ward>> {
-        let offset = (page - 1) * limit;
-        
-        let rows = sqlx::query(
-            "SELECT id, lga_id, name, code, created_at, updated_at FROM wards WHERE lga_id = $1 ORDER BY name LIMIT $2 OFFSET $3"
-        )
+    async fn find_by_lga_id(&self, lga_id: Uuid, request: &PageRequest) -> AppResult<KeysetPage<Ward>> {
+        let listing = scoped(WARDS_BY_LGA, lga_id);
+        let boundary = cursor_key::<2>(request, &listing)?;
+        let (name, id) = match boundary {
+            Some([name, id]) => (Some(name.clone()), Some(key_value::<Uuid>(id)?)),
+            None => (None, None),
+        };
+
+        let rows = sqlx::query(&format!(
+            "SELECT id, lga_id, name, code, status, successor_id, created_at, updated_at FROM wards
+             WHERE lga_id = $1 AND status = 'active' AND ($2::text IS NULL OR (name COLLATE \"C\", id) {seek} ($2, $3))
+             ORDER BY name COLLATE \"C\" {order}, id {order} LIMIT $4 OFFSET $5",
+            seek = keyset::seek(request),
+            order = keyset::order(request),
+        ))
         .bind(&lga_id)
-        .bind(limit as i64)
-        .bind(offset as i64)
+        .bind(name)
+        .bind(id)
+        .bind(request.fetch_limit() as i64)
+        .bind(request.offset() as i64)
         .fetch_all(&self.pool)
         .await?;
 
-        let mut wards = Vec::new();
-        for row in rows {
-            let ward_code = WardCode::new(row.get::<String, _>("code"))
-                .map_err(|e: WardCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-            
-            wards.push(Ward {
-                id: row.get("id"),
-                lga_id: row.get("lga_id"),
-                name: row.get("name"),
-                code: ward_code,
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            });
-        }
+        let wards = rows.iter().map(ward_from_row).collect::<AppResult<Vec<_>>>()?;
+
+        Ok(KeysetPage::from_rows(wards, request, &listing, |ward| {
+            vec![ward.name.clone(), ward.id.to_string()]
+        }))
+    }
 
-        Ok(wards)
+    async fn find_by_lga_ids(&self, lga_ids: &[Uuid]) -> AppResult<Vec<Ward>> {
+        let rows = sqlx::query(
+            "SELECT id, lga_id, name, code, status, successor_id, created_at, updated_at FROM wards WHERE lga_id = ANY($1) AND status = 'active' ORDER BY name COLLATE \"C\", id"
+        )
+        .bind(lga_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(ward_from_row).collect()
     }
 
     async fn update(&self, id: Uuid, request: &UpdateWardRequest) -> AppResult<Ward> {
         let mut ward = self.find_by_id(id).await?
             .ok_or_else(|| crate::errors::AppError::NotFound("Ward not found".to_string()))?;
+        let before = ward.clone();
 
         if let Some(name) = &request.name {
             ward.update_name(name.clone());
@@ -160,38 +170,66 @@ impl WardRepository for PostgresWardRepository {
 
         if let Some(code) = &request.code {
             let ward_code = WardCode::new(code.clone())
-                .map_err(|e: WardCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+                .map_err(|e| crate::errors::AppError::invalid_param("code", e))?;
+            self.ensure_code_matches_lga(&ward_code, ward.lga_id).await?;
             ward.update_code(ward_code);
         }
 
+        let mut tx = self.pool.begin().await?;
         sqlx::query(
-            "UPDATE wards SET name = $1, code = $2, updated_at = $3 WHERE id = $4"
+            "UPDATE wards SET name = $1, code = $2, updated_at = $3, normalized_name = $5, phonetic_key = $6 WHERE id = $4"
         )
         .bind(&ward.name)
         .bind(ward.code.to_string())
         .bind(&ward.updated_at)
         .bind(&ward.id)
-        .execute(&self.pool)
-        .await?;
+        .bind(normalized(&ward.name))
+        .bind(phonetic_key(&ward.name))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| duplicate_ward(e, &ward))?;
+
+        history::record(&mut tx, ChangeEntityType::Ward, id, Some(&before), Some(&ward)).await?;
+        tx.commit().await?;
 
         Ok(ward)
     }
 
     async fn delete(&self, id: Uuid) -> AppResult<()> {
-        let result = sqlx::query("DELETE FROM wards WHERE id = $1")
-            .bind(&id)
-            .execute(&self.pool)
-            .await?;
-
-        if result.rows_affected() == 0 {
-            return Err(crate::errors::AppError::NotFound("Ward not found".to_string()));
+        let ward = self.find_by_id(id).await?
+            .ok_or_else(|| crate::errors::AppError::NotFound("Ward not found".to_string()))?;
+        if ward.status != UnitStatus::Active {
+            return Ok(());
         }
 
+        let request = UpdateStatusRequest { status: UnitStatus::Deprecated, successor_id: None };
+        self.set_status(id, &request).await?;
         Ok(())
     }
 
+    async fn set_status(&self, id: Uuid, request: &UpdateStatusRequest) -> AppResult<Ward> {
+        let mut tx = self.pool.begin().await?;
+        let row = sqlx::query(
+            "SELECT id, lga_id, name, code, status, successor_id, created_at, updated_at FROM wards WHERE id = $1 FOR UPDATE"
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let mut ward = row.as_ref().map(ward_from_row).transpose()?
+            .ok_or_else(|| crate::errors::AppError::NotFound("Ward not found".to_string()))?;
+        lifecycle::ensure_status_change_allowed(&mut tx, &lifecycle::WARDS, id, Some((&lifecycle::LGAS, ward.lga_id)), request).await?;
+        let before = ward.clone();
+        ward.set_status(request.status, request.successor_id);
+
+        lifecycle::save_status(&mut tx, &lifecycle::WARDS, id, ward.status, ward.successor_id, ward.updated_at).await?;
+        history::record(&mut tx, ChangeEntityType::Ward, id, Some(&before), Some(&ward)).await?;
+        tx.commit().await?;
+
+        Ok(ward)
+    }
+
     async fn count_by_lga(&self, lga_id: Uuid) -> AppResult<u64> {
-        let row = sqlx::query("SELECT COUNT(*) as count FROM wards WHERE lga_id = $1")
+        let row = sqlx::query("SELECT COUNT(*) as count FROM wards WHERE lga_id = $1 AND status = 'active'")
             .bind(&lga_id)
             .fetch_one(&self.pool)
             .await?;
@@ -200,33 +238,82 @@ impl WardRepository for PostgresWardRepository {
     }
 
     async fn search(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<Ward>> {
-        let offset = (page - 1) * limit;
+        let offset = page.saturating_sub(1) * limit;
         let search_pattern = format!("%{}%", query);
+        let normalized_pattern = format!("%{}%", normalized(query));
         
         let rows = sqlx::query(
-            "SELECT id, lga_id, name, code, created_at, updated_at FROM wards WHERE name ILIKE $1 OR code ILIKE $1 ORDER BY name LIMIT $2 OFFSET $3"
+            "SELECT id, lga_id, name, code, status, successor_id, created_at, updated_at FROM wards
+             WHERE status = 'active'
+               AND (name ILIKE $1 OR code ILIKE $1 OR normalized_name LIKE $4
+                    OR id IN (SELECT entity_id FROM aliases
+                              WHERE entity_type = 'ward' AND (name ILIKE $1 OR normalized_name LIKE $4)))
+             ORDER BY name COLLATE \"C\", id LIMIT $2 OFFSET $3"
         )
         .bind(&search_pattern)
         .bind(limit as i64)
         .bind(offset as i64)
+        .bind(&normalized_pattern)
         .fetch_all(&self.pool)
         .await?;
 
-        let mut wards = Vec::new();
-        for row in rows {
-            let ward_code = WardCode::new(row.get::<String, _>("code"))
-                .map_err(|e: WardCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
-            
-            wards.push(Ward {
-                id: row.get("id"),
-                lga_id: row.get("lga_id"),
-                name: row.get("name"),
-                code: ward_code,
-                created_at: row.get("created_at"),
-                updated_at: row.get("updated_at"),
-            });
+        rows.iter().map(ward_from_row).collect()
+    }
+
+    async fn search_phonetic(&self, query: &str, page: u32, limit: u32) -> AppResult<Vec<Ward>> {
+        let key = phonetic_key(query);
+        if key.is_empty() {
+            return Ok(Vec::new());
         }
+        let offset = page.saturating_sub(1) * limit;
 
-        Ok(wards)
+        // Exact key first, then keys containing the query's words, then similar keys
+        let rows = sqlx::query(
+            "SELECT wards.id, wards.lga_id, wards.name, wards.code, wards.status, wards.successor_id, wards.created_at, wards.updated_at FROM wards
+             JOIN (
+                 SELECT entity_id, MAX(CASE WHEN phonetic_key = $1 THEN 1.0
+                                            WHEN ' ' || phonetic_key || ' ' LIKE $2 THEN 0.8
+                                            ELSE similarity(phonetic_key, $1) END) AS score
+                 FROM (
+                     SELECT id AS entity_id, phonetic_key FROM wards
+                     UNION ALL
+                     SELECT entity_id, phonetic_key FROM aliases WHERE entity_type = 'ward'
+                 ) keys
+                 WHERE phonetic_key = $1 OR ' ' || phonetic_key || ' ' LIKE $2 OR phonetic_key % $1
+                 GROUP BY entity_id
+             ) matches ON matches.entity_id = wards.id
+             WHERE wards.status = 'active'
+             ORDER BY matches.score DESC, wards.name COLLATE \"C\", wards.id LIMIT $3 OFFSET $4"
+        )
+        .bind(&key)
+        .bind(format!("% {} %", key))
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(ward_from_row).collect()
     }
-}
\ No newline at end of file
+}
+
+pub(crate) fn ward_from_row(row: &PgRow) -> AppResult<Ward> {
+    let ward_code = WardCode::new(row.get::<String, _>("code"))
+        .map_err(|e: WardCodeError| crate::errors::AppError::Internal(anyhow::anyhow!(e)))?;
+
+    Ok(Ward {
+        id: row.get("id"),
+        lga_id: row.get("lga_id"),
+        name: row.get("name"),
+        code: ward_code,
+        status: lifecycle::status_from_row(row)?,
+        successor_id: row.get("successor_id"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+pub(crate) fn duplicate_ward(error: sqlx::Error, ward: &Ward) -> crate::errors::AppError {
+    integrity::conflict_on_duplicate(error, || {
+        format!("A ward coded {} or named '{}' in this LGA already exists", ward.code, ward.name)
+    })
+}
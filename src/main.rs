@@ -1,8 +1,10 @@
 use axum::{
-    routing::{get, post},
+    extract::DefaultBodyLimit,
+    routing::{get, post, put},
     Router,
 };
 use sqlx::PgPool;
+use std::sync::Arc;
 use tower_http::{
     cors::{Any, CorsLayer},
     trace::TraceLayer,
@@ -17,33 +19,53 @@ use nigeria_geo_api::{
         get_top_endpoints_handler, get_usage_by_api_key_handler, get_usage_by_ip_handler,
         get_usage_stats_handler, refresh_stats_views_handler,
     },
+    application::use_cases::batch_validation_use_cases::MAX_BATCH_UPLOAD_BYTES,
     config::Config,
-    infrastructure::repositories::{
-        address_repository_impl::PostgresAddressRepository,
-        api_usage_repository_impl::PostgresApiUsageRepository,
-        lga_repository_impl::PostgresLgaRepository,
-        postal_code_repository_impl::PostgresPostalCodeRepository,
-        state_repository_impl::PostgresStateRepository,
-        ward_repository_impl::PostgresWardRepository,
-    },
+    infrastructure::embedded::EmbeddedDataset,
     presentation::{
-        handlers::health_check_handler,
+        cli,
+        handlers::{
+            create_alias_handler, create_lga_handler, create_postal_code_handler,
+            create_state_handler, create_ward_handler, delete_alias_handler, delete_lga_handler,
+            delete_postal_code_handler, delete_state_handler, delete_ward_handler,
+            diff_dataset_handler, download_batch_results_handler, export_dataset_handler, get_alias_handler,
+            get_batch_job_handler, get_dataset_version_handler, get_entity_history_handler,
+            get_tree_handler, health_check_handler, latest_bundle_handler,
+            latest_bundle_manifest_handler, list_aliases_handler,
+            list_dataset_versions_handler, release_dataset_version_handler,
+            set_lga_status_handler, set_postal_code_status_handler, set_state_status_handler,
+            set_ward_status_handler, submit_batch_validation_handler, sync_handler, update_alias_handler, update_lga_handler, update_postal_code_handler,
+            update_state_handler, update_ward_handler, verify_dataset_handler,
+        },
         handlers_simple::{
-            find_address_by_components_handler, find_nearby_postal_codes_handler,
-            find_similar_addresses_handler, get_lga_by_id_handler, get_lgas_by_state_handler,
+            autocomplete_handler, find_address_by_components_handler, find_nearby_postal_codes_handler,
+            find_similar_addresses_handler, get_lga_by_code_handler, get_lga_by_id_handler,
+            get_lgas_by_state_handler, get_postal_code_breakdown_handler,
             get_postal_code_by_code_handler, get_postal_code_by_id_handler,
-            get_postal_codes_by_ward_handler, get_state_by_id_handler, get_states_handler,
-            get_ward_by_id_handler, get_wards_by_lga_handler, search_all_handler,
+            get_postal_codes_by_ward_handler, get_state_by_code_handler, get_state_by_id_handler,
+            get_states_handler, get_ward_by_code_handler, get_ward_by_id_handler,
+            get_wards_by_lga_handler, lookup_code_handler, route_not_found_handler, search_all_handler,
             search_lgas_handler, search_postal_codes_handler, search_states_handler,
             search_wards_handler, validate_address_handler,
         },
-        middleware::usage_tracking::track_usage_middleware,
+        middleware::{
+            admin_auth::{require_admin_token, AdminToken},
+            dataset_version::{pin_dataset_version, PinnedDatasets},
+            request_id::correlate_request,
+            usage_tracking::track_usage_middleware,
+        },
         state::AppState,
     },
 };
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Run a command-line tool instead of the server when one is named
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(command) = args.first() {
+        std::process::exit(cli::run(command, &args[1..]).await);
+    }
+
     // Initialize tracing
     tracing_subscriber::registry()
         .with(
@@ -62,6 +84,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         config.server_host, config.server_port
     );
 
+    // Serve a dataset bundle from memory instead of the database when one is configured
+    if let Some(dataset) = EmbeddedDataset::configured(config.embedded_dataset.as_deref()) {
+        return serve_embedded(&config, dataset?).await;
+    }
+
     // Create database connection pool with optimized settings
     let pool = sqlx::postgres::PgPoolOptions::new()
         .max_connections(5)
@@ -75,6 +102,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     info!("Database connection established");
 
+    match nigeria_geo_api::infrastructure::database::backfill_name_keys(&pool).await {
+        Ok(0) => {}
+        Ok(updated) => info!("Backfilled normalized names and phonetic keys for {} rows", updated),
+        Err(e) => warn!("Failed to backfill normalized names and phonetic keys: {}", e),
+    }
+
     // Initialize Redis cache with graceful fallback
     let redis_url =
         std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://localhost:6379".to_string());
@@ -97,88 +130,89 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     };
 
-    // Initialize repositories
-    let state_repository = PostgresStateRepository::new(pool.clone());
-    let lga_repository = PostgresLgaRepository::new(pool.clone());
-    let ward_repository = PostgresWardRepository::new(pool.clone());
-    let postal_code_repository = PostgresPostalCodeRepository::new(pool.clone());
-    let address_repository = PostgresAddressRepository::new(
-        pool.clone(),
-        Box::new(state_repository.clone()),
-        Box::new(lga_repository.clone()),
-        Box::new(ward_repository.clone()),
-        Box::new(postal_code_repository.clone()),
-    );
-
-    // Initialize API usage repository
-    let api_usage_repository = PostgresApiUsageRepository::new(pool.clone());
-
     // Initialize unified application state without caching
-    let app_state = AppState::new(
-        state_repository,
-        lga_repository,
-        ward_repository,
-        postal_code_repository,
-        address_repository,
-        api_usage_repository,
-        pool.clone(),
-    );
+    let app_state = AppState::from_pool(pool.clone());
 
-    // Create CORS layer
-    let cors = CorsLayer::new()
-        .allow_origin(Any)
-        .allow_methods(Any)
-        .allow_headers(Any);
+    // Build the autocomplete index and keep it in step with the database
+    if let Err(e) = app_state.autocomplete_use_cases.refresh().await {
+        warn!("Failed to build autocomplete index: {}", e);
+    }
+    app_state
+        .autocomplete_use_cases
+        .spawn_refresh(std::time::Duration::from_secs(
+            config.autocomplete_refresh_secs,
+        ));
+    app_state.batch_validation_use_cases.spawn_purge();
 
-    // Create the application router with all endpoints
-    let app = Router::new()
-        // Health endpoint
-        .route("/api/v1/health", get(health_check_handler))
-        // States endpoints
-        .route("/api/v1/states", get(get_states_handler))
-        .route("/api/v1/states/:id", get(get_state_by_id_handler))
-        .route("/api/v1/states/:id/lgas", get(get_lgas_by_state_handler))
-        // LGAs endpoints
-        .route("/api/v1/lgas/:id", get(get_lga_by_id_handler))
-        .route("/api/v1/lgas/:id/wards", get(get_wards_by_lga_handler))
-        // Wards endpoints
-        .route("/api/v1/wards/:id", get(get_ward_by_id_handler))
+    // Admin API for curating reference data, guarded by a bearer token
+    if config.admin_api_token.is_none() {
+        warn!("ADMIN_API_TOKEN is not set; the admin API is disabled");
+    }
+    let admin_token: AdminToken = config.admin_api_token.as_deref().map(Arc::from);
+    let admin = Router::new()
+        .route("/states", post(create_state_handler))
         .route(
-            "/api/v1/wards/:id/postal-codes",
-            get(get_postal_codes_by_ward_handler),
+            "/states/:id",
+            put(update_state_handler).delete(delete_state_handler),
         )
-        // Postal codes endpoints
+        .route("/states/:id/status", put(set_state_status_handler))
+        .route("/states/:id/lgas", post(create_lga_handler))
         .route(
-            "/api/v1/postal-codes/:id",
-            get(get_postal_code_by_id_handler),
+            "/lgas/:id",
+            put(update_lga_handler).delete(delete_lga_handler),
         )
+        .route("/lgas/:id/status", put(set_lga_status_handler))
+        .route("/lgas/:id/wards", post(create_ward_handler))
         .route(
-            "/api/v1/postal-codes/code/:code",
-            get(get_postal_code_by_code_handler),
+            "/wards/:id",
+            put(update_ward_handler).delete(delete_ward_handler),
         )
+        .route("/wards/:id/status", put(set_ward_status_handler))
+        .route("/wards/:id/postal-codes", post(create_postal_code_handler))
         .route(
-            "/api/v1/postal-codes/nearby",
-            get(find_nearby_postal_codes_handler),
+            "/postal-codes/:id",
+            put(update_postal_code_handler).delete(delete_postal_code_handler),
         )
-        // Address validation endpoints
-        .route("/api/v1/validate", post(validate_address_handler))
+        .route("/postal-codes/:id/status", put(set_postal_code_status_handler))
         .route(
-            "/api/v1/address/find",
-            get(find_address_by_components_handler),
+            "/aliases",
+            get(list_aliases_handler).post(create_alias_handler),
         )
         .route(
-            "/api/v1/address/similar",
-            post(find_similar_addresses_handler),
+            "/aliases/:id",
+            get(get_alias_handler)
+                .put(update_alias_handler)
+                .delete(delete_alias_handler),
         )
-        // Search endpoints
-        .route("/api/v1/search", get(search_all_handler))
-        .route("/api/v1/search/states", get(search_states_handler))
-        .route("/api/v1/search/lgas", get(search_lgas_handler))
-        .route("/api/v1/search/wards", get(search_wards_handler))
+        .route("/versions", post(release_dataset_version_handler))
+        .route("/history/diff", get(diff_dataset_handler))
+        .route("/history/:entity_type/:id", get(get_entity_history_handler))
+        .route("/verify", get(verify_dataset_handler))
+        .layer(axum::middleware::from_fn_with_state(
+            admin_token,
+            require_admin_token,
+        ));
+
+    // Create the application router with all endpoints
+    let app = Router::new()
+        // Health endpoint
+        .route("/api/v1/health", get(health_check_handler))
+        .merge(dataset_routes().layer(axum::middleware::from_fn_with_state(
+            PinnedDatasets::new(app_state.clone(), dataset_routes()),
+            pin_dataset_version,
+        )))
+        .merge(batch_validation_routes())
+        // Dataset releases
+        .route("/api/v1/versions", get(list_dataset_versions_handler))
+        .route("/api/v1/versions/:name", get(get_dataset_version_handler))
+        // Offline bundles
+        .route("/api/v1/bundles/latest", get(latest_bundle_handler))
         .route(
-            "/api/v1/search/postal-codes",
-            get(search_postal_codes_handler),
+            "/api/v1/bundles/latest/manifest",
+            get(latest_bundle_manifest_handler),
         )
+        // Delta sync
+        .route("/api/v1/sync", get(sync_handler))
         // API Usage Analytics endpoints
         .route(
             "/api/v1/analytics/usage-stats",
@@ -212,6 +246,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             "/api/v1/analytics/cleanup",
             post(cleanup_old_records_handler),
         )
+        .nest("/admin/v1", admin)
+        .fallback(route_not_found_handler)
         // OpenAPI documentation (temporarily disabled)
         // .route("/api-docs/openapi.json", get(openapi_json_handler))
         // .merge(SwaggerUi::new("/docs").url("/api-docs/openapi.json", ApiDoc::openapi()))
@@ -219,11 +255,54 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             app_state.clone(),
             track_usage_middleware,
         ))
-        .layer(cors)
+        .layer(cors())
+        .layer(TraceLayer::new_for_http())
+        .layer(axum::middleware::from_fn(correlate_request))
+        .with_state(app_state);
+
+    serve(&config, app).await
+}
+
+/// Serve lookups, search and validation from a dataset held in memory
+///
+/// Nothing here touches PostgreSQL: the admin API, releases, bundles, sync,
+/// export and usage analytics are not served, and requests cannot be pinned
+/// to a release.
+async fn serve_embedded(
+    config: &Config,
+    dataset: EmbeddedDataset,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (states, lgas, wards, postal_codes) = dataset.counts();
+    info!(
+        "Serving embedded dataset {}: {} states, {} LGAs, {} wards, {} postal codes",
+        dataset.version(),
+        states,
+        lgas,
+        wards,
+        postal_codes
+    );
+
+    let app_state = AppState::embedded(Arc::new(dataset));
+    if let Err(e) = app_state.autocomplete_use_cases.refresh().await {
+        warn!("Failed to build autocomplete index: {}", e);
+    }
+    app_state.batch_validation_use_cases.spawn_purge();
+
+    let app = Router::new()
+        .route("/api/v1/health", get(health_check_handler))
+        .merge(lookup_routes())
+        .merge(batch_validation_routes())
+        .fallback(route_not_found_handler)
+        .layer(cors())
         .layer(TraceLayer::new_for_http())
+        .layer(axum::middleware::from_fn(correlate_request))
         .with_state(app_state);
 
-    // Start the server
+    serve(config, app).await
+}
+
+/// Bind the configured address and serve `app` until the process ends
+async fn serve(config: &Config, app: Router) -> Result<(), Box<dyn std::error::Error>> {
     let listener =
         tokio::net::TcpListener::bind(format!("{}:{}", config.server_host, config.server_port))
             .await
@@ -241,3 +320,98 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// Allow requests from any origin
+fn cors() -> CorsLayer {
+    CorsLayer::new()
+        .allow_origin(Any)
+        .allow_methods(Any)
+        .allow_headers(Any)
+}
+
+/// Read routes that can be pinned to a dataset release
+///
+/// Served from the live data, or from a release's copy when the request
+/// names one; see [`pin_dataset_version`].
+fn dataset_routes() -> Router<AppState> {
+    lookup_routes().route("/api/v1/export", get(export_dataset_handler))
+}
+
+/// Batch address validation
+fn batch_validation_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/v1/validate/batch",
+            post(submit_batch_validation_handler)
+                .layer(DefaultBodyLimit::max(MAX_BATCH_UPLOAD_BYTES)),
+        )
+        .route(
+            "/api/v1/validate/batch/:job_id",
+            get(get_batch_job_handler),
+        )
+        .route(
+            "/api/v1/validate/batch/:job_id/results",
+            get(download_batch_results_handler),
+        )
+}
+
+/// Lookups, search and address validation, served from either the database
+/// or an embedded dataset
+fn lookup_routes() -> Router<AppState> {
+    Router::new()
+        // States endpoints
+        .route("/api/v1/states", get(get_states_handler))
+        .route("/api/v1/states/:id", get(get_state_by_id_handler))
+        .route("/api/v1/states/code/:code", get(get_state_by_code_handler))
+        .route("/api/v1/states/:id/lgas", get(get_lgas_by_state_handler))
+        // LGAs endpoints
+        .route("/api/v1/lgas/:id", get(get_lga_by_id_handler))
+        .route("/api/v1/lgas/code/:code", get(get_lga_by_code_handler))
+        .route("/api/v1/lgas/:id/wards", get(get_wards_by_lga_handler))
+        // Wards endpoints
+        .route("/api/v1/wards/:id", get(get_ward_by_id_handler))
+        .route("/api/v1/wards/code/:code", get(get_ward_by_code_handler))
+        .route(
+            "/api/v1/wards/:id/postal-codes",
+            get(get_postal_codes_by_ward_handler),
+        )
+        // Postal codes endpoints
+        .route(
+            "/api/v1/postal-codes/:id",
+            get(get_postal_code_by_id_handler),
+        )
+        .route(
+            "/api/v1/postal-codes/code/:code",
+            get(get_postal_code_by_code_handler),
+        )
+        .route(
+            "/api/v1/postal-codes/code/:code/breakdown",
+            get(get_postal_code_breakdown_handler),
+        )
+        .route(
+            "/api/v1/postal-codes/nearby",
+            get(find_nearby_postal_codes_handler),
+        )
+        // Address validation endpoints
+        .route("/api/v1/validate", post(validate_address_handler))
+        .route(
+            "/api/v1/address/find",
+            get(find_address_by_components_handler),
+        )
+        .route(
+            "/api/v1/address/similar",
+            post(find_similar_addresses_handler),
+        )
+        // Search endpoints
+        .route("/api/v1/search", get(search_all_handler))
+        .route("/api/v1/codes/:code", get(lookup_code_handler))
+        .route("/api/v1/tree", get(get_tree_handler))
+        .route("/api/v1/autocomplete", get(autocomplete_handler))
+        .route("/api/v1/search/states", get(search_states_handler))
+        .route("/api/v1/search/lgas", get(search_lgas_handler))
+        .route("/api/v1/search/wards", get(search_wards_handler))
+        .route(
+            "/api/v1/search/postal-codes",
+            get(search_postal_codes_handler),
+        )
+}
@@ -0,0 +1,101 @@
+//! `export`: write every active unit of one level to a file or stdout
+//!
+//! The output matches `GET /api/v1/export`. A summary with the row count and
+//! SHA-256 of the output is printed to stderr.
+
+use serde_json::json;
+use tokio::io::{AsyncWrite, AsyncWriteExt, BufWriter};
+
+use crate::application::dtos::ExportFormat;
+use crate::application::use_cases::export_use_cases::ExportUseCases;
+use crate::domain::entities::unit_level::UnitLevel;
+use crate::infrastructure::repositories::export_repository_impl::PostgresExportRepository;
+use crate::presentation::cli::{connect, usage_error, EXIT_FAILURE, EXIT_OK};
+
+struct ExportArgs {
+    format: ExportFormat,
+    level: UnitLevel,
+    output: Option<String>,
+}
+
+impl ExportArgs {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut parsed = Self {
+            format: ExportFormat::default(),
+            level: UnitLevel::PostalCode,
+            output: None,
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+            match arg.as_str() {
+                "--format" => {
+                    let name = value()?;
+                    parsed.format = ExportFormat::from_name(name)
+                        .ok_or_else(|| format!("unknown format '{}'", name))?;
+                }
+                "--level" => {
+                    let name = value()?;
+                    parsed.level = UnitLevel::parse(name)
+                        .ok_or_else(|| format!("unknown level '{}'", name))?;
+                }
+                "--output" | "-o" => parsed.output = Some(value()?.clone()),
+                other => return Err(format!("unexpected argument '{}'", other)),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+pub async fn run(args: &[String]) -> i32 {
+    let args = match ExportArgs::parse(args) {
+        Ok(args) => args,
+        Err(message) => return usage_error(&message),
+    };
+    let pool = match connect().await {
+        Ok(pool) => pool,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            return EXIT_FAILURE;
+        }
+    };
+    let use_cases = ExportUseCases::new(PostgresExportRepository::new(pool));
+
+    let output: Box<dyn AsyncWrite + Unpin + Send> = match &args.output {
+        Some(path) => match tokio::fs::File::create(path).await {
+            Ok(file) => Box::new(file),
+            Err(e) => {
+                eprintln!("error: cannot create '{}': {}", path, e);
+                return EXIT_FAILURE;
+            }
+        },
+        None => Box::new(tokio::io::stdout()),
+    };
+    let mut writer = BufWriter::new(output);
+
+    let summary = match use_cases.write(args.format, args.level, &mut writer).await {
+        Ok(summary) => summary,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return EXIT_FAILURE;
+        }
+    };
+    if let Err(e) = writer.shutdown().await {
+        eprintln!("error: {}", e);
+        return EXIT_FAILURE;
+    }
+
+    eprintln!(
+        "{}",
+        json!({
+            "level": args.level.as_str(),
+            "format": args.format.extension(),
+            "rows": summary.rows,
+            "bytes": summary.bytes,
+            "sha256": summary.sha256,
+        })
+    );
+    EXIT_OK
+}
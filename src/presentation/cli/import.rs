@@ -0,0 +1,103 @@
+//! `import`: load states, LGAs, wards and postal codes from a file
+//!
+//! Prints the report as JSON and exits with [`EXIT_FAILURE`] when any row was
+//! rejected, so scripted loads notice.
+
+use crate::application::use_cases::import_use_cases::{ImportFormat, ImportUseCases};
+use crate::domain::audit::as_actor;
+use crate::infrastructure::repositories::import_repository_impl::PostgresImportRepository;
+use crate::presentation::cli::{connect, usage_error, EXIT_FAILURE, EXIT_OK};
+
+/// Actor the history records imported changes under
+const IMPORT_ACTOR: &str = "import";
+
+struct ImportArgs {
+    path: String,
+    format: ImportFormat,
+    dry_run: bool,
+}
+
+impl ImportArgs {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut path = None;
+        let mut format = None;
+        let mut dry_run = false;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--dry-run" => dry_run = true,
+                "--format" => {
+                    let name = args.next().ok_or("--format needs a value")?;
+                    format = Some(
+                        ImportFormat::from_name(name)
+                            .ok_or_else(|| format!("unknown format '{}'", name))?,
+                    );
+                }
+                flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+                _ if path.is_some() => return Err("import takes one file".to_string()),
+                file => path = Some(file.to_string()),
+            }
+        }
+
+        let path = path.ok_or("import needs a file")?;
+        let format = format
+            .or_else(|| ImportFormat::from_file_name(&path))
+            .ok_or_else(|| format!("cannot tell the format of '{}'; pass --format", path))?;
+
+        Ok(Self {
+            path,
+            format,
+            dry_run,
+        })
+    }
+}
+
+pub async fn run(args: &[String]) -> i32 {
+    let args = match ImportArgs::parse(args) {
+        Ok(args) => args,
+        Err(message) => return usage_error(&message),
+    };
+
+    let data = match std::fs::read(&args.path) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("error: cannot read '{}': {}", args.path, e);
+            return EXIT_FAILURE;
+        }
+    };
+    let pool = match connect().await {
+        Ok(pool) => pool,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            return EXIT_FAILURE;
+        }
+    };
+
+    let use_cases = ImportUseCases::new(PostgresImportRepository::new(pool));
+    let report = match as_actor(
+        IMPORT_ACTOR,
+        use_cases.import(&data, args.format, args.dry_run),
+    )
+    .await
+    {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return EXIT_FAILURE;
+        }
+    };
+
+    match serde_json::to_string_pretty(&report) {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return EXIT_FAILURE;
+        }
+    }
+    if report.rejected.is_empty() {
+        EXIT_OK
+    } else {
+        EXIT_FAILURE
+    }
+}
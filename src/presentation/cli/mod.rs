@@ -0,0 +1,77 @@
+//! Commands run by the server binary instead of serving the API
+//!
+//! `nigeria-geo-api <command> [arguments]` runs the command against the
+//! database in `DATABASE_URL` and exits; with no command the binary starts the
+//! server.
+
+pub mod export;
+pub mod import;
+pub mod verify;
+
+use sqlx::postgres::PgPoolOptions;
+use sqlx::PgPool;
+
+use crate::config::Config;
+
+/// The command finished and found nothing wrong
+pub const EXIT_OK: i32 = 0;
+
+/// The command ran but reported problems, or failed
+pub const EXIT_FAILURE: i32 = 1;
+
+/// The command line could not be understood
+pub const EXIT_USAGE: i32 = 2;
+
+/// The command could not run: the database was unreachable, a query failed or
+/// the output could not be written. Used where [`EXIT_FAILURE`] means the
+/// command ran and found problems, so the two can be told apart
+pub const EXIT_ERROR: i32 = 3;
+
+const USAGE: &str = "Usage: nigeria-geo-api [command]
+
+Commands:
+  export [--format csv|json|ndjson|geojson] [--level state|lga|ward|postal_code] [--output <file>]
+      Write every active unit of a level, with its parents, to a file or stdout
+  import <file> [--format csv|json|geojson] [--dry-run]
+      Create or update states, LGAs, wards and postal codes from a file
+  verify [--rules <rule>,...] [--limit <findings per rule>]
+      Check the data against the integrity rules
+
+Without a command the API server starts.
+
+Exit codes:
+  0  success
+  1  export or import failed, import rejected rows, or verify found violations
+  2  the command line could not be understood
+  3  verify could not run, e.g. the database was unreachable";
+
+/// Run `command` with the `args` after it, returning the process exit code
+pub async fn run(command: &str, args: &[String]) -> i32 {
+    match command {
+        "export" => export::run(args).await,
+        "import" => import::run(args).await,
+        "verify" => verify::run(args).await,
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            EXIT_OK
+        }
+        _ => usage_error(&format!("unknown command '{}'", command)),
+    }
+}
+
+/// Report a command line that could not be understood
+pub(crate) fn usage_error(message: &str) -> i32 {
+    eprintln!("error: {}\n\n{}", message, USAGE);
+    EXIT_USAGE
+}
+
+/// Connect to the configured database
+pub(crate) async fn connect() -> Result<PgPool, String> {
+    let config = Config::load().map_err(|e| format!("Failed to load configuration: {}", e))?;
+    PgPoolOptions::new()
+        .max_connections(2)
+        .acquire_timeout(std::time::Duration::from_secs(5))
+        .connect(&config.database_url)
+        .await
+        .map_err(|e| format!("Failed to connect to the database: {}", e))
+}
@@ -0,0 +1,81 @@
+//! `verify`: run the integrity rules against the database
+//!
+//! The report matches `GET /admin/v1/verify` and is printed to stdout as
+//! JSON. The exit code is 0 when every rule passes, 1 when any rule finds
+//! violations and 3 when the rules could not be run, so the command can gate
+//! a data release without mistaking an outage for bad data.
+
+use crate::application::dtos::VerifyParams;
+use crate::application::use_cases::verification_use_cases::VerificationUseCases;
+use crate::infrastructure::repositories::verification_repository_impl::PostgresVerificationRepository;
+use crate::presentation::cli::{connect, usage_error, EXIT_ERROR, EXIT_FAILURE, EXIT_OK};
+
+fn parse(args: &[String]) -> Result<VerifyParams, String> {
+    let mut params = VerifyParams::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--rules" => params.rules = Some(value()?.clone()),
+            "--limit" => {
+                let limit = value()?;
+                params.limit = Some(
+                    limit
+                        .parse()
+                        .map_err(|_| format!("--limit must be a number, not '{}'", limit))?,
+                );
+            }
+            other => return Err(format!("unexpected argument '{}'", other)),
+        }
+    }
+
+    Ok(params)
+}
+
+pub async fn run(args: &[String]) -> i32 {
+    let params = match parse(args) {
+        Ok(params) => params,
+        Err(message) => return usage_error(&message),
+    };
+    let rules = match params.rules() {
+        Ok(rules) => rules,
+        Err(e) => return usage_error(&e.to_string()),
+    };
+    let limit = match params.limit() {
+        Ok(limit) => limit,
+        Err(e) => return usage_error(&e.to_string()),
+    };
+    let pool = match connect().await {
+        Ok(pool) => pool,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            return EXIT_ERROR;
+        }
+    };
+    let use_cases = VerificationUseCases::new(PostgresVerificationRepository::new(pool));
+
+    let report = match use_cases.verify(&rules, limit).await {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return EXIT_ERROR;
+        }
+    };
+    match serde_json::to_string_pretty(&report) {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return EXIT_ERROR;
+        }
+    }
+
+    for rule in report.rules.iter().filter(|rule| !rule.passed) {
+        eprintln!("{}: {} violations", rule.rule, rule.violations);
+    }
+    if report.passed {
+        EXIT_OK
+    } else {
+        EXIT_FAILURE
+    }
+}
@@ -1,8 +1,10 @@
 use axum::{extract::{Query, State}, Json};
 
 use crate::application::use_cases::address_use_cases::AddressUseCases;
-use crate::application::dtos::address_dto::{AddressValidationRequestDto, AddressValidationResponseDto, AddressDto};
+use crate::application::dtos::address_dto::{AddressValidationRequestDto, AddressValidationResponseDto, AddressDto, SimilarAddressDto};
+use crate::application::use_cases::address_use_cases::DEFAULT_SIMILAR_ADDRESS_LIMIT;
 use crate::domain::entities::address::AddressValidationRequest;
+use crate::domain::entities::search::MatchMode;
 use crate::errors::AppResult;
 
 /// Address controller
@@ -19,6 +21,7 @@ impl<AR: crate::domain::repositories::address_repository::AddressRepository> Add
 /// Validate an address
 pub async fn validate_address_handler<AR: crate::domain::repositories::address_repository::AddressRepository + Send + Sync>(
     State(controller): State<AddressController<AR>>,
+    Query(match_params): Query<MatchParams>,
     Json(request): Json<AddressValidationRequestDto>,
 ) -> AppResult<Json<AddressValidationResponseDto>>
 {
@@ -27,6 +30,7 @@ pub async fn validate_address_handler<AR: crate::domain::repositories::address_r
         lga: request.lga,
         ward: request.ward,
         postal_code: request.postal_code,
+        match_mode: match_params.match_mode,
     };
 
     let result = controller.address_use_cases.validate_address(validation_request).await?;
@@ -51,17 +55,22 @@ pub async fn find_address_by_components_handler<AR: crate::domain::repositories:
 /// Find similar addresses
 pub async fn find_similar_addresses_handler<AR: crate::domain::repositories::address_repository::AddressRepository + Send + Sync>(
     State(controller): State<AddressController<AR>>,
+    Query(params): Query<SimilarAddressParams>,
     Json(request): Json<AddressValidationRequestDto>,
-) -> AppResult<Json<Vec<AddressDto>>>
+) -> AppResult<Json<Vec<SimilarAddressDto>>>
 {
     let validation_request = AddressValidationRequest {
         state: request.state,
         lga: request.lga,
         ward: request.ward,
         postal_code: request.postal_code,
+        match_mode: MatchMode::default(),
     };
 
-    let result = controller.address_use_cases.find_similar_addresses(validation_request).await?;
+    let result = controller.address_use_cases.find_similar_addresses(
+        validation_request,
+        params.limit.unwrap_or(DEFAULT_SIMILAR_ADDRESS_LIMIT),
+    ).await?;
     Ok(Json(result))
 }
 
@@ -72,4 +81,18 @@ pub struct AddressComponentsParams {
     pub lga: String,
     pub ward: String,
     pub postal_code: String,
-}
\ No newline at end of file
+}
+
+/// Similar address search parameters
+#[derive(serde::Deserialize)]
+pub struct SimilarAddressParams {
+    pub limit: Option<u32>,
+}
+
+/// Address validation parameters
+#[derive(serde::Deserialize)]
+pub struct MatchParams {
+    /// `standard` (the default) or `phonetic`
+    #[serde(rename = "match", default)]
+    pub match_mode: MatchMode,
+}
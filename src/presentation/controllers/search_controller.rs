@@ -2,7 +2,8 @@ use axum::{extract::{Query, State}, Json};
 
 use crate::application::use_cases::search_use_cases::{SearchUseCases, SearchResultDto};
 use crate::application::dtos::{StateDto, LgaDto, WardDto, PostalCodeDto, PaginationParams};
-use crate::errors::AppResult;
+use crate::domain::entities::search::MatchMode;
+use crate::errors::{AppError, AppResult};
 
 /// Search controller
 #[derive(Clone)]
@@ -11,8 +12,9 @@ pub struct SearchController<
     LR: crate::domain::repositories::lga_repository::LgaRepository + Clone,
     WR: crate::domain::repositories::ward_repository::WardRepository + Clone,
     PR: crate::domain::repositories::postal_code_repository::PostalCodeRepository + Clone,
+    XR: crate::domain::repositories::search_repository::SearchRepository + Clone,
 > {
-    search_use_cases: SearchUseCases<SR, LR, WR, PR>,
+    search_use_cases: SearchUseCases<SR, LR, WR, PR, XR>,
 }
 
 impl<
@@ -20,8 +22,9 @@ impl<
     LR: crate::domain::repositories::lga_repository::LgaRepository + Clone,
     WR: crate::domain::repositories::ward_repository::WardRepository + Clone,
     PR: crate::domain::repositories::postal_code_repository::PostalCodeRepository + Clone,
-> SearchController<SR, LR, WR, PR> {
-    pub fn new(search_use_cases: SearchUseCases<SR, LR, WR, PR>) -> Self {
+    XR: crate::domain::repositories::search_repository::SearchRepository + Clone,
+> SearchController<SR, LR, WR, PR, XR> {
+    pub fn new(search_use_cases: SearchUseCases<SR, LR, WR, PR, XR>) -> Self {
         Self { search_use_cases }
     }
 }
@@ -32,13 +35,14 @@ pub async fn search_all_handler<
     LR: crate::domain::repositories::lga_repository::LgaRepository + Clone + Send + Sync,
     WR: crate::domain::repositories::ward_repository::WardRepository + Clone + Send + Sync,
     PR: crate::domain::repositories::postal_code_repository::PostalCodeRepository + Clone + Send + Sync,
+    XR: crate::domain::repositories::search_repository::SearchRepository + Clone + Send + Sync,
 >(
-    State(controller): State<SearchController<SR, LR, WR, PR>>,
+    State(controller): State<SearchController<SR, LR, WR, PR, XR>>,
     Query(params): Query<PaginationParams>,
     axum::extract::Query(search_params): axum::extract::Query<SearchParams>,
 ) -> AppResult<Json<SearchResultDto>>
 {
-    let result = controller.search_use_cases.search_all(&search_params.query, params).await?;
+    let result = controller.search_use_cases.search_all(&search_params.query, search_params.match_mode, params).await?;
     Ok(Json(result))
 }
 
@@ -48,13 +52,14 @@ pub async fn search_states_handler<
     LR: crate::domain::repositories::lga_repository::LgaRepository + Clone + Send + Sync,
     WR: crate::domain::repositories::ward_repository::WardRepository + Clone + Send + Sync,
     PR: crate::domain::repositories::postal_code_repository::PostalCodeRepository + Clone + Send + Sync,
+    XR: crate::domain::repositories::search_repository::SearchRepository + Clone + Send + Sync,
 >(
-    State(controller): State<SearchController<SR, LR, WR, PR>>,
+    State(controller): State<SearchController<SR, LR, WR, PR, XR>>,
     Query(params): Query<PaginationParams>,
     axum::extract::Query(search_params): axum::extract::Query<SearchParams>,
 ) -> AppResult<Json<Vec<StateDto>>>
 {
-    let result = controller.search_use_cases.search_states(&search_params.query, params).await?;
+    let result = controller.search_use_cases.search_states(&search_params.query, search_params.match_mode, params).await?;
     Ok(Json(result))
 }
 
@@ -64,13 +69,14 @@ pub async fn search_lgas_handler<
     LR: crate::domain::repositories::lga_repository::LgaRepository + Clone + Send + Sync,
     WR: crate::domain::repositories::ward_repository::WardRepository + Clone + Send + Sync,
     PR: crate::domain::repositories::postal_code_repository::PostalCodeRepository + Clone + Send + Sync,
+    XR: crate::domain::repositories::search_repository::SearchRepository + Clone + Send + Sync,
 >(
-    State(controller): State<SearchController<SR, LR, WR, PR>>,
+    State(controller): State<SearchController<SR, LR, WR, PR, XR>>,
     Query(params): Query<PaginationParams>,
     axum::extract::Query(search_params): axum::extract::Query<SearchParams>,
 ) -> AppResult<Json<Vec<LgaDto>>>
 {
-    let result = controller.search_use_cases.search_lgas(&search_params.query, params).await?;
+    let result = controller.search_use_cases.search_lgas(&search_params.query, search_params.match_mode, params).await?;
     Ok(Json(result))
 }
 
@@ -80,13 +86,14 @@ pub async fn search_wards_handler<
     LR: crate::domain::repositories::lga_repository::LgaRepository + Clone + Send + Sync,
     WR: crate::domain::repositories::ward_repository::WardRepository + Clone + Send + Sync,
     PR: crate::domain::repositories::postal_code_repository::PostalCodeRepository + Clone + Send + Sync,
+    XR: crate::domain::repositories::search_repository::SearchRepository + Clone + Send + Sync,
 >(
-    State(controller): State<SearchController<SR, LR, WR, PR>>,
+    State(controller): State<SearchController<SR, LR, WR, PR, XR>>,
     Query(params): Query<PaginationParams>,
     axum::extract::Query(search_params): axum::extract::Query<SearchParams>,
 ) -> AppResult<Json<Vec<WardDto>>>
 {
-    let result = controller.search_use_cases.search_wards(&search_params.query, params).await?;
+    let result = controller.search_use_cases.search_wards(&search_params.query, search_params.match_mode, params).await?;
     Ok(Json(result))
 }
 
@@ -96,12 +103,19 @@ pub async fn search_postal_codes_handler<
     LR: crate::domain::repositories::lga_repository::LgaRepository + Clone + Send + Sync,
     WR: crate::domain::repositories::ward_repository::WardRepository + Clone + Send + Sync,
     PR: crate::domain::repositories::postal_code_repository::PostalCodeRepository + Clone + Send + Sync,
+    XR: crate::domain::repositories::search_repository::SearchRepository + Clone + Send + Sync,
 >(
-    State(controller): State<SearchController<SR, LR, WR, PR>>,
+    State(controller): State<SearchController<SR, LR, WR, PR, XR>>,
     Query(params): Query<PaginationParams>,
     axum::extract::Query(search_params): axum::extract::Query<SearchParams>,
 ) -> AppResult<Json<Vec<PostalCodeDto>>>
 {
+    if search_params.match_mode == MatchMode::Phonetic {
+        return Err(AppError::BadRequest(
+            "match=phonetic applies to names, not postal codes".to_string(),
+        ));
+    }
+
     let result = controller.search_use_cases.search_postal_codes(&search_params.query, params).await?;
     Ok(Json(result))
 }
@@ -110,4 +124,7 @@ pub async fn search_postal_codes_handler<
 #[derive(serde::Deserialize)]
 pub struct SearchParams {
     pub query: String,
+    /// `standard` (the default) or `phonetic`
+    #[serde(rename = "match", default)]
+    pub match_mode: MatchMode,
 }
\ No newline at end of file
@@ -0,0 +1,202 @@
+//! Path, query and JSON extractors that reject bad input with structured 400s
+//!
+//! axum's own extractors answer a malformed UUID or a non-numeric `lat` with a
+//! plain-text body. These deserialize the same way but report the field that
+//! failed and the rule it broke as an [`AppError::InvalidParam`], so clients
+//! get the usual problem+json body with `invalid_params` filled in.
+
+use axum::{
+    async_trait,
+    extract::{
+        path::ErrorKind,
+        rejection::{JsonRejection, PathRejection},
+        FromRequest, FromRequestParts, MatchedPath, Path, Request,
+    },
+    http::request::Parts,
+    Json,
+};
+use serde::de::DeserializeOwned;
+
+use crate::errors::{AppError, InvalidParam};
+
+/// [`Path`] with rejections reported per parameter
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidPath<T>(pub T);
+
+/// [`axum::extract::Query`] with rejections reported per parameter
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidQuery<T>(pub T);
+
+/// [`Json`] with rejections reported per body field
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidJson<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequestParts<S> for ValidPath<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + Send,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match Path::<T>::from_request_parts(parts, state).await {
+            Ok(Path(value)) => Ok(ValidPath(value)),
+            Err(rejection) => Err(path_rejection(rejection, parts)),
+        }
+    }
+}
+
+#[async_trait]
+impl<S, T> FromRequestParts<S> for ValidQuery<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let query = parts.uri.query().unwrap_or_default();
+        let deserializer =
+            serde_urlencoded::Deserializer::new(form_urlencoded::parse(query.as_bytes()));
+
+        serde_path_to_error::deserialize(deserializer)
+            .map(ValidQuery)
+            .map_err(|e| field_error(e.path().to_string(), e.into_inner().to_string()))
+    }
+}
+
+#[async_trait]
+impl<S, T> FromRequest<S> for ValidJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = AppError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<serde_json::Value>::from_request(request, state)
+            .await
+            .map_err(json_rejection)?;
+
+        serde_path_to_error::deserialize(value)
+            .map(ValidJson)
+            .map_err(|e| field_error(e.path().to_string(), e.into_inner().to_string()))
+    }
+}
+
+fn path_rejection(rejection: PathRejection, parts: &Parts) -> AppError {
+    let PathRejection::FailedToDeserializePathParams(error) = rejection else {
+        // The route and the extractor disagree about the parameters: a bug
+        return AppError::Internal(anyhow::anyhow!(rejection.body_text()));
+    };
+
+    // Parameter names in route order, for errors that only give a position
+    let names: Vec<&str> = parts
+        .extensions
+        .get::<MatchedPath>()
+        .map(|path| {
+            path.as_str()
+                .split('/')
+                .filter_map(|segment| segment.strip_prefix(':'))
+                .collect()
+        })
+        .unwrap_or_default();
+    let name_at = |index: usize| names.get(index).copied().unwrap_or("path").to_string();
+
+    let param = match error.into_kind() {
+        ErrorKind::ParseErrorAtKey {
+            key,
+            value,
+            expected_type,
+        } => parse_error(key, &value, expected_type),
+        ErrorKind::ParseErrorAtIndex {
+            index,
+            value,
+            expected_type,
+        } => parse_error(name_at(index), &value, expected_type),
+        ErrorKind::ParseError {
+            value,
+            expected_type,
+        } => parse_error(name_at(0), &value, expected_type),
+        ErrorKind::InvalidUtf8InPathParam { key } => {
+            InvalidParam::new(key, "encoding", "is not valid UTF-8 once percent-decoded")
+        }
+        ErrorKind::Message(message) => InvalidParam::new(name_at(0), rule_for(&message), message),
+        kind => return AppError::Internal(anyhow::anyhow!(kind.to_string())),
+    };
+
+    AppError::InvalidParam(param)
+}
+
+fn json_rejection(rejection: JsonRejection) -> AppError {
+    let param = match &rejection {
+        JsonRejection::MissingJsonContentType(_) => {
+            InvalidParam::new("Content-Type", "content_type", "must be application/json")
+        }
+        JsonRejection::JsonSyntaxError(_) => {
+            InvalidParam::new("body", "syntax", rejection.body_text())
+        }
+        _ => return AppError::BadRequest(rejection.body_text()),
+    };
+
+    AppError::InvalidParam(param)
+}
+
+fn parse_error(field: String, value: &str, expected_type: &str) -> InvalidParam {
+    InvalidParam::new(
+        field,
+        "type",
+        format!("'{}' is not a valid {}", value, type_name(expected_type)),
+    )
+}
+
+/// Error for a query or body field serde rejected
+///
+/// serde reports a missing field against the struct that lacks it, so the
+/// field's own name is taken from the message in that case.
+fn field_error(path: String, message: String) -> AppError {
+    let missing = message
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.split('`').next());
+
+    let field = match (path.as_str(), missing) {
+        (".", Some(name)) => name.to_string(),
+        (".", None) => "body".to_string(),
+        (_, Some(name)) => format!("{}.{}", path, name),
+        (_, None) => path,
+    };
+
+    AppError::InvalidParam(InvalidParam::new(field, rule_for(&message), message))
+}
+
+/// Rule a serde error message describes
+fn rule_for(message: &str) -> &'static str {
+    if message.starts_with("missing field") {
+        "required"
+    } else if message.starts_with("unknown variant") {
+        "one_of"
+    } else if message.starts_with("unknown field") {
+        "unknown_field"
+    } else if message.starts_with("invalid")
+        || message.starts_with("cannot parse")
+        || message.starts_with("number too large")
+        || message.starts_with("UUID parsing failed")
+    {
+        "type"
+    } else {
+        "format"
+    }
+}
+
+/// Readable name for a Rust type named in a path parse error
+fn type_name(expected_type: &str) -> &str {
+    match expected_type.rsplit("::").next().unwrap_or(expected_type) {
+        "Uuid" => "UUID",
+        "u8" | "u16" | "u32" | "u64" | "usize" => "non-negative integer",
+        "i8" | "i16" | "i32" | "i64" | "isize" => "integer",
+        "f32" | "f64" => "number",
+        "bool" => "boolean (true or false)",
+        other => other,
+    }
+}
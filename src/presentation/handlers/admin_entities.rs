@@ -0,0 +1,224 @@
+//! Corrections to states, LGAs, wards and postal codes
+//!
+//! Every write refreshes the in-memory indexes before responding, so the
+//! change shows in search and autocomplete as soon as the call returns.
+//!
+//! Units are never removed: deleting one deprecates it, and `PUT .../status`
+//! deprecates it, merges it into a successor or reactivates it. Retired units
+//! drop out of listings and search, while lookups by ID or code lead to their
+//! successor.
+
+use axum::{extract::State, http::StatusCode, Json};
+use uuid::Uuid;
+
+use crate::{
+    application::dtos::{LgaDto, PostalCodeDto, StateDto, WardDto},
+    domain::entities::{
+        lga::{CreateLgaRequest, UpdateLgaRequest},
+        postal_code::{CreatePostalCodeRequest, UpdatePostalCodeRequest},
+        state::{CreateStateRequest, UpdateStateRequest},
+        unit_status::UpdateStatusRequest,
+        ward::{CreateWardRequest, UpdateWardRequest},
+    },
+    errors::AppResult,
+    presentation::{
+        extractors::{ValidJson, ValidPath},
+        state::AppState,
+    },
+};
+
+/// Add a state
+pub async fn create_state_handler(
+    State(app_state): State<AppState>,
+    ValidJson(request): ValidJson<CreateStateRequest>,
+) -> AppResult<(StatusCode, Json<StateDto>)> {
+    let result = app_state.state_use_cases.create_state(request).await?;
+    app_state.data_changed().await;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// Rename a state or correct its code; the code only while it has no LGAs
+pub async fn update_state_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+    ValidJson(request): ValidJson<UpdateStateRequest>,
+) -> AppResult<Json<StateDto>> {
+    let result = app_state.state_use_cases.update_state(id, request).await?;
+    app_state.data_changed().await;
+    Ok(Json(result))
+}
+
+/// Deprecate a state; refused while it still has active LGAs
+pub async fn delete_state_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+) -> AppResult<StatusCode> {
+    app_state.state_use_cases.delete_state(id).await?;
+    app_state.data_changed().await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Deprecate a state, merge it into a successor, or reactivate it
+pub async fn set_state_status_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+    ValidJson(request): ValidJson<UpdateStatusRequest>,
+) -> AppResult<Json<StateDto>> {
+    let result = app_state
+        .state_use_cases
+        .set_state_status(id, request)
+        .await?;
+    app_state.data_changed().await;
+    Ok(Json(result))
+}
+
+/// Add an LGA to a state; its code must start with the state's
+pub async fn create_lga_handler(
+    State(app_state): State<AppState>,
+    ValidPath(state_id): ValidPath<Uuid>,
+    ValidJson(request): ValidJson<CreateLgaRequest>,
+) -> AppResult<(StatusCode, Json<LgaDto>)> {
+    let result = app_state
+        .lga_use_cases
+        .create_lga(state_id, request)
+        .await?;
+    app_state.data_changed().await;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// Rename an LGA or correct its code; the code only while it has no wards
+pub async fn update_lga_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+    ValidJson(request): ValidJson<UpdateLgaRequest>,
+) -> AppResult<Json<LgaDto>> {
+    let result = app_state.lga_use_cases.update_lga(id, request).await?;
+    app_state.data_changed().await;
+    Ok(Json(result))
+}
+
+/// Deprecate an LGA; refused while it still has active wards
+pub async fn delete_lga_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+) -> AppResult<StatusCode> {
+    app_state.lga_use_cases.delete_lga(id).await?;
+    app_state.data_changed().await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Deprecate an LGA, merge it into a successor, or reactivate it
+pub async fn set_lga_status_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+    ValidJson(request): ValidJson<UpdateStatusRequest>,
+) -> AppResult<Json<LgaDto>> {
+    let result = app_state.lga_use_cases.set_lga_status(id, request).await?;
+    app_state.data_changed().await;
+    Ok(Json(result))
+}
+
+/// Add a ward to an LGA; its code must start with the LGA's
+pub async fn create_ward_handler(
+    State(app_state): State<AppState>,
+    ValidPath(lga_id): ValidPath<Uuid>,
+    ValidJson(request): ValidJson<CreateWardRequest>,
+) -> AppResult<(StatusCode, Json<WardDto>)> {
+    let result = app_state
+        .ward_use_cases
+        .create_ward(lga_id, request)
+        .await?;
+    app_state.data_changed().await;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// Rename a ward or correct its code
+pub async fn update_ward_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+    ValidJson(request): ValidJson<UpdateWardRequest>,
+) -> AppResult<Json<WardDto>> {
+    let result = app_state.ward_use_cases.update_ward(id, request).await?;
+    app_state.data_changed().await;
+    Ok(Json(result))
+}
+
+/// Deprecate a ward; refused while it still has active postal codes
+pub async fn delete_ward_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+) -> AppResult<StatusCode> {
+    app_state.ward_use_cases.delete_ward(id).await?;
+    app_state.data_changed().await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Deprecate a ward, merge it into a successor, or reactivate it
+pub async fn set_ward_status_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+    ValidJson(request): ValidJson<UpdateStatusRequest>,
+) -> AppResult<Json<WardDto>> {
+    let result = app_state
+        .ward_use_cases
+        .set_ward_status(id, request)
+        .await?;
+    app_state.data_changed().await;
+    Ok(Json(result))
+}
+
+/// Add a postal code to a ward; it must lie in the postal region of the
+/// ward's state
+pub async fn create_postal_code_handler(
+    State(app_state): State<AppState>,
+    ValidPath(ward_id): ValidPath<Uuid>,
+    ValidJson(request): ValidJson<CreatePostalCodeRequest>,
+) -> AppResult<(StatusCode, Json<PostalCodeDto>)> {
+    let result = app_state
+        .postal_code_use_cases
+        .create_postal_code(ward_id, request)
+        .await?;
+    app_state.data_changed().await;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+/// Correct a postal code, its coordinates or its urban flag
+pub async fn update_postal_code_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+    ValidJson(request): ValidJson<UpdatePostalCodeRequest>,
+) -> AppResult<Json<PostalCodeDto>> {
+    let result = app_state
+        .postal_code_use_cases
+        .update_postal_code(id, request)
+        .await?;
+    app_state.data_changed().await;
+    Ok(Json(result))
+}
+
+/// Deprecate a postal code
+pub async fn delete_postal_code_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+) -> AppResult<StatusCode> {
+    app_state
+        .postal_code_use_cases
+        .delete_postal_code(id)
+        .await?;
+    app_state.data_changed().await;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Deprecate a postal code, merge it into a successor, or reactivate it
+pub async fn set_postal_code_status_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+    ValidJson(request): ValidJson<UpdateStatusRequest>,
+) -> AppResult<Json<PostalCodeDto>> {
+    let result = app_state
+        .postal_code_use_cases
+        .set_postal_code_status(id, request)
+        .await?;
+    app_state.data_changed().await;
+    Ok(Json(result))
+}
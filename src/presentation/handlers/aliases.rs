@@ -0,0 +1,74 @@
+use axum::{extract::State, http::StatusCode, Json};
+use uuid::Uuid;
+
+use crate::{
+    application::dtos::{AliasDto, PaginatedResponse, PaginationParams},
+    domain::entities::alias::{CreateAliasRequest, UpdateAliasRequest},
+    errors::{AppError, AppResult},
+    presentation::{
+        extractors::{ValidJson, ValidPath, ValidQuery},
+        state::AppState,
+    },
+};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct AliasFilterParams {
+    /// Only aliases of this entity type: `state`, `lga` or `ward`
+    pub entity_type: Option<String>,
+    /// Only aliases of this entity
+    pub entity_id: Option<Uuid>,
+}
+
+/// List aliases, optionally filtered by entity
+pub async fn list_aliases_handler(
+    State(app_state): State<AppState>,
+    ValidQuery(params): ValidQuery<PaginationParams>,
+    ValidQuery(filter): ValidQuery<AliasFilterParams>,
+) -> AppResult<Json<PaginatedResponse<AliasDto>>> {
+    let result = app_state
+        .alias_use_cases
+        .get_aliases(filter.entity_type.as_deref(), filter.entity_id, params)
+        .await?;
+    Ok(Json(result))
+}
+
+pub async fn get_alias_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+) -> AppResult<Json<AliasDto>> {
+    let result = app_state
+        .alias_use_cases
+        .get_alias_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Alias not found".to_string()))?;
+    Ok(Json(result))
+}
+
+/// Add an alias to a state, LGA or ward
+pub async fn create_alias_handler(
+    State(app_state): State<AppState>,
+    ValidJson(request): ValidJson<CreateAliasRequest>,
+) -> AppResult<(StatusCode, Json<AliasDto>)> {
+    let result = app_state.alias_use_cases.create_alias(request).await?;
+    app_state.data_changed().await;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+pub async fn update_alias_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+    ValidJson(request): ValidJson<UpdateAliasRequest>,
+) -> AppResult<Json<AliasDto>> {
+    let result = app_state.alias_use_cases.update_alias(id, request).await?;
+    app_state.data_changed().await;
+    Ok(Json(result))
+}
+
+pub async fn delete_alias_handler(
+    State(app_state): State<AppState>,
+    ValidPath(id): ValidPath<Uuid>,
+) -> AppResult<StatusCode> {
+    app_state.alias_use_cases.delete_alias(id).await?;
+    app_state.data_changed().await;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,153 @@
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Multipart, Request, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        dtos::{batch_validation_dto::BatchResultsParams, BatchJobDto},
+        use_cases::batch_validation_use_cases::{BatchFormat, BatchRow, BatchSubmission},
+    },
+    errors::{AppError, AppResult},
+    presentation::{
+        extractors::{ValidPath, ValidQuery},
+        state::AppState,
+    },
+};
+
+/// Submit a batch of addresses for validation
+///
+/// Accepts a JSON array, an NDJSON or CSV body, or a multipart upload with a
+/// `file` field (`.csv`, `.ndjson` or `.json`). Small batches are validated
+/// inline and return `200 OK`; large batches are queued as a background job
+/// and return `202 Accepted` with URLs for polling and downloading results,
+/// or `503 Service Unavailable` while too many jobs are already pending.
+pub async fn submit_batch_validation_handler(
+    State(app_state): State<AppState>,
+    request: Request,
+) -> AppResult<Response> {
+    let content_type = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("application/json")
+        .to_string();
+
+    let rows = if content_type.starts_with("multipart/form-data") {
+        let multipart = Multipart::from_request(request, &app_state)
+            .await
+            .map_err(|e| AppError::BadRequest(e.body_text()))?;
+        read_upload(multipart).await?
+    } else {
+        let format = BatchFormat::from_content_type(&content_type).ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "Unsupported content type '{}'; send JSON, NDJSON, CSV or a multipart upload",
+                content_type
+            ))
+        })?;
+        let body = Bytes::from_request(request, &app_state)
+            .await
+            .map_err(|e| AppError::BadRequest(e.body_text()))?;
+        format.parse(&body)?
+    };
+
+    match app_state.batch_validation_use_cases.submit(rows).await? {
+        BatchSubmission::Completed(result) => Ok((StatusCode::OK, Json(result)).into_response()),
+        BatchSubmission::Queued(job) => Ok((
+            StatusCode::ACCEPTED,
+            [(header::LOCATION, job.status_url.clone())],
+            Json(job),
+        )
+            .into_response()),
+    }
+}
+
+/// Read the `file` field of a multipart upload
+async fn read_upload(mut multipart: Multipart) -> AppResult<Vec<BatchRow>> {
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::BadRequest(e.body_text()))?
+    {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        // Browsers often send spreadsheets as application/octet-stream, so trust the extension first
+        let format = field
+            .file_name()
+            .and_then(BatchFormat::from_file_name)
+            .or_else(|| {
+                field
+                    .content_type()
+                    .and_then(BatchFormat::from_content_type)
+            })
+            .ok_or_else(|| {
+                AppError::BadRequest(
+                    "Could not determine upload format; use a .csv, .ndjson or .json file"
+                        .to_string(),
+                )
+            })?;
+
+        let data = field
+            .bytes()
+            .await
+            .map_err(|e| AppError::BadRequest(e.body_text()))?;
+        return format.parse(&data);
+    }
+
+    Err(AppError::BadRequest(
+        "Multipart upload must include a 'file' field".to_string(),
+    ))
+}
+
+/// Get the status of a background batch validation job
+pub async fn get_batch_job_handler(
+    State(app_state): State<AppState>,
+    ValidPath(job_id): ValidPath<Uuid>,
+) -> AppResult<Json<BatchJobDto>> {
+    let job = app_state.batch_validation_use_cases.get_job(job_id).await?;
+    Ok(Json(job))
+}
+
+/// Download the results of a completed batch job as JSON, NDJSON or CSV
+pub async fn download_batch_results_handler(
+    State(app_state): State<AppState>,
+    ValidPath(job_id): ValidPath<Uuid>,
+    ValidQuery(params): ValidQuery<BatchResultsParams>,
+) -> AppResult<Response> {
+    let format = match params.format.as_deref() {
+        Some(name) => BatchFormat::from_name(name).ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "Unsupported format '{}'; use json, ndjson or csv",
+                name
+            ))
+        })?,
+        None => BatchFormat::Ndjson,
+    };
+
+    let body = app_state
+        .batch_validation_use_cases
+        .export_results(job_id, format)
+        .await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"batch-{}.{}\"",
+                    job_id,
+                    format.extension()
+                ),
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
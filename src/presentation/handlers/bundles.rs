@@ -0,0 +1,91 @@
+//! Offline bundles for the mobile SDKs
+//!
+//! The manifest is small enough to poll; clients download the bundle only
+//! when its `version` differs from the one they hold, and check it against
+//! the manifest's `sha256`.
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use tokio_util::io::ReaderStream;
+
+use crate::{
+    application::dtos::BundleManifestDto,
+    errors::AppResult,
+    presentation::{
+        handlers::export::{if_none_match, CHECKSUM_HEADER},
+        state::AppState,
+    },
+};
+
+/// Version of the bundle being sent
+pub const BUNDLE_VERSION_HEADER: HeaderName = HeaderName::from_static("x-bundle-version");
+
+/// Manifest of the latest bundle: its version, size and SHA-256
+pub async fn latest_bundle_manifest_handler(
+    State(app_state): State<AppState>,
+) -> AppResult<Response> {
+    let bundle = app_state.bundle_use_cases.latest().await?;
+
+    Ok((
+        [(header::CACHE_CONTROL, "no-cache")],
+        Json::<BundleManifestDto>(bundle.manifest.clone()),
+    )
+        .into_response())
+}
+
+/// The latest bundle: a gzip-compressed SQLite database of every active unit
+/// and alias, with a full-text search index
+///
+/// The bundle's SHA-256 is sent as the `ETag` and in `X-Checksum-Sha256`; a
+/// request whose `If-None-Match` names it is answered `304 Not Modified`.
+pub async fn latest_bundle_handler(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+) -> AppResult<Response> {
+    let bundle = app_state.bundle_use_cases.latest().await?;
+    let manifest = &bundle.manifest;
+
+    let etag = format!("\"{}\"", manifest.sha256);
+    let validators = [
+        (
+            header::ETAG,
+            HeaderValue::from_str(&etag).expect("etag header is ASCII"),
+        ),
+        // "latest" moves, so caches must check before reusing it
+        (header::CACHE_CONTROL, HeaderValue::from_static("no-cache")),
+        (
+            CHECKSUM_HEADER,
+            HeaderValue::from_str(&manifest.sha256).expect("checksum header is ASCII"),
+        ),
+        (
+            BUNDLE_VERSION_HEADER,
+            HeaderValue::from_str(&manifest.version).expect("bundle version is ASCII"),
+        ),
+    ];
+    if if_none_match(&headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, validators).into_response());
+    }
+
+    // The open file outlives the bundle being replaced after a data change
+    let file = tokio::fs::File::open(&bundle.path).await?;
+    let disposition = format!(
+        "attachment; filename=\"nigeria-geo-{}.sqlite.gz\"",
+        manifest.version
+    );
+
+    Ok((
+        validators,
+        [
+            (header::CONTENT_TYPE, "application/gzip".to_string()),
+            (header::CONTENT_LENGTH, manifest.size.to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        Body::from_stream(ReaderStream::new(file)),
+    )
+        .into_response())
+}
@@ -0,0 +1,45 @@
+//! Named releases of the dataset
+//!
+//! Reads are pinned to a release with `X-Dataset-Version` or `?as_of=`; see
+//! [`crate::presentation::middleware::dataset_version`].
+
+use axum::{extract::State, http::StatusCode, Json};
+
+use crate::{
+    application::dtos::{DatasetVersionDetailDto, DatasetVersionDto},
+    domain::entities::dataset_version::CreateDatasetVersionRequest,
+    errors::AppResult,
+    presentation::{
+        extractors::{ValidJson, ValidPath},
+        state::AppState,
+    },
+};
+
+/// All releases, newest first, with how much each changed
+pub async fn list_dataset_versions_handler(
+    State(app_state): State<AppState>,
+) -> AppResult<Json<Vec<DatasetVersionDto>>> {
+    let result = app_state.dataset_version_use_cases.list_versions().await?;
+    Ok(Json(result))
+}
+
+/// One release with its changelog
+pub async fn get_dataset_version_handler(
+    State(app_state): State<AppState>,
+    ValidPath(name): ValidPath<String>,
+) -> AppResult<Json<DatasetVersionDetailDto>> {
+    let result = app_state
+        .dataset_version_use_cases
+        .get_version(&name)
+        .await?;
+    Ok(Json(result))
+}
+
+/// Release the live data as it is now under a new name
+pub async fn release_dataset_version_handler(
+    State(app_state): State<AppState>,
+    ValidJson(request): ValidJson<CreateDatasetVersionRequest>,
+) -> AppResult<(StatusCode, Json<DatasetVersionDto>)> {
+    let result = app_state.dataset_version_use_cases.release(request).await?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
@@ -0,0 +1,88 @@
+use axum::{
+    body::Body,
+    extract::State,
+    http::{header, HeaderMap, HeaderName, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use tokio_util::io::ReaderStream;
+
+use crate::{
+    application::dtos::ExportParams,
+    errors::AppResult,
+    presentation::{extractors::ValidQuery, state::AppState},
+};
+
+/// SHA-256 of an export's body, in hex
+pub const CHECKSUM_HEADER: HeaderName = HeaderName::from_static("x-checksum-sha256");
+
+/// How long clients and proxies may reuse an export without asking again
+const EXPORT_MAX_AGE_SECS: u32 = 3600;
+
+/// Every active unit of one level, with the codes and names of its ancestors
+///
+/// The export is written once per change to the data and streamed from disk.
+/// Its SHA-256 is sent as the `ETag` and in `X-Checksum-Sha256`; a request
+/// whose `If-None-Match` names it is answered `304 Not Modified`.
+pub async fn export_dataset_handler(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    ValidQuery(params): ValidQuery<ExportParams>,
+) -> AppResult<Response> {
+    let export = app_state
+        .export_use_cases
+        .export_file(params.format, params.level)
+        .await?;
+
+    let etag = format!("\"{}\"", export.summary.sha256);
+    let validators = [
+        (
+            header::ETAG,
+            HeaderValue::from_str(&etag).expect("etag header is ASCII"),
+        ),
+        (
+            header::CACHE_CONTROL,
+            HeaderValue::from_str(&format!("public, max-age={}", EXPORT_MAX_AGE_SECS))
+                .expect("cache-control header is ASCII"),
+        ),
+        (
+            CHECKSUM_HEADER,
+            HeaderValue::from_str(&export.summary.sha256).expect("checksum header is ASCII"),
+        ),
+    ];
+    if if_none_match(&headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, validators).into_response());
+    }
+
+    // The open file outlives the export being replaced after a data change
+    let file = tokio::fs::File::open(&export.path).await?;
+    let disposition = format!(
+        "attachment; filename=\"nigeria-{}s.{}\"",
+        export.level.as_str(),
+        export.format.extension()
+    );
+
+    Ok((
+        validators,
+        [
+            (
+                header::CONTENT_TYPE,
+                export.format.content_type().to_string(),
+            ),
+            (header::CONTENT_LENGTH, export.summary.bytes.to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        Body::from_stream(ReaderStream::new(file)),
+    )
+        .into_response())
+}
+
+/// Whether `If-None-Match` lists `etag` or `*`
+pub(crate) fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim())
+        .any(|tag| tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == etag)
+}
@@ -99,6 +99,12 @@ pub async fn health_check_handler(
 
 /// Check database connectivity and basic operations
 async fn check_database_health(app_state: &AppState) -> bool {
+    // An embedded dataset stands in for the database and is always loaded
+    if let Some(dataset) = &app_state.embedded_dataset {
+        info!("Serving embedded dataset {}", dataset.version());
+        return true;
+    }
+
     // Try a simple database query to verify connectivity
     match sqlx::query("SELECT 1")
         .execute(app_state.database_pool())
@@ -0,0 +1,39 @@
+use axum::{extract::State, Json};
+use uuid::Uuid;
+
+use crate::{
+    application::dtos::{
+        ChangeDto, DatasetDiffDto, DiffParams, PaginatedResponse, PaginationParams,
+    },
+    domain::entities::change::ChangeEntityType,
+    errors::AppResult,
+    presentation::{
+        extractors::{ValidPath, ValidQuery},
+        state::AppState,
+    },
+};
+
+/// Changes to one state, LGA, ward, postal code or alias, oldest first
+pub async fn get_entity_history_handler(
+    State(app_state): State<AppState>,
+    ValidPath((entity_type, id)): ValidPath<(ChangeEntityType, Uuid)>,
+    ValidQuery(params): ValidQuery<PaginationParams>,
+) -> AppResult<Json<PaginatedResponse<ChangeDto>>> {
+    let result = app_state
+        .history_use_cases
+        .get_entity_history(entity_type, id, params)
+        .await?;
+    Ok(Json(result))
+}
+
+/// Net change to every entity written between two timestamps
+pub async fn diff_dataset_handler(
+    State(app_state): State<AppState>,
+    ValidQuery(params): ValidQuery<DiffParams>,
+) -> AppResult<Json<DatasetDiffDto>> {
+    let result = app_state
+        .history_use_cases
+        .diff(params.from, params.to, params.entity_type)
+        .await?;
+    Ok(Json(result))
+}
@@ -1,3 +1,23 @@
+pub mod admin_entities;
+pub mod aliases;
+pub mod batch_validation;
+pub mod bundles;
+pub mod dataset_versions;
+pub mod export;
 pub mod health;
+pub mod sync;
+pub mod history;
+pub mod tree;
+pub mod verification;
 
+pub use admin_entities::*;
+pub use aliases::*;
+pub use batch_validation::*;
+pub use bundles::*;
+pub use dataset_versions::*;
+pub use export::*;
 pub use health::*;
+pub use sync::*;
+pub use history::*;
+pub use tree::*;
+pub use verification::*;
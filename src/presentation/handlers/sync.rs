@@ -0,0 +1,24 @@
+//! Delta sync for clients holding a local copy
+//!
+//! A client starts from a full sync or a bundle's `sync_token`, then passes
+//! the token each sync returns to the next one.
+
+use axum::{extract::State, Json};
+
+use crate::{
+    application::dtos::{SyncParams, SyncResponseDto},
+    errors::AppResult,
+    presentation::{extractors::ValidQuery, state::AppState},
+};
+
+/// Entities created, updated and deleted since `since`
+pub async fn sync_handler(
+    State(app_state): State<AppState>,
+    ValidQuery(params): ValidQuery<SyncParams>,
+) -> AppResult<Json<SyncResponseDto>> {
+    let result = app_state
+        .sync_use_cases
+        .sync(params.since.as_deref())
+        .await?;
+    Ok(Json(result))
+}
@@ -0,0 +1,59 @@
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, HeaderValue},
+    response::{IntoResponse, Response},
+};
+
+use crate::{errors::AppResult, presentation::state::AppState};
+
+/// How long clients and proxies may reuse the tree without asking again
+const TREE_MAX_AGE_SECS: u32 = 300;
+
+/// Whole country hierarchy: every state with its LGAs, wards and postal codes
+///
+/// The tree is built once and rebuilt only when the data changes. It is sent
+/// gzip-compressed to clients that accept it.
+pub async fn get_tree_handler(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+) -> AppResult<Response> {
+    let tree = app_state.hierarchy_use_cases.tree().await?;
+
+    let cache_control = HeaderValue::from_str(&format!("public, max-age={}", TREE_MAX_AGE_SECS))
+        .expect("cache-control header is ASCII");
+    let common = [
+        (
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        ),
+        (header::CACHE_CONTROL, cache_control),
+        (header::VARY, HeaderValue::from_static("accept-encoding")),
+    ];
+
+    if accepts_gzip(&headers) {
+        let encoding = [(header::CONTENT_ENCODING, HeaderValue::from_static("gzip"))];
+        Ok((common, encoding, tree.gzip.clone()).into_response())
+    } else {
+        Ok((common, tree.json.clone()).into_response())
+    }
+}
+
+/// Whether `Accept-Encoding` lists gzip without refusing it (`q=0`)
+fn accepts_gzip(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT_ENCODING)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|coding| {
+            let mut parts = coding.split(';').map(str::trim);
+            let name = parts.next().unwrap_or_default();
+            let refused = parts.any(|param| {
+                param
+                    .strip_prefix("q=")
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .is_some_and(|q| q == 0.0)
+            });
+            (name.eq_ignore_ascii_case("gzip") || name == "*") && !refused
+        })
+}
@@ -0,0 +1,26 @@
+//! Integrity checks over the whole dataset, for curators
+
+use axum::{extract::State, Json};
+
+use crate::{
+    application::dtos::{VerificationReportDto, VerifyParams},
+    errors::AppResult,
+    presentation::{extractors::ValidQuery, state::AppState},
+};
+
+/// Run the integrity rules and report every violation
+///
+/// The report comes back with 200 whether or not the rules pass; `passed`
+/// tells them apart.
+pub async fn verify_dataset_handler(
+    State(app_state): State<AppState>,
+    ValidQuery(params): ValidQuery<VerifyParams>,
+) -> AppResult<Json<VerificationReportDto>> {
+    let rules = params.rules()?;
+    let limit = params.limit()?;
+    let result = app_state
+        .verification_use_cases
+        .verify(&rules, limit)
+        .await?;
+    Ok(Json(result))
+}
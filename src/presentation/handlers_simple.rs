@@ -1,109 +1,259 @@
-use axum::{
-    extract::{Path, Query, State},
-    Json,
-};
+use axum::{extract::State, Json};
 use uuid::Uuid;
 
 use crate::application::dtos::{
-    address_dto::{AddressDto, AddressValidationRequestDto, AddressValidationResponseDto},
-    LgaDto, PaginatedResponse, PaginationParams, PostalCodeDto, StateDto, WardDto,
+    address_dto::{
+        AddressDto, AddressValidationRequestDto, AddressValidationResponseDto, SimilarAddressDto,
+    },
+    Fields, FieldsParams, HierarchyLevel, Include, LgaDto, LgaNodeDto, PaginatedResponse,
+    PaginationParams, PostalCodeBreakdownDto, PostalCodeDto, PostalCodeNodeDto, Sparse, StateDto,
+    StateNodeDto, WardDto, WardNodeDto,
 };
-use crate::application::use_cases::search_use_cases::SearchResultDto;
+use crate::application::use_cases::address_use_cases::DEFAULT_SIMILAR_ADDRESS_LIMIT;
+use crate::application::use_cases::autocomplete_use_cases::AutocompleteSuggestionDto;
+use crate::application::use_cases::search_use_cases::{CodeLookupDto, SearchResultDto};
 use crate::domain::entities::address::AddressValidationRequest;
-use crate::errors::AppResult;
+use crate::domain::entities::search::MatchMode;
+use crate::errors::{AppError, AppResult};
+use crate::presentation::extractors::{ValidJson, ValidPath, ValidQuery};
 use crate::presentation::state::AppState;
 
+#[derive(Debug, serde::Deserialize)]
+pub struct IncludeParams {
+    /// Related entities to nest, e.g. `lgas.wards` or `ancestors`; see [`Include`]
+    pub include: Option<String>,
+}
+
 // State handlers
 pub async fn get_states_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<PaginationParams>,
-) -> AppResult<Json<PaginatedResponse<StateDto>>> {
+    ValidQuery(params): ValidQuery<PaginationParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<PaginatedResponse<Sparse<StateDto>>>> {
+    let fields = Fields::parse::<StateDto>(fields_params.fields.as_deref())?;
     let result = app_state.state_use_cases.get_states(params).await?;
-    Ok(Json(result))
+    Ok(Json(result.map(|dto| fields.apply(dto))))
 }
 
 pub async fn get_state_by_id_handler(
     State(app_state): State<AppState>,
-    Path(id): Path<Uuid>,
-) -> AppResult<Json<Option<StateDto>>> {
-    let result = app_state.state_use_cases.get_state_by_id(id).await?;
-    Ok(Json(result))
+    ValidPath(id): ValidPath<Uuid>,
+    ValidQuery(params): ValidQuery<IncludeParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<Sparse<StateNodeDto>>> {
+    let fields = Fields::parse::<StateDto>(fields_params.fields.as_deref())?;
+    let include = Include::parse(params.include.as_deref(), HierarchyLevel::State)?;
+    let state = app_state
+        .state_use_cases
+        .get_state_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("State not found".to_string()))?;
+    let node = app_state
+        .hierarchy_use_cases
+        .expand_state(state, &include)
+        .await?;
+    Ok(Json(fields.apply(node)))
+}
+
+pub async fn get_state_by_code_handler(
+    State(app_state): State<AppState>,
+    ValidPath(code): ValidPath<String>,
+    ValidQuery(params): ValidQuery<IncludeParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<Sparse<StateNodeDto>>> {
+    let fields = Fields::parse::<StateDto>(fields_params.fields.as_deref())?;
+    let include = Include::parse(params.include.as_deref(), HierarchyLevel::State)?;
+    let state = app_state
+        .state_use_cases
+        .get_state_by_code(&code)
+        .await?
+        .ok_or_else(|| AppError::NotFound("State not found".to_string()))?;
+    let node = app_state
+        .hierarchy_use_cases
+        .expand_state(state, &include)
+        .await?;
+    Ok(Json(fields.apply(node)))
 }
 
 // LGA handlers
 pub async fn get_lgas_by_state_handler(
     State(app_state): State<AppState>,
-    Path(state_id): Path<Uuid>,
-    Query(params): Query<PaginationParams>,
-) -> AppResult<Json<PaginatedResponse<LgaDto>>> {
+    ValidPath(state_id): ValidPath<Uuid>,
+    ValidQuery(params): ValidQuery<PaginationParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<PaginatedResponse<Sparse<LgaDto>>>> {
+    let fields = Fields::parse::<LgaDto>(fields_params.fields.as_deref())?;
     let result = app_state
         .lga_use_cases
         .get_lgas_by_state(state_id, params)
         .await?;
-    Ok(Json(result))
+    Ok(Json(result.map(|dto| fields.apply(dto))))
 }
 
 pub async fn get_lga_by_id_handler(
     State(app_state): State<AppState>,
-    Path(id): Path<Uuid>,
-) -> AppResult<Json<Option<LgaDto>>> {
-    let result = app_state.lga_use_cases.get_lga_by_id(id).await?;
-    Ok(Json(result))
+    ValidPath(id): ValidPath<Uuid>,
+    ValidQuery(params): ValidQuery<IncludeParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<Sparse<LgaNodeDto>>> {
+    let fields = Fields::parse::<LgaDto>(fields_params.fields.as_deref())?;
+    let include = Include::parse(params.include.as_deref(), HierarchyLevel::Lga)?;
+    let lga = app_state
+        .lga_use_cases
+        .get_lga_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("LGA not found".to_string()))?;
+    let node = app_state
+        .hierarchy_use_cases
+        .expand_lga(lga, &include)
+        .await?;
+    Ok(Json(fields.apply(node)))
+}
+
+pub async fn get_lga_by_code_handler(
+    State(app_state): State<AppState>,
+    ValidPath(code): ValidPath<String>,
+    ValidQuery(params): ValidQuery<IncludeParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<Sparse<LgaNodeDto>>> {
+    let fields = Fields::parse::<LgaDto>(fields_params.fields.as_deref())?;
+    let include = Include::parse(params.include.as_deref(), HierarchyLevel::Lga)?;
+    let lga = app_state
+        .lga_use_cases
+        .get_lga_by_code(&code)
+        .await?
+        .ok_or_else(|| AppError::NotFound("LGA not found".to_string()))?;
+    let node = app_state
+        .hierarchy_use_cases
+        .expand_lga(lga, &include)
+        .await?;
+    Ok(Json(fields.apply(node)))
 }
 
 // Ward handlers
 pub async fn get_wards_by_lga_handler(
     State(app_state): State<AppState>,
-    Path(lga_id): Path<Uuid>,
-    Query(params): Query<PaginationParams>,
-) -> AppResult<Json<PaginatedResponse<WardDto>>> {
+    ValidPath(lga_id): ValidPath<Uuid>,
+    ValidQuery(params): ValidQuery<PaginationParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<PaginatedResponse<Sparse<WardDto>>>> {
+    let fields = Fields::parse::<WardDto>(fields_params.fields.as_deref())?;
     let result = app_state
         .ward_use_cases
         .get_wards_by_lga(lga_id, params)
         .await?;
-    Ok(Json(result))
+    Ok(Json(result.map(|dto| fields.apply(dto))))
 }
 
 pub async fn get_ward_by_id_handler(
     State(app_state): State<AppState>,
-    Path(id): Path<Uuid>,
-) -> AppResult<Json<Option<WardDto>>> {
-    let result = app_state.ward_use_cases.get_ward_by_id(id).await?;
-    Ok(Json(result))
+    ValidPath(id): ValidPath<Uuid>,
+    ValidQuery(params): ValidQuery<IncludeParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<Sparse<WardNodeDto>>> {
+    let fields = Fields::parse::<WardDto>(fields_params.fields.as_deref())?;
+    let include = Include::parse(params.include.as_deref(), HierarchyLevel::Ward)?;
+    let ward = app_state
+        .ward_use_cases
+        .get_ward_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Ward not found".to_string()))?;
+    let node = app_state
+        .hierarchy_use_cases
+        .expand_ward(ward, &include)
+        .await?;
+    Ok(Json(fields.apply(node)))
+}
+
+pub async fn get_ward_by_code_handler(
+    State(app_state): State<AppState>,
+    ValidPath(code): ValidPath<String>,
+    ValidQuery(params): ValidQuery<IncludeParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<Sparse<WardNodeDto>>> {
+    let fields = Fields::parse::<WardDto>(fields_params.fields.as_deref())?;
+    let include = Include::parse(params.include.as_deref(), HierarchyLevel::Ward)?;
+    let ward = app_state
+        .ward_use_cases
+        .get_ward_by_code(&code)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Ward not found".to_string()))?;
+    let node = app_state
+        .hierarchy_use_cases
+        .expand_ward(ward, &include)
+        .await?;
+    Ok(Json(fields.apply(node)))
 }
 
 // Postal code handlers
 pub async fn get_postal_codes_by_ward_handler(
     State(app_state): State<AppState>,
-    Path(ward_id): Path<Uuid>,
-    Query(params): Query<PaginationParams>,
-) -> AppResult<Json<PaginatedResponse<PostalCodeDto>>> {
+    ValidPath(ward_id): ValidPath<Uuid>,
+    ValidQuery(params): ValidQuery<PaginationParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<PaginatedResponse<Sparse<PostalCodeDto>>>> {
+    let fields = Fields::parse::<PostalCodeDto>(fields_params.fields.as_deref())?;
     let result = app_state
         .postal_code_use_cases
         .get_postal_codes_by_ward(ward_id, params)
         .await?;
-    Ok(Json(result))
+    Ok(Json(result.map(|dto| fields.apply(dto))))
 }
 
 pub async fn get_postal_code_by_id_handler(
     State(app_state): State<AppState>,
-    Path(id): Path<Uuid>,
-) -> AppResult<Json<Option<PostalCodeDto>>> {
-    let result = app_state
+    ValidPath(id): ValidPath<Uuid>,
+    ValidQuery(params): ValidQuery<IncludeParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<Sparse<PostalCodeNodeDto>>> {
+    let fields = Fields::parse::<PostalCodeDto>(fields_params.fields.as_deref())?;
+    let include = Include::parse(params.include.as_deref(), HierarchyLevel::PostalCode)?;
+    let postal_code = app_state
         .postal_code_use_cases
         .get_postal_code_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Postal code not found".to_string()))?;
+    let node = app_state
+        .hierarchy_use_cases
+        .expand_postal_code(postal_code, &include)
         .await?;
-    Ok(Json(result))
+    Ok(Json(fields.apply(node)))
 }
 
 pub async fn get_postal_code_by_code_handler(
     State(app_state): State<AppState>,
-    Path(code): Path<String>,
-) -> AppResult<Json<Option<PostalCodeDto>>> {
-    let result = app_state
+    ValidPath(code): ValidPath<String>,
+    ValidQuery(params): ValidQuery<IncludeParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<Sparse<PostalCodeNodeDto>>> {
+    let fields = Fields::parse::<PostalCodeDto>(fields_params.fields.as_deref())?;
+    let include = Include::parse(params.include.as_deref(), HierarchyLevel::PostalCode)?;
+    let postal_code = app_state
         .postal_code_use_cases
         .get_postal_code_by_code(&code)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Postal code not found".to_string()))?;
+    let node = app_state
+        .hierarchy_use_cases
+        .expand_postal_code(postal_code, &include)
         .await?;
+    Ok(Json(fields.apply(node)))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct PostalCodeBreakdownParams {
+    /// State code the postal code is claimed for, e.g. `NG-LA`
+    pub state: Option<String>,
+}
+
+pub async fn get_postal_code_breakdown_handler(
+    State(app_state): State<AppState>,
+    ValidPath(code): ValidPath<String>,
+    ValidQuery(params): ValidQuery<PostalCodeBreakdownParams>,
+) -> AppResult<Json<PostalCodeBreakdownDto>> {
+    let result = app_state
+        .postal_code_use_cases
+        .get_postal_code_breakdown(&code, params.state.as_deref())?;
     Ok(Json(result))
 }
 
@@ -116,27 +266,40 @@ pub struct NearbyParams {
 
 pub async fn find_nearby_postal_codes_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<NearbyParams>,
-) -> AppResult<Json<Vec<PostalCodeDto>>> {
+    ValidQuery(params): ValidQuery<NearbyParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<Vec<Sparse<PostalCodeDto>>>> {
+    let fields = Fields::parse::<PostalCodeDto>(fields_params.fields.as_deref())?;
     let coordinates = crate::domain::value_objects::Coordinates::new(params.lat, params.lng)?;
     let radius = params.radius_km.unwrap_or(10.0);
     let result = app_state
         .postal_code_use_cases
         .find_near_coordinates(coordinates, radius)
         .await?;
-    Ok(Json(result))
+    Ok(Json(
+        result.into_iter().map(|dto| fields.apply(dto)).collect(),
+    ))
 }
 
 // Address handlers
+#[derive(Debug, serde::Deserialize)]
+pub struct MatchParams {
+    /// How suggestions are found: `standard` (the default) or `phonetic`
+    #[serde(rename = "match", default)]
+    pub match_mode: MatchMode,
+}
+
 pub async fn validate_address_handler(
     State(app_state): State<AppState>,
-    Json(request): Json<AddressValidationRequestDto>,
+    ValidQuery(match_params): ValidQuery<MatchParams>,
+    ValidJson(request): ValidJson<AddressValidationRequestDto>,
 ) -> AppResult<Json<AddressValidationResponseDto>> {
     let validation_request = AddressValidationRequest {
         state: request.state,
         lga: request.lga,
         ward: request.ward,
         postal_code: request.postal_code,
+        match_mode: match_params.match_mode,
     };
 
     let result = app_state
@@ -156,8 +319,8 @@ pub struct AddressComponentsParams {
 
 pub async fn find_address_by_components_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<AddressComponentsParams>,
-) -> AppResult<Json<Option<AddressDto>>> {
+    ValidQuery(params): ValidQuery<AddressComponentsParams>,
+) -> AppResult<Json<AddressDto>> {
     let result = app_state
         .address_use_cases
         .find_address_by_components(
@@ -166,24 +329,35 @@ pub async fn find_address_by_components_handler(
             &params.ward,
             &params.postal_code,
         )
-        .await?;
+        .await?
+        .ok_or_else(|| AppError::NotFound("No address matches these components".to_string()))?;
     Ok(Json(result))
 }
 
+#[derive(Debug, serde::Deserialize)]
+pub struct SimilarAddressParams {
+    pub limit: Option<u32>,
+}
+
 pub async fn find_similar_addresses_handler(
     State(app_state): State<AppState>,
-    Json(request): Json<AddressValidationRequestDto>,
-) -> AppResult<Json<Vec<AddressDto>>> {
+    ValidQuery(params): ValidQuery<SimilarAddressParams>,
+    ValidJson(request): ValidJson<AddressValidationRequestDto>,
+) -> AppResult<Json<Vec<SimilarAddressDto>>> {
     let validation_request = AddressValidationRequest {
         state: request.state,
         lga: request.lga,
         ward: request.ward,
         postal_code: request.postal_code,
+        match_mode: MatchMode::default(),
     };
 
     let result = app_state
         .address_use_cases
-        .find_similar_addresses(validation_request)
+        .find_similar_addresses(
+            validation_request,
+            params.limit.unwrap_or(DEFAULT_SIMILAR_ADDRESS_LIMIT),
+        )
         .await?;
     Ok(Json(result))
 }
@@ -192,65 +366,135 @@ pub async fn find_similar_addresses_handler(
 #[derive(Debug, serde::Deserialize)]
 pub struct SearchParams {
     pub query: String,
+    /// `standard` (the default) or `phonetic`
+    #[serde(rename = "match", default)]
+    pub match_mode: MatchMode,
 }
 
 // Search handlers
 pub async fn search_all_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<PaginationParams>,
-    axum::extract::Query(search_params): axum::extract::Query<SearchParams>,
+    ValidQuery(params): ValidQuery<PaginationParams>,
+    ValidQuery(search_params): ValidQuery<SearchParams>,
 ) -> AppResult<Json<SearchResultDto>> {
     let result = app_state
         .search_use_cases
-        .search_all(&search_params.query, params)
+        .search_all(&search_params.query, search_params.match_mode, params)
         .await?;
     Ok(Json(result))
 }
 
+pub async fn lookup_code_handler(
+    State(app_state): State<AppState>,
+    ValidPath(code): ValidPath<String>,
+) -> AppResult<Json<CodeLookupDto>> {
+    let result = app_state
+        .search_use_cases
+        .lookup_code(&code)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("No state, LGA or ward has code '{}'", code)))?;
+    Ok(Json(result))
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct AutocompleteParams {
+    /// Partial name or code typed so far
+    pub q: String,
+    /// Comma-separated entity types, e.g. `lga,ward`
+    #[serde(rename = "type")]
+    pub entity_types: Option<String>,
+    /// Only suggest entities within this state, e.g. `LA` or `NG-LA`
+    pub state: Option<String>,
+    /// Only suggest entities within this LGA, e.g. `NG-LA-IKJ`
+    pub lga: Option<String>,
+    pub limit: Option<usize>,
+}
+
+pub async fn autocomplete_handler(
+    State(app_state): State<AppState>,
+    ValidQuery(params): ValidQuery<AutocompleteParams>,
+) -> AppResult<Json<Vec<AutocompleteSuggestionDto>>> {
+    let result = app_state.autocomplete_use_cases.suggest(
+        &params.q,
+        params.entity_types.as_deref(),
+        params.state.as_deref(),
+        params.lga.as_deref(),
+        params.limit,
+    )?;
+    Ok(Json(result))
+}
+
 pub async fn search_states_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<PaginationParams>,
-    axum::extract::Query(search_params): axum::extract::Query<SearchParams>,
-) -> AppResult<Json<Vec<StateDto>>> {
+    ValidQuery(params): ValidQuery<PaginationParams>,
+    ValidQuery(search_params): ValidQuery<SearchParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<Vec<Sparse<StateDto>>>> {
+    let fields = Fields::parse::<StateDto>(fields_params.fields.as_deref())?;
     let result = app_state
         .search_use_cases
-        .search_states(&search_params.query, params)
+        .search_states(&search_params.query, search_params.match_mode, params)
         .await?;
-    Ok(Json(result))
+    Ok(Json(
+        result.into_iter().map(|dto| fields.apply(dto)).collect(),
+    ))
 }
 
 pub async fn search_lgas_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<PaginationParams>,
-    axum::extract::Query(search_params): axum::extract::Query<SearchParams>,
-) -> AppResult<Json<Vec<LgaDto>>> {
+    ValidQuery(params): ValidQuery<PaginationParams>,
+    ValidQuery(search_params): ValidQuery<SearchParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<Vec<Sparse<LgaDto>>>> {
+    let fields = Fields::parse::<LgaDto>(fields_params.fields.as_deref())?;
     let result = app_state
         .search_use_cases
-        .search_lgas(&search_params.query, params)
+        .search_lgas(&search_params.query, search_params.match_mode, params)
         .await?;
-    Ok(Json(result))
+    Ok(Json(
+        result.into_iter().map(|dto| fields.apply(dto)).collect(),
+    ))
 }
 
 pub async fn search_wards_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<PaginationParams>,
-    axum::extract::Query(search_params): axum::extract::Query<SearchParams>,
-) -> AppResult<Json<Vec<WardDto>>> {
+    ValidQuery(params): ValidQuery<PaginationParams>,
+    ValidQuery(search_params): ValidQuery<SearchParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<Vec<Sparse<WardDto>>>> {
+    let fields = Fields::parse::<WardDto>(fields_params.fields.as_deref())?;
     let result = app_state
         .search_use_cases
-        .search_wards(&search_params.query, params)
+        .search_wards(&search_params.query, search_params.match_mode, params)
         .await?;
-    Ok(Json(result))
+    Ok(Json(
+        result.into_iter().map(|dto| fields.apply(dto)).collect(),
+    ))
 }
 
 pub async fn search_postal_codes_handler(
     State(app_state): State<AppState>,
-    Query(params): Query<PaginationParams>,
-    axum::extract::Query(search_params): axum::extract::Query<SearchParams>,
-) -> AppResult<Json<Vec<PostalCodeDto>>> {
+    ValidQuery(params): ValidQuery<PaginationParams>,
+    ValidQuery(search_params): ValidQuery<SearchParams>,
+    ValidQuery(fields_params): ValidQuery<FieldsParams>,
+) -> AppResult<Json<Vec<Sparse<PostalCodeDto>>>> {
+    let fields = Fields::parse::<PostalCodeDto>(fields_params.fields.as_deref())?;
+    if search_params.match_mode == MatchMode::Phonetic {
+        return Err(AppError::BadRequest(
+            "match=phonetic applies to names, not postal codes".to_string(),
+        ));
+    }
+
     let result = app_state
         .search_use_cases
         .search_postal_codes(&search_params.query, params)
         .await?;
-    Ok(Json(result))
+    Ok(Json(
+        result.into_iter().map(|dto| fields.apply(dto)).collect(),
+    ))
+}
+
+/// Response for paths no route matches
+pub async fn route_not_found_handler(uri: axum::http::Uri) -> AppError {
+    AppError::NotFound(format!("No endpoint at {}", uri.path()))
 }
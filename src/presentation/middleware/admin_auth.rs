@@ -0,0 +1,83 @@
+use axum::{
+    extract::{Request, State},
+    http::{header::AUTHORIZATION, HeaderName},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+
+use crate::domain::audit::as_actor;
+use crate::errors::{AppError, InvalidParam};
+
+/// Header naming the person or tool behind an admin request, for the change history
+pub const ACTOR_HEADER: HeaderName = HeaderName::from_static("x-actor");
+
+/// Actor recorded for admin requests without an `X-Actor` header
+const DEFAULT_ACTOR: &str = "admin";
+
+/// Longest accepted `X-Actor`
+const MAX_ACTOR_LEN: usize = 100;
+
+/// Token admin requests must present as `Authorization: Bearer <token>`
+///
+/// `None` when `ADMIN_API_TOKEN` is unset, which disables the admin API.
+pub type AdminToken = Option<Arc<str>>;
+
+/// Reject admin requests without the configured bearer token
+///
+/// Writes made by an accepted request are attributed to its `X-Actor`.
+pub async fn require_admin_token(
+    State(admin_token): State<AdminToken>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(expected) = admin_token else {
+        return AppError::Unauthorized("Admin API is disabled; set ADMIN_API_TOKEN".to_string())
+            .into_response();
+    };
+
+    let presented = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match presented {
+        Some(token) if constant_time_eq(token.as_bytes(), expected.as_bytes()) => {}
+        _ => {
+            return AppError::Unauthorized("Missing or invalid admin token".to_string())
+                .into_response()
+        }
+    }
+
+    match actor(&request) {
+        Ok(actor) => as_actor(actor, next.run(request)).await,
+        Err(e) => e.into_response(),
+    }
+}
+
+/// Who the request says is making it
+fn actor(request: &Request) -> Result<String, AppError> {
+    let Some(value) = request.headers().get(&ACTOR_HEADER) else {
+        return Ok(DEFAULT_ACTOR.to_string());
+    };
+
+    value
+        .to_str()
+        .ok()
+        .map(str::trim)
+        .filter(|actor| !actor.is_empty() && actor.len() <= MAX_ACTOR_LEN)
+        .map(str::to_string)
+        .ok_or_else(|| {
+            AppError::InvalidParam(InvalidParam::new(
+                "X-Actor",
+                "format",
+                format!("must be 1 to {} printable characters", MAX_ACTOR_LEN),
+            ))
+        })
+}
+
+/// Compare without short-circuiting, so timing does not reveal the matching prefix
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
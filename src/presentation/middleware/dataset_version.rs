@@ -0,0 +1,193 @@
+use axum::{
+    extract::{Request, State},
+    http::{header::VARY, Extensions, HeaderName, HeaderValue},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Router,
+};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tower::ServiceExt;
+use tracing::{info, warn};
+
+use crate::domain::entities::dataset_version::DatasetVersion;
+use crate::errors::{AppError, InvalidParam};
+use crate::infrastructure::database::release_pool;
+use crate::presentation::state::AppState;
+
+/// Header pinning a request to a named release, echoed on pinned responses
+pub const DATASET_VERSION_HEADER: HeaderName = HeaderName::from_static("x-dataset-version");
+
+/// Query parameter pinning a request to the release current at a time
+const AS_OF_PARAM: &str = "as_of";
+
+/// Releases whose routers are kept ready; each holds a small connection pool
+/// and its own autocomplete index
+const MAX_PINNED_VERSIONS: usize = 4;
+
+/// Serves requests pinned to a release from that release's copy of the data
+#[derive(Clone)]
+pub struct PinnedDatasets {
+    live: AppState,
+    routes: Router<AppState>,
+    /// Routers over recently pinned releases, least recently used first
+    routers: Arc<Mutex<Vec<(i32, Router)>>>,
+}
+
+impl PinnedDatasets {
+    /// `routes` are served for pinned requests, with state reading the
+    /// release in place of `live`'s tables
+    pub fn new(live: AppState, routes: Router<AppState>) -> Self {
+        Self {
+            live,
+            routes,
+            routers: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    async fn resolve(&self, pin: Pin) -> Result<DatasetVersion, AppError> {
+        let versions = &self.live.dataset_version_use_cases;
+        match pin {
+            Pin::Named(name) => versions.find_by_name(&name).await,
+            Pin::AsOf(at) => versions.find_as_of(at).await,
+        }
+    }
+
+    /// Router serving `version`, built on first use
+    async fn router(&self, version: &DatasetVersion) -> Router {
+        let mut routers = self.routers.lock().await;
+        if let Some(index) = routers.iter().position(|(id, _)| *id == version.id) {
+            let entry = routers.remove(index);
+            let router = entry.1.clone();
+            routers.push(entry);
+            return router;
+        }
+
+        info!("Opening dataset version {}", version.name);
+        let state = AppState::from_pool(release_pool(&self.live.pool, version));
+        if let Err(e) = state.autocomplete_use_cases.refresh().await {
+            warn!(
+                "Failed to build autocomplete index for dataset version {}: {}",
+                version.name, e
+            );
+        }
+
+        let router = self.routes.clone().with_state(state);
+        if routers.len() == MAX_PINNED_VERSIONS {
+            drop(routers.remove(0));
+        }
+        routers.push((version.id, router.clone()));
+        router
+    }
+}
+
+/// Serve requests carrying `X-Dataset-Version` or `?as_of=` from the release
+/// they name, and everything else from the live data
+///
+/// `as_of` takes an RFC 3339 timestamp or a date, meaning the end of that day
+/// in UTC, and selects the latest release made by then. Pinned responses echo
+/// the release's name in `X-Dataset-Version`.
+pub async fn pin_dataset_version(
+    State(pinned): State<PinnedDatasets>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let response = match requested_pin(&request) {
+        Ok(None) => next.run(request).await,
+        Ok(Some(pin)) => match pinned.resolve(pin).await {
+            Ok(version) => {
+                // The pinned router routes the request afresh; path parameters
+                // left by this one would be taken as extra segments
+                let mut request = request;
+                *request.extensions_mut() = Extensions::new();
+
+                let router = pinned.router(&version).await;
+                let mut response = router.oneshot(request).await.into_response();
+                if let Ok(value) = HeaderValue::from_str(&version.name) {
+                    response.headers_mut().insert(DATASET_VERSION_HEADER, value);
+                }
+                response
+            }
+            Err(e) => e.into_response(),
+        },
+        Err(e) => e.into_response(),
+    };
+
+    vary_by_version(response)
+}
+
+/// How a request names the release it wants
+enum Pin {
+    Named(String),
+    AsOf(DateTime<Utc>),
+}
+
+/// The release the request is pinned to, if any
+fn requested_pin(request: &Request) -> Result<Option<Pin>, AppError> {
+    match (version_header(request)?, as_of_param(request)?) {
+        (None, None) => Ok(None),
+        (Some(name), None) => Ok(Some(Pin::Named(name))),
+        (None, Some(at)) => Ok(Some(Pin::AsOf(at))),
+        (Some(_), Some(_)) => Err(AppError::BadRequest(
+            "Pin a dataset version with X-Dataset-Version or as_of, not both".to_string(),
+        )),
+    }
+}
+
+fn version_header(request: &Request) -> Result<Option<String>, AppError> {
+    let Some(value) = request.headers().get(&DATASET_VERSION_HEADER) else {
+        return Ok(None);
+    };
+
+    value
+        .to_str()
+        .ok()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| Some(name.to_string()))
+        .ok_or_else(|| {
+            AppError::InvalidParam(InvalidParam::new(
+                "X-Dataset-Version",
+                "format",
+                "must name a dataset version",
+            ))
+        })
+}
+
+fn as_of_param(request: &Request) -> Result<Option<DateTime<Utc>>, AppError> {
+    let Some(query) = request.uri().query() else {
+        return Ok(None);
+    };
+    let Some((_, value)) = form_urlencoded::parse(query.as_bytes()).find(|(k, _)| k == AS_OF_PARAM)
+    else {
+        return Ok(None);
+    };
+
+    parse_as_of(&value).map(Some).ok_or_else(|| {
+        AppError::InvalidParam(InvalidParam::new(
+            AS_OF_PARAM,
+            "format",
+            "must be an RFC 3339 timestamp or a YYYY-MM-DD date",
+        ))
+    })
+}
+
+fn parse_as_of(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return Some(at.with_timezone(&Utc));
+    }
+
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+    let end_of_day = NaiveTime::from_hms_micro_opt(23, 59, 59, 999_999)?;
+    Some(date.and_time(end_of_day).and_utc())
+}
+
+/// The same URL answers differently depending on the pinned release, so
+/// caches must key on the header too
+fn vary_by_version(mut response: Response) -> Response {
+    response
+        .headers_mut()
+        .append(VARY, HeaderValue::from_static("x-dataset-version"));
+    response
+}
@@ -1 +1,4 @@
-pub mod usage_tracking;
\ No newline at end of file
+pub mod admin_auth;
+pub mod dataset_version;
+pub mod request_id;
+pub mod usage_tracking;
@@ -0,0 +1,67 @@
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use tracing::Instrument;
+use uuid::Uuid;
+
+/// Header carrying the correlation ID, both ways
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest client-supplied correlation ID that is kept rather than replaced
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// What error responses need to know about the request being served
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+    pub correlation_id: String,
+    pub path: String,
+}
+
+tokio::task_local! {
+    static REQUEST_CONTEXT: RequestContext;
+}
+
+/// Give every request a correlation ID
+///
+/// The client's `X-Request-Id` is kept when it is short and printable,
+/// otherwise a new UUID is generated. The ID is echoed in the response header,
+/// included in error bodies and recorded on the request's tracing span.
+pub async fn correlate_request(request: Request, next: Next) -> Response {
+    let correlation_id = request
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| is_acceptable_id(id))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let context = RequestContext {
+        correlation_id: correlation_id.clone(),
+        path: request.uri().path().to_string(),
+    };
+    let span = tracing::info_span!("request", correlation_id = %correlation_id);
+    let mut response = REQUEST_CONTEXT
+        .scope(context, next.run(request).instrument(span))
+        .await;
+
+    if let Ok(value) = HeaderValue::from_str(&correlation_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+/// Context of the request the current task is serving, if any
+pub fn current_request() -> Option<RequestContext> {
+    REQUEST_CONTEXT.try_with(Clone::clone).ok()
+}
+
+fn is_acceptable_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LEN
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+}
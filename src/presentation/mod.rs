@@ -1,4 +1,6 @@
+pub mod cli;
 pub mod controllers;
+pub mod extractors;
 pub mod handlers;
 pub mod handlers_simple;
 pub mod middleware;
@@ -1,11 +1,12 @@
 use utoipa::OpenApi;
 
 use crate::application::dtos::{
-    StateDto, LgaDto, WardDto, PostalCodeDto, PaginatedResponse, PaginationParams,
-    address_dto::{AddressValidationRequestDto, AddressValidationResponseDto, AddressDto, AddressSuggestionDto},
+    StateDto, LgaDto, WardDto, PostalCodeDto, PaginatedResponse, PaginationParams, FieldsParams,
+    StateNodeDto, LgaNodeDto, WardNodeDto, PostalCodeNodeDto,
+    address_dto::{AddressValidationRequestDto, AddressValidationResponseDto, AddressDto, AddressSuggestionDto, ComponentMatchDto},
 };
 use crate::application::use_cases::search_use_cases::SearchResultDto;
-use crate::errors::AppError;
+use crate::errors::{AppError, InvalidParam, ProblemDetails};
 use crate::presentation::handlers::{
     get_states_handler, get_state_by_id_handler, validate_address_handler,
 };
@@ -30,6 +31,12 @@ use crate::presentation::handlers::{
             PostalCodeDto,
             AddressDto,
             
+            // Hierarchy
+            StateNodeDto,
+            LgaNodeDto,
+            WardNodeDto,
+            PostalCodeNodeDto,
+            
             // Pagination
             PaginatedResponse<StateDto>,
             PaginatedResponse<LgaDto>,
@@ -38,16 +45,22 @@ use crate::presentation::handlers::{
             PaginationParams,
             crate::application::dtos::pagination_dto::PaginationMeta,
             
+            // Response shaping
+            FieldsParams,
+            
             // Address Validation
             AddressValidationRequestDto,
             AddressValidationResponseDto,
             AddressSuggestionDto,
+            ComponentMatchDto,
             
             // Search
             SearchResultDto,
             
             // Errors
             AppError,
+            ProblemDetails,
+            InvalidParam,
         )
     ),
     tags(
@@ -1,50 +1,98 @@
+use crate::domain::repositories::{
+    address_repository::AddressRepository, lga_repository::LgaRepository,
+    postal_code_repository::PostalCodeRepository, search_repository::SearchRepository,
+    state_repository::StateRepository, ward_repository::WardRepository,
+};
+use crate::infrastructure::embedded::{
+    EmbeddedAddressRepository, EmbeddedDataset, EmbeddedLgaRepository,
+    EmbeddedPostalCodeRepository, EmbeddedSearchRepository, EmbeddedStateRepository,
+    EmbeddedWardRepository,
+};
 use crate::infrastructure::repositories::{
     address_repository_impl::PostgresAddressRepository,
+    alias_repository_impl::PostgresAliasRepository,
     api_usage_repository_impl::PostgresApiUsageRepository,
-    lga_repository_impl::PostgresLgaRepository,
+    bundle_repository_impl::PostgresBundleRepository,
+    dataset_version_repository_impl::PostgresDatasetVersionRepository,
+    export_repository_impl::PostgresExportRepository,
+    history_repository_impl::PostgresHistoryRepository, lga_repository_impl::PostgresLgaRepository,
     postal_code_repository_impl::PostgresPostalCodeRepository,
-    state_repository_impl::PostgresStateRepository, ward_repository_impl::PostgresWardRepository,
+    search_repository_impl::PostgresSearchRepository,
+    state_repository_impl::PostgresStateRepository, sync_repository_impl::PostgresSyncRepository,
+    verification_repository_impl::PostgresVerificationRepository,
+    ward_repository_impl::PostgresWardRepository,
 };
 
 use crate::application::use_cases::{
-    address_use_cases::AddressUseCases, lga_use_cases::LgaUseCases,
-    postal_code_use_cases::PostalCodeUseCases, search_use_cases::SearchUseCases,
-    state_use_cases::StateUseCases, ward_use_cases::WardUseCases,
+    address_use_cases::AddressUseCases, alias_use_cases::AliasUseCases,
+    autocomplete_use_cases::AutocompleteUseCases,
+    batch_validation_use_cases::BatchValidationUseCases, bundle_use_cases::BundleUseCases,
+    dataset_version_use_cases::DatasetVersionUseCases, export_use_cases::ExportUseCases,
+    hierarchy_use_cases::HierarchyUseCases, history_use_cases::HistoryUseCases,
+    lga_use_cases::LgaUseCases, postal_code_use_cases::PostalCodeUseCases,
+    search_use_cases::SearchUseCases, state_use_cases::StateUseCases, sync_use_cases::SyncUseCases,
+    verification_use_cases::VerificationUseCases, ward_use_cases::WardUseCases,
 };
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 use sqlx::PgPool;
 use std::sync::Arc;
+use tracing::warn;
+
+/// Search over the unit repositories, whichever store backs them
+pub type UnitSearchUseCases = SearchUseCases<
+    Arc<dyn StateRepository>,
+    Arc<dyn LgaRepository>,
+    Arc<dyn WardRepository>,
+    Arc<dyn PostalCodeRepository>,
+    Arc<dyn SearchRepository>,
+>;
+
+/// The country tree over the unit repositories, whichever store backs them
+pub type UnitHierarchyUseCases = HierarchyUseCases<
+    Arc<dyn StateRepository>,
+    Arc<dyn LgaRepository>,
+    Arc<dyn WardRepository>,
+    Arc<dyn PostalCodeRepository>,
+    Arc<dyn SearchRepository>,
+>;
 
 /// Simplified application state without caching
 #[derive(Clone)]
 pub struct AppState {
     /// Direct use cases without caching
-    pub state_use_cases: Arc<StateUseCases<PostgresStateRepository>>,
-    pub lga_use_cases: Arc<LgaUseCases<PostgresLgaRepository>>,
-    pub ward_use_cases: Arc<WardUseCases<PostgresWardRepository>>,
-    pub postal_code_use_cases: Arc<PostalCodeUseCases<PostgresPostalCodeRepository>>,
-    pub search_use_cases: Arc<
-        SearchUseCases<
-            PostgresStateRepository,
-            PostgresLgaRepository,
-            PostgresWardRepository,
-            PostgresPostalCodeRepository,
-        >,
-    >,
-    pub address_use_cases: Arc<AddressUseCases<PostgresAddressRepository>>,
+    pub state_use_cases: Arc<StateUseCases<Arc<dyn StateRepository>>>,
+    pub lga_use_cases: Arc<LgaUseCases<Arc<dyn LgaRepository>>>,
+    pub ward_use_cases: Arc<WardUseCases<Arc<dyn WardRepository>>>,
+    pub postal_code_use_cases: Arc<PostalCodeUseCases<Arc<dyn PostalCodeRepository>>>,
+    pub search_use_cases: Arc<UnitSearchUseCases>,
+    pub autocomplete_use_cases: Arc<AutocompleteUseCases<Arc<dyn SearchRepository>>>,
+    pub hierarchy_use_cases: Arc<UnitHierarchyUseCases>,
+    pub address_use_cases: Arc<AddressUseCases<Arc<dyn AddressRepository>>>,
+    pub alias_use_cases: Arc<AliasUseCases<PostgresAliasRepository>>,
+    pub history_use_cases: Arc<HistoryUseCases<PostgresHistoryRepository>>,
+    pub dataset_version_use_cases:
+        Arc<DatasetVersionUseCases<PostgresDatasetVersionRepository, PostgresHistoryRepository>>,
+    pub batch_validation_use_cases: Arc<BatchValidationUseCases<Arc<dyn AddressRepository>>>,
+    pub export_use_cases: Arc<ExportUseCases<PostgresExportRepository>>,
+    pub bundle_use_cases: Arc<BundleUseCases<PostgresBundleRepository>>,
+    pub sync_use_cases: Arc<SyncUseCases<PostgresSyncRepository>>,
+    pub verification_use_cases: Arc<VerificationUseCases<PostgresVerificationRepository>>,
     /// API usage tracking repository
     pub api_usage_repository: Arc<PostgresApiUsageRepository>,
     /// Database connection pool for health checks and direct access
     pub pool: PgPool,
+    /// Dataset served in place of the database, when running without one
+    pub embedded_dataset: Option<Arc<EmbeddedDataset>>,
 }
 
 impl AppState {
     pub fn new(
-        state_repository: PostgresStateRepository,
-        lga_repository: PostgresLgaRepository,
-        ward_repository: PostgresWardRepository,
-        postal_code_repository: PostgresPostalCodeRepository,
-        address_repository: PostgresAddressRepository,
-        api_usage_repository: PostgresApiUsageRepository,
+        state_repository: Arc<dyn StateRepository>,
+        lga_repository: Arc<dyn LgaRepository>,
+        ward_repository: Arc<dyn WardRepository>,
+        postal_code_repository: Arc<dyn PostalCodeRepository>,
+        search_repository: Arc<dyn SearchRepository>,
+        address_repository: Arc<dyn AddressRepository>,
         pool: PgPool,
     ) -> Self {
         // Initialize use cases directly without caching
@@ -54,14 +102,45 @@ impl AppState {
         let postal_code_use_cases =
             Arc::new(PostalCodeUseCases::new(postal_code_repository.clone()));
         let search_use_cases = Arc::new(SearchUseCases::new(
+            state_repository.clone(),
+            lga_repository.clone(),
+            ward_repository.clone(),
+            postal_code_repository.clone(),
+            search_repository.clone(),
+        ));
+        let hierarchy_use_cases = Arc::new(HierarchyUseCases::new(
             state_repository,
             lga_repository,
             ward_repository,
             postal_code_repository,
+            search_repository.clone(),
         ));
+        let autocomplete_use_cases = Arc::new(AutocompleteUseCases::new(search_repository));
 
         let address_use_cases = Arc::new(AddressUseCases::new(address_repository));
-        let api_usage_repository = Arc::new(api_usage_repository);
+        let alias_use_cases = Arc::new(AliasUseCases::new(PostgresAliasRepository::new(
+            pool.clone(),
+        )));
+        let history_use_cases = Arc::new(HistoryUseCases::new(PostgresHistoryRepository::new(
+            pool.clone(),
+        )));
+        let dataset_version_use_cases = Arc::new(DatasetVersionUseCases::new(
+            PostgresDatasetVersionRepository::new(pool.clone()),
+            PostgresHistoryRepository::new(pool.clone()),
+        ));
+        let batch_validation_use_cases =
+            Arc::new(BatchValidationUseCases::new(address_use_cases.clone()));
+        let export_use_cases = Arc::new(ExportUseCases::new(PostgresExportRepository::new(
+            pool.clone(),
+        )));
+        let bundle_use_cases = Arc::new(BundleUseCases::new(PostgresBundleRepository::new(
+            pool.clone(),
+        )));
+        let sync_use_cases = Arc::new(SyncUseCases::new(PostgresSyncRepository::new(pool.clone())));
+        let verification_use_cases = Arc::new(VerificationUseCases::new(
+            PostgresVerificationRepository::new(pool.clone()),
+        ));
+        let api_usage_repository = Arc::new(PostgresApiUsageRepository::new(pool.clone()));
 
         Self {
             state_use_cases,
@@ -69,9 +148,76 @@ impl AppState {
             ward_use_cases,
             postal_code_use_cases,
             search_use_cases,
+            autocomplete_use_cases,
+            hierarchy_use_cases,
             address_use_cases,
+            alias_use_cases,
+            history_use_cases,
+            dataset_version_use_cases,
+            batch_validation_use_cases,
+            export_use_cases,
+            bundle_use_cases,
+            sync_use_cases,
+            verification_use_cases,
             api_usage_repository,
             pool,
+            embedded_dataset: None,
+        }
+    }
+
+    /// State whose repositories all read and write through `pool`
+    pub fn from_pool(pool: PgPool) -> Self {
+        let state_repository = PostgresStateRepository::new(pool.clone());
+        let lga_repository = PostgresLgaRepository::new(pool.clone());
+        let ward_repository = PostgresWardRepository::new(pool.clone());
+        let postal_code_repository = PostgresPostalCodeRepository::new(pool.clone());
+        let address_repository = PostgresAddressRepository::new(
+            pool.clone(),
+            Box::new(state_repository.clone()),
+            Box::new(lga_repository.clone()),
+            Box::new(ward_repository.clone()),
+            Box::new(postal_code_repository.clone()),
+        );
+
+        Self::new(
+            Arc::new(state_repository),
+            Arc::new(lga_repository),
+            Arc::new(ward_repository),
+            Arc::new(postal_code_repository),
+            Arc::new(PostgresSearchRepository::new(pool.clone())),
+            Arc::new(address_repository),
+            pool,
+        )
+    }
+
+    /// State whose lookups, search and validation read `dataset` in memory
+    ///
+    /// The pool never connects; the routes needing PostgreSQL are not served
+    /// in this mode.
+    pub fn embedded(dataset: Arc<EmbeddedDataset>) -> Self {
+        let pool = PgPoolOptions::new().connect_lazy_with(PgConnectOptions::new());
+
+        Self {
+            embedded_dataset: Some(dataset.clone()),
+            ..Self::new(
+                Arc::new(EmbeddedStateRepository::new(dataset.clone())),
+                Arc::new(EmbeddedLgaRepository::new(dataset.clone())),
+                Arc::new(EmbeddedWardRepository::new(dataset.clone())),
+                Arc::new(EmbeddedPostalCodeRepository::new(dataset.clone())),
+                Arc::new(EmbeddedSearchRepository::new(dataset.clone())),
+                Arc::new(EmbeddedAddressRepository::new(dataset)),
+                pool,
+            )
+        }
+    }
+
+    /// Bring in-memory indexes up to date after a write through the admin API
+    ///
+    /// The autocomplete index is rebuilt now rather than at its next periodic
+    /// check. The country tree notices the change on its next request.
+    pub async fn data_changed(&self) {
+        if let Err(e) = self.autocomplete_use_cases.refresh().await {
+            warn!("Failed to refresh autocomplete index after a write: {}", e);
         }
     }
 